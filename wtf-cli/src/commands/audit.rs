@@ -0,0 +1,106 @@
+use crate::commands::{print_json, Command, OutputFormat};
+use async_trait::async_trait;
+use chrono::Local;
+use clap::{Arg, ArgMatches, Command as ClapCommand};
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+use wtf_lib::models::data::AuditEntry;
+use wtf_lib::services::audit_service::AuditService;
+
+const ACTIONS: [&str; 9] = [
+    "create", "edit", "delete", "stage", "unstage", "link", "unlink", "push", "revert",
+];
+
+pub struct AuditCommand;
+
+#[async_trait]
+impl Command for AuditCommand {
+    fn name(&self) -> &'static str {
+        "audit"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let limit = *matches.get_one::<usize>("limit").unwrap();
+        let action = matches.get_one::<String>("action");
+        let target = matches
+            .get_one::<String>("target")
+            .map(|t| t.to_lowercase());
+
+        let entries: Vec<AuditEntry> = AuditService::production()
+            .entries()
+            .into_iter()
+            .filter(|entry| action.is_none_or(|action| entry.action.name() == action))
+            .filter(|entry| {
+                target.as_ref().is_none_or(|target| {
+                    entry.target.to_lowercase().contains(target)
+                        || entry.details.to_lowercase().contains(target)
+                })
+            })
+            .collect();
+        let entries = &entries[entries.len().saturating_sub(limit)..];
+
+        if OutputFormat::from_matches(matches).is_json() {
+            print_json(entries);
+            return;
+        }
+        if entries.is_empty() {
+            println!("No change recorded.");
+            return;
+        }
+        let rows = entries.iter().map(AuditRow::new).collect::<Vec<_>>();
+        let mut table = Table::new(rows);
+        table.with(Style::modern().remove_horizontal());
+        println!("{}", table);
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Show the log of local changes: worklogs created, edited, staged, pushed...")
+            .arg(
+                Arg::new("limit")
+                    .long("limit")
+                    .short('n')
+                    .value_name("N")
+                    .value_parser(clap::value_parser!(usize))
+                    .default_value("50")
+                    .help("Show the N most recent changes"),
+            )
+            .arg(
+                Arg::new("action")
+                    .long("action")
+                    .value_parser(ACTIONS)
+                    .help("Only show this kind of change"),
+            )
+            .arg(
+                Arg::new("target")
+                    .long("target")
+                    .value_name("TEXT")
+                    .help("Only show changes whose record or details contain TEXT (an issue key, a worklog id...)"),
+            )
+    }
+}
+
+#[derive(Tabled)]
+struct AuditRow {
+    time: String,
+    action: &'static str,
+    source: String,
+    target: String,
+    details: String,
+}
+
+impl AuditRow {
+    fn new(entry: &AuditEntry) -> Self {
+        Self {
+            time: entry
+                .at
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string(),
+            action: entry.action.name(),
+            source: entry.source.clone(),
+            target: entry.target.clone(),
+            details: entry.details.clone(),
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use crate::commands::{print_json, Command, OutputFormat};
+use crate::exit;
+use async_trait::async_trait;
+use clap::{Arg, ArgAction, ArgMatches, Command as ClapCommand};
+use colored::Colorize;
+use inquire::Confirm;
+use serde::Serialize;
+use tabled::settings::object::Columns;
+use tabled::settings::{Alignment, Modify, Style};
+use tabled::{Table, Tabled};
+use wtf_lib::storage::backup::{Backup, BackupStore, PRE_RESTORE_LABEL};
+use wtf_lib::storage::database::is_database_available;
+
+pub struct BackupCommand;
+
+#[async_trait]
+impl Command for BackupCommand {
+    fn name(&self) -> &'static str {
+        "backup"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        match matches.subcommand() {
+            Some(("create", sub_m)) => BackupCreateCommand.execute(sub_m).await,
+            Some(("list", sub_m)) => BackupListCommand.execute(sub_m).await,
+            Some(("restore", sub_m)) => BackupRestoreCommand.execute(sub_m).await,
+            _ => eprintln!("❌ Unknown backup subcommand"),
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Create, list and restore backups of the config and database")
+            .subcommand_required(true)
+            .subcommand(BackupCreateCommand.clap_command())
+            .subcommand(BackupListCommand.clap_command())
+            .subcommand(BackupRestoreCommand.clap_command())
+    }
+}
+
+struct BackupCreateCommand;
+
+#[async_trait]
+impl Command for BackupCreateCommand {
+    fn name(&self) -> &'static str {
+        "create"
+    }
+
+    async fn execute(&self, _matches: &ArgMatches) {
+        match BackupStore::production().create(None) {
+            Ok(backup) => println!("✅ Backup created: {}", backup.path.display()),
+            Err(e) => exit::fail(format!("Failed to create the backup: {}", e)),
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name()).about("Copy the config and database into a new backup")
+    }
+}
+
+struct BackupListCommand;
+
+#[derive(Serialize)]
+struct BackupInfo {
+    name: String,
+    created_at: String,
+    /// `None` for manual backups, e.g. "pre-push" for automatic snapshots
+    label: Option<String>,
+    size_bytes: u64,
+    path: String,
+}
+
+#[derive(Tabled)]
+struct BackupRow {
+    name: String,
+    created: String,
+    kind: String,
+    size: String,
+}
+
+#[async_trait]
+impl Command for BackupListCommand {
+    fn name(&self) -> &'static str {
+        "list"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let store = BackupStore::production();
+        let backups = match store.list() {
+            Ok(backups) => backups,
+            Err(e) => return exit::fail(format!("Failed to read the backups: {}", e)),
+        };
+
+        if OutputFormat::from_matches(matches).is_json() {
+            let infos: Vec<_> = backups
+                .iter()
+                .map(|b| BackupInfo {
+                    name: b.name.clone(),
+                    created_at: b.created_at.format("%Y-%m-%dT%H:%M:%S").to_string(),
+                    label: b.label.clone(),
+                    size_bytes: b.size(),
+                    path: b.path.display().to_string(),
+                })
+                .collect();
+            print_json(&infos);
+            return;
+        }
+        if backups.is_empty() {
+            println!("No backups in {}", store.backup_dir().display());
+            return;
+        }
+
+        let rows: Vec<_> = backups.iter().map(backup_row).collect();
+        let mut table = Table::new(rows);
+        table.with(Style::modern().remove_horizontal());
+        table.with(Modify::new(Columns::last()).with(Alignment::right()));
+        println!("{table}");
+        println!(
+            "{} backup(s) in {}",
+            backups.len(),
+            store.backup_dir().display()
+        );
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name()).about("List backups, newest first")
+    }
+}
+
+fn backup_row(backup: &Backup) -> BackupRow {
+    BackupRow {
+        name: backup.name.clone(),
+        created: backup.created_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+        kind: backup.label.clone().unwrap_or_else(|| "manual".to_string()),
+        size: readable_size(backup.size()),
+    }
+}
+
+fn readable_size(bytes: u64) -> String {
+    if bytes < 1_048_576 {
+        format!("{:.0} KB", (bytes as f64 / 1024.0).ceil())
+    } else {
+        format!("{:.1} MB", bytes as f64 / 1_048_576.0)
+    }
+}
+
+struct BackupRestoreCommand;
+
+#[async_trait]
+impl Command for BackupRestoreCommand {
+    fn name(&self) -> &'static str {
+        "restore"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let name = matches.get_one::<String>("backup").unwrap();
+        let store = BackupStore::production();
+        let backup = match store.find(name) {
+            Ok(Some(backup)) => backup,
+            Ok(None) => {
+                return exit::fail(format!(
+                    "No single backup matches '{}' (see `wtf backup list`)",
+                    name
+                ))
+            }
+            Err(e) => return exit::fail(format!("Failed to read the backups: {}", e)),
+        };
+        if !is_database_available() {
+            return exit::fail(
+                "The database is in use. Close the TUI and stop `wtf daemon` before restoring",
+            );
+        }
+
+        if !matches.get_flag("yes") {
+            let prompt = format!(
+                "⚠️  Replace the current config and database with backup {}?",
+                backup.name
+            );
+            let confirmed = Confirm::new(&prompt)
+                .with_default(false)
+                .prompt()
+                .unwrap_or(false);
+            if !confirmed {
+                println!("Cancelled");
+                return;
+            }
+        }
+
+        let current = match store.create(Some(PRE_RESTORE_LABEL)) {
+            Ok(current) => current,
+            Err(e) => {
+                return exit::fail(format!("Failed to back up the current state: {}", e));
+            }
+        };
+        if let Err(e) = store.restore(&backup) {
+            return exit::fail(format!(
+                "Restore failed: {}. The previous state is in {}",
+                e,
+                current.path.display()
+            ));
+        }
+        println!("✅ Restored backup {}", backup.name.bold());
+        println!(
+            "   Previous state saved as {} (undo with `wtf backup restore {}`)",
+            current.name, current.name
+        );
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Replace the config and database with a backup")
+            .arg(
+                Arg::new("backup")
+                    .required(true)
+                    .value_name("TIMESTAMP")
+                    .help("Backup name from `wtf backup list`; a unique prefix is enough"),
+            )
+            .arg(
+                Arg::new("yes")
+                    .long("yes")
+                    .short('y')
+                    .action(ArgAction::SetTrue)
+                    .help("Don't ask for confirmation"),
+            )
+    }
+}
@@ -1,4 +1,5 @@
-use crate::commands::Command;
+use crate::commands::{print_json, Command, OutputFormat};
+use crate::exit;
 use crate::tasks::jira_tasks::FetchJiraBoard;
 use crate::tasks::Task;
 use async_trait::async_trait;
@@ -47,13 +48,25 @@ impl Command for BoardListCommand {
 
     async fn execute(&self, matches: &ArgMatches) {
         let list_all = matches.get_flag("all");
+        let format = OutputFormat::from_matches(matches);
         let boards = if list_all {
+            JiraService::production().get_available_boards()
+        } else {
+            JiraService::production().get_followed_boards()
+        };
+        let boards = match boards {
+            Ok(boards) => boards,
+            Err(e) => return exit::fail(format!("Failed to load the boards: {}", e)),
+        };
+        if format.is_json() {
+            print_json(&boards);
+            return;
+        }
+        if list_all {
             println!("Listing all available boards:");
-            JiraService::production().get_available_boards().unwrap()
         } else {
             println!("Listing followed boards:");
-            JiraService::production().get_followed_boards().unwrap()
-        };
+        }
         if boards.is_empty() {
             println!("No board found.");
             return;
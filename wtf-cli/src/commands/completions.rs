@@ -0,0 +1,126 @@
+use crate::commands::{build_app, default_registry, Command};
+use async_trait::async_trait;
+use clap::{Arg, ArgAction, ArgMatches, Command as ClapCommand};
+use clap_complete::engine::{ArgValueCandidates, CompletionCandidate};
+use clap_complete::env::{Bash, Elvish, EnvCompleter, Fish, Powershell, Zsh};
+use clap_complete::{generate, Shell};
+use std::io;
+use wtf_lib::config::Config;
+use wtf_lib::services::jira_service::{IssueService, JiraService};
+use wtf_lib::storage::database::is_database_available;
+
+/// Environment variable the generated scripts set to ask `wtf` for completions
+pub const COMPLETE_VAR: &str = "COMPLETE";
+
+const SHELLS: [&str; 5] = ["bash", "zsh", "fish", "elvish", "powershell"];
+
+pub struct CompletionsCommand;
+
+#[async_trait]
+impl Command for CompletionsCommand {
+    fn name(&self) -> &'static str {
+        "completions"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let shell = matches.get_one::<String>("shell").unwrap();
+        let mut out = io::stdout();
+
+        if matches.get_flag("static") {
+            let shell: Shell = shell.parse().unwrap();
+            let mut app = build_app(&default_registry());
+            generate(shell, &mut app, "wtf", &mut out);
+            return;
+        }
+
+        let completer: &dyn EnvCompleter = match shell.as_str() {
+            "bash" => &Bash,
+            "zsh" => &Zsh,
+            "fish" => &Fish,
+            "elvish" => &Elvish,
+            _ => &Powershell,
+        };
+        if let Err(e) = completer.write_registration(COMPLETE_VAR, "wtf", "wtf", "wtf", &mut out) {
+            eprintln!("❌ Failed to write completion script: {}", e);
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Print the shell completion script")
+            .long_about(
+                "Print the shell completion script.\n\n\
+                 By default the script asks wtf for candidates while completing, so issue keys \
+                 and sprint IDs come from the local database. Load it from your shell startup \
+                 file, e.g. `source <(wtf completions bash)`.",
+            )
+            .arg(
+                Arg::new("shell")
+                    .required(true)
+                    .value_parser(SHELLS)
+                    .help("The shell to generate the script for"),
+            )
+            .arg(
+                Arg::new("static")
+                    .long("static")
+                    .action(ArgAction::SetTrue)
+                    .help("Print a self-contained script (subcommands and flags only)"),
+            )
+    }
+}
+
+/// Issue keys from the local database, with their summary as description, and the
+/// `[aliases]` names
+pub fn issue_keys() -> ArgValueCandidates {
+    ArgValueCandidates::new(|| {
+        if !is_database_available() {
+            return Vec::new();
+        }
+        let aliases = Config::load().unwrap_or_default().aliases;
+        IssueService::production()
+            .get_all_issues()
+            .into_iter()
+            .map(|issue| CompletionCandidate::new(issue.key).help(Some(issue.summary.into())))
+            .chain(aliases.into_iter().map(|(name, key)| {
+                CompletionCandidate::new(name).help(Some(format!("alias of {}", key).into()))
+            }))
+            .collect()
+    })
+}
+
+/// Sprint IDs from the local database, with their name as description
+pub fn sprint_ids() -> ArgValueCandidates {
+    ArgValueCandidates::new(|| {
+        if !is_database_available() {
+            return Vec::new();
+        }
+        JiraService::production()
+            .get_available_sprints()
+            .into_iter()
+            .map(|sprint| {
+                CompletionCandidate::new(sprint.id.to_string()).help(Some(sprint.name.into()))
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_static_script_covers_every_shell_and_command() {
+        for shell in SHELLS {
+            let shell: Shell = shell.parse().unwrap();
+            let mut script = Vec::new();
+            generate(
+                shell,
+                &mut build_app(&default_registry()),
+                "wtf",
+                &mut script,
+            );
+            let script = String::from_utf8(script).unwrap();
+            assert!(script.contains("completions"), "{} script", shell);
+        }
+    }
+}
@@ -1,8 +1,15 @@
+use crate::commands::config_secret::ConfigSecretCommand;
 use crate::commands::Command;
+use crate::exit::{self, ExitStatus};
 use async_trait::async_trait;
 use clap::{Arg, ArgAction, ArgMatches, Command as ClapCommand};
+use std::fs;
+use std::path::PathBuf;
 use toml::Value;
-use wtf_lib::config::{Config, SensitiveString};
+use wtf_lib::config::{
+    active_profile, base_config_home, config_path, profiles, Config, SensitiveString,
+};
+use wtf_lib::utils::config_file;
 
 pub struct ConfigCommand;
 
@@ -15,6 +22,11 @@ impl Command for ConfigCommand {
     async fn execute(&self, matches: &ArgMatches) {
         match matches.subcommand() {
             Some(("list", sub_matches)) => ConfigListCommand.execute(sub_matches).await,
+            Some(("get", sub_matches)) => ConfigGetCommand.execute(sub_matches).await,
+            Some(("set", sub_matches)) => ConfigSetCommand.execute(sub_matches).await,
+            Some(("validate", sub_matches)) => ConfigValidateCommand.execute(sub_matches).await,
+            Some(("profiles", sub_matches)) => ConfigProfilesCommand.execute(sub_matches).await,
+            Some(("secret", sub_matches)) => ConfigSecretCommand.execute(sub_matches).await,
             _ => eprintln!("Invalid subcommand for config"),
         }
     }
@@ -23,6 +35,11 @@ impl Command for ConfigCommand {
         ClapCommand::new(self.name())
             .about("Manage configuration")
             .subcommand(ConfigListCommand.clap_command())
+            .subcommand(ConfigGetCommand.clap_command())
+            .subcommand(ConfigSetCommand.clap_command())
+            .subcommand(ConfigValidateCommand.clap_command())
+            .subcommand(ConfigProfilesCommand.clap_command())
+            .subcommand(ConfigSecretCommand.clap_command())
     }
 }
 
@@ -55,6 +72,184 @@ impl Command for ConfigListCommand {
     }
 }
 
+pub struct ConfigGetCommand;
+
+#[async_trait]
+impl Command for ConfigGetCommand {
+    fn name(&self) -> &'static str {
+        "get"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let key = matches.get_one::<String>("key").unwrap();
+        let show_sensitive = matches.get_flag("show-sensitive");
+        let config = match Config::load() {
+            Ok(config) => config,
+            Err(e) => return exit::fail(format!("Failed to load the config: {}", e)),
+        };
+        let value = Value::try_from(&config).unwrap();
+        let found = key
+            .split('.')
+            .try_fold(&value, |current, part| current.as_table()?.get(part));
+        match found {
+            // A single value prints bare, for `$(wtf config get ...)`
+            Some(table @ Value::Table(_)) => print_nested(key, table, show_sensitive),
+            Some(value) => println!("{}", display_value(value, show_sensitive)),
+            None => exit::fail(format!("'{}' is not set", key)),
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Print a config value, or every value of a section")
+            .arg(
+                Arg::new("key")
+                    .required(true)
+                    .value_name("KEY")
+                    .help("Dotted key, e.g. worklog.daily_hours_limit or jira"),
+            )
+            .arg(
+                Arg::new("show-sensitive")
+                    .short('s')
+                    .long("show-sensitive")
+                    .help("show sensitive information")
+                    .action(ArgAction::SetTrue),
+            )
+    }
+}
+
+pub struct ConfigSetCommand;
+
+#[async_trait]
+impl Command for ConfigSetCommand {
+    fn name(&self) -> &'static str {
+        "set"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let key = matches.get_one::<String>("key").unwrap();
+        let value = matches.get_one::<String>("value").unwrap();
+        // No config yet: start from the defaults, like `wtf init` does
+        let text = match fs::read_to_string(config_path()) {
+            Ok(text) => text,
+            Err(_) => toml::to_string(&Config::default()).unwrap_or_default(),
+        };
+
+        let config = match config_file::set_key(&text, key, value) {
+            Ok(config) => config,
+            Err(e) => return exit::fail(e),
+        };
+        if let Err(e) = config.save() {
+            return exit::fail(format!("Failed to save the config: {}", e));
+        }
+        println!("✅ {} updated", key);
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Change a config value")
+            .long_about(
+                "Change a config value, e.g. `wtf config set worklog.daily_hours_limit 7.4`.\n\n\
+                 The value is checked against the key's type. Lists take TOML arrays or \
+                 comma-separated values: `wtf config set jira.project_keys PROJ,OPS`.",
+            )
+            .arg(
+                Arg::new("key")
+                    .required(true)
+                    .value_name("KEY")
+                    .help("Dotted key, e.g. worklog.daily_hours_limit"),
+            )
+            .arg(
+                Arg::new("value")
+                    .required(true)
+                    .value_name("VALUE")
+                    .allow_hyphen_values(true)
+                    .help("New value"),
+            )
+    }
+}
+
+pub struct ConfigValidateCommand;
+
+#[async_trait]
+impl Command for ConfigValidateCommand {
+    fn name(&self) -> &'static str {
+        "validate"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let path = matches
+            .get_one::<String>("file")
+            .map(PathBuf::from)
+            .unwrap_or_else(config_path);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) => return exit::fail(format!("Failed to read {}: {}", path.display(), e)),
+        };
+
+        let validation = config_file::validate(&text);
+        for warning in &validation.warnings {
+            eprintln!("⚠️  {}", warning);
+        }
+        for error in &validation.errors {
+            eprintln!("❌ {}", error);
+        }
+        if validation.is_valid() {
+            println!("✅ {} is valid", path.display());
+        } else {
+            exit::report(ExitStatus::Failure);
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Check the config file for syntax errors, wrong types and unknown keys")
+            .arg(
+                Arg::new("file")
+                    .value_name("FILE")
+                    .help("Config file to check (default: the current one)"),
+            )
+    }
+}
+
+pub struct ConfigProfilesCommand;
+
+#[async_trait]
+impl Command for ConfigProfilesCommand {
+    fn name(&self) -> &'static str {
+        "profiles"
+    }
+
+    async fn execute(&self, _matches: &ArgMatches) {
+        let active = active_profile();
+        let marker = |selected: bool| if selected { "*" } else { " " };
+        println!("{} (default)", marker(active.is_none()));
+        for profile in profiles() {
+            println!("{} {}", marker(active.as_ref() == Some(&profile)), profile);
+        }
+        println!(
+            "Profiles live in {}",
+            base_config_home().join("profiles").display()
+        );
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("List the profiles; create one with `wtf --profile <name> init`")
+    }
+}
+
+fn display_value(value: &Value, show_sensitive: bool) -> String {
+    match value {
+        Value::String(s) => match SensitiveString::decode_str(s) {
+            Ok(sensitive_string) if show_sensitive => sensitive_string.reveal().to_string(),
+            Ok(sensitive_string) => sensitive_string.to_string(),
+            Err(_) => s.to_string(),
+        },
+        _ => value.to_string(),
+    }
+}
+
 fn print_nested(prefix: &str, value: &Value, show_sensitive: bool) {
     match value {
         Value::Table(map) => {
@@ -67,19 +262,6 @@ fn print_nested(prefix: &str, value: &Value, show_sensitive: bool) {
                 print_nested(&new_prefix, val, show_sensitive);
             }
         }
-        Value::String(s) => {
-            let value_to_show: String = match SensitiveString::decode_str(s) {
-                Ok(sensitive_string) => {
-                    if show_sensitive {
-                        sensitive_string.reveal().to_string()
-                    } else {
-                        sensitive_string.to_string()
-                    }
-                }
-                Err(_) => s.to_string(),
-            };
-            println!("{} = {}", prefix, value_to_show);
-        }
-        _ => println!("{} = {}", prefix, value),
+        _ => println!("{} = {}", prefix, display_value(value, show_sensitive)),
     }
 }
@@ -0,0 +1,255 @@
+use crate::commands::Command;
+use crate::exit;
+use async_trait::async_trait;
+use clap::{Arg, ArgAction, ArgMatches, Command as ClapCommand};
+use inquire::Password;
+use std::fs;
+use std::io::{self, Read};
+use toml::Value;
+use wtf_lib::config::{config_path, expand_path, Config};
+use wtf_lib::secrets::Secret;
+use wtf_lib::services::google_service::parse_application_secret;
+
+const NAMES: [&str; 3] = ["jira", "google", "github"];
+
+/// `wtf config secret`: keep the Jira and GitHub tokens and the Google credentials in the
+/// OS keyring
+pub struct ConfigSecretCommand;
+
+#[async_trait]
+impl Command for ConfigSecretCommand {
+    fn name(&self) -> &'static str {
+        "secret"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        match matches.subcommand() {
+            Some(("set", sub_matches)) => SecretSetCommand.execute(sub_matches).await,
+            Some(("delete", sub_matches)) => SecretDeleteCommand.execute(sub_matches).await,
+            Some(("status", sub_matches)) => SecretStatusCommand.execute(sub_matches).await,
+            _ => eprintln!("Invalid subcommand for config secret"),
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Keep the Jira and GitHub tokens and the Google credentials in the OS keyring")
+            .long_about(
+                "Keep the Jira and GitHub tokens and the Google credentials in the OS keyring (macOS \
+                 Keychain, Windows Credential Manager, Secret Service on Linux) instead of \
+                 config.toml.\n\n\
+                 A token still set in config.toml takes precedence over the keyring, so \
+                 headless machines without a keyring keep working as before.",
+            )
+            .subcommand_required(true)
+            .subcommand(SecretSetCommand.clap_command())
+            .subcommand(SecretDeleteCommand.clap_command())
+            .subcommand(SecretStatusCommand.clap_command())
+    }
+}
+
+fn secret_arg() -> Arg {
+    Arg::new("name")
+        .required(true)
+        .value_name("NAME")
+        .value_parser(NAMES)
+        .help("jira (API token), google (OAuth credentials JSON) or github (access token)")
+}
+
+fn parse_secret(matches: &ArgMatches) -> Secret {
+    let name = matches.get_one::<String>("name").unwrap();
+    Secret::from_name(name).expect("validated by clap")
+}
+
+struct SecretSetCommand;
+
+#[async_trait]
+impl Command for SecretSetCommand {
+    fn name(&self) -> &'static str {
+        "set"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let secret = parse_secret(matches);
+        let value = match secret {
+            Secret::GoogleCredentials => read_google_credentials(matches),
+            _ => read_token(secret, matches.get_flag("stdin")),
+        };
+        let value = match value {
+            Ok(value) => value,
+            Err(e) => return exit::fail(e),
+        };
+
+        if let Err(e) = secret.set(&value) {
+            return exit::fail(format!(
+                "{} (on machines without a keyring, keep the secret in config.toml)",
+                e
+            ));
+        }
+        println!("✅ {} secret saved in the OS keyring", secret.name());
+
+        if secret != Secret::GoogleCredentials {
+            strip_token(secret, &value);
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Save a secret in the OS keyring")
+            .long_about(
+                "Save a secret in the OS keyring.\n\n\
+                 `jira` and `github` prompt for the token, or read it from stdin with --stdin, \
+                 and remove it from config.toml. `google` stores the content of the OAuth \
+                 credentials file: google.credentials_path, or the file given with --from.",
+            )
+            .arg(secret_arg())
+            .arg(
+                Arg::new("stdin")
+                    .long("stdin")
+                    .action(ArgAction::SetTrue)
+                    .help("Read the token from stdin instead of prompting"),
+            )
+            .arg(
+                Arg::new("from")
+                    .long("from")
+                    .value_name("FILE")
+                    .help("Google credentials file (default: google.credentials_path)"),
+            )
+    }
+}
+
+fn read_token(secret: Secret, from_stdin: bool) -> Result<String, String> {
+    let token = if from_stdin {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| format!("Failed to read stdin: {}", e))?;
+        input
+    } else {
+        let prompt = match secret {
+            Secret::GitHubToken => "GitHub token (hidden):",
+            _ => "Jira API token (hidden):",
+        };
+        Password::new(prompt)
+            .without_confirmation()
+            .prompt()
+            .map_err(|e| e.to_string())?
+    };
+    let token = token.trim();
+    if token.is_empty() {
+        return Err("The token is empty".to_string());
+    }
+    Ok(token.to_string())
+}
+
+fn read_google_credentials(matches: &ArgMatches) -> Result<String, String> {
+    let path = match matches.get_one::<String>("from") {
+        Some(path) => expand_path(path),
+        None => {
+            let config = Config::load().map_err(|e| format!("Failed to load the config: {}", e))?;
+            match config.google {
+                Some(google) => expand_path(&google.credentials_path),
+                None => return Err("No google.credentials_path set: use --from FILE".to_string()),
+            }
+        }
+    };
+    let json = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    parse_application_secret(json.as_bytes()).map_err(|e| e.to_string())?;
+    Ok(json)
+}
+
+/// Saving the config leaves out the token now in the keyring
+fn strip_token(secret: Secret, token: &str) {
+    if !has_token_in_file(secret) {
+        return;
+    }
+    let Ok(config) = Config::load() else {
+        return;
+    };
+    let (file_token, label) = match secret {
+        Secret::GitHubToken => (config.github.token(), "GitHub"),
+        _ => (Some(config.jira.api_token.reveal()), "Jira"),
+    };
+    if file_token != Some(token) {
+        println!(
+            "   config.toml has another {} token, which still takes precedence",
+            label
+        );
+        return;
+    }
+    match config.save() {
+        Ok(()) => println!("   Removed the token from config.toml"),
+        Err(e) => eprintln!("⚠️  Failed to remove the token from config.toml: {}", e),
+    }
+}
+
+struct SecretDeleteCommand;
+
+#[async_trait]
+impl Command for SecretDeleteCommand {
+    fn name(&self) -> &'static str {
+        "delete"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let secret = parse_secret(matches);
+        match secret.delete() {
+            Ok(true) => println!("✅ {} secret removed from the OS keyring", secret.name()),
+            Ok(false) => println!("No {} secret in the OS keyring", secret.name()),
+            Err(e) => exit::fail(e),
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Remove a secret from the OS keyring")
+            .arg(secret_arg())
+    }
+}
+
+struct SecretStatusCommand;
+
+#[async_trait]
+impl Command for SecretStatusCommand {
+    fn name(&self) -> &'static str {
+        "status"
+    }
+
+    async fn execute(&self, _matches: &ArgMatches) {
+        for secret in Secret::CONFIGURABLE {
+            let source = match secret.get() {
+                // The token of config.toml takes precedence over the keyring's
+                _ if has_token_in_file(secret) => "config.toml".to_string(),
+                Ok(Some(_)) => "OS keyring".to_string(),
+                Ok(None) => "config.toml".to_string(),
+                Err(e) => format!("config.toml ({})", e),
+            };
+            println!("{:<8}{}", secret.name(), source);
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name()).about("Show where each secret is read from")
+    }
+}
+
+/// Whether config.toml holds the token `secret` would otherwise provide
+fn has_token_in_file(secret: Secret) -> bool {
+    let (section, key) = match secret {
+        Secret::JiraToken => ("jira", "api_token"),
+        Secret::GitHubToken => ("github", "token"),
+        _ => return false,
+    };
+    fs::read_to_string(config_path())
+        .ok()
+        .and_then(|text| text.parse::<Value>().ok())
+        .and_then(|config| {
+            config
+                .get(section)?
+                .get(key)?
+                .as_str()
+                .map(|token| !token.is_empty())
+        })
+        .unwrap_or(false)
+}
@@ -0,0 +1,121 @@
+use crate::commands::Command;
+use crate::exit;
+use crate::tasks::ensure_online;
+use async_trait::async_trait;
+use chrono::Local;
+use clap::{Arg, ArgAction, ArgMatches, Command as ClapCommand};
+use std::process::{Command as ProcessCommand, Stdio};
+use std::time::Duration;
+use wtf_lib::config::Config;
+use wtf_lib::storage::database::is_database_available;
+
+/// `wtf` invocations making up one sync
+const SYNC_STEPS: [(&str, &[&str]); 2] = [
+    ("Jira & Google", &["fetch", "all", "--no-prompt"]),
+    ("GitHub", &["fetch", "github"]),
+];
+
+pub struct DaemonCommand;
+
+#[async_trait]
+impl Command for DaemonCommand {
+    fn name(&self) -> &'static str {
+        "daemon"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        if let Err(e) = ensure_online("syncing") {
+            return exit::fail(e);
+        }
+        let interval = matches
+            .get_one::<u64>("interval")
+            .copied()
+            .unwrap_or_else(|| Config::load().map_or(30, |c| c.daemon.interval_minutes));
+        let once = matches.get_flag("once");
+        if !once {
+            println!(
+                "🔄 Syncing Jira, Google Calendar and GitHub every {} min (Ctrl+C to stop)",
+                interval
+            );
+        }
+
+        loop {
+            sync();
+            if once {
+                break;
+            }
+            tokio::time::sleep(Duration::from_secs(interval.max(1) * 60)).await;
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Keep the local data fresh by fetching it periodically")
+            .long_about(
+                "Keep the local data fresh by fetching Jira, Google Calendar and GitHub \
+                 periodically, so the TUI opens on recent data. Every fetch runs in its own \
+                 `wtf fetch` process and the database stays free in between; syncs are skipped \
+                 while the TUI holds it. `wtf status` and the TUI footer show the last sync.",
+            )
+            .arg(
+                Arg::new("interval")
+                    .long("interval")
+                    .short('i')
+                    .value_name("MINUTES")
+                    .value_parser(clap::value_parser!(u64).range(1..))
+                    .help("Minutes between syncs (default: daemon.interval_minutes, 30)"),
+            )
+            .arg(
+                Arg::new("once")
+                    .long("once")
+                    .action(ArgAction::SetTrue)
+                    .help("Sync once and exit, e.g. from cron or a systemd timer"),
+            )
+    }
+}
+
+/// Run one sync, reporting a single timestamped line
+fn sync() {
+    let stamp = Local::now().format("%Y-%m-%d %H:%M");
+    // sled allows a single process at a time: the database must not be locked by us
+    // between syncs, and the TUI keeps it locked while open
+    if !is_database_available() {
+        println!("[{}] ⏸  Database in use (TUI open?), skipping", stamp);
+        return;
+    }
+    let exe = match std::env::current_exe() {
+        Ok(exe) => exe,
+        Err(e) => {
+            eprintln!("[{}] ❌ Cannot locate the wtf executable: {}", stamp, e);
+            return;
+        }
+    };
+
+    let mut failed = Vec::new();
+    for (label, args) in SYNC_STEPS {
+        let status = ProcessCommand::new(&exe)
+            .args(args)
+            .arg("--quiet")
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status();
+        match status.map(|s| s.code()) {
+            // Success, or nothing to fetch yet
+            Ok(Some(0)) | Ok(Some(4)) => {}
+            Ok(Some(2)) => failed.push(format!("{} (authentication failed)", label)),
+            Ok(Some(3)) => failed.push(format!("{} (partially)", label)),
+            _ => failed.push(label.to_string()),
+        }
+    }
+
+    if failed.is_empty() {
+        println!("[{}] ✅ Synced", stamp);
+    } else {
+        println!(
+            "[{}] ⚠️  Sync failed for {} (run `wtf fetch` to see why)",
+            stamp,
+            failed.join(", ")
+        );
+    }
+}
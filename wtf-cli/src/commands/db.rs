@@ -0,0 +1,395 @@
+use crate::commands::db_encrypt::{DbDecryptCommand, DbEncryptCommand};
+use crate::commands::Command;
+use crate::exit;
+use async_trait::async_trait;
+use chrono::{Local, Months};
+use clap::{Arg, ArgAction, ArgMatches, Command as ClapCommand};
+use colored::Colorize;
+use inquire::Confirm;
+use std::fs;
+use std::path::PathBuf;
+use wtf_lib::config::{config_home, expand_path, Config, StorageConfig};
+use wtf_lib::models::data::AuditAction;
+use wtf_lib::services::audit_service::AuditService;
+use wtf_lib::services::machine_sync_service::MachineSyncService;
+use wtf_lib::storage::database::DATABASE;
+use wtf_lib::storage::dump::DatabaseDump;
+
+pub struct DbCommand;
+
+#[async_trait]
+impl Command for DbCommand {
+    fn name(&self) -> &'static str {
+        "db"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        match matches.subcommand() {
+            Some(("export", sub_m)) => DbExportCommand.execute(sub_m).await,
+            Some(("import", sub_m)) => DbImportCommand.execute(sub_m).await,
+            Some(("encrypt", sub_m)) => DbEncryptCommand.execute(sub_m).await,
+            Some(("decrypt", sub_m)) => DbDecryptCommand.execute(sub_m).await,
+            Some(("prune", sub_m)) => DbPruneCommand.execute(sub_m).await,
+            Some(("sync", sub_m)) => DbSyncCommand.execute(sub_m).await,
+            _ => eprintln!("❌ Unknown db subcommand"),
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Export, import, encrypt, prune or sync the local database")
+            .subcommand_required(true)
+            .subcommand(DbExportCommand.clap_command())
+            .subcommand(DbImportCommand.clap_command())
+            .subcommand(DbEncryptCommand.clap_command())
+            .subcommand(DbDecryptCommand.clap_command())
+            .subcommand(DbPruneCommand.clap_command())
+            .subcommand(DbSyncCommand.clap_command())
+    }
+}
+
+struct DbExportCommand;
+
+#[async_trait]
+impl Command for DbExportCommand {
+    fn name(&self) -> &'static str {
+        "export"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let file = matches.get_one::<String>("file").unwrap();
+        let dump = match DatabaseDump::export(&DATABASE) {
+            Ok(dump) => dump,
+            Err(e) => return exit::fail(format!("Failed to read the database: {}", e)),
+        };
+        let json = match serde_json::to_string_pretty(&dump) {
+            Ok(json) => json,
+            Err(e) => return exit::fail(format!("Failed to serialize the database: {}", e)),
+        };
+
+        if file == "-" {
+            println!("{}", json);
+            return;
+        }
+        if let Err(e) = fs::write(file, json) {
+            return exit::fail(format!("Failed to write {}: {}", file, e));
+        }
+        println!("✅ Database exported to {}", file.bold());
+        print_counts(dump.counts());
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about(
+                "Write boards, sprints, issues, meetings, worklogs and achievements to a JSON file",
+            )
+            .arg(
+                Arg::new("file")
+                    .required(true)
+                    .value_name("FILE")
+                    .help("Destination file, or - for stdout"),
+            )
+    }
+}
+
+struct DbImportCommand;
+
+#[async_trait]
+impl Command for DbImportCommand {
+    fn name(&self) -> &'static str {
+        "import"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let file = matches.get_one::<String>("file").unwrap();
+        let replace = matches.get_flag("replace");
+
+        let json = match fs::read_to_string(file) {
+            Ok(json) => json,
+            Err(e) => return exit::fail(format!("Failed to read {}: {}", file, e)),
+        };
+        let dump: DatabaseDump = match serde_json::from_str(&json) {
+            Ok(dump) => dump,
+            Err(e) => return exit::fail(format!("{} is not a wtf database export: {}", file, e)),
+        };
+
+        if replace && !matches.get_flag("yes") {
+            let confirmed =
+                Confirm::new("⚠️  This will erase the local data before importing. Continue?")
+                    .with_default(false)
+                    .prompt()
+                    .unwrap_or(false);
+            if !confirmed {
+                println!("Cancelled");
+                return;
+            }
+        }
+
+        let exported_at = dump.exported_at;
+        let counts = dump.counts();
+        if let Err(e) = dump.import(&DATABASE, replace) {
+            return exit::fail(format!("Import failed: {}", e));
+        }
+        println!(
+            "✅ Imported {} (exported {})",
+            file.bold(),
+            exported_at.format("%Y-%m-%d %H:%M UTC")
+        );
+        print_counts(counts);
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Load a JSON export into the local database")
+            .long_about(
+                "Load a JSON export into the local database.\n\n\
+                 Records are merged by ID: existing ones are overwritten, others are kept. \
+                 Use --replace to empty each collection first.",
+            )
+            .arg(
+                Arg::new("file")
+                    .required(true)
+                    .value_name("FILE")
+                    .help("File written by `wtf db export`"),
+            )
+            .arg(
+                Arg::new("replace")
+                    .long("replace")
+                    .action(ArgAction::SetTrue)
+                    .help("Erase the local data before importing"),
+            )
+            .arg(
+                Arg::new("yes")
+                    .long("yes")
+                    .short('y')
+                    .action(ArgAction::SetTrue)
+                    .help("Don't ask for confirmation with --replace"),
+            )
+    }
+}
+
+struct DbPruneCommand;
+
+#[async_trait]
+impl Command for DbPruneCommand {
+    fn name(&self) -> &'static str {
+        "prune"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let Some(months) = matches
+            .get_one::<u32>("months")
+            .copied()
+            .or(StorageConfig::load().retention_months)
+        else {
+            return exit::fail(
+                "No retention window: set storage.retention_months or pass --months".to_string(),
+            );
+        };
+        let today = Local::now().date_naive();
+        let Some(cutoff) = today.checked_sub_months(Months::new(months)) else {
+            return exit::fail(format!("{} months is too long a window", months));
+        };
+
+        let pruned = match DatabaseDump::older_than(&DATABASE, cutoff) {
+            Ok(pruned) => pruned,
+            Err(e) => return exit::fail(format!("Failed to read the database: {}", e)),
+        };
+        let counts: Vec<_> = pruned
+            .counts()
+            .into_iter()
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        if counts.is_empty() {
+            println!("✅ Nothing older than {} to prune", cutoff);
+            return;
+        }
+        println!("Records older than {} ({} months):", cutoff, months);
+        print_counts(counts);
+        if matches.get_flag("dry-run") {
+            return;
+        }
+
+        let archive = if matches.get_flag("no-archive") {
+            None
+        } else {
+            Some(
+                matches
+                    .get_one::<String>("archive")
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| {
+                        config_home().join("archives").join(format!(
+                            "prune_{}.json",
+                            Local::now().format("%Y%m%d_%H%M%S")
+                        ))
+                    }),
+            )
+        };
+        if !matches.get_flag("yes") {
+            let question = match &archive {
+                Some(_) => "Archive and delete these records?",
+                None => "⚠️  Delete these records for good?",
+            };
+            let confirmed = Confirm::new(question)
+                .with_default(false)
+                .prompt()
+                .unwrap_or(false);
+            if !confirmed {
+                println!("Cancelled");
+                return;
+            }
+        }
+
+        if let Some(path) = &archive {
+            let json = match serde_json::to_string_pretty(&pruned) {
+                Ok(json) => json,
+                Err(e) => return exit::fail(format!("Failed to serialize the archive: {}", e)),
+            };
+            let written = path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::write(path, json));
+            if let Err(e) = written {
+                return exit::fail(format!("Failed to write {}: {}", path.display(), e));
+            }
+        }
+        if let Err(e) = pruned.remove_from(&DATABASE) {
+            return exit::fail(format!("Prune failed: {}", e));
+        }
+        let total: usize = pruned.counts().iter().map(|(_, count)| count).sum();
+        AuditService::production().record(
+            AuditAction::Delete,
+            "database",
+            format!("pruned {} records older than {}", total, cutoff),
+        );
+        match archive {
+            Some(path) => println!(
+                "✅ Pruned, archived to {} (bring it back with `wtf db import`)",
+                path.display().to_string().bold()
+            ),
+            None => println!("✅ Pruned"),
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Archive and delete the worklogs, meetings and GitHub activity older than the retention window")
+            .long_about(
+                "Archive and delete the worklogs, meetings and GitHub activity older than \
+                 storage.retention_months, to keep the database and the TUI fast.\n\n\
+                 Worklogs not pushed yet and recurring meetings are kept. The deleted records \
+                 are written to ~/.config/wtf/archives first, in the `wtf db export` format.",
+            )
+            .arg(
+                Arg::new("months")
+                    .long("months")
+                    .value_name("N")
+                    .value_parser(clap::value_parser!(u32).range(1..))
+                    .help("Keep the last N months instead of storage.retention_months"),
+            )
+            .arg(
+                Arg::new("archive")
+                    .long("archive")
+                    .value_name("FILE")
+                    .help("Where to archive the deleted records"),
+            )
+            .arg(
+                Arg::new("no-archive")
+                    .long("no-archive")
+                    .action(ArgAction::SetTrue)
+                    .conflicts_with("archive")
+                    .help("Delete without archiving"),
+            )
+            .arg(
+                Arg::new("dry-run")
+                    .long("dry-run")
+                    .action(ArgAction::SetTrue)
+                    .help("Only count what would be pruned"),
+            )
+            .arg(
+                Arg::new("yes")
+                    .long("yes")
+                    .short('y')
+                    .action(ArgAction::SetTrue)
+                    .help("Don't ask for confirmation"),
+            )
+    }
+}
+
+struct DbSyncCommand;
+
+#[async_trait]
+impl Command for DbSyncCommand {
+    fn name(&self) -> &'static str {
+        "sync"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let config = Config::load().unwrap_or_default().sync;
+        let Some(folder) = matches
+            .get_one::<String>("folder")
+            .or(config.folder.as_ref())
+            .map(|folder| expand_path(folder))
+        else {
+            return exit::fail("No sync folder: set sync.folder or pass --folder".to_string());
+        };
+        let Some(machine) = config.machine_name() else {
+            return exit::fail(
+                "Can't tell this machine's name: set sync.machine in the config".to_string(),
+            );
+        };
+
+        let report = match MachineSyncService::production().sync(&folder, &machine) {
+            Ok(report) => report,
+            Err(e) => return exit::fail(format!("Sync failed: {}", e)),
+        };
+        if report.machines.is_empty() {
+            println!(
+                "No other machine in {} yet, run `wtf db sync` there too",
+                folder.display()
+            );
+        } else {
+            println!("Synced with {}", report.machines.join(", ").bold());
+        }
+        println!("   {:<20}{}", "sent", report.sent);
+        println!("   {:<20}{}", "received", report.received);
+        if report.pending_links > 0 {
+            println!(
+                "   {:<20}{} (meetings not fetched here yet)",
+                "pending links", report.pending_links
+            );
+        }
+        if !report.conflicts.is_empty() {
+            println!("⚠️  Changed on both sides:");
+            for conflict in &report.conflicts {
+                println!("   {}", conflict);
+            }
+        }
+        println!("✅ {} is up to date", machine);
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Share the local worklogs and meeting links with your other machines")
+            .long_about(
+                "Share the local worklogs, their push history and the meeting links with your \
+                 other machines, through a folder they all see (Dropbox, Syncthing, a network \
+                 share...) set as sync.folder.\n\n\
+                 Each machine writes its own file there and merges the others'. When a record \
+                 changed on several machines, the latest change wins, except that a pushed \
+                 worklog always wins over an unpushed version of it.",
+            )
+            .arg(
+                Arg::new("folder")
+                    .long("folder")
+                    .value_name("DIR")
+                    .help("Sync through DIR instead of sync.folder"),
+            )
+    }
+}
+
+fn print_counts(counts: Vec<(&str, usize)>) {
+    for (name, count) in counts {
+        println!("   {:<20}{}", name, count);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::commands::Command;
+use crate::exit;
+use async_trait::async_trait;
+use clap::{ArgMatches, Command as ClapCommand};
+use wtf_lib::config::{Config, StorageBackend};
+use wtf_lib::storage::backup::BackupStore;
+use wtf_lib::storage::database::open_storage;
+use wtf_lib::storage::encryption::{decrypt_all, encrypt_all, DatabaseKey, KEY_ENV};
+
+/// `wtf db encrypt`: encrypt the existing records and keep new ones encrypted
+pub struct DbEncryptCommand;
+
+#[async_trait]
+impl Command for DbEncryptCommand {
+    fn name(&self) -> &'static str {
+        "encrypt"
+    }
+
+    async fn execute(&self, _matches: &ArgMatches) {
+        let mut config = match Config::load() {
+            Ok(config) => config,
+            Err(e) => return exit::fail(format!("Failed to load the config: {}", e)),
+        };
+        if config.storage.backend == StorageBackend::Postgres {
+            return exit::fail("Encryption isn't available with the shared postgres backend");
+        }
+        let key = match DatabaseKey::load_or_create() {
+            Ok(key) => key,
+            Err(e) => {
+                return exit::fail(format!(
+                    "{} (on machines without a keyring, set {} to a base64 key of 32 bytes)",
+                    e, KEY_ENV
+                ))
+            }
+        };
+
+        // Turn encryption on first: the encrypted database still reads the records that
+        // aren't encrypted yet, so an interrupted run loses nothing and can be resumed
+        config.storage.encrypt = true;
+        if let Err(e) = config.save() {
+            return exit::fail(format!("Failed to save the config: {}", e));
+        }
+        let count = match open_storage(&config.storage)
+            .and_then(|storage| encrypt_all(storage.as_ref(), &key))
+        {
+            Ok(count) => count,
+            Err(e) => return exit::fail(format!("Encryption failed: {}", e)),
+        };
+
+        let kept_in = if std::env::var_os(KEY_ENV).is_some() {
+            KEY_ENV
+        } else {
+            "the OS keyring"
+        };
+        println!(
+            "✅ Encrypted {} record(s); the key is in {}",
+            count, kept_in
+        );
+        println!(
+            "   Backups taken before now are still in plaintext, in {}",
+            BackupStore::production().backup_dir().display()
+        );
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Encrypt the local database with a key kept in the OS keyring")
+            .long_about(
+                "Encrypt the records of the local database with XChaCha20-Poly1305 and turn \
+                 on `storage.encrypt` in the config, so that new records are encrypted too. \
+                 The key is created in the OS keyring; on machines without one, set \
+                 WTF_DATABASE_KEY to a base64-encoded 32-byte key. Running it again \
+                 finishes an interrupted encryption.",
+            )
+    }
+}
+
+/// `wtf db decrypt`: store the records in plaintext again
+pub struct DbDecryptCommand;
+
+#[async_trait]
+impl Command for DbDecryptCommand {
+    fn name(&self) -> &'static str {
+        "decrypt"
+    }
+
+    async fn execute(&self, _matches: &ArgMatches) {
+        let mut config = match Config::load() {
+            Ok(config) => config,
+            Err(e) => return exit::fail(format!("Failed to load the config: {}", e)),
+        };
+        let key = match DatabaseKey::load() {
+            Ok(Some(key)) => key,
+            Ok(None) => return exit::fail("No database key in the OS keyring"),
+            Err(e) => return exit::fail(e),
+        };
+
+        // Decrypt before turning encryption off, for the same reason as `encrypt`
+        let count = match open_storage(&config.storage)
+            .and_then(|storage| decrypt_all(storage.as_ref(), &key))
+        {
+            Ok(count) => count,
+            Err(e) => return exit::fail(format!("Decryption failed: {}", e)),
+        };
+        config.storage.encrypt = false;
+        if let Err(e) = config.save() {
+            return exit::fail(format!("Failed to save the config: {}", e));
+        }
+
+        println!("✅ Decrypted {} record(s)", count);
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Store the local database in plaintext again")
+            .long_about(
+                "Decrypt the records of the local database and turn off `storage.encrypt`. \
+                 The key stays in the OS keyring, to read backups taken while the database \
+                 was encrypted.",
+            )
+    }
+}
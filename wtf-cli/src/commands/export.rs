@@ -0,0 +1,253 @@
+use crate::commands::Command;
+use crate::tui::worklog_filter::{status_label, WorklogSourceKind};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use clap::{Arg, ArgMatches, Command as ClapCommand};
+use std::collections::HashSet;
+use std::fs;
+use wtf_lib::config::Config;
+use wtf_lib::services::github_service::GitHubService;
+use wtf_lib::services::meetings_service::MeetingsService;
+use wtf_lib::services::worklogs_service::{LocalWorklogService, WorklogsService};
+use wtf_lib::utils::csv;
+use wtf_lib::utils::meetings::is_untracked;
+
+const CSV_HEADER: [&str; 6] = ["date", "issue", "hours", "comment", "source", "state"];
+
+pub struct ExportCommand;
+
+#[async_trait]
+impl Command for ExportCommand {
+    fn name(&self) -> &'static str {
+        "export"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        match matches.subcommand() {
+            Some(("worklogs", sub_matches)) => ExportWorklogsCommand.execute(sub_matches).await,
+            Some(("meetings", sub_matches)) => ExportMeetingsCommand.execute(sub_matches).await,
+            _ => eprintln!("Invalid subcommand. Use 'wtf export --help' for usage."),
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Export stored worklogs or meetings as CSV")
+            .subcommand(ExportWorklogsCommand.clap_command())
+            .subcommand(ExportMeetingsCommand.clap_command())
+    }
+}
+
+struct ExportWorklogsCommand;
+
+#[async_trait]
+impl Command for ExportWorklogsCommand {
+    fn name(&self) -> &'static str {
+        "worklogs"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let Some(range) = DateRange::from_matches(matches) else {
+            return;
+        };
+        let sessions = GitHubService::production()
+            .get_all_sessions()
+            .unwrap_or_default();
+        let local_worklogs = LocalWorklogService::production().get_all_local_worklogs();
+        let pushed_ids: HashSet<_> = local_worklogs
+            .iter()
+            .filter_map(|wl| wl.worklog_id.clone())
+            .collect();
+
+        let mut rows: Vec<(DateTime<Utc>, [String; 6])> = local_worklogs
+            .iter()
+            .filter(|wl| range.contains(wl.started))
+            .map(|wl| {
+                (
+                    wl.started,
+                    [
+                        wl.started.date_naive().to_string(),
+                        wl.issue_id.clone(),
+                        hours(wl.time_spent_seconds),
+                        clean_comment(&wl.comment),
+                        WorklogSourceKind::of(wl, &sessions).label().to_string(),
+                        status_label(Some(&wl.status)).to_string(),
+                    ],
+                )
+            })
+            .collect();
+        // Worklogs that only exist in Jira (logged by hand or from another tool)
+        rows.extend(
+            WorklogsService::production()
+                .get_all_worklogs()
+                .iter()
+                .filter(|wl| range.contains(wl.started) && !pushed_ids.contains(&wl.id))
+                .map(|wl| {
+                    (
+                        wl.started,
+                        [
+                            wl.started.date_naive().to_string(),
+                            wl.issue_id.clone(),
+                            hours(wl.time_spent_seconds as i64),
+                            wl.comment.clone().unwrap_or_default(),
+                            "Jira".to_string(),
+                            "Jira only".to_string(),
+                        ],
+                    )
+                }),
+        );
+        rows.sort_by_key(|(started, _)| *started);
+
+        write_csv(matches, rows.into_iter().map(|(_, row)| row), "worklog");
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        with_export_args(
+            ClapCommand::new(self.name()).about("Export local and Jira worklogs as CSV"),
+        )
+    }
+}
+
+struct ExportMeetingsCommand;
+
+#[async_trait]
+impl Command for ExportMeetingsCommand {
+    fn name(&self) -> &'static str {
+        "meetings"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let Some(range) = DateRange::from_matches(matches) else {
+            return;
+        };
+        let meetings_service = MeetingsService::production();
+        let config = Config::load().unwrap_or_default();
+        let untracked_ids = meetings_service.get_all_untracked_ids();
+
+        let mut meetings: Vec<_> = meetings_service
+            .get_all_meetings()
+            .into_iter()
+            .filter(|m| range.contains(m.start))
+            .collect();
+        meetings.sort_by_key(|m| m.start);
+
+        let rows = meetings.iter().map(|meeting| {
+            let state = if is_untracked(meeting, &config, &untracked_ids) {
+                "Untracked"
+            } else if !meeting.logs.is_empty() {
+                "Logged"
+            } else if meeting.jira_link.is_some() {
+                "Linked"
+            } else {
+                "Unlinked"
+            };
+            [
+                meeting.start.date_naive().to_string(),
+                meeting.jira_link.clone().unwrap_or_default(),
+                hours((meeting.end - meeting.start).num_seconds()),
+                meeting.title.clone().unwrap_or_default(),
+                "Google Calendar".to_string(),
+                state.to_string(),
+            ]
+        });
+
+        write_csv(matches, rows, "meeting");
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        with_export_args(ClapCommand::new(self.name()).about("Export calendar meetings as CSV"))
+    }
+}
+
+fn with_export_args(command: ClapCommand) -> ClapCommand {
+    command
+        .arg(
+            Arg::new("from")
+                .long("from")
+                .value_name("YYYY-MM-DD")
+                .help("First day to export (inclusive)"),
+        )
+        .arg(
+            Arg::new("to")
+                .long("to")
+                .value_name("YYYY-MM-DD")
+                .help("Last day to export (inclusive)"),
+        )
+        .arg(
+            Arg::new("csv")
+                .long("csv")
+                .value_name("FILE")
+                .help("Write the CSV to this file instead of stdout"),
+        )
+}
+
+/// Optional `--from`/`--to` bounds, both inclusive
+pub(crate) struct DateRange {
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+}
+
+impl DateRange {
+    /// Parse the bounds, printing an error and returning `None` on a bad date
+    pub(crate) fn from_matches(matches: &ArgMatches) -> Option<Self> {
+        let parse = |name: &str| -> Result<Option<NaiveDate>, ()> {
+            match matches.get_one::<String>(name) {
+                None => Ok(None),
+                Some(value) => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                    .map(Some)
+                    .map_err(|_| {
+                        eprintln!(
+                            "❌ Invalid --{} date '{}'. Expected YYYY-MM-DD",
+                            name, value
+                        )
+                    }),
+            }
+        };
+        Some(Self {
+            from: parse("from").ok()?,
+            to: parse("to").ok()?,
+        })
+    }
+
+    pub(crate) fn contains(&self, moment: DateTime<Utc>) -> bool {
+        let date = moment.date_naive();
+        self.from.is_none_or(|from| date >= from) && self.to.is_none_or(|to| date <= to)
+    }
+}
+
+fn hours(seconds: i64) -> String {
+    format!("{:.2}", seconds as f64 / 3600.0)
+}
+
+/// Drop the `wtf[<id>]-` marker added to every local worklog comment
+fn clean_comment(comment: &str) -> String {
+    let message = comment
+        .strip_prefix("wtf[")
+        .and_then(|rest| rest.split_once("]-"))
+        .map(|(_, message)| message)
+        .unwrap_or(comment);
+    if message == "no_msg" {
+        String::new()
+    } else {
+        message.to_string()
+    }
+}
+
+fn write_csv<I: IntoIterator<Item = [String; 6]>>(matches: &ArgMatches, rows: I, item: &str) {
+    let mut content = csv::to_line(&CSV_HEADER);
+    content.push('\n');
+    let mut count = 0;
+    for row in rows {
+        content.push_str(&csv::to_line(&row));
+        content.push('\n');
+        count += 1;
+    }
+
+    match matches.get_one::<String>("csv") {
+        None => print!("{}", content),
+        Some(path) => match fs::write(path, content) {
+            Ok(()) => println!("✅ Exported {} {}(s) to {}", count, item, path),
+            Err(e) => eprintln!("❌ Failed to write {}: {}", path, e),
+        },
+    }
+}
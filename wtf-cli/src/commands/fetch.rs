@@ -1,15 +1,25 @@
 use crate::commands::Command;
+use crate::exit::{self, ExitStatus};
+use crate::logger;
+use crate::progress;
+use crate::tasks::github_tasks::FetchGithubEventsTask;
 use crate::tasks::google_tasks::FetchGoogleCalendarTask;
 use crate::tasks::jira_tasks::{
     FetchJiraBoard, FetchJiraIssues, FetchJiraSprint, FetchJiraWorklogs,
 };
-use crate::tasks::Task;
+use crate::tasks::{ensure_online, Task};
 use async_trait::async_trait;
-use chrono::{TimeZone, Utc};
-use clap::{Arg, ArgMatches, Command as ClapCommand};
-use indicatif::MultiProgress;
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Utc};
+use clap::builder::PossibleValue;
+use clap::{Arg, ArgAction, ArgMatches, Command as ClapCommand};
+use indicatif::{MultiProgress, ProgressDrawTarget};
 use log::{debug, info};
+use serde_json::json;
+use wtf_lib::config::Config;
+use wtf_lib::context::Context;
 use wtf_lib::services::jira_service::JiraService;
+use wtf_lib::services::sync_status_service::SyncStatusService;
+use wtf_lib::utils::hooks::{self, HookEvent};
 
 pub struct FetchCommand;
 
@@ -20,6 +30,7 @@ enum FetchType {
     Sprint,
     Worklog,
     GoogleMeetings,
+    GitHub,
 }
 
 impl std::str::FromStr for FetchType {
@@ -30,8 +41,9 @@ impl std::str::FromStr for FetchType {
             "board" => Ok(FetchType::Board),
             "issue" => Ok(FetchType::Issue),
             "sprint" => Ok(FetchType::Sprint),
-            "worklog" => Ok(FetchType::Worklog),
-            "google" => Ok(FetchType::GoogleMeetings),
+            "worklog" | "worklogs" => Ok(FetchType::Worklog),
+            "google" | "meetings" => Ok(FetchType::GoogleMeetings),
+            "github" => Ok(FetchType::GitHub),
             _ => Err(format!("Unknown fetch type: {}", s)),
         }
     }
@@ -44,20 +56,54 @@ impl Command for FetchCommand {
     }
 
     async fn execute(&self, matches: &ArgMatches) {
-        if let Some(fetch_type) = matches.get_one::<String>("type") {
-            match fetch_type.parse() {
-                Ok(FetchType::All) => fetch_all().await,
-                Ok(FetchType::Board) => fetch_boards(None).await,
-                Ok(FetchType::Sprint) => fetch_sprints(None).await,
-                Ok(FetchType::Issue) => fetch_issues(None).await,
-                Ok(FetchType::Worklog) => fetch_worklogs(None).await,
-                Ok(FetchType::GoogleMeetings) => {
-                    if let Err(e) = fetch_google_meetings(None).await {
-                        eprintln!("Error: {}", e);
-                    }
-                }
-                Err(err) => eprintln!("Error: {}", err),
+        if let Err(e) = ensure_online("fetching") {
+            return exit::fail(e);
+        }
+        let range = match parse_range(matches) {
+            Ok(range) => range,
+            Err(err) => {
+                exit::fail(err);
+                return;
+            }
+        };
+        if matches.get_flag("full") {
+            SyncStatusService::production().clear_watermarks();
+        }
+        let prompt = !matches.get_flag("no-prompt");
+        let Some(fetch_type) = matches.get_one::<String>("type") else {
+            return;
+        };
+        if !matches!(fetch_type.as_str(), "google" | "meetings" | "github") {
+            let status = SyncStatusService::production()
+                .check_jira_credentials(&Context::production())
+                .await;
+            if let Some(message) = status.message() {
+                return exit::fail(message);
+            }
+        }
+        let progress = Some(progress_bars());
+        let result = match fetch_type.parse() {
+            Ok(FetchType::All) => {
+                fetch_all(range, prompt).await;
+                Ok(())
             }
+            Ok(FetchType::Board) => fetch_boards(progress, prompt).await,
+            Ok(FetchType::Sprint) => fetch_sprints(progress).await,
+            Ok(FetchType::Issue) => fetch_issues(progress).await,
+            Ok(FetchType::Worklog) => fetch_worklogs(progress, range).await,
+            Ok(FetchType::GoogleMeetings) => fetch_google(progress, range, prompt).await,
+            Ok(FetchType::GitHub) => fetch_github(range).await,
+            Err(err) => Err(err),
+        };
+        let error = result.as_ref().err().map(|e| e.to_string());
+        let hooks = Config::load().unwrap_or_default().hooks;
+        hooks::notify(
+            &hooks,
+            HookEvent::FetchComplete,
+            &json!({ "type": fetch_type, "error": error }),
+        );
+        if let Err(e) = result {
+            exit::fail(e);
         }
     }
 
@@ -67,46 +113,169 @@ impl Command for FetchCommand {
             .arg(
                 Arg::new("type")
                     .required(true)
-                    .value_parser(["all", "board", "issue", "sprint", "worklog", "google"])
+                    .value_parser([
+                        PossibleValue::new("all"),
+                        PossibleValue::new("board"),
+                        PossibleValue::new("issue"),
+                        PossibleValue::new("sprint"),
+                        PossibleValue::new("worklog").alias("worklogs"),
+                        PossibleValue::new("google").alias("meetings"),
+                        PossibleValue::new("github"),
+                    ])
                     .help("The type of items to fetch"),
             )
+            .arg(
+                Arg::new("from")
+                    .long("from")
+                    .value_name("YYYY-MM-DD")
+                    .help("Fetch worklogs, meetings and GitHub events from this day instead of the followed sprints' dates"),
+            )
+            .arg(
+                Arg::new("to")
+                    .long("to")
+                    .value_name("YYYY-MM-DD")
+                    .requires("from")
+                    .help("Last day to fetch with --from (default: today)"),
+            )
+            .arg(
+                Arg::new("full")
+                    .long("full")
+                    .action(ArgAction::SetTrue)
+                    .help("Download everything again instead of only what changed since the last fetch"),
+            )
+            .arg(
+                Arg::new("no-prompt")
+                    .long("no-prompt")
+                    .action(ArgAction::SetTrue)
+                    .help("Never ask which board to follow or which out-of-office events are absences (for scripts and `wtf daemon`)"),
+            )
     }
 }
 
-async fn fetch_boards(multi_progress: Option<MultiProgress>) {
-    FetchJiraBoard::new()
-        .with_progress(multi_progress.unwrap_or_default())
-        .execute()
-        .await
-        .unwrap();
+/// Progress bars, hidden with `--quiet`
+fn progress_bars() -> MultiProgress {
+    let mp = MultiProgress::new();
+    if logger::is_quiet() {
+        mp.set_draw_target(ProgressDrawTarget::hidden());
+    }
+    mp
 }
 
-async fn fetch_sprints(multi_progress: Option<MultiProgress>) {
-    let _ = FetchJiraSprint::new()
+async fn fetch_boards(multi_progress: Option<MultiProgress>, prompt: bool) -> Result<(), String> {
+    let task = FetchJiraBoard::new().with_progress(multi_progress.unwrap_or_default());
+    let task = if prompt {
+        task
+    } else {
+        task.without_follow_prompt()
+    };
+    task.execute().await.map_err(|e| e.to_string())
+}
+
+async fn fetch_sprints(multi_progress: Option<MultiProgress>) -> Result<(), String> {
+    FetchJiraSprint::new()
         .with_progress(multi_progress.unwrap_or_default())
         .execute()
-        .await;
+        .await
+        .map_err(|e| format!("Failed to fetch sprints: {}", e))
 }
 
-async fn fetch_issues(multi_progress: Option<MultiProgress>) {
+async fn fetch_issues(multi_progress: Option<MultiProgress>) -> Result<(), String> {
     let sprints = JiraService::production().get_followed_sprint();
     FetchJiraIssues::new(sprints)
         .with_progress(multi_progress.unwrap_or_default())
         .execute()
         .await
-        .unwrap();
+        .map_err(|e| e.to_string())
 }
 
-async fn fetch_worklogs(multi_progress: Option<MultiProgress>) {
-    let sprints = JiraService::production().get_followed_sprint();
-    FetchJiraWorklogs::new(sprints)
-        .with_progress(multi_progress.unwrap_or_default())
+/// First and last instant of a `--from`/`--to` fetch
+type FetchRange = (DateTime<Utc>, DateTime<Utc>);
+
+/// `--from`/`--to` as full UTC days, or `None` to follow the sprints
+fn parse_range(matches: &ArgMatches) -> Result<Option<FetchRange>, String> {
+    let parse = |value: &String| {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map_err(|_| format!("Invalid date '{}'. Expected YYYY-MM-DD", value))
+    };
+    let Some(from) = matches.get_one::<String>("from").map(parse).transpose()? else {
+        return Ok(None);
+    };
+    let to = match matches.get_one::<String>("to") {
+        Some(to) => parse(to)?,
+        None => Local::now().date_naive(),
+    };
+    if to < from {
+        return Err(format!("--to ({}) is before --from ({})", to, from));
+    }
+    Ok(Some((
+        Utc.from_utc_datetime(&from.and_hms_opt(0, 0, 0).unwrap()),
+        Utc.from_utc_datetime(&to.and_hms_opt(23, 59, 59).unwrap()),
+    )))
+}
+
+async fn fetch_worklogs(
+    multi_progress: Option<MultiProgress>,
+    range: Option<FetchRange>,
+) -> Result<(), String> {
+    let task = match range {
+        Some((start, end)) => FetchJiraWorklogs::between(start, end),
+        None => FetchJiraWorklogs::new(JiraService::production().get_followed_sprint()),
+    };
+    task.with_progress(multi_progress.unwrap_or_default())
+        .execute()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+async fn fetch_github(range: Option<FetchRange>) -> Result<(), String> {
+    let task = match range {
+        Some((start, end)) => FetchGithubEventsTask::between(start, end),
+        None => FetchGithubEventsTask::new(),
+    };
+    task.execute()
+        .await
+        .map_err(|e| format!("Failed to fetch GitHub events: {}", e))
+}
+
+/// Google Calendar over the range, or the followed sprints; nothing to do without either
+async fn fetch_google(
+    multi_progress: Option<MultiProgress>,
+    range: Option<FetchRange>,
+    prompt: bool,
+) -> Result<(), String> {
+    match range {
+        Some((start, end)) => fetch_google_meetings_between(start, end, prompt).await,
+        None if JiraService::production().get_followed_sprint().is_empty() => {
+            logger::log("No followed sprint, no meetings to fetch".to_string());
+            exit::report(ExitStatus::NothingToDo);
+            Ok(())
+        }
+        None => fetch_google_meetings(multi_progress, prompt).await,
+    }
+}
+
+async fn fetch_google_meetings_between(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    prompt: bool,
+) -> Result<(), String> {
+    logger::log(format!(
+        "Fetching Google Calendar events from {} to {}...",
+        start.format("%Y-%m-%d"),
+        end.format("%Y-%m-%d")
+    ));
+    FetchGoogleCalendarTask::new(start, end)
+        .with_time_off_confirmation(prompt)
         .execute()
         .await
-        .unwrap();
+        .map_err(|e| format!("Failed to fetch Google Calendar events: {}", e))
 }
 
-pub async fn fetch_google_meetings(multi_progress: Option<MultiProgress>) -> Result<(), String> {
+/// With `prompt`, new out-of-office events are offered as absences
+pub async fn fetch_google_meetings(
+    multi_progress: Option<MultiProgress>,
+    prompt: bool,
+) -> Result<(), String> {
     let sprints = JiraService::production().get_followed_sprint();
 
     // Get min start date and max end date
@@ -117,6 +286,7 @@ pub async fn fetch_google_meetings(multi_progress: Option<MultiProgress>) -> Res
         // Expand to full UTC days so meetings at the start/end of sprint day are not missed
         let min = Utc.from_utc_datetime(&min.date_naive().and_hms_opt(0, 0, 0).unwrap());
         let max = Utc.from_utc_datetime(&max.date_naive().and_hms_opt(23, 59, 59).unwrap());
+        progress::event("google", 0, 1, None);
         if let Some(mp) = &multi_progress {
             mp.println(format!(
                 "Fetching Google Calendar events from {} to {}...",
@@ -125,8 +295,13 @@ pub async fn fetch_google_meetings(multi_progress: Option<MultiProgress>) -> Res
             ))
             .ok();
         }
-        match FetchGoogleCalendarTask::new(min, max).execute().await {
+        match FetchGoogleCalendarTask::new(min, max)
+            .with_time_off_confirmation(prompt)
+            .execute()
+            .await
+        {
             Ok(_) => {
+                progress::event("google", 1, 1, None);
                 if let Some(mp) = &multi_progress {
                     mp.println("Google Calendar fetch completed").ok();
                 }
@@ -151,15 +326,75 @@ pub async fn fetch_google_meetings(multi_progress: Option<MultiProgress>) -> Res
     }
 }
 
-async fn fetch_all() {
+async fn fetch_all(range: Option<FetchRange>, prompt: bool) {
     info!("starting fetch all");
-    let m = MultiProgress::new();
+    let m = progress_bars();
 
-    fetch_boards(Some(m.clone())).await;
-    fetch_sprints(Some(m.clone())).await;
-    fetch_issues(Some(m.clone())).await;
-    fetch_worklogs(Some(m.clone())).await;
-    let _ = fetch_google_meetings(Some(m.clone())).await;
+    let results = [
+        fetch_boards(Some(m.clone()), prompt).await,
+        fetch_sprints(Some(m.clone())).await,
+        fetch_issues(Some(m.clone())).await,
+        fetch_worklogs(Some(m.clone()), range).await,
+        fetch_google(Some(m.clone()), range, prompt).await,
+    ];
+    let steps = results.len();
+    let errors: Vec<String> = results.into_iter().filter_map(Result::err).collect();
+    // Steps are independent: one failing still leaves the others' data fresh
+    for error in &errors {
+        eprintln!("❌ {}", error);
+        let status = ExitStatus::of_error(error);
+        exit::report(
+            if status == ExitStatus::AuthFailure || errors.len() == steps {
+                status
+            } else {
+                ExitStatus::PartialFailure
+            },
+        );
+    }
 
     debug!("fetch all finished.")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(args: &[&str]) -> Result<Option<FetchRange>, String> {
+        let matches = FetchCommand
+            .clap_command()
+            .try_get_matches_from([&["fetch", "worklog"], args].concat())
+            .map_err(|e| e.to_string())?;
+        parse_range(&matches)
+    }
+
+    #[test]
+    fn test_range_covers_full_days() {
+        let (start, end) = range(&["--from", "2026-01-10", "--to", "2026-02-03"])
+            .unwrap()
+            .unwrap();
+        assert_eq!(start, Utc.with_ymd_and_hms(2026, 1, 10, 0, 0, 0).unwrap());
+        assert_eq!(end, Utc.with_ymd_and_hms(2026, 2, 3, 23, 59, 59).unwrap());
+        assert_eq!(range(&[]).unwrap(), None);
+    }
+
+    #[test]
+    fn test_range_rejects_reversed_or_invalid_dates() {
+        let reversed = range(&["--from", "2026-02-03", "--to", "2026-01-10"]);
+        assert!(reversed.unwrap_err().contains("before --from"));
+        assert!(range(&["--from", "2026-13-01"])
+            .unwrap_err()
+            .contains("Invalid date '2026-13-01'"));
+        assert!(range(&["--from", "2026-01-01", "--to", "tomorrow"]).is_err());
+    }
+
+    #[test]
+    fn test_to_needs_from() {
+        assert!(range(&["--to", "2026-01-10"]).is_err());
+    }
+
+    #[test]
+    fn test_from_alone_runs_until_today() {
+        let (_, end) = range(&["--from", "2020-01-01"]).unwrap().unwrap();
+        assert_eq!(end.date_naive(), Local::now().date_naive());
+    }
+}
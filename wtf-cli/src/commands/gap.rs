@@ -0,0 +1,208 @@
+use crate::commands::completions::{issue_keys, sprint_ids};
+use crate::commands::{period_arg, period_from_matches, print_json, Command, OutputFormat};
+use async_trait::async_trait;
+use clap::{Arg, ArgAction, ArgGroup, ArgMatches, Command as ClapCommand};
+use colored::Colorize;
+use std::collections::HashMap;
+use wtf_lib::config::Config;
+use wtf_lib::services::jira_service::IssueService;
+use wtf_lib::services::meetings_service::MeetingsService;
+use wtf_lib::services::worklogs_service::{LocalWorklogService, WorklogsService};
+use wtf_lib::utils::gap_fill::{
+    plan_gap_fill, GapFillEntry, GapFillStrategy, GAP_FILL_COMMENT, GAP_FILL_MIN_THRESHOLD,
+};
+
+pub struct GapCommand;
+
+#[async_trait]
+impl Command for GapCommand {
+    fn name(&self) -> &'static str {
+        "gap"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        match matches.subcommand() {
+            Some(("fill", sub_m)) => GapFillCommand.execute(sub_m).await,
+            _ => eprintln!("❌ Unknown gap subcommand"),
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Fill the under-logged days of a sprint")
+            .subcommand_required(true)
+            .subcommand(GapFillCommand.clap_command())
+    }
+}
+
+struct GapFillCommand;
+
+#[async_trait]
+impl Command for GapFillCommand {
+    fn name(&self) -> &'static str {
+        "fill"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let config = Config::load().unwrap_or_default();
+        let issues: Vec<String> = matches
+            .get_many::<String>("issue")
+            .unwrap()
+            .map(|issue| config.expand_issue_alias(issue))
+            .collect();
+        let strategy: GapFillStrategy = matches
+            .get_one::<String>("strategy")
+            .unwrap()
+            .parse()
+            .unwrap_or_default();
+        let threshold = matches
+            .get_one::<f64>("threshold")
+            .copied()
+            .unwrap_or(GAP_FILL_MIN_THRESHOLD);
+        let dry_run = matches.get_flag("dry-run");
+
+        let period = match period_from_matches(matches) {
+            Ok(period) => period,
+            Err(e) => {
+                eprintln!("❌ {}", e);
+                return;
+            }
+        };
+        let issue_service = IssueService::production();
+        if let Some(unknown) = issues
+            .iter()
+            .find(|i| issue_service.get_by_key(i).is_none())
+        {
+            eprintln!("❌ Issue '{}' not found (run 'wtf fetch' first)", unknown);
+            return;
+        }
+
+        let (start, end) = (period.start, period.end);
+        let jira_worklogs = WorklogsService::production().get_all_worklogs();
+        let meetings_service = MeetingsService::production();
+        let worklog_service = LocalWorklogService::production();
+        let gaps = worklog_service.find_gap_days(
+            start,
+            end,
+            &|date| config.worklog.target_hours(date),
+            threshold,
+            &|date| meetings_service.is_absent(date),
+            &jira_worklogs,
+        );
+
+        let mut logged_hours: HashMap<String, f64> = HashMap::new();
+        for worklog in jira_worklogs.iter().filter(|wl| {
+            let date = wl.started.date_naive();
+            date >= start && date <= end
+        }) {
+            *logged_hours.entry(worklog.issue_id.clone()).or_default() +=
+                worklog.time_spent_seconds as f64 / 3600.0;
+        }
+        let epics: HashMap<String, String> = issue_service
+            .get_all_issues()
+            .into_iter()
+            .filter_map(|issue| Some((issue.key, issue.epic?)))
+            .collect();
+        let plan = plan_gap_fill(&gaps, &issues, strategy, &logged_hours, &epics);
+
+        if OutputFormat::from_matches(matches).is_json() {
+            print_json(&plan);
+        } else {
+            print_plan(&plan);
+        }
+        if dry_run || plan.is_empty() {
+            return;
+        }
+
+        for entry in &plan {
+            worklog_service.create_new_local_worklogs(
+                config.worklog.day_start(entry.date),
+                (entry.hours * 3600.0) as i64,
+                &entry.issue,
+                Some(GAP_FILL_COMMENT),
+                None,
+            );
+        }
+        println!(
+            "✅ Created {} worklog(s) in {}, run `wtf log push --include-created` to send them",
+            plan.len(),
+            period.name
+        );
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Top up the under-logged workdays of a sprint or period to the daily hours")
+            .long_about(
+                "Top up the under-logged workdays of a sprint, or of a week or month with \
+                 --period, to the configured daily hours, \
+                 the same way the TUI and the wizard do. Weekends, absences and days already \
+                 logged above the threshold are skipped. The new worklogs are created locally; \
+                 push them with `wtf log push --include-created`.",
+            )
+            .arg(
+                Arg::new("sprint")
+                    .long("sprint")
+                    .short('s')
+                    .value_name("ID")
+                    .add(sprint_ids())
+                    .help("Sprint whose days to fill"),
+            )
+            .arg(period_arg())
+            .group(
+                ArgGroup::new("days")
+                    .args(["sprint", "period"])
+                    .required(true),
+            )
+            .arg(
+                Arg::new("issue")
+                    .long("issue")
+                    .short('i')
+                    .value_name("KEY")
+                    .required(true)
+                    .action(ArgAction::Append)
+                    .add(issue_keys())
+                    .help("Issue to log the missing time on (repeat to split between issues)"),
+            )
+            .arg(
+                Arg::new("strategy")
+                    .long("strategy")
+                    .value_parser(["even", "proportional", "epic"])
+                    .default_value("even")
+                    .help(
+                        "Split between issues evenly, by the hours already logged on them \
+                         or by the hours logged on their epic",
+                    ),
+            )
+            .arg(
+                Arg::new("threshold")
+                    .long("threshold")
+                    .value_name("HOURS")
+                    .value_parser(clap::value_parser!(f64))
+                    .help("Leave alone days already logged above this many hours (default: 6)"),
+            )
+            .arg(
+                Arg::new("dry-run")
+                    .long("dry-run")
+                    .action(ArgAction::SetTrue)
+                    .help("Only print the worklogs that would be created"),
+            )
+    }
+}
+
+fn print_plan(plan: &[GapFillEntry]) {
+    if plan.is_empty() {
+        println!("✓ No gaps to fill - all workdays are substantially logged");
+        return;
+    }
+    for entry in plan {
+        println!(
+            "{}  {:<12} {:>6.2}h",
+            entry.date.format("%a %Y-%m-%d"),
+            entry.issue,
+            entry.hours
+        );
+    }
+    let total: f64 = plan.iter().map(|entry| entry.hours).sum();
+    println!("{}", format!("Total {:.2}h", total).bold());
+}
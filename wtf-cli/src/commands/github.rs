@@ -1,11 +1,12 @@
-use crate::commands::Command;
+use crate::commands::{Command, OutputFormat};
 use crate::logger;
 use crate::tasks::github_tasks::{
-    FetchGithubEventsTask, LogGithubEventsTask, ShowGithubEventsTask, ShowGithubSessionsTask,
+    FetchGithubEventsTask, LogGithubEventsTask, RebuildGithubSessionsTask, ShowGithubEventsTask,
+    ShowGithubSessionsTask,
 };
 use crate::tasks::Task;
 use async_trait::async_trait;
-use clap::{Arg, ArgMatches, Command as ClapCommand};
+use clap::{Arg, ArgAction, ArgMatches, Command as ClapCommand};
 
 pub struct GitHubCommand;
 
@@ -23,6 +24,9 @@ impl Command for GitHubCommand {
             Some(("log", sub_matches)) => LogGithubEventsCommand.execute(sub_matches).await,
             Some(("sessions", sub_matches)) => ShowGithubSessionsCommand.execute(sub_matches).await,
             Some(("events", sub_matches)) => ShowGithubEventsCommand.execute(sub_matches).await,
+            Some(("rebuild-sessions", sub_matches)) => {
+                RebuildGithubSessionsCommand.execute(sub_matches).await
+            }
             _ => eprintln!("Invalid subcommand. Use 'wtf github --help' for usage."),
         }
     }
@@ -35,6 +39,7 @@ impl Command for GitHubCommand {
             .subcommand(LogGithubEventsCommand.clap_command())
             .subcommand(ShowGithubSessionsCommand.clap_command())
             .subcommand(ShowGithubEventsCommand.clap_command())
+            .subcommand(RebuildGithubSessionsCommand.clap_command())
     }
 }
 
@@ -46,12 +51,23 @@ impl Command for FetchGithubEventsCommand {
         "fetch"
     }
 
-    async fn execute(&self, _matches: &ArgMatches) {
-        FetchGithubEventsTask::new().execute().await.unwrap();
+    async fn execute(&self, matches: &ArgMatches) {
+        FetchGithubEventsTask::new()
+            .full(matches.get_flag("full"))
+            .execute()
+            .await
+            .unwrap();
     }
 
     fn clap_command(&self) -> ClapCommand {
-        ClapCommand::new(self.name()).about("Fetch and display GitHub events for followed sprints")
+        ClapCommand::new(self.name())
+            .about("Fetch and display GitHub events for followed sprints")
+            .arg(
+                Arg::new("full")
+                    .long("full")
+                    .action(ArgAction::SetTrue)
+                    .help("Read again the commits earlier fetches already stored"),
+            )
     }
 }
 
@@ -85,7 +101,11 @@ impl Command for ShowGithubSessionsCommand {
 
     async fn execute(&self, matches: &ArgMatches) {
         let date = matches.get_one::<String>("date").map(|s| s.as_str());
-        ShowGithubSessionsTask::new(date).execute().await.unwrap();
+        ShowGithubSessionsTask::new(date)
+            .with_format(OutputFormat::from_matches(matches))
+            .execute()
+            .await
+            .unwrap();
     }
 
     fn clap_command(&self) -> ClapCommand {
@@ -126,3 +146,35 @@ impl Command for ShowGithubEventsCommand {
             )
     }
 }
+
+struct RebuildGithubSessionsCommand;
+
+#[async_trait]
+impl Command for RebuildGithubSessionsCommand {
+    fn name(&self) -> &'static str {
+        "rebuild-sessions"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        RebuildGithubSessionsTask::new()
+            .discard_edits(matches.get_flag("discard-edits"))
+            .execute()
+            .await
+            .unwrap();
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Recompute stored GitHub sessions with the current session settings")
+            .long_about("Rebuild every stored GitHub session from the stored events, using the \
+                        [github] session_gap_minutes, min_session_minutes and session_padding_minutes \
+                        settings. Sessions split, trimmed or merged by hand are kept, unless \
+                        --discard-edits.")
+            .arg(
+                Arg::new("discard-edits")
+                    .long("discard-edits")
+                    .action(ArgAction::SetTrue)
+                    .help("Rebuild the sessions split, trimmed or merged by hand too"),
+            )
+    }
+}
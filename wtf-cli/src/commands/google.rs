@@ -34,7 +34,7 @@ impl Command for FetchGoogleCommand {
     }
 
     async fn execute(&self, _matches: &ArgMatches) {
-        let _ = fetch_google_meetings(None).await;
+        let _ = fetch_google_meetings(None, true).await;
     }
 
     fn clap_command(&self) -> ClapCommand {
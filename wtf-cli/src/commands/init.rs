@@ -3,15 +3,19 @@ use crate::{error, info, success, warn};
 use async_trait::async_trait;
 use clap::{ArgMatches, Command as ClapCommand};
 use inquire::{Confirm, CustomUserError, Password, Select, Text};
+use std::collections::HashMap;
 use std::error::Error;
 use std::fs;
-use std::path::PathBuf;
 use std::str::FromStr;
 use wtf_lib::config::{
-    Config, GithubConfig, GoogleConfig, JiraConfig, SensitiveString, WorklogConfig,
+    active_profile, config_home, config_path, BackupConfig, Config, ConfirmationsConfig,
+    DaemonConfig, GithubConfig, GoogleAuthFlow, GoogleConfig, HooksConfig, JiraConfig,
+    LinkingConfig, MeetingRulesConfig, MeetingsConfig, NetworkConfig, SensitiveString,
+    StorageConfig, SyncConfig, TeamConfig, UiConfig, ValidationConfig, WorklogConfig,
 };
 use wtf_lib::models::data::{Board, Sprint};
 use wtf_lib::services::jira_service::JiraService;
+use wtf_lib::storage::backup::BackupStore;
 
 pub struct InitCommand;
 
@@ -37,66 +41,13 @@ impl Command for InitCommand {
 // Path utilities
 // ============================================================================
 
-fn get_config_path() -> PathBuf {
-    if let Ok(custom_path) = std::env::var("WTF_CONFIG_HOME") {
-        PathBuf::from(custom_path).join("config.toml")
-    } else {
-        let home = std::env::var("HOME").expect("HOME environment variable not set");
-        PathBuf::from(home).join(".config/wtf/config.toml")
-    }
-}
-
-fn get_db_path() -> PathBuf {
-    if let Ok(custom_path) = std::env::var("WTF_CONFIG_HOME") {
-        PathBuf::from(custom_path).join(".wtf_db")
-    } else {
-        let home = std::env::var("HOME").expect("HOME environment variable not set");
-        PathBuf::from(home).join(".config/wtf/.wtf_db")
-    }
-}
-
-fn get_backup_dir() -> PathBuf {
-    let home = std::env::var("HOME").expect("HOME environment variable not set");
-    PathBuf::from(home).join(".config/wtf/backups")
-}
-
 // ============================================================================
 // Backup utilities
 // ============================================================================
 
 fn backup_existing_config() -> Result<(), Box<dyn Error>> {
-    let config_path = get_config_path();
-    let db_path = get_db_path();
-    let backup_dir = get_backup_dir();
-
-    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
-    let backup_path = backup_dir.join(timestamp.to_string());
-
-    fs::create_dir_all(&backup_path)?;
-
-    if config_path.exists() {
-        fs::copy(&config_path, backup_path.join("config.toml"))?;
-    }
-
-    if db_path.exists() {
-        copy_dir_all(&db_path, &backup_path.join(".wtf_db"))?;
-    }
-
-    println!("Backup created: {}", backup_path.display());
-    Ok(())
-}
-
-fn copy_dir_all(src: &PathBuf, dst: &PathBuf) -> Result<(), Box<dyn Error>> {
-    fs::create_dir_all(dst)?;
-    for entry in fs::read_dir(src)? {
-        let entry = entry?;
-        let ty = entry.file_type()?;
-        if ty.is_dir() {
-            copy_dir_all(&entry.path(), &dst.join(entry.file_name()))?;
-        } else {
-            fs::copy(entry.path(), dst.join(entry.file_name()))?;
-        }
-    }
+    let backup = BackupStore::production().create(None)?;
+    println!("Backup created: {}", backup.path.display());
     Ok(())
 }
 
@@ -152,7 +103,7 @@ fn sprint_autocomplete(sprints: Vec<Sprint>) -> AutocompleteFn {
 fn step1_check_existing_config() -> Result<bool, Box<dyn Error>> {
     info!("[Step 1/6] Checking existing configuration");
 
-    let config_path = get_config_path();
+    let config_path = config_path();
 
     if !config_path.exists() {
         return Ok(true); // Continue with setup
@@ -196,7 +147,7 @@ fn step1_check_existing_config() -> Result<bool, Box<dyn Error>> {
 fn step2_setup_directory() -> Result<(), Box<dyn Error>> {
     info!("[Step 2/6] Setting up directory");
 
-    let config_path = get_config_path();
+    let config_path = config_path();
     let config_dir = config_path.parent().unwrap();
 
     fs::create_dir_all(config_dir)?;
@@ -223,15 +174,36 @@ fn step3_configure_jira() -> Result<JiraConfig, Box<dyn Error>> {
         username: jira_email.clone(),
         api_token: SensitiveString::from_str(&jira_token).unwrap(),
         auto_follow_sprint_pattern: None,
+        follow_active_sprint: false,
+        unfollow_closed_after_days: None,
         project_keys: Vec::new(),
+        issue_jql: None,
+        epic_field: None,
+        transition_on_first_worklog: None,
+        assigned_only: false,
+        active_statuses: Vec::new(),
     };
 
     // Save temp config so subsequent API calls can pick up credentials
     let temp_config = Config {
         jira: jira_config.clone(),
-        github: GithubConfig { organisation: None },
+        github: GithubConfig::default(),
         google: None,
         worklog: WorklogConfig::default(),
+        ui: UiConfig::default(),
+        confirmations: ConfirmationsConfig::default(),
+        daemon: DaemonConfig::default(),
+        backup: BackupConfig::default(),
+        network: NetworkConfig::default(),
+        storage: StorageConfig::load(),
+        hooks: HooksConfig::default(),
+        linking: LinkingConfig::default(),
+        validation: ValidationConfig::default(),
+        sync: SyncConfig::default(),
+        meetings: MeetingsConfig::default(),
+        meeting_rules: MeetingRulesConfig::default(),
+        team: TeamConfig::default(),
+        aliases: HashMap::new(),
     };
     temp_config.save()?;
 
@@ -335,9 +307,7 @@ async fn step4_select_boards() -> Result<(), Box<dyn Error>> {
     // Follow selected boards
     info!("🔄 Following boards...");
     for board_id in &selected_boards {
-        JiraService::production()
-            .follow_board(board_id)
-            .map_err(|e| -> Box<dyn Error> { e })?;
+        JiraService::production().follow_board(board_id)?;
     }
 
     success!("Following {} board(s)", selected_boards.len());
@@ -444,9 +414,7 @@ fn step5_select_sprints() -> Result<(), Box<dyn Error>> {
     } else {
         info!("🔄 Following sprints...");
         for sprint_id in &selected_sprints {
-            JiraService::production()
-                .follow_sprint(sprint_id)
-                .map_err(|e| -> Box<dyn Error> { e })?;
+            JiraService::production().follow_sprint(sprint_id)?;
         }
         success!("Following {} sprint(s)", selected_sprints.len());
     }
@@ -463,7 +431,7 @@ fn step6_configure_github() -> Result<GithubConfig, Box<dyn Error>> {
 
     if !enable_github {
         warn!("Skipping GitHub integration");
-        return Ok(GithubConfig { organisation: None });
+        return Ok(GithubConfig::default());
     }
 
     info!("🔄 Checking GitHub CLI...");
@@ -500,7 +468,10 @@ fn step6_configure_github() -> Result<GithubConfig, Box<dyn Error>> {
         success!("Will track events across all repos");
     }
 
-    Ok(GithubConfig { organisation: org })
+    Ok(GithubConfig {
+        organisation: org,
+        ..GithubConfig::default()
+    })
 }
 
 fn step7_configure_google() -> Result<Option<GoogleConfig>, Box<dyn Error>> {
@@ -526,21 +497,17 @@ fn step7_configure_google() -> Result<Option<GoogleConfig>, Box<dyn Error>> {
         return Ok(None);
     }
 
-    // Suggest appropriate default paths based on environment
-    let default_creds_path = if using_test_env {
-        format!(
-            "{}/google_credentials.json",
-            std::env::var("WTF_CONFIG_HOME").unwrap()
-        )
+    // Suggest appropriate default paths based on environment; each profile gets its own
+    // token so it can use a different Google account
+    let custom_home = using_test_env || active_profile().is_some();
+    let default_creds_path = if custom_home {
+        format!("{}/google_credentials.json", config_home().display())
     } else {
         "~/.config/wtf/google_credentials.json".to_string()
     };
 
-    let default_token_path = if using_test_env {
-        format!(
-            "{}/google_token.json",
-            std::env::var("WTF_CONFIG_HOME").unwrap()
-        )
+    let default_token_path = if custom_home {
+        format!("{}/google_token.json", config_home().display())
     } else {
         "~/.config/wtf/google_token.json".to_string()
     };
@@ -586,6 +553,11 @@ fn step7_configure_google() -> Result<Option<GoogleConfig>, Box<dyn Error>> {
             credentials_path,
             token_cache_path,
             color_labels: std::collections::HashMap::new(),
+            calendar_ids: Vec::new(),
+            time_off_keywords: Vec::new(),
+            service_account_key_path: None,
+            impersonate: None,
+            auth_flow: GoogleAuthFlow::default(),
         }))
     } else {
         warn!("Remember to add credentials file before fetching meetings");
@@ -593,24 +565,33 @@ fn step7_configure_google() -> Result<Option<GoogleConfig>, Box<dyn Error>> {
             credentials_path,
             token_cache_path,
             color_labels: std::collections::HashMap::new(),
+            calendar_ids: Vec::new(),
+            time_off_keywords: Vec::new(),
+            service_account_key_path: None,
+            impersonate: None,
+            auth_flow: GoogleAuthFlow::default(),
         }))
     }
 }
 
 // Step 8: Complete Google OAuth flow
-async fn step8_complete_google_oauth(_google_config: &GoogleConfig) -> Result<(), Box<dyn Error>> {
+async fn step8_complete_google_oauth(google_config: &GoogleConfig) -> Result<(), Box<dyn Error>> {
     use crate::tasks::google_tasks::FetchGoogleCalendarTask;
     use crate::tasks::Task;
     use chrono::Utc;
 
-    println!("\nYour browser will open in 3 seconds for Google Calendar authorization...");
-    for i in (1..=3).rev() {
-        print!("  {}...\r", i);
-        use std::io::Write;
-        let _ = std::io::stdout().flush();
-        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+    if google_config.auth_flow.uses_device_code() {
+        println!("\nNo browser here: enter the code printed below on any other device.");
+    } else {
+        println!("\nYour browser will open in 3 seconds for Google Calendar authorization...");
+        for i in (1..=3).rev() {
+            print!("  {}...\r", i);
+            use std::io::Write;
+            let _ = std::io::stdout().flush();
+            tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+        }
+        println!();
     }
-    println!();
 
     info!("🔄 Fetching Google Calendar meetings to complete OAuth setup...");
 
@@ -674,6 +655,20 @@ pub async fn run_init_wizard() -> Result<(), Box<dyn Error>> {
         github: github_config,
         google: google_config.clone(),
         worklog: WorklogConfig::default(),
+        ui: UiConfig::default(),
+        confirmations: ConfirmationsConfig::default(),
+        daemon: DaemonConfig::default(),
+        backup: BackupConfig::default(),
+        network: NetworkConfig::default(),
+        storage: StorageConfig::load(),
+        hooks: HooksConfig::default(),
+        linking: LinkingConfig::default(),
+        validation: ValidationConfig::default(),
+        sync: SyncConfig::default(),
+        meetings: MeetingsConfig::default(),
+        meeting_rules: MeetingRulesConfig::default(),
+        team: TeamConfig::default(),
+        aliases: HashMap::new(),
     };
 
     final_config.save()?;
@@ -690,7 +685,14 @@ pub async fn run_init_wizard() -> Result<(), Box<dyn Error>> {
     info!("🎉 All done! Your configuration has been saved.");
 
     // Show different next steps based on whether using custom config home
-    if std::env::var("WTF_CONFIG_HOME").is_ok() {
+    if let Some(profile) = active_profile() {
+        info!("📋 Next steps for profile '{}':", profile);
+        info!("  1. Fetch Jira data:");
+        info!("     wtf --profile {} fetch all", profile);
+        info!("  2. Launch the TUI:");
+        info!("     wtf --profile {}", profile);
+        info!("  Or export WTF_PROFILE={} to make it the default", profile);
+    } else if std::env::var("WTF_CONFIG_HOME").is_ok() {
         let config_home = std::env::var("WTF_CONFIG_HOME").unwrap();
         warn!("Note: You used WTF_CONFIG_HOME={}", config_home);
         info!("   This is a separate test environment with an empty database.");
@@ -1,11 +1,19 @@
-use crate::commands::Command;
+use crate::commands::completions::issue_keys;
+use crate::commands::{print_json, Command, OutputFormat};
+use crate::exit;
 use crate::tasks::jira_tasks::FetchJiraIssues;
-use crate::tasks::Task;
+use crate::tasks::{ensure_online, Task};
 use async_trait::async_trait;
 use chrono::Utc;
-use clap::{Arg, ArgMatches, Command as ClapCommand};
+use clap::{Arg, ArgAction, ArgMatches, Command as ClapCommand};
 use log::{debug, error, info};
-use wtf_lib::duration::parse_duration;
+use tabled::settings::object::Columns;
+use tabled::settings::{Color, Modify, Style};
+use tabled::{Table, Tabled};
+use wtf_lib::client::jira_client::JiraClient;
+use wtf_lib::config::Config;
+use wtf_lib::duration::parse_work_duration;
+use wtf_lib::models::data::Issue;
 use wtf_lib::services::jira_service::{IssueService, JiraService};
 
 pub struct IssueCommand;
@@ -21,6 +29,7 @@ impl Command for IssueCommand {
             Some(("fetch", sub_matches)) => IssueFetchCommand.execute(sub_matches).await,
             Some(("list", sub_matches)) => IssueListCommand.execute(sub_matches).await,
             Some(("log-time", sub_matches)) => IssueLogTimeCommand.execute(sub_matches).await,
+            Some(("search", sub_matches)) => IssueSearchCommand.execute(sub_matches).await,
             _ => eprintln!("Invalid subcommand for 'issue'"),
         }
     }
@@ -31,12 +40,14 @@ impl Command for IssueCommand {
             .arg(
                 Arg::new("issue-key")
                     .help("Issue Key")
+                    .add(issue_keys())
                     .required(false)
                     .index(1),
             )
             .subcommand(IssueFetchCommand.clap_command())
             .subcommand(IssueListCommand.clap_command())
             .subcommand(IssueLogTimeCommand.clap_command())
+            .subcommand(IssueSearchCommand.clap_command())
     }
 }
 
@@ -75,11 +86,13 @@ impl Command for IssueListCommand {
         "list"
     }
 
-    async fn execute(&self, _matches: &ArgMatches) {
-        IssueService::production()
-            .get_all_issues()
-            .into_iter()
-            .for_each(|b| println!("{:?}", b));
+    async fn execute(&self, matches: &ArgMatches) {
+        let issues = IssueService::production().get_all_issues();
+        if OutputFormat::from_matches(matches).is_json() {
+            print_json(&issues);
+            return;
+        }
+        issues.into_iter().for_each(|b| println!("{:?}", b));
     }
 
     fn clap_command(&self) -> ClapCommand {
@@ -97,17 +110,18 @@ impl Command for IssueLogTimeCommand {
 
     async fn execute(&self, matches: &ArgMatches) {
         let time = matches.get_one::<String>("time").unwrap();
-        let duration = match parse_duration(time) {
+        let config = Config::load().unwrap_or_default();
+        let duration = match parse_work_duration(time, config.worklog.hours_per_day()) {
             Ok(duration) => duration,
             Err(_) => {
                 eprintln!(
-                    "Invalid duration '{}'. Expected format: <number><unit> (e.g. 1h, 30m, 2d)",
+                    "Invalid duration '{}'. Expected e.g. 1h, 30m, 1h30, 1.5, 1:30 or \"1d 2h 30m\"",
                     time
                 );
                 return;
             }
         };
-        let issue_key = matches.get_one::<String>("issue-key").unwrap();
+        let issue_key = &config.expand_issue_alias(matches.get_one::<String>("issue-key").unwrap());
 
         match IssueService::production().get_by_key(issue_key) {
             Some(issue) => {
@@ -116,7 +130,7 @@ impl Command for IssueLogTimeCommand {
                     .await
                 {
                     Ok(_) => debug!("Logged '{}' for issue '{}'", duration, issue_key),
-                    Err(e) => error!("Error: {:?}", e),
+                    Err(e) => error!("Error: {}", e.with_hint()),
                 }
             }
             None => error!("No issue found."),
@@ -129,6 +143,7 @@ impl Command for IssueLogTimeCommand {
             .arg(
                 Arg::new("issue-key")
                     .help("Issue Key")
+                    .add(issue_keys())
                     .required(true)
                     .index(1),
             )
@@ -140,3 +155,102 @@ impl Command for IssueLogTimeCommand {
             )
     }
 }
+
+struct IssueSearchCommand;
+
+#[derive(Tabled)]
+struct IssueRow {
+    key: String,
+    status: String,
+    summary: String,
+}
+
+#[async_trait]
+impl Command for IssueSearchCommand {
+    fn name(&self) -> &'static str {
+        "search"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let jql = matches.get_one::<String>("jql").unwrap();
+        let limit = *matches.get_one::<usize>("limit").unwrap();
+        if let Err(e) = ensure_online("searching Jira") {
+            return exit::fail(e);
+        }
+        let (total, issues): (usize, Vec<Issue>) =
+            match JiraClient::create().search_issues(jql).await {
+                Ok(fetcher) => (
+                    fetcher.len(),
+                    fetcher
+                        .take(limit)
+                        .map(|issue| Issue::from_jira(issue, Vec::new()))
+                        .collect(),
+                ),
+                Err(e) => return exit::fail(format!("Search failed: {}", e)),
+            };
+        if matches.get_flag("save") && !issues.is_empty() {
+            IssueService::production().save_all_issues(issues.clone());
+        }
+
+        if OutputFormat::from_matches(matches).is_json() {
+            print_json(&issues);
+            return;
+        }
+        if issues.is_empty() {
+            println!("No issue matches this JQL.");
+            return;
+        }
+        let count = issues.len();
+        let rows = issues.into_iter().map(|issue| IssueRow {
+            key: issue.key,
+            status: issue.status,
+            summary: issue.summary,
+        });
+        let mut table = Table::new(rows);
+        table.with(Style::modern().remove_horizontal());
+        table.with(Modify::new(Columns::first()).with(Color::BOLD | Color::FG_WHITE));
+        println!("{}", table);
+        if total > count {
+            println!(
+                "{} of about {} issues shown, use --limit to see more",
+                count, total
+            );
+        }
+        if matches.get_flag("save") {
+            println!("{} issue(s) saved to the local cache", count);
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Search Jira issues with JQL")
+            .long_about(
+                "Search Jira issues with any JQL, e.g. \
+                 `wtf issue search \"assignee = currentUser() AND updated >= -14d\"`. \
+                 With --save, the results are added to the local cache so worklogs can \
+                 be logged on them. To restrict what `wtf fetch issue` caches for boards \
+                 and projects, set `jira.issue_jql` instead.",
+            )
+            .arg(
+                Arg::new("jql")
+                    .value_name("JQL")
+                    .help("JQL query")
+                    .required(true)
+                    .index(1),
+            )
+            .arg(
+                Arg::new("limit")
+                    .long("limit")
+                    .value_name("N")
+                    .value_parser(clap::value_parser!(usize))
+                    .default_value("50")
+                    .help("Maximum number of issues"),
+            )
+            .arg(
+                Arg::new("save")
+                    .long("save")
+                    .action(ArgAction::SetTrue)
+                    .help("Add the results to the local issue cache"),
+            )
+    }
+}
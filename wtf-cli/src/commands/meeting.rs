@@ -1,7 +1,10 @@
-use crate::commands::Command;
+use crate::commands::completions::sprint_ids;
+use crate::commands::export::DateRange;
+use crate::commands::{print_json, Command, OutputFormat};
 use crate::tasks::worklog_tasks::MeetingWorklogTask;
-use crate::tasks::Task;
+use crate::tasks::{link_script, Task};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use clap::{Arg, ArgAction, ArgMatches, Command as ClapCommand};
 use colored::Colorize;
 use futures::stream::FuturesUnordered;
@@ -10,15 +13,18 @@ use inquire::{Confirm, CustomUserError, Select, Text};
 use itertools::Itertools;
 use log::debug;
 use regex::Regex;
+use serde::Serialize;
 use std::collections::HashMap;
 use std::fmt::Display;
 use tabled::settings::object::Columns;
 use tabled::settings::{Alignment, Color, Modify, Style};
 use tabled::{Table, Tabled};
 use wtf_lib::common::Common;
+use wtf_lib::config::Config;
 use wtf_lib::models::data::{Issue, Meeting, Sprint};
 use wtf_lib::services::jira_service::{IssueService, JiraService, SprintService};
 use wtf_lib::services::meetings_service::MeetingsService;
+use wtf_lib::utils::meetings::autolink_key;
 
 pub struct MeetingCommand;
 
@@ -34,6 +40,7 @@ impl Command for MeetingCommand {
             Some(("link", sub_matches)) => LinkGoogleMeetingsCommand.execute(sub_matches).await,
             Some(("log", sub_matches)) => LogMeetingCommand.execute(sub_matches).await,
             Some(("clear", sub_matches)) => ClearMeetingsCommand.execute(sub_matches).await,
+            Some(("autolink", sub_matches)) => AutolinkMeetingsCommand.execute(sub_matches).await,
             _ => eprintln!("Invalid subcommand"),
         }
     }
@@ -45,6 +52,7 @@ impl Command for MeetingCommand {
             .subcommand(LinkGoogleMeetingsCommand.clap_command())
             .subcommand(LogMeetingCommand.clap_command())
             .subcommand(ClearMeetingsCommand.clap_command())
+            .subcommand(AutolinkMeetingsCommand.clap_command())
     }
 }
 
@@ -62,7 +70,11 @@ impl Command for LogMeetingCommand {
 
         let sprints = get_sprints_from_id_args(matches);
 
-        match MeetingWorklogTask::new(sprints).execute().await {
+        match MeetingWorklogTask::new(sprints)
+            .interactive()
+            .execute()
+            .await
+        {
             Ok(()) => {}
             Err(e) => eprintln!("{}", e),
         }
@@ -87,7 +99,11 @@ impl Command for ListMeetingCommand {
         debug!("execute meeting list");
         let mut meetings = get_meetings_from_args(matches);
 
-        meetings.sort_by(|a, b| a.start.cmp(&b.start));
+        meetings.sort_by_key(|a| a.start);
+        if OutputFormat::from_matches(matches).is_json() {
+            print_json(&meetings);
+            return;
+        }
         let meetings_data = meetings
             .iter()
             .map(MeetingInfo::from_meeting)
@@ -158,11 +174,11 @@ impl Command for LinkGoogleMeetingsCommand {
         // Build a map of recurring meeting titles to their jira links
         let mut recurring_meeting_links: HashMap<String, String> = HashMap::new();
         for meeting in &meetings {
-            if meeting.recurrence.is_some() && meeting.jira_link.is_some() {
-                if let Some(ref title) = meeting.title {
-                    recurring_meeting_links
-                        .insert(title.clone(), meeting.jira_link.as_ref().unwrap().clone());
-                }
+            if meeting.recurrence.is_none() {
+                continue;
+            }
+            if let (Some(title), Some(link)) = (&meeting.title, &meeting.jira_link) {
+                recurring_meeting_links.insert(title.clone(), link.clone());
             }
         }
 
@@ -195,6 +211,19 @@ impl Command for LinkGoogleMeetingsCommand {
                 continue;
             }
 
+            if let Some(series_link) = meeting
+                .recurring_event_id
+                .as_deref()
+                .and_then(|series| MeetingsService::production().series_link(series))
+            {
+                debug!(
+                    "meeting '{:?}' linked with its series: {}",
+                    meeting.title, series_link
+                );
+                link_issue(meeting, &series_link);
+                continue;
+            }
+
             let meeting_id = meeting.id.clone();
             if let Some(cached_link) = link_cache.get(&meeting_id) {
                 debug!(
@@ -424,6 +453,114 @@ impl Command for ClearMeetingsCommand {
     }
 }
 
+pub struct AutolinkMeetingsCommand;
+
+/// A meeting linked (or to be linked) by `wtf meeting autolink`
+#[derive(Serialize)]
+struct AutolinkEntry {
+    meeting_id: String,
+    start: DateTime<Utc>,
+    title: String,
+    issue: String,
+}
+
+#[async_trait]
+impl Command for AutolinkMeetingsCommand {
+    fn name(&self) -> &'static str {
+        "autolink"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let Some(range) = DateRange::from_matches(matches) else {
+            return;
+        };
+        let config = match Config::load() {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("❌ Failed to load config: {}", e);
+                return;
+            }
+        };
+        let dry_run = matches.get_flag("dry-run");
+        let meetings_service = MeetingsService::production();
+        let issue_service = IssueService::production();
+        let untracked_ids = meetings_service.get_all_untracked_ids();
+        let script = link_script(&config);
+
+        let mut linked = Vec::new();
+        for mut meeting in meetings_service
+            .get_all_meetings()
+            .into_iter()
+            .filter(|m| range.contains(m.start))
+            .sorted_by_key(|m| m.start)
+        {
+            let Some(key) =
+                autolink_key(&meeting, &config, &untracked_ids, script.as_ref(), |key| {
+                    issue_service.get_by_key(key).is_some()
+                })
+            else {
+                continue;
+            };
+            if !dry_run {
+                meeting.jira_link = Some(key.clone());
+                meetings_service.save(&meeting);
+            }
+            linked.push(AutolinkEntry {
+                meeting_id: meeting.id,
+                start: meeting.start,
+                title: meeting.title.unwrap_or_default(),
+                issue: key,
+            });
+        }
+
+        if OutputFormat::from_matches(matches).is_json() {
+            print_json(&linked);
+            return;
+        }
+        for entry in &linked {
+            println!(
+                "🔗 {}  {} → {}",
+                Common::format_date_time(&entry.start),
+                entry.title,
+                entry.issue.bold()
+            );
+        }
+        match (linked.len(), dry_run) {
+            (0, _) => println!("No meeting to auto-link"),
+            (n, true) => println!("{} meeting(s) would be linked (dry run)", n),
+            (n, false) => println!("✅ Auto-linked {} meeting(s)", n),
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Link meetings to the issue key found in their title or description")
+            .long_about(
+                "Link meetings to the issue key found in their title or description, like the \
+                 wizard's auto-link step. Only issues known locally are linked; linked, declined \
+                 and untracked meetings are left alone. Safe to run from a scheduled job.",
+            )
+            .arg(
+                Arg::new("from")
+                    .long("from")
+                    .value_name("YYYY-MM-DD")
+                    .help("First day of the meetings to link (inclusive)"),
+            )
+            .arg(
+                Arg::new("to")
+                    .long("to")
+                    .value_name("YYYY-MM-DD")
+                    .help("Last day of the meetings to link (inclusive)"),
+            )
+            .arg(
+                Arg::new("dry-run")
+                    .long("dry-run")
+                    .action(ArgAction::SetTrue)
+                    .help("Only print the links that would be made"),
+            )
+    }
+}
+
 fn create_all_arg() -> Arg {
     Arg::new("all")
         .short('a')
@@ -437,6 +574,7 @@ fn create_sprint_ids_arg() -> Arg {
         .short('s')
         .long("sprint-id")
         .help("The sprint id")
+        .add(sprint_ids())
         .value_parser(clap::value_parser!(String))
         .num_args(1..)
 }
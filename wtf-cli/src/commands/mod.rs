@@ -1,28 +1,141 @@
+pub mod audit;
+pub mod backup;
 pub mod board;
+pub mod completions;
 pub mod config;
+pub mod config_secret;
+pub mod daemon;
+pub mod db;
+pub mod db_encrypt;
+pub mod export;
 pub mod fetch;
+pub mod gap;
 pub mod github;
 pub mod google;
 pub mod init;
 pub mod issue;
 pub mod meeting;
+pub mod report;
 pub mod sprint;
+pub mod sprint_report;
+pub mod sprint_team;
+pub mod status;
+pub mod today;
 pub mod tui;
 pub mod update;
+pub mod wizard;
 pub mod worklog;
+pub mod worklog_import;
 
 use async_trait::async_trait;
 use clap::{ArgMatches, Command as ClapCommand};
+use serde::Serialize;
 use std::collections::HashMap;
+use std::sync::OnceLock;
+use wtf_lib::services::jira_service::SprintService;
+use wtf_lib::utils::period::Period;
+use wtf_lib::utils::template::Template;
+
+/// Every top-level command of the CLI
+pub fn default_registry() -> CommandRegistry {
+    let mut registry = CommandRegistry::new();
+    registry.register(tui::TuiCommand);
+    registry.register(init::InitCommand);
+    registry.register(fetch::FetchCommand);
+    registry.register(sprint::SprintCommand);
+    registry.register(board::BoardCommand);
+    registry.register(issue::IssueCommand);
+    registry.register(github::GitHubCommand);
+    registry.register(google::GoogleCommand);
+    registry.register(config::ConfigCommand);
+    registry.register(meeting::MeetingCommand);
+    registry.register(worklog::LogCommand);
+    registry.register(update::UpdateCommand);
+    registry.register(wizard::WizardCommand);
+    registry.register(export::ExportCommand);
+    registry.register(report::ReportCommand);
+    registry.register(status::StatusCommand);
+    registry.register(today::TodayCommand);
+    registry.register(today::WeekCommand);
+    registry.register(gap::GapCommand);
+    registry.register(daemon::DaemonCommand);
+    registry.register(db::DbCommand);
+    registry.register(backup::BackupCommand);
+    registry.register(audit::AuditCommand);
+    registry.register(completions::CompletionsCommand);
+    registry
+}
 
 pub fn build_app(registry: &CommandRegistry) -> ClapCommand {
-    let mut app = ClapCommand::new("wtf").arg(
-        clap::Arg::new("debug")
-            .long("debug")
-            .help("Enable debug logging")
-            .action(clap::ArgAction::SetTrue)
-            .global(true),
-    );
+    let mut app = ClapCommand::new("wtf")
+        .arg(
+            clap::Arg::new("debug")
+                .long("debug")
+                .help("Enable debug logging")
+                .action(clap::ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            clap::Arg::new("quiet")
+                .long("quiet")
+                .short('q')
+                .help("Only print warnings and errors (for cron); see the exit codes in the README")
+                .action(clap::ArgAction::SetTrue)
+                .global(true),
+        )
+        .arg(
+            clap::Arg::new("progress")
+                .long("progress")
+                .value_name("STYLE")
+                .value_parser(["bar", "json"])
+                .default_value("bar")
+                .help("Progress of fetch and push: bars, or json events on stderr for wrappers")
+                .global(true),
+        )
+        .arg(
+            clap::Arg::new("trace-http")
+                .long("trace-http")
+                .action(clap::ArgAction::SetTrue)
+                .help("Log every Jira, Google and GitHub call (method, URL, status, duration) with secrets redacted")
+                .global(true),
+        )
+        .arg(
+            clap::Arg::new("trace-http-bodies")
+                .long("trace-http-bodies")
+                .action(clap::ArgAction::SetTrue)
+                .help("Like --trace-http, with the request and response bodies")
+                .global(true),
+        )
+        .arg(
+            clap::Arg::new("offline")
+                .long("offline")
+                .action(clap::ArgAction::SetTrue)
+                .help("Work on the local data only: no fetch or push, worklogs stay staged; also WTF_OFFLINE")
+                .global(true),
+        )
+        .arg(
+            clap::Arg::new("profile")
+                .long("profile")
+                .value_name("NAME")
+                .help("Use a named profile (own config and database); also WTF_PROFILE")
+                .global(true),
+        )
+        .arg(
+            clap::Arg::new("template")
+                .long("template")
+                .value_name("TEMPLATE")
+                .help("Shape the output of list and report commands with a Handlebars-like template, or @FILE")
+                .global(true),
+        )
+        .arg(
+            clap::Arg::new("format")
+                .long("format")
+                .value_name("FORMAT")
+                .value_parser(["table", "json", "csv", "md"])
+                .default_value("table")
+                .help("Output format: json for list commands, json/csv/md for reports")
+                .global(true),
+        );
 
     for subcommand in registry.commands.values() {
         app = app.subcommand(subcommand.clap_command());
@@ -31,6 +144,112 @@ pub fn build_app(registry: &CommandRegistry) -> ClapCommand {
     app
 }
 
+/// Global output template (`--template`), applied by [`print_json`]
+static TEMPLATE: OnceLock<Template> = OnceLock::new();
+
+/// Print the JSON output of commands through `source` instead: the template itself, with
+/// `\n` and `\t` escapes, or `@FILE` to read it from a file
+pub fn set_template(source: &str) -> Result<(), String> {
+    let source = match source.strip_prefix('@') {
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read template {}: {}", path, e))?,
+        None => source.replace("\\n", "\n").replace("\\t", "\t"),
+    };
+    let template = Template::parse(&source).map_err(|e| format!("Invalid template: {}", e))?;
+    let _ = TEMPLATE.set(template);
+    Ok(())
+}
+
+/// How list commands and reports print their results, chosen with the global `--format`
+/// option. List commands only tell JSON apart from tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Table,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl OutputFormat {
+    pub fn from_matches(matches: &ArgMatches) -> Self {
+        // Templates render the JSON output
+        if TEMPLATE.get().is_some() {
+            return OutputFormat::Json;
+        }
+        match matches.try_get_one::<String>("format") {
+            Ok(Some(format)) => match format.as_str() {
+                "json" => OutputFormat::Json,
+                "csv" => OutputFormat::Csv,
+                "md" => OutputFormat::Markdown,
+                _ => OutputFormat::Table,
+            },
+            _ => OutputFormat::Table,
+        }
+    }
+
+    pub fn is_json(self) -> bool {
+        self == OutputFormat::Json
+    }
+}
+
+/// `--period`, the alternative to `--sprint` for teams without sprints
+pub fn period_arg() -> clap::Arg {
+    clap::Arg::new("period")
+        .long("period")
+        .short('p')
+        .value_name("PERIOD")
+        .value_parser(["week", "last-week", "month", "last-month"])
+        .help("Calendar period to work on instead of a sprint")
+}
+
+/// The days chosen with `--sprint` or `--period`, which are exclusive and one of them required
+pub fn period_from_matches(matches: &ArgMatches) -> Result<Period, String> {
+    if let Some(spec) = matches.get_one::<String>("period") {
+        return Period::parse(spec, chrono::Local::now().date_naive());
+    }
+    let sprint_id = matches
+        .get_one::<String>("sprint")
+        .ok_or("Either --sprint or --period is required")?;
+    let sprint = SprintService::production()
+        .get_sprint(sprint_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| {
+            format!(
+                "Sprint '{}' not found (run 'wtf fetch sprint' first)",
+                sprint_id
+            )
+        })?;
+    Period::of_sprint(&sprint).ok_or_else(|| {
+        format!(
+            "Sprint '{}' has no start or end date (use --period instead)",
+            sprint.name
+        )
+    })
+}
+
+/// Print `value` as pretty JSON on stdout, for scripts and pipelines
+pub fn print_json<T: Serialize + ?Sized>(value: &T) {
+    if let Some(template) = TEMPLATE.get() {
+        match serde_json::to_value(value) {
+            Ok(value) => {
+                let output = template.render_output(&value);
+                if output.is_empty() || output.ends_with('\n') {
+                    print!("{}", output);
+                } else {
+                    println!("{}", output);
+                }
+            }
+            Err(e) => eprintln!("❌ Failed to serialize output: {}", e),
+        }
+        return;
+    }
+    match serde_json::to_string_pretty(value) {
+        Ok(json) => println!("{}", json),
+        Err(e) => eprintln!("❌ Failed to serialize output: {}", e),
+    }
+}
+
 #[async_trait]
 pub trait Command {
     fn name(&self) -> &'static str;
@@ -61,3 +280,30 @@ impl CommandRegistry {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Format as seen by the innermost subcommand of `args`
+    fn format_of(args: &[&str]) -> OutputFormat {
+        let mut matches = build_app(&default_registry()).get_matches_from(args);
+        while let Some((_, sub)) = matches.subcommand() {
+            matches = sub.clone();
+        }
+        OutputFormat::from_matches(&matches)
+    }
+
+    #[test]
+    fn test_global_format_reaches_list_commands() {
+        assert_eq!(format_of(&["wtf", "meeting", "list"]), OutputFormat::Table);
+        assert_eq!(
+            format_of(&["wtf", "meeting", "list", "--format", "json"]),
+            OutputFormat::Json
+        );
+        assert_eq!(
+            format_of(&["wtf", "--format", "json", "sprint", "list"]),
+            OutputFormat::Json
+        );
+    }
+}
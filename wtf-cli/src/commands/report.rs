@@ -0,0 +1,272 @@
+use crate::commands::completions::sprint_ids;
+use crate::commands::{print_json, Command, OutputFormat};
+use async_trait::async_trait;
+use chrono::{Local, NaiveDate};
+use clap::{Arg, ArgAction, ArgGroup, ArgMatches, Command as ClapCommand};
+use colored::Colorize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command as ProcessCommand, Stdio};
+use tabled::builder::Builder;
+use tabled::settings::object::{Columns, Rows};
+use tabled::settings::{Alignment, Color, Modify, Style};
+use wtf_lib::config::{Config, WorklogConfig};
+use wtf_lib::models::data::NO_EPIC;
+use wtf_lib::services::jira_service::{IssueService, SprintService};
+use wtf_lib::services::meetings_service::MeetingsService;
+use wtf_lib::services::worklogs_service::WorklogsService;
+use wtf_lib::utils::period::Period;
+use wtf_lib::utils::timesheet::Timesheet;
+use wtf_lib::utils::timesheet_html::HtmlTimesheet;
+
+pub struct ReportCommand;
+
+#[async_trait]
+impl Command for ReportCommand {
+    fn name(&self) -> &'static str {
+        "report"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let config = Config::load().ok();
+        let worklog = config
+            .as_ref()
+            .map(|c| c.worklog.clone())
+            .unwrap_or_default();
+        let today = Local::now().date_naive();
+
+        let (title, from, to, capacity_hours) =
+            if let Some(sprint_id) = matches.get_one::<String>("sprint") {
+                let sprint = match SprintService::production().get_sprint(sprint_id) {
+                    Ok(Some(sprint)) => sprint,
+                    Ok(None) => {
+                        eprintln!("❌ Sprint '{}' not found", sprint_id);
+                        return;
+                    }
+                    Err(e) => {
+                        eprintln!("❌ {}", e);
+                        return;
+                    }
+                };
+                let (Some(start), Some(end)) = (sprint.start, sprint.end) else {
+                    eprintln!("❌ Sprint '{}' has no start or end date", sprint.name);
+                    return;
+                };
+                (
+                    format!("Sprint {}", sprint.name),
+                    start.date_naive(),
+                    end.date_naive(),
+                    capacity(start.date_naive(), end.date_naive(), &worklog),
+                )
+            } else if matches.get_flag("month") {
+                let Period {
+                    name, start, end, ..
+                } = Period::month_of(today);
+                (name, start, end, capacity(start, end, &worklog))
+            } else {
+                let Period {
+                    name, start, end, ..
+                } = Period::week_of(today);
+                (name, start, end, capacity(start, end, &worklog))
+            };
+
+        let worklogs = WorklogsService::production().get_all_worklogs();
+        let mut timesheet = Timesheet::build(from, to, &worklogs, capacity_hours);
+        if matches.get_flag("by-epic") {
+            let epics: HashMap<String, String> = IssueService::production()
+                .get_all_issues()
+                .into_iter()
+                .filter_map(|issue| Some((issue.key, issue.epic?)))
+                .collect();
+            timesheet = timesheet.group_issues(|issue| {
+                epics
+                    .get(issue)
+                    .cloned()
+                    .unwrap_or_else(|| NO_EPIC.to_string())
+            });
+        }
+
+        let html_path = matches.get_one::<String>("html");
+        let pdf_path = matches.get_one::<String>("pdf");
+        if html_path.is_some() || pdf_path.is_some() {
+            let name = matches
+                .get_one::<String>("name")
+                .cloned()
+                .or_else(|| config.map(|c| c.jira.username))
+                .unwrap_or_default();
+            export_document(&title, &name, &timesheet, html_path, pdf_path);
+            return;
+        }
+
+        match OutputFormat::from_matches(matches) {
+            OutputFormat::Json => print_json(&timesheet),
+            OutputFormat::Csv => print!("{}", timesheet.to_csv()),
+            OutputFormat::Markdown => {
+                println!("## {} ({} → {})\n", title, from, to);
+                print!("{}", timesheet.to_markdown());
+            }
+            OutputFormat::Table => print_table(&title, &timesheet),
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Print a timesheet of the hours logged in Jira (day × issue)")
+            .arg(
+                Arg::new("week")
+                    .long("week")
+                    .action(ArgAction::SetTrue)
+                    .help("Report on the current week (default)"),
+            )
+            .arg(
+                Arg::new("month")
+                    .long("month")
+                    .action(ArgAction::SetTrue)
+                    .help("Report on the current month"),
+            )
+            .arg(
+                Arg::new("sprint")
+                    .long("sprint")
+                    .value_name("ID")
+                    .add(sprint_ids())
+                    .help("Report on a sprint, using its workdays as capacity"),
+            )
+            .arg(
+                Arg::new("by-epic")
+                    .long("by-epic")
+                    .action(ArgAction::SetTrue)
+                    .help("One column per epic instead of per issue"),
+            )
+            .arg(
+                Arg::new("html")
+                    .long("html")
+                    .value_name("FILE")
+                    .help("Write a printable HTML timesheet instead of printing the report"),
+            )
+            .arg(
+                Arg::new("pdf")
+                    .long("pdf")
+                    .value_name("FILE")
+                    .help("Also convert the timesheet to PDF (needs wkhtmltopdf or Chromium)"),
+            )
+            .arg(
+                Arg::new("name")
+                    .long("name")
+                    .value_name("NAME")
+                    .help("Name shown on the HTML/PDF timesheet (default: Jira username)"),
+            )
+            .group(ArgGroup::new("period").args(["week", "month", "sprint"]))
+    }
+}
+
+/// Working hours of the period's days, absences excluded
+fn capacity(from: NaiveDate, to: NaiveDate, worklog: &WorklogConfig) -> f64 {
+    let meetings_service = MeetingsService::production();
+    worklog.capacity(from, to, |day| meetings_service.is_absent(day))
+}
+
+fn print_table(title: &str, timesheet: &Timesheet) {
+    println!("{} ({} → {})", title.bold(), timesheet.from, timesheet.to);
+    let (header, rows) = timesheet.grid();
+    let mut builder = Builder::default();
+    builder.push_record(header);
+    for row in rows {
+        builder.push_record(row);
+    }
+    let mut table = builder.build();
+    table.with(Style::modern().remove_horizontal());
+    table.with(Modify::new(Columns::new(1..)).with(Alignment::right()));
+    table.with(Modify::new(Rows::first()).with(Color::BOLD | Color::FG_WHITE));
+    table.with(Modify::new(Rows::last()).with(Color::BOLD));
+    println!("{table}");
+
+    let summary = timesheet.summary();
+    if timesheet.delta_hours < 0.0 {
+        println!("{}", summary.yellow());
+    } else {
+        println!("{}", summary.green());
+    }
+}
+
+/// Write the HTML timesheet and, when asked, turn it into a PDF
+fn export_document(
+    title: &str,
+    name: &str,
+    timesheet: &Timesheet,
+    html_path: Option<&String>,
+    pdf_path: Option<&String>,
+) {
+    let issue_summaries: HashMap<_, _> = IssueService::production()
+        .get_all_issues()
+        .into_iter()
+        .map(|issue| (issue.key, issue.summary))
+        .collect();
+    let html = HtmlTimesheet {
+        title: &format!("Timesheet – {}", title),
+        name,
+        issue_summaries: &issue_summaries,
+    }
+    .render(timesheet);
+
+    // PDF converters read from a file, so the HTML is always written somewhere
+    let html_file = match html_path {
+        Some(path) => PathBuf::from(path),
+        None => std::env::temp_dir().join("wtf-timesheet.html"),
+    };
+    if let Err(e) = fs::write(&html_file, html) {
+        eprintln!("❌ Failed to write {}: {}", html_file.display(), e);
+        return;
+    }
+    if html_path.is_some() {
+        println!("✅ Timesheet written to {}", html_file.display());
+    }
+
+    if let Some(pdf_path) = pdf_path {
+        match html_to_pdf(&html_file, Path::new(pdf_path)) {
+            Ok(()) => println!("✅ Timesheet written to {}", pdf_path),
+            Err(e) => eprintln!("❌ {}", e),
+        }
+    }
+}
+
+/// Convert with the first tool found on the PATH
+fn html_to_pdf(html: &Path, pdf: &Path) -> Result<(), String> {
+    let chrome_args = |binary: &str| {
+        let mut command = ProcessCommand::new(binary);
+        command
+            .arg("--headless")
+            .arg("--disable-gpu")
+            .arg("--no-pdf-header-footer")
+            .arg(format!("--print-to-pdf={}", pdf.display()))
+            .arg(html);
+        command
+    };
+    let mut wkhtmltopdf = ProcessCommand::new("wkhtmltopdf");
+    wkhtmltopdf.arg("--quiet").arg(html).arg(pdf);
+    let candidates = [
+        wkhtmltopdf,
+        chrome_args("chromium"),
+        chrome_args("chromium-browser"),
+        chrome_args("google-chrome"),
+    ];
+
+    for mut command in candidates {
+        match command.stdout(Stdio::null()).stderr(Stdio::null()).status() {
+            Ok(status) if status.success() => return Ok(()),
+            Ok(status) => {
+                return Err(format!(
+                    "{} failed ({})",
+                    command.get_program().to_string_lossy(),
+                    status
+                ))
+            }
+            // Not installed, try the next one
+            Err(_) => continue,
+        }
+    }
+    Err(format!(
+        "No PDF converter found (install wkhtmltopdf or Chromium), or print {} to PDF from a browser",
+        html.display()
+    ))
+}
@@ -1,4 +1,8 @@
-use crate::commands::Command;
+use crate::commands::completions::sprint_ids;
+use crate::commands::sprint_report::SprintReportCommand;
+use crate::commands::sprint_team::SprintTeamCommand;
+use crate::commands::{Command, OutputFormat};
+use crate::exit;
 use crate::tasks::jira_tasks::{FetchJiraSprint, ListJiraSprints};
 use crate::tasks::Task;
 use async_trait::async_trait;
@@ -24,6 +28,8 @@ impl Command for SprintCommand {
             Some(("add", sub_matches)) => SprintAddCommand.execute(sub_matches).await,
             Some(("rm", sub_matches)) => SprintRemoveCommand.execute(sub_matches).await,
             Some(("status", sub_matches)) => SprintStatusCommand.execute(sub_matches).await,
+            Some(("report", sub_matches)) => SprintReportCommand.execute(sub_matches).await,
+            Some(("team", sub_matches)) => SprintTeamCommand.execute(sub_matches).await,
             Some(("clear-worklogs", sub_matches)) => {
                 SprintClearWorklogsCommand.execute(sub_matches).await
             }
@@ -39,6 +45,8 @@ impl Command for SprintCommand {
             .subcommand(SprintAddCommand.clap_command())
             .subcommand(SprintRemoveCommand.clap_command())
             .subcommand(SprintStatusCommand.clap_command())
+            .subcommand(SprintReportCommand.clap_command())
+            .subcommand(SprintTeamCommand.clap_command())
             .subcommand(SprintClearWorklogsCommand.clap_command())
     }
 }
@@ -53,7 +61,11 @@ impl Command for SprintListCommand {
 
     async fn execute(&self, matches: &ArgMatches) {
         let fetch_all = matches.get_flag("all");
-        ListJiraSprints::new(fetch_all).execute().await.unwrap();
+        ListJiraSprints::new(fetch_all)
+            .with_format(OutputFormat::from_matches(matches))
+            .execute()
+            .await
+            .unwrap();
     }
 
     fn clap_command(&self) -> ClapCommand {
@@ -116,6 +128,7 @@ impl Command for SprintAddCommand {
                     .required(true)
                     .value_parser(clap::value_parser!(String))
                     .num_args(1..)
+                    .add(sprint_ids())
                     .help("The board id"),
             )
     }
@@ -186,6 +199,7 @@ impl Command for SprintRemoveCommand {
                 .required(true)
                 .value_parser(clap::value_parser!(String))
                 .num_args(1..)
+                .add(sprint_ids())
                 .help("Sprint ID(s) to unfollow, or 'all' to unfollow all sprints"),
         )
     }
@@ -254,6 +268,7 @@ impl Command for SprintStatusCommand {
             Arg::new("id")
                 .required(true)
                 .value_parser(clap::value_parser!(String))
+                .add(sprint_ids())
                 .help("The sprint id"),
         )
     }
@@ -309,7 +324,10 @@ impl Command for SprintClearWorklogsCommand {
             .collect();
 
         // Get current user's email from config for filtering
-        let config = wtf_lib::config::Config::load().expect("Failed to load config");
+        let config = match wtf_lib::config::Config::load() {
+            Ok(config) => config,
+            Err(e) => return exit::fail(format!("Failed to load config: {}", e)),
+        };
         let current_user_email = config.jira.username.clone();
 
         // Get all worklogs (Jira + Local) in the sprint date range
@@ -364,7 +382,10 @@ impl Command for SprintClearWorklogsCommand {
             println!();
 
             // Get config to build DELETE URLs for preview
-            let config = wtf_lib::config::Config::load().expect("Failed to load config");
+            let config = match wtf_lib::config::Config::load() {
+                Ok(config) => config,
+                Err(e) => return exit::fail(format!("Failed to load config: {}", e)),
+            };
             let base_url = &config.jira.base_url;
 
             if !sprint_jira_wl.is_empty() {
@@ -501,6 +522,7 @@ impl Command for SprintClearWorklogsCommand {
                 Arg::new("id")
                     .required(true)
                     .value_parser(clap::value_parser!(String))
+                    .add(sprint_ids())
                     .help("The sprint id"),
             )
             .arg(
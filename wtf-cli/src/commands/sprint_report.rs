@@ -0,0 +1,121 @@
+use crate::commands::completions::sprint_ids;
+use crate::commands::{print_json, Command, OutputFormat};
+use crate::exit;
+use crate::tasks::ensure_online;
+use async_trait::async_trait;
+use clap::{Arg, ArgMatches, Command as ClapCommand};
+use colored::Colorize;
+use std::collections::HashMap;
+use tabled::builder::Builder;
+use tabled::settings::object::{Columns, Rows};
+use tabled::settings::{Alignment, Color, Modify, Style};
+use wtf_lib::client::jira_client::JiraClient;
+use wtf_lib::services::jira_service::{IssueService, SprintService};
+use wtf_lib::utils::sprint_report::SprintReport;
+
+/// `wtf sprint report`: hours everyone logged on a sprint's issues, from Jira
+pub struct SprintReportCommand;
+
+#[async_trait]
+impl Command for SprintReportCommand {
+    fn name(&self) -> &'static str {
+        "report"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let sprint_id = matches.get_one::<String>("id").unwrap();
+        let format = OutputFormat::from_matches(matches);
+        if format == OutputFormat::Csv {
+            return exit::fail("The sprint report is available as table, md or json");
+        }
+        if let Err(e) = ensure_online("fetching the sprint worklogs") {
+            return exit::fail(e);
+        }
+        let sprint = match SprintService::production().get_sprint(sprint_id) {
+            Ok(Some(sprint)) => sprint,
+            Ok(None) => {
+                return exit::fail(format!(
+                    "Sprint '{}' not found (run `wtf sprint fetch`?)",
+                    sprint_id
+                ))
+            }
+            Err(e) => return exit::fail(e),
+        };
+        let (Some(start), Some(end)) = (sprint.start, sprint.end) else {
+            return exit::fail(format!("Sprint '{}' has no start or end date", sprint.name));
+        };
+        let (from, to) = (start.date_naive(), end.date_naive());
+
+        if !format.is_json() && format != OutputFormat::Markdown {
+            println!("Fetching the worklogs of sprint '{}'…", sprint.name);
+        }
+        let worklogs = match JiraClient::create()
+            .get_sprint_worklogs(sprint_id, from, to)
+            .await
+        {
+            Ok(worklogs) => worklogs,
+            Err(e) => return exit::fail(format!("Failed to fetch the sprint worklogs: {}", e)),
+        };
+        let summaries: HashMap<_, _> = IssueService::production()
+            .get_all_issues()
+            .into_iter()
+            .map(|issue| (issue.key, issue.summary))
+            .collect();
+        let report = SprintReport::build(&sprint.name, from, to, &worklogs, &summaries);
+
+        match format {
+            OutputFormat::Json => print_json(&report),
+            OutputFormat::Markdown => print!("{}", report.to_markdown()),
+            _ => print_tables(&report),
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Hours logged by everyone on a sprint, per person, issue and day")
+            .long_about(
+                "Fetch the worklogs of every author on the sprint's issues from Jira and \
+                 break them down per person, per issue and per day. With the global \
+                 `--format md` option the report is printed as Markdown tables, ready to \
+                 paste into retro notes.",
+            )
+            .arg(
+                Arg::new("id")
+                    .required(true)
+                    .value_name("ID")
+                    .add(sprint_ids())
+                    .help("The sprint id"),
+            )
+    }
+}
+
+fn print_tables(report: &SprintReport) {
+    println!(
+        "{} ({} → {}): {:.2}h logged",
+        format!("Sprint {}", report.sprint).bold(),
+        report.from,
+        report.to,
+        report.total_hours
+    );
+    if report.people.is_empty() {
+        println!("No time logged on this sprint's issues");
+        return;
+    }
+    for (title, (header, rows)) in [
+        ("By person", report.people_grid()),
+        ("By issue", report.issue_grid()),
+        ("By day", report.day_grid()),
+    ] {
+        println!("\n{}", title.bold());
+        let mut builder = Builder::default();
+        builder.push_record(header);
+        for row in rows {
+            builder.push_record(row);
+        }
+        let mut table = builder.build();
+        table.with(Style::modern().remove_horizontal());
+        table.with(Modify::new(Rows::first()).with(Color::BOLD | Color::FG_WHITE));
+        table.with(Modify::new(Columns::last()).with(Alignment::right()));
+        println!("{table}");
+    }
+}
@@ -0,0 +1,139 @@
+use crate::commands::completions::sprint_ids;
+use crate::commands::{print_json, Command, OutputFormat};
+use crate::exit;
+use crate::tasks::ensure_online;
+use async_trait::async_trait;
+use chrono::Local;
+use clap::{Arg, ArgMatches, Command as ClapCommand};
+use colored::Colorize;
+use tabled::builder::Builder;
+use tabled::settings::object::{Columns, Rows};
+use tabled::settings::{Alignment, Color, Modify, Style};
+use wtf_lib::client::jira_client::JiraClient;
+use wtf_lib::config::Config;
+use wtf_lib::models::data::Sprint;
+use wtf_lib::services::jira_service::{JiraService, SprintService};
+use wtf_lib::utils::team_coverage::TeamCoverage;
+
+/// `wtf sprint team`: how well the teammates in `[team] members` logged a sprint, from
+/// Jira. Nothing is stored.
+pub struct SprintTeamCommand;
+
+#[async_trait]
+impl Command for SprintTeamCommand {
+    fn name(&self) -> &'static str {
+        "team"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let config = Config::load().unwrap_or_default();
+        let format = OutputFormat::from_matches(matches);
+        if format == OutputFormat::Csv {
+            return exit::fail("The team coverage is available as table, md or json");
+        }
+        if config.team.members.is_empty() {
+            return exit::fail(
+                "No teammates to check, list them in `[team] members` of the config",
+            );
+        }
+        if let Err(e) = ensure_online("fetching the team worklogs") {
+            return exit::fail(e);
+        }
+        let sprints: Vec<Sprint> = match matches.get_one::<String>("id") {
+            Some(sprint_id) => match SprintService::production().get_sprint(sprint_id) {
+                Ok(Some(sprint)) => vec![sprint],
+                Ok(None) => {
+                    return exit::fail(format!(
+                        "Sprint '{}' not found (run `wtf sprint fetch`?)",
+                        sprint_id
+                    ))
+                }
+                Err(e) => return exit::fail(e),
+            },
+            None => JiraService::production().get_followed_sprint(),
+        };
+
+        let today = Local::now().date_naive();
+        let client = JiraClient::create();
+        let mut coverages = Vec::new();
+        for sprint in sprints {
+            let (Some(start), Some(end)) = (sprint.start, sprint.end) else {
+                eprintln!(
+                    "⚠️  Sprint '{}' has no start or end date, skipped",
+                    sprint.name
+                );
+                continue;
+            };
+            // Days still to come aren't missing yet
+            let (from, to) = (start.date_naive(), end.date_naive().min(today));
+            if from > to {
+                continue;
+            }
+            if !format.is_json() && format != OutputFormat::Markdown {
+                println!("Fetching the team's worklogs of sprint '{}'…", sprint.name);
+            }
+            let worklogs = match client
+                .get_sprint_worklogs(&sprint.id.to_string(), from, to)
+                .await
+            {
+                Ok(worklogs) => worklogs,
+                Err(e) => return exit::fail(format!("Failed to fetch the sprint worklogs: {}", e)),
+            };
+            coverages.push(TeamCoverage::build(
+                &sprint.name,
+                from,
+                to,
+                &worklogs,
+                &config.team.members,
+                |day| config.worklog.target_hours(day),
+            ));
+        }
+
+        match format {
+            OutputFormat::Json => print_json(&coverages),
+            OutputFormat::Markdown => {
+                let tables: Vec<String> = coverages.iter().map(TeamCoverage::to_markdown).collect();
+                print!("{}", tables.join("\n"));
+            }
+            _ if coverages.is_empty() => println!("No followed sprint with dates to check"),
+            _ => coverages.iter().for_each(print_table),
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Hours the teammates in [team] members logged on a sprint (read-only)")
+            .long_about(
+                "Fetch the worklogs of the sprint's issues from Jira and compare what each \
+                 teammate listed in `[team] members` logged with the configured daily hours, \
+                 up to today. Every followed sprint is checked when no id is given. The \
+                 worklogs are only read, never stored.",
+            )
+            .arg(
+                Arg::new("id")
+                    .value_name("ID")
+                    .add(sprint_ids())
+                    .help("The sprint id (default: the followed sprints)"),
+            )
+    }
+}
+
+fn print_table(coverage: &TeamCoverage) {
+    println!(
+        "\n{} ({} → {})",
+        format!("Sprint {}", coverage.sprint).bold(),
+        coverage.from,
+        coverage.to
+    );
+    let (header, rows) = coverage.grid();
+    let mut builder = Builder::default();
+    builder.push_record(header);
+    for row in rows {
+        builder.push_record(row);
+    }
+    let mut table = builder.build();
+    table.with(Style::modern().remove_horizontal());
+    table.with(Modify::new(Rows::first()).with(Color::BOLD | Color::FG_WHITE));
+    table.with(Modify::new(Columns::new(1..4)).with(Alignment::right()));
+    println!("{table}");
+}
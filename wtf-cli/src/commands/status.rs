@@ -0,0 +1,316 @@
+use crate::commands::{print_json, Command, OutputFormat};
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Utc};
+use clap::{Arg, ArgAction, ArgMatches, Command as ClapCommand};
+use colored::Colorize;
+use serde::Serialize;
+use wtf_lib::common::Common;
+use wtf_lib::config::{active_profile, Config, WorklogConfig};
+use wtf_lib::duration::format_hours;
+use wtf_lib::models::data::{LocalWorklogState, SprintState};
+use wtf_lib::services::jira_service::JiraService;
+use wtf_lib::services::meetings_service::MeetingsService;
+use wtf_lib::services::sync_status_service::SyncStatusService;
+use wtf_lib::services::worklogs_service::{LocalWorklogService, WorklogsService};
+use wtf_lib::utils::meetings::is_untracked;
+
+pub struct StatusCommand;
+
+/// Everything `wtf status` reports
+#[derive(Serialize)]
+struct Status {
+    /// Named profile in use, `None` for the default one
+    profile: Option<String>,
+    today_hours: f64,
+    today_target_hours: f64,
+    week_hours: f64,
+    week_target_hours: f64,
+    created_worklogs: usize,
+    staged_worklogs: usize,
+    unlinked_meetings: usize,
+    active_sprint: Option<ActiveSprint>,
+    /// Oldest successful fetch among the data sources, `None` before the first fetch
+    last_sync: Option<DateTime<Utc>>,
+    /// Whether `last_sync` is older than twice the daemon interval
+    stale: bool,
+    /// Last time Jira accepted the API token
+    credentials_verified: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize)]
+struct ActiveSprint {
+    id: usize,
+    name: String,
+    end: Option<NaiveDate>,
+}
+
+#[async_trait]
+impl Command for StatusCommand {
+    fn name(&self) -> &'static str {
+        "status"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let status = collect_status();
+
+        if OutputFormat::from_matches(matches).is_json() {
+            print_json(&status);
+        } else if matches.get_flag("short") {
+            println!("{}", short_line(&status));
+        } else {
+            print_status(&status);
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about(
+                "Show today's and this week's logged hours, pending worklogs and the active sprint",
+            )
+            .arg(
+                Arg::new("short")
+                    .long("short")
+                    .short('s')
+                    .action(ArgAction::SetTrue)
+                    .help("Print a single plain line, for shell prompts and tmux status bars"),
+            )
+    }
+}
+
+fn collect_status() -> Status {
+    let config = Config::load().ok();
+    let worklog = config
+        .as_ref()
+        .map(|c| c.worklog.clone())
+        .unwrap_or_default();
+    let today = Local::now().date_naive();
+    let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+
+    let meetings_service = MeetingsService::production();
+    let local_service = LocalWorklogService::production();
+    let jira_worklogs = WorklogsService::production().get_all_worklogs();
+
+    let target = |day: NaiveDate| target_hours(day, &worklog, &meetings_service);
+    let week: Vec<_> = (0..7).map(|i| monday + Duration::days(i)).collect();
+
+    let local_worklogs = local_service.get_all_local_worklogs();
+    let count = |state: LocalWorklogState| {
+        local_worklogs
+            .iter()
+            .filter(|wl| wl.status == state)
+            .count()
+    };
+
+    let now = Utc::now();
+    let untracked_ids = meetings_service.get_all_untracked_ids();
+    let unlinked_meetings = match &config {
+        Some(config) => meetings_service
+            .get_meetings(false)
+            .iter()
+            .filter(|m| {
+                m.start <= now
+                    && m.jira_link.is_none()
+                    && config.meetings.attends(m)
+                    && !is_untracked(m, config, &untracked_ids)
+            })
+            .count(),
+        None => 0,
+    };
+
+    let active_sprint = JiraService::production()
+        .get_followed_sprint()
+        .into_iter()
+        .find(|s| s.state == SprintState::Active)
+        .map(|s| ActiveSprint {
+            id: s.id,
+            name: s.name,
+            end: s.end.map(|end| end.date_naive()),
+        });
+
+    let sync_status = SyncStatusService::production();
+    let last_sync = sync_status.oldest_sync();
+    let sync_interval = config.as_ref().map_or(30, |c| c.daemon.interval_minutes) as i64;
+    let stale = last_sync.is_none_or(|at| now - at > Duration::minutes(2 * sync_interval));
+
+    Status {
+        profile: active_profile(),
+        today_hours: local_service.calculate_daily_total(today, &jira_worklogs),
+        today_target_hours: target(today),
+        week_hours: week
+            .iter()
+            .map(|day| local_service.calculate_daily_total(*day, &jira_worklogs))
+            .sum(),
+        week_target_hours: week.iter().map(|day| target(*day)).sum(),
+        created_worklogs: count(LocalWorklogState::Created),
+        staged_worklogs: count(LocalWorklogState::Staged),
+        unlinked_meetings,
+        active_sprint,
+        last_sync,
+        stale,
+        credentials_verified: sync_status.credentials_verified(),
+    }
+}
+
+/// e.g. "5.5/8h · 22/40h · 3 to push · 2 unlinked · Sprint 42 · synced 3h ago", prefixed
+/// by the profile name when one is active
+fn short_line(status: &Status) -> String {
+    let mut parts: Vec<String> = status.profile.iter().map(|p| format!("[{}]", p)).collect();
+    parts.extend([
+        format!(
+            "{}/{}h",
+            compact(status.today_hours),
+            compact(status.today_target_hours)
+        ),
+        format!(
+            "{}/{}h",
+            compact(status.week_hours),
+            compact(status.week_target_hours)
+        ),
+    ]);
+    let to_push = status.created_worklogs + status.staged_worklogs;
+    if to_push > 0 {
+        parts.push(format!("{} to push", to_push));
+    }
+    if status.unlinked_meetings > 0 {
+        parts.push(format!("{} unlinked", status.unlinked_meetings));
+    }
+    if let Some(sprint) = &status.active_sprint {
+        parts.push(sprint.name.clone());
+    }
+    if status.stale {
+        parts.push(match status.last_sync {
+            Some(at) => format!("synced {}", Common::time_ago(Utc::now() - at)),
+            None => "never synced".to_string(),
+        });
+    }
+    parts.join(" · ")
+}
+
+/// Hours expected on `day`: its weekday's working hours without an absence, 0 otherwise
+pub(crate) fn target_hours(
+    day: NaiveDate,
+    worklog: &WorklogConfig,
+    meetings: &MeetingsService,
+) -> f64 {
+    if meetings.is_absent(day) {
+        0.0
+    } else {
+        worklog.target_hours(day)
+    }
+}
+
+/// One decimal, dropped when it is zero: 8 → "8", 5.26 → "5.3"
+pub(crate) fn compact(hours: f64) -> String {
+    let text = format!("{:.1}", hours);
+    text.strip_suffix(".0").unwrap_or(&text).to_string()
+}
+
+fn print_status(status: &Status) {
+    if let Some(profile) = &status.profile {
+        println!("{:<11}{}", "Profile", profile.bold());
+    }
+    let hours = |logged: f64, target: f64| {
+        let text = format!("{} / {}", format_hours(logged), format_hours(target));
+        if logged >= target {
+            text.green()
+        } else {
+            text.yellow()
+        }
+    };
+    println!(
+        "{:<11}{}",
+        "Today",
+        hours(status.today_hours, status.today_target_hours)
+    );
+    println!(
+        "{:<11}{}",
+        "This week",
+        hours(status.week_hours, status.week_target_hours)
+    );
+    println!(
+        "{:<11}{} created · {} staged",
+        "To push", status.created_worklogs, status.staged_worklogs
+    );
+    println!("{:<11}{} unlinked", "Meetings", status.unlinked_meetings);
+    match &status.active_sprint {
+        Some(sprint) => println!(
+            "{:<11}{} (#{}{})",
+            "Sprint",
+            sprint.name.bold(),
+            sprint.id,
+            sprint
+                .end
+                .map(|end| format!(", ends {}", end))
+                .unwrap_or_default()
+        ),
+        None => println!("{:<11}{}", "Sprint", "no active followed sprint".dimmed()),
+    }
+    let synced = match status.last_sync {
+        Some(at) => Common::time_ago(Utc::now() - at),
+        None => "never (run `wtf fetch all` or `wtf daemon`)".to_string(),
+    };
+    if status.stale {
+        println!("{:<11}{}", "Last sync", synced.yellow());
+    } else {
+        println!("{:<11}{}", "Last sync", synced);
+    }
+    match status.credentials_verified {
+        Some(at) => println!(
+            "{:<11}verified {}",
+            "Jira token",
+            Common::time_ago(Utc::now() - at)
+        ),
+        None => println!("{:<11}{}", "Jira token", "never verified".dimmed()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status() -> Status {
+        Status {
+            profile: None,
+            today_hours: 5.5,
+            today_target_hours: 8.0,
+            week_hours: 22.0,
+            week_target_hours: 40.0,
+            created_worklogs: 0,
+            staged_worklogs: 0,
+            unlinked_meetings: 0,
+            active_sprint: None,
+            last_sync: None,
+            stale: false,
+            credentials_verified: None,
+        }
+    }
+
+    #[test]
+    fn test_short_line_only_mentions_what_needs_attention() {
+        assert_eq!(short_line(&status()), "5.5/8h · 22/40h");
+
+        let busy = Status {
+            profile: Some("work".to_string()),
+            created_worklogs: 1,
+            staged_worklogs: 2,
+            unlinked_meetings: 4,
+            active_sprint: Some(ActiveSprint {
+                id: 42,
+                name: "Sprint 42".to_string(),
+                end: None,
+            }),
+            stale: true,
+            ..status()
+        };
+        assert_eq!(
+            short_line(&busy),
+            "[work] · 5.5/8h · 22/40h · 3 to push · 4 unlinked · Sprint 42 · never synced"
+        );
+    }
+
+    #[test]
+    fn test_compact_drops_a_zero_decimal() {
+        assert_eq!(compact(8.0), "8");
+        assert_eq!(compact(5.26), "5.3");
+    }
+}
@@ -0,0 +1,228 @@
+use crate::commands::status::{compact, target_hours};
+use crate::commands::{print_json, Command, OutputFormat};
+use async_trait::async_trait;
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use clap::{ArgMatches, Command as ClapCommand};
+use colored::Colorize;
+use serde::Serialize;
+use std::collections::HashMap;
+use wtf_lib::config::{Config, WorklogConfig};
+use wtf_lib::models::data::Worklog;
+use wtf_lib::services::jira_service::IssueService;
+use wtf_lib::services::meetings_service::MeetingsService;
+use wtf_lib::services::worklogs_service::{LocalWorklogService, WorklogsService};
+
+/// Width of the capacity gauge, in characters
+const GAUGE_WIDTH: usize = 20;
+/// Longest issue summary printed next to its key
+const SUMMARY_WIDTH: usize = 50;
+
+/// `wtf today`: hours logged today per issue, against the day's capacity
+pub struct TodayCommand;
+
+/// `wtf week`: hours logged per day and issue over the current week
+pub struct WeekCommand;
+
+#[derive(Serialize)]
+struct DayBreakdown {
+    date: NaiveDate,
+    hours: f64,
+    target_hours: f64,
+    issues: Vec<IssueHours>,
+}
+
+#[derive(Serialize)]
+struct IssueHours {
+    issue: String,
+    hours: f64,
+    /// `None` when the issue isn't in the local database
+    summary: Option<String>,
+}
+
+#[derive(Serialize)]
+struct WeekBreakdown {
+    from: NaiveDate,
+    to: NaiveDate,
+    hours: f64,
+    target_hours: f64,
+    days: Vec<DayBreakdown>,
+}
+
+#[async_trait]
+impl Command for TodayCommand {
+    fn name(&self) -> &'static str {
+        "today"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let view = LoggedTime::load();
+        let day = view.day(Local::now().date_naive());
+        if OutputFormat::from_matches(matches).is_json() {
+            print_json(&day);
+            return;
+        }
+        print_day(&day, view.ascii, true);
+        if day.issues.is_empty() {
+            println!("  {}", "Nothing logged yet".dimmed());
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Show the time logged today per issue (local data only, no network)")
+    }
+}
+
+#[async_trait]
+impl Command for WeekCommand {
+    fn name(&self) -> &'static str {
+        "week"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let view = LoggedTime::load();
+        let today = Local::now().date_naive();
+        let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+        let days: Vec<_> = (0..7)
+            .map(|i| view.day(monday + Duration::days(i)))
+            // Days off (weekends, part-time days) only when something was logged on them
+            .filter(|day| view.worklog.is_workday(day.date) || day.hours > 0.0)
+            .collect();
+        let week = WeekBreakdown {
+            from: monday,
+            to: monday + Duration::days(6),
+            hours: days.iter().map(|day| day.hours).sum(),
+            target_hours: days.iter().map(|day| day.target_hours).sum(),
+            days,
+        };
+        if OutputFormat::from_matches(matches).is_json() {
+            print_json(&week);
+            return;
+        }
+
+        for day in &week.days {
+            print_day(day, view.ascii, day.date == today);
+        }
+        println!();
+        let label = format!("Week {}", week.from.format("%G-W%V"));
+        println!(
+            "{}{}",
+            format!("{:<16}", label).bold(),
+            capacity(week.hours, week.target_hours, view.ascii)
+        );
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name()).about(
+            "Show the time logged each day of this week per issue (local data only, no network)",
+        )
+    }
+}
+
+/// Logged time as the local database knows it: local worklogs plus the ones fetched
+/// from Jira that weren't logged through wtf
+struct LoggedTime {
+    worklog: WorklogConfig,
+    ascii: bool,
+    local: LocalWorklogService,
+    jira_worklogs: Vec<Worklog>,
+    meetings: MeetingsService,
+    summaries: HashMap<String, String>,
+}
+
+impl LoggedTime {
+    fn load() -> Self {
+        let config = Config::load().unwrap_or_default();
+        Self {
+            ascii: config.ui.ascii_mode,
+            worklog: config.worklog,
+            local: LocalWorklogService::production(),
+            jira_worklogs: WorklogsService::production().get_all_worklogs(),
+            meetings: MeetingsService::production(),
+            summaries: IssueService::production()
+                .get_all_issues()
+                .into_iter()
+                .map(|issue| (issue.key, issue.summary))
+                .collect(),
+        }
+    }
+
+    /// Issues sorted by the time spent on them, longest first
+    fn day(&self, date: NaiveDate) -> DayBreakdown {
+        let mut issues: Vec<_> = self
+            .local
+            .daily_hours_by_issue(date, &self.jira_worklogs)
+            .into_iter()
+            .map(|(issue, hours)| IssueHours {
+                summary: self.summaries.get(&issue).cloned(),
+                issue,
+                hours,
+            })
+            .collect();
+        issues.sort_by(|a, b| b.hours.total_cmp(&a.hours));
+        DayBreakdown {
+            date,
+            hours: issues.iter().fold(0.0, |total, issue| total + issue.hours),
+            target_hours: target_hours(date, &self.worklog, &self.meetings),
+            issues,
+        }
+    }
+}
+
+fn print_day(day: &DayBreakdown, ascii: bool, highlight: bool) {
+    let label = format!("{:<16}", day.date.format("%a %Y-%m-%d").to_string());
+    let label = if highlight {
+        label.bold()
+    } else {
+        label.normal()
+    };
+    println!("{}{}", label, capacity(day.hours, day.target_hours, ascii));
+    for issue in &day.issues {
+        let summary = issue
+            .summary
+            .as_deref()
+            .map(|summary| truncate(summary, SUMMARY_WIDTH))
+            .unwrap_or_default();
+        println!(
+            "  {:<12} {:>5}h  {}",
+            issue.issue.cyan(),
+            compact(issue.hours),
+            summary.dimmed()
+        );
+    }
+}
+
+/// "5.5/8h [██████████░░░░░░░░░░]  69%", green once the target is reached
+fn capacity(hours: f64, target: f64, ascii: bool) -> String {
+    if target <= 0.0 {
+        return format!("{}h  {}", compact(hours), "day off".dimmed());
+    }
+    let ratio = hours / target;
+    let filled = ((ratio.min(1.0) * GAUGE_WIDTH as f64).round() as usize).min(GAUGE_WIDTH);
+    let (full, empty) = if ascii { ('#', '-') } else { ('█', '░') };
+    let gauge = format!(
+        "[{}{}] {:>3.0}%",
+        full.to_string().repeat(filled),
+        empty.to_string().repeat(GAUGE_WIDTH - filled),
+        ratio * 100.0
+    );
+    let gauge = if hours >= target {
+        gauge.green()
+    } else {
+        gauge.yellow()
+    };
+    format!(
+        "{:>9} {}",
+        format!("{}/{}h", compact(hours), compact(target)),
+        gauge
+    )
+}
+
+fn truncate(text: &str, max_len: usize) -> String {
+    if text.chars().count() <= max_len {
+        text.to_string()
+    } else {
+        let truncated: String = text.chars().take(max_len - 1).collect();
+        format!("{}…", truncated)
+    }
+}
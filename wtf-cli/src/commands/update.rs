@@ -1,4 +1,6 @@
-use crate::commands::Command;
+use crate::commands::{print_json, Command, OutputFormat};
+use crate::exit;
+use crate::tasks::ensure_online;
 use async_trait::async_trait;
 use clap::{ArgMatches, Command as ClapCommand};
 use colored::Colorize;
@@ -15,10 +17,16 @@ impl Command for UpdateCommand {
     }
 
     async fn execute(&self, matches: &ArgMatches) {
+        if let Err(e) = ensure_online("checking for updates") {
+            return exit::fail(e);
+        }
         match matches.subcommand() {
             Some(("list", sub_matches)) => {
                 let include_prerelease = sub_matches.get_flag("unstable");
-                println!("Fetching available versions...");
+                let json = OutputFormat::from_matches(sub_matches).is_json();
+                if !json {
+                    println!("Fetching available versions...");
+                }
                 let mut versions = wtf_lib::utils::version::list_versions(include_prerelease).await;
                 versions.sort_by(|a, b| {
                     let a = a.trim_start_matches('v');
@@ -31,7 +39,9 @@ impl Command for UpdateCommand {
                         std::cmp::Ordering::Equal
                     }
                 });
-                if versions.is_empty() {
+                if json {
+                    print_json(&versions);
+                } else if versions.is_empty() {
                     println!("{}", "No versions found.".yellow());
                 } else {
                     let current = env!("CARGO_PKG_VERSION");
@@ -0,0 +1,115 @@
+use crate::commands::completions::{issue_keys, sprint_ids};
+use crate::commands::{period_arg, period_from_matches, Command};
+use crate::logger;
+use crate::tasks::wizard_tasks::{HeadlessWizardTask, WizardStage};
+use crate::tasks::Task;
+use async_trait::async_trait;
+use clap::{Arg, ArgAction, ArgGroup, ArgMatches, Command as ClapCommand};
+
+pub struct WizardCommand;
+
+#[async_trait]
+impl Command for WizardCommand {
+    fn name(&self) -> &'static str {
+        "wizard"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        logger::init_logger(logger::stdout_logger());
+
+        match matches.subcommand() {
+            Some(("run", sub_matches)) => WizardRunCommand.execute(sub_matches).await,
+            _ => eprintln!("Invalid subcommand. Use 'wtf wizard --help' for usage."),
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Run the Chronie wizard outside the TUI")
+            .subcommand(WizardRunCommand.clap_command())
+    }
+}
+
+struct WizardRunCommand;
+
+#[async_trait]
+impl Command for WizardRunCommand {
+    fn name(&self) -> &'static str {
+        "run"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let period = match period_from_matches(matches) {
+            Ok(period) => period,
+            Err(e) => {
+                eprintln!("❌ {}", e);
+                std::process::exit(1);
+            }
+        };
+        let skipped = matches
+            .get_many::<String>("skip")
+            .unwrap_or_default()
+            .filter_map(|step| step.parse::<WizardStage>().ok())
+            .collect();
+
+        let result = HeadlessWizardTask::new(period)
+            .with_gap_issue(matches.get_one::<String>("gap-issue").cloned())
+            .with_push(matches.get_flag("auto"))
+            .skipping(skipped)
+            .execute()
+            .await;
+
+        // Non-zero exit so cron jobs and scripts notice a failed run
+        if let Err(e) = result {
+            eprintln!("❌ Wizard failed: {}", e);
+            std::process::exit(1);
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Run the wizard non-interactively (for cron or scripts)")
+            .long_about(
+                "Run sync → auto-link → meeting worklogs → GitHub worklogs → gap fill → push \
+                without prompts. Manual linking is skipped, GitHub sessions are capped to the \
+                daily limit and gaps are only filled when --gap-issue is given. Worklogs are \
+                created but not pushed unless --auto is set.",
+            )
+            .arg(
+                Arg::new("sprint")
+                    .short('s')
+                    .long("sprint")
+                    .value_name("SPRINT_ID")
+                    .add(sprint_ids())
+                    .help("The sprint to fill"),
+            )
+            .arg(period_arg())
+            .group(
+                ArgGroup::new("days")
+                    .args(["sprint", "period"])
+                    .required(true),
+            )
+            .arg(
+                Arg::new("gap-issue")
+                    .short('g')
+                    .long("gap-issue")
+                    .value_name("ISSUE_KEY")
+                    .add(issue_keys())
+                    .help("Issue that receives the remaining time of under-logged days"),
+            )
+            .arg(
+                Arg::new("auto")
+                    .long("auto")
+                    .help("Stage and push the period's worklogs to Jira at the end")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(
+                Arg::new("skip")
+                    .long("skip")
+                    .value_name("STEP")
+                    .value_parser(["sync", "autolink", "meetings", "github", "gaps"])
+                    .num_args(1..)
+                    .help("Steps to leave out"),
+            )
+    }
+}
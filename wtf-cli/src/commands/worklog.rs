@@ -1,17 +1,26 @@
-use crate::commands::Command;
+use crate::commands::completions::{issue_keys, sprint_ids};
+use crate::commands::worklog_import::LogImportCommand;
+use crate::commands::{print_json, Command, OutputFormat};
+use crate::exit;
+use crate::tasks::worklog_tasks::PushStagedWorklogsTask;
+use crate::tasks::{stage_worklogs, Task};
 use async_trait::async_trait;
-use chrono::Duration;
+use chrono::{Duration, Local, NaiveDate, NaiveTime, TimeZone, Utc};
 use clap::{Arg, ArgAction, ArgMatches, Command as ClapCommand};
 use colored::{ColoredString, Colorize};
 use log::debug;
+use serde::Serialize;
 use std::collections::HashMap;
 use tabled::settings::object::Columns;
 use tabled::settings::{Alignment, Color, Modify, Style};
 use tabled::{Table, Tabled};
 use wtf_lib::common::Common;
-use wtf_lib::models::data::{LocalWorklog, LocalWorklogHistory, LocalWorklogState};
-use wtf_lib::services::jira_service::IssueService;
+use wtf_lib::config::Config;
+use wtf_lib::duration::parse_work_duration;
+use wtf_lib::models::data::{LocalWorklog, LocalWorklogHistory, LocalWorklogState, Sprint};
+use wtf_lib::services::jira_service::SprintService;
 use wtf_lib::services::worklogs_service::LocalWorklogService;
+use wtf_lib::utils::dates::parse_day;
 use LocalWorklogState::Created;
 use LocalWorklogState::Pushed;
 use LocalWorklogState::Staged;
@@ -30,6 +39,7 @@ impl Command for LogCommand {
             Some(("add", sub_m)) => LogAddCommand.execute(sub_m).await,
             Some(("rm", sub_m)) => LogRemoveCommand.execute(sub_m).await,
             Some(("push", sub_m)) => LogPushCommand.execute(sub_m).await,
+            Some(("import", sub_m)) => LogImportCommand.execute(sub_m).await,
             Some(("list", sub_m)) => LogListCommand.execute(sub_m).await,
             Some(("revert", sub_m)) => LogRevertCommand.execute(sub_m).await,
             Some(("history", sub_m)) => LogHistoryCommand.execute(sub_m).await,
@@ -41,6 +51,7 @@ impl Command for LogCommand {
     fn clap_command(&self) -> clap::Command {
         clap::Command::new(self.name())
             .about("worklog management")
+            .visible_alias("log")
             .alias("wl")
             .arg(
                 Arg::new("all")
@@ -53,6 +64,7 @@ impl Command for LogCommand {
             .subcommand(LogAddCommand.clap_command())
             .subcommand(LogRemoveCommand.clap_command())
             .subcommand(LogPushCommand.clap_command())
+            .subcommand(LogImportCommand.clap_command())
             .subcommand(LogListCommand.clap_command())
             .subcommand(LogRevertCommand.clap_command())
             .subcommand(LogHistoryCommand.clap_command())
@@ -76,7 +88,11 @@ impl Command for LogListCommand {
             LocalWorklogService::production()
                 .get_all_local_worklogs_by_status(vec![Created, Staged])
         };
-        db_wl.sort_by(|a, b| a.started.cmp(&b.started));
+        db_wl.sort_by_key(|a| a.started);
+        if OutputFormat::from_matches(matches).is_json() {
+            print_json(&db_wl);
+            return;
+        }
         let (status_stats, total_time_spent) = compute_worklogs_stats(db_wl.clone());
 
         let wl: Vec<WorklogInfo> = db_wl.iter().map(|w| WorklogInfo::new(w.clone())).collect();
@@ -190,11 +206,11 @@ impl Command for LogAddCommand {
     }
 
     async fn execute(&self, matches: &ArgMatches) {
-        let ids: Vec<String> = matches
-            .get_many("worklog-ids")
-            .expect("required worklog-ids")
-            .cloned()
-            .collect();
+        let Some(ids) = matches.get_many::<String>("worklog-ids") else {
+            create_worklog(matches);
+            return;
+        };
+        let ids: Vec<String> = ids.cloned().collect();
         let worklogs_to_add = if !ids.is_empty() && ids.contains(&"all".to_string()) {
             LocalWorklogService::production().get_all_local_worklogs_by_status(vec![Created])
         } else {
@@ -205,16 +221,53 @@ impl Command for LogAddCommand {
                 .cloned()
                 .collect()
         };
-        for mut w in worklogs_to_add {
-            w.status = Staged;
-            LocalWorklogService::production().save_local_worklog(w.clone());
+        let config = Config::load().unwrap_or_default();
+        for w in stage_worklogs(worklogs_to_add, &config) {
             println!("Worklog '{}' added", w.id);
         }
     }
 
     fn clap_command(&self) -> ClapCommand {
         ClapCommand::new(self.name())
-            .about("add worklog to the staging")
+            .about("log time on an issue, or add worklogs to the staging")
+            .long_about(
+                "Log time on an issue, e.g. `wtf log add PROJ-12 1h30 --date yesterday`, \
+                 or add existing worklogs to the staging with --worklog-ids.\n\n\
+                 Durations accept Jira notation (`1d 2h 30m`, a day being the configured \
+                 daily hours), `1h30` and `1.5h`. Dates accept `today`, `yesterday`, \
+                 `YYYY-MM-DD`, weekday names, `last friday` and `3 days ago`.",
+            )
+            .arg(
+                Arg::new("issue")
+                    .required_unless_present("worklog-ids")
+                    .add(issue_keys())
+                    .help("issue key to log time on"),
+            )
+            .arg(
+                Arg::new("duration")
+                    .required_unless_present("worklog-ids")
+                    .help("time spent, e.g. 1h30, 45m, 1.5 (hours), 1:30 or \"1d 2h\""),
+            )
+            .arg(
+                Arg::new("date")
+                    .long("date")
+                    .short('d')
+                    .default_value("today")
+                    .help("day the work was done"),
+            )
+            .arg(
+                Arg::new("start")
+                    .long("start")
+                    .value_name("HH:MM")
+                    .default_value("09:00")
+                    .help("time the work started"),
+            )
+            .arg(
+                Arg::new("comment")
+                    .long("comment")
+                    .short('c')
+                    .help("worklog comment"),
+            )
             .arg(
                 Arg::new("worklog-ids")
                     .long("worklog-ids")
@@ -222,12 +275,61 @@ impl Command for LogAddCommand {
                     .short('w')
                     .value_parser(clap::value_parser!(String))
                     .num_args(1..)
-                    .required(true)
+                    .conflicts_with_all(["issue", "duration"])
                     .help("ids of a specific local worklogs to stage"),
             )
     }
 }
 
+/// Create a local worklog from `wtf log add <issue> <duration>`
+fn create_worklog(matches: &ArgMatches) {
+    let config = Config::load().unwrap_or_default();
+    let issue = &config.expand_issue_alias(matches.get_one::<String>("issue").unwrap());
+    let duration = matches.get_one::<String>("duration").unwrap();
+    let date = matches.get_one::<String>("date").unwrap();
+    let start = matches.get_one::<String>("start").unwrap();
+
+    let duration = match parse_work_duration(duration, config.worklog.hours_per_day()) {
+        Ok(duration) if duration > Duration::zero() => duration,
+        _ => {
+            eprintln!(
+                "❌ Invalid duration '{}'. Expected e.g. 1h30, 45m, 1.5, 1:30 or \"1d 2h 30m\"",
+                duration
+            );
+            return;
+        }
+    };
+    let Some(day) = parse_day(date, Local::now().date_naive()) else {
+        eprintln!(
+            "❌ Invalid date '{}'. Expected e.g. today, yesterday, monday, last friday or YYYY-MM-DD",
+            date
+        );
+        return;
+    };
+    let Some(started) = NaiveTime::parse_from_str(start, "%H:%M")
+        .ok()
+        .and_then(|time| Local.from_local_datetime(&day.and_time(time)).earliest())
+    else {
+        eprintln!("❌ Invalid start time '{}'. Expected HH:MM", start);
+        return;
+    };
+
+    let worklog = LocalWorklogService::production().create_new_local_worklogs(
+        started.with_timezone(&Utc),
+        duration.num_seconds(),
+        issue,
+        matches.get_one::<String>("comment").map(String::as_str),
+        None,
+    );
+    println!(
+        "✅ Logged {} on {} for {} (worklog '{}', run `wtf log push` to send it)",
+        Common::readable_time_spent(duration.num_seconds()),
+        issue,
+        day,
+        worklog.id
+    );
+}
+
 struct LogRemoveCommand;
 
 #[async_trait]
@@ -283,49 +385,76 @@ impl Command for LogPushCommand {
         "push"
     }
 
-    async fn execute(&self, _matches: &ArgMatches) {
-        let worklogs =
-            LocalWorklogService::production().get_all_local_worklogs_by_status(vec![Staged]);
-        let mut local_worklogs_id: Vec<String> = Vec::new();
-        for mut wl in worklogs {
-            match IssueService::production()
-                .add_time(
-                    wl.issue_id.as_str(),
-                    Duration::seconds(wl.time_spent_seconds),
-                    wl.started,
-                    Some(wl.comment.clone()),
-                )
-                .await
-            {
-                Ok(Some(jira_worklog)) => {
-                    wl.status = Pushed;
-                    wl.worklog_id = Some(jira_worklog.id);
-                    LocalWorklogService::production().save_local_worklog(wl.clone());
-                    local_worklogs_id.push(wl.id);
+    async fn execute(&self, matches: &ArgMatches) {
+        let mut task =
+            PushStagedWorklogsTask::default().with_created(matches.get_flag("include-created"));
+        if let Some(date) = matches.get_one::<String>("date") {
+            match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+                Ok(date) => task = task.with_date(date),
+                Err(_) => {
+                    exit::fail(format!("Invalid date '{}'. Expected YYYY-MM-DD", date));
+                    return;
                 }
-                Ok(None) => {
-                    // The worklog was created in Jira (2xx response) but no Location header
-                    // was returned, so we couldn't fetch its ID. Mark as Pushed so it isn't
-                    // re-pushed, but warn that it can't be reverted through the app.
-                    eprintln!(
-                        "⚠️  Worklog for '{}' was pushed to Jira but no worklog ID was returned. \
-                        It cannot be reverted through wtf — delete it manually in Jira if needed.",
-                        wl.issue_id
-                    );
-                    wl.status = Pushed;
-                    LocalWorklogService::production().save_local_worklog(wl.clone());
-                    local_worklogs_id.push(wl.id);
+            }
+        }
+        if let Some(issue) = matches.get_one::<String>("issue") {
+            task = task.with_issue(&Config::load().unwrap_or_default().expand_issue_alias(issue));
+        }
+        if let Some(sprint_id) = matches.get_one::<String>("sprint") {
+            match SprintService::production().get_sprint(sprint_id) {
+                Ok(Some(Sprint {
+                    start: Some(start),
+                    end: Some(end),
+                    ..
+                })) => task = task.with_range(start.date_naive(), end.date_naive()),
+                Ok(_) => {
+                    exit::fail(format!("Sprint '{}' not found or has no dates", sprint_id));
+                    return;
+                }
+                Err(e) => {
+                    exit::fail(e);
+                    return;
                 }
-                Err(err) => eprintln!("{:?}", err),
             }
         }
-        if !local_worklogs_id.is_empty() {
-            LocalWorklogService::production().historize(local_worklogs_id.clone());
+
+        if let Err(err) = task.execute().await {
+            exit::fail(err);
         }
     }
 
     fn clap_command(&self) -> ClapCommand {
-        ClapCommand::new(self.name()).about("push worklog to jira")
+        ClapCommand::new(self.name())
+            .about("push worklog to jira")
+            .arg(
+                Arg::new("date")
+                    .long("date")
+                    .short('d')
+                    .value_name("YYYY-MM-DD")
+                    .help("Only push worklogs of this day"),
+            )
+            .arg(
+                Arg::new("issue")
+                    .long("issue")
+                    .short('i')
+                    .value_name("ISSUE_KEY")
+                    .add(issue_keys())
+                    .help("Only push worklogs of this issue"),
+            )
+            .arg(
+                Arg::new("sprint")
+                    .long("sprint")
+                    .short('s')
+                    .value_name("SPRINT_ID")
+                    .add(sprint_ids())
+                    .help("Only push worklogs within this sprint's dates"),
+            )
+            .arg(
+                Arg::new("include-created")
+                    .long("include-created")
+                    .action(ArgAction::SetTrue)
+                    .help("Also push worklogs that were created but not staged (only staged ones by default)"),
+            )
     }
 }
 
@@ -372,6 +501,21 @@ impl Command for LogHistoryCommand {
 
     async fn execute(&self, matches: &ArgMatches) {
         let history = LocalWorklogService::production().get_history();
+        if OutputFormat::from_matches(matches).is_json() {
+            let entries: Vec<_> = history
+                .iter()
+                .map(|entry| HistoryListEntry {
+                    history: entry,
+                    worklogs: entry
+                        .local_worklogs_id
+                        .iter()
+                        .filter_map(|wid| LocalWorklogService::production().get_worklog(wid))
+                        .collect(),
+                })
+                .collect();
+            print_json(&entries);
+            return;
+        }
         if history.is_empty() {
             println!("No worklogs in history");
         }
@@ -381,7 +525,7 @@ impl Command for LogHistoryCommand {
                 .iter()
                 .filter_map(|wid| LocalWorklogService::production().get_worklog(wid))
                 .collect::<Vec<_>>();
-            worklogs.sort_by(|a, b| a.started.cmp(&b.started));
+            worklogs.sort_by_key(|a| a.started);
             let total_time = worklogs.iter().map(|w| w.time_spent_seconds).sum::<i64>();
             println!(
                 "[{:<8}] {:<16} - {:>3} worklogs - time logged: {:>5}",
@@ -417,6 +561,14 @@ impl Command for LogHistoryCommand {
     }
 }
 
+/// A push from the history with its worklogs, as printed by `--format json`
+#[derive(Serialize)]
+struct HistoryListEntry<'a> {
+    #[serde(flatten)]
+    history: &'a LocalWorklogHistory,
+    worklogs: Vec<LocalWorklog>,
+}
+
 pub struct LogCleanupCommand;
 
 #[async_trait]
@@ -0,0 +1,221 @@
+use crate::commands::{print_json, Command, OutputFormat};
+use crate::exit::{self, ExitStatus};
+use async_trait::async_trait;
+use chrono::{Local, TimeZone, Utc};
+use clap::{Arg, ArgAction, ArgMatches, Command as ClapCommand};
+use colored::Colorize;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Read};
+use wtf_lib::common::Common;
+use wtf_lib::config::Config;
+use wtf_lib::services::jira_service::IssueService;
+use wtf_lib::services::worklogs_service::LocalWorklogService;
+use wtf_lib::utils::worklog_import::{plan_import, rows_from_csv, rows_from_json, ImportPlan};
+
+/// `wtf worklog import`: create local worklogs in bulk from a CSV or JSON file
+pub struct LogImportCommand;
+
+#[derive(Serialize)]
+struct ImportReport {
+    dry_run: bool,
+    imported: Vec<ImportedWorklog>,
+    errors: Vec<ImportError>,
+}
+
+#[derive(Serialize)]
+struct ImportedWorklog {
+    row: usize,
+    /// Local worklog ID, `None` on a dry run
+    id: Option<String>,
+    issue: String,
+    started: String,
+    time_spent_seconds: i64,
+}
+
+#[derive(Serialize)]
+struct ImportError {
+    row: usize,
+    message: String,
+}
+
+#[async_trait]
+impl Command for LogImportCommand {
+    fn name(&self) -> &'static str {
+        "import"
+    }
+
+    async fn execute(&self, matches: &ArgMatches) {
+        let file = matches.get_one::<String>("file").unwrap();
+        let dry_run = matches.get_flag("dry-run");
+        let strict = matches.get_flag("strict");
+
+        let text = match read_input(file) {
+            Ok(text) => text,
+            Err(e) => return exit::fail(format!("Failed to read {}: {}", file, e)),
+        };
+        let rows = if file.ends_with(".json") || text.trim_start().starts_with('[') {
+            rows_from_json(&text)
+        } else {
+            rows_from_csv(&text)
+        };
+        let rows = match rows {
+            Ok(rows) => rows,
+            Err(e) => return exit::fail(format!("Can't read {}: {}", file, e)),
+        };
+        if rows.is_empty() {
+            println!("No worklogs to import");
+            exit::report(ExitStatus::NothingToDo);
+            return;
+        }
+
+        let config = Config::load().unwrap_or_default();
+        let issues: HashMap<String, String> = IssueService::production()
+            .get_all_issues()
+            .into_iter()
+            .map(|issue| (issue.key.to_uppercase(), issue.key))
+            .collect();
+        let hours_per_day = config.worklog.hours_per_day();
+        let plan = plan_import(rows, hours_per_day, Local::now().date_naive(), |key| {
+            issues
+                .get(&config.expand_issue_alias(key).to_uppercase())
+                .cloned()
+        });
+
+        // In strict mode a single bad row cancels the whole import
+        let create = !dry_run && (!strict || plan.errors.is_empty());
+        let report = import(&plan, create);
+
+        if OutputFormat::from_matches(matches).is_json() {
+            print_json(&report);
+        } else {
+            print_report(&report, create, strict);
+        }
+        if !plan.errors.is_empty() {
+            exit::report(if report.imported.is_empty() || !create {
+                ExitStatus::Failure
+            } else {
+                ExitStatus::PartialFailure
+            });
+        }
+    }
+
+    fn clap_command(&self) -> ClapCommand {
+        ClapCommand::new(self.name())
+            .about("Create worklogs in bulk from a CSV or JSON file")
+            .long_about(
+                "Create local worklogs in bulk from a CSV or JSON file, then push them with \
+                 `wtf worklog push --include-created`.\n\n\
+                 CSV files need a header naming the columns: date, issue, hours or duration, \
+                 and optionally start (HH:MM) and comment. Other columns are ignored. JSON \
+                 files are an array of objects with the same fields. Rows without a start \
+                 time are placed one after the other from 09:00. Invalid rows are reported \
+                 with their line number and skipped.",
+            )
+            .arg(
+                Arg::new("file")
+                    .value_name("FILE")
+                    .default_value("-")
+                    .help("CSV or JSON file, or - to read stdin"),
+            )
+            .arg(
+                Arg::new("dry-run")
+                    .long("dry-run")
+                    .action(ArgAction::SetTrue)
+                    .help("Validate the rows without creating anything"),
+            )
+            .arg(
+                Arg::new("strict")
+                    .long("strict")
+                    .action(ArgAction::SetTrue)
+                    .help("Create nothing if any row is invalid"),
+            )
+    }
+}
+
+fn read_input(file: &str) -> io::Result<String> {
+    if file == "-" {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        Ok(text)
+    } else {
+        fs::read_to_string(file)
+    }
+}
+
+fn import(plan: &ImportPlan, create: bool) -> ImportReport {
+    let service = LocalWorklogService::production();
+    let imported = plan
+        .entries
+        .iter()
+        .map(|entry| {
+            // A start inside a DST gap doesn't exist locally; read it as UTC instead
+            let started = Local
+                .from_local_datetime(&entry.started)
+                .earliest()
+                .map(|started| started.with_timezone(&Utc))
+                .unwrap_or_else(|| Utc.from_utc_datetime(&entry.started));
+            let id = create.then(|| {
+                service
+                    .create_new_local_worklogs(
+                        started,
+                        entry.seconds,
+                        &entry.issue,
+                        entry.comment.as_deref(),
+                        None,
+                    )
+                    .id
+            });
+            ImportedWorklog {
+                row: entry.row,
+                id,
+                issue: entry.issue.clone(),
+                started: entry.started.format("%Y-%m-%d %H:%M").to_string(),
+                time_spent_seconds: entry.seconds,
+            }
+        })
+        .collect();
+    ImportReport {
+        dry_run: !create,
+        imported,
+        errors: plan
+            .errors
+            .iter()
+            .map(|e| ImportError {
+                row: e.row,
+                message: e.message.clone(),
+            })
+            .collect(),
+    }
+}
+
+fn print_report(report: &ImportReport, created: bool, strict: bool) {
+    for error in &report.errors {
+        eprintln!("❌ row {}: {}", error.row, error.message);
+    }
+    let total: i64 = report.imported.iter().map(|w| w.time_spent_seconds).sum();
+    let summary = format!(
+        "{} worklog(s), {}",
+        report.imported.len(),
+        Common::readable_time_spent(total)
+    );
+    if report.imported.is_empty() {
+        println!("Nothing imported");
+    } else if created {
+        println!(
+            "✅ Imported {} (run `wtf worklog push --include-created` to send them)",
+            summary.bold()
+        );
+    } else if strict && !report.errors.is_empty() {
+        println!(
+            "Nothing imported: {} invalid row(s) with --strict",
+            report.errors.len()
+        );
+    } else {
+        println!("Would import {}", summary.bold());
+    }
+    if !report.errors.is_empty() {
+        println!("{} row(s) skipped", report.errors.len());
+    }
+}
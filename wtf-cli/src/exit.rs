@@ -0,0 +1,101 @@
+//! Process exit codes for scripted use (cron, CI). Commands and tasks report how things
+//! went; `main` exits with the most severe status reported.
+
+use std::fmt::Display;
+use std::sync::atomic::{AtomicI32, Ordering};
+
+/// Stable exit codes, ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ExitStatus {
+    Ok,
+    /// Nothing to fetch or push (no followed sprint, no staged worklog)
+    NothingToDo,
+    /// Some of the work failed, the rest went through
+    PartialFailure,
+    Failure,
+    /// Jira rejected the credentials (HTTP 401/403)
+    AuthFailure,
+}
+
+impl ExitStatus {
+    pub fn code(self) -> i32 {
+        match self {
+            ExitStatus::Ok => 0,
+            ExitStatus::Failure => 1,
+            ExitStatus::AuthFailure => 2,
+            ExitStatus::PartialFailure => 3,
+            ExitStatus::NothingToDo => 4,
+        }
+    }
+
+    fn from_code(code: i32) -> Self {
+        match code {
+            1 => ExitStatus::Failure,
+            2 => ExitStatus::AuthFailure,
+            3 => ExitStatus::PartialFailure,
+            4 => ExitStatus::NothingToDo,
+            _ => ExitStatus::Ok,
+        }
+    }
+
+    /// `Failure`, or `AuthFailure` when the error message carries a 401/403 status
+    pub fn of_error(message: &str) -> Self {
+        if message.contains("401") || message.contains("403") {
+            ExitStatus::AuthFailure
+        } else {
+            ExitStatus::Failure
+        }
+    }
+}
+
+static EXIT_STATUS: AtomicI32 = AtomicI32::new(0);
+
+/// Record an outcome; the most severe one wins
+pub fn report(status: ExitStatus) {
+    let _ = EXIT_STATUS.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |current| {
+        (status > ExitStatus::from_code(current)).then_some(status.code())
+    });
+}
+
+/// Print an error (even in quiet mode) and record it as a failure
+pub fn fail(error: impl Display) {
+    let message = error.to_string();
+    eprintln!("❌ {}", message);
+    report(ExitStatus::of_error(&message));
+}
+
+pub fn status() -> ExitStatus {
+    ExitStatus::from_code(EXIT_STATUS.load(Ordering::Relaxed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auth_errors_are_recognised() {
+        assert_eq!(
+            ExitStatus::of_error("API error: 401 Unauthorized"),
+            ExitStatus::AuthFailure
+        );
+        assert_eq!(
+            ExitStatus::of_error("Timeout while fetching Sprint 4"),
+            ExitStatus::Failure
+        );
+    }
+
+    #[test]
+    fn test_codes_round_trip_and_severity_order() {
+        for status in [
+            ExitStatus::Ok,
+            ExitStatus::NothingToDo,
+            ExitStatus::PartialFailure,
+            ExitStatus::Failure,
+            ExitStatus::AuthFailure,
+        ] {
+            assert_eq!(ExitStatus::from_code(status.code()), status);
+        }
+        assert!(ExitStatus::AuthFailure > ExitStatus::PartialFailure);
+        assert!(ExitStatus::PartialFailure > ExitStatus::NothingToDo);
+    }
+}
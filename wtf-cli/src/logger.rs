@@ -16,6 +16,18 @@ pub fn is_debug_enabled() -> bool {
     DEBUG_MODE.load(Ordering::Relaxed)
 }
 
+/// Global quiet flag: only warnings and errors are logged
+static QUIET_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Enable quiet mode (`--quiet`)
+pub fn enable_quiet() {
+    QUIET_MODE.store(true, Ordering::Relaxed);
+}
+
+pub fn is_quiet() -> bool {
+    QUIET_MODE.load(Ordering::Relaxed)
+}
+
 /// A logger that can output to different targets (stdout or collect for TUI)
 pub trait Logger: Send + Sync {
     fn log(&self, message: String);
@@ -30,6 +42,54 @@ impl Logger for StdoutLogger {
     }
 }
 
+/// Severity of a collected message, derived from the prefix the logging helpers add
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    #[default]
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    pub fn of(message: &str) -> Self {
+        let message = message.trim_start();
+        if message.starts_with("DEBUG:") || message.starts_with('🔍') || message.starts_with('🔬')
+        {
+            LogLevel::Debug
+        } else if message.starts_with('❌') {
+            LogLevel::Error
+        } else if message.starts_with('⚠') {
+            LogLevel::Warn
+        } else {
+            LogLevel::Info
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogLevel::Debug => "debug",
+            LogLevel::Info => "info",
+            LogLevel::Warn => "warn",
+            LogLevel::Error => "error",
+        }
+    }
+
+    /// Next minimum level when cycling the filter
+    pub fn next(self) -> Self {
+        match self {
+            LogLevel::Debug => LogLevel::Info,
+            LogLevel::Info => LogLevel::Warn,
+            LogLevel::Warn => LogLevel::Error,
+            LogLevel::Error => LogLevel::Debug,
+        }
+    }
+}
+
+/// Messages kept by the collecting logger
+const MAX_COLLECTED_MESSAGES: usize = 1000;
+
 /// Collecting logger for TUI mode - stores messages in memory
 pub struct CollectingLogger {
     messages: Arc<Mutex<Vec<String>>>,
@@ -46,7 +106,6 @@ impl CollectingLogger {
         self.messages.lock().unwrap().clone()
     }
 
-    #[allow(dead_code)]
     pub fn clear(&self) {
         self.messages.lock().unwrap().clear();
     }
@@ -56,8 +115,8 @@ impl Logger for CollectingLogger {
     fn log(&self, message: String) {
         let mut messages = self.messages.lock().unwrap();
         messages.push(message);
-        // Keep only last 100 messages to avoid memory growth
-        if messages.len() > 100 {
+        // Keep only the latest messages to avoid memory growth
+        if messages.len() > MAX_COLLECTED_MESSAGES {
             messages.remove(0);
         }
     }
@@ -73,6 +132,9 @@ pub fn init_logger(logger: Arc<dyn Logger>) {
 
 /// Log a message using the global logger
 pub fn log(message: String) {
+    if is_quiet() && LogLevel::of(&message) < LogLevel::Warn {
+        return;
+    }
     if let Some(logger) = GLOBAL_LOGGER.get() {
         logger.log(message);
     } else {
@@ -183,3 +245,18 @@ pub fn init_logger_with_log_bridge(logger: Arc<dyn Logger>) {
     };
     log::set_max_level(level);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_level_from_prefix() {
+        assert_eq!(LogLevel::of("DEBUG: fetching"), LogLevel::Debug);
+        assert_eq!(LogLevel::of("🔍 request sent"), LogLevel::Debug);
+        assert_eq!(LogLevel::of("❌ Failed to push"), LogLevel::Error);
+        assert_eq!(LogLevel::of("⚠️  Token expires soon"), LogLevel::Warn);
+        assert_eq!(LogLevel::of("✓ Synced 3 sprints"), LogLevel::Info);
+        assert!(LogLevel::Warn >= LogLevel::Info);
+    }
+}
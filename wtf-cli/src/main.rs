@@ -1,19 +1,15 @@
-use crate::commands::board::BoardCommand;
-use crate::commands::config::ConfigCommand;
-use crate::commands::fetch::FetchCommand;
-use crate::commands::github::GitHubCommand;
-use crate::commands::google::GoogleCommand;
-use crate::commands::init::InitCommand;
-use crate::commands::issue::IssueCommand;
-use crate::commands::meeting::MeetingCommand;
-use crate::commands::sprint::SprintCommand;
 use crate::commands::tui::TuiCommand;
-use crate::commands::update::UpdateCommand;
-use crate::commands::worklog::LogCommand;
-use crate::commands::{Command, CommandRegistry};
+use crate::commands::Command;
+use clap_complete::CompleteEnv;
+use wtf_lib::client::{http_trace, offline};
+use wtf_lib::config::{active_profile, is_valid_profile_name, profiles, PROFILE_VAR};
+use wtf_lib::services::audit_service;
+use wtf_lib::utils::hooks;
 
 mod commands;
+mod exit;
 mod logger;
+mod progress;
 mod tasks;
 mod tui;
 
@@ -24,19 +20,11 @@ async fn main() {
         logger::enable_debug();
     }
 
-    let mut registry = CommandRegistry::new();
-    registry.register(TuiCommand);
-    registry.register(InitCommand);
-    registry.register(FetchCommand);
-    registry.register(SprintCommand);
-    registry.register(BoardCommand);
-    registry.register(IssueCommand);
-    registry.register(GitHubCommand);
-    registry.register(GoogleCommand);
-    registry.register(ConfigCommand);
-    registry.register(MeetingCommand);
-    registry.register(LogCommand);
-    registry.register(UpdateCommand);
+    let registry = commands::default_registry();
+    // Answers the completion scripts from `wtf completions` (COMPLETE=<shell> wtf ...)
+    CompleteEnv::with_factory(|| commands::build_app(&registry))
+        .var(commands::completions::COMPLETE_VAR)
+        .complete();
 
     let app = commands::build_app(&registry);
     let matches = app.get_matches();
@@ -45,20 +33,89 @@ async fn main() {
     if matches.get_flag("debug") {
         logger::enable_debug();
     }
+    if matches.get_flag("quiet") {
+        logger::enable_quiet();
+    }
+    if matches
+        .get_one::<String>("progress")
+        .is_some_and(|p| p == "json")
+    {
+        progress::enable_json();
+    }
+    if let Some(template) = matches.get_one::<String>("template") {
+        if let Err(e) = commands::set_template(template) {
+            eprintln!("❌ {}", e);
+            std::process::exit(exit::ExitStatus::Failure.code());
+        }
+    }
+    if matches.get_flag("offline") || offline::requested_by_env() {
+        offline::enable();
+    }
+    let trace_bodies = matches.get_flag("trace-http-bodies");
+    if matches.get_flag("trace-http") || trace_bodies {
+        http_trace::enable(trace_bodies);
+    }
 
     // Determine which command will run
     let command_name = matches.subcommand_name().unwrap_or(TuiCommand.name());
 
+    // Exported so that everything resolving paths, and child processes like the daemon's
+    // syncs, see the same profile
+    if let Some(profile) = matches.get_one::<String>("profile") {
+        std::env::set_var(PROFILE_VAR, profile);
+    }
+    if let Some(profile) = active_profile() {
+        if !is_valid_profile_name(&profile) {
+            eprintln!(
+                "❌ Invalid profile name '{}': use letters, digits, - and _",
+                profile
+            );
+            std::process::exit(exit::ExitStatus::Failure.code());
+        }
+        if command_name != "init" && !profiles().contains(&profile) {
+            eprintln!(
+                "❌ Profile '{}' doesn't exist, create it with `wtf --profile {} init`",
+                profile, profile
+            );
+            std::process::exit(exit::ExitStatus::Failure.code());
+        }
+    }
+
     // Initialize env_logger for CLI commands (not for TUI)
     // TUI initializes its own log bridge in Tui::new()
     if command_name != TuiCommand.name() {
-        let _ = env_logger::try_init();
+        let mut builder = env_logger::Builder::from_default_env();
+        if http_trace::is_enabled() {
+            builder.filter_module(http_trace::TRACE_TARGET, log::LevelFilter::Info);
+        }
+        let _ = builder.try_init();
     }
 
+    audit_service::set_source(format!("cli: {}", command_path(&matches)));
     if let Some((name, sub_matches)) = matches.subcommand() {
         registry.execute(name, sub_matches).await;
     } else {
         // No subcommand provided, show tui
         registry.execute(TuiCommand.name(), &matches).await;
     }
+    hooks::wait_pending();
+
+    if command_name != TuiCommand.name() {
+        let code = exit::status().code();
+        if code != 0 {
+            std::process::exit(code);
+        }
+    }
+}
+
+/// `wtf` and the subcommands given, e.g. `wtf log add`
+fn command_path(matches: &clap::ArgMatches) -> String {
+    let mut path = String::from("wtf");
+    let mut matches = matches;
+    while let Some((name, sub_matches)) = matches.subcommand() {
+        path.push(' ');
+        path.push_str(name);
+        matches = sub_matches;
+    }
+    path
 }
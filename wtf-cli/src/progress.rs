@@ -0,0 +1,116 @@
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use serde::Serialize;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+/// Global JSON progress flag (`--progress json`)
+static JSON_PROGRESS: AtomicBool = AtomicBool::new(false);
+
+/// Report progress as NDJSON events on stderr instead of drawing bars
+pub fn enable_json() {
+    JSON_PROGRESS.store(true, Ordering::Relaxed);
+}
+
+pub fn is_json() -> bool {
+    JSON_PROGRESS.load(Ordering::Relaxed)
+}
+
+/// One line of `--progress json` output
+#[derive(Debug, Serialize, PartialEq)]
+struct ProgressEvent<'a> {
+    step: &'a str,
+    current: u64,
+    total: u64,
+    message: Option<&'a str>,
+}
+
+/// Print a progress event of `step` on stderr, only with `--progress json`
+pub fn event(step: &str, current: u64, total: u64, message: Option<&str>) {
+    if !is_json() {
+        return;
+    }
+    let event = ProgressEvent {
+        step,
+        current,
+        total,
+        message,
+    };
+    if let Ok(line) = serde_json::to_string(&event) {
+        // One write per line so events from concurrent tasks don't interleave
+        let _ = writeln!(std::io::stderr().lock(), "{}", line);
+    }
+}
+
+/// Progress of one step of a long-running command: a bar in `mp`, or events on stderr
+/// with `--progress json`
+#[derive(Clone)]
+pub struct StepProgress {
+    step: String,
+    bar: ProgressBar,
+}
+
+impl StepProgress {
+    pub fn new(mp: &MultiProgress, step: &str, total: u64, style: ProgressStyle) -> Self {
+        let bar = if is_json() {
+            ProgressBar::hidden()
+        } else {
+            mp.add(ProgressBar::new(total))
+        };
+        bar.set_length(total);
+        bar.set_style(style);
+        Self {
+            step: step.to_string(),
+            bar,
+        }
+    }
+
+    pub fn inc(&self, delta: u64) {
+        self.bar.inc(delta);
+        self.emit();
+    }
+
+    pub fn set_message(&self, message: String) {
+        self.bar.set_message(message);
+        self.emit();
+    }
+
+    pub fn enable_steady_tick(&self, interval: Duration) {
+        if !is_json() {
+            self.bar.enable_steady_tick(interval);
+        }
+    }
+
+    pub fn finish_and_clear(&self) {
+        self.bar.finish_and_clear();
+    }
+
+    fn emit(&self) {
+        let message = self.bar.message();
+        event(
+            &self.step,
+            self.bar.position(),
+            self.bar.length().unwrap_or_default(),
+            Some(message.as_str()).filter(|m| !m.is_empty()),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_serializes_as_one_line() {
+        let event = ProgressEvent {
+            step: "worklogs",
+            current: 2,
+            total: 5,
+            message: Some("Fetching Sprint 12"),
+        };
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"step":"worklogs","current":2,"total":5,"message":"Fetching Sprint 12"}"#
+        );
+    }
+}
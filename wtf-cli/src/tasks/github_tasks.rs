@@ -1,36 +1,76 @@
+use crate::commands::{print_json, OutputFormat};
+use crate::exit::{self, ExitStatus};
 use crate::logger;
-use crate::tasks::Task;
-use chrono::NaiveDate;
+use crate::tasks::{ensure_online, Task};
+use chrono::{DateTime, NaiveDate, Utc};
 use std::error::Error;
+use wtf_lib::config::Config;
+use wtf_lib::models::data::SyncSource;
 use wtf_lib::services::github_service::GitHubService;
 use wtf_lib::services::jira_service::{IssueService, JiraService};
+use wtf_lib::services::sync_status_service::SyncStatusService;
 use wtf_lib::services::worklogs_service::LocalWorklogService;
 
-pub struct FetchGithubEventsTask;
+pub struct FetchGithubEventsTask {
+    range: Option<(DateTime<Utc>, DateTime<Utc>)>,
+    full: bool,
+}
 
 impl FetchGithubEventsTask {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            range: None,
+            full: false,
+        }
+    }
+
+    /// Fetch an arbitrary period instead of the followed sprints' dates
+    pub fn between(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self {
+            range: Some((start, end)),
+            full: false,
+        }
+    }
+
+    /// Read again the commits earlier fetches already stored
+    pub fn full(mut self, full: bool) -> Self {
+        self.full = full;
+        self
     }
 }
 
 impl Task for FetchGithubEventsTask {
     async fn execute(&self) -> Result<(), Box<dyn Error>> {
+        ensure_online("fetching GitHub events")?;
         // Check if GitHub CLI is configured
         if !GitHubService::is_configured() {
             logger::log("⚠️  GitHub: CLI not installed or configured (skipping)".to_string());
+            exit::report(ExitStatus::NothingToDo);
             return Ok(());
         }
 
-        // Get followed sprints
-        let sprints = JiraService::production().get_followed_sprint();
-        if sprints.is_empty() {
-            return Ok(());
+        if self.full {
+            GitHubService::production().reset_cursors()?;
         }
 
         // Sync events and sessions to database
-        let (events_saved, sessions_saved) =
-            GitHubService::production().sync_events_for_sprints(&sprints)?;
+        let (events_saved, sessions_saved) = match self.range {
+            Some((start, end)) => {
+                GitHubService::production()
+                    .sync_events_between(start, end)
+                    .await?
+            }
+            None => {
+                let sprints = JiraService::production().get_followed_sprint();
+                if sprints.is_empty() {
+                    exit::report(ExitStatus::NothingToDo);
+                    return Ok(());
+                }
+                GitHubService::production()
+                    .sync_events_for_sprints(&sprints)
+                    .await?
+            }
+        };
 
         if events_saved == 0 {
             logger::log("✅ GitHub: no new events found".to_string());
@@ -40,6 +80,7 @@ impl Task for FetchGithubEventsTask {
                 events_saved, sessions_saved
             ));
         }
+        SyncStatusService::production().record(SyncSource::GitHub);
 
         Ok(())
     }
@@ -71,32 +112,38 @@ impl Task for LogGithubEventsTask {
         }
 
         // Fetch events
-        let events = GitHubService::fetch_events_for_sprints(&sprints)?;
+        let events = GitHubService::fetch_events_for_sprints(&sprints).await?;
 
         if events.is_empty() {
             logger::log("No GitHub events found.".to_string());
             return Ok(());
         }
 
+        let config = Config::load().unwrap_or_default();
+        let worklog = &config.worklog;
+
         // Calculate work sessions
-        let sessions_by_day = GitHubService::calculate_work_sessions(&events);
+        let sessions_by_day = GitHubService::calculate_work_sessions(&events, &config.github);
 
         let mut worklogs_created = 0;
         let mut worklogs_skipped = 0;
 
         for (_day, sessions) in sessions_by_day.iter() {
             for session in sessions {
-                // Skip if no Jira issue found
-                if session.jira_issues.is_empty() {
+                // Without a Jira issue, fall back to the repo's in github.repo_issues
+                let Some(issue_id) = session
+                    .jira_issues
+                    .first()
+                    .cloned()
+                    .or_else(|| config.github_default_issue(&session.repo))
+                else {
                     logger::log(format!(
                         "⚠️  Skipping session (no Jira issue): {} on {}",
                         session.description, session.repo
                     ));
                     worklogs_skipped += 1;
                     continue;
-                }
-
-                let issue_id = session.jira_issues[0].clone();
+                };
 
                 // Check if issue exists in database
                 if IssueService::production().get_by_key(&issue_id).is_none() {
@@ -108,17 +155,19 @@ impl Task for LogGithubEventsTask {
                     continue;
                 }
 
-                // Create worklog using the service method
+                // Create worklog using the service method, within the working window
                 let comment = session.description.clone();
+                let (started, seconds) = worklog
+                    .clamp_to_workday(session.start_time, session.duration_seconds(&config.github));
                 LocalWorklogService::production().create_new_local_worklogs(
-                    session.start_time,
-                    session.duration_seconds(),
+                    started,
+                    seconds,
                     &issue_id,
                     Some(&comment),
                     None, // No meeting_id for GitHub events
                 );
 
-                let hours = session.duration_seconds() as f64 / 3600.0;
+                let hours = seconds as f64 / 3600.0;
                 logger::log(format!(
                     "✅ Created worklog: {} - {:.1}h - {}",
                     issue_id, hours, session.description
@@ -138,16 +187,55 @@ impl Task for LogGithubEventsTask {
     }
 }
 
+pub struct RebuildGithubSessionsTask {
+    discard_edits: bool,
+}
+
+impl RebuildGithubSessionsTask {
+    pub fn new() -> Self {
+        Self {
+            discard_edits: false,
+        }
+    }
+
+    /// Rebuild the sessions split, trimmed or merged by hand too
+    pub fn discard_edits(mut self, discard_edits: bool) -> Self {
+        self.discard_edits = discard_edits;
+        self
+    }
+}
+
+impl Task for RebuildGithubSessionsTask {
+    async fn execute(&self) -> Result<(), Box<dyn Error>> {
+        let sessions = GitHubService::production().rebuild_sessions(self.discard_edits)?;
+        if sessions == 0 {
+            logger::log("No GitHub events found in database.".to_string());
+            logger::log("Use 'wtf github fetch' to fetch events first.".to_string());
+            exit::report(ExitStatus::NothingToDo);
+        } else {
+            logger::log(format!("✅ GitHub: {} sessions rebuilt", sessions));
+        }
+        Ok(())
+    }
+}
+
 pub struct ShowGithubSessionsTask {
     date_filter: Option<String>,
+    format: OutputFormat,
 }
 
 impl ShowGithubSessionsTask {
     pub fn new(date: Option<&str>) -> Self {
         Self {
             date_filter: date.map(|s| s.to_string()),
+            format: OutputFormat::Table,
         }
     }
+
+    pub fn with_format(mut self, format: OutputFormat) -> Self {
+        self.format = format;
+        self
+    }
 }
 
 impl Task for ShowGithubSessionsTask {
@@ -160,6 +248,11 @@ impl Task for ShowGithubSessionsTask {
             GitHubService::production().get_all_sessions()?
         };
 
+        if self.format.is_json() {
+            print_json(&sessions);
+            return Ok(());
+        }
+
         if sessions.is_empty() {
             logger::log("No GitHub sessions found in database.".to_string());
             logger::log("Use 'wtf github fetch' to fetch events first.".to_string());
@@ -1,117 +1,235 @@
 use crate::debug;
-use crate::tasks::google_tasks::GoogleEvent::{Absence, Meeting, Unknown};
-use crate::tasks::Task;
+use crate::logger;
+use crate::tasks::google_tasks::GoogleEvent::{Absence, Meeting, TimeOff, Unknown};
+use crate::tasks::{ensure_online, Task};
 use chrono::{DateTime, NaiveTime, TimeZone, Utc};
-use google_calendar3::api::Event;
-use std::collections::HashMap;
+use google_calendar3::api::{Event, Events};
+use inquire::Confirm;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
+use std::time::{Duration, Instant};
+use wtf_lib::client::http_trace;
+use wtf_lib::client::retry::RetryDelegate;
+use wtf_lib::config::{Config, GoogleConfig};
+use wtf_lib::models::data::SyncSource;
 use wtf_lib::models::data::{Absence as AbsenceEntity, Attendee, Meeting as MeetingEntity};
 use wtf_lib::services::google_service::GoogleService;
 use wtf_lib::services::meetings_service::{AbsenceService, MeetingsService};
+use wtf_lib::services::sync_status_service::SyncStatusService;
+use wtf_lib::utils::meetings::autolink_key;
 
 pub struct FetchGoogleCalendarTask {
     start: DateTime<Utc>,
     end: DateTime<Utc>,
+    confirm_time_off: bool,
 }
 
 impl FetchGoogleCalendarTask {
     pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
-        Self { start, end }
+        Self {
+            start,
+            end,
+            confirm_time_off: false,
+        }
+    }
+
+    /// Ask before recording each new out-of-office event as an absence; without it they
+    /// are only counted
+    pub fn with_time_off_confirmation(mut self, confirm: bool) -> Self {
+        self.confirm_time_off = confirm;
+        self
+    }
+
+    /// Record the confirmed time off as absences and remember the declined ones; returns
+    /// how many were recorded
+    fn record_time_off(
+        &self,
+        time_off: Vec<(AbsenceEntity, String)>,
+    ) -> Result<usize, Box<dyn Error>> {
+        if time_off.is_empty() {
+            return Ok(0);
+        }
+        if !self.confirm_time_off {
+            logger::log(format!(
+                "🏖️  {} out-of-office events are not absences yet, `wtf fetch google` offers them",
+                time_off.len()
+            ));
+            return Ok(0);
+        }
+        let service = MeetingsService::production();
+        let mut recorded = 0;
+        for (absence, title) in time_off {
+            let question = format!(
+                "Record '{}' ({} to {}) as an absence?",
+                title,
+                absence.start.format("%Y-%m-%d"),
+                absence.end.format("%Y-%m-%d")
+            );
+            if Confirm::new(&question).with_default(true).prompt()? {
+                service.save_absence(&absence);
+                recorded += 1;
+            } else {
+                service.dismiss_absence(&absence.id);
+            }
+        }
+        Ok(recorded)
     }
 }
 
 impl Task for FetchGoogleCalendarTask {
     async fn execute(&self) -> Result<(), Box<dyn Error>> {
         debug!("fetch google calendar task");
+        ensure_online("fetching Google Calendar events")?;
         let hub = GoogleService::get_hub().await?;
+        let config = Config::load().unwrap_or_default();
+        let calendars = config
+            .google
+            .as_ref()
+            .map(|google| google.calendars())
+            .unwrap_or_else(|| vec!["primary".to_string()]);
+        let untracked_ids = MeetingsService::production().get_all_untracked_ids();
+        let google = config.google.clone().unwrap_or_default();
+
+        let mut count = 0;
+        let mut meeting_count = 0;
+        let mut absence_count = 0;
+        let mut error_count = 0;
+        let mut fetched_meeting_ids = HashSet::new();
+        let mut time_off = Vec::new();
+        let mut time_off_ids = HashSet::new();
 
-        let results = hub
-            .events()
-            .list("primary")
-            .time_min(self.start)
-            .time_max(self.end)
-            .single_events(true) // Expand recurring events to individual instances
-            .max_results(1000)
-            .doit()
-            .await;
-
-        match results {
-            Ok((_, events)) => {
-                if let Some(items) = events.items {
-                    let count = items.len();
-                    let mut meeting_count = 0;
-                    let mut absence_count = 0;
-                    let mut error_count = 0;
-                    let mut fetched_meeting_ids = std::collections::HashSet::new();
-
-                    for event in items {
-                        match GoogleEvent::from_google(event.clone()) {
-                            Absence(absence) => {
-                                AbsenceService::save_absence(&absence);
-                                absence_count += 1;
-                            }
-                            Meeting(meeting) => {
-                                fetched_meeting_ids.insert(meeting.id.clone());
-                                upsert_meetings(*meeting);
-                                meeting_count += 1;
-                            }
-                            Unknown => {
-                                debug!("Unknown event: {:?}", event);
-                                error_count += 1;
-                            }
+        for calendar in &calendars {
+            let started = Instant::now();
+            let results = hub
+                .events()
+                .list(calendar)
+                .time_min(self.start)
+                .time_max(self.end)
+                .single_events(true) // Expand recurring events to individual instances
+                .max_results(1000)
+                .delegate(&mut RetryDelegate::new())
+                .doit()
+                .await;
+            trace_events_call(calendar, self.start, self.end, &results, started.elapsed());
+
+            let items = match results {
+                Ok((_, events)) => events.items.unwrap_or_default(),
+                Err(e) => {
+                    return Err(format!(
+                        "Failed to retrieve the events of calendar '{}': {}",
+                        calendar, e
+                    )
+                    .into());
+                }
+            };
+            debug!("{} events in calendar '{}'", items.len(), calendar);
+            count += items.len();
+
+            for event in items {
+                match GoogleEvent::from_google(event.clone(), &google) {
+                    Absence(absence) => {
+                        AbsenceService::save_absence(&absence);
+                        absence_count += 1;
+                    }
+                    TimeOff(absence, title) => {
+                        if !MeetingsService::production().is_absence_known(&absence.id)
+                            && time_off_ids.insert(absence.id.clone())
+                        {
+                            time_off.push((absence, title));
                         }
                     }
-
-                    // Clean up meetings that no longer exist in Google Calendar
-                    let db_meetings = MeetingsService::production()
-                        .get_meetings_between_dates(self.start, self.end);
-                    debug!(
-                        "Found {} meetings in database for date range {} to {}",
-                        db_meetings.len(),
-                        self.start.format("%Y-%m-%d"),
-                        self.end.format("%Y-%m-%d")
-                    );
-                    debug!(
-                        "Fetched {} meeting IDs from Google Calendar",
-                        fetched_meeting_ids.len()
-                    );
-
-                    let mut removed_count = 0;
-                    for db_meeting in db_meetings {
-                        if !fetched_meeting_ids.contains(&db_meeting.id) {
-                            debug!(
-                                "Removing stale meeting: {} - {} (start: {})",
-                                db_meeting.id,
-                                db_meeting.title.as_deref().unwrap_or("Untitled"),
-                                db_meeting.start.format("%Y-%m-%d %H:%M")
-                            );
-                            MeetingsService::production().delete_meeting(&db_meeting.id);
-                            removed_count += 1;
-                        } else {
-                            debug!(
-                                "Keeping meeting: {} - {}",
-                                db_meeting.id,
-                                db_meeting.title.as_deref().unwrap_or("Untitled")
-                            );
+                    Meeting(mut meeting) => {
+                        // An invitation shared by two calendars keeps the first one
+                        if fetched_meeting_ids.insert(meeting.id.clone()) {
+                            meeting.calendar = Some(calendar.clone());
+                            upsert_meetings(*meeting, &config, &untracked_ids);
+                            meeting_count += 1;
                         }
                     }
-
-                    debug!("{count} Google Calendar events fetched ({meeting_count} meetings, {absence_count} absences, {error_count} skipped, {removed_count} removed)");
-                } else {
-                    debug!("No upcoming events found.");
+                    Unknown => {
+                        debug!("Unknown event: {:?}", event);
+                        error_count += 1;
+                    }
                 }
             }
-            Err(e) => {
-                return Err(format!("Failed to retrieve Google Calendar events: {}", e).into());
+        }
+
+        absence_count += self.record_time_off(time_off)?;
+
+        // Clean up meetings that no longer exist in Google Calendar
+        let db_meetings =
+            MeetingsService::production().get_meetings_between_dates(self.start, self.end);
+        debug!(
+            "Found {} meetings in database for date range {} to {}",
+            db_meetings.len(),
+            self.start.format("%Y-%m-%d"),
+            self.end.format("%Y-%m-%d")
+        );
+        debug!(
+            "Fetched {} meeting IDs from Google Calendar",
+            fetched_meeting_ids.len()
+        );
+
+        let mut removed_count = 0;
+        for db_meeting in db_meetings {
+            if !fetched_meeting_ids.contains(&db_meeting.id) {
+                debug!(
+                    "Removing stale meeting: {} - {} (start: {})",
+                    db_meeting.id,
+                    db_meeting.title.as_deref().unwrap_or("Untitled"),
+                    db_meeting.start.format("%Y-%m-%d %H:%M")
+                );
+                MeetingsService::production().delete_meeting(&db_meeting.id);
+                removed_count += 1;
+            } else {
+                debug!(
+                    "Keeping meeting: {} - {}",
+                    db_meeting.id,
+                    db_meeting.title.as_deref().unwrap_or("Untitled")
+                );
             }
         }
+
+        debug!("{count} Google Calendar events fetched from {} calendar(s) ({meeting_count} meetings, {absence_count} absences, {error_count} skipped, {removed_count} removed)", calendars.len());
+        SyncStatusService::production().record(SyncSource::Google);
         debug!("Google Calendar Task Finished.");
         Ok(())
     }
 }
 
-fn upsert_meetings(mut meeting: MeetingEntity) {
-    match MeetingsService::production().get_meeting_by_id(meeting.id.to_string()) {
+/// The calendar hub makes its own HTTP calls: trace them from their result
+fn trace_events_call(
+    calendar: &str,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    results: &google_calendar3::Result<(google_calendar3::common::Response, Events)>,
+    elapsed: Duration,
+) {
+    if !http_trace::is_enabled() {
+        return;
+    }
+    let url = format!(
+        "https://www.googleapis.com/calendar/v3/calendars/{}/events?timeMin={}&timeMax={}&singleEvents=true&maxResults=1000",
+        calendar,
+        start.to_rfc3339(),
+        end.to_rfc3339()
+    );
+    let outcome = match results {
+        Ok((response, _)) => response.status().to_string(),
+        Err(e) => e.to_string(),
+    };
+    http_trace::record("GET", &url, &outcome, elapsed);
+    if let Ok((_, events)) = results {
+        http_trace::record_body("←", &serde_json::to_string(events).unwrap_or_default());
+    }
+}
+
+/// Save a fetched meeting, keeping its link; one never linked gets the issue of its
+/// linked series, or of the first `[meeting_rules]` rule it matches
+fn upsert_meetings(mut meeting: MeetingEntity, config: &Config, untracked_ids: &HashSet<String>) {
+    let service = MeetingsService::production();
+    match service.get_meeting_by_id(meeting.id.to_string()) {
         Some(db_meeting) => {
             meeting.jira_link = db_meeting.jira_link;
         }
@@ -119,16 +237,39 @@ fn upsert_meetings(mut meeting: MeetingEntity) {
             debug!("No meeting with id: {}", meeting.id);
         }
     }
-    MeetingsService::production().save(&meeting);
+    if meeting.jira_link.is_none() && config.meetings.attends(&meeting) {
+        meeting.jira_link = meeting
+            .recurring_event_id
+            .as_deref()
+            .and_then(|series| service.series_link(series));
+        if let Some(issue) = &meeting.jira_link {
+            debug!("Meeting {} linked to {} with its series", meeting.id, issue);
+        }
+    }
+    if meeting.jira_link.is_none() {
+        // Only the rules apply here: the keys found in the text wait for the auto-link
+        // step, which knows which issues exist
+        meeting.jira_link = autolink_key(&meeting, config, untracked_ids, None, |_| false);
+        if let Some(issue) = &meeting.jira_link {
+            debug!(
+                "Meeting {} linked to {} by a meeting rule",
+                meeting.id, issue
+            );
+        }
+    }
+    service.save(&meeting);
 }
+
 pub enum GoogleEvent {
     Absence(AbsenceEntity),
+    /// An out-of-office or all-day time-off event, with its title: an absence once confirmed
+    TimeOff(AbsenceEntity, String),
     Meeting(Box<MeetingEntity>),
     Unknown,
 }
 
 impl GoogleEvent {
-    pub fn from_google(event: Event) -> Self {
+    pub fn from_google(event: Event, google: &GoogleConfig) -> Self {
         let title = event
             .summary
             .clone()
@@ -136,42 +277,16 @@ impl GoogleEvent {
 
         let title_lower = title.to_lowercase();
         if title_lower.contains("absence") || title_lower.contains("absent") {
-            let to_start = |edt: &google_calendar3::api::EventDateTime| -> Option<DateTime<Utc>> {
-                edt.date_time.or_else(|| {
-                    edt.date.and_then(|d| {
-                        Utc.from_local_datetime(&d.and_time(NaiveTime::MIN))
-                            .single()
-                    })
-                })
-            };
-            let to_end = |edt: &google_calendar3::api::EventDateTime| -> Option<DateTime<Utc>> {
-                edt.date_time.or_else(|| {
-                    edt.date.and_then(|d| {
-                        // Google all-day end date is exclusive (next day), so subtract one day
-                        let day = d.pred_opt().unwrap_or(d);
-                        Utc.from_local_datetime(&day.and_hms_opt(23, 59, 59).unwrap())
-                            .single()
-                    })
-                })
-            };
-            return if let (Some(id), Some(start_dt), Some(end_dt)) = (
-                event.id.clone(),
-                event.start.as_ref().and_then(to_start),
-                event.end.as_ref().and_then(to_end),
-            ) {
-                let absence = AbsenceEntity {
-                    id,
-                    start: start_dt,
-                    end: end_dt,
-                };
-                Absence(absence)
-            } else {
-                debug!(
-                    "Skipping Absence event '{}' - missing required fields (id, start, or end)",
-                    title
-                );
-                Unknown
-            };
+            return absence_of(&event, &title).map_or(Unknown, Absence);
+        }
+        let all_day = event
+            .start
+            .as_ref()
+            .is_some_and(|start| start.date_time.is_none() && start.date.is_some());
+        if event.event_type.as_deref() == Some("outOfOffice")
+            || (all_day && google.is_time_off(&title))
+        {
+            return absence_of(&event, &title).map_or(Unknown, |absence| TimeOff(absence, title));
         }
 
         // Try to parse as meeting
@@ -192,6 +307,45 @@ impl GoogleEvent {
     }
 }
 
+/// The days an absence event covers, all-day ones included
+fn absence_of(event: &Event, title: &str) -> Option<AbsenceEntity> {
+    let to_start = |edt: &google_calendar3::api::EventDateTime| -> Option<DateTime<Utc>> {
+        edt.date_time.or_else(|| {
+            edt.date.and_then(|d| {
+                Utc.from_local_datetime(&d.and_time(NaiveTime::MIN))
+                    .single()
+            })
+        })
+    };
+    let to_end = |edt: &google_calendar3::api::EventDateTime| -> Option<DateTime<Utc>> {
+        edt.date_time.or_else(|| {
+            edt.date.and_then(|d| {
+                // Google all-day end date is exclusive (next day), so subtract one day
+                let day = d.pred_opt().unwrap_or(d);
+                Utc.from_local_datetime(&day.and_hms_opt(23, 59, 59).unwrap())
+                    .single()
+            })
+        })
+    };
+    if let (Some(id), Some(start_dt), Some(end_dt)) = (
+        event.id.clone(),
+        event.start.as_ref().and_then(to_start),
+        event.end.as_ref().and_then(to_end),
+    ) {
+        Some(AbsenceEntity {
+            id,
+            start: start_dt,
+            end: end_dt,
+        })
+    } else {
+        debug!(
+            "Skipping Absence event '{}' - missing required fields (id, start, or end)",
+            title
+        );
+        None
+    }
+}
+
 fn from_google(event: Event) -> Option<wtf_lib::models::data::Meeting> {
     if let (Some(start), Some(end)) = (
         event.start.as_ref().and_then(|s| s.date_time),
@@ -213,6 +367,11 @@ fn from_google(event: Event) -> Option<wtf_lib::models::data::Meeting> {
 
         debug!("extended properties: {:?}", event.extended_properties);
 
+        let organizer = event
+            .organizer
+            .as_ref()
+            .and_then(|o| o.display_name.clone().or_else(|| o.email.clone()));
+        let conference_link = conference_link(&event);
         let id = event.id?;
 
         return Some(wtf_lib::models::data::Meeting {
@@ -227,7 +386,41 @@ fn from_google(event: Event) -> Option<wtf_lib::models::data::Meeting> {
             logs: HashMap::new(),
             my_response_status,
             color_id: event.color_id,
+            organizer,
+            conference_link,
+            calendar: None,
+            recurring_event_id: event.recurring_event_id,
+            event_type: event.event_type,
         });
     }
     None
 }
+
+/// Video call link: Meet link, then conference entry points, then a URL in location/description
+fn conference_link(event: &Event) -> Option<String> {
+    if let Some(link) = &event.hangout_link {
+        return Some(link.clone());
+    }
+    let entry_point = event
+        .conference_data
+        .as_ref()
+        .and_then(|c| c.entry_points.as_ref())
+        .and_then(|points| {
+            points
+                .iter()
+                .find(|p| p.entry_point_type.as_deref() == Some("video"))
+                .and_then(|p| p.uri.clone())
+        });
+    if entry_point.is_some() {
+        return entry_point;
+    }
+
+    let url_pattern = Regex::new(
+        r#"https://[\w.-]*(zoom\.us|teams\.microsoft\.com|meet\.google\.com)/[^\s<>"]+"#,
+    )
+    .ok()?;
+    [&event.location, &event.description]
+        .into_iter()
+        .flatten()
+        .find_map(|text| url_pattern.find(text).map(|m| m.as_str().to_string()))
+}
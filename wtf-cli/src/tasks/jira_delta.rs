@@ -0,0 +1,102 @@
+// Delta fetches from Jira: the watermark keys, and which worklog periods only need
+// Jira's changes since their previous fetch
+
+use chrono::{DateTime, Utc};
+use std::fmt;
+use wtf_lib::services::sync_status_service::SyncStatusService;
+
+/// A period of worklogs to fetch, with its label for the progress bar
+pub(super) type FetchPeriod = (String, DateTime<Utc>, DateTime<Utc>);
+
+/// Watermark key of the issues of a sprint, board or project
+pub(super) fn issues_watermark(scope: &str, id: impl fmt::Display) -> String {
+    format!("issues:{}:{}", scope, id)
+}
+
+/// Watermark key of the worklogs of a period
+pub(super) fn worklogs_watermark(start: DateTime<Utc>, end: DateTime<Utc>) -> String {
+    format!("worklogs:{}:{}", start.date_naive(), end.date_naive())
+}
+
+/// The worklog periods, split between a delta and full fetches
+pub(super) struct WorklogsDelta {
+    /// Periods fetched before, covered by Jira's changes since `since`
+    pub caught_up: Vec<FetchPeriod>,
+    /// Periods never fetched, fetched in full
+    pub full: Vec<FetchPeriod>,
+    /// The oldest watermark of the caught up periods, `None` without any
+    pub since: Option<DateTime<Utc>>,
+}
+
+impl WorklogsDelta {
+    pub(super) fn plan(sync_status: &SyncStatusService, periods: &[FetchPeriod]) -> Self {
+        let (caught_up, full): (Vec<_>, Vec<_>) =
+            periods.iter().cloned().partition(|(_, start, end)| {
+                sync_status
+                    .watermark(&worklogs_watermark(*start, *end))
+                    .is_some()
+            });
+        let since = caught_up
+            .iter()
+            .filter_map(|(_, start, end)| sync_status.watermark(&worklogs_watermark(*start, *end)))
+            .min();
+        Self {
+            caught_up,
+            full,
+            since,
+        }
+    }
+
+    /// Move the watermark of every caught up period once the changes are applied
+    pub(super) fn record_caught_up(
+        &self,
+        sync_status: &SyncStatusService,
+        fetch_started: DateTime<Utc>,
+    ) {
+        for (_, start, end) in &self.caught_up {
+            sync_status.record_watermark(&worklogs_watermark(*start, *end), fetch_started);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use wtf_lib::context::Context;
+
+    fn period(month: u32) -> FetchPeriod {
+        let start = Utc.with_ymd_and_hms(2026, month, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2026, month, 28, 23, 59, 59).unwrap();
+        (format!("month {}", month), start, end)
+    }
+
+    #[test]
+    fn test_only_periods_fetched_before_are_caught_up() {
+        let sync_status = SyncStatusService::new(&Context::temporary());
+        let (january, february, march) = (period(1), period(2), period(3));
+        let older = Utc.with_ymd_and_hms(2026, 3, 1, 8, 0, 0).unwrap();
+        let newer = Utc.with_ymd_and_hms(2026, 3, 2, 8, 0, 0).unwrap();
+        sync_status.record_watermark(&worklogs_watermark(january.1, january.2), newer);
+        sync_status.record_watermark(&worklogs_watermark(march.1, march.2), older);
+
+        let delta = WorklogsDelta::plan(
+            &sync_status,
+            &[january.clone(), february.clone(), march.clone()],
+        );
+
+        assert_eq!(delta.caught_up, vec![january, march.clone()]);
+        assert_eq!(delta.full, vec![february.clone()]);
+        assert_eq!(delta.since, Some(older));
+
+        delta.record_caught_up(&sync_status, newer);
+        assert_eq!(
+            sync_status.watermark(&worklogs_watermark(march.1, march.2)),
+            Some(newer)
+        );
+        assert_eq!(
+            sync_status.watermark(&worklogs_watermark(february.1, february.2)),
+            None
+        );
+    }
+}
@@ -1,14 +1,20 @@
+use crate::commands::{print_json, OutputFormat};
+use crate::exit::{self, ExitStatus};
 use crate::logger;
-use crate::tasks::Task;
+use crate::progress::StepProgress;
+use crate::tasks::jira_delta::{issues_watermark, worklogs_watermark, FetchPeriod, WorklogsDelta};
+use crate::tasks::{ensure_online, Task};
 use crate::tui::FetchStatus;
 use anyhow::Result;
-use chrono::{DateTime, Datelike, Months, NaiveDate, Utc, Weekday};
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
 use colored::Colorize;
 use futures::future::join_all;
-use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use futures::stream::{self, StreamExt};
+use indicatif::{MultiProgress, ProgressStyle};
 use inquire::{CustomUserError, Text};
 use log::{debug, info};
 use regex::Regex;
+use serde::Serialize;
 use std::error::Error;
 use std::fmt;
 use std::sync::mpsc::Sender;
@@ -19,12 +25,16 @@ use tabled::settings::{Alignment, Color, Modify, Style};
 use tabled::{Table, Tabled};
 use tokio::sync::Semaphore;
 use wtf_lib::client::jira_client::JiraClient;
-use wtf_lib::config::Config;
+use wtf_lib::client::paginated_issues::PaginatedIssues;
+use wtf_lib::client::rate_limit;
+use wtf_lib::config::{Config, WorklogConfig};
 use wtf_lib::models::data::SprintState::{Active, Closed, Future};
+use wtf_lib::models::data::SyncSource;
 use wtf_lib::models::data::{Absence, Board, BoardType, Issue, Sprint, Worklog};
-use wtf_lib::models::jira::{format_comment, JiraSprint};
+use wtf_lib::models::jira::{format_comment, JiraSprint, JiraWorklog};
 use wtf_lib::services::jira_service::{BoardService, IssueService, JiraService, SprintService};
 use wtf_lib::services::meetings_service::MeetingsService;
+use wtf_lib::services::sync_status_service::SyncStatusService;
 use wtf_lib::services::worklogs_service::WorklogsService;
 
 pub struct FetchJiraIssues {
@@ -62,55 +72,141 @@ impl FetchJiraIssues {
     }
 }
 
+/// Successes and failures of the independent requests a fetch is made of
+#[derive(Default)]
+struct FetchOutcome {
+    succeeded: usize,
+    failures: usize,
+    last_error: Option<String>,
+}
+
+impl FetchOutcome {
+    fn failed(&mut self, error: impl std::fmt::Display) {
+        self.failures += 1;
+        self.last_error = Some(error.to_string());
+    }
+
+    /// An error when every request failed; a partial failure is only reported, so that
+    /// the caller keeps what was fetched
+    fn into_result(self, what: &str) -> Result<(), Box<dyn Error>> {
+        match (self.failures, self.succeeded) {
+            (0, _) => Ok(()),
+            (_, 0) => Err(format!(
+                "Failed to fetch {}: {}",
+                what,
+                self.last_error.unwrap_or_default()
+            )
+            .into()),
+            (failures, _) => {
+                logger::log(format!(
+                    "⚠️  {} request(s) failed while fetching {}",
+                    failures, what
+                ));
+                exit::report(ExitStatus::PartialFailure);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FetchJiraIssues {
+    /// Report a sub-step through the TUI channel, when wired up
+    fn report(&self, done: usize, total: usize) {
+        if let Some((ref tx, ref label, step, total_steps)) = self.sub_tx {
+            let _ = tx.send(FetchStatus::Fetching(
+                label.clone(),
+                step,
+                total_steps,
+                Some((done, total)),
+            ));
+        }
+    }
+
+    /// Save the issues of `source` page by page as they arrive, so that a year of project
+    /// issues is never held in memory and the progress bar moves with every page.
+    /// Returns the number of issues saved.
+    async fn save_pages(
+        &self,
+        mut fetcher: PaginatedIssues<'_>,
+        source: &str,
+        sprint_ids: &[usize],
+        mp: &MultiProgress,
+    ) -> Result<usize, String> {
+        let total = fetcher.len();
+        let style = ProgressStyle::default_bar()
+            .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} {msg}")
+            .unwrap()
+            .progress_chars("#>-");
+        let bar = StepProgress::new(mp, "issues", total as u64, style);
+        bar.set_message(source.to_string());
+        let issue_service = IssueService::production();
+
+        let mut saved = 0;
+        let result = loop {
+            let page = match fetcher.next_page().await {
+                Ok(Some(page)) => page,
+                Ok(None) => break Ok(saved),
+                Err(e) => break Err(e.to_string()),
+            };
+            let count = page.len();
+            issue_service.save_all_issues(
+                page.into_iter()
+                    .map(|issue| Issue::from_jira(issue, sprint_ids.to_vec()))
+                    .collect(),
+            );
+            saved += count;
+            bar.inc(count as u64);
+            self.report(saved, total.max(saved));
+        };
+        bar.finish_and_clear();
+        result
+    }
+}
+
 impl Task for FetchJiraIssues {
     async fn execute(&self) -> Result<(), Box<dyn Error>> {
-        let jira_client = JiraClient::create();
+        ensure_online("fetching issues")?;
+        let config = Config::load().unwrap_or_default();
+        let jira_client = JiraClient::create().with_issue_scope(config.jira.scope_jql());
         let mp = match &self.multi_progress {
             None => MultiProgress::new(),
             Some(multi) => multi.clone(),
         };
 
-        // Emit a sub-progress update through the TUI channel (if wired up).
-        // Shows per-sprint/board progress — simple and honest (no estimated totals).
-        let emit = |done: usize, total: usize| {
-            if let Some((ref tx, ref label, step, total_steps)) = self.sub_tx {
-                let _ = tx.send(FetchStatus::Fetching(
-                    label.clone(),
-                    step,
-                    total_steps,
-                    Some((done, total)),
-                ));
-            }
-        };
-
-        let mut issues_to_store = Vec::new();
+        let mut total_saved = 0;
+        let mut outcome = FetchOutcome::default();
+        // Only the issues updated since the previous fetch of each sprint/board/project
+        let sync_status = SyncStatusService::production();
+        let fetch_started = Utc::now();
 
         for sprint in &self.sprints {
-            match jira_client.get_all_issues_v2(&sprint.id.to_string()).await {
-                Ok(issue_fetcher) => {
-                    let sprint_total = issue_fetcher.len();
-                    let mut sprint_done = 0usize;
-                    for issue in issue_fetcher {
-                        issues_to_store.push(Issue {
-                            key: issue.key.clone(),
-                            id: issue.id,
-                            created: issue.fields.created,
-                            status: issue.fields.status.name,
-                            summary: issue.fields.summary,
-                        });
-                        sprint_done += 1;
-                        emit(sprint_done, sprint_total);
-                    }
+            let watermark = issues_watermark("sprint", sprint.id);
+            let result = match jira_client
+                .get_all_issues_v2(&sprint.id.to_string(), sync_status.watermark(&watermark))
+                .await
+            {
+                Ok(fetcher) => {
+                    self.save_pages(fetcher, &sprint.name, &[sprint.id], &mp)
+                        .await
+                }
+                Err(e) => Err(e.to_string()),
+            };
+            match result {
+                Ok(saved) => {
                     logger::log(format!(
                         "✅ Sprint '{}': {} issues fetched",
-                        sprint.name, sprint_done
+                        sprint.name, saved
                     ));
+                    total_saved += saved;
+                    sync_status.record_watermark(&watermark, fetch_started);
+                    outcome.succeeded += 1;
                 }
                 Err(e) => {
                     logger::log(format!(
                         "⚠️  Sprint '{}': failed to fetch issues — {}",
                         sprint.name, e
                     ));
+                    outcome.failed(e);
                 }
             }
         }
@@ -120,101 +216,104 @@ impl Task for FetchJiraIssues {
             Err(e) => {
                 logger::log(format!("⚠️  Could not load followed boards: {}", e));
                 let _ = mp.clear();
-                IssueService::production().save_all_issues(issues_to_store);
                 return Ok(());
             }
         };
 
-        for board in boards
-            .iter()
+        // Boards are independent: fetch several at once, as many as calls are allowed
+        let start_date = Utc::now().checked_sub_months(Months::new(12));
+        let board_fetches = boards
+            .into_iter()
             .filter(|b| b.board_type == BoardType::Kanban || b.board_type == BoardType::Scrum)
-            .cloned()
-            .collect::<Vec<Board>>()
-        {
-            if let Some(project_name) = board.project_name {
-                let start_date = Utc::now().checked_sub_months(Months::new(12));
-                let fetch_result = match board.board_type {
-                    // For Scrum boards, only fetch backlog issues (not in any sprint);
-                    // sprint issues are already fetched above per sprint.
-                    BoardType::Scrum => {
-                        jira_client
-                            .get_project_backlog_issues(project_name.as_str(), start_date)
-                            .await
-                    }
-                    _ => {
-                        jira_client
-                            .get_project_issues(project_name.as_str(), start_date)
-                            .await
-                    }
-                };
-                match fetch_result {
-                    Ok(issue_fetcher) => {
-                        let board_total = issue_fetcher.len();
-                        let mut board_done = 0usize;
-                        for issue in issue_fetcher {
-                            issues_to_store.push(Issue {
-                                key: issue.key.clone(),
-                                id: issue.id,
-                                created: issue.fields.created,
-                                status: issue.fields.status.name,
-                                summary: issue.fields.summary,
-                            });
-                            board_done += 1;
-                            emit(board_done, board_total);
+            .filter_map(|board| {
+                let project_name = board.project_name.clone()?;
+                let watermark = issues_watermark("board", board.id);
+                let updated_since = sync_status.watermark(&watermark);
+                let (jira_client, mp) = (&jira_client, &mp);
+                Some(async move {
+                    let fetch_result = match board.board_type {
+                        // For Scrum boards, only fetch backlog issues (not in any sprint);
+                        // sprint issues are already fetched above per sprint.
+                        BoardType::Scrum => {
+                            jira_client
+                                .get_project_backlog_issues(
+                                    project_name.as_str(),
+                                    start_date,
+                                    updated_since,
+                                )
+                                .await
                         }
-                        logger::log(format!(
-                            "✅ Board '{}': {} issues fetched",
-                            board.name, board_done
-                        ));
-                    }
-                    Err(e) => {
-                        logger::log(format!(
-                            "⚠️  Board '{}': failed to fetch issues — {}",
-                            board.name, e
-                        ));
-                    }
+                        _ => {
+                            jira_client
+                                .get_project_issues(
+                                    project_name.as_str(),
+                                    start_date,
+                                    updated_since,
+                                )
+                                .await
+                        }
+                    };
+                    let result = match fetch_result {
+                        Ok(fetcher) => self.save_pages(fetcher, &board.name, &[], mp).await,
+                        Err(e) => Err(e.to_string()),
+                    };
+                    (board, watermark, result)
+                })
+            });
+        let board_results: Vec<_> = stream::iter(board_fetches)
+            .buffer_unordered(rate_limit::pacing().max_concurrent)
+            .collect()
+            .await;
+
+        for (board, watermark, result) in board_results {
+            match result {
+                Ok(saved) => {
+                    logger::log(format!(
+                        "✅ Board '{}': {} issues fetched",
+                        board.name, saved
+                    ));
+                    total_saved += saved;
+                    sync_status.record_watermark(&watermark, fetch_started);
+                    outcome.succeeded += 1;
+                }
+                Err(e) => {
+                    logger::log(format!(
+                        "⚠️  Board '{}': failed to fetch issues — {}",
+                        board.name, e
+                    ));
+                    outcome.failed(e);
                 }
             }
         }
 
-        let config = wtf_lib::config::Config::load().unwrap_or_default();
-        let start_date = Utc::now().checked_sub_months(Months::new(12));
         for key in &config.jira.project_keys {
-            match jira_client
-                .get_project_issues(key.as_str(), start_date)
+            let watermark = issues_watermark("project", key);
+            let result = match jira_client
+                .get_project_issues(key.as_str(), start_date, sync_status.watermark(&watermark))
                 .await
             {
-                Ok(issue_fetcher) => {
-                    let project_total = issue_fetcher.len();
-                    let mut project_done = 0usize;
-                    for issue in issue_fetcher {
-                        issues_to_store.push(Issue {
-                            key: issue.key.clone(),
-                            id: issue.id,
-                            created: issue.fields.created,
-                            status: issue.fields.status.name,
-                            summary: issue.fields.summary,
-                        });
-                        project_done += 1;
-                        emit(project_done, project_total);
-                    }
-                    logger::log(format!(
-                        "✅ Project '{}': {} issues fetched",
-                        key, project_done
-                    ));
+                Ok(fetcher) => self.save_pages(fetcher, key, &[], &mp).await,
+                Err(e) => Err(e.to_string()),
+            };
+            match result {
+                Ok(saved) => {
+                    logger::log(format!("✅ Project '{}': {} issues fetched", key, saved));
+                    total_saved += saved;
+                    sync_status.record_watermark(&watermark, fetch_started);
+                    outcome.succeeded += 1;
                 }
                 Err(e) => {
                     logger::log(format!(
                         "⚠️  Project '{}': failed to fetch issues — {}",
                         key, e
                     ));
+                    outcome.failed(e);
                 }
             }
         }
 
-        logger::log(format!("📦 {} issues saved total", issues_to_store.len()));
-        IssueService::production().save_all_issues(issues_to_store);
-        Ok(())
+        logger::log(format!("📦 {} issues saved total", total_saved));
+        outcome.into_result("issues")
     }
 }
 
@@ -244,6 +343,7 @@ impl FetchJiraBoard {
 
 impl Task for FetchJiraBoard {
     async fn execute(&self) -> Result<(), Box<dyn Error>> {
+        ensure_online("fetching boards")?;
         let mp = match &self.multi_progress {
             None => MultiProgress::new(),
             Some(multi) => multi.clone(),
@@ -255,8 +355,7 @@ impl Task for FetchJiraBoard {
                 if board_fetcher.len() == 0 {
                     info!("No board found.");
                 } else {
-                    let progress_bar = mp.add(ProgressBar::new(board_fetcher.len() as u64));
-                    progress_bar.set_style(ProgressStyle::default_bar()
+                    let progress_bar = StepProgress::new(&mp, "boards", board_fetcher.len() as u64, ProgressStyle::default_bar()
                         .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} boards ({percent}%)")
                         .unwrap());
                     for jira_board in board_fetcher {
@@ -273,7 +372,7 @@ impl Task for FetchJiraBoard {
                     progress_bar.finish_and_clear();
                 }
             }
-            Err(e) => eprintln!("Error: {:?}", e),
+            Err(e) => return Err(format!("Failed to fetch boards: {}", e).into()),
         }
         logger::log(format!("✅ {} boards fetched", boards_added));
         let boards = BoardService::production().get_all_boards();
@@ -287,15 +386,12 @@ impl Task for FetchJiraBoard {
             let board = Text::new("Board to follow: ")
                 .with_autocomplete(&board_suggestor)
                 .with_page_size(5)
-                .prompt()
-                .unwrap();
+                .prompt()?;
             let regex = Regex::new(r"\[([0-9]+)]").unwrap();
             if let Some(caps) = regex.captures(&board) {
                 if let Some(board_id) = caps.get(1) {
                     println!("board '{}' is now followed", board_id.as_str());
-                    JiraService::production()
-                        .follow_board(board_id.as_str())
-                        .unwrap();
+                    JiraService::production().follow_board(board_id.as_str())?;
                 }
             }
         }
@@ -334,6 +430,7 @@ impl FetchJiraSprint {
 
 impl Task for FetchJiraSprint {
     async fn execute(&self) -> Result<(), Box<dyn Error>> {
+        ensure_online("fetching sprints")?;
         let boards = match JiraService::production().get_followed_boards() {
             Ok(b) => b,
             Err(e) => {
@@ -350,13 +447,13 @@ impl Task for FetchJiraSprint {
             None => &MultiProgress::new(),
         };
 
-        // Get the auto-follow pattern from config
-        let auto_follow_pattern = Config::load()
-            .ok()
-            .and_then(|c| c.jira.auto_follow_sprint_pattern);
+        // Follow and unfollow sprints as configured
+        let jira_config = Config::load().unwrap_or_default().jira;
+        let now = Utc::now();
 
         let mut sprint_counts = 0;
         let mut auto_followed_count = 0;
+        let mut auto_unfollowed_count = 0;
 
         for board in boards {
             match board.board_type {
@@ -371,16 +468,13 @@ impl Task for FetchJiraSprint {
                             {
                                 spr.followed = db_sprint.followed;
                             }
-                            // Auto-follow based on pattern (only Active and Future sprints)
-                            if !spr.followed {
-                                if let Some(ref pattern) = auto_follow_pattern {
-                                    if spr.name.contains(pattern)
-                                        && (spr.state == Active || spr.state == Future)
-                                    {
-                                        spr.followed = true;
-                                        auto_followed_count += 1;
-                                    }
+                            if let Some(followed) = jira_config.auto_follow(&spr, now) {
+                                if followed {
+                                    auto_followed_count += 1;
+                                } else {
+                                    auto_unfollowed_count += 1;
                                 }
+                                spr.followed = followed;
                             }
                             spr
                         })
@@ -394,9 +488,12 @@ impl Task for FetchJiraSprint {
         }
         logger::log(format!("✅ {} sprints fetched", sprint_counts));
         if auto_followed_count > 0 {
+            logger::log(format!("🔔 Auto-followed {} sprints", auto_followed_count));
+        }
+        if auto_unfollowed_count > 0 {
             logger::log(format!(
-                "🔔 Auto-followed {} sprints matching pattern",
-                auto_followed_count
+                "🔕 Unfollowed {} closed sprints",
+                auto_unfollowed_count
             ));
         }
         Ok(())
@@ -422,11 +519,12 @@ fn into_sprint(sprint: &JiraSprint) -> Sprint {
         })
         .cloned()
         .collect::<Vec<_>>();
+    let worklog = Config::load().unwrap_or_default().worklog;
     let workdays = sprint
         .start_date
         .zip(sprint.end_date)
         .map_or(0, |(start, end)| {
-            count_workdays(start.date_naive(), end.date_naive(), absences)
+            count_workdays(start.date_naive(), end.date_naive(), absences, &worklog)
         });
     Sprint {
         id: sprint.id,
@@ -447,7 +545,13 @@ fn into_sprint(sprint: &JiraSprint) -> Sprint {
     }
 }
 
-fn count_workdays(start: NaiveDate, end: NaiveDate, absences: Vec<Absence>) -> i64 {
+/// Days of the period with working hours (see `[worklog.weekday_hours]`) and no absence
+fn count_workdays(
+    start: NaiveDate,
+    end: NaiveDate,
+    absences: Vec<Absence>,
+    worklog: &WorklogConfig,
+) -> i64 {
     let mut workdays = 0;
     let mut current_date = start;
     let abs = absences
@@ -458,10 +562,7 @@ fn count_workdays(start: NaiveDate, end: NaiveDate, absences: Vec<Absence>) -> i
         let is_dayoff = abs
             .iter()
             .find(|(s, e)| &current_date <= e && &current_date >= s);
-        if current_date.weekday() != Weekday::Sat
-            && current_date.weekday() != Weekday::Sun
-            && is_dayoff.is_none()
-        {
+        if worklog.is_workday(current_date) && is_dayoff.is_none() {
             workdays += 1;
         }
         current_date = match current_date.succ_opt() {
@@ -482,10 +583,13 @@ async fn fetch_board_sprints(mp: MultiProgress, board_id: usize) -> Result<Vec<J
                     "{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} {msg}",
                 )?
                 .progress_chars("#>-");
-            let sprint_bar = mp.add(ProgressBar::new(sprints_fetcher.len() as u64));
-            sprint_bar.set_style(sprint_progress_style);
+            let sprint_bar = StepProgress::new(
+                &mp,
+                "sprints",
+                sprints_fetcher.len() as u64,
+                sprint_progress_style,
+            );
             for sprint in sprints_fetcher {
-                sprint_bar.tick();
                 sprint_bar.set_message(format!("sprint #{}", sprint.id));
                 sprints_to_store.push(sprint);
                 sprint_bar.inc(1);
@@ -499,45 +603,77 @@ async fn fetch_board_sprints(mp: MultiProgress, board_id: usize) -> Result<Vec<J
 
 pub struct ListJiraSprints {
     fetch_all: bool,
+    format: OutputFormat,
 }
 
 impl ListJiraSprints {
     pub fn new(fetch_all: bool) -> Self {
-        Self { fetch_all }
+        Self {
+            fetch_all,
+            format: OutputFormat::Table,
+        }
     }
+
+    pub fn with_format(mut self, format: OutputFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+/// A sprint with the time logged during it, as printed by `--format json`
+#[derive(Serialize)]
+struct SprintListEntry<'a> {
+    #[serde(flatten)]
+    sprint: &'a Sprint,
+    time_spent_seconds: u64,
 }
 
 impl Task for ListJiraSprints {
     async fn execute(&self) -> std::result::Result<(), Box<dyn Error>> {
         let mut sprints = if self.fetch_all {
-            println!("Listing all available sprints:");
             JiraService::production().get_available_sprints()
         } else {
-            println!("Listing followed sprints:");
             JiraService::production().get_followed_sprint()
         };
+        sprints.sort_by_key(|a| a.start);
+        let worklogs = WorklogsService::production().get_all_worklogs();
+        let time_spent = |s: &Sprint| {
+            worklogs
+                .iter()
+                .filter(|wl| {
+                    let worklog_date = wl.started;
+                    let is_after_start = s.start.is_some_and(|start| worklog_date >= start);
+                    let is_before_end = s.end.is_some_and(|end| worklog_date <= end);
+                    is_after_start && is_before_end
+                })
+                .map(|wl| wl.time_spent_seconds)
+                .sum::<u64>()
+        };
+
+        if self.format.is_json() {
+            let entries: Vec<_> = sprints
+                .iter()
+                .map(|sprint| SprintListEntry {
+                    sprint,
+                    time_spent_seconds: time_spent(sprint),
+                })
+                .collect();
+            print_json(&entries);
+            return Ok(());
+        }
+
+        if self.fetch_all {
+            println!("Listing all available sprints:");
+        } else {
+            println!("Listing followed sprints:");
+        }
         if sprints.is_empty() {
             println!("No sprint found.");
             return Ok(());
         }
-        sprints.sort_by(|a, b| a.start.cmp(&b.start));
         let sprints_data = sprints
             .iter()
-            .map(|s| {
-                let time_spent = WorklogsService::production()
-                    .get_all_worklogs()
-                    .iter()
-                    .filter(|wl| {
-                        let worklog_date = wl.started;
-                        let is_after_start = s.start.is_some_and(|start| worklog_date >= start);
-                        let is_before_end = s.end.is_some_and(|end| worklog_date <= end);
-                        is_after_start && is_before_end
-                    })
-                    .map(|wl| wl.time_spent_seconds)
-                    .sum::<u64>();
-
-                SprintInfo::from_data(s, time_spent)
-            })
+            .map(|s| SprintInfo::from_data(s, time_spent(s)))
             .collect::<Vec<_>>();
 
         let mut table = Table::new(sprints_data);
@@ -625,6 +761,7 @@ impl SprintInfo {
 pub struct FetchJiraWorklogs {
     pub sprints: Vec<Sprint>,
     pub multi_progress: Option<MultiProgress>,
+    range: Option<(DateTime<Utc>, DateTime<Utc>)>,
 }
 
 impl FetchJiraWorklogs {
@@ -632,6 +769,16 @@ impl FetchJiraWorklogs {
         Self {
             sprints: sprints.clone(),
             multi_progress: None,
+            range: None,
+        }
+    }
+
+    /// Fetch an arbitrary period instead of the sprints' dates (Kanban, no sprints)
+    pub fn between(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self {
+            sprints: Vec::new(),
+            multi_progress: None,
+            range: Some((start, end)),
         }
     }
 
@@ -639,51 +786,122 @@ impl FetchJiraWorklogs {
         self.multi_progress = Some(progress);
         self
     }
+
+    /// The periods to fetch, each with a label for the progress bar
+    fn periods(&self) -> Vec<FetchPeriod> {
+        if let Some((start, end)) = self.range {
+            return month_periods(start, end);
+        }
+        self.sprints
+            .iter()
+            .filter_map(|sprint| match (sprint.start, sprint.end) {
+                (Some(start), Some(end)) => Some((format!("sprint #{}", sprint.id), start, end)),
+                _ => {
+                    debug!("Sprint '{}' has no start/end date", sprint.id);
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// Split `start..=end` at month boundaries, so a long `--from` range is fetched in
+/// sprint-sized calls that each fit in the per-call timeout
+fn month_periods(start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<FetchPeriod> {
+    let mut periods = Vec::new();
+    let mut chunk_start = start;
+    while chunk_start <= end {
+        let first_of_next_month = chunk_start
+            .date_naive()
+            .with_day(1)
+            .and_then(|first| first.checked_add_months(Months::new(1)))
+            .and_then(|next| next.and_hms_opt(0, 0, 0))
+            .map(|next| next.and_utc());
+        let chunk_end = match first_of_next_month {
+            Some(next) if next <= end => next - chrono::Duration::seconds(1),
+            _ => end,
+        };
+        let label = format!("{} to {}", chunk_start.date_naive(), chunk_end.date_naive());
+        periods.push((label, chunk_start, chunk_end));
+        chunk_start = chunk_end + chrono::Duration::seconds(1);
+    }
+    periods
 }
 
 impl Task for FetchJiraWorklogs {
     async fn execute(&self) -> Result<(), Box<dyn Error>> {
+        ensure_online("fetching worklogs")?;
+        // Jira's change feeds leave out the last minute: start the next delta before it
+        let fetch_started = Utc::now() - chrono::Duration::minutes(1);
+        let sync_status = SyncStatusService::production();
+        let all_periods = self.periods();
+        // Periods fetched before only need Jira's changes since; the others are fetched in full
+        let delta = WorklogsDelta::plan(&sync_status, &all_periods);
+
         let mp = self.multi_progress.clone().unwrap_or_default();
-        let sprint_progress = mp.add(ProgressBar::new(self.sprints.len() as u64));
         let progress_style = ProgressStyle::default_bar()
             .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} {msg}")
             .unwrap()
             .progress_chars("#>-");
-        sprint_progress.set_style(progress_style);
+        let steps = delta.full.len() + usize::from(delta.since.is_some());
+        let sprint_progress = StepProgress::new(&mp, "worklogs", steps as u64, progress_style);
         sprint_progress.enable_steady_tick(Duration::from_millis(100));
 
-        let semaphore = Arc::new(Semaphore::new(5));
+        let mut outcome = FetchOutcome::default();
+        let mut total_worklogs = 0;
+
+        if let Some(since) = delta.since {
+            sprint_progress.set_message(format!("Fetching the changes since {}", since));
+            match JiraClient::create().get_worklog_changes_since(since).await {
+                Ok(changes) => {
+                    debug!(
+                        "{} worklogs updated, {} deleted since {}",
+                        changes.updated.len(),
+                        changes.deleted.len(),
+                        since
+                    );
+                    total_worklogs += changes.updated.len();
+                    WorklogsService::production().apply_changes(
+                        changes
+                            .updated
+                            .iter()
+                            .map(JiraWorklog::into_worklog)
+                            .collect(),
+                        &changes.deleted,
+                    );
+                    delta.record_caught_up(&sync_status, fetch_started);
+                    outcome.succeeded += 1;
+                }
+                Err(e) => {
+                    outcome.failed(format!("Error from Jira for the worklog changes: {:?}", e))
+                }
+            }
+            sprint_progress.inc(1);
+        }
+
+        let semaphore = Arc::new(Semaphore::new(rate_limit::pacing().max_concurrent));
         let mut tasks = vec![];
 
-        for sprint in self.sprints.clone() {
+        for (label, start, end) in delta.full {
             let progress = sprint_progress.clone();
             let permit = semaphore.clone().acquire_owned().await.unwrap();
 
             let task = tokio::spawn(async move {
                 let _permit = permit;
 
-                if sprint.start.is_none() || sprint.end.is_none() {
-                    debug!("Sprint '{}' has no start/end date", sprint.id);
-                    return Ok::<Vec<Worklog>, String>(vec![]);
-                }
+                progress.set_message(format!("Fetching {}", label));
 
-                progress.set_message(format!("Fetching sprint #{}", sprint.id));
-                tokio::time::sleep(Duration::from_secs(2)).await;
-
-                debug!(
-                    "getting worklogs between {} and {}",
-                    sprint.start.unwrap(),
-                    sprint.end.unwrap()
-                );
+                debug!("getting worklogs between {} and {}", start, end);
+                // Generous for a sprint or a month: a rate-limited Jira spreads the calls
+                // out over Retry-After waits
                 let result = tokio::time::timeout(
-                    Duration::from_secs(15),
-                    JiraClient::create()
-                        .get_worklogs_between(sprint.start.unwrap(), sprint.end.unwrap()),
+                    Duration::from_secs(300),
+                    JiraClient::create().get_worklogs_between(start, end),
                 )
                 .await;
 
                 debug!("parsing fetched worklogs");
-                let worklogs = match result {
+                let worklogs: Vec<Worklog> = match result {
                     Ok(Ok(raw_worklogs)) => raw_worklogs
                         .iter()
                         .map(|wl| Worklog {
@@ -698,17 +916,17 @@ impl Task for FetchJiraWorklogs {
                         })
                         .collect(),
                     Ok(Err(e)) => {
-                        debug!("Error from Jira for sprint {}: {:?}", sprint.id, e);
-                        vec![]
+                        progress.inc(1);
+                        return Err(format!("Error from Jira for {}: {:?}", label, e));
                     }
                     Err(_) => {
-                        debug!("Timeout while fetching sprint {}", sprint.id);
-                        vec![]
+                        progress.inc(1);
+                        return Err(format!("Timeout while fetching {}", label));
                     }
                 };
 
                 progress.inc(1);
-                Ok(worklogs)
+                Ok::<_, String>((start, end, worklogs))
             });
 
             tasks.push(task);
@@ -716,57 +934,86 @@ impl Task for FetchJiraWorklogs {
 
         debug!("adding worklogs to database");
         let results = join_all(tasks).await;
-
-        // Determine the overall date range from sprints (not worklogs)
-        let mut min_date: Option<NaiveDate> = None;
-        let mut max_date: Option<NaiveDate> = None;
-
-        for sprint in &self.sprints {
-            if let (Some(start), Some(end)) = (sprint.start, sprint.end) {
-                let start_date = start.date_naive();
-                let end_date = end.date_naive();
-                min_date = Some(min_date.map_or(start_date, |d| d.min(start_date)));
-                max_date = Some(max_date.map_or(end_date, |d| d.max(end_date)));
-            }
-        }
-
-        // Collect all worklogs
-        let mut all_fetched_worklogs = Vec::new();
+        let worklogs_service = WorklogsService::production();
 
         for result in results {
             match result {
-                Ok(Ok(worklogs)) => {
-                    all_fetched_worklogs.extend(worklogs);
+                Ok(Ok((start, end, worklogs))) => {
+                    // Replace the period's worklogs, even with none: they were deleted on Jira
+                    debug!(
+                        "Replacing worklogs for date range {} to {} with {} fresh worklogs",
+                        start,
+                        end,
+                        worklogs.len()
+                    );
+                    total_worklogs += worklogs.len();
+                    worklogs_service.replace_worklogs_for_date_range(
+                        start.date_naive(),
+                        end.date_naive(),
+                        worklogs,
+                    );
+                    sync_status.record_watermark(&worklogs_watermark(start, end), fetch_started);
+                    outcome.succeeded += 1;
                 }
                 Ok(Err(e)) => {
                     debug!("Task error (string): {}", e);
+                    outcome.failed(e);
                 }
                 Err(join_err) => {
                     debug!("Join error: {}", join_err);
+                    outcome.failed(join_err);
                 }
             }
         }
 
-        let total_worklogs = all_fetched_worklogs.len();
-
-        // Replace worklogs for the sprints' date range (even if we fetched 0 worklogs)
-        if let (Some(min), Some(max)) = (min_date, max_date) {
-            debug!(
-                "Replacing worklogs for sprint date range {} to {} with {} fresh worklogs",
-                min, max, total_worklogs
-            );
-            WorklogsService::production().replace_worklogs_for_date_range(
-                min,
-                max,
-                all_fetched_worklogs,
-            );
-        } else {
-            debug!("No sprints with dates found, not updating database");
-        }
-
         sprint_progress.finish_and_clear();
         logger::log(format!("✅ {} worklogs fetched", total_worklogs));
+        if all_periods.is_empty() {
+            logger::log("No followed sprint, no worklogs to fetch".to_string());
+            exit::report(ExitStatus::NothingToDo);
+        } else if outcome.succeeded > 0 {
+            SyncStatusService::production().record(SyncSource::Jira);
+        }
 
-        Ok(())
+        outcome.into_result("worklogs")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_range_fetch_is_split_per_month() {
+        let start = Utc.with_ymd_and_hms(2026, 1, 20, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2026, 3, 5, 23, 59, 59).unwrap();
+
+        let periods = FetchJiraWorklogs::between(start, end).periods();
+        let bounds: Vec<_> = periods.iter().map(|(_, s, e)| (*s, *e)).collect();
+        assert_eq!(
+            bounds,
+            vec![
+                (
+                    start,
+                    Utc.with_ymd_and_hms(2026, 1, 31, 23, 59, 59).unwrap()
+                ),
+                (
+                    Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap(),
+                    Utc.with_ymd_and_hms(2026, 2, 28, 23, 59, 59).unwrap()
+                ),
+                (Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap(), end),
+            ]
+        );
+        assert_eq!(periods[1].0, "2026-02-01 to 2026-02-28");
+    }
+
+    #[test]
+    fn test_range_within_a_month_is_one_period() {
+        let start = Utc.with_ymd_and_hms(2026, 12, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2026, 12, 31, 23, 59, 59).unwrap();
+        let periods = month_periods(start, end);
+        assert_eq!(periods.len(), 1);
+        assert_eq!((periods[0].1, periods[0].2), (start, end));
     }
 }
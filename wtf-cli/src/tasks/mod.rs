@@ -1,8 +1,72 @@
 pub mod github_tasks;
 pub mod google_tasks;
+mod jira_delta;
 pub mod jira_tasks;
+pub mod wizard_tasks;
 pub mod worklog_tasks;
 
+use crate::logger;
+use std::error::Error;
+use wtf_lib::client::offline;
+use wtf_lib::config::Config;
+use wtf_lib::models::data::LocalWorklog;
+use wtf_lib::services::worklogs_service::{LocalWorklogService, WorklogsService};
+use wtf_lib::utils::link_script::LinkScript;
+use wtf_lib::utils::worklog_rules::Violation;
+
 pub trait Task {
     async fn execute(&self) -> Result<(), Box<dyn std::error::Error>>;
 }
+
+/// Refuse to start `what`, a task reaching Jira, Google or GitHub, with `--offline`
+pub fn ensure_online(what: &str) -> Result<(), Box<dyn Error>> {
+    if offline::is_enabled() {
+        return Err(format!(
+            "Offline: not {} (drop --offline to reach the network)",
+            what
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// The `[linking] script`, if any; one that can't be loaded is reported, and the usual
+/// rules apply without it
+pub fn link_script(config: &Config) -> Option<LinkScript> {
+    LinkScript::load(config).unwrap_or_else(|e| {
+        logger::log(format!("⚠️  {}", e));
+        None
+    })
+}
+
+/// Stage `worklogs` but the ones breaking a blocking `[validation]` rule, reporting every
+/// violation; the worklogs staged
+pub fn stage_worklogs(worklogs: Vec<LocalWorklog>, config: &Config) -> Vec<LocalWorklog> {
+    let (staged, violations) = LocalWorklogService::production().stage_worklogs(
+        worklogs,
+        &config.validation,
+        &WorklogsService::production().get_all_worklogs(),
+    );
+    for violation in &violations {
+        logger::log(violation_line(violation));
+    }
+    staged
+}
+
+/// e.g. "⛔ PROJ-2 on 2026-03-04 not staged: PROJ-2 is Done (no_closed_issues)"
+pub fn violation_line(violation: &Violation) -> String {
+    let (icon, outcome) = if violation.is_blocking() {
+        ("⛔", " not staged")
+    } else {
+        ("⚠️ ", "")
+    };
+    format!(
+        "{} {} on {}{}: {} ({})",
+        icon,
+        violation.issue,
+        violation.date,
+        outcome,
+        violation.message,
+        violation.rule.name()
+    )
+}
@@ -0,0 +1,370 @@
+use crate::commands::fetch::fetch_google_meetings;
+use crate::logger;
+use crate::tasks::github_tasks::FetchGithubEventsTask;
+use crate::tasks::jira_tasks::{
+    FetchJiraBoard, FetchJiraIssues, FetchJiraSprint, FetchJiraWorklogs,
+};
+use crate::tasks::worklog_tasks::{MeetingWorklogTask, PushStagedWorklogsTask};
+use crate::tasks::{link_script, stage_worklogs, Task};
+use chrono::NaiveDate;
+use indicatif::{MultiProgress, ProgressDrawTarget};
+use std::error::Error;
+use wtf_lib::client::offline;
+use wtf_lib::config::{Config, WorklogConfig};
+use wtf_lib::models::data::LocalWorklogState;
+use wtf_lib::services::audit_service;
+use wtf_lib::services::github_service::GitHubService;
+use wtf_lib::services::jira_service::{IssueService, JiraService};
+use wtf_lib::services::meetings_service::MeetingsService;
+use wtf_lib::services::worklogs_service::{LocalWorklogService, WorklogsService};
+use wtf_lib::utils::gap_fill::{GAP_FILL_COMMENT, GAP_FILL_MIN_THRESHOLD};
+use wtf_lib::utils::link_script::LinkScript;
+use wtf_lib::utils::meetings::autolink_key;
+use wtf_lib::utils::period::Period;
+
+/// Wizard steps that can be left out of a headless run with `--skip`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WizardStage {
+    Sync,
+    Autolink,
+    Meetings,
+    GitHub,
+    Gaps,
+}
+
+impl std::str::FromStr for WizardStage {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "sync" => Ok(WizardStage::Sync),
+            "autolink" => Ok(WizardStage::Autolink),
+            "meetings" => Ok(WizardStage::Meetings),
+            "github" => Ok(WizardStage::GitHub),
+            "gaps" => Ok(WizardStage::Gaps),
+            _ => Err(format!("Unknown wizard step: {}", s)),
+        }
+    }
+}
+
+/// The Chronie wizard without the TUI: every step runs with its default answer
+/// (no manual linking, GitHub sessions capped to the daily limit, gaps filled
+/// only when a gap issue is given) and pushing only happens when asked for.
+pub struct HeadlessWizardTask {
+    period: Period,
+    gap_issue: Option<String>,
+    push: bool,
+    skipped: Vec<WizardStage>,
+}
+
+impl HeadlessWizardTask {
+    pub fn new(period: Period) -> Self {
+        Self {
+            period,
+            gap_issue: None,
+            push: false,
+            skipped: Vec::new(),
+        }
+    }
+
+    pub fn with_gap_issue(mut self, gap_issue: Option<String>) -> Self {
+        self.gap_issue = gap_issue;
+        self
+    }
+
+    pub fn with_push(mut self, push: bool) -> Self {
+        self.push = push;
+        self
+    }
+
+    pub fn skipping(mut self, skipped: Vec<WizardStage>) -> Self {
+        self.skipped = skipped;
+        self
+    }
+
+    fn runs(&self, stage: WizardStage) -> bool {
+        !self.skipped.contains(&stage)
+    }
+
+    fn days(&self) -> (NaiveDate, NaiveDate) {
+        (self.period.start, self.period.end)
+    }
+
+    async fn sync(&self) {
+        if offline::is_enabled() {
+            logger::log("⏭️  Step 1/7: Offline, working on the cached data".to_string());
+            return;
+        }
+        logger::log("📡 Step 1/7: Syncing data...".to_string());
+        let mp = MultiProgress::new();
+        mp.set_draw_target(ProgressDrawTarget::hidden());
+
+        let _ = FetchJiraBoard::new()
+            .with_progress(mp.clone())
+            .without_follow_prompt()
+            .execute()
+            .await;
+        let _ = FetchJiraSprint::new()
+            .with_progress(mp.clone())
+            .execute()
+            .await;
+        let sprints = JiraService::production().get_followed_sprint();
+        let _ = FetchJiraIssues::new(sprints.clone())
+            .with_progress(mp.clone())
+            .execute()
+            .await;
+        // Without a sprint (Kanban) the worklogs of the period itself are fetched
+        let fetch_worklogs = match self.period.sprint_id {
+            Some(_) => FetchJiraWorklogs::new(sprints),
+            None => FetchJiraWorklogs::between(self.period.start_time(), self.period.end_time()),
+        };
+        let _ = fetch_worklogs.with_progress(mp.clone()).execute().await;
+        if let Err(e) = fetch_google_meetings(None, false).await {
+            logger::log(format!("⚠️  {}", e));
+        }
+        let _ = FetchGithubEventsTask::new().execute().await;
+    }
+
+    /// Link meetings whose title or description names a known issue
+    fn autolink(&self, config: &Config, script: Option<&LinkScript>) -> usize {
+        logger::log("🔗 Step 1/7: Auto-linking meetings...".to_string());
+        audit_service::set_source("wizard: Auto-link");
+        let meetings_service = MeetingsService::production();
+        let untracked_ids = meetings_service.get_all_untracked_ids();
+        let mut linked = 0;
+
+        let issue_service = IssueService::production();
+        for mut meeting in meetings_service.get_meetings_for_period(&self.period) {
+            if let Some(key) = autolink_key(&meeting, config, &untracked_ids, script, |key| {
+                issue_service.get_by_key(key).is_some()
+            }) {
+                meeting.jira_link = Some(key);
+                meetings_service.save(&meeting);
+                linked += 1;
+            }
+        }
+
+        logger::log(format!("✅ Auto-linked {} meetings", linked));
+        linked
+    }
+
+    /// Create worklogs from the period's GitHub sessions, within the working window and
+    /// never going over the daily limit; sessions logged by a previous run are left alone
+    fn github_worklogs(
+        &self,
+        worklog: &WorklogConfig,
+        script: Option<&LinkScript>,
+    ) -> Result<usize, Box<dyn Error>> {
+        logger::log("💻 Step 4/7: Creating worklogs from GitHub sessions...".to_string());
+        audit_service::set_source("wizard: GitHub worklogs");
+        let (start, end) = self.days();
+        let sessions = GitHubService::production().get_sessions_by_date_range(start, end)?;
+        let worklog_service = LocalWorklogService::production();
+        let jira_worklogs = WorklogsService::production().get_all_worklogs();
+        let local_worklogs = worklog_service.get_all_local_worklogs();
+        let mut created = 0;
+
+        for session in sessions {
+            let link = script.and_then(|script| script.link_session(&session));
+            let issues: Vec<String> = match &link {
+                Some(link) => vec![link.issue.clone()],
+                None => session.get_jira_issues(),
+            }
+            .into_iter()
+            .filter(|key| IssueService::production().get_by_key(key).is_some())
+            .collect();
+            if issues.is_empty() {
+                continue;
+            }
+            let (started, seconds) = worklog.clamp_to_workday(
+                session.start_time,
+                link.as_ref()
+                    .and_then(|link| link.seconds)
+                    .unwrap_or(session.duration_seconds),
+            );
+            let already_logged = local_worklogs
+                .iter()
+                .any(|w| w.started == started && issues.contains(&w.issue_id));
+            if already_logged {
+                continue;
+            }
+
+            let existing_hours =
+                worklog_service.calculate_daily_total(session.date, &jira_worklogs);
+            let available_seconds = ((worklog.daily_hours_limit - existing_hours) * 3600.0) as i64;
+            let seconds = seconds.min(available_seconds);
+            if seconds <= 0 {
+                logger::log(format!(
+                    "⚠️  Skipping GitHub session on {}: daily limit reached",
+                    session.date
+                ));
+                continue;
+            }
+
+            let comment = format!(
+                "GitHub activity: {}",
+                session
+                    .description
+                    .split(';')
+                    .next()
+                    .unwrap_or("Development work")
+            );
+            let seconds_per_issue = seconds / issues.len() as i64;
+            for issue in &issues {
+                worklog_service.create_new_local_worklogs(
+                    started,
+                    seconds_per_issue,
+                    issue,
+                    Some(&comment),
+                    None,
+                );
+                created += 1;
+            }
+        }
+
+        logger::log(format!("✅ Created {} worklogs from GitHub", created));
+        Ok(created)
+    }
+
+    /// Fill the remaining time of under-logged workdays on the gap issue
+    fn fill_gaps(&self, gap_issue: &str, worklog: &WorklogConfig) -> Result<usize, Box<dyn Error>> {
+        logger::log(format!("🔧 Step 5/7: Filling gaps with {}...", gap_issue));
+        audit_service::set_source("wizard: Fill gaps");
+        if IssueService::production().get_by_key(gap_issue).is_none() {
+            return Err(format!(
+                "Gap issue '{}' not found (run 'wtf fetch' first)",
+                gap_issue
+            )
+            .into());
+        }
+        let (start, end) = self.days();
+        let meetings_service = MeetingsService::production();
+        let worklog_service = LocalWorklogService::production();
+        let gaps = worklog_service.find_gap_days(
+            start,
+            end,
+            &|date| worklog.target_hours(date),
+            GAP_FILL_MIN_THRESHOLD,
+            &|date| meetings_service.is_absent(date),
+            &WorklogsService::production().get_all_worklogs(),
+        );
+
+        for (date, hours) in &gaps {
+            worklog_service.create_new_local_worklogs(
+                worklog.day_start(*date),
+                (hours * 3600.0) as i64,
+                gap_issue,
+                Some(GAP_FILL_COMMENT),
+                None,
+            );
+        }
+
+        logger::log(format!("✅ Filled {} gap days", gaps.len()));
+        Ok(gaps.len())
+    }
+
+    /// Stage the period's new worklogs and push them
+    async fn push(&self) -> Result<(), Box<dyn Error>> {
+        logger::log("🚀 Step 7/7: Pushing worklogs to Jira...".to_string());
+        audit_service::set_source("wizard: Push");
+        let (start, end) = self.days();
+        let worklog_service = LocalWorklogService::production();
+        let to_stage: Vec<_> = worklog_service
+            .get_unpushed_in_range(start, end)
+            .into_iter()
+            .filter(|w| w.status == LocalWorklogState::Created)
+            .collect();
+        let count = stage_worklogs(to_stage, &Config::load().unwrap_or_default()).len();
+        logger::log(format!("📦 Staged {} worklogs for push", count));
+        if offline::is_enabled() {
+            logger::log("📴 Offline: push them later with 'wtf worklog push'".to_string());
+            return Ok(());
+        }
+
+        PushStagedWorklogsTask::default().execute().await?;
+        logger::log("✅ Push complete".to_string());
+        Ok(())
+    }
+}
+
+impl Task for HeadlessWizardTask {
+    async fn execute(&self) -> Result<(), Box<dyn Error>> {
+        let (start, end) = self.days();
+        let config = Config::load().unwrap_or_default();
+        logger::log(format!(
+            "🧙 Chronie is running the wizard for {}",
+            self.period
+        ));
+
+        if self.runs(WizardStage::Sync) {
+            self.sync().await;
+        }
+        let script = link_script(&config);
+        if self.runs(WizardStage::Autolink) {
+            self.autolink(&config, script.as_ref());
+        }
+        logger::log("⏭️  Step 2/7: Manual linking is not available headless, skipping".to_string());
+        if self.runs(WizardStage::Meetings) {
+            logger::log("📅 Step 3/7: Creating worklogs from meetings...".to_string());
+            audit_service::set_source("wizard: Meeting worklogs");
+            MeetingWorklogTask::for_period(self.period.clone())
+                .execute()
+                .await?;
+        }
+        if self.runs(WizardStage::GitHub) {
+            self.github_worklogs(&config.worklog, script.as_ref())?;
+        }
+        match (&self.gap_issue, self.runs(WizardStage::Gaps)) {
+            (Some(gap_issue), true) => {
+                self.fill_gaps(gap_issue, &config.worklog)?;
+            }
+            (None, true) => {
+                logger::log("⏭️  Step 5/7: No --gap-issue given, skipping gap filling".to_string())
+            }
+            _ => {}
+        }
+
+        let unpushed = LocalWorklogService::production().get_unpushed_in_range(start, end);
+        let hours: f64 = unpushed
+            .iter()
+            .map(|w| w.time_spent_seconds as f64 / 3600.0)
+            .sum();
+        logger::log(format!(
+            "📋 Step 6/7: {} worklogs ({:.1}h) waiting to be pushed",
+            unpushed.len(),
+            hours
+        ));
+
+        if self.push {
+            self.push().await
+        } else {
+            logger::log(
+                "ℹ️  Not pushing without --auto; review them with 'wtf worklog list' or the TUI"
+                    .to_string(),
+            );
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stage_names_parse_ignoring_case() {
+        assert_eq!("GitHub".parse::<WizardStage>(), Ok(WizardStage::GitHub));
+        assert_eq!("gaps".parse::<WizardStage>(), Ok(WizardStage::Gaps));
+        assert!("push".parse::<WizardStage>().is_err());
+    }
+
+    #[test]
+    fn test_skipped_stages_do_not_run() {
+        let period = Period::week_of(NaiveDate::from_ymd_opt(2026, 3, 4).unwrap());
+        let task =
+            HeadlessWizardTask::new(period).skipping(vec![WizardStage::Sync, WizardStage::GitHub]);
+        assert!(!task.runs(WizardStage::Sync));
+        assert!(!task.runs(WizardStage::GitHub));
+        assert!(task.runs(WizardStage::Meetings));
+        assert!(task.runs(WizardStage::Gaps));
+    }
+}
@@ -1,35 +1,101 @@
+use crate::exit::{self, ExitStatus};
 use crate::logger;
-use crate::tasks::Task;
-use chrono::{DateTime, Duration, NaiveDate, Utc};
+use crate::progress;
+use crate::tasks::{ensure_online, link_script, Task};
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use inquire::Select;
 use log::{debug, error};
 use rayon::prelude::*;
+use serde_json::json;
+use std::collections::HashMap;
 use std::error::Error;
-use wtf_lib::models::data::{Meeting, Sprint};
+use wtf_lib::config::{Config, MeetingsConfig, OverlapPolicy};
+use wtf_lib::models::data::{LocalWorklog, LocalWorklogState, Meeting, Sprint};
+use wtf_lib::services::jira_service::IssueService;
 use wtf_lib::services::meetings_service::MeetingsService;
 use wtf_lib::services::worklogs_service::LocalWorklogService;
+use wtf_lib::storage::backup::{BackupStore, PRE_PUSH_LABEL};
+use wtf_lib::utils::hooks::{self, HookEvent};
+use wtf_lib::utils::link_script::LinkScript;
+use wtf_lib::utils::meeting_overlaps::{resolve, settle_overlaps, MeetingSlot, Resolution};
+use wtf_lib::utils::period::Period;
 
 pub struct MeetingWorklogTask {
-    sprints: Vec<Sprint>,
+    periods: Vec<Period>,
+    interactive: bool,
 }
 
 impl MeetingWorklogTask {
+    /// Sprints without dates are left out
     pub fn new(sprints: Vec<Sprint>) -> Self {
-        Self { sprints }
+        Self {
+            periods: sprints.iter().filter_map(Period::of_sprint).collect(),
+            interactive: false,
+        }
     }
 
-    fn log_sprint_meetings(&self, sprint: Sprint) {
-        debug!("loging time for sprint {:?}", sprint);
-        if let (Some(start), Some(end)) = (sprint.start, sprint.end) {
-            let meetings_to_log = Self::get_meeting_to_logs(start, end);
-            meetings_to_log.par_iter().for_each(|(day, meeting)| {
-                Self::log_meeting_for_day(*day, meeting.clone());
-            });
+    pub fn for_period(period: Period) -> Self {
+        Self {
+            periods: vec![period],
+            interactive: false,
         }
     }
 
+    /// Ask which meeting counts when meetings overlap and `overlaps = "ask"`
+    pub fn interactive(mut self) -> Self {
+        self.interactive = true;
+        self
+    }
+
+    fn log_period_meetings(&self, period: &Period, config: &Config, script: Option<&LinkScript>) {
+        debug!("loging time for period {}", period);
+        let meetings_to_log =
+            Self::get_meeting_to_logs(period.start_time(), period.end_time(), &config.meetings);
+        let trimmed = self.settle_overlaps(&meetings_to_log, config.meetings.overlaps);
+        meetings_to_log.par_iter().for_each(|(day, meeting)| {
+            let allotted = trimmed
+                .get(&(day.date_naive(), meeting.id.clone()))
+                .copied();
+            Self::log_meeting_for_day(*day, meeting.clone(), config, script, allotted);
+        });
+    }
+
+    /// The seconds left to the linked meetings overlapping others, by day and meeting
+    fn settle_overlaps(
+        &self,
+        meetings_to_log: &[(DateTime<Utc>, Meeting)],
+        policy: OverlapPolicy,
+    ) -> HashMap<(NaiveDate, String), i64> {
+        if policy == OverlapPolicy::Keep {
+            return HashMap::new();
+        }
+        let slots: Vec<MeetingSlot> = meetings_to_log
+            .iter()
+            .filter(|(_, meeting)| meeting.jira_link.is_some())
+            .filter_map(|(day, meeting)| MeetingSlot::of_meeting(meeting, day.date_naive()))
+            .collect();
+        settle_overlaps(&slots, |group| {
+            let resolution = match policy {
+                OverlapPolicy::Ask if self.interactive => {
+                    ask_overlap(group).unwrap_or_else(|| resolve(policy, group))
+                }
+                _ => resolve(policy, group),
+            };
+            logger::log(format!(
+                "⚠️  {} overlapping meetings on {}: {}",
+                group.len(),
+                group[0].day,
+                describe_resolution(group, &resolution)
+            ));
+            resolution
+        })
+    }
+
+    /// The attended meetings of each day not logged yet, absent days left out
     fn get_meeting_to_logs(
         mut current: DateTime<Utc>,
         end: DateTime<Utc>,
+        meetings: &MeetingsConfig,
     ) -> Vec<(DateTime<Utc>, Meeting)> {
         let mut meetings_to_log: Vec<(DateTime<Utc>, Meeting)> = Vec::new();
         while current <= end {
@@ -41,6 +107,7 @@ impl MeetingWorklogTask {
                     MeetingsService::production()
                         .get_meeting_by_date(current)
                         .into_iter()
+                        .filter(|m| meetings.attends(m))
                         .filter(|m| !Self::meeting_already_logged(m, current.date_naive()))
                         .map(|meeting| (current, meeting)),
                 );
@@ -56,12 +123,52 @@ impl MeetingWorklogTask {
         !found.is_empty()
     }
 
-    fn log_meeting_for_day(current: DateTime<Utc>, meeting: Meeting) {
+    /// `allotted` caps the time of a meeting overlapping others
+    fn log_meeting_for_day(
+        current: DateTime<Utc>,
+        meeting: Meeting,
+        config: &Config,
+        script: Option<&LinkScript>,
+        allotted: Option<i64>,
+    ) {
         let meeting_title = meeting.clone().title.unwrap_or("no title".to_string());
         debug!("meeting: {}", meeting_title);
         if let Some(jira_link) = meeting.clone().jira_link {
-            let meeting_time_spent = (meeting.end - meeting.start).num_seconds();
-            if let Some(start_date) = meeting.get_start_for_day(current.date_naive()) {
+            // The linking script may log another time than the meeting's, for its own issue
+            let mut meeting_time_spent = script
+                .and_then(|script| script.link_meeting(&meeting))
+                .filter(|link| link.issue == jira_link)
+                .and_then(|link| link.seconds)
+                .unwrap_or_else(|| (meeting.end - meeting.start).num_seconds());
+            if let Some(allotted) = allotted {
+                meeting_time_spent = meeting_time_spent.min(allotted);
+            }
+            let mut start_date = meeting.get_start_for_day(current.date_naive());
+            // Outside the workday and during lunch, see `[meetings]`
+            if let Some(slot) = MeetingSlot::of_meeting(&meeting, current.date_naive()) {
+                let (start, seconds) =
+                    config
+                        .meetings
+                        .worklog_time(&config.worklog, slot.start, slot.end);
+                if seconds < slot.seconds() {
+                    start_date = Some(start);
+                    meeting_time_spent = meeting_time_spent.min(seconds);
+                }
+            }
+            if meeting_time_spent > 0 {
+                meeting_time_spent = config
+                    .meeting_rules
+                    .worklog_seconds(&meeting, meeting_time_spent);
+            }
+            if meeting_time_spent <= 0 {
+                logger::log(format!(
+                    "No worklog for '{}', its time went to an overlapping meeting, lunch or \
+                    outside the workday",
+                    meeting_title
+                ));
+                return;
+            }
+            if let Some(start_date) = start_date {
                 let created_worklog = LocalWorklogService::production().create_new_local_worklogs(
                     start_date,
                     meeting_time_spent,
@@ -89,11 +196,277 @@ impl MeetingWorklogTask {
     }
 }
 
+/// Ask which of the overlapping meetings gets their shared time, `None` when the prompt
+/// is cancelled
+fn ask_overlap(group: &[MeetingSlot]) -> Option<Resolution> {
+    let mut choices: Vec<String> = group
+        .iter()
+        .map(|slot| {
+            format!(
+                "{} ({} - {})",
+                slot.title,
+                slot.start.with_timezone(&Local).format("%H:%M"),
+                slot.end.with_timezone(&Local).format("%H:%M")
+            )
+        })
+        .collect();
+    choices.push("Split the overlap evenly".to_string());
+    choices.push("Log every meeting in full".to_string());
+    let message = format!(
+        "{} meetings overlap on {}, which one counts?",
+        group.len(),
+        group[0].day
+    );
+    let choice = Select::new(&message, choices).raw_prompt().ok()?;
+    Some(match choice.index {
+        index if index < group.len() => Resolution::Winner(group[index].id.clone()),
+        index if index == group.len() => Resolution::Split,
+        _ => Resolution::Keep,
+    })
+}
+
+fn describe_resolution(group: &[MeetingSlot], resolution: &Resolution) -> String {
+    let titles: Vec<&str> = group.iter().map(|slot| slot.title.as_str()).collect();
+    match resolution {
+        Resolution::Keep => format!("{} all logged in full", titles.join(", ")),
+        Resolution::Split => format!("shared time split between {}", titles.join(", ")),
+        Resolution::Winner(id) => {
+            let winner = group
+                .iter()
+                .find(|slot| &slot.id == id)
+                .map_or("the longest", |slot| slot.title.as_str());
+            format!("'{}' counts over {}", winner, titles.join(", "))
+        }
+    }
+}
+
 impl Task for MeetingWorklogTask {
     async fn execute(&self) -> Result<(), Box<dyn Error>> {
-        for sprint in self.sprints.iter() {
-            self.log_sprint_meetings(sprint.clone());
+        let config = Config::load().unwrap_or_default();
+        let script = link_script(&config);
+        for period in self.periods.iter() {
+            self.log_period_meetings(period, &config, script.as_ref());
+        }
+        Ok(())
+    }
+}
+
+/// Push staged worklogs to Jira. Without filters every staged worklog goes out.
+#[derive(Default)]
+pub struct PushStagedWorklogsTask {
+    date: Option<NaiveDate>,
+    issue: Option<String>,
+    range: Option<(NaiveDate, NaiveDate)>,
+    include_created: bool,
+}
+
+impl PushStagedWorklogsTask {
+    pub fn with_date(mut self, date: NaiveDate) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    pub fn with_issue(mut self, issue: &str) -> Self {
+        self.issue = Some(issue.to_string());
+        self
+    }
+
+    /// Only push worklogs started between `start` and `end` (inclusive), e.g. a sprint
+    pub fn with_range(mut self, start: NaiveDate, end: NaiveDate) -> Self {
+        self.range = Some((start, end));
+        self
+    }
+
+    /// Also push worklogs that were created but never staged
+    pub fn with_created(mut self, include_created: bool) -> Self {
+        self.include_created = include_created;
+        self
+    }
+
+    fn matches(&self, worklog: &LocalWorklog) -> bool {
+        let date = worklog.started.date_naive();
+        self.date.is_none_or(|d| d == date)
+            && self
+                .range
+                .is_none_or(|(start, end)| start <= date && date <= end)
+            && self
+                .issue
+                .as_ref()
+                .is_none_or(|issue| worklog.issue_id.eq_ignore_ascii_case(issue))
+    }
+}
+
+impl Task for PushStagedWorklogsTask {
+    async fn execute(&self) -> Result<(), Box<dyn Error>> {
+        let mut states = vec![LocalWorklogState::Staged];
+        if self.include_created {
+            states.push(LocalWorklogState::Created);
+        }
+        let worklogs: Vec<_> = LocalWorklogService::production()
+            .get_all_local_worklogs_by_status(states)
+            .into_iter()
+            .filter(|wl| self.matches(wl))
+            .collect();
+        if worklogs.is_empty() {
+            logger::log("No worklogs to push".to_string());
+            exit::report(ExitStatus::NothingToDo);
+            return Ok(());
+        }
+        ensure_online(&format!(
+            "pushing {} worklogs, they stay staged",
+            worklogs.len()
+        ))?;
+        let hooks = Config::load().unwrap_or_default().hooks;
+        hooks::run(&hooks, HookEvent::PrePush, &worklogs)
+            .map_err(|e| format!("The pre_push hook cancelled the push: {}", e))?;
+        snapshot_before_push();
+        let mut local_worklogs_id: Vec<String> = Vec::new();
+        let mut pushed: Vec<LocalWorklog> = Vec::new();
+        let mut failed = 0;
+        let mut last_error = String::new();
+        let total = worklogs.len() as u64;
+        for (index, mut wl) in worklogs.into_iter().enumerate() {
+            let issue = wl.issue_id.clone();
+            match IssueService::production()
+                .add_time(
+                    wl.issue_id.as_str(),
+                    Duration::seconds(wl.time_spent_seconds),
+                    wl.started,
+                    Some(wl.comment.clone()),
+                )
+                .await
+            {
+                Ok(Some(jira_worklog)) => {
+                    wl.status = LocalWorklogState::Pushed;
+                    wl.worklog_id = Some(jira_worklog.id);
+                    LocalWorklogService::production().save_local_worklog(wl.clone());
+                    local_worklogs_id.push(wl.id.clone());
+                    pushed.push(wl);
+                }
+                Ok(None) => {
+                    // The worklog was created in Jira (2xx response) but no Location header
+                    // was returned, so we couldn't fetch its ID. Mark as Pushed so it isn't
+                    // re-pushed, but warn that it can't be reverted through the app.
+                    eprintln!(
+                        "⚠️  Worklog for '{}' was pushed to Jira but no worklog ID was returned. \
+                        It cannot be reverted through wtf — delete it manually in Jira if needed.",
+                        wl.issue_id
+                    );
+                    wl.status = LocalWorklogState::Pushed;
+                    LocalWorklogService::production().save_local_worklog(wl.clone());
+                    local_worklogs_id.push(wl.id.clone());
+                    pushed.push(wl);
+                }
+                Err(err) => {
+                    logger::log(format!(
+                        "❌ failed to add time to '{}': {}",
+                        issue,
+                        err.with_hint()
+                    ));
+                    last_error = format!("failed to add time to '{}': {}", issue, err);
+                    failed += 1;
+                }
+            }
+            progress::event("push", index as u64 + 1, total, Some(&issue));
+        }
+        if !local_worklogs_id.is_empty() {
+            let history_id = LocalWorklogService::production().historize(local_worklogs_id.clone());
+            logger::log(format!(
+                "⬆️  Pushed {} worklog(s) to Jira",
+                local_worklogs_id.len()
+            ));
+            hooks::notify(
+                &hooks,
+                HookEvent::PostPush,
+                &json!({ "history_id": history_id, "pushed": pushed, "failed": failed }),
+            );
+        }
+
+        if failed > 0 && local_worklogs_id.is_empty() {
+            return Err(format!("{} worklog(s) failed to push: {}", failed, last_error).into());
+        }
+        if failed > 0 {
+            logger::log(format!(
+                "⚠️  {} worklog(s) failed to push, they stay {} for the next push",
+                failed,
+                if self.include_created {
+                    "local"
+                } else {
+                    "staged"
+                }
+            ));
+            exit::report(ExitStatus::PartialFailure);
         }
         Ok(())
     }
 }
+
+/// Back up the config and database before pushing, so a bad push can be rolled back with
+/// `wtf backup restore`. Old snapshots are pruned per `[backup] keep`.
+fn snapshot_before_push() {
+    let config = Config::load().unwrap_or_default().backup;
+    if !config.pre_push {
+        return;
+    }
+    let store = BackupStore::production();
+    match store.create(Some(PRE_PUSH_LABEL)) {
+        Ok(backup) => {
+            debug!("Pre-push backup created: {}", backup.path.display());
+            if let Err(e) = store.prune(config.keep) {
+                error!("Failed to prune old backups: {}", e);
+            }
+        }
+        Err(e) => logger::log(format!("⚠️  Pre-push backup failed: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn worklog(issue: &str, day: u32) -> LocalWorklog {
+        LocalWorklog {
+            id: format!("{}-{}", issue, day),
+            comment: String::new(),
+            time_spent_seconds: 3600,
+            issue_id: issue.to_string(),
+            status: LocalWorklogState::Staged,
+            started: Utc.with_ymd_and_hms(2026, 3, day, 9, 0, 0).unwrap(),
+            meeting_id: None,
+            worklog_id: None,
+        }
+    }
+
+    fn day(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, day).unwrap()
+    }
+
+    #[test]
+    fn test_push_without_filter_takes_everything() {
+        let task = PushStagedWorklogsTask::default();
+        assert!(task.matches(&worklog("PROJ-1", 2)));
+        assert!(task.matches(&worklog("OTHER-9", 28)));
+    }
+
+    #[test]
+    fn test_push_filters_on_issue_ignoring_case() {
+        let task = PushStagedWorklogsTask::default().with_issue("proj-1");
+        assert!(task.matches(&worklog("PROJ-1", 2)));
+        assert!(!task.matches(&worklog("PROJ-2", 2)));
+    }
+
+    #[test]
+    fn test_push_filters_on_inclusive_date_range() {
+        let task = PushStagedWorklogsTask::default().with_range(day(5), day(10));
+        assert!(!task.matches(&worklog("PROJ-1", 4)));
+        assert!(task.matches(&worklog("PROJ-1", 5)));
+        assert!(task.matches(&worklog("PROJ-1", 10)));
+        assert!(!task.matches(&worklog("PROJ-1", 11)));
+
+        let task = task.with_date(day(7)).with_issue("PROJ-1");
+        assert!(task.matches(&worklog("PROJ-1", 7)));
+        assert!(!task.matches(&worklog("PROJ-1", 8)));
+        assert!(!task.matches(&worklog("PROJ-2", 7)));
+    }
+}
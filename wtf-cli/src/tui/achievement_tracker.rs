@@ -8,6 +8,7 @@ pub struct AchievementTracker;
 impl AchievementTracker {
     /// Check which achievements should be unlocked based on an event
     /// Returns a list of achievements that meet their unlock conditions
+    #[allow(clippy::collapsible_match)]
     fn check_unlock_candidates(event: &AppEvent, _has_wizard: bool, tui: &Tui) -> Vec<Achievement> {
         let mut candidates = Vec::new();
 
@@ -54,9 +55,7 @@ impl AchievementTracker {
                 }
 
                 // Wizard-only achievements
-                if tui.wizard_state.is_some() {
-                    let wizard = tui.wizard_state.as_ref().unwrap();
-
+                if let Some(wizard) = tui.wizard_state.as_ref() {
                     // Speed Runner: wizard start to push in under 3 minutes
                     if Self::is_speed_run(&wizard.started_at) {
                         candidates.push(Achievement::SpeedRunner);
@@ -0,0 +1,129 @@
+use ratatui::buffer::Buffer;
+
+/// Rewrite every cell of a rendered frame to plain ASCII (`ui.ascii_mode`).
+///
+/// Working on the finished buffer instead of on each widget keeps the mode
+/// complete: emoji in log lines, braille chart markers and block borders all
+/// go through the same table. Accented letters and other text are left alone.
+pub(in crate::tui) fn asciify_buffer(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        let Some(first) = cell.symbol().chars().next() else {
+            continue;
+        };
+        if first.is_ascii() {
+            continue;
+        }
+        if let Some(replacement) = ascii_char(first) {
+            cell.set_char(replacement);
+        }
+    }
+}
+
+/// The ASCII stand-in for a symbol, or `None` when the character is regular text
+fn ascii_char(c: char) -> Option<char> {
+    let code = c as u32;
+    let replacement = match c {
+        '\u{2010}'..='\u{2015}' => '-',
+        '\u{2018}' | '\u{2019}' => '\'',
+        '\u{201C}' | '\u{201D}' => '"',
+        '•' => '*',
+        '·' | '…' => '.',
+        '←' | '↩' | '◀' | '⏪' => '<',
+        '→' | '▶' | '►' | '❯' | '⏭' | '⏩' => '>',
+        '↑' | '▲' => '^',
+        '↓' | '▼' => 'v',
+        '≥' => '>',
+        '≤' => '<',
+        '✓' | '✔' | '✅' => 'v',
+        '✗' | '✖' | '❌' => 'x',
+        '⚠' | '❗' => '!',
+        'ℹ' => 'i',
+        '●' | '○' | '◯' => 'o',
+        '■' | '⬜' => '#',
+        '★' | '⭐' | '✨' => '*',
+        '⟳' | '🔄' | '🔁' => '~',
+        '⏱' | '⏰' | '⏳' | '🕒' => '~',
+        // Box drawing: keep lines readable, everything that joins becomes a corner
+        '─' | '━' | '═' | '╌' | '╍' | '┄' | '┅' | '┈' | '┉' | '╴' | '╶' | '╸' | '╺' => {
+            '-'
+        }
+        '│' | '┃' | '║' | '╎' | '╏' | '┆' | '┇' | '┊' | '┋' | '╵' | '╷' | '╹' | '╻' => {
+            '|'
+        }
+        '\u{2500}'..='\u{257F}' => '+',
+        // Block elements (gauges, progress bars, sparklines)
+        '░' => '.',
+        '▒' => ':',
+        '▁' | '▂' => '_',
+        '▃' | '▄' => '=',
+        '\u{2580}'..='\u{259F}' => '#',
+        // Braille (charts and spinners): shade by how many dots are raised
+        '\u{2800}'..='\u{28FF}' => match (code - 0x2800).count_ones() {
+            0 => ' ',
+            1..=2 => '.',
+            3..=5 => ':',
+            _ => '#',
+        },
+        // Remaining pictographs and emoji
+        '\u{2300}'..='\u{23FF}'
+        | '\u{25A0}'..='\u{25FF}'
+        | '\u{2600}'..='\u{27BF}'
+        | '\u{27C0}'..='\u{27FF}'
+        | '\u{2B00}'..='\u{2BFF}'
+        | '\u{1F000}'..='\u{1FAFF}' => '*',
+        _ => return None,
+    };
+    Some(replacement)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+
+    #[test]
+    fn test_symbols_map_to_ascii() {
+        assert_eq!(ascii_char('✅'), Some('v'));
+        assert_eq!(ascii_char('❌'), Some('x'));
+        assert_eq!(ascii_char('⚠'), Some('!'));
+        assert_eq!(ascii_char('🧙'), Some('*'));
+        assert_eq!(ascii_char('→'), Some('>'));
+        assert_eq!(ascii_char('—'), Some('-'));
+    }
+
+    #[test]
+    fn test_box_drawing_and_blocks() {
+        assert_eq!(ascii_char('─'), Some('-'));
+        assert_eq!(ascii_char('│'), Some('|'));
+        assert_eq!(ascii_char('╭'), Some('+'));
+        assert_eq!(ascii_char('┼'), Some('+'));
+        assert_eq!(ascii_char('█'), Some('#'));
+        assert_eq!(ascii_char('░'), Some('.'));
+    }
+
+    #[test]
+    fn test_braille_is_shaded_by_dot_count() {
+        assert_eq!(ascii_char('\u{2800}'), Some(' '));
+        assert_eq!(ascii_char('⢀'), Some('.'));
+        assert_eq!(ascii_char('⠋'), Some(':'));
+        assert_eq!(ascii_char('⣿'), Some('#'));
+    }
+
+    #[test]
+    fn test_text_is_left_alone() {
+        assert_eq!(ascii_char('é'), None);
+        assert_eq!(ascii_char('ß'), None);
+        assert_eq!(ascii_char('日'), None);
+    }
+
+    #[test]
+    fn test_asciify_buffer_rewrites_every_cell() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        buffer.set_string(0, 0, "✅ é─│", ratatui::style::Style::default());
+
+        asciify_buffer(&mut buffer);
+
+        let rendered: String = buffer.content.iter().map(|c| c.symbol()).collect();
+        assert_eq!(rendered.trim_end(), "v  é-|");
+    }
+}
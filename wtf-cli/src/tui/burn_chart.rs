@@ -0,0 +1,125 @@
+use chrono::NaiveDate;
+use wtf_lib::config::WorklogConfig;
+
+use crate::tui::data::DayActivity;
+
+/// Points of the sprint burn-up chart. `x` is the number of sprint days
+/// elapsed (0 = sprint start), `y` the cumulative hours.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BurnSeries {
+    /// Capacity accumulated at the target hours of every workday
+    pub ideal: Vec<(f64, f64)>,
+    /// Hours actually logged, up to today
+    pub actual: Vec<(f64, f64)>,
+    pub capacity: f64,
+}
+
+impl BurnSeries {
+    pub fn compute(activities: &[DayActivity], worklog: &WorklogConfig, today: NaiveDate) -> Self {
+        let mut ideal = vec![(0.0, 0.0)];
+        let mut actual = vec![(0.0, 0.0)];
+        let mut planned = 0.0;
+        let mut logged = 0.0;
+
+        for (idx, activity) in activities.iter().enumerate() {
+            let x = (idx + 1) as f64;
+            if !activity.is_absence {
+                planned += worklog.target_hours(activity.date);
+            }
+            ideal.push((x, planned));
+
+            if activity.date <= today {
+                logged += activity.hours.max(0.0);
+                actual.push((x, logged));
+            }
+        }
+
+        Self {
+            ideal,
+            actual,
+            capacity: planned,
+        }
+    }
+
+    pub fn days(&self) -> f64 {
+        self.ideal.last().map(|(x, _)| *x).unwrap_or(0.0)
+    }
+
+    /// Hours logged minus hours planned at the latest logged point (negative when behind)
+    pub fn delta(&self) -> f64 {
+        let Some(&(x, logged)) = self.actual.last() else {
+            return 0.0;
+        };
+        let planned = self
+            .ideal
+            .iter()
+            .find(|(ix, _)| *ix == x)
+            .map(|(_, y)| *y)
+            .unwrap_or(0.0);
+        logged - planned
+    }
+
+    pub fn max_hours(&self) -> f64 {
+        let logged = self.actual.last().map(|(_, y)| *y).unwrap_or(0.0);
+        self.capacity.max(logged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(date: u32, hours: f64, is_absence: bool) -> DayActivity {
+        DayActivity {
+            // March 2026: the 2nd is a Monday
+            date: NaiveDate::from_ymd_opt(2026, 3, date).unwrap(),
+            hours,
+            is_absence,
+        }
+    }
+
+    #[test]
+    fn test_ideal_line_is_flat_on_weekends_and_absences() {
+        let activities = vec![
+            day(5, 8.0, false),
+            day(6, 0.0, true),
+            day(7, 0.0, false),
+            day(8, 0.0, false),
+            day(9, 4.0, false),
+        ];
+        let today = NaiveDate::from_ymd_opt(2026, 3, 9).unwrap();
+
+        let series = BurnSeries::compute(&activities, &WorklogConfig::default(), today);
+
+        let ideal: Vec<f64> = series.ideal.iter().map(|(_, y)| *y).collect();
+        assert_eq!(ideal, vec![0.0, 8.0, 8.0, 8.0, 8.0, 16.0]);
+        assert_eq!(series.capacity, 16.0);
+        assert_eq!(series.actual.last(), Some(&(5.0, 12.0)));
+        assert_eq!(series.delta(), -4.0);
+    }
+
+    #[test]
+    fn test_actual_line_stops_at_today() {
+        let activities = vec![day(2, 6.0, false), day(3, 0.0, false), day(4, 0.0, false)];
+        let today = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+
+        let series = BurnSeries::compute(&activities, &WorklogConfig::default(), today);
+
+        assert_eq!(series.actual, vec![(0.0, 0.0), (1.0, 6.0)]);
+        assert_eq!(series.days(), 3.0);
+        assert_eq!(series.max_hours(), 24.0);
+        assert_eq!(series.delta(), -2.0);
+    }
+
+    #[test]
+    fn test_ideal_line_follows_the_weekday_targets() {
+        let worklog: WorklogConfig = toml::from_str("[weekday_hours]\nwed = 4\nfri = 0").unwrap();
+        let activities = vec![day(4, 0.0, false), day(5, 0.0, false), day(6, 0.0, false)];
+        let today = NaiveDate::from_ymd_opt(2026, 3, 6).unwrap();
+
+        let series = BurnSeries::compute(&activities, &worklog, today);
+
+        let ideal: Vec<f64> = series.ideal.iter().map(|(_, y)| *y).collect();
+        assert_eq!(ideal, vec![0.0, 4.0, 12.0, 12.0]);
+    }
+}
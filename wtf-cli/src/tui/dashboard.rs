@@ -0,0 +1,310 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use std::collections::{HashMap, HashSet};
+use wtf_lib::config::WorklogConfig;
+use wtf_lib::models::data::{GitHubSession, LocalWorklog, Sprint, Worklog};
+
+use crate::tui::data::DayActivity;
+use crate::tui::worklog_filter::WorklogSourceKind;
+
+/// One logged chunk of time, from a local worklog or a Jira worklog wtf doesn't track
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoggedEntry {
+    pub date: NaiveDate,
+    pub issue: String,
+    pub hours: f64,
+    pub source: WorklogSourceKind,
+}
+
+/// Totals for a date range (this week or the current sprint)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PeriodStats {
+    pub label: String,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub logged_hours: f64,
+    pub target_hours: f64,
+    /// Hours per issue, largest first
+    pub by_issue: Vec<(String, f64)>,
+    /// Hours per source, largest first
+    pub by_source: Vec<(WorklogSourceKind, f64)>,
+    /// Past workdays (absences excluded) without any logged time
+    pub unlogged_days: Vec<NaiveDate>,
+}
+
+/// Figures shown on the Dashboard tab
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DashboardStats {
+    pub week: PeriodStats,
+    pub sprint: Option<PeriodStats>,
+    /// Longest run of consecutive workdays with time logged (weekends and absences don't break it)
+    pub longest_streak: usize,
+    pub current_streak: usize,
+}
+
+impl DashboardStats {
+    pub fn compute(
+        today: NaiveDate,
+        entries: &[LoggedEntry],
+        absences: &HashSet<NaiveDate>,
+        sprint: Option<(&Sprint, NaiveDate, NaiveDate)>,
+        worklog: &WorklogConfig,
+    ) -> Self {
+        let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+        let week = period_stats(
+            "This week".to_string(),
+            monday,
+            monday + Duration::days(6),
+            today,
+            entries,
+            absences,
+            worklog,
+        );
+        let sprint = sprint.map(|(sprint, start, end)| {
+            period_stats(
+                sprint.name.clone(),
+                start,
+                end,
+                today,
+                entries,
+                absences,
+                worklog,
+            )
+        });
+
+        let logged_days: HashSet<NaiveDate> = entries
+            .iter()
+            .filter(|e| e.hours > 0.0)
+            .map(|e| e.date)
+            .collect();
+        let (longest_streak, current_streak) = streaks(&logged_days, absences, worklog, today);
+
+        Self {
+            week,
+            sprint,
+            longest_streak,
+            current_streak,
+        }
+    }
+}
+
+/// Local worklogs plus the Jira worklogs that don't come from one of them
+pub fn logged_entries(
+    local_worklogs: &[LocalWorklog],
+    jira_worklogs: &[Worklog],
+    github_sessions: &[GitHubSession],
+) -> Vec<LoggedEntry> {
+    let tracked_ids: HashSet<&str> = local_worklogs
+        .iter()
+        .filter_map(|w| w.worklog_id.as_deref())
+        .collect();
+
+    let local = local_worklogs.iter().map(|w| LoggedEntry {
+        date: w.started.date_naive(),
+        issue: w.issue_id.clone(),
+        hours: (w.time_spent_seconds as f64 / 3600.0).max(0.0),
+        source: WorklogSourceKind::of(w, github_sessions),
+    });
+    let jira_only = jira_worklogs
+        .iter()
+        .filter(|w| !tracked_ids.contains(w.id.as_str()))
+        .map(|w| LoggedEntry {
+            date: w.started.date_naive(),
+            issue: w.issue_id.clone(),
+            hours: w.time_spent_seconds as f64 / 3600.0,
+            source: WorklogSourceKind::Other,
+        });
+
+    local.chain(jira_only).collect()
+}
+
+/// Absence days as flagged in the activity data
+pub fn absence_days(days: &[DayActivity]) -> HashSet<NaiveDate> {
+    days.iter()
+        .filter(|d| d.is_absence)
+        .map(|d| d.date)
+        .collect()
+}
+
+/// The sprint running today, or else the most recently started one
+pub fn current_sprint(sprints: &[Sprint], today: NaiveDate) -> Option<&Sprint> {
+    let started: Vec<&Sprint> = sprints
+        .iter()
+        .filter(|s| s.start.is_some_and(|start| start.date_naive() <= today))
+        .collect();
+    started
+        .iter()
+        .find(|s| s.end.is_some_and(|end| end.date_naive() >= today))
+        .or_else(|| started.iter().max_by_key(|s| s.start))
+        .copied()
+}
+
+fn is_workday(date: NaiveDate, absences: &HashSet<NaiveDate>, worklog: &WorklogConfig) -> bool {
+    worklog.is_workday(date) && !absences.contains(&date)
+}
+
+fn period_stats(
+    label: String,
+    start: NaiveDate,
+    end: NaiveDate,
+    today: NaiveDate,
+    entries: &[LoggedEntry],
+    absences: &HashSet<NaiveDate>,
+    worklog: &WorklogConfig,
+) -> PeriodStats {
+    let in_period: Vec<&LoggedEntry> = entries
+        .iter()
+        .filter(|e| e.date >= start && e.date <= end)
+        .collect();
+
+    let mut by_issue: HashMap<String, f64> = HashMap::new();
+    let mut by_source: HashMap<WorklogSourceKind, f64> = HashMap::new();
+    for entry in &in_period {
+        *by_issue.entry(entry.issue.clone()).or_default() += entry.hours;
+        *by_source.entry(entry.source).or_default() += entry.hours;
+    }
+
+    let logged_dates: HashSet<NaiveDate> = in_period
+        .iter()
+        .filter(|e| e.hours > 0.0)
+        .map(|e| e.date)
+        .collect();
+    let workdays: Vec<NaiveDate> = start
+        .iter_days()
+        .take_while(|d| *d <= end)
+        .filter(|d| is_workday(*d, absences, worklog))
+        .collect();
+    let unlogged_days = workdays
+        .iter()
+        .filter(|d| **d < today && !logged_dates.contains(d))
+        .copied()
+        .collect();
+
+    PeriodStats {
+        label,
+        start,
+        end,
+        logged_hours: in_period.iter().map(|e| e.hours).sum(),
+        target_hours: workdays.iter().map(|d| worklog.target_hours(*d)).sum(),
+        by_issue: sorted_desc(by_issue),
+        by_source: sorted_desc(by_source),
+        unlogged_days,
+    }
+}
+
+fn sorted_desc<K: Ord>(totals: HashMap<K, f64>) -> Vec<(K, f64)> {
+    let mut totals: Vec<_> = totals.into_iter().collect();
+    totals.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    totals
+}
+
+/// (longest, current) streaks of logged workdays. Today only extends the
+/// current streak once something is logged; it never breaks it.
+fn streaks(
+    logged_days: &HashSet<NaiveDate>,
+    absences: &HashSet<NaiveDate>,
+    worklog: &WorklogConfig,
+    today: NaiveDate,
+) -> (usize, usize) {
+    let Some(first) = logged_days.iter().min().copied() else {
+        return (0, 0);
+    };
+
+    let mut longest = 0;
+    let mut run = 0;
+    for day in first.iter_days().take_while(|d| *d <= today) {
+        if !is_workday(day, absences, worklog) {
+            continue;
+        }
+        if logged_days.contains(&day) {
+            run += 1;
+            longest = longest.max(run);
+        } else if day < today {
+            run = 0;
+        }
+    }
+    (longest, run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        // March 2026: the 2nd is a Monday
+        NaiveDate::from_ymd_opt(2026, 3, day).unwrap()
+    }
+
+    fn entry(day: u32, issue: &str, hours: f64, source: WorklogSourceKind) -> LoggedEntry {
+        LoggedEntry {
+            date: date(day),
+            issue: issue.to_string(),
+            hours,
+            source,
+        }
+    }
+
+    #[test]
+    fn test_week_totals_and_unlogged_days() {
+        let entries = vec![
+            entry(2, "PROJ-1", 6.0, WorklogSourceKind::GitHub),
+            entry(2, "PROJ-2", 2.0, WorklogSourceKind::Meeting),
+            entry(4, "PROJ-1", 3.0, WorklogSourceKind::GapFill),
+            entry(1, "PROJ-9", 8.0, WorklogSourceKind::Other), // previous week
+        ];
+        let absences = HashSet::from([date(5)]);
+
+        let stats = DashboardStats::compute(
+            date(6),
+            &entries,
+            &absences,
+            None,
+            &WorklogConfig::default(),
+        );
+
+        assert_eq!(stats.week.start, date(2));
+        assert_eq!(stats.week.logged_hours, 11.0);
+        assert_eq!(stats.week.target_hours, 32.0);
+        assert_eq!(stats.week.by_issue[0], ("PROJ-1".to_string(), 9.0));
+        assert_eq!(stats.week.by_source[0], (WorklogSourceKind::GitHub, 6.0));
+        assert_eq!(stats.week.unlogged_days, vec![date(3)]);
+        assert!(stats.sprint.is_none());
+    }
+
+    #[test]
+    fn test_streaks_skip_weekends_and_absences() {
+        let logged = HashSet::from([date(2), date(3), date(5), date(6), date(9), date(11)]);
+        let absences = HashSet::from([date(4)]);
+        let worklog = WorklogConfig::default();
+
+        // 2, 3, (4 absent), 5, 6, (weekend), 9 → 5 days; 10 missing breaks it
+        assert_eq!(streaks(&logged, &absences, &worklog, date(11)), (5, 1));
+        // Nothing logged yet today: the streak up to yesterday still counts
+        assert_eq!(streaks(&logged, &absences, &worklog, date(10)), (5, 5));
+        assert_eq!(
+            streaks(&HashSet::new(), &absences, &worklog, date(10)),
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn test_days_off_of_a_part_time_schedule() {
+        let worklog: WorklogConfig = toml::from_str("[weekday_hours]\nwed = 4\nfri = 0").unwrap();
+        let entries = vec![entry(2, "PROJ-1", 8.0, WorklogSourceKind::Other)];
+
+        let stats = DashboardStats::compute(date(9), &entries, &HashSet::new(), None, &worklog);
+
+        // Mon-Thu of the week of the 2nd, Friday the 6th off
+        assert_eq!(stats.week.start, date(9));
+        assert_eq!(stats.week.target_hours, 28.0);
+        let last_week = period_stats(
+            String::new(),
+            date(2),
+            date(8),
+            date(9),
+            &entries,
+            &HashSet::new(),
+            &worklog,
+        );
+        assert_eq!(last_week.unlogged_days, vec![date(3), date(4), date(5)]);
+    }
+}
@@ -1,6 +1,16 @@
+use crate::logger::LogLevel;
+use crate::tui::dashboard::{self, DashboardStats};
+use crate::tui::github_session_edit::SessionEditor;
+use crate::tui::quick_worklog::QuickWorklog;
+use crate::tui::sorting::ListSort;
+use crate::tui::types::Tab;
+use crate::tui::ui_helpers::SplitLayout;
+use crate::tui::ui_persistence::PersistedUiState;
+use crate::tui::worklog_filter::{WorklogFilter, WorklogFilterEditor};
 use chrono::{DateTime, NaiveDate, Utc};
 use std::collections::{BTreeSet, HashMap};
-use wtf_lib::config::Config;
+use wtf_lib::client::offline;
+use wtf_lib::config::{Config, WorklogConfig};
 use wtf_lib::models::achievement::AchievementUnlock;
 use wtf_lib::models::data::{
     GitHubEvent, GitHubSession, Issue, LocalWorklog, LocalWorklogHistory, Meeting, Sprint,
@@ -8,6 +18,7 @@ use wtf_lib::models::data::{
 use wtf_lib::services::github_service::GitHubService;
 use wtf_lib::services::jira_service::{IssueService, JiraService};
 use wtf_lib::services::meetings_service::MeetingsService;
+use wtf_lib::services::sync_status_service::SyncStatusService;
 use wtf_lib::services::worklogs_service::{LocalWorklogService, WorklogsService};
 
 /// State of a Jira issue title lookup for the Settings color label display.
@@ -33,16 +44,37 @@ pub struct DayActivity {
     pub is_absence: bool,
 }
 
+/// GitHub session hours of a day, and whether the day has worklogs
+#[derive(Debug, Clone, Copy)]
+pub struct GitHubDayActivity {
+    pub date: NaiveDate,
+    pub session_hours: f64,
+    pub logged: bool,
+}
+
 /// Statistics about meetings (only pending count needed for dashboard)
 #[derive(Debug, Clone)]
 pub struct MeetingStats {
     pub pending: usize,
 }
 
+/// GitHub session drill-down popup (opened with Enter on the GitHub tab)
+#[derive(Debug, Clone)]
+pub struct GitHubSessionDrillDown {
+    pub session_id: String,
+    pub scroll_offset: u16,
+}
+
 /// UI state for tabs (selections, filters, expansions)
 #[derive(Debug, Clone, Default)]
 pub struct TabUiState {
+    /// Tab open when the previous run quit; taken once on startup
+    pub restored_tab: Option<Tab>,
     pub selected_sprint_index: usize,
+    /// List/detail split shared by the Meetings, GitHub, History and Worklogs tabs
+    pub split_layout: SplitLayout,
+    /// Sprints tab shows the per-issue breakdown instead of the daily activity
+    pub show_sprint_issues: bool,
     pub selected_meeting_index: usize,
     pub selected_worklog_index: usize,
     pub selected_github_session_index: usize,
@@ -50,8 +82,38 @@ pub struct TabUiState {
     pub expanded_history_ids: std::collections::HashSet<String>,
     pub filter_unlinked_only: bool,
     pub filter_staged_only: bool,
+    pub worklog_filter: WorklogFilter,
+    /// Open filter popup on the Worklogs tab (None = closed)
+    pub worklog_filter_editor: Option<WorklogFilterEditor>,
+    pub worklog_sort: ListSort,
+    pub meeting_sort: ListSort,
+    pub github_sort: ListSort,
+    pub github_drill_down: Option<GitHubSessionDrillDown>,
+    /// Open split/trim popup on the GitHub tab (None = closed)
+    pub github_session_editor: Option<SessionEditor>,
     pub meeting_search_query: String,
     pub meeting_search_active: bool,
+    /// Meetings tab: only meetings with this Google Calendar color id (None = all)
+    pub meeting_color_filter: Option<String>,
+    /// Meetings tab: only meetings fetched from this Google calendar (None = all)
+    pub meeting_calendar_filter: Option<String>,
+    /// Logs tab: minimum level shown, search query and lines scrolled up from the newest
+    pub log_min_level: LogLevel,
+    pub log_search_query: String,
+    pub log_search_active: bool,
+    pub log_view_scroll: usize,
+    /// Issues tab
+    pub selected_issue_index: usize,
+    pub issue_search_query: String,
+    pub issue_search_active: bool,
+    pub favorite_issues: BTreeSet<String>,
+    pub favorite_issues_only: bool,
+    /// Issues tab lists epics with their rolled-up hours instead of issues
+    pub issues_by_epic: bool,
+    pub selected_epic_index: usize,
+    pub quick_worklog: Option<QuickWorklog>,
+    /// Wizard steps turned off on the setup screen (remembered across runs)
+    pub wizard_skipped_steps: BTreeSet<usize>,
     pub achievements_scroll_offset: usize,
     // Settings tab state
     pub settings_selected_field: usize,
@@ -83,18 +145,22 @@ pub struct TuiData {
     pub meeting_stats: MeetingStats,
     pub sprint_activities: HashMap<usize, Vec<DayActivity>>,
     pub worklog_wall: Vec<DayActivity>,
-    pub last_sync: DateTime<Utc>,
+    /// GitHub activity over the days of the worklog wall
+    pub github_heatmap: Vec<GitHubDayActivity>,
+    /// Oldest successful fetch among Jira, Google and GitHub (see `wtf daemon`)
+    pub last_sync: Option<DateTime<Utc>>,
     pub daily_hours_limit: f64,
     pub config: Config,
     pub ui_state: TabUiState,
     pub unlocked_achievements: Vec<AchievementUnlock>,
     pub tiered_progress: std::collections::HashMap<String, u64>,
+    pub dashboard: DashboardStats,
 }
 
 impl TuiData {
-    /// Collect all dashboard data from services
+    /// Collect all dashboard data from services, restoring the UI state of the previous run
     pub fn collect() -> Self {
-        Self::collect_with_ui_state(TabUiState::default())
+        Self::collect_with_ui_state(PersistedUiState::load().into_ui_state())
     }
 
     /// Collect data while preserving existing UI state
@@ -136,6 +202,19 @@ impl TuiData {
             Self::calculate_meeting_stats(&all_meetings, &config, &untracked_meeting_ids);
         let sprint_activities = Self::calculate_all_sprint_activities(&sprints);
         let worklog_wall = Self::calculate_worklog_wall();
+        let github_heatmap = Self::calculate_github_heatmap(
+            &GitHubService::production()
+                .get_all_sessions()
+                .unwrap_or_default(),
+            &worklog_wall,
+        );
+        let dashboard = Self::calculate_dashboard(
+            &sprints,
+            &sprint_activities,
+            &worklog_wall,
+            &dashboard::logged_entries(&all_worklogs, &jira_worklogs, &github_sessions),
+            &config.worklog,
+        );
         let unlocked_achievements =
             wtf_lib::services::achievement_service::AchievementService::production()
                 .get_all_unlocked();
@@ -154,7 +233,8 @@ impl TuiData {
             meeting_stats,
             sprint_activities,
             worklog_wall,
-            last_sync: Utc::now(),
+            github_heatmap,
+            last_sync: SyncStatusService::production().oldest_sync(),
             daily_hours_limit: config.worklog.daily_hours_limit,
             config,
             ui_state,
@@ -163,6 +243,7 @@ impl TuiData {
                 wtf_lib::services::tiered_achievement_service::TieredAchievementService::production(
                 )
                 .get_all_progress(),
+            dashboard,
         }
     }
 
@@ -237,6 +318,13 @@ impl TuiData {
         if missing_keys.is_empty() {
             return validations;
         }
+        if offline::is_enabled() {
+            // Can't be checked against Jira: treat them as unknown issues
+            for key in missing_keys {
+                validations.insert(key, GitHubIssueValidation::Missing);
+            }
+            return validations;
+        }
 
         let fetched_issues = std::thread::spawn(move || {
             let jira_service = JiraService::production();
@@ -320,7 +408,11 @@ impl TuiData {
         use wtf_lib::utils::meetings::is_untracked;
         let pending = meetings
             .iter()
-            .filter(|m| m.jira_link.is_none() && !is_untracked(m, config, untracked_ids))
+            .filter(|m| {
+                m.jira_link.is_none()
+                    && config.meetings.attends(m)
+                    && !is_untracked(m, config, untracked_ids)
+            })
             .count();
         MeetingStats { pending }
     }
@@ -432,6 +524,26 @@ impl TuiData {
         activities
     }
 
+    fn calculate_dashboard(
+        sprints: &[Sprint],
+        sprint_activities: &HashMap<usize, Vec<DayActivity>>,
+        worklog_wall: &[DayActivity],
+        entries: &[dashboard::LoggedEntry],
+        worklog: &WorklogConfig,
+    ) -> DashboardStats {
+        let today = chrono::Local::now().date_naive();
+        let mut absences = dashboard::absence_days(worklog_wall);
+
+        // Sprint bounds follow the activity calendar of the Sprints tab
+        let sprint = dashboard::current_sprint(sprints, today).and_then(|sprint| {
+            let days = sprint_activities.get(&sprint.id)?;
+            absences.extend(dashboard::absence_days(days));
+            Some((sprint, days.first()?.date, days.last()?.date))
+        });
+
+        DashboardStats::compute(today, entries, &absences, sprint, worklog)
+    }
+
     /// Calculate worklog wall data - last 365 days (full year) of daily activity
     fn calculate_worklog_wall() -> Vec<DayActivity> {
         use chrono::{Datelike, Duration, Local};
@@ -523,16 +635,70 @@ impl TuiData {
 
         activities
     }
+
+    /// Session hours for each day of the worklog wall; absences count as logged
+    fn calculate_github_heatmap(
+        sessions: &[GitHubSession],
+        worklog_wall: &[DayActivity],
+    ) -> Vec<GitHubDayActivity> {
+        let mut session_hours: HashMap<NaiveDate, f64> = HashMap::new();
+        for session in sessions {
+            *session_hours.entry(session.date).or_insert(0.0) += session.duration_hours();
+        }
+
+        worklog_wall
+            .iter()
+            .map(|day| GitHubDayActivity {
+                date: day.date,
+                session_hours: session_hours.get(&day.date).copied().unwrap_or(0.0),
+                logged: day.hours > 0.0,
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::GitHubIssueValidation;
-    use super::TuiData;
-    use chrono::{TimeZone, Utc};
+    use super::{DayActivity, TuiData};
+    use chrono::{NaiveDate, TimeZone, Utc};
     use std::collections::HashMap;
     use wtf_lib::models::data::{GitHubEvent, GitHubSession};
 
+    #[test]
+    fn github_heatmap_flags_days_without_worklogs() {
+        let session = |day, hours: i64| {
+            let start = Utc.with_ymd_and_hms(2026, 3, day, 9, 0, 0).unwrap();
+            GitHubSession::new(
+                start,
+                start + chrono::Duration::hours(hours),
+                hours * 3600,
+                "org/repo".to_string(),
+                String::new(),
+                Vec::new(),
+                Vec::new(),
+            )
+        };
+        let wall: Vec<DayActivity> = [(16, 7.0), (17, 0.0), (18, 0.0)]
+            .into_iter()
+            .map(|(day, hours)| DayActivity {
+                date: NaiveDate::from_ymd_opt(2026, 3, day).unwrap(),
+                hours,
+                is_absence: false,
+            })
+            .collect();
+
+        let heatmap = TuiData::calculate_github_heatmap(
+            &[session(16, 2), session(17, 1), session(17, 2)],
+            &wall,
+        );
+
+        assert_eq!(heatmap.len(), 3);
+        assert!(heatmap[0].logged && heatmap[0].session_hours == 2.0);
+        assert!(!heatmap[1].logged && heatmap[1].session_hours == 3.0);
+        assert_eq!(heatmap[2].session_hours, 0.0);
+    }
+
     #[test]
     fn collect_detected_github_issue_keys_deduplicates_session_and_event_keys() {
         let session = GitHubSession {
@@ -0,0 +1,208 @@
+use chrono::{DateTime, Local, NaiveTime, Utc};
+use crossterm::event::{KeyCode, KeyEvent};
+use wtf_lib::models::data::{GitHubEvent, GitHubSession};
+
+/// Which edit the GitHub session popup performs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionEditMode {
+    Split,
+    Trim,
+}
+
+/// Editing state of the split/trim popup on the GitHub tab (opened with `s` / `t`)
+#[derive(Debug, Clone)]
+pub struct SessionEditor {
+    pub session_id: String,
+    pub mode: SessionEditMode,
+    /// `[at]` when splitting, `[start, end]` when trimming, as HH:MM local time
+    pub fields: Vec<String>,
+    pub selected_field: usize,
+    pub error: Option<String>,
+}
+
+/// Outcome of a key press in the session editor; times are local, on the session's day
+#[derive(Debug, PartialEq, Eq)]
+pub enum SessionEditAction {
+    None,
+    Split(NaiveTime),
+    Trim(NaiveTime, NaiveTime),
+    Cancel,
+}
+
+impl SessionEditor {
+    /// Start a split, suggesting the middle of the largest gap between events
+    pub fn split(session: &GitHubSession, events: &[&GitHubEvent]) -> Self {
+        let mut timestamps: Vec<_> = events.iter().map(|e| e.timestamp).collect();
+        timestamps.sort();
+        let suggestion = timestamps
+            .windows(2)
+            .max_by_key(|pair| pair[1] - pair[0])
+            .map(|pair| pair[0] + (pair[1] - pair[0]) / 2)
+            .unwrap_or_else(|| session.start_time + (session.end_time - session.start_time) / 2);
+
+        Self {
+            session_id: session.id.clone(),
+            mode: SessionEditMode::Split,
+            fields: vec![time_label(suggestion)],
+            selected_field: 0,
+            error: None,
+        }
+    }
+
+    pub fn trim(session: &GitHubSession) -> Self {
+        Self {
+            session_id: session.id.clone(),
+            mode: SessionEditMode::Trim,
+            fields: vec![time_label(session.start_time), time_label(session.end_time)],
+            selected_field: 0,
+            error: None,
+        }
+    }
+
+    pub fn field_labels(&self) -> &'static [&'static str] {
+        match self.mode {
+            SessionEditMode::Split => &["Split at"],
+            SessionEditMode::Trim => &["Start", "End"],
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> SessionEditAction {
+        match key.code {
+            KeyCode::Esc => return SessionEditAction::Cancel,
+            KeyCode::Enter => match self.to_action() {
+                Ok(action) => return action,
+                Err(e) => self.error = Some(e),
+            },
+            KeyCode::Up | KeyCode::BackTab => {
+                self.selected_field = self.selected_field.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Tab => {
+                self.selected_field = (self.selected_field + 1).min(self.fields.len() - 1);
+            }
+            KeyCode::Backspace => {
+                self.fields[self.selected_field].pop();
+            }
+            KeyCode::Char(c) if c.is_ascii_digit() || c == ':' => {
+                self.fields[self.selected_field].push(c);
+            }
+            _ => {}
+        }
+        SessionEditAction::None
+    }
+
+    fn to_action(&self) -> Result<SessionEditAction, String> {
+        let labels = self.field_labels();
+        let times = self
+            .fields
+            .iter()
+            .zip(labels)
+            .map(|(input, label)| parse_time(input, label))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(match (self.mode, times.as_slice()) {
+            (SessionEditMode::Split, [at]) => SessionEditAction::Split(*at),
+            (SessionEditMode::Trim, [start, end]) => SessionEditAction::Trim(*start, *end),
+            _ => SessionEditAction::None,
+        })
+    }
+}
+
+/// Times are entered in local time, as displayed in the GitHub list
+fn time_label(time: DateTime<Utc>) -> String {
+    time.with_timezone(&Local).format("%H:%M").to_string()
+}
+
+fn parse_time(input: &str, field: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(input.trim(), "%H:%M")
+        .map_err(|_| format!("'{}' must be a time like 14:30", field))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::empty())
+    }
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 15, hour, minute, 0).unwrap()
+    }
+
+    fn local(time: DateTime<Utc>) -> NaiveTime {
+        time.with_timezone(&Local).time()
+    }
+
+    fn event(id: &str, timestamp: DateTime<Utc>) -> GitHubEvent {
+        GitHubEvent {
+            id: id.to_string(),
+            event_type: "PushEvent".to_string(),
+            repo: "org/repo".to_string(),
+            timestamp,
+            description: String::new(),
+            jira_issues: String::new(),
+            date: timestamp.date_naive(),
+        }
+    }
+
+    fn session() -> GitHubSession {
+        GitHubSession::new(
+            at(9, 0),
+            at(18, 0),
+            9 * 3600,
+            "org/repo".to_string(),
+            String::new(),
+            Vec::new(),
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn test_split_suggests_middle_of_largest_gap() {
+        let events = [
+            event("e1", at(9, 0)),
+            event("e2", at(10, 0)),
+            event("e3", at(18, 0)),
+        ];
+        let refs: Vec<_> = events.iter().collect();
+
+        let mut editor = SessionEditor::split(&session(), &refs);
+
+        assert_eq!(editor.fields, vec![time_label(at(14, 0))]);
+        assert_eq!(
+            editor.handle_key(key(KeyCode::Enter)),
+            SessionEditAction::Split(local(at(14, 0)))
+        );
+    }
+
+    #[test]
+    fn test_trim_edits_end_time() {
+        let mut editor = SessionEditor::trim(&session());
+        editor.handle_key(key(KeyCode::Down));
+        for _ in 0..5 {
+            editor.handle_key(key(KeyCode::Backspace));
+        }
+        for c in "11:30".chars() {
+            editor.handle_key(key(KeyCode::Char(c)));
+        }
+
+        assert_eq!(
+            editor.handle_key(key(KeyCode::Enter)),
+            SessionEditAction::Trim(local(at(9, 0)), NaiveTime::from_hms_opt(11, 30, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_invalid_time_is_reported() {
+        let mut editor = SessionEditor::trim(&session());
+        editor.fields[0] = "25:00".to_string();
+
+        assert_eq!(
+            editor.handle_key(key(KeyCode::Enter)),
+            SessionEditAction::None
+        );
+        assert!(editor.error.is_some());
+    }
+}
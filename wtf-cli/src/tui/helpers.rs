@@ -1,4 +1,6 @@
 use crossterm::event::{KeyCode, KeyEvent};
+use std::collections::{BTreeSet, HashMap};
+use wtf_lib::models::data::Issue;
 
 /// Generic navigation handler for list-based tabs
 /// Returns true if the key was handled, false otherwise
@@ -28,6 +30,47 @@ pub fn handle_list_navigation(key: KeyEvent, selected_index: &mut usize, max_ind
     }
 }
 
+/// Step a filter through `options`: off → first → … → last → off
+pub fn cycle_filter<T: PartialEq + Clone>(current: Option<&T>, options: &[T]) -> Option<T> {
+    match current.and_then(|c| options.iter().position(|o| o == c)) {
+        None if current.is_none() => options.first().cloned(),
+        None => None,
+        Some(i) => options.get(i + 1).cloned(),
+    }
+}
+
+/// Issues for a selection popup: the starred ones first, then by key
+pub fn favorites_first(mut issues: Vec<Issue>, favorites: &BTreeSet<String>) -> Vec<Issue> {
+    issues.sort_by(|a, b| {
+        favorites
+            .contains(&b.key)
+            .cmp(&favorites.contains(&a.key))
+            .then_with(|| a.key.cmp(&b.key))
+    });
+    issues
+}
+
+/// Issues whose key or summary contains `query`, or that an alias containing it stands
+/// for (case-insensitive); every issue when the query is empty
+pub fn filter_issues<'a>(
+    issues: &'a [Issue],
+    query: &str,
+    aliases: &HashMap<String, String>,
+) -> Vec<&'a Issue> {
+    let query = query.to_lowercase();
+    let aliased: Vec<String> = aliases
+        .iter()
+        .filter(|(name, _)| name.to_lowercase().contains(&query))
+        .map(|(_, key)| key.trim().to_lowercase())
+        .collect();
+    issues
+        .iter()
+        .filter(|issue| {
+            query.is_empty() || issue.matches(&query) || aliased.contains(&issue.key.to_lowercase())
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,4 +156,53 @@ mod tests {
         ));
         assert_eq!(index, 5);
     }
+
+    #[test]
+    fn test_cycle_filter_walks_options_then_turns_off() {
+        let options = vec!["3".to_string(), "7".to_string()];
+
+        let first = cycle_filter(None, &options);
+        assert_eq!(first.as_deref(), Some("3"));
+        let second = cycle_filter(first.as_ref(), &options);
+        assert_eq!(second.as_deref(), Some("7"));
+        assert_eq!(cycle_filter(second.as_ref(), &options), None);
+    }
+
+    #[test]
+    fn test_cycle_filter_turns_off_a_stale_value() {
+        let options = vec!["3".to_string()];
+        assert_eq!(cycle_filter(Some(&"9".to_string()), &options), None);
+        assert_eq!(cycle_filter::<String>(None, &[]), None);
+    }
+
+    #[test]
+    fn test_filter_issues_matches_alias_names() {
+        let issue = |key: &str, summary: &str| Issue {
+            id: key.to_string(),
+            key: key.to_string(),
+            summary: summary.to_string(),
+            status: "To Do".to_string(),
+            created: chrono::Utc::now(),
+            sprint_ids: Vec::new(),
+            epic: None,
+            components: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
+        };
+        let issues = vec![
+            issue("PROJ-101", "Daily meeting"),
+            issue("OPS-7", "On call"),
+        ];
+        let aliases = HashMap::from([("standup".to_string(), "PROJ-101".to_string())]);
+
+        let keys = |query: &str| -> Vec<String> {
+            filter_issues(&issues, query, &aliases)
+                .iter()
+                .map(|i| i.key.clone())
+                .collect()
+        };
+        assert_eq!(keys("Stand"), vec!["PROJ-101"]);
+        assert_eq!(keys("ops"), vec!["OPS-7"]);
+        assert_eq!(keys("").len(), 2);
+    }
 }
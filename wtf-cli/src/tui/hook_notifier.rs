@@ -0,0 +1,46 @@
+use crate::tui::types::{AppEvent, EventSubscriber, Tui};
+use serde_json::json;
+use wtf_lib::models::data::LocalWorklogState;
+use wtf_lib::services::worklogs_service::LocalWorklogService;
+use wtf_lib::utils::hooks::{self, HookEvent};
+
+/// Runs the `post_push` and `fetch_complete` hooks of the `[hooks]` section
+pub struct HookNotifier;
+
+impl EventSubscriber for HookNotifier {
+    fn on_event(&mut self, event: &AppEvent, tui: &mut Tui) {
+        let config = &tui.data.config.hooks;
+        match event {
+            AppEvent::FetchComplete(_) => hooks::notify(
+                config,
+                HookEvent::FetchComplete,
+                &json!({ "type": "all", "error": null }),
+            ),
+            AppEvent::FetchError(err) => hooks::notify(
+                config,
+                HookEvent::FetchComplete,
+                &json!({ "type": "all", "error": err }),
+            ),
+            AppEvent::PushComplete { history_id } => {
+                let service = LocalWorklogService::production();
+                let (pushed, failed): (Vec<_>, Vec<_>) = service
+                    .get_history_by_id(history_id)
+                    .map(|entry| entry.local_worklogs_id)
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|id| service.get_local_worklog_by_id(id))
+                    .partition(|worklog| worklog.status == LocalWorklogState::Pushed);
+                hooks::notify(
+                    config,
+                    HookEvent::PostPush,
+                    &json!({
+                        "history_id": history_id,
+                        "pushed": pushed,
+                        "failed": failed.len(),
+                    }),
+                );
+            }
+            _ => {}
+        }
+    }
+}
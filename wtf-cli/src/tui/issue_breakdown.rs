@@ -0,0 +1,266 @@
+use chrono::NaiveDate;
+use std::collections::{HashMap, HashSet};
+use wtf_lib::models::data::{Issue, LocalWorklog, Sprint, Worklog, NO_EPIC};
+
+/// Hours logged on one issue over a date range
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IssueHours {
+    pub issue: String,
+    /// Local worklogs not (yet) in Jira
+    pub local_hours: f64,
+    pub jira_hours: f64,
+}
+
+impl IssueHours {
+    pub fn total(&self) -> f64 {
+        self.local_hours + self.jira_hours
+    }
+}
+
+/// Per-issue hours between `start` and `end` (inclusive), largest total first.
+/// Local worklogs already pushed to Jira only count on the Jira side, like the
+/// sprint activity graph.
+pub fn issue_breakdown(
+    local_worklogs: &[LocalWorklog],
+    jira_worklogs: &[Worklog],
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Vec<IssueHours> {
+    let in_range = |date: NaiveDate| date >= start && date <= end;
+    let jira_ids: HashSet<&str> = jira_worklogs.iter().map(|w| w.id.as_str()).collect();
+    let mut by_issue: HashMap<&str, IssueHours> = HashMap::new();
+
+    for worklog in jira_worklogs
+        .iter()
+        .filter(|w| in_range(w.started.date_naive()))
+    {
+        by_issue.entry(&worklog.issue_id).or_default().jira_hours +=
+            worklog.time_spent_seconds as f64 / 3600.0;
+    }
+    for worklog in local_worklogs.iter().filter(|w| {
+        in_range(w.started.date_naive())
+            && !w
+                .worklog_id
+                .as_deref()
+                .is_some_and(|id| jira_ids.contains(id))
+    }) {
+        by_issue.entry(&worklog.issue_id).or_default().local_hours +=
+            worklog.time_spent_seconds.max(0) as f64 / 3600.0;
+    }
+
+    let mut breakdown: Vec<IssueHours> = by_issue
+        .into_iter()
+        .map(|(issue, hours)| IssueHours {
+            issue: issue.to_string(),
+            ..hours
+        })
+        .collect();
+    breakdown.sort_by(|a, b| {
+        b.total()
+            .total_cmp(&a.total())
+            .then_with(|| a.issue.cmp(&b.issue))
+    });
+    breakdown
+}
+
+/// Hours logged on the issues of one epic, all time and per sprint
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EpicHours {
+    /// Epic key, or [`NO_EPIC`]
+    pub epic: String,
+    /// Issues of the epic with time logged, largest total first
+    pub issues: Vec<IssueHours>,
+    /// (sprint name, hours) for the sprints with time on the epic, oldest first
+    pub sprints: Vec<(String, f64)>,
+}
+
+impl EpicHours {
+    pub fn total(&self) -> f64 {
+        self.issues.iter().map(IssueHours::total).sum()
+    }
+}
+
+/// Roll the per-issue hours up to the epics of `issues`, largest total first. Issues
+/// missing from the cache or without an epic are counted under [`NO_EPIC`]. A sprint's
+/// share is the time logged between its start and end dates.
+pub fn epic_breakdown(
+    local_worklogs: &[LocalWorklog],
+    jira_worklogs: &[Worklog],
+    issues: &HashMap<String, Issue>,
+    sprints: &[Sprint],
+) -> Vec<EpicHours> {
+    let epic_of = |issue: &str| {
+        issues
+            .get(issue)
+            .and_then(|issue| issue.epic.clone())
+            .unwrap_or_else(|| NO_EPIC.to_string())
+    };
+
+    let mut by_epic: HashMap<String, EpicHours> = HashMap::new();
+    for hours in issue_breakdown(
+        local_worklogs,
+        jira_worklogs,
+        NaiveDate::MIN,
+        NaiveDate::MAX,
+    ) {
+        let epic = epic_of(&hours.issue);
+        by_epic
+            .entry(epic.clone())
+            .or_insert_with(|| EpicHours {
+                epic,
+                ..EpicHours::default()
+            })
+            .issues
+            .push(hours);
+    }
+
+    let mut dated: Vec<_> = sprints
+        .iter()
+        .filter_map(|sprint| Some((sprint, sprint.start?, sprint.end?)))
+        .collect();
+    dated.sort_by_key(|(_, start, _)| *start);
+    for (sprint, start, end) in dated {
+        let mut sprint_hours: HashMap<String, f64> = HashMap::new();
+        for hours in issue_breakdown(
+            local_worklogs,
+            jira_worklogs,
+            start.date_naive(),
+            end.date_naive(),
+        ) {
+            *sprint_hours.entry(epic_of(&hours.issue)).or_default() += hours.total();
+        }
+        for (epic, hours) in sprint_hours {
+            if let Some(epic_hours) = by_epic.get_mut(&epic) {
+                epic_hours.sprints.push((sprint.name.clone(), hours));
+            }
+        }
+    }
+
+    let mut breakdown: Vec<EpicHours> = by_epic.into_values().collect();
+    breakdown.sort_by(|a, b| {
+        b.total()
+            .total_cmp(&a.total())
+            .then_with(|| a.epic.cmp(&b.epic))
+    });
+    breakdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use wtf_lib::models::data::LocalWorklogState;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, day).unwrap()
+    }
+
+    fn jira(id: &str, issue: &str, day: u32, hours: u64) -> Worklog {
+        Worklog {
+            id: id.to_string(),
+            author: "me".to_string(),
+            created: Utc.with_ymd_and_hms(2026, 3, day, 18, 0, 0).unwrap(),
+            time_spent: format!("{}h", hours),
+            time_spent_seconds: hours * 3600,
+            comment: None,
+            issue_id: issue.to_string(),
+            started: Utc.with_ymd_and_hms(2026, 3, day, 9, 0, 0).unwrap(),
+        }
+    }
+
+    fn local(issue: &str, day: u32, hours: i64, worklog_id: Option<&str>) -> LocalWorklog {
+        LocalWorklog {
+            id: format!("{}-{}", issue, day),
+            comment: String::new(),
+            time_spent_seconds: hours * 3600,
+            issue_id: issue.to_string(),
+            status: LocalWorklogState::Created,
+            started: Utc.with_ymd_and_hms(2026, 3, day, 9, 0, 0).unwrap(),
+            meeting_id: None,
+            worklog_id: worklog_id.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_breakdown_splits_sources_and_skips_pushed_duplicates() {
+        let jira_worklogs = vec![
+            jira("w1", "PROJ-1", 2, 3),
+            jira("w2", "PROJ-2", 3, 2),
+            jira("w3", "PROJ-2", 20, 8), // outside the range
+        ];
+        let local_worklogs = vec![
+            local("PROJ-1", 2, 3, Some("w1")), // pushed: already counted in Jira
+            local("PROJ-2", 4, 4, None),
+            local("PROJ-3", 5, 1, None),
+        ];
+
+        let breakdown = issue_breakdown(&local_worklogs, &jira_worklogs, date(2), date(13));
+
+        let totals: Vec<(&str, f64, f64)> = breakdown
+            .iter()
+            .map(|h| (h.issue.as_str(), h.local_hours, h.jira_hours))
+            .collect();
+        assert_eq!(
+            totals,
+            vec![
+                ("PROJ-2", 4.0, 2.0),
+                ("PROJ-1", 0.0, 3.0),
+                ("PROJ-3", 1.0, 0.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_epic_breakdown_rolls_issues_up_per_sprint() {
+        let issue = |key: &str, epic: Option<&str>| Issue {
+            id: key.to_string(),
+            key: key.to_string(),
+            summary: String::new(),
+            status: "To Do".to_string(),
+            created: Utc::now(),
+            sprint_ids: Vec::new(),
+            epic: epic.map(str::to_string),
+            components: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
+        };
+        let issues = HashMap::from([
+            ("PROJ-1".to_string(), issue("PROJ-1", Some("PROJ-100"))),
+            ("PROJ-2".to_string(), issue("PROJ-2", Some("PROJ-100"))),
+            ("PROJ-3".to_string(), issue("PROJ-3", None)),
+        ]);
+        let sprint = |id: usize, start: u32, end: u32| Sprint {
+            id,
+            name: format!("Sprint {}", id),
+            state: wtf_lib::models::data::SprintState::Closed,
+            start: Some(Utc.with_ymd_and_hms(2026, 3, start, 0, 0, 0).unwrap()),
+            end: Some(Utc.with_ymd_and_hms(2026, 3, end, 0, 0, 0).unwrap()),
+            followed: true,
+            workdays: 10,
+        };
+        let jira_worklogs = vec![
+            jira("w1", "PROJ-1", 2, 3),
+            jira("w2", "PROJ-2", 16, 2),
+            jira("w3", "PROJ-3", 3, 1),
+        ];
+        let local_worklogs = vec![local("PROJ-4", 4, 1, None)];
+
+        let breakdown = epic_breakdown(
+            &local_worklogs,
+            &jira_worklogs,
+            &issues,
+            &[sprint(2, 16, 27), sprint(1, 2, 13)],
+        );
+
+        assert_eq!(breakdown.len(), 2);
+        assert_eq!(breakdown[0].epic, "PROJ-100");
+        assert_eq!(breakdown[0].total(), 5.0);
+        assert_eq!(
+            breakdown[0].sprints,
+            vec![("Sprint 1".to_string(), 3.0), ("Sprint 2".to_string(), 2.0)]
+        );
+        assert_eq!(breakdown[1].epic, NO_EPIC);
+        assert_eq!(breakdown[1].issues.len(), 2);
+        assert_eq!(breakdown[1].sprints, vec![("Sprint 1".to_string(), 2.0)]);
+    }
+}
@@ -1,13 +1,25 @@
 mod achievement_tracker;
+mod ascii;
+mod burn_chart;
+mod dashboard;
 pub mod data;
+mod github_session_edit;
 mod helpers;
+mod hook_notifier;
+mod issue_breakdown;
 mod operations;
+mod quick_worklog;
+mod sorting;
 mod tab_controller;
 pub mod theme;
+mod toast;
 mod types;
 pub mod ui;
 mod ui_helpers;
+mod ui_persistence;
+mod undo;
 mod wizard;
+pub(crate) mod worklog_filter;
 
 // Re-export types for public API
 pub use types::*;
@@ -38,12 +50,18 @@ use crate::tasks::jira_tasks::{
 };
 use crate::tasks::Task;
 use data::TuiData;
+use helpers::filter_issues;
 use once_cell::sync::Lazy;
 use tab_controller::TabController;
+use ui_persistence::PersistedUiState;
+use undo::UndoableAction;
+use wtf_lib::duration::{self, DurationDisplay};
+use wtf_lib::services::audit_service;
 use wtf_lib::services::jira_service::JiraService;
 use wtf_lib::services::meetings_service::MeetingsService;
 use wtf_lib::services::worklogs_service::LocalWorklogService;
 use wtf_lib::utils::branding::AppBranding;
+use wtf_lib::utils::gap_fill::{GAP_FILL_COMMENT, GAP_FILL_MIN_THRESHOLD};
 
 // Load application branding from embedded logo
 static APP_BRANDING: Lazy<Option<AppBranding>> = Lazy::new(|| AppBranding::load().ok());
@@ -90,20 +108,27 @@ impl Tui {
         let mut event_bus = EventBus::new();
         event_bus.subscribe(Box::new(wizard::WizardEventHandler));
         event_bus.subscribe(Box::new(AchievementTracker));
-
-        // Spawn async version check — result arrives via channel
-        let (update_sender, update_receiver) = std::sync::mpsc::channel();
-        std::thread::spawn(move || {
-            let rt = tokio::runtime::Runtime::new().unwrap();
-            let result = rt.block_on(wtf_lib::utils::version::check_latest_version());
-            let _ = update_sender.send(result);
-        });
+        event_bus.subscribe(Box::new(toast::ToastNotifier));
+        event_bus.subscribe(Box::new(hook_notifier::HookNotifier));
+
+        let network_mode = operations::network::initial_network_mode();
+        let online = network_mode == NetworkMode::Online;
+        // Version check, Jira probe and credential check: results arrive via channels
+        let update_receiver = online.then(operations::network::spawn_version_check);
+        let mut data = TuiData::collect();
+        let base_url = data.config.jira.base_url.clone();
+        let reachability_receiver =
+            online.then(|| operations::network::spawn_reachability_probe(base_url));
+        let credential_receiver = online.then(operations::credentials::spawn_credential_check);
+        let has_achievements = achievement_service.has_any_unlocked();
+        let current_tab = ui_persistence::take_restored_tab(&mut data.ui_state, has_achievements);
 
         Self {
-            data: TuiData::collect(),
+            data,
             achievement_service,
             tiered_achievement_service,
-            current_tab: Tab::Sprints,
+            current_tab,
+            dashboard_tab: ui::tabs::dashboard::DashboardTab,
             sprints_tab: ui::tabs::sprints::SprintsTab,
             achievements_tab: ui::tabs::achievements::AchievementsTab,
             meetings_tab: ui::tabs::meetings::MeetingsTab,
@@ -111,6 +136,10 @@ impl Tui {
             settings_tab: ui::tabs::settings::SettingsTab,
             worklogs_tab: ui::tabs::worklogs::WorklogsTab,
             history_tab: ui::tabs::history::HistoryTab,
+            logs_tab: ui::tabs::logs::LogsTab {
+                collector: log_collector.clone(),
+            },
+            issues_tab: ui::tabs::issues::IssuesTab,
             revert_confirmation_state: None,
             worklog_creation_confirmation: None,
             gap_fill_state: None,
@@ -120,11 +149,14 @@ impl Tui {
             wizard_pre_launch_prompt: None,
             sprint_follow_state: None,
             issue_selection_state: None,
+            issue_detail_state: None,
             unlink_confirmation_meeting_id: None,
             show_about_popup: false,
             about_image,
             image_picker: None,
             fetch_status: FetchStatus::Idle,
+            network_mode,
+            credential_status: None,
             event_bus,
             key_sequence_buffer: VecDeque::with_capacity(20),
             fetch_receiver: None,
@@ -133,9 +165,16 @@ impl Tui {
             push_receiver: None,
             push_progress_receiver: None,
             data_refresh_receiver: None,
-            update_receiver: Some(update_receiver),
+            update_receiver,
+            reachability_receiver,
+            credential_receiver,
             settings_issue_title_receiver: None,
+            issue_detail_receiver: None,
+            issue_refresh_receiver: None,
+            undo_stack: undo::UndoStack::default(),
+            toasts: toast::ToastQueue::default(),
             last_fetch_completed_at: None,
+            next_auto_refresh: None,
             status_clear_time: None,
             needs_full_clear: false,
             should_quit: false,
@@ -206,9 +245,15 @@ impl Tui {
         self.handle_push_operations();
         self.handle_data_refresh();
         self.handle_update_check();
+        self.handle_reachability_check();
+        self.handle_credential_check();
+        self.handle_auto_refresh();
         self.check_and_clear_status_timer();
+        self.toasts.expire(std::time::Instant::now());
         self.wizard_update_animation();
         self.handle_settings_issue_title_lookups();
+        self.handle_issue_detail_updates();
+        self.handle_issue_refresh_updates();
         self.trigger_color_label_title_lookups();
 
         // Process EventBus events (temporarily take ownership to avoid borrow issues)
@@ -416,7 +461,10 @@ impl Tui {
     /// that are not yet in the cache. Skips the reserved "notrack" keyword.
     fn trigger_color_label_title_lookups(&mut self) {
         use crate::tui::data::IssueTitleState;
-        if self.current_tab != Tab::Settings || self.settings_issue_title_receiver.is_some() {
+        if self.current_tab != Tab::Settings
+            || self.settings_issue_title_receiver.is_some()
+            || self.network_mode != NetworkMode::Online
+        {
             return;
         }
 
@@ -524,6 +572,7 @@ impl Tui {
             }
 
             if self.should_quit {
+                PersistedUiState::capture(&self.data.ui_state, self.current_tab).save();
                 break;
             }
         }
@@ -566,13 +615,22 @@ impl Tui {
     }
 
     fn handle_key(&mut self, key: KeyEvent) {
+        audit_service::set_source(match &self.wizard_state {
+            Some(wizard) => format!("wizard: {}", wizard.current_step.name()),
+            None => format!("tui: {:?} [{}]", self.current_tab, key.code),
+        });
         // Track key sequences globally (for secret achievements)
         // Skip tracking if we're in input mode (wizard, gap fill, issue selection)
         let in_input_mode = self.wizard_state.is_some()
             || self.gap_fill_state.is_some()
             || self.issue_selection_state.is_some()
+            || self.issue_detail_state.is_some()
             || self.sprint_follow_state.is_some()
-            || (self.current_tab == Tab::Settings && self.data.ui_state.settings_editing);
+            || self.data.ui_state.worklog_filter_editor.is_some()
+            || self.data.ui_state.github_session_editor.is_some()
+            || (self.current_tab == Tab::Settings && self.data.ui_state.settings_editing)
+            || self.logs_search_captures_keys()
+            || self.issues_tab_captures_keys();
 
         if !in_input_mode {
             self.track_key_sequence(&key);
@@ -585,6 +643,11 @@ impl Tui {
             return;
         }
 
+        // Issue detail popup sits on top of issue selection (also in the wizard)
+        if self.dispatch_issue_detail_key(key) {
+            return;
+        }
+
         // Wizard pre-launch prompt (existing unpushed worklogs detected)
         if self.wizard_pre_launch_prompt.is_some() {
             self.handle_wizard_pre_launch_key(key);
@@ -597,9 +660,18 @@ impl Tui {
             return;
         }
 
+        // `b` steps the wizard back, undoing the previous step
+        if self.dispatch_wizard_back_key(key) {
+            return;
+        }
+
         // If wizard is active, handle wizard keys (except for manual linking which uses existing popup)
         if let Some(ref wizard) = self.wizard_state {
             match wizard.current_step {
+                WizardStep::Setup { .. } => {
+                    self.handle_wizard_setup_key(key);
+                    return;
+                }
                 WizardStep::ManualLinking { .. } => {
                     // If in manual linking and issue selection is shown, let it handle keys
                     if self.issue_selection_state.is_some() {
@@ -754,6 +826,15 @@ impl Tui {
             return;
         }
 
+        // If a GitHub session popup is open, capture all keys before global shortcuts
+        if self.data.ui_state.github_drill_down.is_some()
+            || self.data.ui_state.github_session_editor.is_some()
+        {
+            let github_tab = self.github_tab;
+            github_tab.handle_key(self, key);
+            return;
+        }
+
         // If we're in meetings search mode, capture all keys before global shortcuts
         if self.current_tab == Tab::Meetings && self.data.ui_state.meeting_search_active {
             let meetings_tab = self.meetings_tab;
@@ -761,6 +842,14 @@ impl Tui {
             return;
         }
 
+        // Same for the Logs tab search, the Issues tab inputs and the worklog filter popup
+        if self.dispatch_logs_search_key(key)
+            || self.dispatch_issues_input_key(key)
+            || self.dispatch_worklog_filter_key(key)
+        {
+            return;
+        }
+
         // If we're in unlink confirmation mode, handle that
         if self.unlink_confirmation_meeting_id.is_some() {
             self.handle_unlink_confirmation_key(key);
@@ -823,6 +912,9 @@ impl Tui {
             {
                 self.should_quit = true;
             }
+            KeyCode::Char('z') if key.modifiers.contains(event::KeyModifiers::CONTROL) => {
+                self.undo_last_action();
+            }
             // Tab switching
             KeyCode::Tab => {
                 let has_achievements = self.achievement_service.has_any_unlocked();
@@ -834,42 +926,26 @@ impl Tui {
                 self.current_tab = self.current_tab.previous(has_achievements);
                 self.needs_full_clear = true;
             }
-            KeyCode::Char('1') => {
-                self.current_tab = Tab::Sprints;
-                self.needs_full_clear = true;
-            }
-            KeyCode::Char('2') => {
-                self.current_tab = Tab::Meetings;
-                self.needs_full_clear = true;
-            }
-            KeyCode::Char('3') => {
-                self.current_tab = Tab::GitHub;
-                self.needs_full_clear = true;
-            }
-            KeyCode::Char('4') => {
-                self.current_tab = Tab::History;
-                self.needs_full_clear = true;
-            }
-            KeyCode::Char('5') => {
-                self.current_tab = Tab::Worklogs;
-                self.needs_full_clear = true;
-            }
-            KeyCode::Char('6') => {
-                self.current_tab = Tab::Settings;
-                self.needs_full_clear = true;
-            }
-            KeyCode::Char('7') => {
-                // Only allow switching to Achievements if user has unlocked at least one
-                if self.achievement_service.has_any_unlocked() {
-                    self.current_tab = Tab::Achievements;
+            // Tab switching by number; Achievements only once one is unlocked
+            KeyCode::Char(digit) if digit.is_ascii_digit() => {
+                let has_achievements = self.achievement_service.has_any_unlocked();
+                if let Some(tab) = Tab::for_digit(digit, has_achievements) {
+                    self.current_tab = tab;
                     self.needs_full_clear = true;
                 }
             }
-            // Log panel scrolling (available globally)
-            KeyCode::PageUp => {
+            // Resize / collapse the list/detail split
+            KeyCode::Left if key.modifiers.contains(event::KeyModifiers::CONTROL) => {
+                self.data.ui_state.split_layout.narrow();
+            }
+            KeyCode::Right if key.modifiers.contains(event::KeyModifiers::CONTROL) => {
+                self.data.ui_state.split_layout.widen();
+            }
+            // Log panel scrolling (available globally, the Logs tab scrolls its own view)
+            KeyCode::PageUp if self.current_tab != Tab::Logs => {
                 self.log_scroll_offset = self.log_scroll_offset.saturating_add(3);
             }
-            KeyCode::PageDown => {
+            KeyCode::PageDown if self.current_tab != Tab::Logs => {
                 self.log_scroll_offset = self.log_scroll_offset.saturating_sub(3);
             }
             // Tab-specific navigation and actions
@@ -888,14 +964,18 @@ impl Tui {
         self.current_tab.handle_scroll(self, scroll_up);
     }
 
-    fn handle_update(&mut self) {
+    pub(in crate::tui) fn handle_update(&mut self) {
         // Don't start a new fetch if one is already in progress
         if matches!(self.fetch_status, FetchStatus::Fetching(_, _, _, _)) {
             return;
         }
+        if self.refuse_offline("update") {
+            return;
+        }
 
         let (sender, receiver) = channel();
         self.fetch_receiver = Some(receiver);
+        self.credential_receiver = Some(operations::credentials::spawn_credential_check());
 
         let tab = self.current_tab;
         self.fetch_tab = Some(tab);
@@ -972,7 +1052,7 @@ impl Tui {
                             TOTAL,
                             None,
                         ));
-                        if let Err(e) = fetch_google_meetings(Some(mp.clone())).await {
+                        if let Err(e) = fetch_google_meetings(Some(mp.clone()), false).await {
                             let _ = sender.send(FetchStatus::Error(e));
                             return;
                         }
@@ -995,7 +1075,7 @@ impl Tui {
                             1,
                             None,
                         ));
-                        match fetch_google_meetings(Some(mp.clone())).await {
+                        match fetch_google_meetings(Some(mp.clone()), false).await {
                             Ok(_) => {
                                 let _ = sender.send(FetchStatus::Complete);
                             }
@@ -1034,16 +1114,13 @@ impl Tui {
                             }
                         }
                     }
-                    Tab::History => {
-                        // No fetch action on History tab, it's local data
-                        let _ = sender.send(FetchStatus::Complete);
-                    }
-                    Tab::Achievements => {
-                        // No fetch action on Achievements tab, it's local data
-                        let _ = sender.send(FetchStatus::Complete);
-                    }
-                    Tab::Settings => {
-                        // No fetch action on Settings tab
+                    Tab::Dashboard
+                    | Tab::History
+                    | Tab::Achievements
+                    | Tab::Settings
+                    | Tab::Logs
+                    | Tab::Issues => {
+                        // Local data only; Issues refreshes single issues with R
                         let _ = sender.send(FetchStatus::Complete);
                     }
                 }
@@ -1051,6 +1128,7 @@ impl Tui {
         });
     }
 
+    #[allow(clippy::collapsible_match)]
     fn handle_issue_selection_key(&mut self, key: KeyEvent) {
         let state = match &mut self.issue_selection_state {
             Some(s) => s,
@@ -1058,23 +1136,21 @@ impl Tui {
         };
 
         // Filter issues based on search query
-        let filtered_issues: Vec<&wtf_lib::models::data::Issue> = state
-            .all_issues
-            .iter()
-            .filter(|issue| {
-                if state.search_query.is_empty() {
-                    true
-                } else {
-                    let query_lower = state.search_query.to_lowercase();
-                    issue.key.to_lowercase().contains(&query_lower)
-                        || issue.summary.to_lowercase().contains(&query_lower)
-                }
-            })
-            .collect();
+        let filtered_issues = state.filtered_issues();
 
         let max_index = filtered_issues.len().saturating_sub(1);
 
         match key.code {
+            KeyCode::Char('a') if key.modifiers.contains(event::KeyModifiers::CONTROL) => {
+                state.show_all = !state.show_all;
+                state.selected_issue_index = 0;
+            }
+            KeyCode::Char('s')
+                if key.modifiers.contains(event::KeyModifiers::CONTROL)
+                    && state.series.is_some() =>
+            {
+                state.link_series = !state.link_series;
+            }
             KeyCode::Esc => {
                 // If search is active, clear it; otherwise cancel
                 if !state.search_query.is_empty() {
@@ -1084,61 +1160,28 @@ impl Tui {
                     self.issue_selection_state = None;
                 }
             }
+            KeyCode::Tab => {
+                if let Some(issue) = filtered_issues.get(state.selected_issue_index) {
+                    let issue_key = issue.key.clone();
+                    self.open_issue_detail(issue_key);
+                }
+            }
             KeyCode::Enter => {
                 if let Some(issue) = filtered_issues.get(state.selected_issue_index) {
                     // Use selected issue from list
                     let issue_key = issue.key.clone();
                     let meeting_id = state.meeting_id.clone();
-                    if let Some(mut meeting) =
-                        MeetingsService::production().get_meeting_by_id(meeting_id.clone())
-                    {
-                        meeting.jira_link = Some(issue_key.clone());
-                        MeetingsService::production().save(&meeting);
-                        logger::log(format!("✅ Linked meeting to {}", issue_key));
-                        self.refresh_data();
-
-                        // If in wizard manual linking, track and advance
-                        if let Some(wizard) = &mut self.wizard_state {
-                            if let WizardStep::ManualLinking {
-                                ref mut unlinked_meetings,
-                                ref mut selected_index,
-                            } = wizard.current_step
-                            {
-                                wizard.summary.meetings_manually_linked += 1;
-                                wizard
-                                    .rollback_log
-                                    .original_meeting_links
-                                    .insert(meeting_id.clone(), None);
-                                wizard
-                                    .rollback_log
-                                    .linked_meeting_ids
-                                    .push(meeting_id.clone());
-
-                                // Remove this meeting from unlinked list and auto-advance
-                                let mid_clone = meeting_id.clone();
-                                unlinked_meetings.retain(|m| m.id != mid_clone);
-
-                                if unlinked_meetings.is_empty() {
-                                    // All done, advance to next step
-                                    logger::log("✅ All meetings linked!".to_string());
-                                    wizard.completed_steps.insert(2);
-                                    wizard.current_step = WizardStep::CreatingMeetingWorklogs;
-                                    self.wizard_step_create_meeting_worklogs();
-                                } else {
-                                    // More to link, keep index in bounds
-                                    if *selected_index >= unlinked_meetings.len() {
-                                        *selected_index = unlinked_meetings.len() - 1;
-                                    }
-                                }
-                            }
-                        }
-                    }
+                    let series = state.series.clone().filter(|_| state.link_series);
+                    self.link_picked_meeting(&meeting_id, &issue_key, series.as_deref());
                     self.issue_selection_state = None;
                 } else if !state.search_query.is_empty() {
                     // No match found in database, try fetching from Jira
                     let issue_key = state.search_query.to_uppercase();
                     let meeting_id = state.meeting_id.clone();
                     self.issue_selection_state = None;
+                    if self.refuse_offline(&format!("fetching {} from Jira", issue_key)) {
+                        return;
+                    }
 
                     // Spawn background task to fetch issue
                     logger::log(format!("🔍 Fetching {} from Jira...", issue_key));
@@ -1157,61 +1200,11 @@ impl Tui {
                     match receiver.recv_timeout(std::time::Duration::from_secs(5)) {
                         Ok(Ok(jira_issue)) => {
                             // Save issue to database
-                            let issue = wtf_lib::models::data::Issue {
-                                key: jira_issue.key.clone(),
-                                id: jira_issue.id,
-                                created: jira_issue.fields.created,
-                                status: jira_issue.fields.status.name,
-                                summary: jira_issue.fields.summary,
-                            };
+                            let issue = jira_issue.into();
                             wtf_lib::services::jira_service::IssueService::production()
                                 .save_issue(&issue);
 
-                            // Link meeting
-                            if let Some(mut meeting) =
-                                MeetingsService::production().get_meeting_by_id(meeting_id.clone())
-                            {
-                                meeting.jira_link = Some(issue_key.clone());
-                                MeetingsService::production().save(&meeting);
-                                logger::log(format!(
-                                    "✅ Fetched and linked meeting to {}",
-                                    issue_key
-                                ));
-                                self.refresh_data();
-
-                                // If in wizard manual linking, track and advance
-                                if let Some(wizard) = &mut self.wizard_state {
-                                    if let WizardStep::ManualLinking {
-                                        ref mut unlinked_meetings,
-                                        ref mut selected_index,
-                                    } = wizard.current_step
-                                    {
-                                        wizard.summary.meetings_manually_linked += 1;
-                                        wizard
-                                            .rollback_log
-                                            .original_meeting_links
-                                            .insert(meeting_id.clone(), None);
-                                        wizard
-                                            .rollback_log
-                                            .linked_meeting_ids
-                                            .push(meeting_id.clone());
-
-                                        // Remove this meeting from unlinked list
-                                        let mid_clone = meeting_id.clone();
-                                        unlinked_meetings.retain(|m| m.id != mid_clone);
-
-                                        if unlinked_meetings.is_empty() {
-                                            logger::log("✅ All meetings linked!".to_string());
-                                            wizard.completed_steps.insert(2);
-                                            wizard.current_step =
-                                                WizardStep::CreatingMeetingWorklogs;
-                                            self.wizard_step_create_meeting_worklogs();
-                                        } else if *selected_index >= unlinked_meetings.len() {
-                                            *selected_index = unlinked_meetings.len() - 1;
-                                        }
-                                    }
-                                }
-                            }
+                            self.link_picked_meeting(&meeting_id, &issue_key, None);
                         }
                         Ok(Err(e)) => {
                             logger::log(format!(
@@ -1281,6 +1274,7 @@ impl Tui {
         }
     }
 
+    #[allow(clippy::collapsible_match)]
     fn handle_sprint_follow_key(&mut self, key: KeyEvent) {
         let state = match &mut self.sprint_follow_state {
             Some(s) => s,
@@ -1361,7 +1355,7 @@ impl Tui {
                             self.refresh_data();
                         }
                         Err(e) => {
-                            logger::log(format!("❌ Failed to toggle sprint: {}", e));
+                            logger::log(format!("❌ Failed to toggle sprint: {}", e.with_hint()));
                         }
                     }
                 } else {
@@ -1398,9 +1392,8 @@ impl Tui {
                                 .find(|s| s.id == session_id)
                             {
                                 let session_clone = session.clone();
-                                let jira_issues =
-                                    self.data.valid_github_issues_for_session(&session_clone);
-                                let duration_seconds = session_clone.duration_seconds;
+                                let (jira_issues, duration_seconds) =
+                                    self.session_issues_and_duration(&session_clone);
                                 let time_per_issue = if jira_issues.len() > 1 {
                                     duration_seconds / jira_issues.len() as i64
                                 } else {
@@ -1457,8 +1450,8 @@ impl Tui {
                                 .find(|s| s.id == session_id)
                             {
                                 let session_clone = session.clone();
-                                let jira_issues =
-                                    self.data.valid_github_issues_for_session(&session_clone);
+                                let (jira_issues, _) =
+                                    self.session_issues_and_duration(&session_clone);
                                 // For partial, divide the suggested total evenly across issues
                                 let time_per_issue_partial = if jira_issues.len() > 1 {
                                     suggested_seconds / jira_issues.len() as i64
@@ -1505,22 +1498,12 @@ impl Tui {
         }
     }
 
+    #[allow(clippy::collapsible_match)]
     fn handle_gap_fill_issue_selection_key(&mut self, key: KeyEvent) {
         if let Some(state) = &mut self.gap_fill_state {
             // Apply search filter
-            let filtered_issues: Vec<_> = state
-                .all_issues
-                .iter()
-                .filter(|issue| {
-                    if state.search_query.is_empty() {
-                        true
-                    } else {
-                        let query_lower = state.search_query.to_lowercase();
-                        issue.key.to_lowercase().contains(&query_lower)
-                            || issue.summary.to_lowercase().contains(&query_lower)
-                    }
-                })
-                .collect();
+            let filtered_issues: Vec<_> =
+                filter_issues(&state.all_issues, &state.search_query, &state.aliases);
 
             let max_index = filtered_issues.len().saturating_sub(1);
 
@@ -1538,44 +1521,37 @@ impl Tui {
                 KeyCode::Enter => {
                     // Select the issue
                     if let Some(&issue) = filtered_issues.get(state.selected_issue_index) {
-                        let sprint_id = state.sprint_id;
+                        let period = state.period.clone();
                         let issue_id = issue.key.clone();
 
                         // Remove gap fill state
                         self.gap_fill_state = None;
 
-                        // Calculate gaps for the sprint
-                        if let Some(sprint) =
-                            self.data.all_sprints.iter().find(|s| s.id == sprint_id)
-                        {
-                            if let (Some(start), Some(end)) = (sprint.start, sprint.end) {
-                                let meetings_svc = MeetingsService::production();
-                                let gaps = LocalWorklogService::production().find_gap_days(
-                                    start.date_naive(),
-                                    end.date_naive(),
-                                    self.data.daily_hours_limit,
-                                    6.0, // Skip days already over 6h
-                                    &|date| meetings_svc.is_absent(date),
-                                    &self.data.jira_worklogs,
-                                );
-
-                                if gaps.is_empty() {
-                                    logger::log(
-                                        "✓ No gaps to fill - all workdays are substantially logged"
-                                            .to_string(),
-                                    );
-                                    return;
-                                }
+                        // Calculate gaps for the period
+                        let meetings_svc = MeetingsService::production();
+                        let gaps = LocalWorklogService::production().find_gap_days(
+                            period.start,
+                            period.end,
+                            &|date| self.data.config.worklog.target_hours(date),
+                            GAP_FILL_MIN_THRESHOLD,
+                            &|date| meetings_svc.is_absent(date),
+                            &self.data.jira_worklogs,
+                        );
 
-                                // Show confirmation popup
-                                self.gap_fill_confirmation = Some(GapFillConfirmation {
-                                    _sprint_id: sprint_id,
-                                    sprint_name: sprint.name.clone(),
-                                    issue_id,
-                                    gaps,
-                                });
-                            }
+                        if gaps.is_empty() {
+                            logger::log(
+                                "✓ No gaps to fill - all workdays are substantially logged"
+                                    .to_string(),
+                            );
+                            return;
                         }
+
+                        // Show confirmation popup
+                        self.gap_fill_confirmation = Some(GapFillConfirmation {
+                            period,
+                            issue_id,
+                            gaps,
+                        });
                     }
                 }
                 KeyCode::Esc => {
@@ -1611,14 +1587,12 @@ impl Tui {
                 // Confirm and create worklogs
                 if let Some(confirmation) = self.gap_fill_confirmation.take() {
                     let mut created_count = 0;
+                    let mut created_ids = Vec::new();
                     let total_hours: f64 = confirmation.gaps.iter().map(|(_, h)| h).sum();
 
                     for (date, hours_to_add) in &confirmation.gaps {
-                        // Create worklog at noon (12:00) for that day
-                        use chrono::{NaiveTime, TimeZone, Utc};
-                        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
-                        let datetime = date.and_time(noon);
-                        let datetime_utc = Utc.from_utc_datetime(&datetime);
+                        // Create worklog at the start of that working day
+                        let datetime_utc = self.data.config.worklog.day_start(*date);
 
                         let seconds = (hours_to_add * 3600.0) as i64;
 
@@ -1626,21 +1600,29 @@ impl Tui {
                             datetime_utc,
                             seconds,
                             &confirmation.issue_id,
-                            Some("Tech activities"),
+                            Some(GAP_FILL_COMMENT),
                             None,
                         );
 
                         // Track for wizard rollback
                         if let Some(wizard) = &mut self.wizard_state {
-                            wizard.rollback_log.created_worklog_ids.push(worklog.id);
+                            wizard
+                                .rollback_log
+                                .created_worklog_ids
+                                .push(worklog.id.clone());
                         }
 
+                        created_ids.push(worklog.id);
                         created_count += 1;
                     }
+                    self.record_undo(
+                        format!("gap fill of {} worklog(s)", created_count),
+                        UndoableAction::WorklogsCreated(created_ids),
+                    );
 
                     logger::log(format!(
                         "✅ Created {} worklogs ({:.1}h) for {} in {}",
-                        created_count, total_hours, confirmation.issue_id, confirmation.sprint_name
+                        created_count, total_hours, confirmation.issue_id, confirmation.period.name
                     ));
                     log_chronie_message("gap_filling", "🧙 Chronie:");
                     self.refresh_data();
@@ -1718,30 +1700,21 @@ impl Tui {
             KeyCode::Char('k') | KeyCode::Char('K') => {
                 // Keep existing worklogs, launch wizard as-is
                 logger::log("▶️  Keeping existing worklogs, starting wizard...".to_string());
-                self.do_launch_wizard(prompt.sprint_id, &prompt.sprint_name);
+                self.do_launch_wizard(prompt.period);
             }
             KeyCode::Char('r') | KeyCode::Char('R') => {
-                // Reset: delete unpushed worklogs within this sprint's date range, then launch
-                logger::log("🗑️  Resetting unpushed worklogs for this sprint...".to_string());
-                let to_delete = self
-                    .data
-                    .all_sprints
-                    .iter()
-                    .find(|s| s.id == prompt.sprint_id)
-                    .and_then(|s| s.start.zip(s.end))
-                    .map(|(start, end)| {
-                        LocalWorklogService::production()
-                            .get_unpushed_in_range(start.date_naive(), end.date_naive())
-                    })
-                    .unwrap_or_default();
+                // Reset: delete unpushed worklogs within the period, then launch
+                logger::log("🗑️  Resetting unpushed worklogs for this period...".to_string());
+                let to_delete = LocalWorklogService::production()
+                    .get_unpushed_in_range(prompt.period.start, prompt.period.end);
                 for wl in &to_delete {
                     LocalWorklogService::production().remove_local_worklog(wl);
                 }
                 logger::log(format!(
-                    "🗑️  Deleted {} unpushed worklog(s) for this sprint",
+                    "🗑️  Deleted {} unpushed worklog(s) for this period",
                     to_delete.len()
                 ));
-                self.do_launch_wizard(prompt.sprint_id, &prompt.sprint_name);
+                self.do_launch_wizard(prompt.period);
             }
             KeyCode::Esc => {
                 // Abort wizard launch
@@ -1755,6 +1728,7 @@ impl Tui {
         }
     }
 
+    #[allow(clippy::collapsible_match)]
     fn handle_wizard_manual_linking_key(&mut self, key: KeyEvent) {
         // Extract meeting ID if we need to link
         let meeting_id_to_link = if let Some(WizardState {
@@ -1854,7 +1828,7 @@ impl Tui {
     }
 
     pub(in crate::tui) fn apply_settings_field_edit(&mut self) {
-        use wtf_lib::config::{GoogleConfig, SensitiveString};
+        use wtf_lib::config::{GoogleAuthFlow, GoogleConfig, SensitiveString};
 
         let field_idx = self.data.ui_state.settings_selected_field;
         let value = self.data.ui_state.settings_input_buffer.clone();
@@ -1884,6 +1858,11 @@ impl Tui {
                         credentials_path: value,
                         token_cache_path: String::new(),
                         color_labels: std::collections::HashMap::new(),
+                        calendar_ids: Vec::new(),
+                        time_off_keywords: Vec::new(),
+                        service_account_key_path: None,
+                        impersonate: None,
+                        auth_flow: GoogleAuthFlow::default(),
                     });
                 }
             }
@@ -1895,6 +1874,11 @@ impl Tui {
                         credentials_path: String::new(),
                         token_cache_path: value,
                         color_labels: std::collections::HashMap::new(),
+                        calendar_ids: Vec::new(),
+                        time_off_keywords: Vec::new(),
+                        service_account_key_path: None,
+                        impersonate: None,
+                        auth_flow: GoogleAuthFlow::default(),
                     });
                 }
             }
@@ -1927,6 +1911,7 @@ impl Tui {
     pub(in crate::tui) fn save_settings(&mut self) {
         match self.data.config.save() {
             Ok(()) => {
+                duration::configure(DurationDisplay::from(&self.data.config));
                 self.data.ui_state.settings_dirty = false;
                 self.data.ui_state.settings_status =
                     Some("✓ Settings saved successfully".to_string());
@@ -2003,7 +1988,7 @@ impl Tui {
         };
 
         // Check each sequence from PNG
-        for (_name, sequence_def) in &secrets.sequences {
+        for sequence_def in secrets.sequences.values() {
             if self.matches_sequence_hash(sequence_def.length, &sequence_def.hash) {
                 // Publish event — carry the achievement ID from the PNG, not the sequence key
                 self.event_bus.publish(AppEvent::SecretSequenceTriggered {
@@ -0,0 +1,92 @@
+// Background refresh of the TUI data every ui.auto_refresh_minutes
+
+use std::time::{Duration, Instant};
+
+use wtf_lib::config::UiConfig;
+
+use crate::logger;
+use crate::tui::types::FetchStatus;
+
+use super::super::Tui;
+
+/// Time between two background refreshes, `None` when they are off
+fn auto_refresh_interval(ui: &UiConfig) -> Option<Duration> {
+    match ui.auto_refresh_minutes {
+        0 => None,
+        minutes => Some(Duration::from_secs(minutes * 60)),
+    }
+}
+
+/// When a manual update ran less than `interval` ago, the round it pushes back to
+fn postponed_by_last_fetch(
+    now: Instant,
+    last_fetch: Option<Instant>,
+    interval: Duration,
+) -> Option<Instant> {
+    last_fetch
+        .filter(|last| now.duration_since(*last) < interval)
+        .map(|last| last + interval)
+}
+
+impl Tui {
+    /// Fetch like `u` when the background refresh is due. It waits for a running fetch,
+    /// push or wizard to end, and starts over after a manual update.
+    pub(in crate::tui) fn handle_auto_refresh(&mut self) {
+        let Some(interval) = auto_refresh_interval(&self.data.config.ui) else {
+            self.next_auto_refresh = None;
+            return;
+        };
+        let now = Instant::now();
+        // Turned on at startup or from the settings: the first round is an interval away
+        let due = *self.next_auto_refresh.get_or_insert(now + interval);
+        if now < due {
+            return;
+        }
+        let busy = matches!(self.fetch_status, FetchStatus::Fetching(_, _, _, _))
+            || self.push_receiver.is_some()
+            || self.wizard_state.is_some();
+        if busy {
+            return;
+        }
+        if let Some(next) = postponed_by_last_fetch(now, self.last_fetch_completed_at, interval) {
+            self.next_auto_refresh = Some(next);
+            return;
+        }
+        self.next_auto_refresh = Some(now + interval);
+        if self.is_offline() {
+            return;
+        }
+        logger::log("🔄 Auto-refresh: updating in the background".to_string());
+        self.handle_update();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_refresh_is_off_at_zero_minutes() {
+        let mut ui = UiConfig::default();
+        assert_eq!(auto_refresh_interval(&ui), None);
+
+        ui.auto_refresh_minutes = 15;
+        assert_eq!(auto_refresh_interval(&ui), Some(Duration::from_secs(900)));
+    }
+
+    #[test]
+    fn test_recent_manual_update_pushes_the_refresh_back() {
+        let interval = Duration::from_secs(600);
+        let now = Instant::now() + Duration::from_secs(3600);
+
+        let recent = now - Duration::from_secs(120);
+        assert_eq!(
+            postponed_by_last_fetch(now, Some(recent), interval),
+            Some(recent + interval)
+        );
+
+        let old = now - Duration::from_secs(900);
+        assert_eq!(postponed_by_last_fetch(now, Some(old), interval), None);
+        assert_eq!(postponed_by_last_fetch(now, None, interval), None);
+    }
+}
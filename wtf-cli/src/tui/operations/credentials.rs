@@ -0,0 +1,39 @@
+// Jira credentials check: the `myself` probe run at startup and before each fetch
+
+use std::sync::mpsc::Receiver;
+
+use wtf_lib::context::Context;
+use wtf_lib::services::sync_status_service::{CredentialStatus, SyncStatusService};
+
+use crate::logger;
+
+use super::super::Tui;
+use super::runtime;
+
+/// Run the `myself` probe in the background, which also records when the token last worked
+pub(in crate::tui) fn spawn_credential_check() -> Receiver<CredentialStatus> {
+    runtime::spawn(async {
+        SyncStatusService::production()
+            .check_jira_credentials(&Context::production())
+            .await
+    })
+}
+
+impl Tui {
+    pub(in crate::tui) fn handle_credential_check(&mut self) {
+        let Some(receiver) = &self.credential_receiver else {
+            return;
+        };
+        let Ok(status) = receiver.try_recv() else {
+            return;
+        };
+        self.credential_receiver = None;
+        if let Some(message) = status.message() {
+            logger::log(format!("🔑 {}", message));
+        }
+        // A network failure says nothing about the token: keep the last verdict
+        if !matches!(status, CredentialStatus::Unreachable(_)) {
+            self.credential_status = Some(status);
+        }
+    }
+}
@@ -1,36 +1,28 @@
 // Gap filling operations: automatically create worklogs for time gaps
 
-use crate::logger;
-
+use super::super::helpers::favorites_first;
 use super::super::{types::GapFillState, Tui};
 
 impl Tui {
     pub(in crate::tui) fn handle_fill_gaps(&mut self) {
-        // Get the selected sprint
-        if let Some(sprint) = self
-            .data
-            .all_sprints
-            .get(self.data.ui_state.selected_sprint_index)
-        {
-            // Check if sprint has date range
-            if sprint.start.is_none() || sprint.end.is_none() {
-                logger::log("⚠️  Cannot fill gaps: Sprint has no date range".to_string());
-                return;
-            }
-
-            // Get all issues
-            let mut all_issues: Vec<_> = self.data.issues_by_key.values().cloned().collect();
+        // The selected sprint, or the configured week or month without one
+        let period = self.selected_period();
 
-            // Sort by key for now (simple alphabetical)
-            all_issues.sort_by(|a, b| a.key.cmp(&b.key));
+        // Starred issues first, then alphabetical
+        let favorites = self.data.ui_state.favorite_issues.clone();
+        let all_issues = favorites_first(
+            self.data.issues_by_key.values().cloned().collect(),
+            &favorites,
+        );
 
-            // Show gap fill issue selection popup
-            self.gap_fill_state = Some(GapFillState {
-                sprint_id: sprint.id,
-                all_issues,
-                selected_issue_index: 0,
-                search_query: String::new(),
-            });
-        }
+        // Show gap fill issue selection popup
+        self.gap_fill_state = Some(GapFillState {
+            period,
+            all_issues,
+            selected_issue_index: 0,
+            search_query: String::new(),
+            aliases: self.data.config.aliases.clone(),
+            favorites,
+        });
     }
 }
@@ -1,12 +1,17 @@
-// GitHub operations: sync events, create worklogs from sessions
+// GitHub operations: sync events, edit sessions, create worklogs from sessions
 
 use std::sync::mpsc::channel;
 use std::thread;
 
 use wtf_lib::models::data::GitHubSession;
+use wtf_lib::services::github_session_edit_service::GitHubSessionEditService;
 use wtf_lib::services::worklogs_service::LocalWorklogService;
 
 use crate::logger;
+use crate::tasks::link_script;
+use crate::tui::github_session_edit::{SessionEditAction, SessionEditor};
+use crate::tui::ui::tabs::github::visible_github_sessions;
+use crate::tui::undo::UndoableAction;
 
 use super::super::{
     types::{FetchStatus, WorklogCreationConfirmation, WorklogSource},
@@ -19,6 +24,9 @@ impl Tui {
         if matches!(self.fetch_status, FetchStatus::Fetching(_, _, _, _)) {
             return;
         }
+        if self.refuse_offline("GitHub sync") {
+            return;
+        }
 
         let (sender, receiver) = channel();
         self.fetch_receiver = Some(receiver);
@@ -51,7 +59,10 @@ impl Tui {
                 }
 
                 // Sync events and sessions
-                match GitHubService::production().sync_events_for_sprints(&sprints) {
+                match GitHubService::production()
+                    .sync_events_for_sprints(&sprints)
+                    .await
+                {
                     Ok((events_count, sessions_count)) => {
                         logger::log(format!(
                             "✅ Synced {} events, {} sessions",
@@ -60,24 +71,106 @@ impl Tui {
                         let _ = sender.send(FetchStatus::Complete);
                     }
                     Err(e) => {
-                        logger::log(format!("❌ GitHub sync failed: {}", e));
-                        let _ = sender.send(FetchStatus::Error(e));
+                        logger::log(format!("❌ GitHub sync failed: {}", e.with_hint()));
+                        let _ = sender.send(FetchStatus::Error(e.to_string()));
                     }
                 }
             });
         });
     }
 
+    /// Open the split (`trim == false`) or trim popup for the selected session
+    pub(in crate::tui) fn open_session_editor(&mut self, trim: bool) {
+        let sessions = visible_github_sessions(&self.data);
+        let Some(session) = sessions.get(self.data.ui_state.selected_github_session_index) else {
+            return;
+        };
+
+        let editor = if trim {
+            SessionEditor::trim(session)
+        } else {
+            let events: Vec<_> = session
+                .get_event_ids()
+                .iter()
+                .filter_map(|id| self.data.github_events_by_id.get(id))
+                .collect();
+            SessionEditor::split(session, &events)
+        };
+        self.data.ui_state.github_session_editor = Some(editor);
+    }
+
+    pub(in crate::tui) fn handle_session_editor_key(&mut self, key: crossterm::event::KeyEvent) {
+        let Some(editor) = &mut self.data.ui_state.github_session_editor else {
+            return;
+        };
+        let session_id = editor.session_id.clone();
+
+        let result = match editor.handle_key(key) {
+            SessionEditAction::None => return,
+            SessionEditAction::Cancel => {
+                self.data.ui_state.github_session_editor = None;
+                return;
+            }
+            SessionEditAction::Split(at) => GitHubSessionEditService::production()
+                .split_session(&session_id, at)
+                .map(|_| "✂️  Session split in two".to_string()),
+            SessionEditAction::Trim(start, end) => GitHubSessionEditService::production()
+                .trim_session(&session_id, start, end)
+                .map(|s| format!("✂️  Session trimmed to {:.1}h", s.duration_hours())),
+        };
+
+        match result {
+            Ok(message) => {
+                logger::log(message);
+                self.data.ui_state.github_session_editor = None;
+                self.refresh_data();
+            }
+            // Keep the popup open so the times can be corrected
+            Err(e) => editor_error(self, e.to_string()),
+        }
+    }
+
+    /// Merge the selected session with the next one of the same repo and day
+    pub(in crate::tui) fn merge_selected_github_session(&mut self) {
+        let sessions = visible_github_sessions(&self.data);
+        let Some(session) = sessions.get(self.data.ui_state.selected_github_session_index) else {
+            return;
+        };
+
+        let next = sessions
+            .iter()
+            .filter(|s| {
+                s.date == session.date
+                    && s.repo == session.repo
+                    && s.start_time > session.start_time
+            })
+            .min_by_key(|s| s.start_time);
+        let Some(next) = next else {
+            logger::log("⚠️  No later session of the same repo and day to merge with".to_string());
+            return;
+        };
+
+        match GitHubSessionEditService::production().merge_sessions(&session.id, &next.id) {
+            Ok(merged) => {
+                logger::log(format!(
+                    "🔗 Sessions merged into {:.1}h",
+                    merged.duration_hours()
+                ));
+                self.refresh_data();
+            }
+            Err(e) => logger::log(format!("❌ Failed to merge sessions: {}", e.with_hint())),
+        }
+    }
+
     pub(in crate::tui) fn handle_create_worklog_from_session(&mut self) {
-        // Get sessions in reverse chronological order (same as display)
-        let mut sorted_sessions: Vec<_> = self.data.github_sessions.clone();
-        sorted_sessions.sort_by(|a, b| b.start_time.cmp(&a.start_time));
+        // Same order as the displayed list
+        let sessions = visible_github_sessions(&self.data);
 
-        if let Some(session) = sorted_sessions
+        if let Some(session) = sessions
             .get(self.data.ui_state.selected_github_session_index)
             .cloned()
         {
-            let jira_issues = self.data.valid_github_issues_for_session(&session);
+            let (jira_issues, duration_seconds) = self.session_issues_and_duration(&session);
 
             if jira_issues.is_empty() {
                 logger::log(
@@ -97,7 +190,6 @@ impl Tui {
                 return;
             }
 
-            let duration_seconds = session.duration_seconds;
             let time_per_issue = if jira_issues.len() > 1 {
                 duration_seconds / jira_issues.len() as i64
             } else {
@@ -137,6 +229,31 @@ impl Tui {
         }
     }
 
+    /// The issues a session is logged on and the time it logs: the linking script's pick,
+    /// or the known issues of its branches and commits for its whole duration
+    pub(in crate::tui) fn session_issues_and_duration(
+        &self,
+        session: &GitHubSession,
+    ) -> (Vec<String>, i64) {
+        let link = link_script(&self.data.config)
+            .and_then(|script| script.link_session(session))
+            .filter(|link| self.data.issues_by_key.contains_key(&link.issue));
+        match link {
+            Some(link) => (
+                vec![link.issue],
+                link.seconds.unwrap_or(session.duration_seconds),
+            ),
+            None => {
+                let mut issues = self.data.valid_github_issues_for_session(session);
+                // Sessions naming no issue go to their repo's, rather than being skipped
+                if issues.is_empty() {
+                    issues.extend(self.data.config.github_default_issue(&session.repo));
+                }
+                (issues, session.duration_seconds)
+            }
+        }
+    }
+
     pub(in crate::tui) fn create_worklogs_from_session(
         &mut self,
         session: &GitHubSession,
@@ -144,6 +261,7 @@ impl Tui {
         time_per_issue: i64,
     ) -> usize {
         let mut created_count = 0;
+        let mut created_ids = Vec::new();
 
         for issue_id in jira_issues {
             // Check if issue exists
@@ -165,9 +283,14 @@ impl Tui {
                     .unwrap_or("Development work")
             );
 
+            let (started, seconds) = self
+                .data
+                .config
+                .worklog
+                .clamp_to_workday(session.start_time, time_per_issue);
             let worklog = LocalWorklogService::production().create_new_local_worklogs(
-                session.start_time,
-                time_per_issue,
+                started,
+                seconds,
                 issue_id,
                 Some(&comment),
                 None,
@@ -181,11 +304,12 @@ impl Tui {
                     .push(worklog.id.clone());
             }
 
+            created_ids.push(worklog.id);
             created_count += 1;
             logger::log(format!(
                 "✅ Created worklog for {} ({:.1}h)",
                 issue_id,
-                time_per_issue as f64 / 3600.0
+                seconds as f64 / 3600.0
             ));
         }
 
@@ -194,6 +318,10 @@ impl Tui {
                 "📝 Created {} worklog(s) from GitHub session",
                 created_count
             ));
+            self.record_undo(
+                format!("{} worklog(s) from GitHub session", created_count),
+                UndoableAction::WorklogsCreated(created_ids),
+            );
             self.refresh_data();
         } else {
             logger::log("⚠️  No worklogs created".to_string());
@@ -202,3 +330,9 @@ impl Tui {
         created_count
     }
 }
+
+fn editor_error(tui: &mut Tui, error: String) {
+    if let Some(editor) = &mut tui.data.ui_state.github_session_editor {
+        editor.error = Some(error);
+    }
+}
@@ -0,0 +1,90 @@
+// Issue detail operations: lazy Jira fetch and popup navigation
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::logger;
+
+use super::runtime;
+
+use super::super::{
+    types::{IssueDetailLoad, IssueDetailState},
+    Tui,
+};
+
+impl Tui {
+    /// Open the detail popup for an issue and fetch its full Jira record in the background
+    pub(in crate::tui) fn open_issue_detail(&mut self, issue_key: String) {
+        if self.is_offline() {
+            self.issue_detail_state = Some(IssueDetailState {
+                issue_key,
+                detail: IssueDetailLoad::Failed("wtf is offline".to_string()),
+                scroll_offset: 0,
+            });
+            return;
+        }
+        self.issue_detail_state = Some(IssueDetailState {
+            issue_key: issue_key.clone(),
+            detail: IssueDetailLoad::Loading,
+            scroll_offset: 0,
+        });
+
+        self.issue_detail_receiver = Some(runtime::spawn(async move {
+            wtf_lib::client::jira_client::JiraClient::create()
+                .get_issue(&issue_key)
+                .await
+                .map_err(|e| e.to_string())
+        }));
+    }
+
+    /// Apply the fetched issue once the background request completes
+    pub(in crate::tui) fn handle_issue_detail_updates(&mut self) {
+        let Some(receiver) = &self.issue_detail_receiver else {
+            return;
+        };
+        let Ok(result) = receiver.try_recv() else {
+            return;
+        };
+        self.issue_detail_receiver = None;
+
+        if let Some(state) = &mut self.issue_detail_state {
+            state.detail = match result {
+                Ok(issue) => IssueDetailLoad::Loaded(Box::new(issue)),
+                Err(e) => {
+                    logger::log(format!(
+                        "❌ Failed to load {} from Jira: {}",
+                        state.issue_key, e
+                    ));
+                    IssueDetailLoad::Failed(e)
+                }
+            };
+        }
+    }
+
+    /// Handle a key for the detail popup, `false` when it is not open
+    pub(in crate::tui) fn dispatch_issue_detail_key(&mut self, key: KeyEvent) -> bool {
+        let Some(state) = &mut self.issue_detail_state else {
+            return false;
+        };
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Tab | KeyCode::Char('q') => {
+                self.issue_detail_state = None;
+                self.issue_detail_receiver = None;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                state.scroll_offset = state.scroll_offset.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                state.scroll_offset = state.scroll_offset.saturating_add(1);
+            }
+            KeyCode::PageUp => {
+                state.scroll_offset = state.scroll_offset.saturating_sub(10);
+            }
+            KeyCode::PageDown => {
+                state.scroll_offset = state.scroll_offset.saturating_add(10);
+            }
+            _ => {}
+        }
+        true
+    }
+}
@@ -0,0 +1,141 @@
+// Issues tab operations: single issue refresh, favorites and quick worklogs
+
+use chrono::Local;
+use crossterm::event::KeyEvent;
+use wtf_lib::services::jira_service::IssueService;
+use wtf_lib::services::worklogs_service::LocalWorklogService;
+
+use crate::logger;
+use crate::tui::quick_worklog::{QuickWorklog, QuickWorklogAction};
+use crate::tui::tab_controller::TabController;
+use crate::tui::ui::tabs::issues::visible_issues;
+use crate::tui::ui_persistence::PersistedUiState;
+use crate::tui::undo::UndoableAction;
+
+use super::super::{Tab, Tui};
+use super::runtime;
+
+impl Tui {
+    /// Whether the quick worklog popup or the issue search is capturing keys
+    pub(in crate::tui) fn issues_tab_captures_keys(&self) -> bool {
+        self.data.ui_state.quick_worklog.is_some()
+            || (self.current_tab == Tab::Issues && self.data.ui_state.issue_search_active)
+    }
+
+    /// Hand keys to the Issues tab while one of its inputs is open
+    pub(in crate::tui) fn dispatch_issues_input_key(&mut self, key: KeyEvent) -> bool {
+        if self.current_tab != Tab::Issues || !self.issues_tab_captures_keys() {
+            return false;
+        }
+        let issues_tab = self.issues_tab;
+        issues_tab.handle_key(self, key);
+        true
+    }
+
+    fn selected_issue_key(&self) -> Option<String> {
+        visible_issues(&self.data)
+            .get(self.data.ui_state.selected_issue_index)
+            .map(|issue| issue.key.clone())
+    }
+
+    /// Re-fetch the selected issue from Jira in the background
+    pub(in crate::tui) fn refresh_selected_issue(&mut self) {
+        if self.issue_refresh_receiver.is_some() {
+            return;
+        }
+        let Some(key) = self.selected_issue_key() else {
+            return;
+        };
+        if self.refuse_offline(&format!("refreshing {}", key)) {
+            return;
+        }
+        logger::log(format!("🔄 Refreshing {} from Jira...", key));
+
+        self.issue_refresh_receiver = Some(runtime::spawn(async move {
+            IssueService::production()
+                .refresh_issue(&key)
+                .await
+                .map_err(|e| format!("{}: {}", key, e.with_hint()))
+        }));
+    }
+
+    pub(in crate::tui) fn handle_issue_refresh_updates(&mut self) {
+        let Some(receiver) = &self.issue_refresh_receiver else {
+            return;
+        };
+        let Ok(result) = receiver.try_recv() else {
+            return;
+        };
+        self.issue_refresh_receiver = None;
+
+        match result {
+            Ok(issue) => {
+                logger::log(format!("✅ {} refreshed ({})", issue.key, issue.status));
+                self.data.issues_by_key.insert(issue.key.clone(), issue);
+            }
+            Err(e) => logger::log(format!("❌ Failed to refresh issue {}", e)),
+        }
+    }
+
+    /// Star or unstar the selected issue; favorites are saved right away
+    pub(in crate::tui) fn toggle_favorite_issue(&mut self) {
+        let Some(key) = self.selected_issue_key() else {
+            return;
+        };
+        let favorites = &mut self.data.ui_state.favorite_issues;
+        if !favorites.remove(&key) {
+            favorites.insert(key);
+        }
+        PersistedUiState::capture(&self.data.ui_state, self.current_tab).save();
+    }
+
+    pub(in crate::tui) fn open_quick_worklog(&mut self) {
+        if let Some(key) = self.selected_issue_key() {
+            self.data.ui_state.quick_worklog = Some(
+                QuickWorklog::new(
+                    key,
+                    Local::now().date_naive(),
+                    self.data.config.worklog.hours_per_day(),
+                )
+                .with_favorites(&self.data.ui_state.favorite_issues),
+            );
+        }
+    }
+
+    pub(in crate::tui) fn handle_quick_worklog_key(&mut self, key: KeyEvent) {
+        let Some(editor) = &mut self.data.ui_state.quick_worklog else {
+            return;
+        };
+
+        match editor.handle_key(key) {
+            QuickWorklogAction::None => {}
+            QuickWorklogAction::Cancel => self.data.ui_state.quick_worklog = None,
+            QuickWorklogAction::Create {
+                started,
+                seconds,
+                comment,
+            } => {
+                let issue_key = editor.issue_key.clone();
+                let worklog = LocalWorklogService::production().create_new_local_worklogs(
+                    started,
+                    seconds,
+                    &issue_key,
+                    comment.as_deref(),
+                    None,
+                );
+                logger::log(format!(
+                    "✅ Created {:.1}h worklog on {} ({})",
+                    seconds as f64 / 3600.0,
+                    issue_key,
+                    started.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+                ));
+                self.record_undo(
+                    format!("quick worklog on {}", issue_key),
+                    UndoableAction::WorklogsCreated(vec![worklog.id]),
+                );
+                self.data.ui_state.quick_worklog = None;
+                self.refresh_data();
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+// Logs tab operations: the search input captures keys while it is open
+
+use crossterm::event::KeyEvent;
+
+use crate::tui::tab_controller::TabController;
+
+use super::super::{Tab, Tui};
+
+impl Tui {
+    pub(in crate::tui) fn logs_search_captures_keys(&self) -> bool {
+        self.current_tab == Tab::Logs && self.data.ui_state.log_search_active
+    }
+
+    /// Hand keys to the Logs tab while its search is open
+    pub(in crate::tui) fn dispatch_logs_search_key(&mut self, key: KeyEvent) -> bool {
+        if !self.logs_search_captures_keys() {
+            return false;
+        }
+        let logs_tab = self.logs_tab.clone();
+        logs_tab.handle_key(self, key);
+        true
+    }
+}
@@ -8,21 +8,87 @@ use regex::Regex;
 
 use wtf_lib::client::jira_client::JiraClient;
 use wtf_lib::config::GOOGLE_CALENDAR_EVENT_COLORS;
-use wtf_lib::models::data::Issue;
 use wtf_lib::services::jira_service::{IssueService, JiraService};
 use wtf_lib::services::meetings_service::MeetingsService;
 use wtf_lib::utils::meetings::is_untracked;
 
 use crate::logger;
 use crate::tasks::worklog_tasks::MeetingWorklogTask;
-use crate::tasks::Task;
+use crate::tasks::{link_script, Task};
+use crate::tui::undo::UndoableAction;
 
 use super::super::{
-    types::{AppEvent, FetchStatus, IssueSelectionState},
+    types::{AppEvent, FetchStatus, IssueSelectionState, NetworkMode, WizardStep},
     Tui,
 };
 
 impl Tui {
+    /// Link a meeting picked in the issue selection, with the rest of its recurring
+    /// `series` when given, undoable with Ctrl+Z
+    pub(in crate::tui) fn link_picked_meeting(
+        &mut self,
+        meeting_id: &str,
+        issue_key: &str,
+        series: Option<&str>,
+    ) {
+        let meetings_service = MeetingsService::production();
+        let Some(mut meeting) = meetings_service.get_meeting_by_id(meeting_id.to_string()) else {
+            return;
+        };
+        let previous = meeting.jira_link.replace(issue_key.to_string());
+        meetings_service.save(&meeting);
+        let mut links = vec![(meeting_id.to_string(), previous)];
+        if let Some(series) = series {
+            links.extend(meetings_service.link_series(series, issue_key));
+            logger::log(format!(
+                "✅ Linked the {} meetings of the series to {}, and its next ones",
+                links.len(),
+                issue_key
+            ));
+        } else {
+            logger::log(format!("✅ Linked meeting to {}", issue_key));
+        }
+        self.record_undo(
+            format!("link meeting to {}", issue_key),
+            UndoableAction::MeetingLinks(links),
+        );
+        self.refresh_data();
+        self.track_wizard_manual_link(meeting_id);
+    }
+
+    /// During the wizard's manual linking, count the link and move on to the next meeting
+    pub(in crate::tui) fn track_wizard_manual_link(&mut self, meeting_id: &str) {
+        let Some(wizard) = &mut self.wizard_state else {
+            return;
+        };
+        let WizardStep::ManualLinking {
+            ref mut unlinked_meetings,
+            ref mut selected_index,
+        } = wizard.current_step
+        else {
+            return;
+        };
+        wizard.summary.meetings_manually_linked += 1;
+        wizard
+            .rollback_log
+            .original_meeting_links
+            .insert(meeting_id.to_string(), None);
+        wizard
+            .rollback_log
+            .linked_meeting_ids
+            .push(meeting_id.to_string());
+
+        unlinked_meetings.retain(|m| m.id != meeting_id);
+        if unlinked_meetings.is_empty() {
+            logger::log("✅ All meetings linked!".to_string());
+            wizard.completed_steps.insert(2);
+            wizard.current_step = WizardStep::CreatingMeetingWorklogs;
+            self.wizard_step_create_meeting_worklogs();
+        } else if *selected_index >= unlinked_meetings.len() {
+            *selected_index = unlinked_meetings.len() - 1;
+        }
+    }
+
     pub(in crate::tui) fn handle_meeting_log(&mut self) {
         // Don't start a new operation if fetch is already in progress
         if matches!(self.fetch_status, FetchStatus::Fetching(_, _, _, _)) {
@@ -60,8 +126,21 @@ impl Tui {
 
     pub(in crate::tui) fn unlink_meeting(&mut self, meeting_id: String) {
         if let Some(mut meeting) = MeetingsService::production().get_meeting_by_id(meeting_id) {
-            meeting.jira_link = None;
+            let previous = meeting.jira_link.take();
             MeetingsService::production().save(&meeting);
+            // Otherwise the next fetch would link it again with its series
+            if let Some(series) = &meeting.recurring_event_id {
+                if MeetingsService::production().forget_series_link(series) {
+                    logger::log(
+                        "🔁 The series' next meetings won't be linked automatically anymore"
+                            .to_string(),
+                    );
+                }
+            }
+            self.record_undo(
+                format!("unlink meeting from {}", previous.as_deref().unwrap_or("?")),
+                UndoableAction::MeetingLinks(vec![(meeting.id.clone(), previous)]),
+            );
             self.refresh_data();
         }
     }
@@ -106,9 +185,13 @@ impl Tui {
             if self.data.issues_by_key.contains_key(issue_key) {
                 // Auto-link
                 let mut meeting = meeting;
-                meeting.jira_link = Some(issue_key.clone());
+                let previous = meeting.jira_link.replace(issue_key.clone());
                 MeetingsService::production().save(&meeting);
                 logger::log(format!("✅ Auto-linked meeting to {}", issue_key));
+                self.record_undo(
+                    format!("link meeting to {}", issue_key),
+                    UndoableAction::MeetingLinks(vec![(meeting_id, previous)]),
+                );
                 self.refresh_data();
                 return;
             }
@@ -129,7 +212,8 @@ impl Tui {
             }
         }
 
-        // Sort issues: extracted issues first, then by usage count
+        // Sort issues: extracted issues first, then the starred ones, then by usage count
+        let favorites = &self.data.ui_state.favorite_issues;
         let mut sorted_issues = all_issues;
         sorted_issues.sort_by(|a, b| {
             let a_extracted = potential_issues.contains(&a.key);
@@ -142,6 +226,11 @@ impl Tui {
                 return std::cmp::Ordering::Greater;
             }
 
+            let favorite_order = favorites.contains(&b.key).cmp(&favorites.contains(&a.key));
+            if favorite_order.is_ne() {
+                return favorite_order;
+            }
+
             // Then by usage count
             let a_count = issue_usage.get(&a.key).copied().unwrap_or(0);
             let b_count = issue_usage.get(&b.key).copied().unwrap_or(0);
@@ -157,12 +246,27 @@ impl Tui {
             ));
         }
 
+        // Issues found in the title stay offered whatever the scope
+        let out_of_scope = sorted_issues
+            .iter()
+            .filter(|issue| {
+                !self.data.config.jira.in_scope(issue) && !potential_issues.contains(&issue.key)
+            })
+            .map(|issue| issue.key.clone())
+            .collect();
+
         // Open issue selection dialog
         self.issue_selection_state = Some(IssueSelectionState {
             meeting_id,
             all_issues: sorted_issues,
             selected_issue_index: 0,
             search_query: String::new(),
+            aliases: self.data.config.aliases.clone(),
+            out_of_scope,
+            show_all: false,
+            favorites: favorites.clone(),
+            series: meeting.recurring_event_id.clone(),
+            link_series: false,
         });
     }
 
@@ -171,15 +275,18 @@ impl Tui {
 
         let jira_regex = Regex::new(r"(?i)([A-Z]+-\d+)").unwrap();
         let mut linked_count = 0;
+        let mut linked_ids: Vec<(String, Option<String>)> = Vec::new();
         let mut color_linked = false;
+        let script = link_script(&self.data.config);
 
-        // Get all unlinked, tracked meetings
+        // Get all unlinked, tracked and attended meetings
         let unlinked_meetings: Vec<_> = self
             .data
             .all_meetings
             .iter()
             .filter(|m| {
                 m.jira_link.is_none()
+                    && self.data.config.meetings.attends(m)
                     && !is_untracked(m, &self.data.config, &self.data.untracked_meeting_ids)
             })
             .collect();
@@ -202,7 +309,7 @@ impl Tui {
         }
 
         // Fetch missing issues directly from Jira and save them locally
-        if !missing_keys.is_empty() {
+        if !missing_keys.is_empty() && self.network_mode == NetworkMode::Online {
             logger::log(format!(
                 "🔍 Fetching {} unknown issue(s) from Jira: {}",
                 missing_keys.len(),
@@ -216,13 +323,7 @@ impl Tui {
                     for key in &keys {
                         match client.get_issue(key).await {
                             Ok(jira_issue) => {
-                                let issue = Issue {
-                                    key: jira_issue.key.clone(),
-                                    id: jira_issue.id,
-                                    created: jira_issue.fields.created,
-                                    status: jira_issue.fields.status.name,
-                                    summary: jira_issue.fields.summary,
-                                };
+                                let issue = jira_issue.into();
                                 IssueService::production().save_issue(&issue);
                             }
                             Err(e) => {
@@ -266,6 +367,7 @@ impl Tui {
                             {
                                 m.jira_link = Some(key.clone());
                                 MeetingsService::production().save(&m);
+                                linked_ids.push((m.id, None));
                                 linked_count += 1;
                                 color_linked = true;
                                 continue;
@@ -275,7 +377,24 @@ impl Tui {
                 }
             }
 
-            // Priority 2: Jira key found in title or description
+            // Priority 2: issue picked by the linking script
+            if let Some(link) = script
+                .as_ref()
+                .and_then(|script| script.link_meeting(meeting))
+                .filter(|link| self.data.issues_by_key.contains_key(&link.issue))
+            {
+                if let Some(mut m) =
+                    MeetingsService::production().get_meeting_by_id(meeting.id.clone())
+                {
+                    m.jira_link = Some(link.issue);
+                    MeetingsService::production().save(&m);
+                    linked_ids.push((m.id, None));
+                    linked_count += 1;
+                    continue;
+                }
+            }
+
+            // Priority 3: Jira key found in title or description
             let empty_string = String::new();
             let title = meeting.title.as_ref().unwrap_or(&empty_string);
             let description = meeting.description.as_ref().unwrap_or(&empty_string);
@@ -292,6 +411,7 @@ impl Tui {
                         {
                             meeting.jira_link = Some(key.clone());
                             MeetingsService::production().save(&meeting);
+                            linked_ids.push((meeting.id, None));
                             linked_count += 1;
                         }
                     }
@@ -300,6 +420,10 @@ impl Tui {
         }
 
         logger::log(format!("✅ Auto-linked {} meeting(s)", linked_count));
+        self.record_undo(
+            format!("auto-link of {} meeting(s)", linked_count),
+            UndoableAction::MeetingLinks(linked_ids),
+        );
 
         if color_linked {
             self.event_bus.publish(AppEvent::MeetingColorLinked);
@@ -1,6 +1,14 @@
 // Operations modules - business logic grouped by domain
 
+mod auto_refresh;
+pub(super) mod credentials;
 mod gap_filling;
 mod github;
+mod issue_detail;
+mod issues;
+mod logs;
 mod meetings;
+pub(super) mod network;
+pub(super) mod runtime;
+mod undo;
 mod worklogs;
@@ -0,0 +1,79 @@
+// Network state: forced offline mode, Jira reachability and the startup checks that need
+// the network
+
+use std::sync::mpsc::Receiver;
+use std::time::Duration;
+
+use wtf_lib::client::offline;
+
+use crate::logger;
+
+use super::super::{types::NetworkMode, Tui};
+use super::runtime;
+
+/// How long the TCP probe of Jira waits before declaring it unreachable
+const REACHABILITY_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// `Forced` when wtf was started with `--offline`, `Online` otherwise
+pub(in crate::tui) fn initial_network_mode() -> NetworkMode {
+    if offline::is_enabled() {
+        logger::log("📴 Offline: working on the cached data, worklogs stay staged".to_string());
+        NetworkMode::Forced
+    } else {
+        NetworkMode::Online
+    }
+}
+
+/// Look for a newer release in the background
+pub(in crate::tui) fn spawn_version_check() -> Receiver<Option<String>> {
+    runtime::spawn(wtf_lib::utils::version::check_latest_version())
+}
+
+/// Probe Jira in the background: when it doesn't answer, wtf goes offline
+pub(in crate::tui) fn spawn_reachability_probe(base_url: String) -> Receiver<bool> {
+    runtime::spawn_blocking(move || offline::is_reachable(&base_url, REACHABILITY_TIMEOUT))
+}
+
+impl Tui {
+    pub(in crate::tui) fn handle_reachability_check(&mut self) {
+        if let Some(receiver) = &self.reachability_receiver {
+            if let Ok(reachable) = receiver.try_recv() {
+                self.reachability_receiver = None;
+                if !reachable {
+                    self.network_mode = NetworkMode::Unreachable;
+                    offline::enable();
+                    logger::log(
+                        "📴 Jira is unreachable, working offline on the cached data".to_string(),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Whether network actions must be skipped. When Jira was found unreachable it is
+    /// probed again, so actions go through as soon as the network is back.
+    pub(crate) fn is_offline(&mut self) -> bool {
+        match self.network_mode {
+            NetworkMode::Online => false,
+            NetworkMode::Forced => true,
+            NetworkMode::Unreachable => {
+                if !offline::is_reachable(&self.data.config.jira.base_url, REACHABILITY_TIMEOUT) {
+                    return true;
+                }
+                self.network_mode = NetworkMode::Online;
+                offline::disable();
+                logger::log("📶 Jira is reachable again, back online".to_string());
+                false
+            }
+        }
+    }
+
+    /// Log that `action` is skipped when offline; true when it must be
+    pub(crate) fn refuse_offline(&mut self, action: &str) -> bool {
+        if !self.is_offline() {
+            return false;
+        }
+        logger::log(format!("📴 Offline: {} skipped", action));
+        true
+    }
+}
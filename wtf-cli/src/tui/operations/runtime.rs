@@ -0,0 +1,51 @@
+// Background work on the runtime `main` started, rather than a runtime per operation
+
+use std::future::Future;
+use std::sync::mpsc::{channel, Receiver};
+
+use tokio::runtime::Handle;
+
+use crate::logger;
+
+/// Run `future` on wtf's runtime; its output arrives on the returned channel. Without a
+/// runtime to run it on, the failure is logged and the channel closes empty.
+pub(in crate::tui) fn spawn<T, F>(future: F) -> Receiver<T>
+where
+    T: Send + 'static,
+    F: Future<Output = T> + Send + 'static,
+{
+    let (sender, receiver) = channel();
+    match Handle::try_current() {
+        Ok(handle) => {
+            handle.spawn(async move {
+                let _ = sender.send(future.await);
+            });
+        }
+        Err(e) => logger::log(format!(
+            "❌ Can't run the operation in the background: {}",
+            e
+        )),
+    }
+    receiver
+}
+
+/// Run the blocking `work` on wtf's runtime's blocking pool, like [`spawn`]
+pub(in crate::tui) fn spawn_blocking<T, F>(work: F) -> Receiver<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (sender, receiver) = channel();
+    match Handle::try_current() {
+        Ok(handle) => {
+            handle.spawn_blocking(move || {
+                let _ = sender.send(work());
+            });
+        }
+        Err(e) => logger::log(format!(
+            "❌ Can't run the operation in the background: {}",
+            e
+        )),
+    }
+    receiver
+}
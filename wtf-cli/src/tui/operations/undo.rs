@@ -0,0 +1,89 @@
+// Undo operations: record local changes and revert the most recent one (Ctrl+Z)
+
+use wtf_lib::models::data::LocalWorklogState;
+use wtf_lib::services::meetings_service::MeetingsService;
+use wtf_lib::services::worklogs_service::LocalWorklogService;
+
+use crate::logger;
+use crate::tui::undo::UndoableAction;
+
+use super::super::Tui;
+
+impl Tui {
+    /// Remember an action for Ctrl+Z. The wizard keeps its own rollback log, so
+    /// nothing is recorded while it runs.
+    pub(in crate::tui) fn record_undo(&mut self, description: String, action: UndoableAction) {
+        if self.wizard_state.is_none() {
+            self.toasts
+                .success(format!("Done: {} · Ctrl+Z to undo", description));
+            self.undo_stack.push(description, action);
+        }
+    }
+
+    pub(in crate::tui) fn undo_last_action(&mut self) {
+        if self.wizard_state.is_some() {
+            logger::log("⚠️  Undo is not available while the wizard runs".to_string());
+            return;
+        }
+        let Some(entry) = self.undo_stack.pop() else {
+            logger::log("Nothing to undo".to_string());
+            self.toasts.info("Nothing to undo");
+            return;
+        };
+
+        let worklog_service = LocalWorklogService::production();
+        // Pushed worklogs live in Jira too; they are reverted through History instead
+        let mut skipped_pushed = 0;
+        match entry.action {
+            UndoableAction::MeetingLinks(previous) => {
+                let meetings_service = MeetingsService::production();
+                for (meeting_id, link) in previous {
+                    if let Some(mut meeting) = meetings_service.get_meeting_by_id(meeting_id) {
+                        meeting.jira_link = link;
+                        meetings_service.save(&meeting);
+                    }
+                }
+            }
+            UndoableAction::WorklogStatuses(previous) => {
+                for (worklog_id, status) in previous {
+                    if let Some(mut worklog) = worklog_service.get_worklog(&worklog_id) {
+                        if worklog.status == LocalWorklogState::Pushed {
+                            skipped_pushed += 1;
+                            continue;
+                        }
+                        worklog.status = status;
+                        worklog_service.save_local_worklog(worklog);
+                    }
+                }
+            }
+            UndoableAction::WorklogsDeleted(worklogs) => {
+                for worklog in worklogs {
+                    worklog_service.save_local_worklog(worklog);
+                }
+            }
+            UndoableAction::WorklogsCreated(worklog_ids) => {
+                for worklog_id in worklog_ids {
+                    if let Some(worklog) = worklog_service.get_worklog(&worklog_id) {
+                        if worklog.status == LocalWorklogState::Pushed {
+                            skipped_pushed += 1;
+                            continue;
+                        }
+                        worklog_service.remove_local_worklog(&worklog);
+                    }
+                }
+            }
+        }
+
+        logger::log(format!("↩️  Undid: {}", entry.description));
+        self.toasts.success(format!("Undid: {}", entry.description));
+        if skipped_pushed > 0 {
+            logger::log(format!(
+                "⚠️  {} worklog(s) were already pushed and were left alone (use History to revert)",
+                skipped_pushed
+            ));
+            self.toasts
+                .error(format!("{} pushed worklog(s) left alone", skipped_pushed));
+        }
+        self.refresh_data();
+    }
+}
@@ -1,10 +1,16 @@
 // Worklog operations: create, stage, push, delete, reset
 
+use chrono::{DateTime, Utc};
+use crossterm::event::KeyEvent;
 use wtf_lib::models::data::{LocalWorklogState, Meeting};
 use wtf_lib::services::worklogs_service::LocalWorklogService;
+use wtf_lib::utils::hooks::{self, HookEvent};
 
 use crate::logger;
+use crate::tasks::stage_worklogs;
 use crate::tui::log_chronie_message;
+use crate::tui::tab_controller::TabController;
+use crate::tui::undo::UndoableAction;
 
 use super::super::{
     types::{FetchStatus, WizardStep},
@@ -12,6 +18,16 @@ use super::super::{
 };
 
 impl Tui {
+    /// Hand keys to the Worklogs tab while its filter popup is open
+    pub(in crate::tui) fn dispatch_worklog_filter_key(&mut self, key: KeyEvent) -> bool {
+        if self.data.ui_state.worklog_filter_editor.is_none() {
+            return false;
+        }
+        let worklogs_tab = self.worklogs_tab;
+        worklogs_tab.handle_key(self, key);
+        true
+    }
+
     pub(in crate::tui) fn handle_push_worklogs(&mut self) {
         if self.push_receiver.is_some() {
             logger::log("⏳ Push already in progress — please wait".to_string());
@@ -38,6 +54,31 @@ impl Tui {
         }
 
         let count = staged_worklogs.len();
+        if self.is_offline() {
+            logger::log(format!(
+                "📴 Offline: {} staged worklogs will be pushed once back online",
+                count
+            ));
+            if let Some(wizard) = &self.wizard_state {
+                if matches!(wizard.current_step, WizardStep::Pushing) {
+                    self.wizard_push_complete();
+                }
+            }
+            return;
+        }
+        if let Err(e) = hooks::run(
+            &self.data.config.hooks,
+            HookEvent::PrePush,
+            &staged_worklogs,
+        ) {
+            logger::log(format!("❌ The pre_push hook cancelled the push: {}", e));
+            if let Some(wizard) = &self.wizard_state {
+                if matches!(wizard.current_step, WizardStep::Pushing) {
+                    self.wizard_push_complete();
+                }
+            }
+            return;
+        }
         logger::log(format!("🚀 Starting push of {} worklogs...", count));
         self.fetch_status =
             FetchStatus::Fetching(format!("Pushing {} worklogs to Jira...", count), 0, 0, None);
@@ -105,7 +146,7 @@ impl Tui {
                                 idx + 1,
                                 total_count,
                                 worklog.issue_id,
-                                e
+                                e.with_hint()
                             ));
                         }
                     }
@@ -132,11 +173,16 @@ impl Tui {
             .collect();
 
         let count = unpushed_worklogs.len();
-        for worklog in unpushed_worklogs {
+        for worklog in &unpushed_worklogs {
             LocalWorklogService::production().remove_local_worklog(worklog);
         }
 
         logger::log(format!("Deleted {} unpushed worklogs", count));
+        let deleted = unpushed_worklogs.into_iter().cloned().collect();
+        self.record_undo(
+            format!("reset of {} worklog(s)", count),
+            UndoableAction::WorklogsDeleted(deleted),
+        );
         self.refresh_data();
         self.data.ui_state.selected_worklog_index = 0;
     }
@@ -145,6 +191,10 @@ impl Tui {
         if let Some(worklog) = LocalWorklogService::production().get_worklog(&worklog_id) {
             LocalWorklogService::production().remove_local_worklog(&worklog);
             logger::log(format!("Deleted worklog {}", worklog_id));
+            self.record_undo(
+                format!("delete worklog on {}", worklog.issue_id),
+                UndoableAction::WorklogsDeleted(vec![worklog]),
+            );
             log_chronie_message("erasing_timeline", "🧙 Chronie:");
             self.refresh_data();
         }
@@ -154,15 +204,31 @@ impl Tui {
         if let Some(mut worklog) = LocalWorklogService::production().get_worklog(&worklog_id) {
             match worklog.status {
                 LocalWorklogState::Created => {
-                    worklog.status = LocalWorklogState::Staged;
-                    LocalWorklogService::production().save_local_worklog(worklog.clone());
+                    // Held back by a blocking validation rule, already reported
+                    if stage_worklogs(vec![worklog.clone()], &self.data.config).is_empty() {
+                        return;
+                    }
                     logger::log(format!("Staged worklog for {}", worklog.issue_id));
+                    self.record_undo(
+                        format!("stage worklog on {}", worklog.issue_id),
+                        UndoableAction::WorklogStatuses(vec![(
+                            worklog_id,
+                            LocalWorklogState::Created,
+                        )]),
+                    );
                     self.refresh_data();
                 }
                 LocalWorklogState::Staged => {
                     worklog.status = LocalWorklogState::Created;
                     LocalWorklogService::production().save_local_worklog(worklog.clone());
                     logger::log(format!("Unstaged worklog for {}", worklog.issue_id));
+                    self.record_undo(
+                        format!("unstage worklog on {}", worklog.issue_id),
+                        UndoableAction::WorklogStatuses(vec![(
+                            worklog_id,
+                            LocalWorklogState::Staged,
+                        )]),
+                    );
                     self.refresh_data();
                 }
                 LocalWorklogState::Pushed => {
@@ -182,13 +248,17 @@ impl Tui {
             .cloned()
             .collect();
 
-        let count = created_worklogs.len();
-        for mut worklog in created_worklogs {
-            worklog.status = LocalWorklogState::Staged;
-            LocalWorklogService::production().save_local_worklog(worklog);
-        }
+        let previous: Vec<_> = stage_worklogs(created_worklogs, &self.data.config)
+            .into_iter()
+            .map(|worklog| (worklog.id, LocalWorklogState::Created))
+            .collect();
+        let count = previous.len();
 
         logger::log(format!("Staged {} worklogs", count));
+        self.record_undo(
+            format!("stage all ({} worklogs)", count),
+            UndoableAction::WorklogStatuses(previous),
+        );
         self.refresh_data();
     }
 
@@ -196,9 +266,9 @@ impl Tui {
         &mut self,
         meeting: &Meeting,
         issue_key: &str,
+        started: DateTime<Utc>,
+        duration_seconds: i64,
     ) {
-        // Calculate duration from start and end
-        let duration_seconds = (meeting.end - meeting.start).num_seconds();
         let duration_hours = duration_seconds as f64 / 3600.0;
 
         let comment = format!(
@@ -207,7 +277,7 @@ impl Tui {
         );
 
         let worklog = LocalWorklogService::production().create_new_local_worklogs(
-            meeting.start,
+            started,
             duration_seconds,
             issue_key,
             Some(&comment),
@@ -0,0 +1,225 @@
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, TimeZone, Utc};
+use crossterm::event::{KeyCode, KeyEvent};
+use wtf_lib::duration::parse_work_duration;
+
+/// Fields of the quick worklog popup, in display order
+const FIELD_LABELS: [&str; 4] = ["Date", "Start", "Duration", "Comment"];
+const COMMENT_FIELD: usize = 3;
+
+/// Editing state of the quick worklog popup on the Issues tab (opened with `w`)
+#[derive(Debug, Clone)]
+pub struct QuickWorklog {
+    pub issue_key: String,
+    /// Date (YYYY-MM-DD), start (HH:MM, local time), duration and comment
+    pub fields: Vec<String>,
+    pub selected_field: usize,
+    pub error: Option<String>,
+    /// Length of a `1d` typed as the duration
+    pub hours_per_day: f64,
+    /// Issues ←/→ switch between: the one the popup was opened on, then the favorites
+    pub targets: Vec<String>,
+}
+
+/// Outcome of a key press in the quick worklog popup
+#[derive(Debug, PartialEq, Eq)]
+pub enum QuickWorklogAction {
+    None,
+    Create {
+        started: DateTime<Utc>,
+        seconds: i64,
+        comment: Option<String>,
+    },
+    Cancel,
+}
+
+impl QuickWorklog {
+    /// Start a worklog on `today` at 09:00 for one hour
+    pub fn new(issue_key: String, today: NaiveDate, hours_per_day: f64) -> Self {
+        Self {
+            fields: vec![
+                today.format("%Y-%m-%d").to_string(),
+                "09:00".to_string(),
+                "1".to_string(),
+                String::new(),
+            ],
+            targets: vec![issue_key.clone()],
+            issue_key,
+            selected_field: 0,
+            error: None,
+            hours_per_day,
+        }
+    }
+
+    /// Offer the starred issues as other targets
+    pub fn with_favorites<'a>(mut self, favorites: impl IntoIterator<Item = &'a String>) -> Self {
+        for key in favorites {
+            if !self.targets.contains(key) {
+                self.targets.push(key.clone());
+            }
+        }
+        self
+    }
+
+    /// Log on the next (`step` = 1) or previous (`step` = -1) target
+    fn switch_target(&mut self, step: isize) {
+        let count = self.targets.len() as isize;
+        let current = self
+            .targets
+            .iter()
+            .position(|key| *key == self.issue_key)
+            .unwrap_or(0) as isize;
+        self.issue_key = self.targets[(current + step).rem_euclid(count) as usize].clone();
+    }
+
+    pub fn field_labels(&self) -> &'static [&'static str] {
+        &FIELD_LABELS
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> QuickWorklogAction {
+        match key.code {
+            KeyCode::Esc => return QuickWorklogAction::Cancel,
+            KeyCode::Enter => match self.to_action() {
+                Ok(action) => return action,
+                Err(e) => self.error = Some(e),
+            },
+            KeyCode::Up | KeyCode::BackTab => {
+                self.selected_field = self.selected_field.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Tab => {
+                self.selected_field = (self.selected_field + 1).min(self.fields.len() - 1);
+            }
+            KeyCode::Left => self.switch_target(-1),
+            KeyCode::Right => self.switch_target(1),
+            KeyCode::Backspace => {
+                self.fields[self.selected_field].pop();
+            }
+            KeyCode::Char(c) if self.selected_field == COMMENT_FIELD => {
+                self.fields[COMMENT_FIELD].push(c);
+            }
+            KeyCode::Char(c)
+                if c.is_ascii_alphanumeric() || matches!(c, '-' | ':' | '.' | ',' | ' ') =>
+            {
+                self.fields[self.selected_field].push(c);
+            }
+            _ => {}
+        }
+        QuickWorklogAction::None
+    }
+
+    fn to_action(&self) -> Result<QuickWorklogAction, String> {
+        let date = NaiveDate::parse_from_str(self.fields[0].trim(), "%Y-%m-%d")
+            .map_err(|_| "'Date' must look like 2026-03-02".to_string())?;
+        let time = NaiveTime::parse_from_str(self.fields[1].trim(), "%H:%M")
+            .map_err(|_| "'Start' must be a time like 09:30".to_string())?;
+        let seconds = parse_work_duration(&self.fields[2], self.hours_per_day)
+            .map_err(|_| "'Duration' must look like 1.5, 1h30, 1:30 or 1d".to_string())?
+            .num_seconds();
+        if seconds <= 0 || seconds > 24 * 3600 {
+            return Err("'Duration' must be between 0 and 24 hours".to_string());
+        }
+        let started = Local
+            .from_local_datetime(&date.and_time(time))
+            .earliest()
+            .ok_or_else(|| "That start time does not exist locally".to_string())?
+            .with_timezone(&Utc);
+        let comment = self.fields[COMMENT_FIELD].trim();
+
+        Ok(QuickWorklogAction::Create {
+            started,
+            seconds,
+            comment: (!comment.is_empty()).then(|| comment.to_string()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::empty())
+    }
+
+    fn type_text(editor: &mut QuickWorklog, text: &str) {
+        for c in text.chars() {
+            editor.handle_key(key(KeyCode::Char(c)));
+        }
+    }
+
+    #[test]
+    fn test_creates_worklog_with_typed_hours_and_comment() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let mut editor = QuickWorklog::new("PROJ-1".to_string(), today, 8.0);
+        editor.selected_field = 2;
+        editor.handle_key(key(KeyCode::Backspace));
+        type_text(&mut editor, "1,5");
+        editor.handle_key(key(KeyCode::Down));
+        type_text(&mut editor, "Code review");
+
+        let expected_start = Local
+            .from_local_datetime(&today.and_hms_opt(9, 0, 0).unwrap())
+            .earliest()
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            editor.handle_key(key(KeyCode::Enter)),
+            QuickWorklogAction::Create {
+                started: expected_start,
+                seconds: 5400,
+                comment: Some("Code review".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_switches_between_favorites() {
+        let favorites = ["OPS-7".to_string(), "PROJ-1".to_string()];
+        let mut editor = QuickWorklog::new(
+            "PROJ-1".to_string(),
+            NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+            8.0,
+        )
+        .with_favorites(&favorites);
+
+        assert_eq!(editor.targets, vec!["PROJ-1", "OPS-7"]);
+        editor.handle_key(key(KeyCode::Right));
+        assert_eq!(editor.issue_key, "OPS-7");
+        editor.handle_key(key(KeyCode::Right));
+        assert_eq!(editor.issue_key, "PROJ-1");
+        editor.handle_key(key(KeyCode::Left));
+        assert_eq!(editor.issue_key, "OPS-7");
+    }
+
+    #[test]
+    fn test_rejects_zero_hours() {
+        let mut editor = QuickWorklog::new(
+            "PROJ-1".to_string(),
+            NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+            8.0,
+        );
+        editor.fields[2] = "0".to_string();
+
+        assert_eq!(
+            editor.handle_key(key(KeyCode::Enter)),
+            QuickWorklogAction::None
+        );
+        assert!(editor.error.is_some());
+    }
+
+    #[test]
+    fn test_accepts_jira_style_durations() {
+        let mut editor = QuickWorklog::new(
+            "PROJ-1".to_string(),
+            NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+            7.5,
+        );
+        editor.fields[2] = "1d".to_string();
+
+        let QuickWorklogAction::Create { seconds, .. } = editor.handle_key(key(KeyCode::Enter))
+        else {
+            panic!("{:?}", editor.error);
+        };
+        assert_eq!(seconds, 27_000);
+    }
+}
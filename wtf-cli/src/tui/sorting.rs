@@ -0,0 +1,154 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Sort order for the Worklogs, Meetings and GitHub lists (cycled with `o`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ListSort {
+    #[default]
+    DateDesc,
+    DateAsc,
+    Issue,
+    Duration,
+    Status,
+}
+
+impl ListSort {
+    pub fn next(self) -> Self {
+        match self {
+            ListSort::DateDesc => ListSort::DateAsc,
+            ListSort::DateAsc => ListSort::Issue,
+            ListSort::Issue => ListSort::Duration,
+            ListSort::Duration => ListSort::Status,
+            ListSort::Status => ListSort::DateDesc,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ListSort::DateDesc => "Date ↓",
+            ListSort::DateAsc => "Date ↑",
+            ListSort::Issue => "Issue",
+            ListSort::Duration => "Duration",
+            ListSort::Status => "Status",
+        }
+    }
+
+    /// Whether items end up ordered by date (lists may keep their day grouping)
+    pub fn is_chronological(self) -> bool {
+        matches!(self, ListSort::DateDesc | ListSort::DateAsc)
+    }
+
+    /// Sort items in place. `status_rank` gives the position of an item's status
+    /// (lower first). Ties always fall back to newest first.
+    pub fn sort<T: SortKey>(self, items: &mut [T], status_rank: impl Fn(&T) -> u8) {
+        items.sort_by(|a, b| {
+            let primary = match self {
+                ListSort::DateDesc => Ordering::Equal,
+                ListSort::DateAsc => a.sort_date().cmp(&b.sort_date()),
+                ListSort::Issue => compare_issues(a.sort_issue(), b.sort_issue()),
+                ListSort::Duration => b.sort_duration().cmp(&a.sort_duration()),
+                ListSort::Status => status_rank(a).cmp(&status_rank(b)),
+            };
+            primary.then_with(|| b.sort_date().cmp(&a.sort_date()))
+        });
+    }
+}
+
+/// Fields a list item exposes so it can be ordered by [`ListSort`]
+pub trait SortKey {
+    fn sort_date(&self) -> DateTime<Utc>;
+    fn sort_issue(&self) -> Option<&str>;
+    fn sort_duration(&self) -> i64;
+}
+
+/// Items with an issue come first (alphabetically), items without one last
+fn compare_issues(a: Option<&str>, b: Option<&str>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.cmp(b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    struct Item {
+        day: u32,
+        issue: Option<&'static str>,
+        duration: i64,
+        rank: u8,
+    }
+
+    impl SortKey for Item {
+        fn sort_date(&self) -> DateTime<Utc> {
+            Utc.with_ymd_and_hms(2026, 1, self.day, 9, 0, 0).unwrap()
+        }
+        fn sort_issue(&self) -> Option<&str> {
+            self.issue
+        }
+        fn sort_duration(&self) -> i64 {
+            self.duration
+        }
+    }
+
+    fn items() -> Vec<Item> {
+        vec![
+            Item {
+                day: 2,
+                issue: None,
+                duration: 60,
+                rank: 1,
+            },
+            Item {
+                day: 1,
+                issue: Some("PROJ-2"),
+                duration: 600,
+                rank: 0,
+            },
+            Item {
+                day: 3,
+                issue: Some("PROJ-1"),
+                duration: 300,
+                rank: 2,
+            },
+        ]
+    }
+
+    fn days(sort: ListSort) -> Vec<u32> {
+        let mut list = items();
+        sort.sort(&mut list, |i| i.rank);
+        list.iter().map(|i| i.day).collect()
+    }
+
+    #[test]
+    fn test_next_cycles_through_all_orders() {
+        let mut sort = ListSort::default();
+        for _ in 0..5 {
+            sort = sort.next();
+        }
+        assert_eq!(sort, ListSort::DateDesc);
+        assert_eq!(ListSort::DateDesc.next(), ListSort::DateAsc);
+    }
+
+    #[test]
+    fn test_sort_by_date() {
+        assert_eq!(days(ListSort::DateDesc), vec![3, 2, 1]);
+        assert_eq!(days(ListSort::DateAsc), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_sort_by_issue_puts_missing_last() {
+        assert_eq!(days(ListSort::Issue), vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn test_sort_by_duration_and_status() {
+        assert_eq!(days(ListSort::Duration), vec![1, 3, 2]);
+        assert_eq!(days(ListSort::Status), vec![1, 2, 3]);
+    }
+}
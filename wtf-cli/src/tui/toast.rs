@@ -0,0 +1,136 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use super::types::{AppEvent, EventSubscriber};
+use super::Tui;
+
+/// How many toasts are kept on screen; older ones make room for new ones
+const MAX_TOASTS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastKind {
+    Info,
+    Success,
+    Error,
+}
+
+impl ToastKind {
+    /// Errors stay up longer so they can actually be read
+    fn lifetime(self) -> Duration {
+        match self {
+            ToastKind::Info | ToastKind::Success => Duration::from_secs(3),
+            ToastKind::Error => Duration::from_secs(6),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub kind: ToastKind,
+    pub message: String,
+    expires_at: Instant,
+}
+
+/// Short-lived notifications shown in the top-right corner, oldest first
+#[derive(Debug, Default)]
+pub struct ToastQueue {
+    toasts: VecDeque<Toast>,
+}
+
+impl ToastQueue {
+    pub fn info(&mut self, message: impl Into<String>) {
+        self.push(ToastKind::Info, message.into(), Instant::now());
+    }
+
+    pub fn success(&mut self, message: impl Into<String>) {
+        self.push(ToastKind::Success, message.into(), Instant::now());
+    }
+
+    pub fn error(&mut self, message: impl Into<String>) {
+        self.push(ToastKind::Error, message.into(), Instant::now());
+    }
+
+    fn push(&mut self, kind: ToastKind, message: String, now: Instant) {
+        if self.toasts.len() == MAX_TOASTS {
+            self.toasts.pop_front();
+        }
+        self.toasts.push_back(Toast {
+            kind,
+            message,
+            expires_at: now + kind.lifetime(),
+        });
+    }
+
+    /// Drop every toast whose timer ran out
+    pub fn expire(&mut self, now: Instant) {
+        self.toasts.retain(|toast| toast.expires_at > now);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Toast> {
+        self.toasts.iter()
+    }
+
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+}
+
+/// Turns background operation results into toasts
+pub struct ToastNotifier;
+
+impl EventSubscriber for ToastNotifier {
+    fn on_event(&mut self, event: &AppEvent, tui: &mut Tui) {
+        let toasts = &mut tui.toasts;
+        match event {
+            AppEvent::FetchComplete(_) => toasts.success("Sync complete"),
+            AppEvent::FetchError(err) => toasts.error(err.clone()),
+            AppEvent::PushComplete { .. } => toasts.success("Worklogs pushed to Jira"),
+            AppEvent::PushError(err) => toasts.error(format!("Push failed: {}", err)),
+            AppEvent::RevertComplete => toasts.success("Worklogs reverted"),
+            AppEvent::RevertError(err) => toasts.error(format!("Revert failed: {}", err)),
+            AppEvent::AutoLinkComplete { linked_count } if *linked_count > 0 => {
+                toasts.info(format!("Auto-linked {} meeting(s)", linked_count))
+            }
+            AppEvent::AchievementUnlocked { achievement } => {
+                let meta = achievement.meta();
+                toasts.success(format!("{} {} unlocked", meta.icon, meta.name))
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toasts_expire_on_their_own_timers() {
+        let now = Instant::now();
+        let mut queue = ToastQueue::default();
+        queue.push(ToastKind::Success, "saved".to_string(), now);
+        queue.push(ToastKind::Error, "failed".to_string(), now);
+
+        queue.expire(now + Duration::from_secs(4));
+        let left: Vec<_> = queue.iter().map(|t| t.message.as_str()).collect();
+        assert_eq!(left, vec!["failed"]);
+
+        queue.expire(now + Duration::from_secs(7));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_oldest_toast_makes_room_for_new_ones() {
+        let now = Instant::now();
+        let mut queue = ToastQueue::default();
+        for i in 0..MAX_TOASTS + 1 {
+            queue.push(ToastKind::Info, format!("toast {}", i), now);
+        }
+
+        let messages: Vec<_> = queue.iter().map(|t| t.message.clone()).collect();
+        assert_eq!(messages.len(), MAX_TOASTS);
+        assert_eq!(messages[0], "toast 1");
+        assert_eq!(messages[MAX_TOASTS - 1], format!("toast {}", MAX_TOASTS));
+    }
+}
@@ -1,12 +1,17 @@
 // Type definitions for dashboard state and configuration
 
-use std::collections::{HashMap, VecDeque};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
 use std::sync::mpsc::Receiver;
 
-use wtf_lib::models::data::Meeting;
+use wtf_lib::models::data::{Issue, Meeting};
+use wtf_lib::models::jira::JiraIssue;
 use wtf_lib::services::achievement_service::AchievementService;
+use wtf_lib::services::sync_status_service::CredentialStatus;
+use wtf_lib::utils::period::Period;
 
 use super::data::TuiData;
+use super::helpers::filter_issues;
 
 // ============================================================================
 // EventBus System - Centralized event handling
@@ -129,8 +134,19 @@ pub enum FetchStatus {
     Error(String),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// Whether the TUI reaches Jira, Google and GitHub or works on the cached data only
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkMode {
+    Online,
+    /// `--offline` or `WTF_OFFLINE`
+    Forced,
+    /// Jira didn't answer at startup; probed again before each network action
+    Unreachable,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum Tab {
+    Dashboard,
     Sprints,
     Meetings,
     Worklogs,
@@ -138,12 +154,15 @@ pub enum Tab {
     History,
     Achievements,
     Settings,
+    Logs,
+    Issues,
 }
 
 impl Tab {
     /// Get all available tabs (conditional based on state)
     pub fn available_tabs(has_achievements: bool) -> Vec<Tab> {
         let mut tabs = vec![
+            Tab::Dashboard,
             Tab::Sprints,
             Tab::Meetings,
             Tab::GitHub,
@@ -155,10 +174,29 @@ impl Tab {
         if has_achievements {
             tabs.push(Tab::Achievements);
         }
+        tabs.push(Tab::Logs);
+        tabs.push(Tab::Issues);
 
         tabs
     }
 
+    /// The tab the number key `digit` switches to, from `0` (Dashboard) to `9` (Issues)
+    pub fn for_digit(digit: char, has_achievements: bool) -> Option<Tab> {
+        match digit {
+            '0' => Some(Tab::Dashboard),
+            '1' => Some(Tab::Sprints),
+            '2' => Some(Tab::Meetings),
+            '3' => Some(Tab::GitHub),
+            '4' => Some(Tab::History),
+            '5' => Some(Tab::Worklogs),
+            '6' => Some(Tab::Settings),
+            '7' => has_achievements.then_some(Tab::Achievements),
+            '8' => Some(Tab::Logs),
+            '9' => Some(Tab::Issues),
+            _ => None,
+        }
+    }
+
     pub fn next(self, has_achievements: bool) -> Self {
         let tabs = Self::available_tabs(has_achievements);
         let current_index = tabs.iter().position(|&t| t == self).unwrap_or(0);
@@ -186,6 +224,7 @@ impl Tab {
         use super::tab_controller::TabController;
 
         match self {
+            Tab::Dashboard => tui.dashboard_tab.render(frame, area, &tui.data),
             Tab::Sprints => tui.sprints_tab.render(frame, area, &tui.data),
             Tab::Meetings => tui.meetings_tab.render(frame, area, &tui.data),
             Tab::Worklogs => tui.worklogs_tab.render(frame, area, &tui.data),
@@ -193,6 +232,8 @@ impl Tab {
             Tab::History => tui.history_tab.render(frame, area, &tui.data),
             Tab::Achievements => tui.achievements_tab.render(frame, area, &tui.data),
             Tab::Settings => tui.settings_tab.render(frame, area, &tui.data),
+            Tab::Logs => tui.logs_tab.render(frame, area, &tui.data),
+            Tab::Issues => tui.issues_tab.render(frame, area, &tui.data),
         }
     }
 
@@ -200,6 +241,10 @@ impl Tab {
         use super::tab_controller::TabController;
 
         match self {
+            Tab::Dashboard => {
+                let controller = tui.dashboard_tab;
+                controller.handle_key(tui, key);
+            }
             Tab::Sprints => {
                 let controller = tui.sprints_tab;
                 controller.handle_key(tui, key);
@@ -228,6 +273,14 @@ impl Tab {
                 let controller = tui.settings_tab;
                 controller.handle_key(tui, key);
             }
+            Tab::Logs => {
+                let controller = tui.logs_tab.clone();
+                controller.handle_key(tui, key);
+            }
+            Tab::Issues => {
+                let controller = tui.issues_tab;
+                controller.handle_key(tui, key);
+            }
         }
     }
 
@@ -248,6 +301,7 @@ pub struct Tui {
     pub(crate) tiered_achievement_service:
         wtf_lib::services::tiered_achievement_service::TieredAchievementService,
     pub(crate) current_tab: Tab,
+    pub(in crate::tui) dashboard_tab: super::ui::tabs::dashboard::DashboardTab,
     pub(in crate::tui) sprints_tab: super::ui::tabs::sprints::SprintsTab,
     pub(in crate::tui) achievements_tab: super::ui::tabs::achievements::AchievementsTab,
     pub(in crate::tui) meetings_tab: super::ui::tabs::meetings::MeetingsTab,
@@ -255,6 +309,8 @@ pub struct Tui {
     pub(in crate::tui) settings_tab: super::ui::tabs::settings::SettingsTab,
     pub(in crate::tui) worklogs_tab: super::ui::tabs::worklogs::WorklogsTab,
     pub(in crate::tui) history_tab: super::ui::tabs::history::HistoryTab,
+    pub(in crate::tui) logs_tab: super::ui::tabs::logs::LogsTab,
+    pub(in crate::tui) issues_tab: super::ui::tabs::issues::IssuesTab,
     pub(crate) revert_confirmation_state: Option<RevertConfirmationState>,
     pub(crate) worklog_creation_confirmation: Option<WorklogCreationConfirmation>,
     pub(crate) gap_fill_state: Option<GapFillState>,
@@ -264,11 +320,15 @@ pub struct Tui {
     pub(crate) wizard_pre_launch_prompt: Option<WizardPreLaunchPrompt>,
     pub(crate) sprint_follow_state: Option<SprintFollowState>,
     pub(crate) issue_selection_state: Option<IssueSelectionState>,
+    pub(crate) issue_detail_state: Option<IssueDetailState>,
     pub(crate) unlink_confirmation_meeting_id: Option<String>,
     pub(crate) show_about_popup: bool,
     pub(crate) about_image: Option<image::DynamicImage>,
     pub(crate) image_picker: Option<ratatui_image::picker::Picker>,
     pub(crate) fetch_status: FetchStatus,
+    pub(crate) network_mode: NetworkMode,
+    /// Outcome of the last Jira credential check, `None` until one answers
+    pub(crate) credential_status: Option<CredentialStatus>,
 
     // EventBus - Centralized event system
     pub(crate) event_bus: EventBus,
@@ -284,10 +344,26 @@ pub struct Tui {
     pub(super) push_progress_receiver: Option<Receiver<String>>,
     pub(super) data_refresh_receiver: Option<Receiver<super::data::TuiData>>,
     pub(super) update_receiver: Option<Receiver<Option<String>>>,
+    /// Receives whether Jira answered the startup probe.
+    pub(super) reachability_receiver: Option<Receiver<bool>>,
+    /// Receives the outcome of the `myself` probe run at startup and before each update.
+    pub(super) credential_receiver: Option<Receiver<CredentialStatus>>,
     /// Receives resolved (issue_id, title) pairs for Settings color label lookups.
     pub(super) settings_issue_title_receiver: Option<Receiver<(String, Option<String>)>>,
+    /// Receives the Jira issue fetched for the issue detail popup.
+    pub(super) issue_detail_receiver: Option<Receiver<Result<JiraIssue, String>>>,
+    /// Receives the issue re-fetched from the Issues tab.
+    pub(super) issue_refresh_receiver:
+        Option<Receiver<Result<wtf_lib::models::data::Issue, String>>>,
+
+    /// Recent local changes that Ctrl+Z can revert
+    pub(super) undo_stack: super::undo::UndoStack,
+    /// Notifications shown in the top-right corner
+    pub(super) toasts: super::toast::ToastQueue,
 
     pub(super) last_fetch_completed_at: Option<std::time::Instant>,
+    /// When the next background refresh is due, with `ui.auto_refresh_minutes`
+    pub(super) next_auto_refresh: Option<std::time::Instant>,
     pub(super) status_clear_time: Option<std::time::Instant>,
     pub(super) needs_full_clear: bool,
     pub(super) should_quit: bool,
@@ -304,21 +380,56 @@ pub struct RevertConfirmationState {
 
 pub struct IssueSelectionState {
     pub(crate) meeting_id: String,
-    pub(crate) all_issues: Vec<wtf_lib::models::data::Issue>, // Keep all issues
+    pub(crate) all_issues: Vec<Issue>, // Keep all issues
     pub(crate) selected_issue_index: usize,
-    pub(crate) search_query: String, // Search filter
+    pub(crate) search_query: String,             // Search filter
+    pub(crate) aliases: HashMap<String, String>, // From the `[aliases]` config, searchable
+    /// Issues outside `jira.assigned_only`/`jira.active_statuses`, hidden unless `show_all`
+    pub(crate) out_of_scope: HashSet<String>,
+    pub(crate) show_all: bool,
+    /// Starred issues, marked in the list
+    pub(crate) favorites: BTreeSet<String>,
+    /// Recurring event ID of the meeting, `None` for a one-off meeting
+    pub(crate) series: Option<String>,
+    /// Link every meeting of the series, the ones fetched later included
+    pub(crate) link_series: bool,
+}
+
+impl IssueSelectionState {
+    /// Issues matching the search, the out-of-scope ones only once widened
+    pub(crate) fn filtered_issues(&self) -> Vec<&Issue> {
+        filter_issues(&self.all_issues, &self.search_query, &self.aliases)
+            .into_iter()
+            .filter(|issue| self.show_all || !self.out_of_scope.contains(&issue.key))
+            .collect()
+    }
+}
+
+/// Issue detail popup, opened from issue selection with Tab
+pub struct IssueDetailState {
+    pub(crate) issue_key: String,
+    pub(crate) detail: IssueDetailLoad,
+    pub(crate) scroll_offset: u16,
+}
+
+pub enum IssueDetailLoad {
+    Loading,
+    Loaded(Box<JiraIssue>),
+    Failed(String),
 }
 
 pub struct GapFillState {
-    pub(crate) sprint_id: usize,
+    pub(crate) period: Period,
     pub(crate) all_issues: Vec<wtf_lib::models::data::Issue>,
     pub(crate) selected_issue_index: usize,
     pub(crate) search_query: String,
+    pub(crate) aliases: HashMap<String, String>,
+    /// Starred issues, listed first and marked
+    pub(crate) favorites: BTreeSet<String>,
 }
 
 pub struct GapFillConfirmation {
-    pub(crate) _sprint_id: usize,
-    pub(crate) sprint_name: String,
+    pub(crate) period: Period,
     pub(crate) issue_id: String,
     pub(crate) gaps: Vec<(chrono::NaiveDate, f64)>, // (date, hours_to_add)
 }
@@ -371,6 +482,10 @@ impl WorklogCreationConfirmation {
 // Wizard state structures
 #[allow(dead_code)]
 pub enum WizardStep {
+    /// Choose which steps run, shown before syncing
+    Setup {
+        selected_index: usize,
+    },
     Syncing,
     AutoLinking,
     ManualLinking {
@@ -387,14 +502,33 @@ pub enum WizardStep {
     },
     ReviewingWorklogs {
         excluded_days: std::collections::HashSet<chrono::NaiveDate>,
+        /// Validation rules the worklogs to push break; blocking ones won't be staged
+        violations: Vec<wtf_lib::utils::worklog_rules::Violation>,
     },
     Pushing,
     Complete,
 }
 
+impl WizardStep {
+    /// The step as named in the audit log
+    pub fn name(&self) -> &'static str {
+        match self {
+            WizardStep::Setup { .. } => "Setup",
+            WizardStep::Syncing => "Sync",
+            WizardStep::AutoLinking => "Auto-link",
+            WizardStep::ManualLinking { .. } => "Manual link",
+            WizardStep::CreatingMeetingWorklogs => "Meeting worklogs",
+            WizardStep::CreatingGitHubWorklogs { .. } => "GitHub worklogs",
+            WizardStep::FillingGaps { .. } => "Fill gaps",
+            WizardStep::ReviewingWorklogs { .. } => "Review",
+            WizardStep::Pushing => "Push",
+            WizardStep::Complete => "Complete",
+        }
+    }
+}
+
 pub struct WizardState {
-    pub sprint_id: usize,
-    pub sprint_name: String,
+    pub period: Period,
     pub current_step: WizardStep,
     pub started_at: chrono::DateTime<chrono::Utc>,
     pub completed_steps: std::collections::HashSet<usize>, // 1-8
@@ -407,6 +541,26 @@ pub struct WizardState {
     pub push_total: usize,                    // Total worklogs to push (for progress bar)
     pub startup_message: Option<String>,      // Chronie's startup quote (set once)
     pub github_step_intro: Option<GitHubStepIntro>, // Shown before processing GitHub sessions
+    pub skipped_steps: BTreeSet<usize>,       // Steps turned off on the setup screen
+    pub checkpoints: Vec<WizardCheckpoint>,   // Interactive steps entered so far, for going back
+}
+
+/// Wizard steps that can be turned off on the setup screen (review and push always run)
+pub const WIZARD_OPTIONAL_STEPS: [(usize, &str); 5] = [
+    (1, "Sync & auto-link"),
+    (2, "Manual-link meetings"),
+    (3, "Create meeting worklogs"),
+    (4, "Create GitHub worklogs"),
+    (5, "Fill gaps"),
+];
+
+/// Where an interactive step started, so `b` can undo everything done since
+#[derive(Clone)]
+pub struct WizardCheckpoint {
+    pub step: usize,
+    pub linked_meetings: usize,
+    pub created_worklogs: usize,
+    pub summary: WizardSummary,
 }
 
 #[derive(Clone, Default)]
@@ -416,6 +570,28 @@ pub struct WizardRollbackLog {
     pub original_meeting_links: HashMap<String, Option<String>>,
 }
 
+impl WizardRollbackLog {
+    /// Remove and return the entries recorded after the first `linked` meeting
+    /// links and `created` worklogs
+    pub fn split_off(&mut self, linked: usize, created: usize) -> WizardRollbackLog {
+        let linked_meeting_ids = self
+            .linked_meeting_ids
+            .split_off(linked.min(self.linked_meeting_ids.len()));
+        let created_worklog_ids = self
+            .created_worklog_ids
+            .split_off(created.min(self.created_worklog_ids.len()));
+        let original_meeting_links = linked_meeting_ids
+            .iter()
+            .filter_map(|id| self.original_meeting_links.remove_entry(id))
+            .collect();
+        WizardRollbackLog {
+            linked_meeting_ids,
+            created_worklog_ids,
+            original_meeting_links,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct WizardSummary {
     pub meetings_auto_linked: usize,
@@ -445,6 +621,37 @@ pub struct WizardCancelConfirmation;
 
 pub struct WizardPreLaunchPrompt {
     pub(crate) existing_count: usize,
-    pub(crate) sprint_id: usize,
-    pub(crate) sprint_name: String,
+    pub(crate) period: Period,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_keys_pick_tabs() {
+        assert_eq!(Tab::for_digit('0', false), Some(Tab::Dashboard));
+        assert_eq!(Tab::for_digit('9', false), Some(Tab::Issues));
+        assert_eq!(Tab::for_digit('7', false), None);
+        assert_eq!(Tab::for_digit('7', true), Some(Tab::Achievements));
+    }
+
+    #[test]
+    fn test_rollback_log_split_off_keeps_entries_before_checkpoint() {
+        let mut log = WizardRollbackLog::default();
+        for id in ["m1", "m2", "m3"] {
+            log.linked_meeting_ids.push(id.to_string());
+            log.original_meeting_links.insert(id.to_string(), None);
+        }
+        log.created_worklog_ids.push("w1".to_string());
+
+        let undone = log.split_off(1, 1);
+
+        assert_eq!(log.linked_meeting_ids, vec!["m1"]);
+        assert_eq!(log.created_worklog_ids, vec!["w1"]);
+        assert_eq!(log.original_meeting_links.len(), 1);
+        assert_eq!(undone.linked_meeting_ids, vec!["m2", "m3"]);
+        assert!(undone.created_worklog_ids.is_empty());
+        assert_eq!(undone.original_meeting_links.len(), 2);
+    }
 }
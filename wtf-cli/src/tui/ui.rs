@@ -1,4 +1,4 @@
-use chrono::Local;
+use chrono::Utc;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
@@ -6,10 +6,12 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
+use wtf_lib::common::Common;
+use wtf_lib::services::sync_status_service::CredentialStatus;
 
 use super::data::TuiData;
 use super::theme::theme;
-use super::{FetchStatus, Tab};
+use super::{FetchStatus, NetworkMode, Tab};
 
 mod popups;
 pub(in crate::tui) mod tabs;
@@ -22,14 +24,16 @@ pub fn render(frame: &mut Frame, tui: &super::Tui, logs: &[String]) {
         frame.area(),
     );
 
+    // The Logs tab already shows every message, so the panel makes room for it
+    let logs_panel_height = if tui.current_tab == Tab::Logs { 0 } else { 8 };
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(0) // Ensure no margin
         .constraints([
-            Constraint::Length(3), // Tab bar
-            Constraint::Min(0),    // Content
-            Constraint::Length(8), // Logs panel (7 lines + border)
-            Constraint::Length(1), // Status bar
+            Constraint::Length(3),                 // Tab bar
+            Constraint::Min(0),                    // Content
+            Constraint::Length(logs_panel_height), // Logs panel (7 lines + border)
+            Constraint::Length(1),                 // Status bar
         ])
         .split(frame.area());
 
@@ -40,19 +44,33 @@ pub fn render(frame: &mut Frame, tui: &super::Tui, logs: &[String]) {
     tui.current_tab.render(tui, frame, &main_chunks[1]);
 
     // Render logs panel
-    render_logs_panel(frame, &main_chunks[2], logs, tui.log_scroll_offset);
+    if logs_panel_height > 0 {
+        render_logs_panel(frame, &main_chunks[2], logs, tui.log_scroll_offset);
+    }
 
     // Render status bar at bottom
-    render_status_bar(frame, &main_chunks[3], &tui.data, &tui.fetch_status);
+    render_status_bar(
+        frame,
+        &main_chunks[3],
+        &tui.data,
+        &tui.fetch_status,
+        tui.network_mode,
+        tui.credential_status.as_ref(),
+    );
 
     // Render all active popups in priority order
     popups::render_all(frame, tui);
+
+    if tui.data.config.ui.ascii_mode {
+        super::ascii::asciify_buffer(frame.buffer_mut());
+    }
 }
 
 fn render_tab_bar(frame: &mut Frame, area: &Rect, tui: &super::Tui) {
     let has_achievements = tui.achievement_service.has_any_unlocked();
 
     let mut tabs = vec![
+        ("0", "Dashboard", Tab::Dashboard),
         ("1", "Sprints", Tab::Sprints),
         ("2", "Meetings", Tab::Meetings),
         ("3", "GitHub", Tab::GitHub),
@@ -64,6 +82,8 @@ fn render_tab_bar(frame: &mut Frame, area: &Rect, tui: &super::Tui) {
     if has_achievements {
         tabs.push(("7", "Achievements", Tab::Achievements));
     }
+    tabs.push(("8", "Logs", Tab::Logs));
+    tabs.push(("9", "Issues", Tab::Issues));
 
     let tab_titles: Vec<Span> = tabs
         .iter()
@@ -152,16 +172,41 @@ fn render_logs_panel(frame: &mut Frame, area: &Rect, logs: &[String], scroll_off
 }
 
 /// Render status bar at the bottom
-fn render_status_bar(frame: &mut Frame, area: &Rect, data: &TuiData, fetch_status: &FetchStatus) {
-    // Split status bar into left (app version) and center (status/shortcuts)
+fn render_status_bar(
+    frame: &mut Frame,
+    area: &Rect,
+    data: &TuiData,
+    fetch_status: &FetchStatus,
+    network_mode: NetworkMode,
+    credentials: Option<&CredentialStatus>,
+) {
+    // Split status bar into left (app version), center (status/shortcuts) and right
+    // (offline badge)
+    let offline_width = if network_mode == NetworkMode::Online {
+        0
+    } else {
+        9
+    };
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
             Constraint::Length(20), // Left: app version (enough for "WTF (0.1.0-beta.0)")
             Constraint::Min(0),     // Center: status/shortcuts
+            Constraint::Length(offline_width),
         ])
         .split(*area);
 
+    if network_mode != NetworkMode::Online {
+        let badge = Paragraph::new(Span::styled(
+            " OFFLINE ",
+            Style::default()
+                .fg(theme().bg_primary)
+                .bg(theme().warning)
+                .add_modifier(Modifier::BOLD),
+        ));
+        frame.render_widget(badge, chunks[2]);
+    }
+
     // Render app name and version on the left
     let version = env!("CARGO_PKG_VERSION");
     let app_info = Paragraph::new(Line::from(vec![Span::styled(
@@ -213,9 +258,9 @@ fn render_status_bar(frame: &mut Frame, area: &Rect, data: &TuiData, fetch_statu
 
             // Sub-step bar (per-item progress within the current step)
             if let Some((sub_done, sub_total)) = sub {
-                if *sub_total > 0 {
-                    let bar_width = 10usize;
-                    let filled = ((*sub_done * bar_width) / *sub_total).min(bar_width);
+                let bar_width = 10usize;
+                if let Some(filled) = (*sub_done * bar_width).checked_div(*sub_total) {
+                    let filled = filled.min(bar_width);
                     let empty = bar_width - filled;
                     let bar = format!("  ({}/{})", sub_done, sub_total);
                     let progress = format!("[{}{}]", "█".repeat(filled), "░".repeat(empty));
@@ -250,13 +295,33 @@ fn render_status_bar(frame: &mut Frame, area: &Rect, data: &TuiData, fetch_statu
                 Style::default().fg(theme().error),
             ),
         ]),
+        FetchStatus::Idle if credentials.is_some_and(CredentialStatus::is_rejected) => {
+            // A refused token breaks every fetch: say so until a check passes
+            let message = credentials
+                .and_then(CredentialStatus::message)
+                .unwrap_or_default();
+            Line::from(vec![
+                Span::styled(
+                    format!("🔑 {}", message),
+                    Style::default()
+                        .fg(theme().error)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw("   "),
+                Span::styled(
+                    "[U]",
+                    Style::default()
+                        .fg(theme().highlight)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(" Retry"),
+            ])
+        }
         FetchStatus::Idle => {
             // Show normal footer with last sync time
-            let elapsed = Local::now().signed_duration_since(data.last_sync.with_timezone(&Local));
-            let time_ago = if elapsed.num_seconds() < 60 {
-                format!("{}s ago", elapsed.num_seconds())
-            } else {
-                format!("{}m ago", elapsed.num_minutes())
+            let time_ago = match data.last_sync {
+                Some(last_sync) => Common::time_ago(Utc::now() - last_sync),
+                None => "never".to_string(),
             };
 
             Line::from(vec![
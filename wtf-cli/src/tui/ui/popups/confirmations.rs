@@ -12,6 +12,7 @@ use crate::tui::ui_helpers::*;
 use crate::tui::{
     GapFillConfirmation, RevertConfirmationState, WorklogCreationConfirmation, WorklogSource,
 };
+use wtf_lib::duration::format_hours;
 use wtf_lib::services::worklogs_service::LocalWorklogService;
 
 /// Render unlink confirmation dialog
@@ -203,7 +204,7 @@ pub(in crate::tui) fn render_revert_confirmation(
         ]
     } else {
         // Show confirmation form
-        vec![
+        let mut lines = vec![
             Line::from(""),
             Line::from(vec![Span::styled(
                 "⚠️  DANGER: Revert Worklog Push ⚠️",
@@ -236,37 +237,57 @@ pub(in crate::tui) fn render_revert_confirmation(
             ]),
             Line::from(""),
             Line::from(""),
-            Line::from(vec![
-                Span::raw("Type the number of hours ("),
-                Span::styled(
-                    format!("{:.1}", total_hours),
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::raw(") to confirm:"),
-            ]),
-            Line::from(vec![Span::raw("")]),
-            Line::from(vec![
+        ];
+        if data.config.confirmations.revert_type_hours {
+            lines.extend([
+                Line::from(vec![
+                    Span::raw("Type the number of hours ("),
+                    Span::styled(
+                        format!("{:.1}", total_hours),
+                        Style::default()
+                            .fg(Color::Yellow)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(") to confirm:"),
+                ]),
+                Line::from(vec![Span::raw("")]),
+                Line::from(vec![
+                    Span::styled(
+                        state.user_input.clone(),
+                        Style::default()
+                            .fg(Color::White)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled("_", Style::default().fg(Color::DarkGray)),
+                ]),
+                Line::from(""),
+                Line::from(vec![
+                    Span::styled(
+                        "[Esc]",
+                        Style::default()
+                            .fg(Color::DarkGray)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(" Cancel"),
+                ]),
+            ]);
+        } else {
+            lines.push(Line::from(vec![
                 Span::styled(
-                    &state.user_input,
-                    Style::default()
-                        .fg(Color::White)
-                        .add_modifier(Modifier::BOLD),
+                    "[Y]",
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
                 ),
-                Span::styled("_", Style::default().fg(Color::DarkGray)),
-            ]),
-            Line::from(""),
-            Line::from(vec![
+                Span::raw(" Revert   "),
                 Span::styled(
-                    "[Esc]",
+                    "[N/Esc]",
                     Style::default()
                         .fg(Color::DarkGray)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::raw(" Cancel"),
-            ]),
-        ]
+            ]));
+        }
+        lines
     };
 
     let block = Block::default()
@@ -350,21 +371,21 @@ pub(in crate::tui) fn render_worklog_creation_confirmation(
         Line::from(vec![
             Span::raw("Already logged:  "),
             Span::styled(
-                format!("{:.1}h", state.existing_hours),
+                format_hours(state.existing_hours),
                 Style::default().fg(Color::White),
             ),
         ]),
         Line::from(vec![
             Span::raw("Requested:      +"),
             Span::styled(
-                format!("{:.1}h", state.requested_hours),
+                format_hours(state.requested_hours),
                 Style::default().fg(Color::White),
             ),
         ]),
         Line::from(vec![
             Span::raw("Total would be:  "),
             Span::styled(
-                format!("{:.1}h", total_with_full),
+                format_hours(total_with_full),
                 if total_with_full > state.daily_limit {
                     Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
                 } else {
@@ -479,8 +500,12 @@ pub(in crate::tui) fn render_gap_fill_confirmation(
         )]),
         Line::from(""),
         Line::from(vec![
-            Span::raw("Sprint: "),
-            Span::styled(&state.sprint_name, Style::default().fg(Color::Cyan)),
+            Span::raw(if state.period.sprint_id.is_some() {
+                "Sprint: "
+            } else {
+                "Period: "
+            }),
+            Span::styled(&state.period.name, Style::default().fg(Color::Cyan)),
         ]),
         Line::from(vec![
             Span::raw("Issue: "),
@@ -499,7 +524,7 @@ pub(in crate::tui) fn render_gap_fill_confirmation(
         Line::from(vec![
             Span::raw("Total hours: "),
             Span::styled(
-                format!("{:.1}h", total_hours),
+                format_hours(total_hours),
                 Style::default()
                     .fg(Color::Cyan)
                     .add_modifier(Modifier::BOLD),
@@ -523,7 +548,7 @@ pub(in crate::tui) fn render_gap_fill_confirmation(
                 Style::default().fg(Color::White),
             ),
             Span::raw(": +"),
-            Span::styled(format!("{:.1}h", hours), Style::default().fg(Color::Green)),
+            Span::styled(format_hours(*hours), Style::default().fg(Color::Green)),
             Span::raw(format!(" ({:.1} → {:.1})", existing, existing + hours)),
         ]));
     }
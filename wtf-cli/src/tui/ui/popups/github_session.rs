@@ -0,0 +1,286 @@
+use chrono::{DateTime, Local, Utc};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use wtf_lib::duration::format_hours;
+use wtf_lib::models::data::GitHubEvent;
+
+use crate::tui::data::{GitHubSessionDrillDown, TuiData};
+use crate::tui::github_session_edit::{SessionEditMode, SessionEditor};
+use crate::tui::theme::theme;
+use crate::tui::ui::tabs::github::normalized_activity_name;
+use crate::tui::ui_helpers::*;
+
+/// Gaps at least this long are highlighted: they still count towards the session duration
+const LONG_GAP_MINUTES: i64 = 30;
+
+pub(in crate::tui) fn render_github_session_popup(
+    frame: &mut Frame,
+    drill_down: &GitHubSessionDrillDown,
+    data: &TuiData,
+) {
+    let Some(session) = data
+        .github_sessions
+        .iter()
+        .find(|s| s.id == drill_down.session_id)
+    else {
+        return;
+    };
+
+    let area = frame.area();
+    let popup_width = 100.min(area.width.saturating_sub(4));
+    let popup_height = 30.min(area.height.saturating_sub(4));
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    frame.render_widget(Clear, popup_area);
+
+    let mut events: Vec<&GitHubEvent> = session
+        .get_event_ids()
+        .iter()
+        .filter_map(|id| data.github_events_by_id.get(id))
+        .collect();
+    events.sort_by_key(|e| e.timestamp);
+
+    let label = Style::default().fg(Color::Gray);
+    let value = Style::default().fg(Color::White);
+    let span_minutes = (session.end_time - session.start_time).num_minutes();
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("📁 ", label),
+            Span::styled(
+                session.repo.clone(),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("📅 Date: ", label),
+            Span::styled(
+                format!(
+                    "{} ({}-{})",
+                    session.date,
+                    time_label(session.start_time),
+                    time_label(session.end_time)
+                ),
+                value,
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("⏱  Duration: ", label),
+            Span::styled(format_hours(session.duration_hours()), value),
+            Span::styled(
+                format!(
+                    "  (first to last event: {}h {:02}m, 15m minimum)",
+                    span_minutes / 60,
+                    span_minutes % 60
+                ),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("🧩 Events ({})", events.len()),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+    ];
+
+    if events.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No event records stored for this session (sync again to rebuild them)",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let text_width = popup_width.saturating_sub(4) as usize;
+    let mut previous: Option<DateTime<Utc>> = None;
+    for event in &events {
+        let gap = previous.map(|p| (event.timestamp - p).num_minutes());
+        previous = Some(event.timestamp);
+
+        let gap_label = match gap {
+            Some(minutes) if minutes > 0 => format!("+{:>3}m", minutes),
+            _ => "     ".to_string(),
+        };
+        let gap_color = if gap.is_some_and(|m| m >= LONG_GAP_MINUTES) {
+            Color::Yellow
+        } else {
+            Color::DarkGray
+        };
+        let (icon, kind) = event_kind(&event.event_type);
+        let issues = event.get_jira_issues().join(", ");
+        let used = 28 + issues.chars().count();
+
+        let mut spans = vec![
+            Span::raw("  "),
+            Span::styled(
+                time_label(event.timestamp),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::styled(format!(" {} ", gap_label), Style::default().fg(gap_color)),
+            Span::styled(
+                format!("{} {:<13}", icon, kind),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(
+                truncate_string(&event.description, text_width.saturating_sub(used)),
+                value,
+            ),
+        ];
+        if !issues.is_empty() {
+            spans.push(Span::styled(
+                format!(" {}", issues),
+                Style::default().fg(Color::Green),
+            ));
+        }
+        lines.push(Line::from(spans));
+
+        if event.repo != session.repo {
+            lines.push(Line::from(Span::styled(
+                format!("{:>22}{}", "", event.repo),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+    }
+
+    let visible = popup_height.saturating_sub(2);
+    let max_scroll = (lines.len() as u16).saturating_sub(visible);
+    let scroll = drill_down.scroll_offset.min(max_scroll);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Session Events | [↑↓] Scroll | [Esc] Close ")
+        .title_alignment(Alignment::Center)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(theme().bg_primary));
+
+    let paragraph = Paragraph::new(lines).block(block).scroll((scroll, 0));
+    frame.render_widget(paragraph, popup_area);
+}
+
+pub(in crate::tui) fn render_github_session_editor_popup(
+    frame: &mut Frame,
+    editor: &SessionEditor,
+) {
+    let area = frame.area();
+    let popup_width = 56.min(area.width.saturating_sub(4));
+    let popup_height = 12.min(area.height.saturating_sub(4));
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    frame.render_widget(Clear, popup_area);
+
+    let (title, hint) = match editor.mode {
+        SessionEditMode::Split => (
+            "✂️  Split Session",
+            "Events before this time stay in the first session",
+        ),
+        SessionEditMode::Trim => ("✂️  Trim Session", "Events outside the range are dropped"),
+    };
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+
+    for (idx, (label, value)) in editor.field_labels().iter().zip(&editor.fields).enumerate() {
+        let is_selected = idx == editor.selected_field;
+        let (cursor, value_display, value_style) = if is_selected {
+            (
+                theme().selector,
+                format!("{}█", value),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            (
+                theme().unselected_selector,
+                value.clone(),
+                Style::default().fg(Color::White),
+            )
+        };
+        lines.push(Line::from(vec![
+            Span::styled(cursor, Style::default().fg(Color::Yellow)),
+            Span::raw(" "),
+            Span::styled(format!("{:<10}", label), Style::default().fg(Color::Gray)),
+            Span::styled(value_display, value_style),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    for note in ["Times as HH:MM, as shown in the list", hint] {
+        lines.push(Line::from(Span::styled(
+            note,
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    if let Some(error) = &editor.error {
+        lines.push(Line::from(Span::styled(
+            format!("❌ {}", error),
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" [Enter] Apply | [Esc] Cancel ")
+        .title_alignment(Alignment::Center)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(theme().bg_primary));
+
+    frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
+
+fn time_label(time: DateTime<Utc>) -> String {
+    time.with_timezone(&Local).format("%H:%M").to_string()
+}
+
+fn event_kind(event_type: &str) -> (&'static str, String) {
+    let icon = match event_type {
+        "PushEvent" => "📦",
+        "PullRequestEvent" => "🔀",
+        "PullRequestReviewEvent" => "👀",
+        "PullRequestReviewCommentEvent" | "IssueCommentEvent" | "CommitCommentEvent" => "💬",
+        "IssuesEvent" => "🐛",
+        "CreateEvent" | "DeleteEvent" => "🌿",
+        _ => "•",
+    };
+    (icon, normalized_activity_name(event_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_kind_groups_comments_and_names_unknown_events() {
+        assert_eq!(event_kind("PushEvent"), ("📦", "Push".to_string()));
+        assert_eq!(
+            event_kind("IssueCommentEvent"),
+            ("💬", "Issue comment".to_string())
+        );
+        assert_eq!(event_kind("ForkEvent"), ("•", "Fork".to_string()));
+    }
+}
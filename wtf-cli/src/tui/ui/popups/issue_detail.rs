@@ -0,0 +1,207 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::tui::data::TuiData;
+use crate::tui::theme::theme;
+use crate::tui::ui_helpers::*;
+use crate::tui::{IssueDetailLoad, IssueDetailState};
+
+/// Number of my worklogs listed under the issue
+const RECENT_WORKLOGS: usize = 10;
+
+pub(in crate::tui) fn render_issue_detail_popup(
+    frame: &mut Frame,
+    state: &IssueDetailState,
+    data: &TuiData,
+) {
+    let area = frame.area();
+    let popup_width = 90.min(area.width.saturating_sub(4));
+    let popup_height = 30.min(area.height.saturating_sub(4));
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    frame.render_widget(Clear, popup_area);
+
+    let text_width = popup_width.saturating_sub(6) as usize;
+    let cached = data.issues_by_key.get(&state.issue_key);
+    let label = Style::default().fg(Color::Gray);
+    let value = Style::default().fg(Color::White);
+
+    let mut lines = Vec::new();
+
+    let summary = match &state.detail {
+        IssueDetailLoad::Loaded(issue) => issue.fields.summary.clone(),
+        _ => cached.map(|i| i.summary.clone()).unwrap_or_default(),
+    };
+    lines.push(Line::from(vec![
+        Span::styled(
+            format!("🎫 {} ", state.issue_key),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            truncate_string(
+                &summary,
+                text_width.saturating_sub(state.issue_key.len() + 4),
+            ),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]));
+    lines.push(Line::from(""));
+
+    match &state.detail {
+        IssueDetailLoad::Loading => {
+            if let Some(issue) = cached {
+                lines.push(Line::from(vec![
+                    Span::styled("Status: ", label),
+                    Span::styled(issue.status.clone(), value),
+                ]));
+            }
+            lines.push(Line::from(vec![Span::styled(
+                "⏳ Loading details from Jira...",
+                Style::default().fg(Color::Yellow),
+            )]));
+        }
+        IssueDetailLoad::Failed(error) => {
+            lines.push(Line::from(vec![Span::styled(
+                format!("❌ Could not load issue: {}", error),
+                Style::default().fg(Color::Red),
+            )]));
+        }
+        IssueDetailLoad::Loaded(issue) => {
+            lines.push(Line::from(vec![
+                Span::styled("Status:   ", label),
+                Span::styled(issue.fields.status.name.clone(), value),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled("Assignee: ", label),
+                Span::styled(
+                    issue
+                        .fields
+                        .assignee
+                        .as_ref()
+                        .map(|a| a.display_name.clone())
+                        .unwrap_or_else(|| "Unassigned".to_string()),
+                    value,
+                ),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled("Created:  ", label),
+                Span::styled(issue.fields.created.format("%Y-%m-%d").to_string(), value),
+            ]));
+            lines.push(Line::from(""));
+            lines.push(section_title("📝 Description"));
+            match &issue.fields.description {
+                Some(description) => {
+                    for paragraph in description.lines() {
+                        for wrapped in wrap_text(paragraph, text_width) {
+                            lines.push(Line::from(Span::styled(format!("  {}", wrapped), value)));
+                        }
+                    }
+                }
+                None => lines.push(Line::from(Span::styled(
+                    "  No description",
+                    Style::default().fg(Color::DarkGray),
+                ))),
+            }
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.extend(recent_worklog_lines(data, &state.issue_key, text_width));
+
+    // Keep at least one page of content visible when scrolled past the end
+    let visible = popup_height.saturating_sub(2);
+    let max_scroll = (lines.len() as u16).saturating_sub(visible);
+    let scroll = state.scroll_offset.min(max_scroll);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Issue Details | [↑↓] Scroll | [Esc] Close ")
+        .title_alignment(Alignment::Center)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(theme().bg_primary));
+
+    let paragraph = Paragraph::new(lines).block(block).scroll((scroll, 0));
+    frame.render_widget(paragraph, popup_area);
+}
+
+fn section_title(title: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        title.to_string(),
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn recent_worklog_lines(data: &TuiData, issue_key: &str, width: usize) -> Vec<Line<'static>> {
+    let mut worklogs: Vec<_> = data
+        .all_worklogs
+        .iter()
+        .filter(|w| w.issue_id == issue_key)
+        .collect();
+    worklogs.sort_by_key(|w| std::cmp::Reverse(w.started));
+
+    let total_hours: f64 = worklogs
+        .iter()
+        .map(|w| w.time_spent_seconds as f64 / 3600.0)
+        .sum();
+
+    let mut lines = vec![section_title(&format!(
+        "⏱  My worklogs ({}, {:.1}h total)",
+        worklogs.len(),
+        total_hours
+    ))];
+
+    if worklogs.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No worklogs on this issue yet",
+            Style::default().fg(Color::DarkGray),
+        )));
+        return lines;
+    }
+
+    for worklog in worklogs.iter().take(RECENT_WORKLOGS) {
+        let status = worklog_status_style(&worklog.status, data.config.ui.color_blind_palette);
+        lines.push(Line::from(vec![
+            Span::raw("  "),
+            Span::styled(
+                worklog.started.format("%Y-%m-%d").to_string(),
+                Style::default().fg(Color::White),
+            ),
+            Span::styled(
+                format!(" {:>5.1}h ", worklog.time_spent_seconds as f64 / 3600.0),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(
+                format!("{} {:<8}", status.icon, status.label.to_lowercase()),
+                Style::default().fg(status.color),
+            ),
+            Span::styled(
+                truncate_string(&worklog.comment, width.saturating_sub(28)),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+    }
+    if worklogs.len() > RECENT_WORKLOGS {
+        lines.push(Line::from(Span::styled(
+            format!("  … {} older worklogs", worklogs.len() - RECENT_WORKLOGS),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    lines
+}
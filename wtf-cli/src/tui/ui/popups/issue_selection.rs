@@ -6,6 +6,7 @@ use ratatui::{
     Frame,
 };
 
+use crate::tui::helpers::filter_issues;
 use crate::tui::theme::theme;
 use crate::tui::ui_helpers::*;
 use crate::tui::{GapFillState, IssueSelectionState};
@@ -39,19 +40,7 @@ pub(in crate::tui) fn render_issue_selection_popup(frame: &mut Frame, state: &Is
     );
 
     // Filter issues based on search query
-    let filtered_issues: Vec<&wtf_lib::models::data::Issue> = state
-        .all_issues
-        .iter()
-        .filter(|issue| {
-            if state.search_query.is_empty() {
-                true
-            } else {
-                let query_lower = state.search_query.to_lowercase();
-                issue.key.to_lowercase().contains(&query_lower)
-                    || issue.summary.to_lowercase().contains(&query_lower)
-            }
-        })
-        .collect();
+    let filtered_issues = state.filtered_issues();
 
     // Render the issue list
     let mut lines = vec![];
@@ -107,6 +96,11 @@ pub(in crate::tui) fn render_issue_selection_popup(frame: &mut Frame, state: &Is
             let is_selected = idx == state.selected_issue_index;
 
             let cursor = if is_selected { "❯ " } else { "  " };
+            let star = if state.favorites.contains(&issue.key) {
+                "★ "
+            } else {
+                "  "
+            };
             let base_style = if is_selected {
                 Style::default().bg(Color::DarkGray)
             } else {
@@ -124,6 +118,7 @@ pub(in crate::tui) fn render_issue_selection_popup(frame: &mut Frame, state: &Is
 
             lines.push(Line::from(vec![
                 Span::styled(cursor, base_style.fg(Color::Yellow)),
+                Span::styled(star, base_style.fg(Color::Yellow)),
                 Span::styled(
                     &issue.key,
                     base_style.fg(Color::Cyan).add_modifier(Modifier::BOLD),
@@ -134,11 +129,24 @@ pub(in crate::tui) fn render_issue_selection_popup(frame: &mut Frame, state: &Is
         }
     }
 
-    let showing = if !state.search_query.is_empty() {
-        format!("{}/{}", filtered_issues.len(), state.all_issues.len())
+    let offered = if state.show_all {
+        state.all_issues.len()
+    } else {
+        state.all_issues.len() - state.out_of_scope.len()
+    };
+    let mut showing = if !state.search_query.is_empty() {
+        format!("{}/{}", filtered_issues.len(), offered)
     } else {
-        format!("{}", state.all_issues.len())
+        format!("{}", offered)
     };
+    if state.show_all && !state.out_of_scope.is_empty() {
+        showing.push_str(", all | [Ctrl+A] My issues");
+    } else if !state.out_of_scope.is_empty() {
+        showing.push_str(&format!(
+            ", {} hidden | [Ctrl+A] Show all",
+            state.out_of_scope.len()
+        ));
+    }
 
     let meeting_info = if let Some(ref m) = meeting {
         let meeting_title = m.title.as_deref().unwrap_or("Untitled meeting");
@@ -147,9 +155,14 @@ pub(in crate::tui) fn render_issue_selection_popup(frame: &mut Frame, state: &Is
         String::new()
     };
 
+    let series = match (&state.series, state.link_series) {
+        (None, _) => "",
+        (Some(_), false) => "[Ctrl+S] Whole series | ",
+        (Some(_), true) => "Whole series ✓ [Ctrl+S] | ",
+    };
     let title = format!(
-        "{}Select Jira Issue ({}) | Type to search | [Enter] Select | [Esc] Cancel",
-        meeting_info, showing
+        "{}Select Jira Issue ({}) | Type to search | [Enter] Select | {}[Tab] Details | [Esc] Cancel",
+        meeting_info, showing, series
     );
 
     let block = Block::default()
@@ -185,19 +198,8 @@ pub(in crate::tui) fn render_gap_fill_issue_selection(frame: &mut Frame, state:
     frame.render_widget(Clear, popup_area);
 
     // Filter issues based on search
-    let filtered_issues: Vec<_> = state
-        .all_issues
-        .iter()
-        .filter(|issue| {
-            if state.search_query.is_empty() {
-                true
-            } else {
-                let query_lower = state.search_query.to_lowercase();
-                issue.key.to_lowercase().contains(&query_lower)
-                    || issue.summary.to_lowercase().contains(&query_lower)
-            }
-        })
-        .collect();
+    let filtered_issues: Vec<_> =
+        filter_issues(&state.all_issues, &state.search_query, &state.aliases);
 
     let block = Block::default()
         .title("Select Issue to Fill Gaps")
@@ -219,8 +221,14 @@ pub(in crate::tui) fn render_gap_fill_issue_selection(frame: &mut Frame, state:
                 Style::default()
             };
 
+            let star = if state.favorites.contains(&issue.key) {
+                "★"
+            } else {
+                " "
+            };
             let content = format!(
-                "{:<15} {}",
+                "{} {:<15} {}",
+                star,
                 issue.key,
                 issue.summary.chars().take(50).collect::<String>(),
             );
@@ -1,9 +1,14 @@
 // Popup rendering modules organized by functionality
 
 mod confirmations;
+mod github_session;
+mod issue_detail;
 mod issue_selection;
 mod other;
+mod quick_worklog;
+mod toasts;
 mod wizard;
+mod worklog_filter;
 
 use ratatui::Frame;
 
@@ -12,6 +17,12 @@ pub(in crate::tui) use wizard::{
     render_wizard, render_wizard_cancel_confirmation, render_wizard_pre_launch_prompt,
 };
 
+pub(in crate::tui) use github_session::{
+    render_github_session_editor_popup, render_github_session_popup,
+};
+
+pub(in crate::tui) use issue_detail::render_issue_detail_popup;
+
 pub(in crate::tui) use issue_selection::{
     render_gap_fill_issue_selection, render_issue_selection_popup,
 };
@@ -23,13 +34,20 @@ pub(in crate::tui) use confirmations::{
 
 pub(in crate::tui) use other::{render_about_popup, render_sprint_follow_popup};
 
+pub(in crate::tui) use worklog_filter::render_worklog_filter_popup;
+
+pub(in crate::tui) use quick_worklog::render_quick_worklog_popup;
+
+pub(in crate::tui) use toasts::render_toasts;
+
 /// Render all active popups in the correct priority order
 ///
 /// Popup rendering priority (bottom to top):
 /// 1. Wizard (if active)
 /// 2. Various confirmations and selections
 /// 3. Wizard cancel confirmation (highest priority)
-/// 4. About popup (on top of everything)
+/// 4. Toast notifications
+/// 5. About popup (on top of everything)
 pub(in crate::tui) fn render_all(frame: &mut Frame, tui: &crate::tui::Tui) {
     // Render wizard pre-launch prompt (existing unpushed worklogs detected)
     if let Some(prompt) = &tui.wizard_pre_launch_prompt {
@@ -51,6 +69,11 @@ pub(in crate::tui) fn render_all(frame: &mut Frame, tui: &crate::tui::Tui) {
         render_issue_selection_popup(frame, state);
     }
 
+    // Render issue detail popup on top of the selection it was opened from
+    if let Some(state) = &tui.issue_detail_state {
+        render_issue_detail_popup(frame, state, &tui.data);
+    }
+
     // Render unlink confirmation if active
     if let Some(meeting_id) = &tui.unlink_confirmation_meeting_id {
         render_unlink_confirmation(frame, &tui.data, meeting_id);
@@ -83,6 +106,29 @@ pub(in crate::tui) fn render_all(frame: &mut Frame, tui: &crate::tui::Tui) {
         render_sprint_follow_popup(frame, state);
     }
 
+    // Render GitHub session drill-down if active
+    if let Some(drill_down) = &tui.data.ui_state.github_drill_down {
+        render_github_session_popup(frame, drill_down, &tui.data);
+    }
+
+    // Render GitHub session split/trim popup if active
+    if let Some(editor) = &tui.data.ui_state.github_session_editor {
+        render_github_session_editor_popup(frame, editor);
+    }
+
+    // Render worklog filter popup if active
+    if let Some(editor) = &tui.data.ui_state.worklog_filter_editor {
+        render_worklog_filter_popup(frame, editor);
+    }
+
+    // Render quick worklog popup (Issues tab) if active
+    if let Some(editor) = &tui.data.ui_state.quick_worklog {
+        render_quick_worklog_popup(frame, editor, &tui.data);
+    }
+
+    // Render toast notifications over any popup so confirmations stay visible
+    render_toasts(frame, &tui.toasts);
+
     // Render about popup if active (should be on top of everything)
     if tui.show_about_popup {
         render_about_popup(frame, &tui.about_image, &tui.image_picker);
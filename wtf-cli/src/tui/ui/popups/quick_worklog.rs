@@ -0,0 +1,119 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::tui::data::TuiData;
+use crate::tui::quick_worklog::QuickWorklog;
+use crate::tui::theme::theme;
+use crate::tui::ui_helpers::*;
+
+pub(in crate::tui) fn render_quick_worklog_popup(
+    frame: &mut Frame,
+    editor: &QuickWorklog,
+    data: &TuiData,
+) {
+    let area = frame.area();
+    let popup_width = 64.min(area.width.saturating_sub(4));
+    let popup_height = 14.min(area.height.saturating_sub(4));
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    frame.render_widget(Clear, popup_area);
+
+    let summary = data
+        .issues_by_key
+        .get(&editor.issue_key)
+        .map(|i| i.summary.as_str())
+        .unwrap_or("");
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(
+                format!("⏱  Log time on {} ", editor.issue_key),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                truncate_string(summary, (popup_width as usize).saturating_sub(28)),
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+        Line::from(""),
+    ];
+
+    // The other issues ←/→ switch to
+    if editor.targets.len() > 1 {
+        let mut spans = vec![Span::styled("★ ", Style::default().fg(Color::Yellow))];
+        for key in &editor.targets {
+            let style = if *key == editor.issue_key {
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            spans.push(Span::styled(key.as_str(), style));
+            spans.push(Span::raw(" "));
+        }
+        lines.push(Line::from(spans));
+        lines.push(Line::from(""));
+    }
+
+    for (idx, (label, value)) in editor.field_labels().iter().zip(&editor.fields).enumerate() {
+        let is_selected = idx == editor.selected_field;
+        let (cursor, value_display, value_style) = if is_selected {
+            (
+                theme().selector,
+                format!("{}█", value),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            (
+                theme().unselected_selector,
+                value.clone(),
+                Style::default().fg(Color::White),
+            )
+        };
+        lines.push(Line::from(vec![
+            Span::styled(cursor, Style::default().fg(Color::Yellow)),
+            Span::raw(" "),
+            Span::styled(format!("{:<10}", label), Style::default().fg(Color::Gray)),
+            Span::styled(value_display, value_style),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Start is local time; the worklog is created unstaged",
+        Style::default().fg(Color::DarkGray),
+    )));
+    if let Some(error) = &editor.error {
+        lines.push(Line::from(Span::styled(
+            format!("❌ {}", error),
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(if editor.targets.len() > 1 {
+            " [↑↓] Field | [←→] Issue | [Enter] Create | [Esc] Cancel "
+        } else {
+            " [↑↓] Field | [Enter] Create | [Esc] Cancel "
+        })
+        .title_alignment(Alignment::Center)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(theme().bg_primary));
+
+    frame.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
@@ -0,0 +1,59 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::tui::theme::theme;
+use crate::tui::toast::{ToastKind, ToastQueue};
+
+const TOAST_WIDTH: u16 = 52;
+const TOAST_HEIGHT: u16 = 3;
+/// Toasts start below the tab bar
+const TOP_OFFSET: u16 = 3;
+
+/// Stack the toasts in the top-right corner, newest at the bottom
+pub(in crate::tui) fn render_toasts(frame: &mut Frame, toasts: &ToastQueue) {
+    let area = frame.area();
+    let width = TOAST_WIDTH.min(area.width.saturating_sub(2));
+    let x = area.width.saturating_sub(width + 1);
+    let mut y = TOP_OFFSET;
+
+    for toast in toasts.iter() {
+        if y + TOAST_HEIGHT > area.height {
+            break;
+        }
+        let (icon, color) = match toast.kind {
+            ToastKind::Info => ("ℹ", theme().info),
+            ToastKind::Success => ("✔", theme().success),
+            ToastKind::Error => ("✖", theme().error),
+        };
+        let toast_area = Rect {
+            x,
+            y,
+            width,
+            height: TOAST_HEIGHT,
+        };
+
+        frame.render_widget(Clear, toast_area);
+        let line = Line::from(vec![
+            Span::styled(
+                format!("{} ", icon),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                toast.message.clone(),
+                Style::default().fg(theme().fg_primary),
+            ),
+        ]);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(color))
+            .style(Style::default().bg(theme().bg_primary));
+        frame.render_widget(Paragraph::new(line).block(block), toast_area);
+
+        y += TOAST_HEIGHT;
+    }
+}
@@ -7,8 +7,15 @@ use ratatui::{
 };
 
 use crate::tui::data::{DayActivity, TuiData};
+use crate::tui::helpers::filter_issues;
 use crate::tui::theme::theme;
-use crate::tui::{GapFillState, WizardPreLaunchPrompt, WizardState, WizardStep};
+use crate::tui::{
+    GapFillState, WizardPreLaunchPrompt, WizardState, WizardStep, WIZARD_OPTIONAL_STEPS,
+};
+use wtf_lib::duration::format_hours;
+
+/// Validation findings listed on the review step, the rest being counted
+const MAX_REVIEW_VIOLATIONS: usize = 8;
 
 pub(in crate::tui) fn render_wizard(
     frame: &mut Frame,
@@ -40,9 +47,10 @@ pub(in crate::tui) fn render_wizard(
         5
     };
     let activity_width = popup_width / 2;
-    let activity_count = data
-        .sprint_activities
-        .get(&wizard.sprint_id)
+    let activity_count = wizard
+        .period
+        .sprint_id
+        .and_then(|id| data.sprint_activities.get(&id))
         .map(|a| a.len())
         .unwrap_or(0) as u16;
     // Two-column layout: ceil(days / 2) rows, no border overhead
@@ -87,10 +95,10 @@ pub(in crate::tui) fn render_wizard(
     }
 
     lines.push(Line::from(""));
-    lines.push(Line::from(vec![Span::raw(format!(
-        "Sprint: {}",
-        wizard.sprint_name
-    ))]));
+    lines.push(Line::from(vec![Span::raw(match wizard.period.sprint_id {
+        Some(_) => format!("Sprint: {}", wizard.period.name),
+        None => format!("Period: {}", wizard.period),
+    })]));
     lines.push(Line::from(""));
 
     // Vertical progress indicator with step names
@@ -118,6 +126,8 @@ pub(in crate::tui) fn render_wizard(
             let spinner_chars = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
             let spinner = spinner_chars[wizard.spinner_frame % spinner_chars.len()];
             (spinner, Color::Yellow, String::new())
+        } else if wizard.skipped_steps.contains(internal_num) {
+            (" ", Color::DarkGray, " (off)".to_string())
         } else {
             (" ", Color::DarkGray, String::new())
         };
@@ -135,6 +145,58 @@ pub(in crate::tui) fn render_wizard(
 
     // Current step content
     match &wizard.current_step {
+        WizardStep::Setup { selected_index } => {
+            lines.push(Line::from("⚙️  Choose the steps to run:"));
+            lines.push(Line::from(""));
+            for (idx, (step, name)) in WIZARD_OPTIONAL_STEPS.iter().enumerate() {
+                let enabled = !wizard.skipped_steps.contains(step);
+                let style = if idx == *selected_index {
+                    Style::default().bg(Color::DarkGray).fg(Color::White)
+                } else if enabled {
+                    Style::default()
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+                let checkbox = if enabled { "[x]" } else { "[ ]" };
+                lines.push(Line::from(vec![Span::styled(
+                    format!("  {} {}. {}", checkbox, step, name),
+                    style,
+                )]));
+            }
+            lines.push(Line::from(vec![Span::styled(
+                "  Review and push always run",
+                Style::default().fg(Color::DarkGray),
+            )]));
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::styled(
+                    "[↑/↓]",
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(" Navigate  "),
+                Span::styled(
+                    "[Space]",
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(" Toggle  "),
+                Span::styled(
+                    "[Enter]",
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(" Start  "),
+                Span::styled(
+                    "[Esc]",
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(" Cancel"),
+            ]));
+        }
         WizardStep::Syncing => {
             lines.push(Line::from("⏳ Syncing data and auto-linking meetings..."));
             lines.push(Line::from(""));
@@ -242,6 +304,13 @@ pub(in crate::tui) fn render_wizard(
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::raw(" Skip  "),
+                Span::styled(
+                    "[b]",
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(" Back  "),
                 Span::styled(
                     "[Esc]",
                     Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
@@ -279,6 +348,13 @@ pub(in crate::tui) fn render_wizard(
                             .add_modifier(Modifier::BOLD),
                     ),
                     Span::raw(" Process sessions  "),
+                    Span::styled(
+                        "[b]",
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(" Back  "),
                     Span::styled(
                         "[Esc]",
                         Style::default()
@@ -312,7 +388,7 @@ pub(in crate::tui) fn render_wizard(
                     ]));
                     lines.push(Line::from(vec![
                         Span::styled("Duration: ", Style::default().fg(Color::Cyan)),
-                        Span::raw(format!("{:.1}h", session.duration_seconds as f64 / 3600.0)),
+                        Span::raw(format_hours(session.duration_seconds as f64 / 3600.0)),
                     ]));
                     lines.push(Line::from(vec![
                         Span::styled("Issues: ", Style::default().fg(Color::Cyan)),
@@ -329,19 +405,8 @@ pub(in crate::tui) fn render_wizard(
                 lines.push(Line::from(""));
 
                 // Filter issues based on search query
-                let filtered_issues: Vec<_> = state
-                    .all_issues
-                    .iter()
-                    .filter(|issue| {
-                        if state.search_query.is_empty() {
-                            true
-                        } else {
-                            let query_lower = state.search_query.to_lowercase();
-                            issue.key.to_lowercase().contains(&query_lower)
-                                || issue.summary.to_lowercase().contains(&query_lower)
-                        }
-                    })
-                    .collect();
+                let filtered_issues: Vec<_> =
+                    filter_issues(&state.all_issues, &state.search_query, &state.aliases);
 
                 // Search box
                 lines.push(Line::from(vec![
@@ -396,6 +461,13 @@ pub(in crate::tui) fn render_wizard(
                             .add_modifier(Modifier::BOLD),
                     ),
                     Span::raw(" Select  "),
+                    Span::styled(
+                        "[b]",
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(" Back (empty search)  "),
                     Span::styled(
                         "[Esc]",
                         Style::default()
@@ -408,7 +480,7 @@ pub(in crate::tui) fn render_wizard(
                 lines.push(Line::from("🔧 Preparing gap fill..."));
             }
         }
-        WizardStep::ReviewingWorklogs { .. } => {
+        WizardStep::ReviewingWorklogs { violations, .. } => {
             lines.push(Line::from("📋 Review worklogs before pushing:"));
             lines.push(Line::from(""));
 
@@ -443,6 +515,36 @@ pub(in crate::tui) fn render_wizard(
                 wizard.summary.total_hours
             )));
             lines.push(Line::from(""));
+            if !violations.is_empty() {
+                lines.push(Line::from("Validation:"));
+                for violation in violations.iter().take(MAX_REVIEW_VIOLATIONS) {
+                    let (icon, color) = if violation.is_blocking() {
+                        ("⛔", Color::Red)
+                    } else {
+                        ("⚠️ ", Color::Yellow)
+                    };
+                    lines.push(Line::from(Span::styled(
+                        format!(
+                            "  {} {} {}: {}",
+                            icon, violation.date, violation.issue, violation.message
+                        ),
+                        Style::default().fg(color),
+                    )));
+                }
+                if violations.len() > MAX_REVIEW_VIOLATIONS {
+                    lines.push(Line::from(format!(
+                        "  … and {} more",
+                        violations.len() - MAX_REVIEW_VIOLATIONS
+                    )));
+                }
+                if violations.iter().any(|v| v.is_blocking()) {
+                    lines.push(Line::from(Span::styled(
+                        "  ⛔ marked worklogs won't be staged or pushed",
+                        Style::default().fg(Color::Red),
+                    )));
+                }
+                lines.push(Line::from(""));
+            }
             lines.push(Line::from(vec![
                 Span::styled(
                     "[P]",
@@ -451,6 +553,13 @@ pub(in crate::tui) fn render_wizard(
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::raw(" Push to Jira  "),
+                Span::styled(
+                    "[b]",
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(" Back  "),
                 Span::styled(
                     "[Esc]",
                     Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
@@ -558,7 +667,7 @@ pub(in crate::tui) fn render_wizard(
     };
     frame.render_widget(Paragraph::new(sep_lines), sep_area);
 
-    render_activity_panel(frame, activity_area, wizard.sprint_id, data);
+    render_activity_panel(frame, activity_area, wizard.period.sprint_id, data);
 
     // Render progress bar for Pushing step
     if matches!(wizard.current_step, WizardStep::Pushing) && wizard.push_total > 0 {
@@ -588,16 +697,16 @@ pub(in crate::tui) fn render_wizard(
     }
 }
 
-fn render_activity_panel(frame: &mut Frame, area: Rect, sprint_id: usize, data: &TuiData) {
+fn render_activity_panel(frame: &mut Frame, area: Rect, sprint_id: Option<usize>, data: &TuiData) {
     // Paint background to overwrite underlying text
     frame.render_widget(
         Block::default().style(Style::default().bg(theme().bg_primary)),
         area,
     );
 
-    let activities = data
-        .sprint_activities
-        .get(&sprint_id)
+    // Daily activity is only tracked per sprint
+    let activities = sprint_id
+        .and_then(|id| data.sprint_activities.get(&id))
         .cloned()
         .unwrap_or_default();
 
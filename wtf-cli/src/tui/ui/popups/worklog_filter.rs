@@ -0,0 +1,129 @@
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::tui::theme::theme;
+use crate::tui::worklog_filter::{source_label, status_label, WorklogFilterEditor, FILTER_FIELDS};
+
+pub(in crate::tui) fn render_worklog_filter_popup(frame: &mut Frame, editor: &WorklogFilterEditor) {
+    let area = frame.area();
+    let popup_width = 60.min(area.width.saturating_sub(4));
+    let popup_height = 16.min(area.height.saturating_sub(4));
+    let popup_area = Rect {
+        x: (area.width.saturating_sub(popup_width)) / 2,
+        y: (area.height.saturating_sub(popup_height)) / 2,
+        width: popup_width,
+        height: popup_height,
+    };
+
+    frame.render_widget(Clear, popup_area);
+
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            "🔎 Filter Worklogs",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(""),
+    ];
+
+    for (idx, name) in FILTER_FIELDS.iter().enumerate() {
+        let is_selected = idx == editor.selected_field;
+        let (value, is_text) = match idx {
+            0 => (editor.issue.clone(), true),
+            1 => (editor.from.clone(), true),
+            2 => (editor.to.clone(), true),
+            3 => (status_label(editor.status.as_ref()).to_string(), false),
+            _ => (source_label(editor.source).to_string(), false),
+        };
+        let cursor = if is_selected {
+            theme().selector
+        } else {
+            theme().unselected_selector
+        };
+        let value_display = match (is_selected, is_text) {
+            (true, true) => format!("{}█", value),
+            (true, false) => format!("◀ {} ▶", value),
+            (false, _) if value.is_empty() => "-".to_string(),
+            (false, _) => value,
+        };
+        let value_style = if is_selected {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+
+        lines.push(Line::from(vec![
+            Span::styled(cursor, Style::default().fg(Color::Yellow)),
+            Span::raw(" "),
+            Span::styled(format!("{:<8}", name), Style::default().fg(Color::Gray)),
+            Span::styled(value_display, value_style),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![Span::styled(
+        "Dates use YYYY-MM-DD, empty fields match everything",
+        Style::default().fg(Color::DarkGray),
+    )]));
+    if let Some(error) = &editor.error {
+        lines.push(Line::from(vec![Span::styled(
+            format!("❌ {}", error),
+            Style::default().fg(Color::Red),
+        )]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(
+            "[↑/↓]",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" Field  "),
+        Span::styled(
+            "[←/→]",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" Change  "),
+        Span::styled(
+            "[Del]",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" Clear  "),
+        Span::styled(
+            "[Enter]",
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" Apply  "),
+        Span::styled(
+            "[Esc]",
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" Cancel"),
+    ]));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(theme().bg_primary));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .alignment(Alignment::Left);
+
+    frame.render_widget(paragraph, popup_area);
+}
@@ -50,6 +50,7 @@ impl TabController for AchievementsTab {
         render(frame, area, data);
     }
 
+    #[allow(clippy::collapsible_match)]
     fn handle_key(&self, tui: &mut Tui, key: KeyEvent) {
         let shown_count =
             shown_achievements_count(&tui.data.unlocked_achievements, &tui.data.tiered_progress);
@@ -0,0 +1,243 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::tui::dashboard::PeriodStats;
+use crate::tui::data::TuiData;
+use crate::tui::tab_controller::TabController;
+use crate::tui::theme::theme;
+use crate::tui::ui_helpers::*;
+use crate::tui::Tui;
+
+/// Issues listed per period before the rest is summed up
+const TOP_ISSUES: usize = 6;
+/// Unlogged days listed per period before collapsing into "+N more"
+const MAX_UNLOGGED_DAYS: usize = 8;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(in crate::tui) struct DashboardTab;
+
+impl TabController for DashboardTab {
+    fn render(&self, frame: &mut Frame, area: &Rect, data: &TuiData) {
+        render_dashboard_tab(frame, area, data);
+    }
+
+    fn handle_key(&self, tui: &mut Tui, key: KeyEvent) {
+        if let KeyCode::Char('r') | KeyCode::Char('R') = key.code {
+            tui.refresh_data();
+        }
+    }
+}
+
+fn render_dashboard_tab(frame: &mut Frame, area: &Rect, data: &TuiData) {
+    let stats = &data.dashboard;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(*area);
+
+    let streak_line = Line::from(vec![
+        Span::styled("🔥 Current streak: ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            format!("{} workdays", stats.current_streak),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("   🏆 Longest streak: ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            format!("{} workdays", stats.longest_streak),
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]);
+    let title = Line::from(
+        [
+            vec![Span::raw("📊 Dashboard | ")],
+            build_shortcut_help(&[("R", "efresh")]),
+        ]
+        .concat(),
+    );
+    frame.render_widget(
+        Paragraph::new(streak_line).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(Style::default().fg(theme().border))
+                .style(Style::default().bg(theme().bg_primary)),
+        ),
+        chunks[0],
+    );
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[1]);
+
+    render_period(frame, &columns[0], Some(&stats.week), data);
+    render_period(frame, &columns[1], stats.sprint.as_ref(), data);
+}
+
+fn render_period(frame: &mut Frame, area: &Rect, period: Option<&PeriodStats>, data: &TuiData) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().border))
+        .style(Style::default().bg(theme().bg_primary));
+
+    let Some(period) = period else {
+        let paragraph = Paragraph::new(Line::from(Span::styled(
+            "No followed sprint has started yet",
+            Style::default().fg(Color::DarkGray),
+        )))
+        .block(block.title("🏃 Sprint"));
+        frame.render_widget(paragraph, *area);
+        return;
+    };
+
+    let width = area.width.saturating_sub(4) as usize;
+    let block = block.title(format!(
+        "📅 {} ({} → {})",
+        period.label,
+        period.start.format("%d/%m"),
+        period.end.format("%d/%m")
+    ));
+    let section = |title: &str| {
+        Line::from(Span::styled(
+            title.to_string(),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ))
+    };
+
+    let mut lines = vec![target_line(period), Line::from(""), section("🎫 By issue")];
+
+    if period.by_issue.is_empty() {
+        lines.push(muted("  Nothing logged"));
+    }
+    for (issue, hours) in period.by_issue.iter().take(TOP_ISSUES) {
+        let summary = data
+            .issues_by_key
+            .get(issue)
+            .map(|i| i.summary.as_str())
+            .unwrap_or("");
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {:>5.1}h ", hours),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(format!("{:<10} ", issue), Style::default().fg(Color::Green)),
+            Span::styled(
+                truncate_string(summary, width.saturating_sub(20)),
+                Style::default().fg(Color::White),
+            ),
+        ]));
+    }
+    if period.by_issue.len() > TOP_ISSUES {
+        let rest: f64 = period.by_issue[TOP_ISSUES..].iter().map(|(_, h)| h).sum();
+        lines.push(muted(&format!(
+            "  {:>5.1}h {} other issues",
+            rest,
+            period.by_issue.len() - TOP_ISSUES
+        )));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(section("🧭 By source"));
+    for (source, hours) in &period.by_source {
+        let share = if period.logged_hours > 0.0 {
+            hours / period.logged_hours * 100.0
+        } else {
+            0.0
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {:>5.1}h ", hours),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(
+                format!("{:<10}", source.label()),
+                Style::default().fg(Color::White),
+            ),
+            Span::styled(format!("{:>4.0}%", share), Style::default().fg(Color::Gray)),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(section(&format!(
+        "🕳  Unlogged days ({})",
+        period.unlogged_days.len()
+    )));
+    if period.unlogged_days.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  ✓ Every past workday has time logged",
+            Style::default().fg(Color::Green),
+        )));
+    }
+    for day in period.unlogged_days.iter().take(MAX_UNLOGGED_DAYS) {
+        lines.push(Line::from(Span::styled(
+            format!("  {}", day.format("%a %Y-%m-%d")),
+            Style::default().fg(Color::Red),
+        )));
+    }
+    if period.unlogged_days.len() > MAX_UNLOGGED_DAYS {
+        lines.push(muted(&format!(
+            "  +{} more",
+            period.unlogged_days.len() - MAX_UNLOGGED_DAYS
+        )));
+    }
+
+    frame.render_widget(Paragraph::new(lines).block(block), *area);
+}
+
+fn target_line(period: &PeriodStats) -> Line<'static> {
+    let percentage = if period.target_hours > 0.0 {
+        (period.logged_hours / period.target_hours * 100.0).round() as u16
+    } else {
+        0
+    };
+    let filled_blocks = ((percentage.min(100) as f64 / 10.0).round() as usize).min(10);
+    let color = if percentage >= 80 {
+        Color::Green
+    } else if percentage >= 50 {
+        Color::Yellow
+    } else {
+        Color::Red
+    };
+
+    Line::from(vec![
+        Span::styled("⏱  ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            format!(
+                "{}{}",
+                "█".repeat(filled_blocks),
+                "░".repeat(10 - filled_blocks)
+            ),
+            Style::default().fg(color),
+        ),
+        Span::styled(
+            format!(" {:>3}% ", percentage),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(
+                "{:.1}h of {:.1}h target",
+                period.logged_hours, period.target_hours
+            ),
+            Style::default().fg(Color::White),
+        ),
+    ])
+}
+
+fn muted(text: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        text.to_string(),
+        Style::default().fg(Color::DarkGray),
+    ))
+}
@@ -1,33 +1,120 @@
-use chrono::Timelike;
+use chrono::{Local, Timelike};
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
-use crate::tui::data::GitHubIssueValidation;
 use crate::tui::data::TuiData;
+use crate::tui::data::{GitHubIssueValidation, GitHubSessionDrillDown};
 use crate::tui::helpers;
+use crate::tui::sorting::SortKey;
 use crate::tui::tab_controller::TabController;
 use crate::tui::theme::theme;
 use crate::tui::ui_helpers::*;
 use crate::tui::Tui;
+use wtf_lib::duration::format_hours;
+use wtf_lib::models::data::GitHubSession;
 
 #[derive(Debug, Clone, Copy, Default)]
 pub(in crate::tui) struct GitHubTab;
 
+impl SortKey for GitHubSession {
+    fn sort_date(&self) -> chrono::DateTime<chrono::Utc> {
+        self.start_time
+    }
+
+    fn sort_issue(&self) -> Option<&str> {
+        self.jira_issues
+            .split(',')
+            .map(str::trim)
+            .find(|issue| !issue.is_empty())
+    }
+
+    fn sort_duration(&self) -> i64 {
+        self.duration_seconds
+    }
+}
+
+/// Sessions in display order; list, details and worklog creation all index into this
+pub(in crate::tui) fn visible_github_sessions(data: &TuiData) -> Vec<GitHubSession> {
+    let mut sessions = data.github_sessions.clone();
+    // Status order: worklog missing, no issue detected, already logged
+    data.ui_state.github_sort.sort(&mut sessions, |session| {
+        let issues = session.get_jira_issues();
+        if issues.is_empty() {
+            1
+        } else if session_has_worklog(data, session, &issues) {
+            2
+        } else {
+            0
+        }
+    });
+    sessions
+}
+
+fn session_has_worklog(data: &TuiData, session: &GitHubSession, issues: &[String]) -> bool {
+    data.all_worklogs
+        .iter()
+        .any(|w| w.started.date_naive() == session.date && issues.contains(&w.issue_id))
+}
+
 impl TabController for GitHubTab {
     fn render(&self, frame: &mut Frame, area: &Rect, data: &TuiData) {
         render_github_tab(frame, area, data);
     }
 
     fn handle_key(&self, tui: &mut Tui, key: KeyEvent) {
+        if tui.data.ui_state.github_session_editor.is_some() {
+            tui.handle_session_editor_key(key);
+            return;
+        }
+
+        // A refresh may have rebuilt the session under a new id: drop the stale popup
+        if let Some(drill_down) = &tui.data.ui_state.github_drill_down {
+            if !tui
+                .data
+                .github_sessions
+                .iter()
+                .any(|s| s.id == drill_down.session_id)
+            {
+                tui.data.ui_state.github_drill_down = None;
+            }
+        }
+
+        if let Some(drill_down) = &mut tui.data.ui_state.github_drill_down {
+            match key.code {
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                    tui.data.ui_state.github_drill_down = None;
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    drill_down.scroll_offset = drill_down.scroll_offset.saturating_sub(1);
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    drill_down.scroll_offset = drill_down.scroll_offset.saturating_add(1);
+                }
+                KeyCode::PageUp => {
+                    drill_down.scroll_offset = drill_down.scroll_offset.saturating_sub(10);
+                }
+                KeyCode::PageDown => {
+                    drill_down.scroll_offset = drill_down.scroll_offset.saturating_add(10);
+                }
+                _ => {}
+            }
+            return;
+        }
+
         let sessions = &tui.data.github_sessions;
 
         match key.code {
+            KeyCode::Char('o') | KeyCode::Char('O') => {
+                tui.data.ui_state.github_sort = tui.data.ui_state.github_sort.next();
+                tui.data.ui_state.selected_github_session_index = 0;
+                return;
+            }
             KeyCode::Char('u') | KeyCode::Char('U') => {
                 tui.handle_github_sync();
                 return;
@@ -38,6 +125,18 @@ impl TabController for GitHubTab {
                 }
                 return;
             }
+            KeyCode::Char('s') | KeyCode::Char('S') => {
+                tui.open_session_editor(false);
+                return;
+            }
+            KeyCode::Char('t') | KeyCode::Char('T') => {
+                tui.open_session_editor(true);
+                return;
+            }
+            KeyCode::Char('m') | KeyCode::Char('M') => {
+                tui.merge_selected_github_session();
+                return;
+            }
             _ => {}
         }
 
@@ -59,6 +158,16 @@ impl TabController for GitHubTab {
         }
 
         match key.code {
+            KeyCode::Enter => {
+                let sessions = visible_github_sessions(&tui.data);
+                if let Some(session) = sessions.get(tui.data.ui_state.selected_github_session_index)
+                {
+                    tui.data.ui_state.github_drill_down = Some(GitHubSessionDrillDown {
+                        session_id: session.id.clone(),
+                        scroll_offset: 0,
+                    });
+                }
+            }
             KeyCode::PageUp => {
                 tui.data.ui_state.selected_github_session_index = tui
                     .data
@@ -78,112 +187,179 @@ impl TabController for GitHubTab {
 /// GitHub tab - list and details extracted from ui.rs
 pub(in crate::tui) fn render_github_tab(frame: &mut Frame, area: &Rect, data: &TuiData) {
     let selected_index = data.ui_state.selected_github_session_index;
+    let sessions = visible_github_sessions(data);
+
+    // Sessions on top, activity heatmap at the bottom
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),    // Sessions list and details
+            Constraint::Length(7), // Heatmap (5 rows + borders)
+        ])
+        .split(*area);
 
     render_list_detail_layout(
         frame,
-        area,
-        |f, a| render_github_sessions_list(f, a, data, selected_index),
-        |f, a| render_github_session_details(f, a, data, selected_index),
+        &rows[0],
+        data.ui_state.split_layout,
+        |f, a| render_github_sessions_list(f, a, data, &sessions, selected_index),
+        |f, a| render_github_session_details(f, a, data, &sessions, selected_index),
     );
+    render_github_heatmap(frame, &rows[1], data);
+}
+
+/// Session hours per weekday over the last year; days with sessions but no worklog in red
+fn render_github_heatmap(frame: &mut Frame, area: &Rect, data: &TuiData) {
+    let unlogged = data
+        .github_heatmap
+        .iter()
+        .filter(|day| day.session_hours > 0.0 && !day.logged)
+        .count();
+    let block = Block::default()
+        .title(Line::from(vec![
+            Span::raw("🔥 GitHub Activity (Last Year) | "),
+            Span::styled("⣿", Style::default().fg(Color::Green)),
+            Span::raw(" logged "),
+            Span::styled("⣿", Style::default().fg(Color::Red)),
+            Span::raw(format!(" no worklog ({} days)", unlogged)),
+        ]))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().border))
+        .style(Style::default().bg(theme().bg_primary));
+
+    let inner = block.inner(*area);
+    frame.render_widget(block, *area);
+
+    let days: Vec<_> = data
+        .github_heatmap
+        .iter()
+        .map(|day| (day.date, (day.session_hours, day.logged)))
+        .collect();
+    let weekday_labels = ["Mon", "Tue", "Wed", "Thu", "Fri"];
+
+    let lines: Vec<Line> = weekday_wall(&days)
+        .iter()
+        .zip(weekday_labels)
+        .map(|(row, label)| {
+            let mut spans = vec![Span::styled(
+                format!("{} ", label),
+                Style::default().fg(Color::DarkGray),
+            )];
+            spans.extend(row.iter().map(|cell| match *cell {
+                WallCell::YearSeparator => Span::styled("│", Style::default().fg(theme().border)),
+                WallCell::Blank => Span::raw(hours_to_braille(0.0, data.daily_hours_limit)),
+                WallCell::Day((hours, logged)) => Span::styled(
+                    hours_to_braille(hours, data.daily_hours_limit),
+                    Style::default().fg(if logged { Color::Green } else { Color::Red }),
+                ),
+            }));
+            Line::from(spans)
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines), inner);
 }
 
 fn render_github_sessions_list(
     frame: &mut Frame,
     area: &Rect,
     data: &TuiData,
+    sessions: &[GitHubSession],
     selected_index: usize,
 ) {
     use chrono::Timelike;
-    use std::collections::BTreeMap;
 
-    let sessions = &data.github_sessions;
-
-    // Group sessions by date
-    let mut sessions_by_date: BTreeMap<String, Vec<&wtf_lib::models::data::GitHubSession>> =
-        BTreeMap::new();
-    for session in sessions {
-        let date_str = session.date.to_string();
-        sessions_by_date.entry(date_str).or_default().push(session);
-    }
+    // Date headers only make sense while the list is in date order
+    let group_by_date = data.ui_state.github_sort.is_chronological();
 
     // Build the content
     let mut lines = Vec::new();
-    let mut session_index = 0; // Tracks actual session number
     let mut selected_line_index = 0;
+    let mut current_date = None;
 
-    for (date, day_sessions) in sessions_by_date.iter().rev() {
-        // Calculate total hours for the day
-        let total_hours: f64 = day_sessions.iter().map(|s| s.duration_hours()).sum();
-
-        // Date header
-        lines.push(Line::from(vec![Span::styled(
-            format!("📅 {} ({:.1}h)", date, total_hours),
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        )]));
-
-        // Sessions for this day
-        for session in day_sessions {
-            let is_selected = session_index == selected_index;
-            if is_selected {
-                selected_line_index = lines.len();
+    for (session_index, session) in sessions.iter().enumerate() {
+        if group_by_date && current_date != Some(session.date) {
+            if current_date.is_some() {
+                // Empty line between dates
+                lines.push(Line::from(""));
             }
-
-            let start_time = format!(
-                "{:02}:{:02}",
-                session.start_time.hour(),
-                session.start_time.minute()
-            );
-            let end_time = format!(
-                "{:02}:{:02}",
-                session.end_time.hour(),
-                session.end_time.minute()
-            );
-            let duration = session.duration_hours();
-
-            // Extract short repo name (without org/)
-            let repo_parts: Vec<&str> = session.repo.split('/').collect();
-            let repo_short = repo_parts.last().unwrap_or(&"unknown");
-
-            let cursor = if is_selected {
-                theme().selector
-            } else {
-                theme().unselected_selector
-            };
-            let style = if is_selected {
+            current_date = Some(session.date);
+
+            // Calculate total hours for the day
+            let total_hours: f64 = sessions
+                .iter()
+                .filter(|s| s.date == session.date)
+                .map(|s| s.duration_hours())
+                .sum();
+
+            // Date header
+            lines.push(Line::from(vec![Span::styled(
+                format!("📅 {} ({:.1}h)", session.date, total_hours),
                 Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD)
-            } else {
-                Style::default().fg(Color::White)
-            };
-
-            // Build Jira issues string
-            let issues_str = if session.jira_issues.is_empty() {
-                String::new()
-            } else {
-                format!(" [{}]", session.jira_issues)
-            };
-
-            // Single line: time range, duration, repo, issues
-            lines.push(Line::from(vec![
-                Span::styled(cursor, Style::default().fg(Color::Yellow)),
-                Span::raw(" "),
-                Span::styled(
-                    format!(
-                        "{}-{} {:.1}h {}{}",
-                        start_time, end_time, duration, repo_short, issues_str
-                    ),
-                    style,
-                ),
-            ]));
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )]));
+        }
 
-            session_index += 1;
+        let is_selected = session_index == selected_index;
+        if is_selected {
+            selected_line_index = lines.len();
         }
 
-        // Empty line between dates
-        lines.push(Line::from(""));
+        let start_time = format!(
+            "{:02}:{:02}",
+            session.start_time.with_timezone(&Local).hour(),
+            session.start_time.with_timezone(&Local).minute()
+        );
+        let end_time = format!(
+            "{:02}:{:02}",
+            session.end_time.with_timezone(&Local).hour(),
+            session.end_time.with_timezone(&Local).minute()
+        );
+        let duration = session.duration_hours();
+
+        // Extract short repo name (without org/)
+        let repo_parts: Vec<&str> = session.repo.split('/').collect();
+        let repo_short = repo_parts.last().unwrap_or(&"unknown");
+
+        let cursor = if is_selected {
+            theme().selector
+        } else {
+            theme().unselected_selector
+        };
+        let style = if is_selected {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+
+        // Build Jira issues string
+        let issues_str = if session.jira_issues.is_empty() {
+            String::new()
+        } else {
+            format!(" [{}]", session.jira_issues)
+        };
+
+        let date_prefix = if group_by_date {
+            String::new()
+        } else {
+            format!("{} ", session.date)
+        };
+
+        // Single line: time range, duration, repo, issues
+        lines.push(Line::from(vec![
+            Span::styled(cursor, Style::default().fg(Color::Yellow)),
+            Span::raw(" "),
+            Span::styled(
+                format!(
+                    "{}{}-{} {:.1}h {}{}",
+                    date_prefix, start_time, end_time, duration, repo_short, issues_str
+                ),
+                style,
+            ),
+        ]));
     }
 
     if lines.is_empty() {
@@ -207,11 +383,21 @@ fn render_github_sessions_list(
     };
 
     // Build help text
-    let shortcuts = build_shortcut_help(&[("C", " Create Worklog"), ("↑↓", " Navigate")]);
+    let shortcuts = build_shortcut_help(&[
+        ("C", " Create Worklog"),
+        ("Enter", " Events"),
+        ("S", "plit"),
+        ("T", "rim"),
+        ("M", "erge"),
+        ("O", "rder"),
+        ("↑↓", " Navigate"),
+    ]);
     let mut title_spans = vec![
         Span::raw("💻 GitHub Sessions ("),
         Span::raw(sessions.len().to_string()),
-        Span::raw(") | "),
+        Span::raw(")"),
+        Span::raw(format!(" [Sort: {}]", data.ui_state.github_sort.label())),
+        Span::raw(" | "),
     ];
     title_spans.extend(shortcuts);
 
@@ -232,12 +418,11 @@ fn render_github_session_details(
     frame: &mut Frame,
     area: &Rect,
     data: &TuiData,
+    sessions: &[GitHubSession],
     selected_index: usize,
 ) {
     use chrono::Timelike;
 
-    let sessions = &data.github_sessions;
-
     if sessions.is_empty() {
         let paragraph = Paragraph::new("No session selected")
             .block(
@@ -251,41 +436,33 @@ fn render_github_session_details(
         return;
     }
 
-    // Get sessions in reverse chronological order
-    let mut sorted_sessions: Vec<_> = sessions.iter().collect();
-    sorted_sessions.sort_by(|a, b| b.start_time.cmp(&a.start_time));
-
-    let session = sorted_sessions
-        .get(selected_index)
-        .unwrap_or(&sorted_sessions[0]);
+    let session = sessions.get(selected_index).unwrap_or(&sessions[0]);
     let mut activity_events: Vec<_> = session
         .get_event_ids()
         .into_iter()
         .filter_map(|event_id| data.github_events_by_id.get(&event_id))
         .collect();
-    activity_events.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+    activity_events.sort_by_key(|a| a.timestamp);
     let activity_groups = group_activity_events(&activity_events);
     let session_repos = collect_session_repos(session, &activity_events);
 
-    let mut lines = Vec::new();
-
-    // Session info
-    lines.push(Line::from(vec![Span::styled(
-        "📊 Session Info",
-        Style::default()
-            .fg(Color::Cyan)
-            .add_modifier(Modifier::BOLD),
-    )]));
-    lines.push(Line::from(""));
-
-    // Duration
-    lines.push(Line::from(vec![
-        Span::styled("⏱  Duration: ", Style::default().fg(Color::Gray)),
-        Span::styled(
-            format!("{:.1}h", session.duration_hours()),
-            Style::default().fg(Color::White),
-        ),
-    ]));
+    // Session info, and its duration
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            "📊 Session Info",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("⏱  Duration: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format_hours(session.duration_hours()),
+                Style::default().fg(Color::White),
+            ),
+        ]),
+    ];
 
     // Repo(s)
     lines.push(Line::from(vec![
@@ -299,13 +476,13 @@ fn render_github_session_details(
     // Date and time
     let start_time = format!(
         "{:02}:{:02}",
-        session.start_time.hour(),
-        session.start_time.minute()
+        session.start_time.with_timezone(&Local).hour(),
+        session.start_time.with_timezone(&Local).minute()
     );
     let end_time = format!(
         "{:02}:{:02}",
-        session.end_time.hour(),
-        session.end_time.minute()
+        session.end_time.with_timezone(&Local).hour(),
+        session.end_time.with_timezone(&Local).minute()
     );
     lines.push(Line::from(vec![
         Span::styled("📅 Date: ", Style::default().fg(Color::Gray)),
@@ -509,13 +686,14 @@ fn group_activity_events(events: &[&wtf_lib::models::data::GitHubEvent]) -> Vec<
     groups
 }
 
-fn normalized_activity_name(event_type: &str) -> String {
+pub(in crate::tui) fn normalized_activity_name(event_type: &str) -> String {
     match event_type {
         "PullRequestReviewEvent" | "PullRequestReviewCommentEvent" => "PR review".to_string(),
         "PullRequestEvent" => "Pull request".to_string(),
         "PushEvent" => "Push".to_string(),
         "IssuesEvent" => "Issue".to_string(),
         "IssueCommentEvent" => "Issue comment".to_string(),
+        "CommitCommentEvent" => "Commit comment".to_string(),
         other => other.trim_end_matches("Event").to_string(),
     }
 }
@@ -524,16 +702,16 @@ fn render_activity_line(group: &ActivityGroup) -> Line<'static> {
     let time_label = if group.start_time == group.end_time {
         format!(
             "{:02}:{:02}",
-            group.start_time.hour(),
-            group.start_time.minute()
+            group.start_time.with_timezone(&Local).hour(),
+            group.start_time.with_timezone(&Local).minute()
         )
     } else {
         format!(
             "{:02}:{:02}-{:02}:{:02}",
-            group.start_time.hour(),
-            group.start_time.minute(),
-            group.end_time.hour(),
-            group.end_time.minute()
+            group.start_time.with_timezone(&Local).hour(),
+            group.start_time.with_timezone(&Local).minute(),
+            group.end_time.with_timezone(&Local).hour(),
+            group.end_time.with_timezone(&Local).minute()
         )
     };
 
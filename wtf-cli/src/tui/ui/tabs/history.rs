@@ -14,6 +14,7 @@ use crate::tui::tab_controller::TabController;
 use crate::tui::theme::theme;
 use crate::tui::ui_helpers::*;
 use crate::tui::{RevertConfirmationState, Tui};
+use wtf_lib::duration::format_hours;
 use wtf_lib::models::data::{Sprint, Worklog};
 use wtf_lib::services::worklogs_service::LocalWorklogService;
 
@@ -48,7 +49,14 @@ impl TabController for HistoryTab {
                     return;
                 }
 
+                let type_hours = tui.data.config.confirmations.revert_type_hours;
                 match key.code {
+                    KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter if !type_hours => {
+                        revert_history_id = Some(state.history_id.clone());
+                    }
+                    KeyCode::Char('n') | KeyCode::Char('N') if !type_hours => {
+                        tui.revert_confirmation_state = None;
+                    }
                     KeyCode::Char(c) if c.is_ascii_digit() || c == '.' => {
                         state.user_input.push(c);
                     }
@@ -326,7 +334,7 @@ pub(in crate::tui) fn jira_only_by_sprint(data: &TuiData) -> Vec<(&Sprint, Vec<&
         })
         .collect();
 
-    result.sort_by(|a, b| b.0.start.cmp(&a.0.start));
+    result.sort_by_key(|r| std::cmp::Reverse(r.0.start));
     result
 }
 
@@ -368,6 +376,7 @@ pub(in crate::tui) fn render_history_tab(frame: &mut Frame, area: &Rect, data: &
     render_list_detail_layout(
         frame,
         area,
+        data.ui_state.split_layout,
         |f, a| render_history_list(f, a, data, selected_index, expanded_history_ids),
         |f, a| render_history_details(f, a, data, selected_index, expanded_history_ids),
     );
@@ -510,10 +519,7 @@ fn render_history_list(
                         Style::default().fg(count_color),
                     ),
                     Span::raw(" • "),
-                    Span::styled(
-                        format!("{:.1}h", total_hours),
-                        Style::default().fg(Color::Cyan),
-                    ),
+                    Span::styled(format_hours(total_hours), Style::default().fg(Color::Cyan)),
                 ]));
             }
 
@@ -569,10 +575,7 @@ fn render_history_list(
                     Span::raw("  "),
                     Span::styled(bar, Style::default().fg(Color::Green)),
                     Span::raw("  "),
-                    Span::styled(
-                        format!("{:.1}h", day_hours),
-                        Style::default().fg(Color::Cyan),
-                    ),
+                    Span::styled(format_hours(day_hours), Style::default().fg(Color::Cyan)),
                     Span::raw("  "),
                     Span::styled(
                         format!("({} WL)", day_count),
@@ -612,10 +615,7 @@ fn render_history_list(
                         Style::default().fg(Color::White),
                     ),
                     Span::raw(" • "),
-                    Span::styled(
-                        format!("{:.1}h", total_hours),
-                        Style::default().fg(Color::Cyan),
-                    ),
+                    Span::styled(format_hours(total_hours), Style::default().fg(Color::Cyan)),
                     Span::raw("  "),
                     Span::styled("[C] import", Style::default().fg(Color::DarkGray)),
                 ]));
@@ -633,10 +633,7 @@ fn render_history_list(
                             Span::raw(" "),
                             Span::styled(wl.issue_id.clone(), Style::default().fg(Color::Cyan)),
                             Span::raw(" • "),
-                            Span::styled(
-                                format!("{:.1}h", hours),
-                                Style::default().fg(Color::DarkGray),
-                            ),
+                            Span::styled(format_hours(hours), Style::default().fg(Color::DarkGray)),
                         ]));
                     }
                     if total_count > visible_count {
@@ -976,10 +973,7 @@ fn render_selected_history_item(
         ]),
         Line::from(vec![
             Span::raw("Total: "),
-            Span::styled(
-                format!("{:.1}h", total_hours),
-                Style::default().fg(Color::Cyan),
-            ),
+            Span::styled(format_hours(total_hours), Style::default().fg(Color::Cyan)),
         ]),
     ];
 
@@ -1081,7 +1075,7 @@ fn render_day_details(
             ),
             Span::raw("  "),
             Span::styled(
-                format!("{:<8}", format!("{:.1}h", hours)),
+                format!("{:<8}", format_hours(hours)),
                 Style::default().fg(Color::Yellow),
             ),
             Span::raw("  "),
@@ -0,0 +1,658 @@
+use chrono::{Local, NaiveDate};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+use std::collections::HashMap;
+use wtf_lib::duration::format_hours;
+use wtf_lib::models::data::{Issue, NO_EPIC};
+
+use crate::tui::data::TuiData;
+use crate::tui::helpers;
+use crate::tui::issue_breakdown::{epic_breakdown, issue_breakdown, EpicHours, IssueHours};
+use crate::tui::tab_controller::TabController;
+use crate::tui::theme::theme;
+use crate::tui::ui_helpers::*;
+use crate::tui::Tui;
+
+/// Worklogs listed in the details pane
+const RECENT_WORKLOGS: usize = 5;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(in crate::tui) struct IssuesTab;
+
+impl TabController for IssuesTab {
+    fn render(&self, frame: &mut Frame, area: &Rect, data: &TuiData) {
+        if data.ui_state.issues_by_epic {
+            render_epics_view(frame, area, data);
+        } else {
+            render_issues_tab(frame, area, data);
+        }
+    }
+
+    fn handle_key(&self, tui: &mut Tui, key: KeyEvent) {
+        if tui.data.ui_state.quick_worklog.is_some() {
+            tui.handle_quick_worklog_key(key);
+            return;
+        }
+
+        // Search mode: all input goes to the query, no shortcuts fire
+        if tui.data.ui_state.issue_search_active {
+            let ui_state = &mut tui.data.ui_state;
+            match key.code {
+                KeyCode::Esc => {
+                    ui_state.issue_search_active = false;
+                    ui_state.issue_search_query.clear();
+                }
+                KeyCode::Enter => ui_state.issue_search_active = false,
+                KeyCode::Backspace => {
+                    ui_state.issue_search_query.pop();
+                }
+                KeyCode::Char(c) => ui_state.issue_search_query.push(c),
+                _ => return,
+            }
+            ui_state.selected_issue_index = 0;
+            return;
+        }
+
+        if tui.data.ui_state.issues_by_epic {
+            handle_epics_key(tui, key);
+            return;
+        }
+
+        let max_index = visible_issues(&tui.data).len().saturating_sub(1);
+        if helpers::handle_list_navigation(
+            key,
+            &mut tui.data.ui_state.selected_issue_index,
+            max_index,
+        ) {
+            return;
+        }
+
+        match key.code {
+            KeyCode::Char('/') => tui.data.ui_state.issue_search_active = true,
+            KeyCode::Esc if !tui.data.ui_state.issue_search_query.is_empty() => {
+                tui.data.ui_state.issue_search_query.clear();
+                tui.data.ui_state.selected_issue_index = 0;
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => tui.refresh_selected_issue(),
+            KeyCode::Char('f') | KeyCode::Char('F') | KeyCode::Char('*') => {
+                tui.toggle_favorite_issue()
+            }
+            KeyCode::Char('v') | KeyCode::Char('V') => {
+                tui.data.ui_state.favorite_issues_only = !tui.data.ui_state.favorite_issues_only;
+                tui.data.ui_state.selected_issue_index = 0;
+            }
+            KeyCode::Char('w') | KeyCode::Char('W') => tui.open_quick_worklog(),
+            KeyCode::Char('e') | KeyCode::Char('E') => tui.data.ui_state.issues_by_epic = true,
+            KeyCode::Enter => {
+                if let Some(issue) =
+                    visible_issues(&tui.data).get(tui.data.ui_state.selected_issue_index)
+                {
+                    let key = issue.key.clone();
+                    tui.open_issue_detail(key);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Epics view: Enter lists the issues of the selected epic
+fn handle_epics_key(tui: &mut Tui, key: KeyEvent) {
+    let epics = epic_hours(&tui.data);
+    let ui_state = &mut tui.data.ui_state;
+    let max_index = epics.len().saturating_sub(1);
+    if helpers::handle_list_navigation(key, &mut ui_state.selected_epic_index, max_index) {
+        return;
+    }
+
+    match key.code {
+        KeyCode::Char('e') | KeyCode::Char('E') | KeyCode::Esc => ui_state.issues_by_epic = false,
+        KeyCode::Enter => {
+            if let Some(epic) = epics
+                .get(ui_state.selected_epic_index)
+                .filter(|epic| epic.epic != NO_EPIC)
+            {
+                ui_state.issue_search_query = epic.epic.clone();
+                ui_state.selected_issue_index = 0;
+                ui_state.issues_by_epic = false;
+            }
+        }
+        _ => {}
+    }
+}
+
+/// My logged hours per epic, all time
+fn epic_hours(data: &TuiData) -> Vec<EpicHours> {
+    epic_breakdown(
+        &data.all_worklogs,
+        &data.jira_worklogs,
+        &data.issues_by_key,
+        &data.all_sprints,
+    )
+}
+
+/// My logged hours per issue, all time
+fn logged_hours(data: &TuiData) -> HashMap<String, IssueHours> {
+    issue_breakdown(
+        &data.all_worklogs,
+        &data.jira_worklogs,
+        NaiveDate::MIN,
+        NaiveDate::MAX,
+    )
+    .into_iter()
+    .map(|hours| (hours.issue.clone(), hours))
+    .collect()
+}
+
+/// Cached issues after search and favorites filtering: favorites first, then
+/// the issues with the most logged time
+pub(in crate::tui) fn visible_issues(data: &TuiData) -> Vec<&Issue> {
+    let ui_state = &data.ui_state;
+    let query = ui_state.issue_search_query.to_lowercase();
+    let hours = logged_hours(data);
+    let total = |issue: &Issue| hours.get(&issue.key).map_or(0.0, IssueHours::total);
+
+    let mut issues: Vec<&Issue> = data
+        .issues_by_key
+        .values()
+        .filter(|issue| {
+            !ui_state.favorite_issues_only || ui_state.favorite_issues.contains(&issue.key)
+        })
+        .filter(|issue| query.is_empty() || issue.matches(&query))
+        .collect();
+    issues.sort_by(|a, b| {
+        let a_favorite = ui_state.favorite_issues.contains(&a.key);
+        let b_favorite = ui_state.favorite_issues.contains(&b.key);
+        b_favorite
+            .cmp(&a_favorite)
+            .then_with(|| total(b).total_cmp(&total(a)))
+            .then_with(|| a.key.cmp(&b.key))
+    });
+    issues
+}
+
+fn render_issues_tab(frame: &mut Frame, area: &Rect, data: &TuiData) {
+    let issues = visible_issues(data);
+    let hours = logged_hours(data);
+    let selected_index = data.ui_state.selected_issue_index;
+
+    render_list_detail_layout(
+        frame,
+        area,
+        data.ui_state.split_layout,
+        |f, a| render_issues_list(f, a, data, &issues, &hours, selected_index),
+        |f, a| render_issue_details(f, a, data, issues.get(selected_index).copied(), &hours),
+    );
+}
+
+fn render_issues_list(
+    frame: &mut Frame,
+    area: &Rect,
+    data: &TuiData,
+    issues: &[&Issue],
+    hours: &HashMap<String, IssueHours>,
+    selected_index: usize,
+) {
+    let ui_state = &data.ui_state;
+    let summary_width = (area.width as usize).saturating_sub(42);
+
+    let items: Vec<ListItem> = if issues.is_empty() {
+        vec![ListItem::new(Line::from(Span::styled(
+            " No issues found",
+            Style::default().fg(Color::DarkGray),
+        )))]
+    } else {
+        issues
+            .iter()
+            .map(|issue| {
+                let star = if ui_state.favorite_issues.contains(&issue.key) {
+                    Span::styled("★ ", Style::default().fg(Color::Yellow))
+                } else {
+                    Span::raw("  ")
+                };
+                let logged = hours.get(&issue.key).map_or(0.0, IssueHours::total);
+                let logged_style = if logged > 0.0 {
+                    Style::default().fg(Color::Cyan)
+                } else {
+                    Style::default().fg(Color::DarkGray)
+                };
+                ListItem::new(Line::from(vec![
+                    Span::raw(" "),
+                    star,
+                    Span::styled(
+                        format!("{:<11}", issue.key),
+                        Style::default().fg(Color::Green),
+                    ),
+                    Span::styled(
+                        format!("{:<13}", truncate_string(&issue.status, 12)),
+                        Style::default().fg(Color::Gray),
+                    ),
+                    Span::styled(format!("{:>6.1}h  ", logged), logged_style),
+                    Span::styled(
+                        truncate_string(&issue.summary, summary_width),
+                        Style::default().fg(Color::White),
+                    ),
+                ]))
+            })
+            .collect()
+    };
+
+    let filter_text = if ui_state.favorite_issues_only {
+        " [Favorites only]"
+    } else {
+        ""
+    };
+    let mut title_spans = vec![Span::raw(format!(
+        "🎫 Issues ({}/{}){} | ",
+        issues.len(),
+        data.issues_by_key.len(),
+        filter_text
+    ))];
+    title_spans.extend(build_shortcut_help(&[
+        ("F", "avorite"),
+        ("V", "iew favorites"),
+        ("R", "efresh issue"),
+        ("W", "orklog"),
+        ("E", "pics"),
+        ("Enter", " Details"),
+    ]));
+
+    let search_bottom = if ui_state.issue_search_active {
+        Line::from(vec![
+            Span::styled(" / ", Style::default().fg(Color::Yellow)),
+            Span::styled(
+                ui_state.issue_search_query.clone(),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("█", Style::default().fg(Color::Yellow)),
+            Span::styled(
+                "  Esc: cancel  Enter: apply ",
+                Style::default().fg(Color::DarkGray),
+            ),
+        ])
+    } else if !ui_state.issue_search_query.is_empty() {
+        Line::from(vec![
+            Span::styled(" / ", Style::default().fg(Color::Yellow)),
+            Span::styled(
+                ui_state.issue_search_query.clone(),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("  Esc: clear ", Style::default().fg(Color::DarkGray)),
+        ])
+    } else {
+        Line::from(Span::styled(
+            " / search ",
+            Style::default().fg(Color::DarkGray),
+        ))
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Line::from(title_spans))
+        .title_alignment(Alignment::Left)
+        .title_bottom(search_bottom)
+        .border_style(Style::default().fg(theme().border))
+        .style(Style::default().bg(theme().bg_primary));
+
+    let list = List::new(items).block(block).highlight_style(
+        Style::default()
+            .bg(Color::Rgb(45, 40, 60))
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = ListState::default();
+    if !issues.is_empty() {
+        state.select(Some(selected_index));
+    }
+    frame.render_stateful_widget(list, *area, &mut state);
+}
+
+fn render_issue_details(
+    frame: &mut Frame,
+    area: &Rect,
+    data: &TuiData,
+    issue: Option<&Issue>,
+    hours: &HashMap<String, IssueHours>,
+) {
+    let block = Block::default()
+        .title("📋 Issue Details")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().border))
+        .style(Style::default().bg(theme().bg_primary));
+
+    let Some(issue) = issue else {
+        let paragraph = Paragraph::new("No issue selected")
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(paragraph, *area);
+        return;
+    };
+
+    let label = Style::default().fg(Color::Gray);
+    let value = Style::default().fg(Color::White);
+    let logged = hours.get(&issue.key).cloned().unwrap_or_default();
+
+    let sprints: Vec<String> = issue
+        .sprint_ids
+        .iter()
+        .map(|id| {
+            data.all_sprints
+                .iter()
+                .find(|s| s.id == *id)
+                .map(|s| s.name.clone())
+                .unwrap_or_else(|| format!("#{}", id))
+        })
+        .collect();
+    // Components as they are, labels marked with a # to tell them apart
+    let tags: Vec<String> = issue
+        .components
+        .iter()
+        .cloned()
+        .chain(issue.labels.iter().map(|label| format!("#{}", label)))
+        .collect();
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(
+                issue.key.clone(),
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            if data.ui_state.favorite_issues.contains(&issue.key) {
+                Span::styled("  ★ favorite", Style::default().fg(Color::Yellow))
+            } else {
+                Span::raw("")
+            },
+        ]),
+        Line::from(Span::styled(issue.summary.clone(), value)),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Status:  ", label),
+            Span::styled(issue.status.clone(), value),
+        ]),
+        Line::from(vec![
+            Span::styled("Created: ", label),
+            Span::styled(
+                issue
+                    .created
+                    .with_timezone(&Local)
+                    .format("%Y-%m-%d")
+                    .to_string(),
+                value,
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Sprints: ", label),
+            if sprints.is_empty() {
+                Span::styled("none known", Style::default().fg(Color::DarkGray))
+            } else {
+                Span::styled(sprints.join(", "), value)
+            },
+        ]),
+        Line::from(vec![
+            Span::styled("Epic:    ", label),
+            match &issue.epic {
+                Some(epic) => Span::styled(epic.clone(), value),
+                None => Span::styled("none", Style::default().fg(Color::DarkGray)),
+            },
+        ]),
+        Line::from(vec![
+            Span::styled("Tags:    ", label),
+            if tags.is_empty() {
+                Span::styled("none", Style::default().fg(Color::DarkGray))
+            } else {
+                Span::styled(tags.join(", "), value)
+            },
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Logged:  ", label),
+            Span::styled(
+                format_hours(logged.total()),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(
+                    "  ({:.1}h local, {:.1}h in Jira)",
+                    logged.local_hours, logged.jira_hours
+                ),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "🕒 Recent worklogs",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+    ];
+
+    let mut recent: Vec<_> = data
+        .jira_worklogs
+        .iter()
+        .filter(|w| w.issue_id == issue.key)
+        .map(|w| (w.started, w.time_spent_seconds as i64, "Jira"))
+        .chain(
+            data.all_worklogs
+                .iter()
+                .filter(|w| w.issue_id == issue.key && w.worklog_id.is_none())
+                .map(|w| (w.started, w.time_spent_seconds, "local")),
+        )
+        .collect();
+    recent.sort_by_key(|(started, _, _)| std::cmp::Reverse(*started));
+
+    if recent.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  None yet — [W] to log time",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    for (started, seconds, source) in recent.iter().take(RECENT_WORKLOGS) {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(
+                    "  {} ",
+                    started.with_timezone(&Local).format("%a %Y-%m-%d %H:%M")
+                ),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::styled(format!("{:>5.1}h ", *seconds as f64 / 3600.0), value),
+            Span::styled(source.to_string(), Style::default().fg(Color::DarkGray)),
+        ]));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, *area);
+}
+
+fn render_epics_view(frame: &mut Frame, area: &Rect, data: &TuiData) {
+    let epics = epic_hours(data);
+    let selected_index = data.ui_state.selected_epic_index;
+
+    render_list_detail_layout(
+        frame,
+        area,
+        data.ui_state.split_layout,
+        |f, a| render_epics_list(f, a, data, &epics, selected_index),
+        |f, a| render_epic_details(f, a, data, epics.get(selected_index)),
+    );
+}
+
+/// The summary of the epic's own issue, when it is cached
+fn epic_summary<'a>(data: &'a TuiData, epic: &str) -> &'a str {
+    data.issues_by_key
+        .get(epic)
+        .map_or("", |issue| issue.summary.as_str())
+}
+
+fn render_epics_list(
+    frame: &mut Frame,
+    area: &Rect,
+    data: &TuiData,
+    epics: &[EpicHours],
+    selected_index: usize,
+) {
+    let summary_width = (area.width as usize).saturating_sub(36);
+
+    let items: Vec<ListItem> = if epics.is_empty() {
+        vec![ListItem::new(Line::from(Span::styled(
+            " No time logged yet",
+            Style::default().fg(Color::DarkGray),
+        )))]
+    } else {
+        epics
+            .iter()
+            .map(|epic| {
+                let key_style = if epic.epic == NO_EPIC {
+                    Style::default().fg(Color::DarkGray)
+                } else {
+                    Style::default().fg(Color::Green)
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!(" {:<11}", epic.epic), key_style),
+                    Span::styled(
+                        format!("{:>3} issues ", epic.issues.len()),
+                        Style::default().fg(Color::Gray),
+                    ),
+                    Span::styled(
+                        format!("{:>7.1}h  ", epic.total()),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::styled(
+                        truncate_string(epic_summary(data, &epic.epic), summary_width),
+                        Style::default().fg(Color::White),
+                    ),
+                ]))
+            })
+            .collect()
+    };
+
+    let total: f64 = epics.iter().map(EpicHours::total).sum();
+    let mut title_spans = vec![Span::raw(format!(
+        "🧭 Epics ({}, {}) | ",
+        epics.len(),
+        format_hours(total)
+    ))];
+    title_spans.extend(build_shortcut_help(&[
+        ("E", " Issues"),
+        ("Enter", " Epic issues"),
+    ]));
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(Line::from(title_spans))
+        .title_alignment(Alignment::Left)
+        .border_style(Style::default().fg(theme().border))
+        .style(Style::default().bg(theme().bg_primary));
+
+    let list = List::new(items).block(block).highlight_style(
+        Style::default()
+            .bg(Color::Rgb(45, 40, 60))
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut state = ListState::default();
+    if !epics.is_empty() {
+        state.select(Some(selected_index));
+    }
+    frame.render_stateful_widget(list, *area, &mut state);
+}
+
+fn render_epic_details(frame: &mut Frame, area: &Rect, data: &TuiData, epic: Option<&EpicHours>) {
+    let block = Block::default()
+        .title("📋 Epic Details")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().border))
+        .style(Style::default().bg(theme().bg_primary));
+
+    let Some(epic) = epic else {
+        let paragraph = Paragraph::new("No epic selected")
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(paragraph, *area);
+        return;
+    };
+
+    let label = Style::default().fg(Color::Gray);
+    let value = Style::default().fg(Color::White);
+    let heading = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::BOLD);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            epic.epic.clone(),
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            epic_summary(data, &epic.epic).to_string(),
+            value,
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Logged:  ", label),
+            Span::styled(
+                format_hours(epic.total()),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled("🏃 Per sprint", heading)),
+    ];
+    if epic.sprints.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No time within a known sprint",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    for (sprint, hours) in &epic.sprints {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:>6.1}h ", hours), value),
+            Span::styled(sprint.clone(), Style::default().fg(Color::Yellow)),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("🎫 Issues", heading)));
+    for issue in &epic.issues {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:>6.1}h ", issue.total()), value),
+            Span::styled(
+                format!("{:<11} ", issue.issue),
+                Style::default().fg(Color::Green),
+            ),
+            Span::styled(
+                data.issues_by_key
+                    .get(&issue.issue)
+                    .map(|i| i.summary.clone())
+                    .unwrap_or_default(),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, *area);
+}
@@ -0,0 +1,149 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use std::sync::Arc;
+
+use crate::logger::{CollectingLogger, LogLevel};
+use crate::tui::data::TuiData;
+use crate::tui::tab_controller::TabController;
+use crate::tui::theme::theme;
+use crate::tui::ui_helpers::*;
+use crate::tui::Tui;
+
+/// Lines moved by PageUp / PageDown
+const PAGE_LINES: usize = 10;
+
+/// Full-height view of the collected logs, with level filter and search
+#[derive(Clone)]
+pub(in crate::tui) struct LogsTab {
+    pub(in crate::tui) collector: Arc<CollectingLogger>,
+}
+
+impl TabController for LogsTab {
+    fn render(&self, frame: &mut Frame, area: &Rect, data: &TuiData) {
+        render_logs_tab(frame, area, data, &self.collector.get_messages());
+    }
+
+    fn handle_key(&self, tui: &mut Tui, key: KeyEvent) {
+        let ui_state = &mut tui.data.ui_state;
+
+        // Search mode: all input goes to the query, no shortcuts fire
+        if ui_state.log_search_active {
+            match key.code {
+                KeyCode::Esc => {
+                    ui_state.log_search_active = false;
+                    ui_state.log_search_query.clear();
+                }
+                KeyCode::Enter => ui_state.log_search_active = false,
+                KeyCode::Backspace => {
+                    ui_state.log_search_query.pop();
+                }
+                KeyCode::Char(c) => ui_state.log_search_query.push(c),
+                _ => return,
+            }
+            ui_state.log_view_scroll = 0;
+            return;
+        }
+
+        let logs = self.collector.get_messages();
+        let total = filter_logs(&logs, ui_state.log_min_level, &ui_state.log_search_query).len();
+        let scroll = &mut ui_state.log_view_scroll;
+
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => *scroll = (*scroll + 1).min(total),
+            KeyCode::Down | KeyCode::Char('j') => *scroll = scroll.saturating_sub(1),
+            KeyCode::PageUp => *scroll = (*scroll + PAGE_LINES).min(total),
+            KeyCode::PageDown => *scroll = scroll.saturating_sub(PAGE_LINES),
+            KeyCode::Home | KeyCode::Char('g') => *scroll = total,
+            KeyCode::End | KeyCode::Char('G') => *scroll = 0,
+            KeyCode::Char('/') => ui_state.log_search_active = true,
+            KeyCode::Esc if !ui_state.log_search_query.is_empty() => {
+                ui_state.log_search_query.clear();
+                ui_state.log_view_scroll = 0;
+            }
+            KeyCode::Char('l') | KeyCode::Char('L') => {
+                ui_state.log_min_level = ui_state.log_min_level.next();
+                ui_state.log_view_scroll = 0;
+            }
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                self.collector.clear();
+                ui_state.log_view_scroll = 0;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Messages at or above `min_level` containing `query` (case-insensitive)
+fn filter_logs<'a>(logs: &'a [String], min_level: LogLevel, query: &str) -> Vec<&'a String> {
+    let query = query.to_lowercase();
+    logs.iter()
+        .filter(|log| LogLevel::of(log) >= min_level)
+        .filter(|log| query.is_empty() || log.to_lowercase().contains(&query))
+        .collect()
+}
+
+fn render_logs_tab(frame: &mut Frame, area: &Rect, data: &TuiData, logs: &[String]) {
+    let ui_state = &data.ui_state;
+    let visible_logs = filter_logs(logs, ui_state.log_min_level, &ui_state.log_search_query);
+
+    let mut title = vec![Span::raw(format!(
+        "📜 Logs ({}/{}) | Level ≥ {} | ",
+        visible_logs.len(),
+        logs.len(),
+        ui_state.log_min_level.label()
+    ))];
+    if ui_state.log_search_active || !ui_state.log_search_query.is_empty() {
+        let cursor = if ui_state.log_search_active {
+            "█"
+        } else {
+            ""
+        };
+        title.push(Span::styled(
+            format!("🔍 {}{} | ", ui_state.log_search_query, cursor),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    title.extend(build_shortcut_help(&[
+        ("/", " search"),
+        ("L", "evel"),
+        ("C", "lear"),
+        ("↑↓", " scroll"),
+    ]));
+
+    let block = Block::default()
+        .title(Line::from(title))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().border))
+        .style(Style::default().bg(theme().bg_primary));
+    let inner = block.inner(*area);
+    frame.render_widget(block, *area);
+
+    // Newest at the bottom; the offset counts lines scrolled up from there
+    let max_lines = inner.height as usize;
+    let offset = ui_state
+        .log_view_scroll
+        .min(visible_logs.len().saturating_sub(max_lines));
+    let end = visible_logs.len() - offset;
+    let start = end.saturating_sub(max_lines);
+
+    let lines: Vec<Line> = visible_logs[start..end]
+        .iter()
+        .map(|log| {
+            let color = match LogLevel::of(log) {
+                LogLevel::Debug => theme().fg_muted,
+                LogLevel::Info => theme().fg_secondary,
+                LogLevel::Warn => Color::Yellow,
+                LogLevel::Error => Color::Red,
+            };
+            Line::from(Span::styled(log.to_string(), Style::default().fg(color)))
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
@@ -8,10 +8,12 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Frame,
 };
+use std::collections::BTreeSet;
 
 use crate::logger;
 use crate::tui::data::TuiData;
 use crate::tui::helpers;
+use crate::tui::sorting::SortKey;
 use crate::tui::tab_controller::TabController;
 use crate::tui::theme::theme;
 use crate::tui::ui_helpers::*;
@@ -19,13 +21,45 @@ use crate::tui::Tui;
 use wtf_lib::config::Config;
 use wtf_lib::models::data::Meeting;
 use wtf_lib::services::meetings_service::MeetingsService;
+use wtf_lib::utils::meetings::{describe_recurrence, response_breakdown};
 
 #[derive(Debug, Clone, Copy, Default)]
 pub(in crate::tui) struct MeetingsTab;
 
+impl SortKey for Meeting {
+    fn sort_date(&self) -> chrono::DateTime<chrono::Utc> {
+        self.start
+    }
+
+    fn sort_issue(&self) -> Option<&str> {
+        self.jira_link.as_deref()
+    }
+
+    fn sort_duration(&self) -> i64 {
+        (self.end - self.start).num_seconds()
+    }
+}
+
 pub(in crate::tui) fn visible_meetings(data: &TuiData) -> Vec<Meeting> {
     let mut sorted_meetings = data.all_meetings.clone();
-    sorted_meetings.sort_by(|a, b| b.start.cmp(&a.start));
+    // Status order: unlinked, linked, declined, untracked
+    data.ui_state
+        .meeting_sort
+        .sort(&mut sorted_meetings, |meeting| {
+            if wtf_lib::utils::meetings::is_untracked(
+                meeting,
+                &data.config,
+                &data.untracked_meeting_ids,
+            ) {
+                3
+            } else if meeting.my_response_status.as_deref() == Some("declined") {
+                2
+            } else if meeting.jira_link.is_some() {
+                1
+            } else {
+                0
+            }
+        });
 
     let query = data.ui_state.meeting_search_query.to_lowercase();
 
@@ -34,17 +68,25 @@ pub(in crate::tui) fn visible_meetings(data: &TuiData) -> Vec<Meeting> {
         .filter(|meeting| {
             if data.ui_state.filter_unlinked_only {
                 let is_unlinked = meeting.jira_link.is_none();
-                let is_not_declined = meeting
-                    .my_response_status
-                    .as_ref()
-                    .map(|status| status != "declined")
-                    .unwrap_or(true);
+                let is_attended = data.config.meetings.attends(meeting);
                 let is_not_untracked = !wtf_lib::utils::meetings::is_untracked(
                     meeting,
                     &data.config,
                     &data.untracked_meeting_ids,
                 );
-                if !(is_unlinked && is_not_declined && is_not_untracked) {
+                if !(is_unlinked && is_attended && is_not_untracked) {
+                    return false;
+                }
+            }
+
+            if let Some(color_id) = &data.ui_state.meeting_color_filter {
+                if meeting.color_id.as_ref() != Some(color_id) {
+                    return false;
+                }
+            }
+
+            if let Some(calendar) = &data.ui_state.meeting_calendar_filter {
+                if meeting.calendar.as_ref() != Some(calendar) {
                     return false;
                 }
             }
@@ -78,6 +120,7 @@ impl TabController for MeetingsTab {
         render_meetings_tab(frame, area, data);
     }
 
+    #[allow(clippy::collapsible_match)]
     fn handle_key(&self, tui: &mut Tui, key: KeyEvent) {
         // Search mode: all input goes to the query, no shortcuts fire
         if tui.data.ui_state.meeting_search_active {
@@ -140,10 +183,45 @@ impl TabController for MeetingsTab {
                 tui.data.ui_state.filter_unlinked_only = !tui.data.ui_state.filter_unlinked_only;
                 tui.data.ui_state.selected_meeting_index = 0;
             }
+            KeyCode::Char('c') | KeyCode::Char('C') => {
+                let state = &mut tui.data.ui_state;
+                state.meeting_color_filter = helpers::cycle_filter(
+                    state.meeting_color_filter.as_ref(),
+                    &meeting_color_ids(&tui.data.all_meetings),
+                );
+                state.selected_meeting_index = 0;
+            }
+            KeyCode::Char('g') | KeyCode::Char('G') => {
+                let state = &mut tui.data.ui_state;
+                state.meeting_calendar_filter = helpers::cycle_filter(
+                    state.meeting_calendar_filter.as_ref(),
+                    &meeting_calendars(&tui.data.all_meetings),
+                );
+                state.selected_meeting_index = 0;
+            }
+            KeyCode::Char('v') | KeyCode::Char('V') => {
+                if let Some(link) = meetings
+                    .get(tui.data.ui_state.selected_meeting_index)
+                    .and_then(|m| m.conference_link.as_ref())
+                {
+                    match open::that(link) {
+                        Ok(()) => logger::log(format!("🎥 Opening {}", link)),
+                        Err(e) => logger::log(format!("❌ Failed to open {}: {}", link, e)),
+                    }
+                }
+            }
+            KeyCode::Char('o') | KeyCode::Char('O') => {
+                tui.data.ui_state.meeting_sort = tui.data.ui_state.meeting_sort.next();
+                tui.data.ui_state.selected_meeting_index = 0;
+            }
             KeyCode::Delete | KeyCode::Backspace => {
                 if let Some(meeting) = meetings.get(tui.data.ui_state.selected_meeting_index) {
                     if meeting.jira_link.is_some() {
-                        tui.unlink_confirmation_meeting_id = Some(meeting.id.clone());
+                        if tui.data.config.confirmations.unlink {
+                            tui.unlink_confirmation_meeting_id = Some(meeting.id.clone());
+                        } else {
+                            tui.unlink_meeting(meeting.id.clone());
+                        }
                     }
                 }
             }
@@ -176,6 +254,20 @@ impl TabController for MeetingsTab {
     }
 }
 
+/// Google Calendar color ids used by at least one meeting, in calendar order
+fn meeting_color_ids(meetings: &[Meeting]) -> Vec<String> {
+    let mut ids: Vec<String> = meetings.iter().filter_map(|m| m.color_id.clone()).collect();
+    ids.sort_by_key(|id| id.parse::<u32>().unwrap_or(u32::MAX));
+    ids.dedup();
+    ids
+}
+
+/// Google calendars the meetings were fetched from, by name
+fn meeting_calendars(meetings: &[Meeting]) -> Vec<String> {
+    let calendars: BTreeSet<String> = meetings.iter().filter_map(|m| m.calendar.clone()).collect();
+    calendars.into_iter().collect()
+}
+
 /// Render meetings tab with list and details
 pub(in crate::tui) fn render_meetings_tab(frame: &mut Frame, area: &Rect, data: &TuiData) {
     let selected_index = data.ui_state.selected_meeting_index;
@@ -184,6 +276,7 @@ pub(in crate::tui) fn render_meetings_tab(frame: &mut Frame, area: &Rect, data:
     render_list_detail_layout(
         frame,
         area,
+        data.ui_state.split_layout,
         |f, a| render_meetings_list(f, a, data, &meetings, selected_index),
         |f, a| render_meeting_details(f, a, data, &meetings, selected_index),
     );
@@ -197,7 +290,11 @@ fn render_meetings_list(
     selected_index: usize,
 ) {
     let items: Vec<ListItem> = if meetings.is_empty() {
-        let message = if data.ui_state.filter_unlinked_only {
+        let message = if data.ui_state.meeting_calendar_filter.is_some() {
+            "No meetings in this calendar"
+        } else if data.ui_state.meeting_color_filter.is_some() {
+            "No meetings with this color"
+        } else if data.ui_state.filter_unlinked_only {
             "No unlinked meetings found"
         } else {
             "No meetings found"
@@ -289,10 +386,12 @@ fn render_meetings_list(
                                 gc_color(cid)
                             }
                         });
-                        if let Some(c) = circle_color {
-                            Span::styled("● ", Style::default().fg(c))
-                        } else {
-                            Span::raw("  ")
+                        match (circle_color, meeting.color_id.as_deref()) {
+                            (Some(c), Some(cid)) => Span::styled(
+                                format!("● {:<9} ", gc_color_name(cid)),
+                                Style::default().fg(c),
+                            ),
+                            _ => Span::raw(" ".repeat(12)),
                         }
                     },
                     Span::styled(
@@ -324,6 +423,10 @@ fn render_meetings_list(
         .get(selected_index)
         .and_then(|m| m.jira_link.as_ref())
         .is_some();
+    let selected_has_call = meetings
+        .get(selected_index)
+        .and_then(|m| m.conference_link.as_ref())
+        .is_some();
 
     let pending_count = data.meeting_stats.pending;
     let filter_text = if data.ui_state.filter_unlinked_only {
@@ -332,12 +435,19 @@ fn render_meetings_list(
         ""
     };
 
-    let mut shortcuts_data = vec![("F", "ilter"), ("A", "uto-link"), ("X", " Untrack")];
+    let mut shortcuts_data = vec![("F", "ilter"), ("C", "olor")];
+    if meeting_calendars(&data.all_meetings).len() > 1 {
+        shortcuts_data.push(("G", " Calendar"));
+    }
+    shortcuts_data.extend([("O", "rder"), ("A", "uto-link"), ("X", " Untrack")]);
     if selected_has_link {
         shortcuts_data.push(("Del", " Unlink"));
     }
     shortcuts_data.push(("Enter", " Link"));
     shortcuts_data.push(("L", "og"));
+    if selected_has_call {
+        shortcuts_data.push(("V", "ideo call"));
+    }
     let shortcuts = build_shortcut_help(&shortcuts_data);
 
     let mut title_spans = vec![Span::raw("📅 Meetings (")];
@@ -351,6 +461,19 @@ fn render_meetings_list(
         title_spans.push(Span::raw(format!("{} total", data.all_meetings.len())));
     }
     title_spans.push(Span::raw(filter_text));
+    if let Some(color_id) = &data.ui_state.meeting_color_filter {
+        title_spans.push(Span::styled(
+            format!(" [Color: {}]", gc_color_name(color_id)),
+            Style::default().fg(gc_color(color_id)),
+        ));
+    }
+    if let Some(calendar) = &data.ui_state.meeting_calendar_filter {
+        title_spans.push(Span::raw(format!(" [Calendar: {}]", calendar)));
+    }
+    title_spans.push(Span::raw(format!(
+        " [Sort: {}]",
+        data.ui_state.meeting_sort.label()
+    )));
     title_spans.push(Span::raw(") | "));
     title_spans.extend(shortcuts);
 
@@ -412,7 +535,7 @@ fn render_meetings_list(
 fn render_meeting_details(
     frame: &mut Frame,
     area: &Rect,
-    _data: &TuiData,
+    data: &TuiData,
     meetings: &[Meeting],
     selected_index: usize,
 ) {
@@ -534,12 +657,24 @@ fn render_meeting_details(
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(format!("● ({})", label), Style::default().fg(color)),
+            Span::styled(
+                data.config
+                    .google
+                    .as_ref()
+                    .and_then(|g| g.color_labels.get(label))
+                    .map(|mapped| format!(" → {}", mapped))
+                    .unwrap_or_default(),
+                Style::default().fg(Color::Gray),
+            ),
         ]));
     }
 
     // Recurrence info
     if let Some(recurrence) = &meeting.recurrence {
         if !recurrence.is_empty() {
+            let summary = describe_recurrence(recurrence)
+                .map(|rule| format!("🔁 {}", rule))
+                .unwrap_or_else(|| "🔁 Recurring".to_string());
             lines.push(Line::from(vec![
                 Span::styled(
                     "Recurrence: ",
@@ -547,12 +682,33 @@ fn render_meeting_details(
                         .fg(Color::Yellow)
                         .add_modifier(Modifier::BOLD),
                 ),
-                Span::styled("🔁 Recurring", Style::default().fg(Color::Magenta)),
+                Span::styled(summary, Style::default().fg(Color::Magenta)),
             ]));
         }
     }
+
+    // Conferencing link
+    if let Some(link) = &meeting.conference_link {
+        lines.push(Line::from(vec![
+            Span::styled(
+                "Call: ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                link.clone(),
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::UNDERLINED),
+            ),
+            Span::styled(" [V] open", Style::default().fg(Color::DarkGray)),
+        ]));
+    }
     lines.push(Line::from(""));
 
+    lines.extend(participant_lines(meeting));
+
     // Jira Link - with full URL for clickability
     if let Some(link) = &meeting.jira_link {
         // Build full URL for terminal click support
@@ -730,3 +886,99 @@ fn render_meeting_details(
     let paragraph = Paragraph::new(lines).alignment(Alignment::Left);
     frame.render_widget(paragraph, inner);
 }
+
+/// Maximum attendees listed in the details pane before collapsing into "+N more"
+const MAX_LISTED_ATTENDEES: usize = 8;
+
+fn participant_lines(meeting: &Meeting) -> Vec<Line<'static>> {
+    let label = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+    let mut lines = Vec::new();
+
+    if let Some(organizer) = &meeting.organizer {
+        lines.push(Line::from(vec![
+            Span::styled("Organizer: ", label),
+            Span::styled(organizer.clone(), Style::default().fg(Color::White)),
+        ]));
+    }
+    if let Some(calendar) = &meeting.calendar {
+        lines.push(Line::from(vec![
+            Span::styled("Calendar: ", label),
+            Span::styled(calendar.clone(), Style::default().fg(Color::White)),
+        ]));
+    }
+    if meeting.is_focus_time() {
+        lines.push(Line::from(vec![
+            Span::styled("Type: ", label),
+            Span::styled("Focus time", Style::default().fg(Color::White)),
+        ]));
+    }
+
+    let attendees = meeting.attendees.as_deref().unwrap_or_default();
+    if attendees.is_empty() {
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        return lines;
+    }
+
+    let breakdown = response_breakdown(meeting);
+    lines.push(Line::from(vec![
+        Span::styled(format!("Attendees ({}): ", attendees.len()), label),
+        Span::styled(
+            format!("✓ {} ", breakdown.accepted),
+            Style::default().fg(Color::Green),
+        ),
+        Span::styled(
+            format!("? {} ", breakdown.tentative),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::styled(
+            format!("✗ {} ", breakdown.declined),
+            Style::default().fg(Color::Red),
+        ),
+        Span::styled(
+            format!("⏳ {}", breakdown.pending),
+            Style::default().fg(Color::Cyan),
+        ),
+    ]));
+
+    for attendee in attendees.iter().take(MAX_LISTED_ATTENDEES) {
+        let (icon, color) = match attendee.response_status.as_deref() {
+            Some("accepted") => ("✓", Color::Green),
+            Some("declined") => ("✗", Color::Red),
+            Some("tentative") => ("?", Color::Yellow),
+            _ => ("⏳", Color::Cyan),
+        };
+        let name = attendee
+            .display_name
+            .clone()
+            .or_else(|| attendee.email.clone())
+            .unwrap_or_else(|| "Unknown".to_string());
+        let mut spans = vec![
+            Span::raw("  "),
+            Span::styled(format!("{} ", icon), Style::default().fg(color)),
+            Span::styled(
+                truncate_string(&name, 40),
+                Style::default().fg(Color::White),
+            ),
+        ];
+        if attendee.organizer {
+            spans.push(Span::styled(
+                " (organizer)",
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+    if attendees.len() > MAX_LISTED_ATTENDEES {
+        lines.push(Line::from(Span::styled(
+            format!("  +{} more", attendees.len() - MAX_LISTED_ATTENDEES),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    lines.push(Line::from(""));
+
+    lines
+}
@@ -1,6 +1,9 @@
 pub(in crate::tui) mod achievements;
+pub(in crate::tui) mod dashboard;
 pub(in crate::tui) mod github;
 pub(in crate::tui) mod history;
+pub(in crate::tui) mod issues;
+pub(in crate::tui) mod logs;
 pub(in crate::tui) mod meetings;
 pub(in crate::tui) mod settings;
 pub(in crate::tui) mod sprints;
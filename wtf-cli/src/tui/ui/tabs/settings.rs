@@ -23,6 +23,7 @@ impl TabController for SettingsTab {
         render_settings_tab(frame, area, data);
     }
 
+    #[allow(clippy::collapsible_match)]
     fn handle_key(&self, tui: &mut Tui, key: crossterm::event::KeyEvent) {
         if tui.data.ui_state.settings_editing {
             match key.code {
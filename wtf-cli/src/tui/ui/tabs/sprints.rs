@@ -3,18 +3,25 @@ use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
+    symbols,
     text::{Line, Span},
-    widgets::{Block, Borders, Gauge, List, ListItem, ListState, Paragraph},
+    widgets::{
+        Axis, Block, Borders, Chart, Dataset, Gauge, GraphType, List, ListItem, ListState,
+        Paragraph,
+    },
     Frame,
 };
 
 use crate::logger;
+use crate::tui::burn_chart::BurnSeries;
 use crate::tui::data::{DayActivity, TuiData};
 use crate::tui::helpers;
+use crate::tui::issue_breakdown::issue_breakdown;
 use crate::tui::tab_controller::TabController;
 use crate::tui::theme::theme;
 use crate::tui::ui_helpers::*;
 use crate::tui::{SprintFollowState, Tui};
+use wtf_lib::duration::format_hours;
 use wtf_lib::models::data::{Sprint, SprintState};
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -46,6 +53,9 @@ impl TabController for SprintsTab {
             KeyCode::Char('f') | KeyCode::Char('F') => {
                 tui.handle_fill_gaps();
             }
+            KeyCode::Char('i') | KeyCode::Char('I') => {
+                tui.data.ui_state.show_sprint_issues = !tui.data.ui_state.show_sprint_issues;
+            }
             KeyCode::Char('a') | KeyCode::Char('A') => {
                 let mut all_sprints = wtf_lib::services::jira_service::JiraService::production()
                     .get_available_sprints();
@@ -82,7 +92,7 @@ impl TabController for SprintsTab {
                             tui.refresh_data();
                         }
                         Err(e) => {
-                            logger::log(format!("❌ Failed to unfollow sprint: {}", e));
+                            logger::log(format!("❌ Failed to unfollow sprint: {}", e.with_hint()));
                         }
                     }
                 }
@@ -93,8 +103,6 @@ impl TabController for SprintsTab {
 }
 
 fn render_worklog_wall(frame: &mut Frame, area: &Rect, data: &TuiData) {
-    use chrono::Datelike;
-
     let block = Block::default()
         .title("📈 Worklog Activity (Last Year)")
         .borders(Borders::ALL)
@@ -108,82 +116,39 @@ fn render_worklog_wall(frame: &mut Frame, area: &Rect, data: &TuiData) {
         return;
     }
 
-    // Build grid with year boundaries respected
-    // When year changes, we pad current column, add separator, then continue from same weekday
-    let mut grid: Vec<Vec<(f64, bool, bool)>> = vec![vec![]; 5]; // (hours, is_absence, is_separator)
-    let mut prev_year: Option<i32> = None;
-
-    for (day_index, activity) in data.worklog_wall.iter().enumerate() {
-        let weekday = day_index % 7;
-
-        // Only process Mon-Fri (weekdays 0-4)
-        if weekday < 5 {
-            let current_year = activity.date.year();
-
-            // Check for year change
-            if let Some(prev) = prev_year {
-                if current_year != prev {
-                    // Year changed! Pad all rows to same length
-                    let max_len = grid.iter().map(|row| row.len()).max().unwrap_or(0);
-                    for row in &mut grid {
-                        while row.len() < max_len {
-                            row.push((0.0, false, false));
-                        }
-                    }
-
-                    // Add separator column to all rows
-                    for row in &mut grid {
-                        row.push((0.0, false, true)); // Special marker for separator
-                    }
-
-                    // Pad rows BEFORE the current weekday to align properly
-                    // If year changes on Thursday, pad Mon-Wed with empty cells
-                    let max_len_after_sep = grid.iter().map(|row| row.len()).max().unwrap_or(0);
-                    for row in grid.iter_mut().take(weekday) {
-                        while row.len() < max_len_after_sep + 1 {
-                            row.push((0.0, false, false));
-                        }
-                    }
-                }
-            }
-            prev_year = Some(current_year);
-
-            // Add data to grid at correct weekday row
-            grid[weekday].push((activity.hours, activity.is_absence, false));
-        }
-    }
-
-    // Pad all rows to same length
-    let max_len = grid.iter().map(|row| row.len()).max().unwrap_or(0);
-    for row in &mut grid {
-        while row.len() < max_len {
-            row.push((0.0, false, false));
-        }
-    }
+    let days: Vec<_> = data
+        .worklog_wall
+        .iter()
+        .map(|activity| (activity.date, (activity.hours, activity.is_absence)))
+        .collect();
 
     // Render the grid with weekday labels (Mon-Fri only)
     let mut lines = Vec::new();
     let weekday_labels = ["Mon", "Tue", "Wed", "Thu", "Fri"];
 
-    for (weekday_idx, weekday_row) in grid.iter().enumerate() {
+    for (weekday_idx, weekday_row) in weekday_wall(&days).iter().enumerate() {
         let mut line_spans = vec![Span::styled(
             format!("{} ", weekday_labels[weekday_idx]),
             Style::default().fg(Color::DarkGray),
         )];
 
-        for &(hours, is_absence, is_separator) in weekday_row {
-            if is_separator {
-                // Draw vertical separator (year boundary)
-                line_spans.push(Span::styled("│", Style::default().fg(theme().border)));
+        for cell in weekday_row {
+            let (hours, is_absence) = match *cell {
+                WallCell::YearSeparator => {
+                    // Draw vertical separator (year boundary)
+                    line_spans.push(Span::styled("│", Style::default().fg(theme().border)));
+                    continue;
+                }
+                WallCell::Blank => (0.0, false),
+                WallCell::Day(day) => day,
+            };
+            let braille = hours_to_braille(hours, data.daily_hours_limit);
+            let color = if is_absence {
+                Color::DarkGray // Absences in dark gray
             } else {
-                let braille = hours_to_braille(hours, data.daily_hours_limit);
-                let color = if is_absence {
-                    Color::DarkGray // Absences in dark gray
-                } else {
-                    Color::Green // Imputations in green
-                };
-                line_spans.push(Span::styled(braille, Style::default().fg(color)));
-            }
+                Color::Green // Imputations in green
+            };
+            line_spans.push(Span::styled(braille, Style::default().fg(color)));
         }
 
         lines.push(Line::from(line_spans));
@@ -193,27 +158,6 @@ fn render_worklog_wall(frame: &mut Frame, area: &Rect, data: &TuiData) {
     frame.render_widget(paragraph, inner);
 }
 
-/// Convert hours to braille character based on density
-fn hours_to_braille(hours: f64, daily_limit: f64) -> &'static str {
-    if hours == 0.0 {
-        "⠀" // Empty
-    } else if hours >= daily_limit {
-        "⣿" // Full square for >= daily limit (100%)
-    } else {
-        let percentage = (hours / daily_limit * 100.0).min(100.0);
-        match percentage as u32 {
-            0..=12 => "⢀",  // 1 dot (minimum for any activity)
-            13..=25 => "⢠", // 2 dots
-            26..=37 => "⢰", // 3 dots
-            38..=50 => "⢸", // 4 dots left
-            51..=62 => "⣀", // 4 dots bottom
-            63..=75 => "⣠", // 5 dots
-            76..=87 => "⣰", // 6 dots
-            _ => "⣸",       // 7 dots
-        }
-    }
-}
-
 /// Sprints tab - Two-column layout: (sprint list / worklog wall) | sprint details
 pub(in crate::tui) fn render_sprints_tab(frame: &mut Frame, area: &Rect, data: &TuiData) {
     let selected_index = data.ui_state.selected_sprint_index;
@@ -256,6 +200,7 @@ fn render_sprint_list_expanded(
         ("A", "dd/follow"),
         ("X", " unfollow"),
         ("F", "ill"),
+        ("I", "ssues"),
     ]);
     let mut title_spans = vec![
         Span::raw("📊 Followed Sprints ("),
@@ -297,8 +242,7 @@ fn render_sprint_list_expanded(
                 SprintState::Closed => Color::DarkGray,
             };
 
-            let capacity_hours =
-                calculate_sprint_capacity(sprint.id, data) as f64 * data.daily_hours_limit;
+            let capacity_hours = calculate_sprint_capacity_hours(sprint.id, data);
             let logged_hours = calculate_sprint_logged_hours(sprint.id, data);
             let percentage = if capacity_hours > 0.0 {
                 ((logged_hours / capacity_hours * 100.0).min(100.0).round()) as u16
@@ -400,17 +344,160 @@ fn render_sprint_details_with_activity(
     sprint: &Sprint,
     data: &TuiData,
 ) {
-    // Split vertically: details on top, activity on bottom
+    // Split vertically: details on top, burn chart in the middle, activity on bottom
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(14), // Sprint details
+            Constraint::Min(10),    // Burn-up chart
             Constraint::Min(5),     // Activity graph
         ])
         .split(*area);
 
     render_sprint_details(frame, &chunks[0], sprint, data);
-    render_sprint_activity_compact(frame, &chunks[1], sprint, data);
+    render_sprint_burn_chart(frame, &chunks[1], sprint, data);
+    if data.ui_state.show_sprint_issues {
+        render_sprint_issue_breakdown(frame, &chunks[2], sprint, data);
+    } else {
+        render_sprint_activity_compact(frame, &chunks[2], sprint, data);
+    }
+}
+
+fn render_sprint_issue_breakdown(frame: &mut Frame, area: &Rect, sprint: &Sprint, data: &TuiData) {
+    let block = Block::default()
+        .title("🎫 Time per Issue (local / Jira)")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().border))
+        .style(Style::default().bg(theme().bg_primary));
+
+    // Same date range as the activity graph
+    let range = data
+        .sprint_activities
+        .get(&sprint.id)
+        .and_then(|days| Some((days.first()?.date, days.last()?.date)));
+    let breakdown = range
+        .map(|(start, end)| issue_breakdown(&data.all_worklogs, &data.jira_worklogs, start, end))
+        .unwrap_or_default();
+
+    if breakdown.is_empty() {
+        let content = Paragraph::new("No time logged")
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(content, *area);
+        return;
+    }
+
+    let summary_width = (area.width as usize).saturating_sub(44);
+    let lines: Vec<Line> = breakdown
+        .iter()
+        .map(|hours| {
+            let summary = data
+                .issues_by_key
+                .get(&hours.issue)
+                .map(|i| i.summary.as_str())
+                .unwrap_or("");
+            Line::from(vec![
+                Span::styled(
+                    format!("{:>6.1}h ", hours.total()),
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{:<10} ", hours.issue),
+                    Style::default().fg(Color::Green),
+                ),
+                Span::styled(
+                    format!("{:>5.1}h", hours.local_hours),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(" / ", Style::default().fg(Color::DarkGray)),
+                Span::styled(
+                    format!("{:>5.1}h  ", hours.jira_hours),
+                    Style::default().fg(Color::White),
+                ),
+                Span::styled(
+                    truncate_string(summary, summary_width),
+                    Style::default().fg(Color::Gray),
+                ),
+            ])
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines).block(block), *area);
+}
+
+fn render_sprint_burn_chart(frame: &mut Frame, area: &Rect, sprint: &Sprint, data: &TuiData) {
+    let activities = data
+        .sprint_activities
+        .get(&sprint.id)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let series = BurnSeries::compute(activities, &data.config.worklog, Local::now().date_naive());
+
+    let block = Block::default()
+        .title("📉 Burn-up")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme().border))
+        .style(Style::default().bg(theme().bg_primary));
+
+    if activities.is_empty() {
+        let content = Paragraph::new("No dates")
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(content, *area);
+        return;
+    }
+
+    // More than a full day of hours behind the ideal line is red
+    let delta = series.delta();
+    let actual_color = if delta < -data.daily_hours_limit {
+        Color::Red
+    } else if delta < 0.0 {
+        Color::Yellow
+    } else {
+        Color::Green
+    };
+
+    let datasets = vec![
+        Dataset::default()
+            .name("Ideal")
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::DarkGray))
+            .data(&series.ideal),
+        Dataset::default()
+            .name(format!("Logged ({:+.1}h)", delta))
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(actual_color))
+            .data(&series.actual),
+    ];
+
+    let first = activities
+        .first()
+        .map(|a| a.date.format("%d %b").to_string());
+    let last = activities
+        .last()
+        .map(|a| a.date.format("%d %b").to_string());
+    let max_hours = series.max_hours().max(1.0);
+    let label_style = Style::default().fg(Color::Gray);
+
+    let chart = Chart::new(datasets)
+        .block(block)
+        .x_axis(
+            Axis::default()
+                .style(label_style)
+                .bounds([0.0, series.days()])
+                .labels([first.unwrap_or_default(), last.unwrap_or_default()]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(label_style)
+                .bounds([0.0, max_hours])
+                .labels(["0h".to_string(), format!("{:.0}h", max_hours)]),
+        );
+    frame.render_widget(chart, *area);
 }
 
 fn render_sprint_details(frame: &mut Frame, area: &Rect, sprint: &Sprint, data: &TuiData) {
@@ -423,7 +510,7 @@ fn render_sprint_details(frame: &mut Frame, area: &Rect, sprint: &Sprint, data:
     let inner = block.inner(*area);
     frame.render_widget(block, *area);
 
-    let capacity_hours = calculate_sprint_capacity(sprint.id, data) as f64 * data.daily_hours_limit;
+    let capacity_hours = calculate_sprint_capacity_hours(sprint.id, data);
     let logged_hours = calculate_sprint_logged_hours(sprint.id, data);
     let remaining_hours = (capacity_hours - logged_hours).max(0.0);
     let percentage = if capacity_hours > 0.0 {
@@ -462,9 +549,8 @@ fn render_sprint_details(frame: &mut Frame, area: &Rect, sprint: &Sprint, data:
                     activities
                         .iter()
                         .filter(|a| {
-                            use chrono::Datelike;
                             !a.is_absence
-                                && a.date.weekday().num_days_from_monday() < 5
+                                && data.config.worklog.is_workday(a.date)
                                 && a.hours > 0.0
                                 && a.date <= today
                         })
@@ -539,12 +625,12 @@ fn render_sprint_details(frame: &mut Frame, area: &Rect, sprint: &Sprint, data:
         Line::from(vec![
             Span::raw("Progress: "),
             Span::styled(
-                format!("{:.1}h", logged_hours),
+                format_hours(logged_hours),
                 Style::default().fg(Color::Green),
             ),
             Span::raw(" / "),
             Span::styled(
-                format!("{:.1}h", capacity_hours),
+                format_hours(capacity_hours),
                 Style::default().fg(Color::White),
             ),
             Span::raw(format!("  ({:.0}%)", percentage)),
@@ -572,7 +658,7 @@ fn render_sprint_details(frame: &mut Frame, area: &Rect, sprint: &Sprint, data:
     lines.push(Line::from(vec![
         Span::raw("Remaining: "),
         Span::styled(
-            format!("{:.1}h", remaining_hours),
+            format_hours(remaining_hours),
             Style::default().fg(Color::Yellow),
         ),
         Span::raw(format!("  ({:.0}%)", 100.0 - percentage)),
@@ -721,18 +807,31 @@ fn get_activity_color(hours: f64) -> Style {
 // Helper functions
 
 fn calculate_sprint_capacity(sprint_id: usize, data: &TuiData) -> usize {
-    use chrono::Datelike;
     data.sprint_activities
         .get(&sprint_id)
         .map(|activities| {
             activities
                 .iter()
-                .filter(|a| !a.is_absence && a.date.weekday().num_days_from_monday() < 5)
+                .filter(|a| !a.is_absence && data.config.worklog.is_workday(a.date))
                 .count()
         })
         .unwrap_or(0)
 }
 
+/// Working hours of the sprint's days, following the per-weekday targets
+fn calculate_sprint_capacity_hours(sprint_id: usize, data: &TuiData) -> f64 {
+    data.sprint_activities
+        .get(&sprint_id)
+        .map(|activities| {
+            activities
+                .iter()
+                .filter(|a| !a.is_absence)
+                .map(|a| data.config.worklog.target_hours(a.date))
+                .sum()
+        })
+        .unwrap_or(0.0)
+}
+
 fn calculate_sprint_logged_hours(sprint_id: usize, data: &TuiData) -> f64 {
     use chrono::Datelike;
     let h = data
@@ -9,31 +9,56 @@ use ratatui::{
 
 use crate::tui::data::TuiData;
 use crate::tui::helpers;
+use crate::tui::sorting::SortKey;
 use crate::tui::tab_controller::TabController;
 use crate::tui::theme::theme;
 use crate::tui::ui_helpers::*;
+use crate::tui::worklog_filter::{FilterEditorAction, WorklogFilterEditor};
 use crate::tui::Tui;
+use wtf_lib::duration::format_hours;
 use wtf_lib::models::data::LocalWorklog;
 use wtf_lib::models::data::LocalWorklogState;
 
 #[derive(Debug, Clone, Copy, Default)]
 pub(in crate::tui) struct WorklogsTab;
 
+impl SortKey for LocalWorklog {
+    fn sort_date(&self) -> chrono::DateTime<chrono::Utc> {
+        self.started
+    }
+
+    fn sort_issue(&self) -> Option<&str> {
+        Some(self.issue_id.as_str())
+    }
+
+    fn sort_duration(&self) -> i64 {
+        self.time_spent_seconds
+    }
+}
+
 pub(in crate::tui) fn visible_worklogs(data: &TuiData) -> Vec<LocalWorklog> {
     let mut sorted_worklogs = data.all_worklogs.clone();
-    sorted_worklogs.sort_by(|a, b| b.started.cmp(&a.started));
-
-    if data.ui_state.filter_staged_only {
-        sorted_worklogs
-            .into_iter()
-            .filter(|worklog| {
-                worklog.status == LocalWorklogState::Staged
-                    || worklog.status == LocalWorklogState::Created
-            })
-            .collect()
-    } else {
-        sorted_worklogs
-    }
+    data.ui_state
+        .worklog_sort
+        .sort(&mut sorted_worklogs, |worklog| match worklog.status {
+            LocalWorklogState::Created => 0,
+            LocalWorklogState::Staged => 1,
+            LocalWorklogState::Pushed => 2,
+        });
+
+    sorted_worklogs
+        .into_iter()
+        .filter(|worklog| {
+            !data.ui_state.filter_staged_only
+                || worklog.status == LocalWorklogState::Staged
+                || worklog.status == LocalWorklogState::Created
+        })
+        .filter(|worklog| {
+            data.ui_state
+                .worklog_filter
+                .matches(worklog, &data.github_sessions)
+        })
+        .collect()
 }
 
 impl TabController for WorklogsTab {
@@ -42,6 +67,20 @@ impl TabController for WorklogsTab {
     }
 
     fn handle_key(&self, tui: &mut Tui, key: KeyEvent) {
+        // Filter popup: all input goes to the editor
+        if let Some(editor) = &mut tui.data.ui_state.worklog_filter_editor {
+            match editor.handle_key(key) {
+                FilterEditorAction::None => {}
+                FilterEditorAction::Cancel => tui.data.ui_state.worklog_filter_editor = None,
+                FilterEditorAction::Apply(filter) => {
+                    tui.data.ui_state.worklog_filter = filter;
+                    tui.data.ui_state.worklog_filter_editor = None;
+                    tui.data.ui_state.selected_worklog_index = 0;
+                }
+            }
+            return;
+        }
+
         let worklogs = visible_worklogs(&tui.data);
         let max_index = worklogs.len().saturating_sub(1);
 
@@ -64,6 +103,19 @@ impl TabController for WorklogsTab {
                 tui.data.ui_state.filter_staged_only = !tui.data.ui_state.filter_staged_only;
                 tui.data.ui_state.selected_worklog_index = 0;
             }
+            KeyCode::Char('/') => {
+                tui.data.ui_state.worklog_filter_editor = Some(WorklogFilterEditor::from_filter(
+                    &tui.data.ui_state.worklog_filter,
+                ));
+            }
+            KeyCode::Esc if tui.data.ui_state.worklog_filter.is_active() => {
+                tui.data.ui_state.worklog_filter = Default::default();
+                tui.data.ui_state.selected_worklog_index = 0;
+            }
+            KeyCode::Char('o') | KeyCode::Char('O') => {
+                tui.data.ui_state.worklog_sort = tui.data.ui_state.worklog_sort.next();
+                tui.data.ui_state.selected_worklog_index = 0;
+            }
             KeyCode::Char('a') | KeyCode::Char('A') => {
                 if key.modifiers.contains(event::KeyModifiers::CONTROL) {
                     tui.handle_stage_all_worklogs();
@@ -100,6 +152,7 @@ pub(in crate::tui) fn render_worklogs_tab(frame: &mut Frame, area: &Rect, data:
     render_list_detail_layout(
         frame,
         area,
+        data.ui_state.split_layout,
         |f, a| render_worklogs_list(f, a, data, &worklogs, selected_index),
         |f, a| render_worklog_details(f, a, data, &worklogs, selected_index),
     );
@@ -114,11 +167,17 @@ fn render_worklogs_list(
 ) {
     use chrono::{Datelike, Timelike};
 
-    let filter_text = if data.ui_state.filter_staged_only {
-        " [FILTERED: Unpushed Only]"
+    let mut filter_text = if data.ui_state.filter_staged_only {
+        " [FILTERED: Unpushed Only]".to_string()
     } else {
-        ""
+        String::new()
     };
+    if data.ui_state.worklog_filter.is_active() {
+        filter_text.push_str(&format!(
+            " [Filter: {}]",
+            data.ui_state.worklog_filter.summary()
+        ));
+    }
 
     let shortcuts_data = vec![
         ("A", " Stage/Unstage"),
@@ -127,6 +186,8 @@ fn render_worklogs_list(
         ("Del", " Delete"),
         ("X", " Reset"),
         ("F", "ilter"),
+        ("/", " Filter…"),
+        ("O", "rder"),
     ];
     let shortcuts = build_shortcut_help(&shortcuts_data);
 
@@ -135,6 +196,7 @@ fn render_worklogs_list(
         Span::raw(worklogs.len().to_string()),
         Span::raw(")"),
         Span::raw(filter_text),
+        Span::raw(format!(" [Sort: {}]", data.ui_state.worklog_sort.label())),
         Span::raw(" | "),
     ];
     title_spans.extend(shortcuts);
@@ -161,14 +223,11 @@ fn render_worklogs_list(
         return;
     }
 
+    let color_blind = data.config.ui.color_blind_palette;
     let items: Vec<ListItem> = worklogs
         .iter()
         .map(|worklog| {
-            let (status_icon, status_color) = match worklog.status {
-                LocalWorklogState::Staged => ("●", Color::Yellow),
-                LocalWorklogState::Pushed => ("✓", Color::Green),
-                LocalWorklogState::Created => ("○", Color::Gray),
-            };
+            let status = worklog_status_style(&worklog.status, color_blind);
 
             let date_str = format!(
                 "{}-{:02}-{:02}",
@@ -197,9 +256,9 @@ fn render_worklogs_list(
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(
-                    status_icon,
+                    format!("{} {}", status.icon, status.badge),
                     Style::default()
-                        .fg(status_color)
+                        .fg(status.color)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::raw(" "),
@@ -338,7 +397,7 @@ fn render_daily_summary(frame: &mut Frame, area: &Rect, data: &TuiData) {
             ),
             Span::raw("  ("),
             Span::styled(
-                format!("{:.1}h", pushed_hours),
+                format_hours(pushed_hours),
                 Style::default().fg(Color::Green),
             ),
             Span::raw(" already)  "),
@@ -362,7 +421,7 @@ fn render_daily_summary(frame: &mut Frame, area: &Rect, data: &TuiData) {
     lines.push(Line::from(vec![
         Span::raw("Total to push: "),
         Span::styled(
-            format!("{:.1}h", total_staged_hours),
+            format_hours(total_staged_hours),
             Style::default()
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
@@ -439,11 +498,7 @@ fn render_selected_worklog_info(
     };
 
     // Build condensed details
-    let status_text = match worklog.status {
-        LocalWorklogState::Created => ("Created", Color::Gray),
-        LocalWorklogState::Staged => ("Staged", Color::Yellow),
-        LocalWorklogState::Pushed => ("Pushed", Color::Green),
-    };
+    let status = worklog_status_style(&worklog.status, data.config.ui.color_blind_palette);
 
     let date_str = format!(
         "{}-{:02}-{:02}",
@@ -475,15 +530,15 @@ fn render_selected_worklog_info(
     let mut lines = vec![
         Line::from(vec![
             Span::styled(
-                status_text.0,
+                format!("{} {}", status.icon, status.label),
                 Style::default()
-                    .fg(status_text.1)
+                    .fg(status.color)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::raw(" • "),
             Span::styled(&worklog.issue_id, Style::default().fg(Color::Cyan)),
             Span::raw(" • "),
-            Span::styled(format!("{:.1}h", hours), Style::default().fg(Color::Yellow)),
+            Span::styled(format_hours(hours), Style::default().fg(Color::Yellow)),
         ]),
         Line::from(vec![Span::styled(
             format!("{} at {}", date_str, time_str),
@@ -585,11 +640,7 @@ fn render_worklog_details(
     };
 
     // Build details
-    let status_text = match worklog.status {
-        LocalWorklogState::Created => ("Created", Color::Gray),
-        LocalWorklogState::Staged => ("Staged", Color::Yellow),
-        LocalWorklogState::Pushed => ("Pushed", Color::Green),
-    };
+    let status = worklog_status_style(&worklog.status, data.config.ui.color_blind_palette);
 
     let date_str = format!(
         "{}-{:02}-{:02}",
@@ -618,9 +669,9 @@ fn render_worklog_details(
         Line::from(vec![
             Span::raw("Status: "),
             Span::styled(
-                status_text.0,
+                format!("{} {}", status.icon, status.label),
                 Style::default()
-                    .fg(status_text.1)
+                    .fg(status.color)
                     .add_modifier(Modifier::BOLD),
             ),
         ]),
@@ -638,7 +689,7 @@ fn render_worklog_details(
         ]),
         Line::from(vec![
             Span::raw("Time: "),
-            Span::styled(format!("{:.1}h", hours), Style::default().fg(Color::Yellow)),
+            Span::styled(format_hours(hours), Style::default().fg(Color::Yellow)),
         ]),
         Line::from(""),
         Line::from(vec![Span::raw("Comment:")]),
@@ -1,26 +1,82 @@
+use chrono::{Datelike, NaiveDate};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::Span,
     Frame,
 };
+use serde::{Deserialize, Serialize};
+use wtf_lib::models::data::LocalWorklogState;
+
+/// Width of the list in list/detail tabs, adjusted with Ctrl+Left/Right
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SplitLayout {
+    pub list_percent: u16,
+    pub details_collapsed: bool,
+}
+
+impl SplitLayout {
+    const MIN_LIST_PERCENT: u16 = 30;
+    const MAX_LIST_PERCENT: u16 = 80;
+    const STEP: u16 = 5;
+
+    /// Grow the list; past the maximum the details pane collapses
+    pub fn widen(&mut self) {
+        if self.list_percent >= Self::MAX_LIST_PERCENT {
+            self.details_collapsed = true;
+        } else {
+            self.list_percent = (self.list_percent + Self::STEP).min(Self::MAX_LIST_PERCENT);
+        }
+    }
+
+    /// Shrink the list, bringing a collapsed details pane back first
+    pub fn narrow(&mut self) {
+        if self.details_collapsed {
+            self.details_collapsed = false;
+        } else {
+            self.list_percent = self
+                .list_percent
+                .saturating_sub(Self::STEP)
+                .max(Self::MIN_LIST_PERCENT);
+        }
+    }
+}
+
+impl Default for SplitLayout {
+    fn default() -> Self {
+        Self {
+            list_percent: 60,
+            details_collapsed: false,
+        }
+    }
+}
 
-/// Render a tab with list on left (60%) and details on right (40%)
+/// Render a tab with list on left and details on right (60/40 unless resized)
 /// This is a common pattern used across multiple tabs
 pub(super) fn render_list_detail_layout<L, D>(
     frame: &mut Frame,
     area: &Rect,
+    layout: SplitLayout,
     render_list: L,
     render_details: D,
 ) where
     L: FnOnce(&mut Frame, &Rect),
     D: FnOnce(&mut Frame, &Rect),
 {
+    if layout.details_collapsed {
+        render_list(frame, area);
+        return;
+    }
+
+    // Clamped again here since the ratio is restored from the saved UI state
+    let list_percent = layout
+        .list_percent
+        .clamp(SplitLayout::MIN_LIST_PERCENT, SplitLayout::MAX_LIST_PERCENT);
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Percentage(60), // List
-            Constraint::Percentage(40), // Details
+            Constraint::Percentage(list_percent),       // List
+            Constraint::Percentage(100 - list_percent), // Details
         ])
         .split(*area);
 
@@ -28,6 +84,38 @@ pub(super) fn render_list_detail_layout<L, D>(
     render_details(frame, &chunks[1]);
 }
 
+/// How a worklog status is drawn: icon, short text badge, full label and color
+pub(super) struct StatusStyle {
+    pub icon: &'static str,
+    pub badge: &'static str,
+    pub label: &'static str,
+    pub color: Color,
+}
+
+/// Style for a worklog status. Icon shape and badge already tell the statuses
+/// apart; `color_blind` swaps yellow/green for orange/sky blue, which stay
+/// distinct for red-green color blindness.
+pub(super) fn worklog_status_style(status: &LocalWorklogState, color_blind: bool) -> StatusStyle {
+    let (icon, badge, label) = match status {
+        LocalWorklogState::Created => ("○", "[C]", "Created"),
+        LocalWorklogState::Staged => ("●", "[S]", "Staged"),
+        LocalWorklogState::Pushed => ("✓", "[P]", "Pushed"),
+    };
+    let color = match (status, color_blind) {
+        (LocalWorklogState::Created, _) => Color::Gray,
+        (LocalWorklogState::Staged, false) => Color::Yellow,
+        (LocalWorklogState::Pushed, false) => Color::Green,
+        (LocalWorklogState::Staged, true) => Color::Rgb(230, 159, 0),
+        (LocalWorklogState::Pushed, true) => Color::Rgb(86, 180, 233),
+    };
+    StatusStyle {
+        icon,
+        badge,
+        label,
+        color,
+    }
+}
+
 /// Helper function to truncate strings with ellipsis
 pub(super) fn truncate_string(s: &str, max_len: usize) -> String {
     if s.chars().count() <= max_len {
@@ -258,3 +346,120 @@ pub(super) fn clean_and_strip_html(text: &str) -> String {
         .collect::<Vec<_>>()
         .join("")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_worklog_status_badges_differ_without_color() {
+        let states = [
+            LocalWorklogState::Created,
+            LocalWorklogState::Staged,
+            LocalWorklogState::Pushed,
+        ];
+        let badges: Vec<_> = states
+            .iter()
+            .map(|s| worklog_status_style(s, false).badge)
+            .collect();
+        assert_eq!(badges, vec!["[C]", "[S]", "[P]"]);
+
+        let staged = worklog_status_style(&LocalWorklogState::Staged, true);
+        let pushed = worklog_status_style(&LocalWorklogState::Pushed, true);
+        assert_ne!(staged.color, Color::Yellow);
+        assert_ne!(pushed.color, Color::Green);
+    }
+
+    #[test]
+    fn test_split_layout_collapses_past_max_and_restores() {
+        let mut layout = SplitLayout {
+            list_percent: 75,
+            details_collapsed: false,
+        };
+
+        layout.widen();
+        assert_eq!(layout.list_percent, 80);
+        layout.widen();
+        assert!(layout.details_collapsed);
+
+        layout.narrow();
+        assert!(!layout.details_collapsed);
+        assert_eq!(layout.list_percent, 80);
+
+        for _ in 0..20 {
+            layout.narrow();
+        }
+        assert_eq!(layout.list_percent, 30);
+    }
+}
+
+/// A cell of an activity wall
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum WallCell<T> {
+    Day(T),
+    Blank,
+    /// Vertical line where the year changes
+    YearSeparator,
+}
+
+/// Lay out consecutive `days`, starting on a Monday, as Mon-Fri rows with a column per
+/// week. When the year changes, the rows are padded, a separator column is added, then
+/// the new year continues from the same weekday.
+pub(super) fn weekday_wall<T: Copy>(days: &[(NaiveDate, T)]) -> Vec<Vec<WallCell<T>>> {
+    fn pad<T: Copy>(rows: &mut [Vec<WallCell<T>>], len: usize) {
+        for row in rows {
+            while row.len() < len {
+                row.push(WallCell::Blank);
+            }
+        }
+    }
+    let width = |grid: &[Vec<WallCell<T>>]| grid.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    let mut grid: Vec<Vec<WallCell<T>>> = vec![vec![]; 5];
+    let mut prev_year: Option<i32> = None;
+
+    for (day_index, (date, value)) in days.iter().enumerate() {
+        let weekday = day_index % 7;
+        // Only Mon-Fri (weekdays 0-4)
+        if weekday >= 5 {
+            continue;
+        }
+        if prev_year.is_some_and(|prev| prev != date.year()) {
+            let len = width(&grid);
+            pad(&mut grid, len);
+            for row in &mut grid {
+                row.push(WallCell::YearSeparator);
+            }
+            // If the year changes on Thursday, Mon-Wed get an empty cell
+            let len = width(&grid) + 1;
+            pad(&mut grid[..weekday], len);
+        }
+        prev_year = Some(date.year());
+        grid[weekday].push(WallCell::Day(*value));
+    }
+
+    let len = width(&grid);
+    pad(&mut grid, len);
+    grid
+}
+
+/// Convert hours to braille character based on density
+pub(super) fn hours_to_braille(hours: f64, daily_limit: f64) -> &'static str {
+    if hours == 0.0 {
+        "⠀" // Empty
+    } else if hours >= daily_limit {
+        "⣿" // Full square for >= daily limit (100%)
+    } else {
+        let percentage = (hours / daily_limit * 100.0).min(100.0);
+        match percentage as u32 {
+            0..=12 => "⢀",  // 1 dot (minimum for any activity)
+            13..=25 => "⢠", // 2 dots
+            26..=37 => "⢰", // 3 dots
+            38..=50 => "⢸", // 4 dots left
+            51..=62 => "⣀", // 4 dots bottom
+            63..=75 => "⣠", // 5 dots
+            76..=87 => "⣰", // 6 dots
+            _ => "⣸",       // 7 dots
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashSet};
+use wtf_lib::services::ui_state_service::UiStateService;
+
+use crate::tui::data::TabUiState;
+use crate::tui::sorting::ListSort;
+use crate::tui::types::Tab;
+use crate::tui::ui_helpers::SplitLayout;
+use crate::tui::worklog_filter::WorklogFilter;
+
+/// Storage key of the TUI state
+const UI_STATE_KEY: &str = "tui";
+
+/// The part of the UI state kept between runs. Popups, edit buffers and caches
+/// are left out on purpose. Missing fields fall back to their defaults so older
+/// saves keep loading as the struct grows.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PersistedUiState {
+    pub tab: Option<Tab>,
+    pub selected_sprint_index: usize,
+    pub show_sprint_issues: bool,
+    pub split_layout: SplitLayout,
+    pub selected_meeting_index: usize,
+    pub selected_worklog_index: usize,
+    pub selected_github_session_index: usize,
+    pub selected_history_index: usize,
+    pub expanded_history_ids: HashSet<String>,
+    pub filter_unlinked_only: bool,
+    pub filter_staged_only: bool,
+    pub worklog_filter: WorklogFilter,
+    pub worklog_sort: ListSort,
+    pub meeting_sort: ListSort,
+    pub github_sort: ListSort,
+    pub meeting_search_query: String,
+    pub meeting_color_filter: Option<String>,
+    pub meeting_calendar_filter: Option<String>,
+    pub achievements_scroll_offset: usize,
+    pub selected_issue_index: usize,
+    pub favorite_issues: BTreeSet<String>,
+    pub favorite_issues_only: bool,
+    pub issues_by_epic: bool,
+    pub wizard_skipped_steps: BTreeSet<usize>,
+}
+
+/// Tab the previous run ended on, or Sprints when it is gone (achievements still locked)
+pub fn take_restored_tab(ui_state: &mut TabUiState, has_achievements: bool) -> Tab {
+    ui_state
+        .restored_tab
+        .take()
+        .filter(|tab| Tab::available_tabs(has_achievements).contains(tab))
+        .unwrap_or(Tab::Sprints)
+}
+
+impl PersistedUiState {
+    pub fn capture(ui_state: &TabUiState, tab: Tab) -> Self {
+        Self {
+            tab: Some(tab),
+            selected_sprint_index: ui_state.selected_sprint_index,
+            show_sprint_issues: ui_state.show_sprint_issues,
+            split_layout: ui_state.split_layout,
+            selected_meeting_index: ui_state.selected_meeting_index,
+            selected_worklog_index: ui_state.selected_worklog_index,
+            selected_github_session_index: ui_state.selected_github_session_index,
+            selected_history_index: ui_state.selected_history_index,
+            expanded_history_ids: ui_state.expanded_history_ids.clone(),
+            filter_unlinked_only: ui_state.filter_unlinked_only,
+            filter_staged_only: ui_state.filter_staged_only,
+            worklog_filter: ui_state.worklog_filter.clone(),
+            worklog_sort: ui_state.worklog_sort,
+            meeting_sort: ui_state.meeting_sort,
+            github_sort: ui_state.github_sort,
+            meeting_search_query: ui_state.meeting_search_query.clone(),
+            meeting_color_filter: ui_state.meeting_color_filter.clone(),
+            meeting_calendar_filter: ui_state.meeting_calendar_filter.clone(),
+            achievements_scroll_offset: ui_state.achievements_scroll_offset,
+            selected_issue_index: ui_state.selected_issue_index,
+            favorite_issues: ui_state.favorite_issues.clone(),
+            favorite_issues_only: ui_state.favorite_issues_only,
+            issues_by_epic: ui_state.issues_by_epic,
+            wizard_skipped_steps: ui_state.wizard_skipped_steps.clone(),
+        }
+    }
+
+    pub fn into_ui_state(self) -> TabUiState {
+        TabUiState {
+            restored_tab: self.tab,
+            selected_sprint_index: self.selected_sprint_index,
+            show_sprint_issues: self.show_sprint_issues,
+            split_layout: self.split_layout,
+            selected_meeting_index: self.selected_meeting_index,
+            selected_worklog_index: self.selected_worklog_index,
+            selected_github_session_index: self.selected_github_session_index,
+            selected_history_index: self.selected_history_index,
+            expanded_history_ids: self.expanded_history_ids,
+            filter_unlinked_only: self.filter_unlinked_only,
+            filter_staged_only: self.filter_staged_only,
+            worklog_filter: self.worklog_filter,
+            worklog_sort: self.worklog_sort,
+            meeting_sort: self.meeting_sort,
+            github_sort: self.github_sort,
+            meeting_search_query: self.meeting_search_query,
+            meeting_color_filter: self.meeting_color_filter,
+            meeting_calendar_filter: self.meeting_calendar_filter,
+            achievements_scroll_offset: self.achievements_scroll_offset,
+            selected_issue_index: self.selected_issue_index,
+            favorite_issues: self.favorite_issues,
+            favorite_issues_only: self.favorite_issues_only,
+            issues_by_epic: self.issues_by_epic,
+            wizard_skipped_steps: self.wizard_skipped_steps,
+            ..TabUiState::default()
+        }
+    }
+
+    /// Load the state saved by the previous run (defaults if none or unreadable)
+    pub fn load() -> Self {
+        UiStateService::production()
+            .load(UI_STATE_KEY)
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        match serde_json::to_string(self) {
+            Ok(json) => UiStateService::production().save(UI_STATE_KEY, json),
+            Err(e) => log::error!("Failed to serialize UI state: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wtf_lib::models::data::LocalWorklogState;
+
+    #[test]
+    fn test_round_trip_keeps_persisted_fields_only() {
+        let mut ui_state = TabUiState {
+            selected_meeting_index: 4,
+            meeting_sort: ListSort::Duration,
+            filter_staged_only: true,
+            meeting_search_active: true,
+            worklog_filter: WorklogFilter {
+                issue: "PROJ".to_string(),
+                status: Some(LocalWorklogState::Staged),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        ui_state.expanded_history_ids.insert("h1".to_string());
+
+        let json =
+            serde_json::to_string(&PersistedUiState::capture(&ui_state, Tab::Meetings)).unwrap();
+        let restored = serde_json::from_str::<PersistedUiState>(&json)
+            .unwrap()
+            .into_ui_state();
+
+        assert_eq!(restored.restored_tab, Some(Tab::Meetings));
+        assert_eq!(restored.selected_meeting_index, 4);
+        assert_eq!(restored.meeting_sort, ListSort::Duration);
+        assert!(restored.filter_staged_only);
+        assert_eq!(restored.worklog_filter, ui_state.worklog_filter);
+        assert!(restored.expanded_history_ids.contains("h1"));
+        assert!(!restored.meeting_search_active);
+    }
+
+    #[test]
+    fn test_missing_fields_use_defaults() {
+        let state: PersistedUiState = serde_json::from_str(r#"{"tab":"GitHub"}"#).unwrap();
+
+        assert_eq!(state.tab, Some(Tab::GitHub));
+        assert_eq!(state.github_sort, ListSort::DateDesc);
+    }
+
+    #[test]
+    fn test_restored_tab_falls_back_to_sprints_when_unavailable() {
+        let mut ui_state = PersistedUiState {
+            tab: Some(Tab::Logs),
+            ..Default::default()
+        }
+        .into_ui_state();
+        assert_eq!(take_restored_tab(&mut ui_state, false), Tab::Logs);
+        assert_eq!(ui_state.restored_tab, None);
+
+        ui_state.restored_tab = Some(Tab::Achievements);
+        assert_eq!(take_restored_tab(&mut ui_state, false), Tab::Sprints);
+        ui_state.restored_tab = Some(Tab::Achievements);
+        assert_eq!(take_restored_tab(&mut ui_state, true), Tab::Achievements);
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::VecDeque;
+
+use wtf_lib::models::data::{LocalWorklog, LocalWorklogState};
+
+/// How many actions Ctrl+Z can walk back
+const UNDO_LIMIT: usize = 20;
+
+/// A local change that can be reverted from the TUI, holding what is needed to restore
+/// the state from before it
+#[derive(Debug, Clone)]
+pub enum UndoableAction {
+    /// Meetings were linked or unlinked; keeps each meeting's previous link
+    MeetingLinks(Vec<(String, Option<String>)>),
+    /// Worklogs were staged or unstaged; keeps each worklog's previous status
+    WorklogStatuses(Vec<(String, LocalWorklogState)>),
+    /// Worklogs were deleted; keeps full copies so they can be saved back
+    WorklogsDeleted(Vec<LocalWorklog>),
+    /// Worklogs were created; undoing removes them as long as they are not pushed
+    WorklogsCreated(Vec<String>),
+}
+
+/// An action on the undo stack with the text shown when it gets undone
+#[derive(Debug, Clone)]
+pub struct UndoEntry {
+    pub description: String,
+    pub action: UndoableAction,
+}
+
+/// Most recent mutating actions, newest last; the oldest are dropped past [`UNDO_LIMIT`]
+#[derive(Debug, Default)]
+pub struct UndoStack {
+    entries: VecDeque<UndoEntry>,
+}
+
+impl UndoStack {
+    pub fn push(&mut self, description: impl Into<String>, action: UndoableAction) {
+        let is_empty = match &action {
+            UndoableAction::MeetingLinks(v) => v.is_empty(),
+            UndoableAction::WorklogStatuses(v) => v.is_empty(),
+            UndoableAction::WorklogsDeleted(v) => v.is_empty(),
+            UndoableAction::WorklogsCreated(v) => v.is_empty(),
+        };
+        if is_empty {
+            return;
+        }
+        if self.entries.len() == UNDO_LIMIT {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(UndoEntry {
+            description: description.into(),
+            action,
+        });
+    }
+
+    pub fn pop(&mut self) -> Option<UndoEntry> {
+        self.entries.pop_back()
+    }
+
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn created(id: &str) -> UndoableAction {
+        UndoableAction::WorklogsCreated(vec![id.to_string()])
+    }
+
+    #[test]
+    fn test_pop_returns_most_recent_first() {
+        let mut stack = UndoStack::default();
+        stack.push("first", created("a"));
+        stack.push("second", created("b"));
+
+        assert_eq!(stack.pop().unwrap().description, "second");
+        assert_eq!(stack.pop().unwrap().description, "first");
+        assert!(stack.pop().is_none());
+    }
+
+    #[test]
+    fn test_empty_actions_are_not_recorded() {
+        let mut stack = UndoStack::default();
+        stack.push(
+            "nothing staged",
+            UndoableAction::WorklogStatuses(Vec::new()),
+        );
+        stack.push(
+            "nothing deleted",
+            UndoableAction::WorklogsDeleted(Vec::new()),
+        );
+
+        assert_eq!(stack.len(), 0);
+    }
+
+    #[test]
+    fn test_oldest_entries_are_dropped_past_the_limit() {
+        let mut stack = UndoStack::default();
+        for i in 0..UNDO_LIMIT + 5 {
+            stack.push(format!("action {}", i), created(&i.to_string()));
+        }
+
+        assert_eq!(stack.len(), UNDO_LIMIT);
+        let mut last = None;
+        while let Some(entry) = stack.pop() {
+            last = Some(entry.description);
+        }
+        assert_eq!(last.as_deref(), Some("action 5"));
+    }
+}
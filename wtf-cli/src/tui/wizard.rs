@@ -2,66 +2,65 @@
 
 use std::collections::HashMap;
 
-use chrono::TimeZone;
-use chrono::Utc;
+use crossterm::event::{KeyCode, KeyEvent};
+use wtf_lib::config::OverlapPolicy;
 use wtf_lib::models::data::LocalWorklogState;
+use wtf_lib::services::audit_service;
 use wtf_lib::services::meetings_service::MeetingsService;
 use wtf_lib::services::worklogs_service::LocalWorklogService;
+use wtf_lib::utils::meeting_overlaps::{resolve, settle_overlaps, MeetingSlot};
+use wtf_lib::utils::period::Period;
 
 use crate::logger;
+use crate::tasks::stage_worklogs;
+use crate::tui::ui_persistence::PersistedUiState;
 
+use super::helpers::favorites_first;
 use super::types::*;
 use super::{get_branding_text, Tui};
 
 impl Tui {
     pub(super) fn launch_wizard(&mut self) {
-        // Get the selected sprint
-        if let Some(sprint) = self
-            .data
-            .all_sprints
-            .get(self.data.ui_state.selected_sprint_index)
-        {
-            // Check if sprint has date range
-            if sprint.start.is_none() || sprint.end.is_none() {
-                logger::log("⚠️  Chronie says: Sprint has no date range!".to_string());
-                return;
-            }
-
-            // Check for existing unpushed worklogs within this sprint's date range
-            let existing_unpushed = if let (Some(start), Some(end)) = (sprint.start, sprint.end) {
-                LocalWorklogService::production()
-                    .get_unpushed_in_range(start.date_naive(), end.date_naive())
-            } else {
-                vec![]
-            };
-            if !existing_unpushed.is_empty() {
-                logger::log(format!(
-                    "⚠️  Found {} unpushed worklog(s) from a previous session",
-                    existing_unpushed.len()
-                ));
-                self.wizard_pre_launch_prompt = Some(WizardPreLaunchPrompt {
-                    existing_count: existing_unpushed.len(),
-                    sprint_id: sprint.id,
-                    sprint_name: sprint.name.clone(),
-                });
-                return;
-            }
+        let period = self.selected_period();
 
-            self.do_launch_wizard(sprint.id, &sprint.name.clone());
+        // Check for existing unpushed worklogs within the period
+        let existing_unpushed =
+            LocalWorklogService::production().get_unpushed_in_range(period.start, period.end);
+        if !existing_unpushed.is_empty() {
+            logger::log(format!(
+                "⚠️  Found {} unpushed worklog(s) from a previous session",
+                existing_unpushed.len()
+            ));
+            self.wizard_pre_launch_prompt = Some(WizardPreLaunchPrompt {
+                existing_count: existing_unpushed.len(),
+                period,
+            });
+            return;
         }
+
+        self.do_launch_wizard(period);
+    }
+
+    /// The selected sprint's days, or the configured week or month when no sprint with
+    /// dates is selected (Kanban teams)
+    pub(super) fn selected_period(&self) -> Period {
+        self.data
+            .all_sprints
+            .get(self.data.ui_state.selected_sprint_index)
+            .and_then(Period::of_sprint)
+            .unwrap_or_else(|| self.data.config.worklog.period())
     }
 
-    pub(super) fn do_launch_wizard(&mut self, sprint_id: usize, sprint_name: &str) {
+    pub(super) fn do_launch_wizard(&mut self, period: Period) {
         logger::log(format!(
             "🧙 Chronie is starting the wizard for {}...",
-            sprint_name
+            period
         ));
 
         // Initialize wizard state
         self.wizard_state = Some(WizardState {
-            sprint_id,
-            sprint_name: sprint_name.to_string(),
-            current_step: WizardStep::Syncing,
+            period,
+            current_step: WizardStep::Setup { selected_index: 0 },
             started_at: chrono::Utc::now(),
             completed_steps: std::collections::HashSet::new(),
             summary: WizardSummary::default(),
@@ -73,15 +72,188 @@ impl Tui {
             push_total: 0,
             startup_message: get_branding_text("startup"), // Set once at wizard start
             github_step_intro: None,
+            skipped_steps: self.data.ui_state.wizard_skipped_steps.clone(),
+            checkpoints: Vec::new(),
         });
 
-        // Start the first step (syncing)
-        self.wizard_step_sync();
+        // Steps run once the setup screen is confirmed
+        self.wizard_checkpoint(0);
+    }
+
+    pub(super) fn handle_wizard_setup_key(&mut self, key: KeyEvent) {
+        let Some(wizard) = &mut self.wizard_state else {
+            return;
+        };
+        let WizardStep::Setup { selected_index } = &mut wizard.current_step else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => *selected_index = selected_index.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                *selected_index = (*selected_index + 1).min(WIZARD_OPTIONAL_STEPS.len() - 1);
+            }
+            KeyCode::Char(' ') => {
+                let step = WIZARD_OPTIONAL_STEPS[*selected_index].0;
+                if !wizard.skipped_steps.remove(&step) {
+                    wizard.skipped_steps.insert(step);
+                }
+            }
+            KeyCode::Enter => {
+                wizard.current_step = WizardStep::Syncing;
+                self.data.ui_state.wizard_skipped_steps = wizard.skipped_steps.clone();
+                PersistedUiState::capture(&self.data.ui_state, self.current_tab).save();
+                self.wizard_step_sync();
+            }
+            KeyCode::Esc => {
+                // Nothing has been done yet, no rollback needed
+                logger::log("⚠️  Wizard launch aborted".to_string());
+                self.wizard_state = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// Record where an interactive step starts so `b` can come back to it
+    fn wizard_checkpoint(&mut self, step: usize) {
+        if let Some(wizard) = &mut self.wizard_state {
+            let checkpoint = WizardCheckpoint {
+                step,
+                linked_meetings: wizard.rollback_log.linked_meeting_ids.len(),
+                created_worklogs: wizard.rollback_log.created_worklog_ids.len(),
+                summary: wizard.summary.clone(),
+            };
+            wizard.checkpoints.push(checkpoint);
+        }
+    }
+
+    /// Whether `step` was turned off on the setup screen (marks it skipped if so)
+    fn wizard_skips(&mut self, step: usize) -> bool {
+        let Some(wizard) = &mut self.wizard_state else {
+            return false;
+        };
+        if !wizard.skipped_steps.contains(&step) {
+            return false;
+        }
+        wizard.completed_steps.insert(step);
+        wizard
+            .skip_reasons
+            .insert(step, "turned off in setup".to_string());
+        logger::log(format!(
+            "⏭️  Step {}/7: Turned off in setup, skipping",
+            step
+        ));
+        true
+    }
+
+    /// `b` is available on interactive steps unless a text field or a
+    /// confirmation popup of that step has the focus
+    fn wizard_can_go_back(&self) -> bool {
+        let Some(wizard) = &self.wizard_state else {
+            return false;
+        };
+        let interactive = matches!(
+            wizard.current_step,
+            WizardStep::ManualLinking { .. }
+                | WizardStep::CreatingGitHubWorklogs { .. }
+                | WizardStep::FillingGaps { .. }
+                | WizardStep::ReviewingWorklogs { .. }
+        );
+        let typing = self.issue_selection_state.is_some()
+            || self.worklog_creation_confirmation.is_some()
+            || self.gap_fill_confirmation.is_some()
+            || self
+                .gap_fill_state
+                .as_ref()
+                .is_some_and(|state| !state.search_query.is_empty());
+        interactive && !typing
+    }
+
+    /// Step back on `b` when the wizard allows it, `false` when the key is not taken
+    pub(super) fn dispatch_wizard_back_key(&mut self, key: KeyEvent) -> bool {
+        if key.code != KeyCode::Char('b') || !self.wizard_can_go_back() {
+            return false;
+        }
+        self.wizard_go_back();
+        true
+    }
+
+    /// Go back to the previous interactive step, undoing everything done since it started
+    fn wizard_go_back(&mut self) {
+        let Some(wizard) = &mut self.wizard_state else {
+            return;
+        };
+        if wizard.checkpoints.len() < 2 {
+            logger::log("ℹ️  Wizard: already at the first step".to_string());
+            return;
+        }
+        wizard.checkpoints.pop();
+        let Some(target) = wizard.checkpoints.pop() else {
+            return;
+        };
+
+        let undone = wizard
+            .rollback_log
+            .split_off(target.linked_meetings, target.created_worklogs);
+        wizard.summary = target.summary;
+        wizard.completed_steps.retain(|step| *step < target.step);
+        wizard.skip_reasons.retain(|step, _| *step < target.step);
+        wizard.github_step_intro = None;
+        self.gap_fill_state = None;
+        self.gap_fill_confirmation = None;
+        self.worklog_creation_confirmation = None;
+        self.issue_selection_state = None;
+
+        self.wizard_undo(&undone);
+        logger::log(format!(
+            "⏪ Wizard: back to step {} ({} meetings unlinked, {} worklogs deleted)",
+            target.step,
+            undone.linked_meeting_ids.len(),
+            undone.created_worklog_ids.len()
+        ));
+
+        match target.step {
+            0 => {
+                if let Some(wizard) = &mut self.wizard_state {
+                    wizard.current_step = WizardStep::Setup { selected_index: 0 };
+                }
+                self.wizard_checkpoint(0);
+                self.refresh_data();
+            }
+            2 => self.wizard_advance_to_manual_linking(),
+            4 => {
+                if let Some(wizard) = &mut self.wizard_state {
+                    wizard.current_step = WizardStep::CreatingGitHubWorklogs {
+                        sessions: vec![],
+                        current_session_index: 0,
+                    };
+                }
+                self.wizard_step_create_github_worklogs();
+            }
+            5 => {
+                if let Some(wizard) = &mut self.wizard_state {
+                    wizard.current_step = WizardStep::FillingGaps {
+                        selected_issue: None,
+                    };
+                }
+                self.wizard_step_fill_gaps();
+            }
+            _ => self.wizard_step_review(),
+        }
     }
 
     // Wizard step implementations
     pub(super) fn wizard_step_sync(&mut self) {
         const FRESHNESS_SECS: u64 = 300;
+        if self.wizard_skips(1) {
+            self.wizard_advance_to_manual_linking();
+            return;
+        }
+        if self.is_offline() {
+            logger::log("⏭️  Step 1/7: Offline, working on the cached data".to_string());
+            self.wizard_step_autolink();
+            return;
+        }
         if let Some(last) = self.last_fetch_completed_at {
             if last.elapsed().as_secs() < FRESHNESS_SECS {
                 let elapsed = last.elapsed().as_secs();
@@ -101,6 +273,7 @@ impl Tui {
 
     pub(super) fn wizard_step_autolink(&mut self) {
         use regex::Regex;
+        audit_service::set_source("wizard: Auto-link");
 
         logger::log("🔗 Step 1/7: Auto-linking meetings...".to_string());
 
@@ -108,14 +281,14 @@ impl Tui {
         let mut linked_count = 0;
 
         if let Some(wizard) = &mut self.wizard_state {
-            // Get all unlinked, non-untracked, non-declined meetings
+            // Get all unlinked, tracked and attended meetings
             let unlinked_meetings: Vec<_> = self
                 .data
                 .all_meetings
                 .iter()
                 .filter(|m| {
                     m.jira_link.is_none()
-                        && m.my_response_status.as_deref() != Some("declined")
+                        && self.data.config.meetings.attends(m)
                         && !wtf_lib::utils::meetings::is_untracked(
                             m,
                             &self.data.config,
@@ -171,33 +344,35 @@ impl Tui {
     }
 
     pub(super) fn wizard_advance_to_manual_linking(&mut self) {
+        if self.wizard_skips(2) {
+            if let Some(wizard) = &mut self.wizard_state {
+                wizard.current_step = WizardStep::CreatingMeetingWorklogs;
+            }
+            self.wizard_step_create_meeting_worklogs();
+            return;
+        }
+
         // Refresh data to see the newly linked meetings
         self.refresh_data();
 
-        let sprint_for_filter = self.wizard_state.as_ref().and_then(|w| {
-            self.data
-                .all_sprints
-                .iter()
-                .find(|s| s.id == w.sprint_id)
-                .cloned()
-        });
+        let period_for_filter = self.wizard_state.as_ref().map(|w| w.period.clone());
 
-        // Get unlinked, non-untracked, non-declined meetings filtered to the sprint's date range
+        // Get unlinked, tracked and attended meetings filtered to the period
         let unlinked_meetings: Vec<_> = self
             .data
             .all_meetings
             .iter()
             .filter(|m| {
                 m.jira_link.is_none()
-                    && m.my_response_status.as_deref() != Some("declined")
+                    && self.data.config.meetings.attends(m)
                     && !wtf_lib::utils::meetings::is_untracked(
                         m,
                         &self.data.config,
                         &self.data.untracked_meeting_ids,
                     )
-                    && sprint_for_filter
+                    && period_for_filter
                         .as_ref()
-                        .map(|sprint| sprint.contains_meeting(m))
+                        .map(|period| period.contains_meeting(m))
                         .unwrap_or(true)
             })
             .cloned()
@@ -222,66 +397,111 @@ impl Tui {
                     selected_index: 0,
                 };
             }
+            self.wizard_checkpoint(2);
         }
     }
 
     pub(super) fn wizard_step_create_meeting_worklogs(&mut self) {
+        if self.wizard_skips(3) {
+            if let Some(wizard) = &mut self.wizard_state {
+                wizard.current_step = WizardStep::CreatingGitHubWorklogs {
+                    sessions: vec![],
+                    current_session_index: 0,
+                };
+            }
+            self.wizard_step_create_github_worklogs();
+            return;
+        }
+        audit_service::set_source("wizard: Meeting worklogs");
+
         logger::log("📅 Step 3/7: Creating worklogs from meetings...".to_string());
 
         if let Some(wizard) = &self.wizard_state {
-            let sprint = self
+            let period = &wizard.period;
+            let meetings_svc = MeetingsService::production();
+            // Get all linked meetings in the period, skipping absent days
+            let meetings_to_log: Vec<_> = self
                 .data
-                .all_sprints
+                .all_meetings
                 .iter()
-                .find(|s| s.id == wizard.sprint_id);
+                .filter(|m| {
+                    m.jira_link.is_some()
+                        && period.contains_meeting(m)
+                        && !meetings_svc.is_absent(m.start.date_naive())
+                        && self.data.config.meetings.attends(m)
+                })
+                .cloned()
+                .collect();
 
-            if let Some(sprint) = sprint {
-                let meetings_svc = MeetingsService::production();
-                // Get all linked meetings in sprint date range, skipping absent days
-                let meetings_to_log: Vec<_> = self
-                    .data
-                    .all_meetings
+            let count = meetings_to_log.len();
+            let wl_svc = LocalWorklogService::production();
+            let mut created = 0;
+            let mut skipped = 0;
+            let mut emptied = 0;
+
+            // Overlapping meetings share their time as configured, there's nobody to ask
+            let policy = self.data.config.meetings.overlaps;
+            let trimmed = if policy == OverlapPolicy::Keep {
+                HashMap::new()
+            } else {
+                let slots: Vec<MeetingSlot> = meetings_to_log
                     .iter()
-                    .filter(|m| {
-                        m.jira_link.is_some()
-                            && sprint.contains_meeting(m)
-                            && !meetings_svc.is_absent(m.start.date_naive())
-                            && m.my_response_status.as_deref() != Some("declined")
-                    })
-                    .cloned()
+                    .filter_map(|m| MeetingSlot::of_meeting(m, m.start.date_naive()))
                     .collect();
+                settle_overlaps(&slots, |group| resolve(policy, group))
+            };
 
-                let count = meetings_to_log.len();
-                let wl_svc = LocalWorklogService::production();
-                let mut created = 0;
-                let mut skipped = 0;
-
-                // Create worklogs, skipping meetings that already have one
-                for meeting in meetings_to_log {
-                    if let Some(issue_key) = &meeting.jira_link {
-                        if wl_svc
-                            .get_local_worklogs_on_day_for_meeting(
-                                &meeting.id,
-                                meeting.start.date_naive(),
-                            )
-                            .is_empty()
-                        {
-                            self.create_worklog_from_meeting(&meeting, issue_key);
-                            created += 1;
-                        } else {
-                            skipped += 1;
-                        }
+            // Create worklogs, skipping meetings that already have one
+            for meeting in meetings_to_log {
+                if let Some(issue_key) = &meeting.jira_link {
+                    let (started, clipped) = self.data.config.meetings.worklog_time(
+                        &self.data.config.worklog,
+                        meeting.start,
+                        meeting.end,
+                    );
+                    let seconds = trimmed
+                        .get(&(meeting.start.date_naive(), meeting.id.clone()))
+                        .copied()
+                        .unwrap_or(clipped)
+                        .min(clipped);
+                    let seconds = if seconds > 0 {
+                        self.data
+                            .config
+                            .meeting_rules
+                            .worklog_seconds(&meeting, seconds)
+                    } else {
+                        seconds
+                    };
+                    if seconds <= 0 {
+                        emptied += 1;
+                    } else if wl_svc
+                        .get_local_worklogs_on_day_for_meeting(
+                            &meeting.id,
+                            meeting.start.date_naive(),
+                        )
+                        .is_empty()
+                    {
+                        self.create_worklog_from_meeting(&meeting, issue_key, started, seconds);
+                        created += 1;
+                    } else {
+                        skipped += 1;
                     }
                 }
+            }
 
-                if skipped > 0 {
-                    logger::log(format!(
-                        "✅ Created worklogs from {}/{} meetings ({} already logged, skipped)",
-                        created, count, skipped
-                    ));
-                } else {
-                    logger::log(format!("✅ Created worklogs from {} meetings", created));
-                }
+            if skipped > 0 {
+                logger::log(format!(
+                    "✅ Created worklogs from {}/{} meetings ({} already logged, skipped)",
+                    created, count, skipped
+                ));
+            } else {
+                logger::log(format!("✅ Created worklogs from {} meetings", created));
+            }
+            if emptied > 0 {
+                logger::log(format!(
+                    "⚠️  {} meetings got no worklog: emptied by another one, at lunch or outside the workday",
+                    emptied
+                ));
             }
         }
 
@@ -299,6 +519,16 @@ impl Tui {
     }
 
     pub(super) fn wizard_step_create_github_worklogs(&mut self) {
+        if self.wizard_skips(4) {
+            if let Some(wizard) = &mut self.wizard_state {
+                wizard.current_step = WizardStep::FillingGaps {
+                    selected_issue: None,
+                };
+            }
+            self.wizard_step_fill_gaps();
+            return;
+        }
+
         logger::log("💻 Step 4/7: Creating worklogs from GitHub sessions...".to_string());
 
         // Get sessions from wizard state or initialize
@@ -309,61 +539,54 @@ impl Tui {
         };
 
         if should_initialize {
-            // Get sprint date range
+            // Get the period
             if let Some(wizard) = &self.wizard_state {
-                if let Some(sprint) = self
+                let (start, end) = (wizard.period.start, wizard.period.end);
+                // Filter GitHub sessions to the period
+                let period_sessions: Vec<_> = self
                     .data
-                    .all_sprints
+                    .github_sessions
                     .iter()
-                    .find(|s| s.id == wizard.sprint_id)
-                {
-                    if let (Some(start), Some(end)) = (sprint.start, sprint.end) {
-                        // Filter GitHub sessions to this sprint's date range
-                        let sprint_sessions: Vec<_> = self
-                            .data
-                            .github_sessions
-                            .iter()
-                            .filter(|s| {
-                                let session_date = s.start_time.date_naive();
-                                session_date >= start.date_naive()
-                                    && session_date <= end.date_naive()
-                                    && !self.data.valid_github_issues_for_session(s).is_empty()
-                            })
-                            .cloned()
-                            .collect();
-
-                        let count = sprint_sessions.len();
-                        logger::log(format!("📊 Found {} GitHub sessions in sprint", count));
-
-                        if count == 0 {
-                            // No sessions, skip to next step
-                            if let Some(wizard) = &mut self.wizard_state {
-                                wizard.completed_steps.insert(4); // Step 4 complete (no sessions)
-                                wizard
-                                    .skip_reasons
-                                    .insert(4, "no GitHub sessions found".to_string());
-                                wizard.current_step = WizardStep::FillingGaps {
-                                    selected_issue: None,
-                                };
-                            }
-                            self.wizard_step_fill_gaps();
-                            return;
-                        }
+                    .filter(|s| {
+                        let session_date = s.start_time.date_naive();
+                        session_date >= start
+                            && session_date <= end
+                            && !self.session_issues_and_duration(s).0.is_empty()
+                    })
+                    .cloned()
+                    .collect();
 
-                        // Initialize the sessions list and show intro prompt
-                        if let Some(wizard) = &mut self.wizard_state {
-                            wizard.current_step = WizardStep::CreatingGitHubWorklogs {
-                                sessions: sprint_sessions,
-                                current_session_index: 0,
-                            };
-                            // Show intro prompt so user can skip the entire step
-                            wizard.github_step_intro = Some(GitHubStepIntro {
-                                session_count: count,
-                            });
-                        }
-                        return;
+                let count = period_sessions.len();
+                logger::log(format!("📊 Found {} GitHub sessions in the period", count));
+
+                if count == 0 {
+                    // No sessions, skip to next step
+                    if let Some(wizard) = &mut self.wizard_state {
+                        wizard.completed_steps.insert(4); // Step 4 complete (no sessions)
+                        wizard
+                            .skip_reasons
+                            .insert(4, "no GitHub sessions found".to_string());
+                        wizard.current_step = WizardStep::FillingGaps {
+                            selected_issue: None,
+                        };
                     }
+                    self.wizard_step_fill_gaps();
+                    return;
+                }
+
+                // Initialize the sessions list and show intro prompt
+                if let Some(wizard) = &mut self.wizard_state {
+                    wizard.current_step = WizardStep::CreatingGitHubWorklogs {
+                        sessions: period_sessions,
+                        current_session_index: 0,
+                    };
+                    // Show intro prompt so user can skip the entire step
+                    wizard.github_step_intro = Some(GitHubStepIntro {
+                        session_count: count,
+                    });
                 }
+                self.wizard_checkpoint(4);
+                return;
             }
         }
 
@@ -372,6 +595,7 @@ impl Tui {
     }
 
     pub(super) fn wizard_process_next_github_session(&mut self) {
+        audit_service::set_source("wizard: GitHub worklogs");
         let (session, current_index, total_count) = if let Some(wizard) = &self.wizard_state {
             if let WizardStep::CreatingGitHubWorklogs {
                 ref sessions,
@@ -406,10 +630,9 @@ impl Tui {
         ));
 
         // Get Jira issues from session
-        let jira_issues = self.data.valid_github_issues_for_session(&session);
+        let (jira_issues, duration_seconds) = self.session_issues_and_duration(&session);
 
         // Calculate time per issue
-        let duration_seconds = session.duration_seconds;
         let time_per_issue = if jira_issues.len() > 1 {
             duration_seconds / jira_issues.len() as i64
         } else {
@@ -478,20 +701,30 @@ impl Tui {
     }
 
     pub(super) fn wizard_step_fill_gaps(&mut self) {
+        if self.wizard_skips(5) {
+            self.wizard_step_review();
+            return;
+        }
         logger::log("🔧 Step 5/7: Fill gaps with default task...".to_string());
 
         // Show issue selection popup
-        let mut all_issues: Vec<_> = self.data.issues_by_key.values().cloned().collect();
-        all_issues.sort_by(|a, b| a.key.cmp(&b.key));
+        let favorites = self.data.ui_state.favorite_issues.clone();
+        let all_issues = favorites_first(
+            self.data.issues_by_key.values().cloned().collect(),
+            &favorites,
+        );
 
         if let Some(wizard) = &self.wizard_state {
             self.gap_fill_state = Some(GapFillState {
-                sprint_id: wizard.sprint_id,
+                period: wizard.period.clone(),
                 all_issues,
                 selected_issue_index: 0,
                 search_query: String::new(),
+                aliases: self.data.config.aliases.clone(),
+                favorites,
             });
         }
+        self.wizard_checkpoint(5);
     }
 
     pub(super) fn wizard_step_review(&mut self) {
@@ -501,124 +734,107 @@ impl Tui {
         self.refresh_data();
 
         if let Some(wizard) = &mut self.wizard_state {
-            // Get the sprint to filter worklogs
-            let sprint = self
+            let (day_start, day_end) = (wizard.period.start_time(), wizard.period.end_time());
+            // Count worklogs in "Created" state for the period
+            let worklogs_to_push: Vec<_> = self
                 .data
-                .all_sprints
+                .all_worklogs
                 .iter()
-                .find(|s| s.id == wizard.sprint_id);
-
-            if let Some(sprint) = sprint {
-                if let (Some(start), Some(end)) = (sprint.start, sprint.end) {
-                    let day_start =
-                        Utc.from_utc_datetime(&start.date_naive().and_hms_opt(0, 0, 0).unwrap());
-                    let day_end =
-                        Utc.from_utc_datetime(&end.date_naive().and_hms_opt(23, 59, 59).unwrap());
-                    // Count worklogs in "Created" state for this sprint
-                    let worklogs_to_push: Vec<_> = self
-                        .data
-                        .all_worklogs
-                        .iter()
-                        .filter(|w| {
-                            w.status == LocalWorklogState::Created
-                                && w.started >= day_start
-                                && w.started <= day_end
-                        })
-                        .collect();
-
-                    let count = worklogs_to_push.len();
-                    let total_hours: f64 = worklogs_to_push
-                        .iter()
-                        .map(|w| w.time_spent_seconds as f64 / 3600.0)
-                        .sum();
-
-                    wizard.summary.pushed_count = count;
-
-                    logger::log(format!(
-                        "📊 Found {} worklogs ({:.1}h) ready to push",
-                        count, total_hours
-                    ));
-
-                    if count == 0 {
-                        logger::log(
-                            "ℹ️  No worklogs to push, skipping to completion...".to_string(),
-                        );
-                        wizard.completed_steps.insert(6);
-                        wizard.completed_steps.insert(7);
-                        wizard.current_step = WizardStep::Complete;
-                    } else {
-                        wizard.completed_steps.insert(6);
-                        wizard.current_step = WizardStep::ReviewingWorklogs {
-                            excluded_days: std::collections::HashSet::new(),
-                        };
-                    }
-                }
+                .filter(|w| {
+                    w.status == LocalWorklogState::Created
+                        && w.started >= day_start
+                        && w.started <= day_end
+                })
+                .cloned()
+                .collect();
+
+            let violations = LocalWorklogService::production().validate(
+                &worklogs_to_push,
+                &self.data.config.validation,
+                &self.data.jira_worklogs,
+            );
+            let blocked: std::collections::HashSet<&str> = violations
+                .iter()
+                .filter(|v| v.is_blocking())
+                .map(|v| v.worklog_id.as_str())
+                .collect();
+            let count = worklogs_to_push.len() - blocked.len();
+            let total_hours: f64 = worklogs_to_push
+                .iter()
+                .filter(|w| !blocked.contains(w.id.as_str()))
+                .map(|w| w.time_spent_seconds as f64 / 3600.0)
+                .sum();
+
+            wizard.summary.pushed_count = count;
+
+            logger::log(format!(
+                "📊 Found {} worklogs ({:.1}h) ready to push",
+                count, total_hours
+            ));
+
+            if count == 0 {
+                logger::log("ℹ️  No worklogs to push, skipping to completion...".to_string());
+                wizard.completed_steps.insert(6);
+                wizard.completed_steps.insert(7);
+                wizard.current_step = WizardStep::Complete;
+            } else {
+                wizard.completed_steps.insert(6);
+                wizard.current_step = WizardStep::ReviewingWorklogs {
+                    excluded_days: std::collections::HashSet::new(),
+                    violations,
+                };
             }
         }
+
+        let reviewing = self
+            .wizard_state
+            .as_ref()
+            .is_some_and(|w| matches!(w.current_step, WizardStep::ReviewingWorklogs { .. }));
+        if reviewing {
+            self.wizard_checkpoint(6);
+        }
     }
 
     pub(super) fn wizard_step_push(&mut self) {
+        audit_service::set_source("wizard: Push");
         logger::log("🚀 Step 7/7: Pushing worklogs to Jira...".to_string());
 
-        let (sprint_id, _pushed_count) = if let Some(wizard) = &mut self.wizard_state {
+        let (period, _pushed_count) = if let Some(wizard) = &mut self.wizard_state {
             wizard.current_step = WizardStep::Pushing;
             wizard.push_current = 0;
             wizard.push_total = wizard.summary.pushed_count;
-            (wizard.sprint_id, wizard.summary.pushed_count)
+            (wizard.period.clone(), wizard.summary.pushed_count)
         } else {
             return;
         };
 
-        // Stage all Created worklogs for this sprint before pushing
-        let sprint = self
+        // Stage all Created worklogs of the period before pushing
+        let (day_start, day_end) = (period.start_time(), period.end_time());
+        let worklogs_to_stage: Vec<_> = self
             .data
-            .all_sprints
+            .all_worklogs
             .iter()
-            .find(|s| s.id == sprint_id)
-            .cloned();
-
-        if let Some(sprint) = sprint {
-            if let (Some(start), Some(end)) = (sprint.start, sprint.end) {
-                let day_start =
-                    Utc.from_utc_datetime(&start.date_naive().and_hms_opt(0, 0, 0).unwrap());
-                let day_end =
-                    Utc.from_utc_datetime(&end.date_naive().and_hms_opt(23, 59, 59).unwrap());
-                // Get all Created worklogs in sprint date range
-                let worklogs_to_stage: Vec<_> = self
-                    .data
-                    .all_worklogs
-                    .iter()
-                    .filter(|w| {
-                        w.status == LocalWorklogState::Created
-                            && w.started >= day_start
-                            && w.started <= day_end
-                    })
-                    .cloned()
-                    .collect();
+            .filter(|w| {
+                w.status == LocalWorklogState::Created
+                    && w.started >= day_start
+                    && w.started <= day_end
+            })
+            .cloned()
+            .collect();
 
-                // Stage them
-                let staged_count = worklogs_to_stage.len();
-                for mut worklog in worklogs_to_stage {
-                    worklog.status = LocalWorklogState::Staged;
-                    LocalWorklogService::production().save_local_worklog(worklog);
-                }
+        // Stage them, but those a blocking validation rule holds back
+        let staged_count = stage_worklogs(worklogs_to_stage, &self.data.config).len();
 
-                logger::log(format!("📦 Staged {} worklogs for push", staged_count));
+        logger::log(format!("📦 Staged {} worklogs for push", staged_count));
 
-                // DON'T call refresh_data() here - it's async and handle_push_worklogs
-                // will read stale data. Instead, handle_push_worklogs queries the DB directly.
+        // DON'T call refresh_data() here - it's async and handle_push_worklogs
+        // will read stale data. Instead, handle_push_worklogs queries the DB directly.
 
-                // Trigger the push operation (reuse existing push functionality)
-                self.handle_push_worklogs();
+        // Trigger the push operation (reuse existing push functionality)
+        self.handle_push_worklogs();
 
-                // Refresh data AFTER push is started (async in background)
-                self.refresh_data();
-            } else {
-                logger::log("⚠️  Sprint has no date range - cannot stage worklogs".to_string());
-            }
-        } else {
-            logger::log("⚠️  Sprint not found - cannot stage worklogs".to_string());
-        }
+        // Refresh data AFTER push is started (async in background)
+        self.refresh_data();
     }
 
     pub(super) fn wizard_push_complete(&mut self) {
@@ -638,41 +854,58 @@ impl Tui {
     }
 
     pub(super) fn wizard_rollback(&mut self) {
+        audit_service::set_source("wizard: Rollback");
         if let Some(wizard) = &self.wizard_state {
-            let log = &wizard.rollback_log;
-
-            // Unlink meetings
-            for meeting_id in &log.linked_meeting_ids {
-                if let Some(mut meeting) =
-                    MeetingsService::production().get_meeting_by_id(meeting_id.clone())
-                {
-                    meeting.jira_link = None;
-                    MeetingsService::production().save(&meeting);
-                    logger::log(format!("🔗 Unlinked meeting: {}", meeting_id));
-                } else {
-                    logger::log(format!("⚠️  Meeting {} not found", meeting_id));
-                }
-            }
+            let log = wizard.rollback_log.clone();
+            self.wizard_undo(&log);
 
-            // Delete created worklogs
-            for worklog_id in &log.created_worklog_ids {
-                if let Some(worklog) = LocalWorklogService::production().get_worklog(worklog_id) {
-                    LocalWorklogService::production().remove_local_worklog(&worklog);
-                    logger::log(format!("🗑️  Deleted worklog: {}", worklog_id));
-                } else {
-                    logger::log(format!("⚠️  Worklog {} not found", worklog_id));
-                }
-            }
-
-            let total_unlinked = log.linked_meeting_ids.len();
-            let total_deleted = log.created_worklog_ids.len();
             logger::log(format!(
                 "✅ Rollback complete: {} meetings unlinked, {} worklogs deleted",
-                total_unlinked, total_deleted
+                log.linked_meeting_ids.len(),
+                log.created_worklog_ids.len()
             ));
         }
     }
 
+    /// Unlink the meetings and delete the worklogs recorded in `log`; the loaded
+    /// data is patched too so the next step sees the change before a refresh lands
+    fn wizard_undo(&mut self, log: &WizardRollbackLog) {
+        // Unlink meetings
+        for meeting_id in &log.linked_meeting_ids {
+            if let Some(mut meeting) =
+                MeetingsService::production().get_meeting_by_id(meeting_id.clone())
+            {
+                meeting.jira_link = None;
+                MeetingsService::production().save(&meeting);
+                logger::log(format!("🔗 Unlinked meeting: {}", meeting_id));
+            } else {
+                logger::log(format!("⚠️  Meeting {} not found", meeting_id));
+            }
+        }
+
+        // Delete created worklogs
+        for worklog_id in &log.created_worklog_ids {
+            if let Some(worklog) = LocalWorklogService::production().get_worklog(worklog_id) {
+                LocalWorklogService::production().remove_local_worklog(&worklog);
+                logger::log(format!("🗑️  Deleted worklog: {}", worklog_id));
+            } else {
+                logger::log(format!("⚠️  Worklog {} not found", worklog_id));
+            }
+        }
+
+        for meeting in self
+            .data
+            .all_meetings
+            .iter_mut()
+            .filter(|m| log.linked_meeting_ids.contains(&m.id))
+        {
+            meeting.jira_link = None;
+        }
+        self.data
+            .all_worklogs
+            .retain(|w| !log.created_worklog_ids.contains(&w.id));
+    }
+
     /// Update wizard animation frame
     pub(super) fn wizard_update_animation(&mut self) {
         if let Some(wizard) = &mut self.wizard_state {
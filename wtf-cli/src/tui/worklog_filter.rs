@@ -0,0 +1,352 @@
+use chrono::NaiveDate;
+use crossterm::event::{KeyCode, KeyEvent};
+use serde::{Deserialize, Serialize};
+use wtf_lib::models::data::{GitHubSession, LocalWorklog, LocalWorklogState};
+use wtf_lib::utils::gap_fill::GAP_FILL_COMMENT;
+
+/// Where a local worklog came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum WorklogSourceKind {
+    Meeting,
+    GitHub,
+    GapFill,
+    Other,
+}
+
+impl WorklogSourceKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            WorklogSourceKind::Meeting => "Meeting",
+            WorklogSourceKind::GitHub => "GitHub",
+            WorklogSourceKind::GapFill => "Gap fill",
+            WorklogSourceKind::Other => "Other",
+        }
+    }
+
+    /// Infer the source from the worklog's meeting link, comment and matching GitHub session
+    pub fn of(worklog: &LocalWorklog, github_sessions: &[GitHubSession]) -> Self {
+        if worklog.meeting_id.is_some() {
+            return WorklogSourceKind::Meeting;
+        }
+        if worklog.comment.ends_with(GAP_FILL_COMMENT) {
+            return WorklogSourceKind::GapFill;
+        }
+        let from_session = github_sessions.iter().any(|session| {
+            session.start_time == worklog.started
+                && session.get_jira_issues().contains(&worklog.issue_id)
+        });
+        if from_session || worklog.comment.contains("GitHub activity") {
+            return WorklogSourceKind::GitHub;
+        }
+        WorklogSourceKind::Other
+    }
+}
+
+/// Combinable filter for the Worklogs tab. Empty fields match everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorklogFilter {
+    pub issue: String,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub status: Option<LocalWorklogState>,
+    pub source: Option<WorklogSourceKind>,
+}
+
+impl WorklogFilter {
+    pub fn is_active(&self) -> bool {
+        *self != WorklogFilter::default()
+    }
+
+    pub fn matches(&self, worklog: &LocalWorklog, github_sessions: &[GitHubSession]) -> bool {
+        if !self.issue.is_empty()
+            && !worklog
+                .issue_id
+                .to_lowercase()
+                .contains(&self.issue.to_lowercase())
+        {
+            return false;
+        }
+        let date = worklog.started.date_naive();
+        if self.from.is_some_and(|from| date < from) || self.to.is_some_and(|to| date > to) {
+            return false;
+        }
+        if self.status.as_ref().is_some_and(|s| *s != worklog.status) {
+            return false;
+        }
+        if let Some(source) = self.source {
+            if WorklogSourceKind::of(worklog, github_sessions) != source {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Short description for the block title, e.g. "PROJ-1, 2026-01-05.., Staged"
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.issue.is_empty() {
+            parts.push(self.issue.clone());
+        }
+        match (self.from, self.to) {
+            (None, None) => {}
+            (from, to) => parts.push(format!(
+                "{}..{}",
+                from.map(|d| d.to_string()).unwrap_or_default(),
+                to.map(|d| d.to_string()).unwrap_or_default()
+            )),
+        }
+        if let Some(status) = &self.status {
+            parts.push(status_label(Some(status)).to_string());
+        }
+        if let Some(source) = self.source {
+            parts.push(source.label().to_string());
+        }
+        parts.join(", ")
+    }
+}
+
+pub fn status_label(status: Option<&LocalWorklogState>) -> &'static str {
+    match status {
+        None => "Any",
+        Some(LocalWorklogState::Created) => "Created",
+        Some(LocalWorklogState::Staged) => "Staged",
+        Some(LocalWorklogState::Pushed) => "Pushed",
+    }
+}
+
+pub fn source_label(source: Option<WorklogSourceKind>) -> &'static str {
+    source.map(WorklogSourceKind::label).unwrap_or("Any")
+}
+
+/// Fields of the filter popup, in display order
+pub const FILTER_FIELDS: [&str; 5] = ["Issue", "From", "To", "Status", "Source"];
+
+/// Editing state of the Worklogs filter popup (opened with `/`)
+#[derive(Debug, Clone, Default)]
+pub struct WorklogFilterEditor {
+    pub selected_field: usize,
+    pub issue: String,
+    pub from: String,
+    pub to: String,
+    pub status: Option<LocalWorklogState>,
+    pub source: Option<WorklogSourceKind>,
+    pub error: Option<String>,
+}
+
+/// Outcome of a key press in the filter popup
+#[derive(Debug, PartialEq, Eq)]
+pub enum FilterEditorAction {
+    None,
+    Apply(WorklogFilter),
+    Cancel,
+}
+
+impl WorklogFilterEditor {
+    pub fn from_filter(filter: &WorklogFilter) -> Self {
+        Self {
+            selected_field: 0,
+            issue: filter.issue.clone(),
+            from: filter.from.map(|d| d.to_string()).unwrap_or_default(),
+            to: filter.to.map(|d| d.to_string()).unwrap_or_default(),
+            status: filter.status.clone(),
+            source: filter.source,
+            error: None,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> FilterEditorAction {
+        match key.code {
+            KeyCode::Esc => return FilterEditorAction::Cancel,
+            KeyCode::Enter => match self.to_filter() {
+                Ok(filter) => return FilterEditorAction::Apply(filter),
+                Err(e) => self.error = Some(e),
+            },
+            KeyCode::Up | KeyCode::BackTab => {
+                self.selected_field = self.selected_field.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Tab => {
+                self.selected_field = (self.selected_field + 1).min(FILTER_FIELDS.len() - 1);
+            }
+            KeyCode::Delete => *self = Self::default(),
+            KeyCode::Left => self.cycle(false),
+            KeyCode::Right | KeyCode::Char(' ') if self.selected_field >= 3 => self.cycle(true),
+            KeyCode::Backspace => {
+                if let Some(text) = self.text_field() {
+                    text.pop();
+                }
+            }
+            KeyCode::Char(c) => {
+                if let Some(text) = self.text_field() {
+                    text.push(c);
+                }
+            }
+            _ => {}
+        }
+        FilterEditorAction::None
+    }
+
+    fn text_field(&mut self) -> Option<&mut String> {
+        match self.selected_field {
+            0 => Some(&mut self.issue),
+            1 => Some(&mut self.from),
+            2 => Some(&mut self.to),
+            _ => None,
+        }
+    }
+
+    fn cycle(&mut self, forward: bool) {
+        match self.selected_field {
+            3 => {
+                let options = [
+                    None,
+                    Some(LocalWorklogState::Created),
+                    Some(LocalWorklogState::Staged),
+                    Some(LocalWorklogState::Pushed),
+                ];
+                self.status = cycle_option(&options, &self.status, forward);
+            }
+            4 => {
+                let options = [
+                    None,
+                    Some(WorklogSourceKind::Meeting),
+                    Some(WorklogSourceKind::GitHub),
+                    Some(WorklogSourceKind::GapFill),
+                    Some(WorklogSourceKind::Other),
+                ];
+                self.source = cycle_option(&options, &self.source, forward);
+            }
+            _ => {}
+        }
+    }
+
+    fn to_filter(&self) -> Result<WorklogFilter, String> {
+        let from = parse_date(&self.from, "From")?;
+        let to = parse_date(&self.to, "To")?;
+        if let (Some(from), Some(to)) = (from, to) {
+            if from > to {
+                return Err("'From' must not be after 'To'".to_string());
+            }
+        }
+        Ok(WorklogFilter {
+            issue: self.issue.trim().to_string(),
+            from,
+            to,
+            status: self.status.clone(),
+            source: self.source,
+        })
+    }
+}
+
+fn cycle_option<T: Clone + PartialEq>(options: &[T], current: &T, forward: bool) -> T {
+    let pos = options.iter().position(|o| o == current).unwrap_or(0);
+    let next = if forward {
+        (pos + 1) % options.len()
+    } else {
+        (pos + options.len() - 1) % options.len()
+    };
+    options[next].clone()
+}
+
+fn parse_date(input: &str, field: &str) -> Result<Option<NaiveDate>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+    NaiveDate::parse_from_str(input, "%Y-%m-%d")
+        .map(Some)
+        .map_err(|_| format!("'{}' must be a date like 2026-01-31", field))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use crossterm::event::KeyModifiers;
+
+    fn worklog(issue: &str, day: u32, comment: &str, meeting: bool) -> LocalWorklog {
+        LocalWorklog {
+            id: format!("{}-{}", issue, day),
+            comment: comment.to_string(),
+            time_spent_seconds: 3600,
+            issue_id: issue.to_string(),
+            status: LocalWorklogState::Created,
+            started: Utc.with_ymd_and_hms(2026, 1, day, 12, 0, 0).unwrap(),
+            meeting_id: meeting.then(|| "m1".to_string()),
+            worklog_id: None,
+        }
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::empty())
+    }
+
+    #[test]
+    fn test_source_detection() {
+        let meeting = worklog("PROJ-1", 5, "wtf[abc]-Meeting: Daily", true);
+        let gap = worklog("PROJ-1", 5, "wtf[abc]-Tech activities", false);
+        let github = worklog("PROJ-1", 5, "wtf[abc]-GitHub activity: push", false);
+        let other = worklog("PROJ-1", 5, "wtf[abc]-no_msg", false);
+
+        assert_eq!(
+            WorklogSourceKind::of(&meeting, &[]),
+            WorklogSourceKind::Meeting
+        );
+        assert_eq!(WorklogSourceKind::of(&gap, &[]), WorklogSourceKind::GapFill);
+        assert_eq!(
+            WorklogSourceKind::of(&github, &[]),
+            WorklogSourceKind::GitHub
+        );
+        assert_eq!(WorklogSourceKind::of(&other, &[]), WorklogSourceKind::Other);
+    }
+
+    #[test]
+    fn test_filter_combines_criteria() {
+        let filter = WorklogFilter {
+            issue: "proj-1".to_string(),
+            from: NaiveDate::from_ymd_opt(2026, 1, 5),
+            to: None,
+            status: Some(LocalWorklogState::Created),
+            source: Some(WorklogSourceKind::GapFill),
+        };
+
+        assert!(filter.matches(&worklog("PROJ-12", 6, "Tech activities", false), &[]));
+        assert!(!filter.matches(&worklog("PROJ-12", 4, "Tech activities", false), &[]));
+        assert!(!filter.matches(&worklog("OTHER-1", 6, "Tech activities", false), &[]));
+        assert!(!filter.matches(&worklog("PROJ-1", 6, "no_msg", false), &[]));
+        assert!(!WorklogFilter::default().is_active());
+        assert!(WorklogFilter::default().matches(&worklog("X-1", 1, "", false), &[]));
+    }
+
+    #[test]
+    fn test_editor_rejects_invalid_dates() {
+        let mut editor = WorklogFilterEditor {
+            selected_field: 1,
+            ..Default::default()
+        };
+        for c in "2026-13-01".chars() {
+            editor.handle_key(key(KeyCode::Char(c)));
+        }
+        assert_eq!(
+            editor.handle_key(key(KeyCode::Enter)),
+            FilterEditorAction::None
+        );
+        assert!(editor.error.is_some());
+    }
+
+    #[test]
+    fn test_editor_applies_filter() {
+        let mut editor = WorklogFilterEditor::default();
+        editor.handle_key(key(KeyCode::Char('P')));
+        editor.handle_key(key(KeyCode::Down));
+        editor.handle_key(key(KeyCode::Down));
+        editor.handle_key(key(KeyCode::Down));
+        editor.handle_key(key(KeyCode::Right));
+
+        let FilterEditorAction::Apply(filter) = editor.handle_key(key(KeyCode::Enter)) else {
+            panic!("expected filter to apply");
+        };
+        assert_eq!(filter.issue, "P");
+        assert_eq!(filter.status, Some(LocalWorklogState::Created));
+        assert_eq!(filter.summary(), "P, Created");
+    }
+}
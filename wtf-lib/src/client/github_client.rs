@@ -1,7 +1,14 @@
+use crate::client::{http_client, http_trace, retry};
+use crate::config::{Config, GithubConfig, IssuePatternsConfig, NetworkConfig};
+use crate::error::{WtfError, WtfResult};
 use chrono::{DateTime, Utc};
-use log::debug;
+use log::{debug, warn};
+use regex::{Captures, Regex};
+use reqwest::header::{ACCEPT, USER_AGENT};
+use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::process::Command;
+use std::time::Instant;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct GitHubEvent {
@@ -25,41 +32,276 @@ pub struct GitHubCommit {
     pub message: String,
 }
 
-pub struct GitHubClient;
+/// Reads GitHub with `github.token` when set, through `gh api` otherwise. The HTTP
+/// client is built once, so that the calls of a sync share its connections.
+pub struct GitHubClient {
+    client: Client,
+    token: Option<String>,
+    username: Option<String>,
+}
+
+const API_URL: &str = "https://api.github.com";
+
+/// A call to the GitHub API
+pub(crate) enum Request<'a> {
+    /// GET of a REST path, e.g. `/user`
+    Get(&'a str),
+    Graphql {
+        query: &'a str,
+        variables: &'a [(&'a str, String)],
+    },
+}
+
+impl Request<'_> {
+    fn method(&self) -> &'static str {
+        match self {
+            Request::Get(_) => "GET",
+            Request::Graphql { .. } => "POST",
+        }
+    }
+
+    fn url(&self) -> String {
+        match self {
+            Request::Get(path) => format!("{}{}", API_URL, path),
+            Request::Graphql { .. } => format!("{}/graphql", API_URL),
+        }
+    }
+}
+
+/// What GitHub answered, through `gh` or to the configured token
+pub(crate) struct ApiResponse {
+    pub success: bool,
+    pub status: String,
+    pub body: Vec<u8>,
+    /// The failure as gh reports it, e.g. `HTTP 422: ...`
+    pub error: String,
+}
+
+/// Call `gh api`, retrying while GitHub is briefly unavailable
+async fn call_gh(request: &Request<'_>) -> WtfResult<ApiResponse> {
+    let policy = retry::policy();
+    let mut retry = 0;
+    loop {
+        let started = Instant::now();
+        let response = run_gh(request).await?;
+        http_trace::record(
+            request.method(),
+            &request.url(),
+            &response.status,
+            started.elapsed(),
+        );
+        http_trace::record_body("←", &String::from_utf8_lossy(&response.body));
+        if response.success || retry >= policy.max_retries || !is_transient_failure(&response.error)
+        {
+            return Ok(response);
+        }
+        retry += 1;
+        let delay = policy.delay(retry);
+        debug!(
+            "GitHub unavailable, retrying in {} ms ({}/{})",
+            delay.as_millis(),
+            retry,
+            policy.max_retries
+        );
+        tokio::time::sleep(delay).await;
+    }
+}
+
+async fn run_gh(request: &Request<'_>) -> WtfResult<ApiResponse> {
+    let mut command = tokio::process::Command::new("gh");
+    command.arg("api");
+    match request {
+        Request::Get(path) => {
+            command.arg(path);
+        }
+        Request::Graphql { query, variables } => {
+            command
+                .arg("graphql")
+                .arg("-f")
+                .arg(format!("query={}", query));
+            for (name, value) in variables.iter() {
+                command.arg("-f").arg(format!("{}={}", name, value));
+            }
+        }
+    }
+    let output = command.output().await.map_err(|e| {
+        debug!("Failed to execute gh command: {}", e);
+        WtfError::GitHubUnavailable
+    })?;
+    Ok(ApiResponse {
+        success: output.status.success(),
+        status: output.status.to_string(),
+        body: output.stdout,
+        error: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+    })
+}
+
+/// The errors a GraphQL response reports next to, or instead of, its data
+fn graphql_errors(body: &[u8]) -> Option<String> {
+    let response: serde_json::Value = serde_json::from_slice(body).ok()?;
+    let errors = response
+        .get("errors")?
+        .as_array()
+        .filter(|e| !e.is_empty())?;
+    Some(
+        errors
+            .iter()
+            .filter_map(|error| error["message"].as_str())
+            .collect::<Vec<_>>()
+            .join("; "),
+    )
+}
+
+/// The failure of a direct call, worded like gh's so the callers read both alike
+fn response_error(status: u16, body: &[u8]) -> String {
+    if !(200..300).contains(&status) {
+        let message = serde_json::from_slice::<serde_json::Value>(body)
+            .ok()
+            .and_then(|response| response["message"].as_str().map(str::to_string))
+            .unwrap_or_default();
+        format!("HTTP {}: {}", status, message)
+    } else {
+        graphql_errors(body)
+            .map(|errors| format!("GraphQL: {}", errors))
+            .unwrap_or_default()
+    }
+}
+
+/// Read a page of events, skipping the ones GitHub sent in a shape wtf doesn't know rather
+/// than losing the whole page
+fn parse_events(json: &[u8]) -> WtfResult<Vec<GitHubEvent>> {
+    let values: Vec<serde_json::Value> =
+        serde_json::from_slice(json).map_err(|e| WtfError::Malformed {
+            api: "GitHub",
+            message: format!("events page: {}", e),
+        })?;
+    Ok(values
+        .into_iter()
+        .filter_map(|value| match serde_json::from_value(value) {
+            Ok(event) => Some(event),
+            Err(e) => {
+                warn!("Skipping a GitHub event wtf can't read: {}", e);
+                None
+            }
+        })
+        .collect())
+}
+
+/// gh reports the HTTP status of a failed call as `HTTP 503` on stderr
+fn is_transient_failure(error: &str) -> bool {
+    ["HTTP 429", "HTTP 502", "HTTP 503", "HTTP 504"]
+        .iter()
+        .any(|status| error.contains(status))
+}
 
 impl GitHubClient {
-    /// Check if GitHub CLI is available
-    pub fn is_available() -> bool {
-        Command::new("gh")
-            .arg("--version")
-            .output()
-            .map(|output| output.status.success())
-            .unwrap_or(false)
+    pub fn new(github: &GithubConfig, network: &NetworkConfig) -> WtfResult<Self> {
+        Ok(Self {
+            client: http_client::build(network).map_err(WtfError::GitHub)?,
+            token: github.token().map(str::to_string),
+            username: github
+                .username
+                .as_deref()
+                .map(str::trim)
+                .filter(|username| !username.is_empty())
+                .map(str::to_string),
+        })
     }
 
-    /// Get the current authenticated GitHub user
-    pub fn get_username() -> Result<String, String> {
-        let output = Command::new("gh")
-            .args(["api", "user", "--jq", ".login"])
-            .output()
-            .map_err(|e| format!("Failed to execute gh command: {}", e))?;
+    /// A client for the config file's `[github]` and `[network]` sections
+    pub fn create() -> WtfResult<Self> {
+        let config = Config::load().unwrap_or_default();
+        Self::new(&config.github, &config.network)
+    }
 
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(format!("GitHub CLI error: {}", stderr));
+    /// Call the GitHub API with the token when set, through `gh api` otherwise, retrying
+    /// while GitHub is briefly unavailable
+    pub(crate) async fn call(&self, request: &Request<'_>) -> WtfResult<ApiResponse> {
+        match &self.token {
+            Some(token) => self.call_with_token(request, token).await,
+            None => call_gh(request).await,
         }
+    }
 
-        let username = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    async fn call_with_token(&self, request: &Request<'_>, token: &str) -> WtfResult<ApiResponse> {
+        let url = request.url();
+        let builder = match request {
+            Request::Get(_) => self.client.get(&url),
+            Request::Graphql { query, variables } => {
+                let variables: serde_json::Map<String, serde_json::Value> = variables
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.clone().into()))
+                    .collect();
+                self.client
+                    .post(&url)
+                    .json(&serde_json::json!({ "query": query, "variables": variables }))
+            }
+        };
+        let response = retry::send(
+            builder
+                .bearer_auth(token)
+                .header(USER_AGENT, "wtf")
+                .header(ACCEPT, "application/vnd.github+json"),
+        )
+        .await
+        .map_err(|e| WtfError::GitHub(format!("{} {}: {}", request.method(), url, e)))?;
+        let status = response.status().as_u16();
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| WtfError::GitHub(e.to_string()))?
+            .to_vec();
+        Ok(ApiResponse {
+            success: (200..300).contains(&status) && graphql_errors(&body).is_none(),
+            status: status.to_string(),
+            error: response_error(status, &body),
+            body,
+        })
+    }
+
+    /// Check if a GitHub token is configured or the GitHub CLI is available
+    pub fn is_available() -> bool {
+        let has_token = Config::load().is_ok_and(|config| config.github.token().is_some());
+        has_token
+            || Command::new("gh")
+                .arg("--version")
+                .output()
+                .map(|output| output.status.success())
+                .unwrap_or(false)
+    }
+
+    /// The user whose activity is read: `github.username`, else the authenticated one
+    pub async fn get_username(&self) -> WtfResult<String> {
+        if let Some(username) = &self.username {
+            return Ok(username.clone());
+        }
 
-        Ok(username)
+        let response = self.call(&Request::Get("/user")).await?;
+        if !response.success {
+            return Err(WtfError::GitHub(response.error));
+        }
+
+        let user: serde_json::Value =
+            serde_json::from_slice(&response.body).map_err(|e| WtfError::Malformed {
+                api: "GitHub",
+                message: format!("user: {}", e),
+            })?;
+        user["login"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| WtfError::Malformed {
+                api: "GitHub",
+                message: "user without a login".to_string(),
+            })
     }
 
     /// Fetch GitHub events for a user within a date range
-    pub fn fetch_events(
+    pub async fn fetch_events(
+        &self,
         username: &str,
         from: DateTime<Utc>,
         to: DateTime<Utc>,
-    ) -> Result<Vec<GitHubEvent>, String> {
+    ) -> WtfResult<Vec<GitHubEvent>> {
         debug!(
             "Fetching GitHub events for {} from {} to {}",
             username, from, to
@@ -72,19 +314,14 @@ impl GitHubClient {
         let per_page = 100;
 
         loop {
-            let output = Command::new("gh")
-                .args([
-                    "api",
-                    &format!(
-                        "/users/{}/events?per_page={}&page={}",
-                        username, per_page, page
-                    ),
-                ])
-                .output()
-                .map_err(|e| format!("Failed to execute gh command: {}", e))?;
+            let path = format!(
+                "/users/{}/events?per_page={}&page={}",
+                username, per_page, page
+            );
+            let response = self.call(&Request::Get(&path)).await?;
 
-            if !output.status.success() {
-                let stderr = String::from_utf8_lossy(&output.stderr);
+            if !response.success {
+                let stderr = response.error;
                 // GitHub API pagination limit is expected when fetching old data
                 if stderr.contains("pagination is limited") || stderr.contains("HTTP 422") {
                     debug!(
@@ -97,9 +334,7 @@ impl GitHubClient {
                 break;
             }
 
-            let json = String::from_utf8_lossy(&output.stdout);
-            let events: Vec<GitHubEvent> = serde_json::from_str(&json)
-                .map_err(|e| format!("Failed to parse GitHub events: {}", e))?;
+            let events = parse_events(&response.body)?;
 
             if events.is_empty() {
                 break;
@@ -136,24 +371,45 @@ impl GitHubClient {
         Ok(all_events)
     }
 
-    /// Extract Jira issue IDs from various GitHub event sources
-    pub fn extract_jira_issues(event: &GitHubEvent) -> Vec<String> {
+    /// The issues of `event`: the keys it names, else for a repo mapped to a project in
+    /// `github.repo_issues`, the numbers of its branches in that project
+    pub fn event_issues(event: &GitHubEvent, github: &GithubConfig) -> Vec<String> {
+        let issues = Self::extract_jira_issues(event, &github.issue_patterns);
+        match github.repo_project(&event.repo.name) {
+            Some(project) if issues.is_empty() => numbered_branch_issues(event, project),
+            _ => issues,
+        }
+    }
+
+    /// Extract Jira issue IDs from various GitHub event sources, with `patterns` on top
+    /// of the usual `PROJ-123` form
+    pub fn extract_jira_issues(event: &GitHubEvent, patterns: &IssuePatternsConfig) -> Vec<String> {
         let mut issues = Vec::new();
         // Case-insensitive regex to match issue IDs like "PAT-11" or "pat-11"
-        let jira_pattern = regex::Regex::new(r"(?i)\b([A-Z][A-Z0-9]+-\d+)\b").unwrap();
+        let jira_pattern = Regex::new(r"(?i)\b([A-Z][A-Z0-9]+-\d+)\b").unwrap();
+        let branch_patterns = compile_issue_patterns(&patterns.branches);
+        let commit_patterns = compile_issue_patterns(&patterns.commits);
+        let title_patterns = compile_issue_patterns(&patterns.titles);
 
-        // Helper function to extract issues from text
-        let extract_from_text = |text: &str, issues: &mut Vec<String>| {
+        // Helper function to extract issues from text, with the extra patterns for its kind
+        let extract_from_text = |text: &str, extra: &[Regex], issues: &mut Vec<String>| {
             for cap in jira_pattern.captures_iter(text) {
                 if let Some(issue) = cap.get(1) {
                     // Convert to uppercase for consistency (PAT-11, ETECH-123, etc.)
                     issues.push(issue.as_str().to_uppercase());
                 }
             }
+            for pattern in extra {
+                issues.extend(
+                    pattern
+                        .captures_iter(text)
+                        .filter_map(|cap| issue_key(&cap)),
+                );
+            }
         };
 
         // Extract from repo name (e.g., "org/PROJ-123-feature")
-        extract_from_text(&event.repo.name, &mut issues);
+        extract_from_text(&event.repo.name, &[], &mut issues);
 
         // Extract from event payload based on type
         match event.event_type.as_str() {
@@ -162,35 +418,35 @@ impl GitHubClient {
                 if let Some(commits) = event.payload.get("commits").and_then(|c| c.as_array()) {
                     for commit in commits {
                         if let Some(message) = commit.get("message").and_then(|m| m.as_str()) {
-                            extract_from_text(message, &mut issues);
+                            extract_from_text(message, &commit_patterns, &mut issues);
                         }
                     }
                 }
                 // Extract from branch name (e.g., "refs/heads/feature/PROJ-123")
                 if let Some(ref_str) = event.payload.get("ref").and_then(|r| r.as_str()) {
-                    extract_from_text(ref_str, &mut issues);
+                    extract_from_text(ref_str, &branch_patterns, &mut issues);
                 }
             }
             "PullRequestEvent" | "PullRequestReviewEvent" | "PullRequestReviewCommentEvent" => {
                 if let Some(pr) = event.payload.get("pull_request") {
                     // Extract from PR title
                     if let Some(title) = pr.get("title").and_then(|t| t.as_str()) {
-                        extract_from_text(title, &mut issues);
+                        extract_from_text(title, &title_patterns, &mut issues);
                     }
                     // Extract from PR body/description
                     if let Some(body) = pr.get("body").and_then(|b| b.as_str()) {
-                        extract_from_text(body, &mut issues);
+                        extract_from_text(body, &[], &mut issues);
                     }
                     // Extract from PR head branch name
                     if let Some(head) = pr.get("head") {
                         if let Some(ref_str) = head.get("ref").and_then(|r| r.as_str()) {
-                            extract_from_text(ref_str, &mut issues);
+                            extract_from_text(ref_str, &branch_patterns, &mut issues);
                         }
                     }
                     // Extract from PR base branch name
                     if let Some(base) = pr.get("base") {
                         if let Some(ref_str) = base.get("ref").and_then(|r| r.as_str()) {
-                            extract_from_text(ref_str, &mut issues);
+                            extract_from_text(ref_str, &branch_patterns, &mut issues);
                         }
                     }
                 }
@@ -198,7 +454,7 @@ impl GitHubClient {
                 if event.event_type == "PullRequestReviewCommentEvent" {
                     if let Some(comment) = event.payload.get("comment") {
                         if let Some(body) = comment.get("body").and_then(|b| b.as_str()) {
-                            extract_from_text(body, &mut issues);
+                            extract_from_text(body, &[], &mut issues);
                         }
                     }
                 }
@@ -207,26 +463,33 @@ impl GitHubClient {
                 if let Some(issue) = event.payload.get("issue") {
                     // Extract from issue title
                     if let Some(title) = issue.get("title").and_then(|t| t.as_str()) {
-                        extract_from_text(title, &mut issues);
+                        extract_from_text(title, &title_patterns, &mut issues);
                     }
                     // Extract from issue body
                     if let Some(body) = issue.get("body").and_then(|b| b.as_str()) {
-                        extract_from_text(body, &mut issues);
+                        extract_from_text(body, &[], &mut issues);
                     }
                 }
                 // For issue comments, check the comment body
                 if event.event_type == "IssueCommentEvent" {
                     if let Some(comment) = event.payload.get("comment") {
                         if let Some(body) = comment.get("body").and_then(|b| b.as_str()) {
-                            extract_from_text(body, &mut issues);
+                            extract_from_text(body, &[], &mut issues);
                         }
                     }
                 }
             }
+            "CommitCommentEvent" => {
+                if let Some(comment) = event.payload.get("comment") {
+                    if let Some(body) = comment.get("body").and_then(|b| b.as_str()) {
+                        extract_from_text(body, &[], &mut issues);
+                    }
+                }
+            }
             "CreateEvent" | "DeleteEvent" => {
                 // Extract from ref (branch/tag name)
                 if let Some(ref_str) = event.payload.get("ref").and_then(|r| r.as_str()) {
-                    extract_from_text(ref_str, &mut issues);
+                    extract_from_text(ref_str, &branch_patterns, &mut issues);
                 }
             }
             _ => {}
@@ -261,7 +524,10 @@ impl GitHubClient {
                 }
                 "Pull Request".to_string()
             }
-            "PullRequestReviewEvent" => "PR Review".to_string(),
+            "PullRequestReviewEvent"
+            | "PullRequestReviewCommentEvent"
+            | "IssueCommentEvent"
+            | "CommitCommentEvent" => Self::review_description(event),
             "IssuesEvent" => {
                 if let Some(action) = event.payload.get("action").and_then(|a| a.as_str()) {
                     if let Some(issue) = event.payload.get("issue") {
@@ -273,8 +539,188 @@ impl GitHubClient {
                 }
                 "Issue activity".to_string()
             }
-            "IssueCommentEvent" => "Issue comment".to_string(),
             _ => event.event_type.clone(),
         }
     }
+
+    /// Describe a review or comment with the pull request or issue it was left on
+    pub fn review_description(event: &GitHubEvent) -> String {
+        let title_of = |key: &str| {
+            event
+                .payload
+                .get(key)
+                .and_then(|item| item.get("title"))
+                .and_then(|t| t.as_str())
+        };
+        let on_pull_request = |label: &str| match title_of("pull_request") {
+            Some(title) => format!("{} PR: {}", label, title),
+            None => format!("{} PR", label),
+        };
+
+        match event.event_type.as_str() {
+            "PullRequestReviewEvent" => {
+                let reviewed = on_pull_request("Reviewed");
+                match event
+                    .payload
+                    .get("review")
+                    .and_then(|r| r.get("state"))
+                    .and_then(|s| s.as_str())
+                {
+                    Some(state) => {
+                        format!("{} ({})", reviewed, state.to_lowercase().replace('_', " "))
+                    }
+                    None => reviewed,
+                }
+            }
+            "PullRequestReviewCommentEvent" => on_pull_request("Commented on"),
+            "IssueCommentEvent" => {
+                // Conversation comments on a pull request come as issue comments too
+                let kind = match event.payload.get("issue") {
+                    Some(issue) if issue.get("pull_request").is_some() => "PR",
+                    _ => "issue",
+                };
+                match title_of("issue") {
+                    Some(title) => format!("Commented on {}: {}", kind, title),
+                    None => format!("Commented on {}", kind),
+                }
+            }
+            "CommitCommentEvent" => "Commented on commit".to_string(),
+            _ => event.event_type.clone(),
+        }
+    }
+}
+
+/// `project`'s issues numbered by the branches of `event`, e.g. `BILL-123` for
+/// `feature/123-fix`
+fn numbered_branch_issues(event: &GitHubEvent, project: &str) -> Vec<String> {
+    let number = Regex::new(r"(?:^|/)(\d+)(?:[-_.]|$)").unwrap();
+    let payload = &event.payload;
+    let mut issues: Vec<String> = [
+        payload.get("ref"),
+        payload.pointer("/pull_request/head/ref"),
+    ]
+    .into_iter()
+    .flatten()
+    .filter_map(|branch| branch.as_str())
+    .map(|branch| branch.trim_start_matches("refs/heads/"))
+    .filter_map(|branch| number.captures(branch))
+    .map(|cap| format!("{}-{}", project, &cap[1]))
+    .collect();
+    issues.dedup();
+    issues
+}
+
+fn compile_issue_patterns(patterns: &[String]) -> Vec<Regex> {
+    patterns
+        .iter()
+        .filter_map(|pattern| match Regex::new(pattern) {
+            Ok(regex) => Some(regex),
+            Err(e) => {
+                warn!("Invalid github issue pattern '{}': {}", pattern, e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// The issue key a configured pattern captured: its `project` and `number` groups, else
+/// its first two groups joined by a dash, else its first group, upper-cased
+fn issue_key(cap: &Captures) -> Option<String> {
+    let key = match (cap.name("project"), cap.name("number")) {
+        (Some(project), Some(number)) => format!("{}-{}", project.as_str(), number.as_str()),
+        _ => match (cap.get(1), cap.get(2)) {
+            (Some(project), Some(number)) => format!("{}-{}", project.as_str(), number.as_str()),
+            (Some(key), None) => key.as_str().to_string(),
+            _ => return None,
+        },
+    };
+    Some(key.to_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_configured_issue_patterns() {
+        let event: GitHubEvent = serde_json::from_value(serde_json::json!({
+            "id": "1", "type": "PushEvent", "created_at": "2026-03-02T09:00:00Z",
+            "repo": {"name": "org/app", "url": ""},
+            "payload": {
+                "ref": "refs/heads/feature/proj_123-desc",
+                "commits": [{"message": "Cache tokens\n\nRefs: ops 9"}]
+            }
+        }))
+        .unwrap();
+        let patterns = IssuePatternsConfig {
+            branches: vec![r"(?i)/([a-z]+)_(\d+)".to_string()],
+            commits: vec![r"(?m)^Refs: (?P<project>\w+) (?P<number>\d+)$".to_string()],
+            titles: vec!["(".to_string()],
+        };
+
+        assert!(
+            GitHubClient::extract_jira_issues(&event, &IssuePatternsConfig::default()).is_empty()
+        );
+        assert_eq!(
+            GitHubClient::extract_jira_issues(&event, &patterns),
+            vec!["OPS-9", "PROJ-123"]
+        );
+    }
+
+    #[test]
+    fn test_malformed_events_are_skipped() {
+        let page = br#"[
+            {"id": "1", "type": "PushEvent", "created_at": "2026-03-02T09:00:00Z",
+             "repo": {"name": "org/app", "url": "https://api.github.com/repos/org/app"},
+             "payload": {}},
+            {"id": "2", "type": "PushEvent", "created_at": "yesterday"}
+        ]"#;
+
+        let events = parse_events(page).unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].id, "1");
+        assert!(matches!(
+            parse_events(b"<html>"),
+            Err(WtfError::Malformed { api: "GitHub", .. })
+        ));
+    }
+
+    #[test]
+    fn test_token_failures_read_like_gh_ones() {
+        let limited = br#"{"message": "pagination is limited for this resource"}"#;
+        let error = response_error(422, limited);
+        assert_eq!(error, "HTTP 422: pagination is limited for this resource");
+        assert!(is_transient_failure(&response_error(503, b"")));
+
+        let partial = br#"{"data": null, "errors": [{"message": "Could not resolve to a User"}]}"#;
+        assert_eq!(
+            response_error(200, partial),
+            "GraphQL: Could not resolve to a User"
+        );
+        assert_eq!(response_error(200, br#"{"data": {}, "errors": []}"#), "");
+    }
+
+    #[test]
+    fn test_repos_mapped_to_a_project_read_numbered_branches() {
+        let event: GitHubEvent = serde_json::from_value(serde_json::json!({
+            "id": "1", "type": "PushEvent", "created_at": "2026-03-02T09:00:00Z",
+            "repo": {"name": "acme/billing", "url": ""},
+            "payload": {"ref": "refs/heads/feature/123-fix", "commits": [{"message": "Fix"}]}
+        }))
+        .unwrap();
+        let github = GithubConfig {
+            repo_issues: std::collections::HashMap::from([(
+                "billing".to_string(),
+                "BILL".to_string(),
+            )]),
+            ..GithubConfig::default()
+        };
+
+        assert_eq!(
+            GitHubClient::event_issues(&event, &github),
+            vec!["BILL-123"]
+        );
+        assert!(GitHubClient::event_issues(&event, &GithubConfig::default()).is_empty());
+    }
 }
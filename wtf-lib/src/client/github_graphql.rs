@@ -0,0 +1,418 @@
+//! GitHub activity read through the GraphQL API. Unlike the REST events it isn't limited
+//! to the last 90 days, and commits can be read per repository from a given date. The
+//! results are shaped like REST events so that issue extraction and sessions treat both
+//! alike.
+
+use crate::client::github_client::{GitHubClient, GitHubEvent, GitHubRepo, Request};
+use crate::error::{WtfError, WtfResult};
+use chrono::{DateTime, Duration, Utc};
+use log::debug;
+use serde_json::{json, Value};
+use std::collections::HashSet;
+
+/// Pages of issue comments read before giving up on reaching the start of the period
+const MAX_COMMENT_PAGES: usize = 5;
+
+const CONTRIBUTIONS_QUERY: &str = r#"
+query($login: String!, $from: DateTime!, $to: DateTime!) {
+  user(login: $login) {
+    id
+    contributionsCollection(from: $from, to: $to) {
+      commitContributionsByRepository(maxRepositories: 100) {
+        repository { nameWithOwner }
+      }
+      pullRequestContributions(first: 100) {
+        nodes {
+          occurredAt
+          pullRequest {
+            id title body headRefName baseRefName
+            repository { nameWithOwner }
+          }
+        }
+      }
+      pullRequestReviewContributions(first: 100) {
+        nodes {
+          occurredAt
+          pullRequestReview { id state }
+          pullRequest { title body headRefName baseRefName }
+          repository { nameWithOwner }
+        }
+      }
+      issueContributions(first: 100) {
+        nodes {
+          occurredAt
+          issue { id title body repository { nameWithOwner } }
+        }
+      }
+    }
+  }
+}"#;
+
+const COMMITS_QUERY: &str = r#"
+query($owner: String!, $name: String!, $author: ID!, $since: GitTimestamp!, $until: GitTimestamp!) {
+  repository(owner: $owner, name: $name) {
+    refs(refPrefix: "refs/heads/", first: 25, orderBy: {field: TAG_COMMIT_DATE, direction: DESC}) {
+      nodes {
+        name
+        target {
+          ... on Commit {
+            history(first: 100, since: $since, until: $until, author: {id: $author}) {
+              nodes { oid committedDate message }
+            }
+          }
+        }
+      }
+    }
+  }
+}"#;
+
+const COMMENTS_QUERY: &str = r#"
+query($login: String!, $after: String) {
+  user(login: $login) {
+    issueComments(first: 100, after: $after, orderBy: {field: UPDATED_AT, direction: DESC}) {
+      pageInfo { hasNextPage endCursor }
+      nodes {
+        id createdAt updatedAt body
+        repository { nameWithOwner }
+        issue { title body }
+        pullRequest { title body headRefName baseRefName }
+      }
+    }
+  }
+}"#;
+
+/// What I did between two dates, apart from commits and comments
+pub struct Contributions {
+    /// My GitHub node ID, which commits are filtered by
+    pub author_id: String,
+    /// Repos I committed to, whose commits are read with [`fetch_commits`]
+    pub commit_repos: Vec<String>,
+    /// The pull requests I opened, reviews I submitted and issues I opened
+    pub events: Vec<GitHubEvent>,
+}
+
+/// The pull requests, reviews and issues of `login` between `from` and `to`, and the repos
+/// they committed to. Read by login rather than as the viewer, which an installation
+/// token isn't.
+pub async fn fetch_contributions(
+    client: &GitHubClient,
+    login: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> WtfResult<Contributions> {
+    let mut contributions = Contributions {
+        author_id: String::new(),
+        commit_repos: Vec::new(),
+        events: Vec::new(),
+    };
+    // A contributions collection spans a year at most
+    let mut start = from;
+    while start < to {
+        let end = (start + Duration::days(365)).min(to);
+        let data = graphql(
+            client,
+            CONTRIBUTIONS_QUERY,
+            &[
+                ("login", login.to_string()),
+                ("from", start.to_rfc3339()),
+                ("to", end.to_rfc3339()),
+            ],
+        )
+        .await?;
+        let year = contributions_of(&data);
+        contributions.author_id = year.author_id;
+        for repo in year.commit_repos {
+            if !contributions.commit_repos.contains(&repo) {
+                contributions.commit_repos.push(repo);
+            }
+        }
+        contributions.events.extend(year.events);
+        start = end;
+    }
+    Ok(contributions)
+}
+
+/// My commits to `repo` ("owner/name") between `since` and `until`, on any of its most
+/// recently updated branches
+pub async fn fetch_commits(
+    client: &GitHubClient,
+    repo: &str,
+    author_id: &str,
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+) -> WtfResult<Vec<GitHubEvent>> {
+    let Some((owner, name)) = repo.split_once('/') else {
+        return Ok(Vec::new());
+    };
+    let data = graphql(
+        client,
+        COMMITS_QUERY,
+        &[
+            ("owner", owner.to_string()),
+            ("name", name.to_string()),
+            ("author", author_id.to_string()),
+            ("since", since.to_rfc3339()),
+            ("until", until.to_rfc3339()),
+        ],
+    )
+    .await?;
+    Ok(commits_of(repo, &data))
+}
+
+/// The comments of `login` on issues and pull requests between `from` and `to`
+pub async fn fetch_issue_comments(
+    client: &GitHubClient,
+    login: &str,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> WtfResult<Vec<GitHubEvent>> {
+    let mut comments = Vec::new();
+    let mut after: Option<String> = None;
+    for _ in 0..MAX_COMMENT_PAGES {
+        let mut variables = vec![("login", login.to_string())];
+        if let Some(cursor) = &after {
+            variables.push(("after", cursor.clone()));
+        }
+        let data = graphql(client, COMMENTS_QUERY, &variables).await?;
+        let page = &data["user"]["issueComments"];
+        comments.extend(
+            comments_of(page)
+                .into_iter()
+                .filter(|c| c.created_at >= from && c.created_at <= to),
+        );
+
+        // Comments come most recently updated first: the rest were all written earlier
+        let reached_start = nodes(page)
+            .last()
+            .and_then(|node| timestamp(node, "/updatedAt"))
+            .is_none_or(|updated| updated < from);
+        if reached_start || page["pageInfo"]["hasNextPage"] != json!(true) {
+            break;
+        }
+        after = str_at(page, "/pageInfo/endCursor").map(str::to_string);
+    }
+    Ok(comments)
+}
+
+/// Run a GraphQL query, returning its `data`
+async fn graphql(
+    client: &GitHubClient,
+    query: &str,
+    variables: &[(&str, String)],
+) -> WtfResult<Value> {
+    let response = client.call(&Request::Graphql { query, variables }).await?;
+    if !response.success {
+        debug!("GitHub GraphQL error: {}", response.error);
+        return Err(WtfError::GitHub(response.error));
+    }
+    let response: Value =
+        serde_json::from_slice(&response.body).map_err(|e| WtfError::Malformed {
+            api: "GitHub",
+            message: format!("GraphQL response: {}", e),
+        })?;
+    Ok(response["data"].clone())
+}
+
+fn contributions_of(data: &Value) -> Contributions {
+    let user = &data["user"];
+    let collection = &user["contributionsCollection"];
+    let mut events = Vec::new();
+
+    for node in &nodes(&collection["pullRequestContributions"]) {
+        let pr = &node["pullRequest"];
+        if let (Some(id), Some(at), Some(repo)) = (
+            str_at(pr, "/id"),
+            timestamp(node, "/occurredAt"),
+            str_at(pr, "/repository/nameWithOwner"),
+        ) {
+            let payload = json!({"action": "opened", "pull_request": pull_request_payload(pr)});
+            events.push(event(id, "PullRequestEvent", at, repo, payload));
+        }
+    }
+
+    for node in &nodes(&collection["pullRequestReviewContributions"]) {
+        let review = &node["pullRequestReview"];
+        if let (Some(id), Some(at), Some(repo)) = (
+            str_at(review, "/id"),
+            timestamp(node, "/occurredAt"),
+            str_at(node, "/repository/nameWithOwner"),
+        ) {
+            let payload = json!({
+                "review": {"state": review["state"]},
+                "pull_request": pull_request_payload(&node["pullRequest"]),
+            });
+            events.push(event(id, "PullRequestReviewEvent", at, repo, payload));
+        }
+    }
+
+    for node in &nodes(&collection["issueContributions"]) {
+        let issue = &node["issue"];
+        if let (Some(id), Some(at), Some(repo)) = (
+            str_at(issue, "/id"),
+            timestamp(node, "/occurredAt"),
+            str_at(issue, "/repository/nameWithOwner"),
+        ) {
+            let payload = json!({
+                "action": "opened",
+                "issue": {"title": issue["title"], "body": issue["body"]},
+            });
+            events.push(event(id, "IssuesEvent", at, repo, payload));
+        }
+    }
+
+    Contributions {
+        author_id: str_at(user, "/id").unwrap_or_default().to_string(),
+        commit_repos: nodes_of(&collection["commitContributionsByRepository"])
+            .iter()
+            .filter_map(|node| str_at(node, "/repository/nameWithOwner"))
+            .map(str::to_string)
+            .collect(),
+        events,
+    }
+}
+
+/// Each commit once, as a push of its first branch
+fn commits_of(repo: &str, data: &Value) -> Vec<GitHubEvent> {
+    let mut seen = HashSet::new();
+    let mut commits = Vec::new();
+    for branch in &nodes(&data["repository"]["refs"]) {
+        let name = str_at(branch, "/name").unwrap_or_default();
+        for commit in &nodes(&branch["target"]["history"]) {
+            let (Some(oid), Some(at)) =
+                (str_at(commit, "/oid"), timestamp(commit, "/committedDate"))
+            else {
+                continue;
+            };
+            if !seen.insert(oid.to_string()) {
+                continue;
+            }
+            let payload = json!({
+                "ref": format!("refs/heads/{}", name),
+                "commits": [{"sha": oid, "message": commit["message"]}],
+            });
+            commits.push(event(oid, "PushEvent", at, repo, payload));
+        }
+    }
+    commits
+}
+
+fn comments_of(page: &Value) -> Vec<GitHubEvent> {
+    nodes(page)
+        .iter()
+        .filter_map(|node| {
+            let id = str_at(node, "/id")?;
+            let at = timestamp(node, "/createdAt")?;
+            let repo = str_at(node, "/repository/nameWithOwner")?;
+            // Comments on a pull request's conversation are issue comments too
+            let issue = match &node["pullRequest"] {
+                pr if pr.is_object() => {
+                    json!({"title": pr["title"], "body": pr["body"], "pull_request": {}})
+                }
+                _ => json!({"title": node["issue"]["title"], "body": node["issue"]["body"]}),
+            };
+            let payload = json!({"comment": {"body": node["body"]}, "issue": issue});
+            Some(event(id, "IssueCommentEvent", at, repo, payload))
+        })
+        .collect()
+}
+
+/// A pull request the way REST events describe it
+fn pull_request_payload(pr: &Value) -> Value {
+    json!({
+        "title": pr["title"],
+        "body": pr["body"],
+        "head": {"ref": pr["headRefName"]},
+        "base": {"ref": pr["baseRefName"]},
+    })
+}
+
+fn event(id: &str, event_type: &str, at: DateTime<Utc>, repo: &str, payload: Value) -> GitHubEvent {
+    GitHubEvent {
+        id: id.to_string(),
+        event_type: event_type.to_string(),
+        created_at: at,
+        repo: GitHubRepo {
+            name: repo.to_string(),
+            url: format!("https://api.github.com/repos/{}", repo),
+        },
+        payload,
+    }
+}
+
+fn nodes(connection: &Value) -> Vec<Value> {
+    nodes_of(&connection["nodes"])
+}
+
+fn nodes_of(list: &Value) -> Vec<Value> {
+    list.as_array().cloned().unwrap_or_default()
+}
+
+fn str_at<'a>(value: &'a Value, pointer: &str) -> Option<&'a str> {
+    value.pointer(pointer).and_then(Value::as_str)
+}
+
+fn timestamp(value: &Value, pointer: &str) -> Option<DateTime<Utc>> {
+    str_at(value, pointer)?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::IssuePatternsConfig;
+
+    #[test]
+    fn test_graphql_activity_reads_like_rest_events() {
+        let contributions = contributions_of(&json!({"user": {
+            "id": "U_1",
+            "contributionsCollection": {
+                "commitContributionsByRepository": [
+                    {"repository": {"nameWithOwner": "org/api"}}
+                ],
+                "pullRequestReviewContributions": {"nodes": [{
+                    "occurredAt": "2026-03-02T15:00:00Z",
+                    "pullRequestReview": {"id": "PRR_1", "state": "APPROVED"},
+                    "pullRequest": {"title": "Cache tokens", "headRefName": "feature/PROJ-7"},
+                    "repository": {"nameWithOwner": "org/api"}
+                }]}
+            }
+        }}));
+        let commits = commits_of(
+            "org/api",
+            &json!({"repository": {"refs": {"nodes": [
+                {"name": "feature/PROJ-7", "target": {"history": {"nodes": [
+                    {"oid": "abc", "committedDate": "2026-03-02T09:00:00Z", "message": "Cache"}
+                ]}}},
+                {"name": "main", "target": {"history": {"nodes": [
+                    {"oid": "abc", "committedDate": "2026-03-02T09:00:00Z", "message": "Cache"}
+                ]}}}
+            ]}}}),
+        );
+        let comments = comments_of(&json!({"nodes": [{
+            "id": "IC_1", "createdAt": "2026-03-02T16:00:00Z", "updatedAt": "2026-03-02T16:00:00Z",
+            "body": "Looks good", "repository": {"nameWithOwner": "org/api"},
+            "issue": {"title": "PROJ-7 Cache tokens"},
+            "pullRequest": {"title": "PROJ-7 Cache tokens"}
+        }]}));
+
+        assert_eq!(contributions.author_id, "U_1");
+        assert_eq!(contributions.commit_repos, vec!["org/api"]);
+        let review = &contributions.events[0];
+        assert_eq!(
+            GitHubClient::review_description(review),
+            "Reviewed PR: Cache tokens (approved)"
+        );
+        assert_eq!(
+            GitHubClient::extract_jira_issues(review, &IssuePatternsConfig::default()),
+            vec!["PROJ-7"]
+        );
+        assert_eq!(commits.len(), 1);
+        assert_eq!(
+            GitHubClient::extract_jira_issues(&commits[0], &IssuePatternsConfig::default()),
+            vec!["PROJ-7"]
+        );
+        assert_eq!(
+            GitHubClient::review_description(&comments[0]),
+            "Commented on PR: PROJ-7 Cache tokens"
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use crate::client::retry;
+use http::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use http::{HeaderMap, HeaderValue, Method, StatusCode};
+use lazy_static::lazy_static;
+use log::debug;
+use reqwest::{RequestBuilder, Response};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Responses kept at most; the cache starts over beyond
+const MAX_ENTRIES: usize = 500;
+
+lazy_static! {
+    static ref CACHE: Mutex<HashMap<String, Entry>> = Mutex::new(HashMap::new());
+}
+
+/// A response whose body can be reused while the server says it hasn't changed
+#[derive(Clone)]
+struct Entry {
+    etag: Option<HeaderValue>,
+    last_modified: Option<HeaderValue>,
+    headers: HeaderMap,
+    body: Vec<u8>,
+}
+
+impl Entry {
+    /// Ask the server to answer 304 if the resource is still the one cached
+    fn revalidate(&self, request: RequestBuilder) -> RequestBuilder {
+        let request = match &self.etag {
+            Some(etag) => request.header(IF_NONE_MATCH, etag),
+            None => request,
+        };
+        match &self.last_modified {
+            Some(last_modified) => request.header(IF_MODIFIED_SINCE, last_modified),
+            None => request,
+        }
+    }
+
+    fn to_response(&self) -> Response {
+        rebuild(StatusCode::OK, self.headers.clone(), self.body.clone())
+    }
+}
+
+/// Send a GET like [`retry::send`], revalidating the response cached for its URL with
+/// `If-None-Match` / `If-Modified-Since`: when the server answers 304 Not Modified, the
+/// cached body is returned as a 200 instead of being downloaded again. Only responses
+/// carrying an `ETag` or a `Last-Modified` are cached, for the time of the session.
+pub async fn send(request: RequestBuilder) -> reqwest::Result<Response> {
+    let Some(url) = cache_key(&request) else {
+        return retry::send(request).await;
+    };
+    let cached = cache().get(&url).cloned();
+    let request = match &cached {
+        Some(entry) => entry.revalidate(request),
+        None => request,
+    };
+
+    let response = retry::send(request).await?;
+    if response.status() == StatusCode::NOT_MODIFIED {
+        if let Some(entry) = cached {
+            debug!("{} not modified, reusing the cached response", url);
+            return Ok(entry.to_response());
+        }
+    }
+    let etag = response.headers().get(ETAG).cloned();
+    let last_modified = response.headers().get(LAST_MODIFIED).cloned();
+    if !response.status().is_success() || (etag.is_none() && last_modified.is_none()) {
+        return Ok(response);
+    }
+
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = response.bytes().await?.to_vec();
+    let mut cache = cache();
+    if cache.len() >= MAX_ENTRIES {
+        cache.clear();
+    }
+    cache.insert(
+        url,
+        Entry {
+            etag,
+            last_modified,
+            headers: headers.clone(),
+            body: body.clone(),
+        },
+    );
+    Ok(rebuild(status, headers, body))
+}
+
+/// The URL of a GET, the only calls cached
+fn cache_key(request: &RequestBuilder) -> Option<String> {
+    let request = request.try_clone()?.build().ok()?;
+    (request.method() == Method::GET).then(|| request.url().to_string())
+}
+
+fn cache() -> std::sync::MutexGuard<'static, HashMap<String, Entry>> {
+    CACHE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn rebuild(status: StatusCode, headers: HeaderMap, body: Vec<u8>) -> Response {
+    let mut response = http::Response::new(body);
+    *response.status_mut() = status;
+    *response.headers_mut() = headers;
+    Response::from(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Answers the first call with an ETag, and 304 to calls sending it back
+    fn serve_etag(listener: TcpListener, calls: usize) -> thread::JoinHandle<Vec<bool>> {
+        thread::spawn(move || {
+            let mut revalidated = Vec::new();
+            for stream in listener.incoming().take(calls) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut if_none_match = false;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if_none_match |= line.to_lowercase() == "if-none-match: \"v1\"\r\n";
+                }
+                revalidated.push(if_none_match);
+                let response = if if_none_match {
+                    "HTTP/1.1 304 Not Modified\r\nETag: \"v1\"\r\nConnection: close\r\n\r\n"
+                } else {
+                    "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Length: 7\r\nConnection: close\r\n\r\n{\"a\":1}"
+                };
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+            revalidated
+        })
+    }
+
+    #[test]
+    fn test_unchanged_responses_come_from_the_cache() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!(
+            "http://{}/rest/agile/1.0/board",
+            listener.local_addr().unwrap()
+        );
+        let server = serve_etag(listener, 2);
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let client = reqwest::Client::new();
+
+        let bodies: Vec<String> = runtime.block_on(async {
+            let mut bodies = Vec::new();
+            for _ in 0..2 {
+                let response = send(client.get(&url)).await.unwrap();
+                assert_eq!(response.status(), StatusCode::OK);
+                bodies.push(response.text().await.unwrap());
+            }
+            bodies
+        });
+
+        assert_eq!(bodies, vec!["{\"a\":1}", "{\"a\":1}"]);
+        assert_eq!(server.join().unwrap(), vec![false, true]);
+    }
+}
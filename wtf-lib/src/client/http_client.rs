@@ -0,0 +1,55 @@
+use crate::client::http_trace;
+use crate::config::{expand_path, NetworkConfig};
+use log::warn;
+use reqwest::{Certificate, Client, ClientBuilder, Proxy};
+use std::fs;
+
+/// A reqwest client builder honoring the `[network]` section: its proxy, its private CA
+/// bundle and, in lab environments, skipping certificate verification. Without a
+/// configured proxy, the usual `HTTPS_PROXY` / `NO_PROXY` variables still apply.
+pub fn builder(network: &NetworkConfig) -> Result<ClientBuilder, String> {
+    let mut builder = Client::builder();
+    let proxy = network.proxy.as_ref().map(|proxy| proxy.reveal());
+    if let Some(proxy) = proxy.filter(|proxy| !proxy.is_empty()) {
+        let proxy = Proxy::all(proxy).map_err(|e| {
+            format!(
+                "Invalid network.proxy '{}': {}",
+                http_trace::redact(proxy),
+                e
+            )
+        })?;
+        builder = builder.proxy(proxy);
+    }
+    if let Some(path) = network.ca_bundle.as_deref().filter(|path| !path.is_empty()) {
+        for certificate in ca_certificates(path)? {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+    if network.insecure_skip_verify {
+        warn!("TLS certificates aren't verified (network.insecure_skip_verify)");
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+    Ok(builder)
+}
+
+/// A client built from [`builder`]
+pub fn build(network: &NetworkConfig) -> Result<Client, String> {
+    builder(network)?
+        .build()
+        .map_err(|e| format!("Failed to set up the HTTP client: {}", e))
+}
+
+/// The certificates of the PEM file at `path`, trusted on top of the built-in roots
+pub fn ca_certificates(path: &str) -> Result<Vec<Certificate>, String> {
+    let pem = fs::read(expand_path(path))
+        .map_err(|e| format!("Failed to read network.ca_bundle '{}': {}", path, e))?;
+    let certificates = Certificate::from_pem_bundle(&pem)
+        .map_err(|e| format!("Invalid network.ca_bundle '{}': {}", path, e))?;
+    if certificates.is_empty() {
+        return Err(format!(
+            "network.ca_bundle '{}' holds no PEM certificate",
+            path
+        ));
+    }
+    Ok(certificates)
+}
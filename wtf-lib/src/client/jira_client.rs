@@ -1,20 +1,42 @@
+use crate::client::http_cache;
+use crate::client::http_client;
 use crate::client::paginated::PaginatedFetcher;
 use crate::client::paginated_issues::PaginatedIssues;
-use crate::config::{Config, JiraConfig};
-use crate::models::jira::JiraError::ApiError;
-use crate::models::jira::{JiraBoard, JiraError, JiraIssue, JiraSprint, JiraWorklog};
+use crate::client::retry;
+use crate::config::{Config, JiraConfig, NetworkConfig};
+use crate::models::jira::JiraError::{ApiError, DeserializeError};
+use crate::models::jira::{
+    JiraBoard, JiraError, JiraIssue, JiraSprint, JiraTransition, JiraTransitions,
+    JiraUpdatedWorklogsResponse, JiraUser, JiraWorklog, WorklogChanges,
+};
 use base64::engine::general_purpose;
 use base64::Engine;
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use lazy_static::lazy_static;
 use log::{debug, error, trace};
+use regex::Regex;
 use reqwest::Client;
 use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+
+/// Most worklogs `/worklog/list` returns for one call
+const WORKLOG_LIST_MAX: usize = 1000;
+
+lazy_static! {
+    static ref ORDER_BY: Regex = Regex::new(r"(?i)\border\s+by\b").unwrap();
+}
 
 #[derive(Debug, Clone)]
 pub struct JiraClient {
     base_url: String,
     client: Client,
     auth_header: String,
+    issue_jql: Option<String>,
+    epic_field: Option<String>,
+    /// JQL every issue search is narrowed to, see [`JiraClient::with_issue_scope`]
+    issue_scope: Option<String>,
+    /// The configured account, whose worklogs `get_issue_worklogs` keeps
+    username: String,
 }
 
 impl JiraClient {
@@ -26,30 +48,56 @@ impl JiraClient {
                 std::process::exit(1);
             }
         };
-        Self::new(&config.jira)
+        Self::new(&config.jira, &config.network)
     }
 
-    fn new(config: &JiraConfig) -> Self {
+    pub fn new(config: &JiraConfig, network: &NetworkConfig) -> Self {
         let credentials = format!("{}:{}", config.username, config.api_token.reveal());
         let encoded_credentials = general_purpose::STANDARD.encode(credentials);
         let auth_header = format!("Basic {}", encoded_credentials);
+        let client = match http_client::build(network) {
+            Ok(client) => client,
+            Err(err) => {
+                eprintln!("Error: {}", err);
+                std::process::exit(1);
+            }
+        };
         JiraClient {
             base_url: config.base_url.clone(),
-            client: Client::new(),
+            client,
             auth_header,
+            issue_jql: config.issue_jql.clone(),
+            epic_field: config.epic_field.clone(),
+            issue_scope: None,
+            username: config.username.clone(),
+        }
+    }
+
+    /// Narrow the sprint, project and backlog issue searches to `scope` (e.g.
+    /// [`JiraConfig::scope_jql`]); other calls, like sprint worklogs, are left alone
+    pub fn with_issue_scope(self, scope: Option<String>) -> Self {
+        Self {
+            issue_scope: scope,
+            ..self
         }
     }
 
+    /// Issues of a project, only the ones updated since `updated_since` when set
     pub async fn get_project_issues(
         &self,
         project_name: &str,
         start_date: Option<DateTime<Utc>>,
+        updated_since: Option<DateTime<Utc>>,
     ) -> Result<PaginatedIssues<'_>, JiraError> {
-        let start = start_date.unwrap_or(Utc::now());
-        let jql = format!(
-            "project='{}' and createdDate >= '{}'",
-            project_name,
-            start.format("%Y-%m-%d")
+        let jql = project_jql(
+            &format!(
+                "project='{}'{}{}",
+                project_name,
+                updated_since_clause(updated_since),
+                self.issue_scope_clause()
+            ),
+            start_date.unwrap_or(Utc::now()),
+            self.issue_jql.as_deref(),
         );
         let fetcher = self.get_issue_fetcher(jql).await?;
         Ok(fetcher)
@@ -59,12 +107,17 @@ impl JiraClient {
         &self,
         project_name: &str,
         start_date: Option<DateTime<Utc>>,
+        updated_since: Option<DateTime<Utc>>,
     ) -> Result<PaginatedIssues<'_>, JiraError> {
-        let start = start_date.unwrap_or(Utc::now());
-        let jql = format!(
-            "project='{}' and sprint is EMPTY and createdDate >= '{}'",
-            project_name,
-            start.format("%Y-%m-%d")
+        let jql = project_jql(
+            &format!(
+                "project='{}' and sprint is EMPTY{}{}",
+                project_name,
+                updated_since_clause(updated_since),
+                self.issue_scope_clause()
+            ),
+            start_date.unwrap_or(Utc::now()),
+            self.issue_jql.as_deref(),
         );
         let fetcher = self.get_issue_fetcher(jql).await?;
         Ok(fetcher)
@@ -76,26 +129,48 @@ impl JiraClient {
             self.base_url.clone(),
             self.auth_header.clone(),
             jql,
+            self.epic_field.clone(),
         )
         .await
         .map_err(|e| ApiError(e.to_string()))?;
         Ok(fetcher)
     }
 
+    /// Issues matching any `jql`, as typed by the user
+    pub async fn search_issues(&self, jql: &str) -> Result<PaginatedIssues<'_>, JiraError> {
+        self.get_issue_fetcher(jql.to_string()).await
+    }
+
     pub async fn get_all_issues_v2(
         &self,
         sprint_id: &str,
+        updated_since: Option<DateTime<Utc>>,
     ) -> Result<PaginatedIssues<'_>, JiraError> {
-        let jql = format!("sprint={}", sprint_id);
+        let jql = format!(
+            "sprint={}{}{}",
+            sprint_id,
+            updated_since_clause(updated_since),
+            self.issue_scope_clause()
+        );
         let fetcher = self.get_issue_fetcher(jql).await?;
         Ok(fetcher)
     }
 
-    pub async fn get_issue_worklogs(&self, issue: JiraIssue) -> Vec<JiraWorklog> {
+    fn issue_scope_clause(&self) -> String {
+        match &self.issue_scope {
+            Some(scope) => format!(" and ({})", scope),
+            None => String::new(),
+        }
+    }
+
+    pub async fn get_issue_worklogs(
+        &self,
+        issue: JiraIssue,
+    ) -> Result<Vec<JiraWorklog>, JiraError> {
         let jql = "worklogAuthor=currentUser()";
         let endpoint = format!("/rest/api/3/issue/{}/worklog?jql={}&", issue.key, jql);
 
-        let fetcher = match PaginatedFetcher::initialize(
+        let fetcher = PaginatedFetcher::initialize(
             &self.client,
             self.base_url.clone(),
             self.auth_header.clone(),
@@ -103,27 +178,13 @@ impl JiraClient {
             |start_at| format!("startAt={}", start_at),
         )
         .await
-        {
-            Ok(f) => f,
-            Err(e) => {
-                eprintln!("Error fetching worklogs: {}", e);
-                return vec![];
-            }
-        };
+        .map_err(|e| ApiError(format!("worklogs of {}: {}", issue.key, e)))?;
 
         let mut worklogs: Vec<JiraWorklog> = fetcher.collect();
 
         // Get current user email for filtering (JQL might not work on this endpoint)
-        let current_user_email = match Config::load() {
-            Ok(config) => config.jira.username.clone(),
-            Err(e) => {
-                error!("Failed to load config for worklog filtering: {}", e);
-                return vec![];
-            }
-        };
-
         // Filter to only include worklogs by current user
-        worklogs.retain(|w| w.author.email_address == current_user_email);
+        worklogs.retain(|w| w.author.email_address == self.username);
 
         // IMPORTANT: Set the issue_id to the issue key for each worklog
         // Jira API doesn't always return issue_id, but we need it for deletion
@@ -131,7 +192,49 @@ impl JiraClient {
             worklog.issue_id = issue.key.clone();
         }
 
-        worklogs
+        Ok(worklogs)
+    }
+
+    /// Worklogs of every author on the issues of a sprint, started between `from` and
+    /// `to` (inclusive)
+    pub async fn get_sprint_worklogs(
+        &self,
+        sprint_id: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<JiraWorklog>, JiraError> {
+        // Every issue of the sprint, whatever the issue scope: teammates log time too
+        let issues: Vec<JiraIssue> = self
+            .get_issue_fetcher(format!("sprint={}", sprint_id))
+            .await?
+            .collect();
+        let started_after = from.and_time(NaiveTime::MIN).and_utc().timestamp_millis();
+        let started_before = (to + Duration::days(1))
+            .and_time(NaiveTime::MIN)
+            .and_utc()
+            .timestamp_millis();
+
+        let mut worklogs = Vec::new();
+        for issue in issues {
+            let endpoint = format!(
+                "/rest/api/3/issue/{}/worklog?startedAfter={}&startedBefore={}&",
+                issue.key, started_after, started_before
+            );
+            let fetcher: PaginatedFetcher<JiraWorklog> = PaginatedFetcher::initialize(
+                &self.client,
+                self.base_url.clone(),
+                self.auth_header.clone(),
+                endpoint,
+                |start_at| format!("startAt={}", start_at),
+            )
+            .await
+            .map_err(|e| ApiError(e.to_string()))?;
+            worklogs.extend(fetcher.map(|mut w| {
+                w.issue_id = issue.key.clone();
+                w
+            }));
+        }
+        Ok(worklogs)
     }
 
     pub async fn add_time_to_issue(
@@ -150,14 +253,14 @@ impl JiraClient {
             started: start.format("%Y-%m-%dT%H:%M:%S%.3f%z").to_string(),
             comment: comment.unwrap_or("wtf".to_string()),
         };
-        let response = self
-            .client
-            .post(&url)
-            .header("Authorization", &self.auth_header)
-            .json(&worklog)
-            .send()
-            .await
-            .map_err(|e| ApiError(e.to_string()))?;
+        let response = retry::send(
+            self.client
+                .post(&url)
+                .header("Authorization", &self.auth_header)
+                .json(&worklog),
+        )
+        .await
+        .map_err(|e| ApiError(e.to_string()))?;
         if response.status().is_success() {
             trace!("response : {:?}", response);
             debug!("Time logged successfully on issue {}", issue_key);
@@ -185,13 +288,13 @@ impl JiraClient {
             &self.base_url, issue_key, worklog_id
         );
         debug!("DELETE URL: {}", url);
-        let response = self
-            .client
-            .delete(&url)
-            .header("Authorization", &self.auth_header)
-            .send()
-            .await
-            .map_err(|e| ApiError(e.to_string()))?;
+        let response = retry::send(
+            self.client
+                .delete(&url)
+                .header("Authorization", &self.auth_header),
+        )
+        .await
+        .map_err(|e| ApiError(e.to_string()))?;
 
         let status = response.status();
         debug!("DELETE response status: {}", status);
@@ -214,12 +317,12 @@ impl JiraClient {
 
     pub async fn get_worklog(&self, url: &str) -> Option<JiraWorklog> {
         debug!("Fetching worklog from {}", url);
-        let response = match self
-            .client
-            .get(url.to_string())
-            .header("Authorization", &self.auth_header)
-            .send()
-            .await
+        let response = match retry::send(
+            self.client
+                .get(url.to_string())
+                .header("Authorization", &self.auth_header),
+        )
+        .await
         {
             Ok(r) => r,
             Err(e) => {
@@ -238,23 +341,46 @@ impl JiraClient {
         None
     }
 
+    /// The account the API token belongs to: the cheapest call telling whether the
+    /// credentials still work
+    pub async fn get_myself(&self) -> Result<JiraUser, JiraError> {
+        let url = format!("{}/rest/api/3/myself", self.base_url);
+        let response = retry::send(
+            self.client
+                .get(&url)
+                .header("Authorization", &self.auth_header),
+        )
+        .await
+        .map_err(|e| ApiError(e.to_string()))?;
+
+        if response.status().is_success() {
+            response
+                .json()
+                .await
+                .map_err(|e| DeserializeError(e.to_string()))
+        } else {
+            Err(ApiError(response.status().to_string()))
+        }
+    }
+
     pub async fn get_issue(&self, issue_id: &str) -> Result<JiraIssue, JiraError> {
         let url = format!("{}/rest/api/3/issue/{}", self.base_url, issue_id);
         debug!("url: {}", url);
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", &self.auth_header)
-            .send()
-            .await
-            .map_err(|e| ApiError(e.to_string()))?;
+        let response = http_cache::send(
+            self.client
+                .get(&url)
+                .header("Authorization", &self.auth_header),
+        )
+        .await
+        .map_err(|e| ApiError(e.to_string()))?;
 
         if response.status().is_success() {
             debug!("getting issue from jira with key: {}", issue_id);
-            let issue: JiraIssue = response
+            let mut issue: JiraIssue = response
                 .json()
                 .await
                 .map_err(|e| ApiError(format!("Failed to deserialize issue: {}", e)))?;
+            issue.fields.resolve_epic(self.epic_field.as_deref());
             Ok(issue)
         } else {
             debug!("getting error from jira with key: {:?}", response);
@@ -262,6 +388,58 @@ impl JiraClient {
         }
     }
 
+    /// Transitions the workflow allows from the issue's current status
+    pub async fn get_transitions(&self, issue_key: &str) -> Result<Vec<JiraTransition>, JiraError> {
+        let url = format!(
+            "{}/rest/api/3/issue/{}/transitions",
+            self.base_url, issue_key
+        );
+        let response = retry::send(
+            self.client
+                .get(&url)
+                .header("Authorization", &self.auth_header),
+        )
+        .await
+        .map_err(|e| ApiError(e.to_string()))?;
+
+        if response.status().is_success() {
+            let transitions: JiraTransitions = response
+                .json()
+                .await
+                .map_err(|e| DeserializeError(e.to_string()))?;
+            Ok(transitions.transitions)
+        } else {
+            Err(ApiError(response.status().to_string()))
+        }
+    }
+
+    pub async fn transition_issue(
+        &self,
+        issue_key: &str,
+        transition_id: &str,
+    ) -> Result<(), JiraError> {
+        let url = format!(
+            "{}/rest/api/3/issue/{}/transitions",
+            self.base_url, issue_key
+        );
+        let body = serde_json::json!({ "transition": { "id": transition_id } });
+        let response = retry::send(
+            self.client
+                .post(&url)
+                .header("Authorization", &self.auth_header)
+                .json(&body),
+        )
+        .await
+        .map_err(|e| ApiError(e.to_string()))?;
+
+        if response.status().is_success() {
+            debug!("Issue {} transitioned ({})", issue_key, transition_id);
+            Ok(())
+        } else {
+            Err(ApiError(response.status().to_string()))
+        }
+    }
+
     pub async fn get_all_sprint(
         &self,
         board_id: usize,
@@ -306,6 +484,7 @@ impl JiraClient {
             self.base_url.clone(),
             self.auth_header.clone(),
             jql,
+            self.epic_field.clone(),
         )
         .await
         .map_err(|e| ApiError(e.to_string()))?;
@@ -315,7 +494,7 @@ impl JiraClient {
         for issue in issues {
             debug!("issue: {:?}", issue);
             debug!("Fetching worklogs for issue: {}", issue.key);
-            let issue_worklogs = get_worklogs_for_issue(issue).await;
+            let issue_worklogs = self.get_issue_worklogs(issue).await?;
             let worklogs_to_add: Vec<JiraWorklog> = issue_worklogs
                 .iter()
                 .filter(|w| {
@@ -331,6 +510,118 @@ impl JiraClient {
         Ok(worklogs)
     }
 
+    /// The changes to worklogs since `since`, from Jira's updated and deleted worklogs
+    /// feeds: far cheaper than fetching a sprint's worklogs again issue by issue
+    pub async fn get_worklog_changes_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<WorklogChanges, JiraError> {
+        let updated_ids = self.changed_worklog_ids("updated", since).await?;
+        let deleted = self.changed_worklog_ids("deleted", since).await?;
+        debug!(
+            "{} worklogs updated and {} deleted since {}",
+            updated_ids.len(),
+            deleted.len(),
+            since
+        );
+
+        let mut updated = Vec::new();
+        for ids in updated_ids.chunks(WORKLOG_LIST_MAX) {
+            updated.extend(self.get_worklogs_by_ids(ids).await?);
+        }
+        // The feeds cover every author of the instance
+        updated.retain(|w| w.author.email_address == self.username);
+        self.use_issue_keys(&mut updated).await?;
+
+        Ok(WorklogChanges {
+            updated,
+            deleted: deleted.iter().map(usize::to_string).collect(),
+        })
+    }
+
+    /// Ids of the worklogs of the `updated` or `deleted` feed since `since`
+    async fn changed_worklog_ids(
+        &self,
+        feed: &str,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<usize>, JiraError> {
+        let mut url = format!(
+            "{}/rest/api/3/worklog/{}?since={}",
+            self.base_url,
+            feed,
+            since.timestamp_millis()
+        );
+        let mut ids = Vec::new();
+        loop {
+            let response = retry::send(
+                self.client
+                    .get(&url)
+                    .header("Authorization", &self.auth_header),
+            )
+            .await
+            .map_err(|e| ApiError(e.to_string()))?;
+            if !response.status().is_success() {
+                return Err(ApiError(format!(
+                    "{} worklogs feed: {}",
+                    feed,
+                    response.status()
+                )));
+            }
+            let page: JiraUpdatedWorklogsResponse = response
+                .json()
+                .await
+                .map_err(|e| DeserializeError(e.to_string()))?;
+            ids.extend(page.values.iter().map(|w| w.worklog_id));
+            match page.next_page {
+                Some(next_page) if !page.last_page => url = next_page,
+                _ => return Ok(ids),
+            }
+        }
+    }
+
+    async fn get_worklogs_by_ids(&self, ids: &[usize]) -> Result<Vec<JiraWorklog>, JiraError> {
+        let url = format!("{}/rest/api/3/worklog/list", self.base_url);
+        let response = retry::send(
+            self.client
+                .post(&url)
+                .header("Authorization", &self.auth_header)
+                .json(&serde_json::json!({ "ids": ids })),
+        )
+        .await
+        .map_err(|e| ApiError(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(ApiError(format!("worklog list: {}", response.status())));
+        }
+        response
+            .json()
+            .await
+            .map_err(|e| DeserializeError(e.to_string()))
+    }
+
+    /// Worklogs from the feeds carry their issue's numeric id: swap it for the issue key,
+    /// as `get_issue_worklogs` does
+    async fn use_issue_keys(&self, worklogs: &mut [JiraWorklog]) -> Result<(), JiraError> {
+        let issue_ids: BTreeSet<&str> = worklogs.iter().map(|w| w.issue_id.as_str()).collect();
+        if issue_ids.is_empty() {
+            return Ok(());
+        }
+        let jql = format!(
+            "id in ({})",
+            issue_ids.into_iter().collect::<Vec<_>>().join(",")
+        );
+        let keys: HashMap<String, String> = self
+            .get_issue_fetcher(jql)
+            .await?
+            .map(|issue| (issue.id, issue.key))
+            .collect();
+        for worklog in worklogs {
+            if let Some(key) = keys.get(&worklog.issue_id) {
+                worklog.issue_id = key.clone();
+            }
+        }
+        Ok(())
+    }
+
     pub async fn get_worklogs_of_day(
         &self,
         date: DateTime<Utc>,
@@ -348,7 +639,88 @@ struct Worklog {
 }
 
 //FIXME: maybe implements a cache here
-pub async fn get_worklogs_for_issue(issue: JiraIssue) -> Vec<JiraWorklog> {
+/// JQL of the issues of `scope` to cache: the ones matching the user's `issue_jql` when
+/// set, otherwise the ones created since `created_since`
+fn project_jql(scope: &str, created_since: DateTime<Utc>, issue_jql: Option<&str>) -> String {
+    match issue_jql.map(str::trim).filter(|jql| !jql.is_empty()) {
+        Some(jql) => {
+            // An ORDER BY clause can't be wrapped in parentheses: keep it last
+            let (filter, order) = match ORDER_BY.find(jql) {
+                Some(m) => (jql[..m.start()].trim(), format!(" {}", &jql[m.start()..])),
+                None => (jql, String::new()),
+            };
+            if filter.is_empty() {
+                format!("{}{}", scope, order)
+            } else {
+                format!("{} and ({}){}", scope, filter, order)
+            }
+        }
+        None => format!(
+            "{} and createdDate >= '{}'",
+            scope,
+            created_since.format("%Y-%m-%d")
+        ),
+    }
+}
+
+/// JQL restricting a query to the issues updated since `since`. Jira reads JQL dates in
+/// the user's time zone: a day of margin keeps the issues updated around midnight.
+fn updated_since_clause(since: Option<DateTime<Utc>>) -> String {
+    match since {
+        Some(since) => format!(
+            " and updated >= '{}'",
+            (since - Duration::days(1)).format("%Y-%m-%d")
+        ),
+        None => String::new(),
+    }
+}
+
+pub async fn get_worklogs_for_issue(issue: JiraIssue) -> Result<Vec<JiraWorklog>, JiraError> {
     let client = JiraClient::create();
     client.get_issue_worklogs(issue).await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_updated_since_clause_keeps_a_day_of_margin() {
+        assert_eq!(updated_since_clause(None), "");
+        let since = Utc.with_ymd_and_hms(2025, 3, 10, 0, 30, 0).unwrap();
+        assert_eq!(
+            updated_since_clause(Some(since)),
+            " and updated >= '2025-03-09'"
+        );
+    }
+
+    #[test]
+    fn test_project_jql_uses_the_configured_filter() {
+        let since = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            project_jql("project='PROJ'", since, None),
+            "project='PROJ' and createdDate >= '2025-03-01'"
+        );
+        assert_eq!(
+            project_jql(
+                "project='PROJ'",
+                since,
+                Some("assignee = currentUser() OR updated >= -30d")
+            ),
+            "project='PROJ' and (assignee = currentUser() OR updated >= -30d)"
+        );
+        assert_eq!(
+            project_jql(
+                "project='PROJ'",
+                since,
+                Some("updated >= -30d order by updated DESC")
+            ),
+            "project='PROJ' and (updated >= -30d) order by updated DESC"
+        );
+        assert_eq!(
+            project_jql("project='PROJ'", since, Some("  ")),
+            "project='PROJ' and createdDate >= '2025-03-01'"
+        );
+    }
+}
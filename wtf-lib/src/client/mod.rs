@@ -1,4 +1,12 @@
 pub mod github_client;
+pub mod github_graphql;
+pub mod http_cache;
+pub mod http_client;
+pub mod http_trace;
+pub mod hyper_client;
 pub mod jira_client;
+pub mod offline;
 mod paginated;
-mod paginated_issues;
+pub mod paginated_issues;
+pub mod rate_limit;
+pub mod retry;
@@ -0,0 +1,73 @@
+use log::debug;
+use reqwest::Url;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+/// Environment variable turning offline mode on, like `--offline`
+pub const OFFLINE_VAR: &str = "WTF_OFFLINE";
+
+/// Global offline flag (`--offline`, or Jira found unreachable)
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// Work on the local data only: fetches and pushes are refused, worklogs stay staged
+pub fn enable() {
+    OFFLINE.store(true, Ordering::Relaxed);
+}
+
+pub fn disable() {
+    OFFLINE.store(false, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    OFFLINE.load(Ordering::Relaxed)
+}
+
+/// Whether `WTF_OFFLINE` asks for offline mode (any value but empty, `0` or `false`)
+pub fn requested_by_env() -> bool {
+    std::env::var(OFFLINE_VAR).is_ok_and(|value| !matches!(value.trim(), "" | "0" | "false"))
+}
+
+/// Whether the host of `url` accepts a connection within `timeout`. Only the TCP
+/// connection is attempted, so it is cheap enough to run before a sync.
+pub fn is_reachable(url: &str, timeout: Duration) -> bool {
+    let Some(url) = Url::parse(url).ok() else {
+        return false;
+    };
+    let (Some(host), Some(port)) = (url.host_str(), url.port_or_known_default()) else {
+        return false;
+    };
+    let addresses = match (host, port).to_socket_addrs() {
+        Ok(addresses) => addresses,
+        Err(e) => {
+            debug!("Can't resolve {}: {}", host, e);
+            return false;
+        }
+    };
+    addresses.into_iter().any(|address| {
+        TcpStream::connect_timeout(&address, timeout)
+            .inspect_err(|e| debug!("Can't reach {}: {}", address, e))
+            .is_ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    #[test]
+    fn test_reachability_of_a_local_listener() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let timeout = Duration::from_millis(500);
+        assert!(is_reachable(&format!("http://127.0.0.1:{}", port), timeout));
+
+        drop(listener);
+        assert!(!is_reachable(
+            &format!("http://127.0.0.1:{}", port),
+            timeout
+        ));
+        assert!(!is_reachable("not a url", timeout));
+    }
+}
@@ -1,5 +1,6 @@
+use crate::client::http_cache;
 use log::debug;
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use std::collections::HashMap;
@@ -92,13 +93,17 @@ where
         let query = (self.query_fn)(self.start_at);
         let url = format!("{}/{}{}", self.base_url, self.endpoint, query);
         debug!("Fetching page {}", url);
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", self.auth_header.clone())
-            .send()
-            .await?;
-
+        let response = http_cache::send(
+            self.client
+                .get(&url)
+                .header("Authorization", self.auth_header.clone()),
+        )
+        .await?;
+
+        // Still throttled after the retries: an empty page would pass for "no items"
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            return Err(response.error_for_status().unwrap_err());
+        }
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
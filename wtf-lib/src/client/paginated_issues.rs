@@ -1,3 +1,6 @@
+use crate::client::{http_cache, retry};
+#[cfg(doc)]
+use crate::models::jira::JiraFields;
 use crate::models::jira::JiraIssue;
 use serde::Deserialize;
 use std::fmt::Debug;
@@ -20,6 +23,8 @@ pub struct PaginatedIssues<'a> {
     base_url: String,
     auth_header: String,
     jql: String,
+    /// Custom field holding the epic, see [`JiraFields::resolve_epic`]
+    epic_field: Option<String>,
     next_page_token: Option<String>,
     current_items: Vec<JiraIssue>,
     finished: bool,
@@ -33,12 +38,14 @@ impl<'a> PaginatedIssues<'a> {
         base_url: String,
         auth_header: String,
         jql: String,
+        epic_field: Option<String>,
     ) -> Result<Self, Box<dyn std::error::Error>> {
         let mut fetcher = PaginatedIssues {
             client,
             base_url,
             auth_header,
             jql,
+            epic_field,
             next_page_token: None,
             current_items: Vec::new(),
             finished: false,
@@ -56,41 +63,70 @@ impl<'a> PaginatedIssues<'a> {
     }
 
     async fn fetch_page(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        let mut url = format!(
-            "{}/rest/api/latest/search/jql?jql={}&fields=created,summary,status",
-            self.base_url, self.jql
-        );
-
+        let url = format!("{}/rest/api/latest/search/jql", self.base_url);
+        // Query parameters are encoded, so user JQL may contain `&`, `+` or `#`
+        let mut fields = "created,summary,status,parent,labels,components,assignee".to_string();
+        if let Some(epic_field) = &self.epic_field {
+            fields.push(',');
+            fields.push_str(epic_field);
+        }
+        let mut query = vec![("jql", self.jql.as_str()), ("fields", fields.as_str())];
         if let Some(token) = &self.next_page_token {
-            url.push_str(&format!("&nextPageToken={}", token));
+            query.push(("nextPageToken", token.as_str()));
         }
 
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", self.auth_header.clone())
-            .send()
-            .await?;
+        let response = http_cache::send(
+            self.client
+                .get(&url)
+                .query(&query)
+                .header("Authorization", self.auth_header.clone()),
+        )
+        .await?;
         let text = response.text().await?;
-        let page: JiraIssuesPage = serde_json::from_str(&text)?;
+        let mut page: JiraIssuesPage = serde_json::from_str(&text)?;
+        for issue in &mut page.issues {
+            issue.fields.resolve_epic(self.epic_field.as_deref());
+        }
 
-        self.current_items.extend(page.issues);
+        // Items are popped from the end: keep Jira's order
+        self.current_items.extend(page.issues.into_iter().rev());
         self.next_page_token = page.next_page_token;
         self.finished = page.is_last.unwrap_or(false);
         Ok(())
     }
 
+    /// The issues of the next page, in Jira's order, or `None` once every page was read.
+    /// Unlike iterating, it doesn't block the runtime while a page is fetched, and a page
+    /// that can't be fetched is an error instead of the end of the results.
+    pub async fn next_page(
+        &mut self,
+    ) -> Result<Option<Vec<JiraIssue>>, Box<dyn std::error::Error>> {
+        if self.current_items.is_empty() {
+            if self.finished {
+                return Ok(None);
+            }
+            self.fetch_page().await?;
+            if self.current_items.is_empty() {
+                self.finished = true;
+                return Ok(None);
+            }
+        }
+        let page: Vec<JiraIssue> = self.current_items.drain(..).rev().collect();
+        self.yielded_items += page.len();
+        Ok(Some(page))
+    }
+
     async fn fetch_total(&self) -> Result<usize, Box<dyn std::error::Error>> {
         let url = format!("{}/rest/api/3/search/approximate-count", self.base_url);
         let body = serde_json::json!({ "jql": self.jql });
 
-        let response = self
-            .client
-            .post(&url)
-            .header("Authorization", self.auth_header.clone())
-            .json(&body)
-            .send()
-            .await?;
+        let response = retry::send(
+            self.client
+                .post(&url)
+                .header("Authorization", self.auth_header.clone())
+                .json(&body),
+        )
+        .await?;
         let text = response.text().await?;
         let count: JiraIssuesCount = serde_json::from_str(&text)?;
 
@@ -129,3 +165,105 @@ impl<'a> ExactSizeIterator for PaginatedIssues<'a> {
         self.total_items
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    fn issues_json(keys: &[&str]) -> String {
+        let issues: Vec<String> = keys
+            .iter()
+            .map(|key| {
+                format!(
+                    r#"{{"id":"{0}","key":"{0}","fields":{{"summary":"s","status":{{"name":"To Do"}},"created":"2026-01-05T09:00:00Z"}}}}"#,
+                    key
+                )
+            })
+            .collect();
+        issues.join(",")
+    }
+
+    /// Answers each call with the next body, and returns the request lines
+    fn serve(listener: TcpListener, bodies: Vec<String>) -> thread::JoinHandle<Vec<String>> {
+        thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (body, stream) in bodies.into_iter().zip(listener.incoming()) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                }
+                reader.read_exact(&mut vec![0; content_length]).unwrap();
+                requests.push(request_line.trim().to_string());
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        })
+    }
+
+    #[test]
+    fn test_next_page_follows_the_page_tokens() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = serve(
+            listener,
+            vec![
+                format!(
+                    r#"{{"issues":[{}],"nextPageToken":"p2","isLast":false}}"#,
+                    issues_json(&["PROJ-1", "PROJ-2"])
+                ),
+                r#"{"count":3}"#.to_string(),
+                format!(
+                    r#"{{"issues":[{}],"isLast":true}}"#,
+                    issues_json(&["PROJ-3"])
+                ),
+            ],
+        );
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let client = reqwest::Client::new();
+
+        let (total, pages, yielded) = runtime.block_on(async {
+            let mut fetcher = PaginatedIssues::initialize(
+                &client,
+                base_url,
+                "Basic x".to_string(),
+                "project = PROJ".to_string(),
+                None,
+            )
+            .await
+            .unwrap();
+            let mut pages = Vec::new();
+            while let Some(page) = fetcher.next_page().await.unwrap() {
+                pages.push(page.into_iter().map(|i| i.key).collect::<Vec<_>>());
+            }
+            (fetcher.len(), pages, fetcher.yielded_items)
+        });
+
+        assert_eq!(total, 3);
+        assert_eq!(pages, vec![vec!["PROJ-1", "PROJ-2"], vec!["PROJ-3"]]);
+        assert_eq!(yielded, 3);
+        let requests = server.join().unwrap();
+        assert!(requests[2].contains("nextPageToken=p2"), "{:?}", requests);
+    }
+}
@@ -0,0 +1,292 @@
+use crate::config::{Config, NetworkConfig};
+use chrono::{DateTime, Utc};
+use http::header::RETRY_AFTER;
+use http::HeaderMap;
+use lazy_static::lazy_static;
+use log::debug;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+use tokio::sync::{Semaphore, SemaphorePermit};
+use tokio::time::Instant;
+
+/// Successful calls after which one more concurrent call is allowed again
+const SUCCESSES_TO_GROW: usize = 10;
+
+/// Set by [`configure`], or read from the config on first use
+static PACING: RwLock<Option<Pacing>> = RwLock::new(None);
+
+/// How many calls a host gets at once, and how far apart they start
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pacing {
+    /// Calls in flight to a host that hasn't throttled us
+    pub max_concurrent: usize,
+    pub min_interval: Duration,
+}
+
+impl From<&NetworkConfig> for Pacing {
+    fn from(config: &NetworkConfig) -> Self {
+        Self {
+            max_concurrent: config.max_concurrent_requests.max(1),
+            min_interval: Duration::from_millis(config.min_request_interval_ms),
+        }
+    }
+}
+
+impl Default for Pacing {
+    fn default() -> Self {
+        Self::from(&NetworkConfig::default())
+    }
+}
+
+/// Use `pacing` instead of the `[network]` section of the config for the hosts not
+/// called yet
+pub fn configure(pacing: Pacing) {
+    if let Ok(mut current) = PACING.write() {
+        *current = Some(pacing);
+    }
+}
+
+/// The pacing in effect
+pub fn pacing() -> Pacing {
+    if let Some(pacing) = PACING.read().ok().and_then(|pacing| *pacing) {
+        return pacing;
+    }
+    let pacing = Config::load()
+        .map(|config| Pacing::from(&config.network))
+        .unwrap_or_default();
+    configure(pacing);
+    pacing
+}
+
+/// Longest `Retry-After` honored, so a misbehaving server can't stall a fetch for hours
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(120);
+
+lazy_static! {
+    static ref LIMITS: Mutex<HashMap<String, Arc<AdaptiveLimit>>> = Mutex::new(HashMap::new());
+}
+
+/// The limit shared by every call to `host`
+pub fn for_host(host: &str) -> Arc<AdaptiveLimit> {
+    let mut limits = LIMITS.lock().unwrap_or_else(|e| e.into_inner());
+    limits
+        .entry(host.to_string())
+        .or_insert_with(|| Arc::new(AdaptiveLimit::paced(pacing())))
+        .clone()
+}
+
+/// How long a 429 or 503 asks to wait: `Retry-After` in seconds or as an HTTP date
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    let wait = match value.parse::<u64>() {
+        Ok(seconds) => Duration::from_secs(seconds),
+        Err(_) => {
+            let date = DateTime::parse_from_rfc2822(value).ok()?;
+            (date.with_timezone(&Utc) - Utc::now())
+                .to_std()
+                .unwrap_or_default()
+        }
+    };
+    Some(wait.min(MAX_RETRY_AFTER))
+}
+
+/// Number of concurrent calls to a host, halved each time it answers 429 and grown back
+/// one by one while calls succeed
+pub struct AdaptiveLimit {
+    semaphore: Semaphore,
+    max: usize,
+    min_interval: Duration,
+    state: Mutex<LimitState>,
+}
+
+struct LimitState {
+    limit: usize,
+    /// Permits in use when the limit was lowered, dropped instead of released
+    owed: usize,
+    successes: usize,
+    /// Earliest start of the next call, with a minimum interval
+    next_start: Option<Instant>,
+}
+
+/// Allows one call; released on drop
+pub struct Permit<'a> {
+    limit: &'a AdaptiveLimit,
+    permit: Option<SemaphorePermit<'a>>,
+}
+
+impl AdaptiveLimit {
+    pub fn new(max: usize) -> Self {
+        Self {
+            semaphore: Semaphore::new(max),
+            max,
+            min_interval: Duration::ZERO,
+            state: Mutex::new(LimitState {
+                limit: max,
+                owed: 0,
+                successes: 0,
+                next_start: None,
+            }),
+        }
+    }
+
+    /// A limit of `pacing.max_concurrent` calls, started `pacing.min_interval` apart
+    pub fn paced(pacing: Pacing) -> Self {
+        Self {
+            min_interval: pacing.min_interval,
+            ..Self::new(pacing.max_concurrent)
+        }
+    }
+
+    /// Current number of concurrent calls allowed
+    pub fn limit(&self) -> usize {
+        self.state().limit
+    }
+
+    /// Wait until a call is allowed
+    pub async fn acquire(&self) -> Permit<'_> {
+        let permit = Permit {
+            limit: self,
+            permit: self.semaphore.acquire().await.ok(),
+        };
+        if let Some(start) = self.reserve_start() {
+            tokio::time::sleep_until(start).await;
+        }
+        permit
+    }
+
+    /// Book the next start slot, `min_interval` after the previous one
+    fn reserve_start(&self) -> Option<Instant> {
+        if self.min_interval.is_zero() {
+            return None;
+        }
+        let mut state = self.state();
+        let now = Instant::now();
+        let start = state.next_start.map_or(now, |next| next.max(now));
+        state.next_start = Some(start + self.min_interval);
+        Some(start)
+    }
+
+    /// The host answered 429: halve the concurrent calls
+    pub fn throttled(&self) {
+        let mut state = self.state();
+        let lowered = (state.limit / 2).max(1);
+        let removed = state.limit - lowered;
+        let forgotten = self.semaphore.forget_permits(removed);
+        state.owed += removed - forgotten;
+        state.limit = lowered;
+        state.successes = 0;
+        debug!("Rate limited, down to {} concurrent calls", lowered);
+    }
+
+    /// A call went through: allow one more concurrent call every few successes
+    pub fn succeeded(&self) {
+        let mut state = self.state();
+        if state.limit >= self.max {
+            return;
+        }
+        state.successes += 1;
+        if state.successes < SUCCESSES_TO_GROW {
+            return;
+        }
+        state.successes = 0;
+        state.limit += 1;
+        if state.owed > 0 {
+            state.owed -= 1;
+        } else {
+            self.semaphore.add_permits(1);
+        }
+        debug!("Back up to {} concurrent calls", state.limit);
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, LimitState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        let Some(permit) = self.permit.take() else {
+            return;
+        };
+        let mut state = self.limit.state();
+        if state.owed > 0 {
+            state.owed -= 1;
+            permit.forget();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::HeaderValue;
+
+    #[test]
+    fn test_limit_halves_when_throttled_and_grows_back() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let limit = AdaptiveLimit::new(4);
+        let first = runtime.block_on(limit.acquire());
+        let second = runtime.block_on(limit.acquire());
+        let third = runtime.block_on(limit.acquire());
+
+        limit.throttled();
+        assert_eq!(limit.limit(), 2);
+        // One free permit was forgotten, one of the calls in flight is owed
+        drop(first);
+        assert_eq!(limit.semaphore.available_permits(), 0);
+        drop(second);
+        assert_eq!(limit.semaphore.available_permits(), 1);
+        drop(third);
+        assert_eq!(limit.semaphore.available_permits(), 2);
+
+        limit.throttled();
+        limit.throttled();
+        assert_eq!(limit.limit(), 1);
+        assert_eq!(limit.semaphore.available_permits(), 1);
+
+        for _ in 0..SUCCESSES_TO_GROW {
+            limit.succeeded();
+        }
+        assert_eq!(limit.limit(), 2);
+        assert_eq!(limit.semaphore.available_permits(), 2);
+    }
+
+    #[test]
+    fn test_paced_calls_start_min_interval_apart() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+        let limit = AdaptiveLimit::paced(Pacing {
+            max_concurrent: 3,
+            min_interval: Duration::from_millis(20),
+        });
+        runtime.block_on(async {
+            let start = Instant::now();
+            let _first = limit.acquire().await;
+            let _second = limit.acquire().await;
+            let _third = limit.acquire().await;
+            assert!(start.elapsed() >= Duration::from_millis(40));
+        });
+    }
+
+    #[test]
+    fn test_retry_after_accepts_seconds_and_dates() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(7)));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("86400"));
+        assert_eq!(retry_after(&headers), Some(MAX_RETRY_AFTER));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+    }
+}
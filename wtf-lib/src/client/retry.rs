@@ -0,0 +1,258 @@
+use crate::client::http_trace;
+use crate::client::rate_limit::{self, AdaptiveLimit};
+use crate::config::{Config, NetworkConfig};
+use google_calendar3::common::{Delegate, Retry};
+use log::debug;
+use rand::Rng;
+use reqwest::{RequestBuilder, Response, StatusCode};
+use std::sync::RwLock;
+use std::time::Duration;
+
+/// Longest wait between two attempts
+const MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Set by [`configure`], or read from the config on first use
+static POLICY: RwLock<Option<RetryPolicy>> = RwLock::new(None);
+
+/// How often and how patiently failing HTTP calls are retried
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    /// Wait before the first retry, doubled for each following one
+    pub backoff: Duration,
+    /// Wait a random 50 to 100% of the backoff instead
+    pub jitter: bool,
+}
+
+impl RetryPolicy {
+    /// Wait before retry number `retry` (counted from 1)
+    pub fn delay(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        let delay = self.backoff.saturating_mul(factor).min(MAX_DELAY);
+        if self.jitter {
+            delay.mul_f64(rand::rng().random_range(0.5..=1.0))
+        } else {
+            delay
+        }
+    }
+}
+
+impl From<&NetworkConfig> for RetryPolicy {
+    fn from(config: &NetworkConfig) -> Self {
+        Self {
+            max_retries: config.max_retries,
+            backoff: Duration::from_millis(config.retry_backoff_ms),
+            jitter: config.retry_jitter,
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::from(&NetworkConfig::default())
+    }
+}
+
+/// Use `policy` instead of the `[network]` section of the config, e.g. when embedding
+/// wtf-lib
+pub fn configure(policy: RetryPolicy) {
+    if let Ok(mut current) = POLICY.write() {
+        *current = Some(policy);
+    }
+}
+
+/// The policy in effect
+pub fn policy() -> RetryPolicy {
+    if let Some(policy) = POLICY.read().ok().and_then(|policy| *policy) {
+        return policy;
+    }
+    let policy = Config::load()
+        .map(|config| RetryPolicy::from(&config.network))
+        .unwrap_or_default();
+    configure(policy);
+    policy
+}
+
+/// Statuses of a gateway or a server that is briefly unavailable
+pub fn is_transient_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// Send `request` like [`http_trace::send`], retrying transient failures. Calls that may
+/// have changed something on the server (POST) are only retried when they didn't reach
+/// it, got a 503 or a 429. Calls to a host that answers 429 are spread out: they wait as
+/// long as its `Retry-After` asks and fewer of them run at once.
+pub async fn send(request: RequestBuilder) -> reqwest::Result<Response> {
+    let policy = policy();
+    let built = request.try_clone().and_then(|request| request.build().ok());
+    let idempotent = built
+        .as_ref()
+        .is_some_and(|request| request.method().is_idempotent());
+    let limit = rate_limit::for_host(
+        built
+            .as_ref()
+            .and_then(|request| request.url().host_str())
+            .unwrap_or_default(),
+    );
+
+    let mut retry = 0;
+    loop {
+        // Streamed bodies can't be sent twice
+        let attempt = match request.try_clone() {
+            Some(attempt) if retry < policy.max_retries => attempt,
+            _ => return send_within(&limit, request).await,
+        };
+        let result = send_within(&limit, attempt).await;
+        if !should_retry(&result, idempotent) {
+            return result;
+        }
+        retry += 1;
+        let delay = requested_wait(&result).unwrap_or_else(|| policy.delay(retry));
+        debug!(
+            "{}, retrying in {} ms ({}/{})",
+            describe(&result),
+            delay.as_millis(),
+            retry,
+            policy.max_retries
+        );
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// Send once `limit` allows it, and adapt it to the answer
+async fn send_within(limit: &AdaptiveLimit, request: RequestBuilder) -> reqwest::Result<Response> {
+    let _permit = limit.acquire().await;
+    let result = http_trace::send(request).await;
+    match &result {
+        Ok(response) if response.status() == StatusCode::TOO_MANY_REQUESTS => limit.throttled(),
+        Ok(response) if response.status().is_success() => limit.succeeded(),
+        _ => {}
+    }
+    result
+}
+
+fn should_retry(result: &reqwest::Result<Response>, idempotent: bool) -> bool {
+    match result {
+        Ok(response) => {
+            matches!(
+                response.status(),
+                StatusCode::SERVICE_UNAVAILABLE | StatusCode::TOO_MANY_REQUESTS
+            ) || (idempotent && is_transient_status(response.status()))
+        }
+        Err(e) => e.is_connect() || (idempotent && e.is_timeout()),
+    }
+}
+
+/// The wait asked for by a throttled or unavailable server
+fn requested_wait(result: &reqwest::Result<Response>) -> Option<Duration> {
+    result
+        .as_ref()
+        .ok()
+        .and_then(|response| rate_limit::retry_after(response.headers()))
+}
+
+fn describe(result: &reqwest::Result<Response>) -> String {
+    match result {
+        Ok(response) => format!("{} answered {}", response.url(), response.status()),
+        Err(e) => http_trace::redact(&e.to_string()),
+    }
+}
+
+/// Applies the retry policy to the Google Calendar hub's calls, which retry on their own
+/// when told to: `.delegate(&mut RetryDelegate::new())`
+pub struct RetryDelegate {
+    policy: RetryPolicy,
+    retries: u32,
+}
+
+impl RetryDelegate {
+    pub fn new() -> Self {
+        Self {
+            policy: policy(),
+            retries: 0,
+        }
+    }
+
+    fn next(&mut self, reason: &str, requested: Option<Duration>) -> Retry {
+        if self.retries >= self.policy.max_retries {
+            return Retry::Abort;
+        }
+        self.retries += 1;
+        let delay = requested.unwrap_or_else(|| self.policy.delay(self.retries));
+        debug!(
+            "Google Calendar {}, retrying in {} ms ({}/{})",
+            reason,
+            delay.as_millis(),
+            self.retries,
+            self.policy.max_retries
+        );
+        Retry::After(delay)
+    }
+}
+
+impl Default for RetryDelegate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Delegate for RetryDelegate {
+    fn http_error(&mut self, err: &google_calendar3::hyper_util::client::legacy::Error) -> Retry {
+        self.next(&err.to_string(), None)
+    }
+
+    fn http_failure(
+        &mut self,
+        response: &google_calendar3::common::Response,
+        _err: Option<&serde_json::Value>,
+    ) -> Retry {
+        if is_transient_status(response.status())
+            || response.status() == StatusCode::TOO_MANY_REQUESTS
+        {
+            self.next(
+                &format!("answered {}", response.status()),
+                rate_limit::retry_after(response.headers()),
+            )
+        } else {
+            Retry::Abort
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_up_to_the_cap() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            backoff: Duration::from_millis(500),
+            jitter: false,
+        };
+        assert_eq!(policy.delay(1), Duration::from_millis(500));
+        assert_eq!(policy.delay(3), Duration::from_secs(2));
+        assert_eq!(policy.delay(10), MAX_DELAY);
+
+        let jittered = RetryPolicy {
+            jitter: true,
+            ..policy
+        };
+        for _ in 0..20 {
+            let delay = jittered.delay(2);
+            assert!(delay >= Duration::from_millis(500) && delay <= Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn test_only_gateway_and_unavailable_statuses_are_transient() {
+        assert!(is_transient_status(StatusCode::BAD_GATEWAY));
+        assert!(is_transient_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(is_transient_status(StatusCode::GATEWAY_TIMEOUT));
+        assert!(!is_transient_status(StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(!is_transient_status(StatusCode::NOT_FOUND));
+    }
+}
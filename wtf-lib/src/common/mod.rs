@@ -1,4 +1,5 @@
-use chrono::{DateTime, Local, Utc};
+use crate::duration;
+use chrono::{DateTime, Duration, Local, Utc};
 
 pub struct Common;
 
@@ -9,11 +10,34 @@ impl Common {
             .to_string()
     }
 
+    /// Logged time in the `[worklog] duration_format`
     pub fn readable_time_spent(time_spent_seconds: i64) -> String {
-        if time_spent_seconds < 3_600 {
-            format!("{}m", time_spent_seconds / 60)
+        duration::format_seconds(time_spent_seconds)
+    }
+
+    /// Coarsest unit only: "45s ago", "12m ago", "3h ago", "2d ago"
+    pub fn time_ago(elapsed: Duration) -> String {
+        if elapsed.num_minutes() < 1 {
+            format!("{}s ago", elapsed.num_seconds().max(0))
+        } else if elapsed.num_hours() < 1 {
+            format!("{}m ago", elapsed.num_minutes())
+        } else if elapsed.num_days() < 1 {
+            format!("{}h ago", elapsed.num_hours())
         } else {
-            format!("{:.1}h", time_spent_seconds as f64 / 3_600.0)
+            format!("{}d ago", elapsed.num_days())
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_ago_uses_the_coarsest_unit() {
+        assert_eq!(Common::time_ago(Duration::seconds(42)), "42s ago");
+        assert_eq!(Common::time_ago(Duration::minutes(59)), "59m ago");
+        assert_eq!(Common::time_ago(Duration::minutes(150)), "2h ago");
+        assert_eq!(Common::time_ago(Duration::hours(50)), "2d ago");
+    }
+}
@@ -0,0 +1,189 @@
+use super::SensitiveString;
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GithubConfig {
+    #[serde(default)]
+    pub organisation: Option<String>,
+    /// Personal access token or GitHub App installation token, used instead of `gh`
+    /// when set; `wtf config secret set github` keeps it in the keyring instead
+    #[serde(default)]
+    pub token: Option<SensitiveString>,
+    /// Login whose activity is read; asked to GitHub when unset, which an installation
+    /// token can't answer
+    #[serde(default)]
+    pub username: Option<String>,
+    /// API activity is read from
+    #[serde(default)]
+    pub api: GitHubApi,
+    /// Repos tracked even outside the organisation, as `owner/name` or a bare name
+    #[serde(default)]
+    pub include_repos: Vec<String>,
+    /// Repos never tracked, as `owner/name` or a bare name, e.g. dotfiles
+    #[serde(default)]
+    pub exclude_repos: Vec<String>,
+    /// Events closer than this many minutes are stitched into the same session.
+    #[serde(default = "default_session_gap_minutes")]
+    pub session_gap_minutes: u32,
+    /// Shortest length a session is credited with.
+    #[serde(default = "default_min_session_minutes")]
+    pub min_session_minutes: u32,
+    /// Minutes credited before the first event of a session, since a push or a review
+    /// only lands once the work is done.
+    #[serde(default)]
+    pub session_padding_minutes: u32,
+    /// Extra regexes finding issue keys the usual `PROJ-123` form misses
+    #[serde(default)]
+    pub issue_patterns: IssuePatternsConfig,
+    /// Where the activity of a repo (`owner/name` or a bare name) goes when it names no
+    /// issue: an issue key or alias, or a project key numbered branches like `123-fix`
+    /// are read against
+    #[serde(default)]
+    pub repo_issues: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GitHubApi {
+    /// Contributions and per-repository commits, synced incrementally with no limit on
+    /// history; falls back to the events API when it fails
+    #[default]
+    Graphql,
+    /// The events API, which only covers about the last 90 days
+    Rest,
+}
+
+/// Regexes finding issue keys in GitHub activity, on top of the usual `PROJ-123` form.
+/// The key is the `project` and `number` named groups joined by a dash, else the first
+/// two capture groups, else the first one, upper-cased: `(?i)([a-z]+)_(\d+)` reads
+/// `feature/proj_123-desc` as `PROJ-123`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IssuePatternsConfig {
+    /// Searched in branch names
+    #[serde(default)]
+    pub branches: Vec<String>,
+    /// Searched in commit messages
+    #[serde(default)]
+    pub commits: Vec<String>,
+    /// Searched in pull request and issue titles
+    #[serde(default)]
+    pub titles: Vec<String>,
+}
+
+impl Default for GithubConfig {
+    fn default() -> Self {
+        Self {
+            organisation: None,
+            token: None,
+            username: None,
+            api: GitHubApi::default(),
+            include_repos: Vec::new(),
+            exclude_repos: Vec::new(),
+            session_gap_minutes: default_session_gap_minutes(),
+            min_session_minutes: default_min_session_minutes(),
+            session_padding_minutes: 0,
+            issue_patterns: IssuePatternsConfig::default(),
+            repo_issues: HashMap::new(),
+        }
+    }
+}
+
+impl GithubConfig {
+    /// Whether events of `repo` ("owner/name") are kept: excluded repos never are,
+    /// included ones always are, the others when they belong to the organisation
+    pub fn tracks_repo(&self, repo: &str) -> bool {
+        let listed = |names: &[String]| names.iter().any(|name| names_repo(name, repo));
+        if listed(&self.exclude_repos) {
+            return false;
+        }
+        if listed(&self.include_repos) {
+            return true;
+        }
+        match &self.organisation {
+            Some(org) => repo.starts_with(&format!("{}/", org)),
+            None => true,
+        }
+    }
+
+    /// The configured token, `None` when unset or empty
+    pub fn token(&self) -> Option<&str> {
+        self.token
+            .as_ref()
+            .map(|token| token.reveal())
+            .filter(|token| !token.is_empty())
+    }
+
+    /// The `repo_issues` entry of `repo`, an `owner/name` one over a bare name
+    pub fn repo_issue(&self, repo: &str) -> Option<&str> {
+        self.repo_issues
+            .iter()
+            .filter(|(name, _)| names_repo(name, repo))
+            .max_by_key(|(name, _)| name.contains('/'))
+            .map(|(_, issue)| issue.trim())
+            .filter(|issue| !issue.is_empty())
+    }
+
+    /// The project key `repo` is mapped to, when its `repo_issues` entry is one rather than
+    /// an issue key
+    pub fn repo_project(&self, repo: &str) -> Option<&str> {
+        self.repo_issue(repo).filter(|project| {
+            project.starts_with(|c: char| c.is_ascii_uppercase())
+                && project
+                    .chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        })
+    }
+
+    pub fn session_gap(&self) -> Duration {
+        Duration::minutes(self.session_gap_minutes as i64)
+    }
+
+    pub fn min_session_seconds(&self) -> i64 {
+        self.min_session_minutes as i64 * 60
+    }
+
+    pub fn session_padding(&self) -> Duration {
+        Duration::minutes(self.session_padding_minutes as i64)
+    }
+}
+
+/// Whether `name`, `owner/name` or a bare name matching any owner, stands for `repo`
+fn names_repo(name: &str, repo: &str) -> bool {
+    name.eq_ignore_ascii_case(repo)
+        || (!name.contains('/')
+            && repo
+                .rsplit_once('/')
+                .is_some_and(|(_, repo_name)| repo_name.eq_ignore_ascii_case(name)))
+}
+
+fn default_session_gap_minutes() -> u32 {
+    120
+}
+
+fn default_min_session_minutes() -> u32 {
+    15
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_github_repo_filters() {
+        let github = GithubConfig {
+            organisation: Some("acme".to_string()),
+            include_repos: vec!["me/tooling".to_string()],
+            exclude_repos: vec!["acme/experiments".to_string(), "dotfiles".to_string()],
+            ..GithubConfig::default()
+        };
+
+        assert!(github.tracks_repo("acme/api"));
+        assert!(github.tracks_repo("me/tooling"));
+        assert!(!github.tracks_repo("me/other"));
+        assert!(!github.tracks_repo("acme/Experiments"));
+        assert!(!github.tracks_repo("acme/dotfiles"));
+        assert!(GithubConfig::default().tracks_repo("me/other"));
+    }
+}
@@ -0,0 +1,178 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::env;
+
+/// Google Calendar event color names, indexed by (colorId - 1).
+/// colorId "1" → "Lavender", "2" → "Sage", ..., "11" → "Tomato"
+pub const GOOGLE_CALENDAR_EVENT_COLORS: [&str; 11] = [
+    "Lavender",
+    "Sage",
+    "Grape",
+    "Flamingo",
+    "Banana",
+    "Tangerine",
+    "Peacock",
+    "Graphite",
+    "Blueberry",
+    "Basil",
+    "Tomato",
+];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GoogleConfig {
+    #[serde(default)]
+    pub credentials_path: String,
+    #[serde(default)]
+    pub token_cache_path: String,
+    /// Maps Google Calendar color name (e.g. "Grape") to a Jira issue key (e.g. "INTERNAL-42").
+    /// Used as highest-priority auto-link source in meetings.
+    #[serde(default)]
+    pub color_labels: HashMap<String, String>,
+    /// Calendars whose events are fetched and merged, e.g. a team calendar next to
+    /// `primary`; only `primary` when empty.
+    #[serde(default)]
+    pub calendar_ids: Vec<String>,
+    /// Words marking an all-day event as time off (e.g. "PTO"), offered as an absence
+    /// like "Out of office" events; a few common ones when empty.
+    #[serde(default)]
+    pub time_off_keywords: Vec<String>,
+    /// Key file of a service account, used instead of the OAuth client and the browser
+    /// sign-in where admins don't allow per-user OAuth apps
+    #[serde(default)]
+    pub service_account_key_path: Option<String>,
+    /// User the service account acts as through domain-wide delegation, e.g. my work email
+    #[serde(default)]
+    pub impersonate: Option<String>,
+    /// How the OAuth client signs in the first time
+    #[serde(default)]
+    pub auth_flow: GoogleAuthFlow,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GoogleAuthFlow {
+    /// The device flow over SSH or without a display, the browser otherwise
+    #[default]
+    Auto,
+    /// Open a browser and catch its redirect on a local port
+    Browser,
+    /// Print a URL and a code to enter on any other device, then wait for the grant
+    Device,
+}
+
+impl GoogleAuthFlow {
+    pub fn uses_device_code(self) -> bool {
+        match self {
+            GoogleAuthFlow::Auto => {
+                let set = |name: &str| env::var_os(name).is_some();
+                set("SSH_CONNECTION")
+                    || set("SSH_TTY")
+                    || (cfg!(target_os = "linux") && !set("DISPLAY") && !set("WAYLAND_DISPLAY"))
+            }
+            GoogleAuthFlow::Browser => false,
+            GoogleAuthFlow::Device => true,
+        }
+    }
+}
+
+/// All-day events whose title contains one of these words are time off
+const DEFAULT_TIME_OFF_KEYWORDS: &[&str] = &[
+    "out of office",
+    "ooo",
+    "pto",
+    "vacation",
+    "holiday",
+    "day off",
+    "time off",
+    "congé",
+];
+
+impl GoogleConfig {
+    /// The calendars to fetch, `primary` when none is configured
+    pub fn calendars(&self) -> Vec<String> {
+        if self.calendar_ids.is_empty() {
+            vec!["primary".to_string()]
+        } else {
+            self.calendar_ids.clone()
+        }
+    }
+
+    /// Whether an all-day event with this title is time off
+    pub fn is_time_off(&self, title: &str) -> bool {
+        let keywords: Vec<&str> = if self.time_off_keywords.is_empty() {
+            DEFAULT_TIME_OFF_KEYWORDS.to_vec()
+        } else {
+            self.time_off_keywords.iter().map(String::as_str).collect()
+        };
+        let pattern = keywords
+            .iter()
+            .map(|keyword| regex::escape(keyword))
+            .collect::<Vec<_>>()
+            .join("|");
+        Regex::new(&format!(r"(?i)\b({})\b", pattern)).is_ok_and(|re| re.is_match(title))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_google_calendars_default_to_primary() {
+        assert_eq!(GoogleConfig::default().calendars(), vec!["primary"]);
+
+        let google: GoogleConfig = toml::from_str(
+            r#"
+            credentials_path = "creds.json"
+            token_cache_path = "token.json"
+            calendar_ids = ["primary", "team@group.calendar.google.com"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            google.calendars(),
+            vec!["primary", "team@group.calendar.google.com"]
+        );
+    }
+
+    #[test]
+    fn test_google_service_account_needs_no_oauth_client() {
+        let google: GoogleConfig = toml::from_str(
+            r#"
+            service_account_key_path = "sa.json"
+            impersonate = "me@example.com"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(google.service_account_key_path.as_deref(), Some("sa.json"));
+        assert_eq!(google.impersonate.as_deref(), Some("me@example.com"));
+        assert!(google.credentials_path.is_empty());
+    }
+
+    #[test]
+    fn test_google_auth_flow_parses_and_forces_a_flow() {
+        assert_eq!(GoogleConfig::default().auth_flow, GoogleAuthFlow::Auto);
+
+        let google: GoogleConfig = toml::from_str(r#"auth_flow = "device""#).unwrap();
+        assert_eq!(google.auth_flow, GoogleAuthFlow::Device);
+        assert!(GoogleAuthFlow::Device.uses_device_code());
+        assert!(!GoogleAuthFlow::Browser.uses_device_code());
+        assert!(toml::from_str::<GoogleConfig>(r#"auth_flow = "sms""#).is_err());
+    }
+
+    #[test]
+    fn test_time_off_keywords() {
+        let google = GoogleConfig::default();
+        assert!(google.is_time_off("PTO - Alice"));
+        assert!(google.is_time_off("Summer vacation"));
+        assert!(!google.is_time_off("Roadmap offsite"));
+
+        let custom = GoogleConfig {
+            time_off_keywords: vec!["RTT".to_string()],
+            ..GoogleConfig::default()
+        };
+        assert!(custom.is_time_off("rtt"));
+        assert!(!custom.is_time_off("PTO"));
+    }
+}
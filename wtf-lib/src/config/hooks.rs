@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+/// Shell commands run on lifecycle events, reading a JSON payload on their stdin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HooksConfig {
+    /// Run before pushing worklogs to Jira; a non-zero exit cancels the push.
+    #[serde(default)]
+    pub pre_push: Option<String>,
+    /// Run once worklogs have been pushed.
+    #[serde(default)]
+    pub post_push: Option<String>,
+    /// Run for each worklog created locally.
+    #[serde(default)]
+    pub worklog_created: Option<String>,
+    /// Run once a fetch is over.
+    #[serde(default)]
+    pub fetch_complete: Option<String>,
+    /// Seconds a hook may run before it is killed.
+    #[serde(default = "default_hook_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+impl Default for HooksConfig {
+    fn default() -> Self {
+        Self {
+            pre_push: None,
+            post_push: None,
+            worklog_created: None,
+            fetch_complete: None,
+            timeout_secs: default_hook_timeout_secs(),
+        }
+    }
+}
+
+fn default_hook_timeout_secs() -> u64 {
+    30
+}
+
+/// Custom rules linking meetings and GitHub sessions to issues
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LinkingConfig {
+    /// Rhai script consulted before the issue keys found in titles, descriptions and
+    /// branch names; needs a build with the `scripting` feature.
+    #[serde(default)]
+    pub script: Option<String>,
+}
@@ -0,0 +1,212 @@
+use super::SensitiveString;
+use crate::models::data::{Issue, Sprint, SprintState};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JiraConfig {
+    pub base_url: String,
+    pub username: String,
+    pub api_token: SensitiveString,
+    #[serde(default)]
+    pub auto_follow_sprint_pattern: Option<String>,
+    /// Follow the active sprint of every followed board when sprints are fetched
+    #[serde(default)]
+    pub follow_active_sprint: bool,
+    /// Unfollow closed sprints this many days after their end date; kept when unset
+    #[serde(default)]
+    pub unfollow_closed_after_days: Option<u32>,
+    #[serde(default)]
+    pub project_keys: Vec<String>,
+    /// Extra JQL restricting the board and project issues kept in the local cache,
+    /// instead of everything created in the last 12 months
+    #[serde(default)]
+    pub issue_jql: Option<String>,
+    /// Custom field holding the epic of an issue (e.g. `customfield_10014`) on instances
+    /// still using Epic Link; the parent epic is used when unset
+    #[serde(default)]
+    pub epic_field: Option<String>,
+    /// Status to move an issue to (e.g. "In Progress") when the first worklog is pushed
+    /// on it; issues are left alone when unset
+    #[serde(default)]
+    pub transition_on_first_worklog: Option<String>,
+    /// Only fetch and offer the issues assigned to the configured account
+    #[serde(default)]
+    pub assigned_only: bool,
+    /// Only fetch and offer the issues in these statuses (all when empty)
+    #[serde(default)]
+    pub active_statuses: Vec<String>,
+}
+
+impl Default for JiraConfig {
+    fn default() -> Self {
+        Self {
+            base_url: String::new(),
+            username: String::new(),
+            api_token: SensitiveString::new(String::new()),
+            auto_follow_sprint_pattern: None,
+            follow_active_sprint: false,
+            unfollow_closed_after_days: None,
+            project_keys: Vec::new(),
+            issue_jql: None,
+            epic_field: None,
+            transition_on_first_worklog: None,
+            assigned_only: false,
+            active_statuses: Vec::new(),
+        }
+    }
+}
+
+impl JiraConfig {
+    /// JQL narrowing the fetched issues to `assigned_only` and `active_statuses`, `None`
+    /// when neither is set
+    pub fn scope_jql(&self) -> Option<String> {
+        let mut clauses = Vec::new();
+        if self.assigned_only {
+            clauses.push("assignee = currentUser()".to_string());
+        }
+        if !self.active_statuses.is_empty() {
+            let statuses: Vec<String> = self
+                .active_statuses
+                .iter()
+                .map(|status| format!("\"{}\"", status.replace('"', "\\\"")))
+                .collect();
+            clauses.push(format!("status in ({})", statuses.join(", ")));
+        }
+        (!clauses.is_empty()).then(|| clauses.join(" and "))
+    }
+
+    /// Whether a cached issue passes the same filters as [`JiraConfig::scope_jql`]
+    pub fn in_scope(&self, issue: &Issue) -> bool {
+        let assigned = !self.assigned_only
+            || issue
+                .assignee
+                .as_deref()
+                .is_some_and(|assignee| assignee.eq_ignore_ascii_case(&self.username));
+        let active = self.active_statuses.is_empty()
+            || self
+                .active_statuses
+                .iter()
+                .any(|status| status.eq_ignore_ascii_case(&issue.status));
+        assigned && active
+    }
+
+    /// Whether fetching sprints should follow (`Some(true)`) or unfollow (`Some(false)`)
+    /// `sprint`, from `auto_follow_sprint_pattern`, `follow_active_sprint` and
+    /// `unfollow_closed_after_days`; `None` leaves it as it is
+    pub fn auto_follow(&self, sprint: &Sprint, now: DateTime<Utc>) -> Option<bool> {
+        if sprint.followed {
+            let expired = self.unfollow_closed_after_days.is_some_and(|days| {
+                sprint.state == SprintState::Closed
+                    && sprint
+                        .end
+                        .is_some_and(|end| end + Duration::days(days as i64) <= now)
+            });
+            return expired.then_some(false);
+        }
+        let matches_pattern = self
+            .auto_follow_sprint_pattern
+            .as_ref()
+            .is_some_and(|pattern| sprint.name.contains(pattern.as_str()))
+            && matches!(sprint.state, SprintState::Active | SprintState::Future);
+        let active = self.follow_active_sprint && sprint.state == SprintState::Active;
+        (matches_pattern || active).then_some(true)
+    }
+}
+
+/// The teammates whose timesheets `wtf sprint team` checks, for team leads
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TeamConfig {
+    /// Jira accounts, by email or display name
+    #[serde(default)]
+    pub members: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_issue_scope_jql_and_cached_filter() {
+        let jira = JiraConfig {
+            username: "me@example.com".to_string(),
+            assigned_only: true,
+            active_statuses: vec!["To Do".to_string(), "In Progress".to_string()],
+            ..JiraConfig::default()
+        };
+        assert_eq!(
+            jira.scope_jql().as_deref(),
+            Some("assignee = currentUser() and status in (\"To Do\", \"In Progress\")")
+        );
+        assert_eq!(JiraConfig::default().scope_jql(), None);
+
+        let issue = |assignee: Option<&str>, status: &str| Issue {
+            id: "1".to_string(),
+            key: "PROJ-1".to_string(),
+            summary: String::new(),
+            status: status.to_string(),
+            created: Utc::now(),
+            sprint_ids: Vec::new(),
+            epic: None,
+            components: Vec::new(),
+            labels: Vec::new(),
+            assignee: assignee.map(str::to_string),
+        };
+        assert!(jira.in_scope(&issue(Some("Me@example.com"), "in progress")));
+        assert!(!jira.in_scope(&issue(Some("me@example.com"), "Done")));
+        assert!(!jira.in_scope(&issue(None, "To Do")));
+        assert!(JiraConfig::default().in_scope(&issue(None, "Done")));
+    }
+
+    #[test]
+    fn test_sprints_followed_and_unfollowed_on_fetch() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 20, 12, 0, 0).unwrap();
+        let sprint = |name: &str, state: SprintState, ended_days_ago: i64, followed: bool| Sprint {
+            id: 1,
+            name: name.to_string(),
+            state,
+            start: Some(now - Duration::days(ended_days_ago + 14)),
+            end: Some(now - Duration::days(ended_days_ago)),
+            followed,
+            workdays: 10,
+        };
+        let jira = JiraConfig {
+            auto_follow_sprint_pattern: Some("Tech".to_string()),
+            follow_active_sprint: true,
+            unfollow_closed_after_days: Some(7),
+            ..JiraConfig::default()
+        };
+
+        assert_eq!(
+            jira.auto_follow(&sprint("Team 12", SprintState::Active, -3, false), now),
+            Some(true)
+        );
+        assert_eq!(
+            jira.auto_follow(&sprint("Tech 4", SprintState::Future, -20, false), now),
+            Some(true)
+        );
+        assert_eq!(
+            jira.auto_follow(&sprint("Team 13", SprintState::Future, -20, false), now),
+            None
+        );
+        assert_eq!(
+            jira.auto_follow(&sprint("Team 10", SprintState::Closed, 8, true), now),
+            Some(false)
+        );
+        assert_eq!(
+            jira.auto_follow(&sprint("Team 11", SprintState::Closed, 2, true), now),
+            None
+        );
+        // A closed sprint is never followed again
+        assert_eq!(
+            jira.auto_follow(&sprint("Tech 3", SprintState::Closed, 8, false), now),
+            None
+        );
+        assert_eq!(
+            JiraConfig::default()
+                .auto_follow(&sprint("Team 12", SprintState::Active, -3, false), now),
+            None
+        );
+    }
+}
@@ -0,0 +1,266 @@
+use super::{default_true, WorklogConfig};
+use crate::models::data::Meeting;
+use crate::utils::{dates, meetings};
+use chrono::{DateTime, Local, NaiveTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Which meetings count as attended, by my answer to the invitation: the others get no
+/// worklog, aren't offered for linking and are left out of the unlinked counts; and how
+/// much of their time their worklogs get
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeetingsConfig {
+    #[serde(default)]
+    pub include_declined: bool,
+    #[serde(default = "default_true")]
+    pub include_tentative: bool,
+    /// Which of two overlapping meetings gets the time they share when their worklogs
+    /// are created
+    #[serde(default)]
+    pub overlaps: OverlapPolicy,
+    /// Issue Google "Focus time" blocks are linked to, so they get worklogs like meetings;
+    /// left unlinked when unset
+    #[serde(default)]
+    pub focus_time_issue: Option<String>,
+    /// Only log the part of a meeting inside `worklog.workday_start`..`workday_end`
+    #[serde(default)]
+    pub clip_to_workday: bool,
+    /// Lunch break left out of meeting worklogs, `HH:MM-HH:MM` local time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lunch_break: Option<String>,
+}
+
+impl Default for MeetingsConfig {
+    fn default() -> Self {
+        Self {
+            include_declined: false,
+            include_tentative: true,
+            overlaps: OverlapPolicy::default(),
+            focus_time_issue: None,
+            clip_to_workday: false,
+            lunch_break: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OverlapPolicy {
+    /// Every meeting logs its whole length, the shared time is counted twice
+    #[default]
+    Keep,
+    /// The longest meeting gets the shared time
+    Longest,
+    /// The shared time is divided evenly between the meetings
+    Split,
+    /// `wtf meeting log` asks which meeting gets it; elsewhere the longest does
+    Ask,
+}
+
+impl MeetingsConfig {
+    /// Whether the meeting counts, from my response status; unanswered ones do
+    pub fn attends(&self, meeting: &Meeting) -> bool {
+        match meeting.my_response_status.as_deref() {
+            Some("declined") => self.include_declined,
+            Some("tentative") => self.include_tentative,
+            _ => true,
+        }
+    }
+
+    /// The lunch break, `None` when unset or invalid
+    pub fn lunch_break(&self) -> Option<(NaiveTime, NaiveTime)> {
+        let (start, end) = self.lunch_break.as_deref()?.split_once('-')?;
+        Some((
+            dates::parse_time_of_day(start)?,
+            dates::parse_time_of_day(end)?,
+        ))
+        .filter(|(s, e)| s < e)
+    }
+
+    /// Start and seconds of the worklog of a meeting from `start` to `end`: clipped to
+    /// the working window of `worklog` with `clip_to_workday`, without the lunch break.
+    /// Nothing may be left, e.g. of a team lunch.
+    pub fn worklog_time(
+        &self,
+        worklog: &WorklogConfig,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> (DateTime<Utc>, i64) {
+        self.worklog_time_in(&Local, worklog, start, end)
+    }
+
+    fn worklog_time_in<Tz: TimeZone>(
+        &self,
+        tz: &Tz,
+        worklog: &WorklogConfig,
+        mut start: DateTime<Utc>,
+        mut end: DateTime<Utc>,
+    ) -> (DateTime<Utc>, i64) {
+        let day = start.with_timezone(tz).date_naive();
+        if self.clip_to_workday {
+            if let Some(workday_start) = worklog.workday_start() {
+                start = start.max(dates::at_local_time(tz, day, workday_start));
+            }
+            if let Some(workday_end) = worklog.workday_end() {
+                end = end.min(dates::at_local_time(tz, day, workday_end));
+            }
+        }
+        if end <= start {
+            return (start, 0);
+        }
+        let mut seconds = (end - start).num_seconds();
+        if let Some((lunch_start, lunch_end)) = self.lunch_break() {
+            let lunch_start = dates::at_local_time(tz, day, lunch_start);
+            let lunch_end = dates::at_local_time(tz, day, lunch_end);
+            let overlap = (end.min(lunch_end) - start.max(lunch_start)).num_seconds();
+            if overlap > 0 {
+                seconds -= overlap;
+                // A meeting starting during lunch is logged from its end
+                if lunch_start <= start {
+                    start = lunch_end.min(end);
+                }
+            }
+        }
+        (start, seconds)
+    }
+}
+
+/// Fixed links for recurring meetings, e.g. every standup to `PROJ-100`, applied when
+/// meetings are fetched and auto-linked, before the issue keys found in their text; and
+/// the share of their time their worklogs get
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MeetingRulesConfig {
+    /// Checked in order, the first matching rule wins
+    #[serde(default)]
+    pub rules: Vec<MeetingRule>,
+}
+
+impl MeetingRulesConfig {
+    /// The issue of the first rule linking the meeting
+    pub fn issue_for(&self, meeting: &Meeting) -> Option<String> {
+        self.rules
+            .iter()
+            .filter(|rule| rule.issue.is_some())
+            .find(|rule| meetings::matches_rule(rule, meeting))
+            .and_then(|rule| rule.issue.clone())
+    }
+
+    /// Seconds the worklog of a meeting lasting `seconds` gets, from the first matching
+    /// rule setting a `multiplier` or `min_minutes`: scaled, then raised to the minimum
+    pub fn worklog_seconds(&self, meeting: &Meeting, seconds: i64) -> i64 {
+        let Some(rule) = self
+            .rules
+            .iter()
+            .filter(|rule| rule.multiplier.is_some() || rule.min_minutes.is_some())
+            .find(|rule| meetings::matches_rule(rule, meeting))
+        else {
+            return seconds;
+        };
+        let scaled = rule.multiplier.map_or(seconds, |multiplier| {
+            (seconds as f64 * multiplier.max(0.0)).round() as i64
+        });
+        scaled.max(
+            rule.min_minutes
+                .map_or(0, |minutes| i64::from(minutes) * 60),
+        )
+    }
+}
+
+/// Links the meetings fulfilling every condition it sets to `issue`, and/or changes the
+/// time their worklogs get; a rule without conditions matches nothing
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MeetingRule {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<String>,
+    /// Regex searched in the title, case-insensitive
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Email or display name of one of the attendees, case-insensitive
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attendee: Option<String>,
+    /// Google Calendar color name, e.g. "Grape"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Share of the meeting's time logged, e.g. `0.5` for an optional guild meeting
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub multiplier: Option<f64>,
+    /// Fewest minutes logged for the meeting
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_minutes: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_meetings_attended_by_response_status() {
+        let meeting = |status: Option<&str>| Meeting {
+            id: "m".to_string(),
+            title: None,
+            description: None,
+            start: Utc::now(),
+            end: Utc::now(),
+            attendees: None,
+            jira_link: None,
+            recurrence: None,
+            logs: HashMap::new(),
+            my_response_status: status.map(str::to_string),
+            color_id: None,
+            organizer: None,
+            conference_link: None,
+            calendar: None,
+            recurring_event_id: None,
+            event_type: None,
+        };
+        let defaults = MeetingsConfig::default();
+        assert!(defaults.attends(&meeting(None)));
+        assert!(defaults.attends(&meeting(Some("accepted"))));
+        assert!(defaults.attends(&meeting(Some("tentative"))));
+        assert!(!defaults.attends(&meeting(Some("declined"))));
+
+        let strict = MeetingsConfig {
+            include_declined: true,
+            include_tentative: false,
+            overlaps: OverlapPolicy::Ask,
+            ..MeetingsConfig::default()
+        };
+        assert!(strict.attends(&meeting(Some("declined"))));
+        assert!(!strict.attends(&meeting(Some("tentative"))));
+
+        let meetings: MeetingsConfig = toml::from_str(r#"overlaps = "longest""#).unwrap();
+        assert_eq!(meetings.overlaps, OverlapPolicy::Longest);
+        assert_eq!(defaults.overlaps, OverlapPolicy::Keep);
+    }
+
+    #[test]
+    fn test_meeting_worklogs_clipped_to_the_workday_without_lunch() {
+        let worklog = WorklogConfig {
+            workday_start: Some("09:00".to_string()),
+            workday_end: Some("17:30".to_string()),
+            ..WorklogConfig::default()
+        };
+        let meetings = MeetingsConfig {
+            clip_to_workday: true,
+            lunch_break: Some("12:00-13:00".to_string()),
+            ..MeetingsConfig::default()
+        };
+        let at = |hour, minute| Utc.with_ymd_and_hms(2026, 3, 2, hour, minute, 0).unwrap();
+        let time = |start, end| meetings.worklog_time_in(&Utc, &worklog, start, end);
+
+        assert_eq!(time(at(10, 0), at(11, 0)), (at(10, 0), 3600));
+        // A team lunch only logs the hour after the break
+        assert_eq!(time(at(12, 0), at(14, 0)), (at(13, 0), 3600));
+        assert_eq!(time(at(12, 15), at(12, 45)), (at(12, 45), 0));
+        assert_eq!(time(at(11, 30), at(12, 30)), (at(11, 30), 1800));
+        assert_eq!(time(at(8, 0), at(9, 30)), (at(9, 0), 1800));
+        assert_eq!(time(at(17, 0), at(18, 0)), (at(17, 0), 1800));
+        assert_eq!(time(at(18, 0), at(19, 0)).1, 0);
+
+        let unset = MeetingsConfig::default();
+        assert_eq!(
+            unset.worklog_time_in(&Utc, &worklog, at(12, 0), at(14, 0)),
+            (at(12, 0), 7200)
+        );
+    }
+}
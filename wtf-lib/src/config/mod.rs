@@ -0,0 +1,294 @@
+mod github;
+mod google;
+mod hooks;
+mod jira;
+mod meetings;
+mod network;
+mod sensitive;
+mod storage;
+mod sync;
+mod ui;
+mod worklog;
+
+pub use github::{GitHubApi, GithubConfig, IssuePatternsConfig};
+pub use google::{GoogleAuthFlow, GoogleConfig, GOOGLE_CALENDAR_EVENT_COLORS};
+pub use hooks::{HooksConfig, LinkingConfig};
+pub use jira::{JiraConfig, TeamConfig};
+pub use meetings::{MeetingRule, MeetingRulesConfig, MeetingsConfig, OverlapPolicy};
+pub use network::NetworkConfig;
+pub use sensitive::SensitiveString;
+pub use storage::{BackupConfig, StorageBackend, StorageConfig};
+pub use sync::{DaemonConfig, SyncConfig};
+pub use ui::{ConfirmationsConfig, UiConfig};
+pub use worklog::{ValidationConfig, WeekdayHours, WorklogConfig};
+
+use crate::secrets::Secret;
+use crate::utils::config_file;
+use config::{Config as ConfigLoader, File};
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::PathBuf;
+use std::{env, fs};
+
+/// Environment variable selecting a named profile; `--profile` sets it for the process
+/// and the processes it spawns
+pub const PROFILE_VAR: &str = "WTF_PROFILE";
+
+/// Directory holding the profiles: `$WTF_CONFIG_HOME` when set, `~/.config/wtf` otherwise.
+/// Without a profile it holds the config, database and backups itself.
+pub fn base_config_home() -> PathBuf {
+    if let Ok(custom_path) = env::var("WTF_CONFIG_HOME") {
+        PathBuf::from(custom_path)
+    } else {
+        expand_tilde("~/.config/wtf")
+    }
+}
+
+/// Directory holding the config, the database and the backups of the active profile:
+/// `profiles/<name>` under [`base_config_home`], or the base itself without a profile
+pub fn config_home() -> PathBuf {
+    match active_profile() {
+        Some(profile) => base_config_home().join("profiles").join(profile),
+        None => base_config_home(),
+    }
+}
+
+/// Profile named by [`PROFILE_VAR`], if any
+pub fn active_profile() -> Option<String> {
+    env::var(PROFILE_VAR)
+        .ok()
+        .filter(|profile| !profile.is_empty())
+}
+
+/// Profile names become directory names: letters, digits, `-` and `_` only
+pub fn is_valid_profile_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Names of the profiles created so far, sorted
+pub fn profiles() -> Vec<String> {
+    let mut profiles: Vec<String> = fs::read_dir(base_config_home().join("profiles"))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .collect()
+        })
+        .unwrap_or_default();
+    profiles.sort();
+    profiles
+}
+
+pub fn config_path() -> PathBuf {
+    config_home().join("config.toml")
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Config {
+    pub jira: JiraConfig,
+    pub github: GithubConfig,
+    #[serde(default)]
+    pub google: Option<GoogleConfig>,
+    #[serde(default)]
+    pub worklog: WorklogConfig,
+    #[serde(default)]
+    pub ui: UiConfig,
+    #[serde(default)]
+    pub confirmations: ConfirmationsConfig,
+    #[serde(default)]
+    pub daemon: DaemonConfig,
+    #[serde(default)]
+    pub backup: BackupConfig,
+    #[serde(default)]
+    pub network: NetworkConfig,
+    #[serde(default)]
+    pub storage: StorageConfig,
+    #[serde(default)]
+    pub hooks: HooksConfig,
+    #[serde(default)]
+    pub linking: LinkingConfig,
+    #[serde(default)]
+    pub meetings: MeetingsConfig,
+    #[serde(default)]
+    pub meeting_rules: MeetingRulesConfig,
+    #[serde(default)]
+    pub validation: ValidationConfig,
+    #[serde(default)]
+    pub sync: SyncConfig,
+    #[serde(default)]
+    pub team: TeamConfig,
+    /// Short names for issue keys, e.g. `standup = "PROJ-101"`, accepted wherever a key is
+    #[serde(default)]
+    pub aliases: HashMap<String, String>,
+}
+
+impl Config {
+    /// Returns true if essential Jira credentials have been configured.
+    pub fn is_configured(&self) -> bool {
+        !self.jira.base_url.is_empty() && !self.jira.username.is_empty()
+    }
+
+    /// Issue the activity of `repo` goes to when it names none: its `github.repo_issues`
+    /// entry, aliases expanded, unless that's a project key
+    pub fn github_default_issue(&self, repo: &str) -> Option<String> {
+        let issue = self.expand_issue_alias(self.github.repo_issue(repo)?);
+        let (project, number) = issue.rsplit_once('-')?;
+        (!project.is_empty() && !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+            .then(|| issue.to_uppercase())
+    }
+
+    /// Issue key the alias `key` stands for (names are case-insensitive), or `key` itself
+    /// when it isn't an alias
+    pub fn expand_issue_alias(&self, key: &str) -> String {
+        let key = key.trim();
+        self.aliases
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map_or_else(|| key.to_string(), |(_, issue)| issue.trim().to_string())
+    }
+
+    pub fn load() -> Result<Self, Box<dyn Error>> {
+        let config_path = config_path();
+        debug!("config path: {:?}", config_path);
+        let builder = config_file::with_env_overrides(
+            ConfigLoader::builder().add_source(File::from(config_path).required(false)),
+            &config_file::env_overrides(),
+        )?;
+        let mut config: Config = builder.build()?.try_deserialize()?;
+        // A token in config.toml wins; otherwise use the one `wtf config secret` stored
+        if config.jira.api_token.reveal().is_empty() {
+            if let Some(token) = keyring_secret(Secret::JiraToken) {
+                config.jira.api_token = SensitiveString::new(token);
+            }
+        }
+        if config.github.token().is_none() {
+            config.github.token = keyring_secret(Secret::GitHubToken).map(SensitiveString::new);
+        }
+        Ok(config)
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn Error>> {
+        let config_path = config_path();
+        let config_dir = config_path.parent().unwrap();
+        fs::create_dir_all(config_dir)?;
+
+        // Don't copy the keyring's token back into the file
+        let mut stored = self.clone();
+        let token = self.jira.api_token.reveal();
+        if !token.is_empty() && keyring_secret(Secret::JiraToken).as_deref() == Some(token) {
+            stored.jira.api_token = SensitiveString::new(String::new());
+        }
+        if let Some(token) = self.github.token() {
+            if keyring_secret(Secret::GitHubToken).as_deref() == Some(token) {
+                stored.github.token = None;
+            }
+        }
+        // Nor the values of the WTF_* environment variables
+        let overrides = config_file::env_overrides();
+        let toml = if overrides.is_empty() {
+            toml::to_string(&stored)?
+        } else {
+            let file = fs::read_to_string(&config_path)
+                .ok()
+                .and_then(|text| toml::from_str(&text).ok())
+                .unwrap_or_default();
+            let mut table = toml::Table::try_from(&stored)?;
+            config_file::keep_file_values(&mut table, &file, overrides.keys());
+            toml::to_string(&table)?
+        };
+        fs::write(config_path, toml)?;
+
+        Ok(())
+    }
+}
+
+/// The keyring's value for `secret`; `None` as well when there's no keyring (headless
+/// machines keep their secrets in config.toml)
+fn keyring_secret(secret: Secret) -> Option<String> {
+    secret
+        .get()
+        .inspect_err(|e| debug!("No {} secret from the keyring: {}", secret.name(), e))
+        .ok()
+        .flatten()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+pub fn expand_path(path: &str) -> PathBuf {
+    let expanded = shellexpand::full(path).unwrap_or(std::borrow::Cow::Borrowed(path));
+    PathBuf::from(expanded.as_ref())
+}
+
+fn expand_tilde(path: &str) -> PathBuf {
+    expand_path(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_names_are_safe_directory_names() {
+        assert!(is_valid_profile_name("client-a_2"));
+        assert!(!is_valid_profile_name(""));
+        assert!(!is_valid_profile_name("../other"));
+        assert!(!is_valid_profile_name("client a"));
+    }
+
+    #[test]
+    fn test_issue_aliases_expand_case_insensitively() {
+        let config: Config = toml::from_str(
+            r#"
+[jira]
+base_url = ""
+username = ""
+api_token = ""
+
+[github]
+
+[aliases]
+standup = "PROJ-101"
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.expand_issue_alias("Standup"), "PROJ-101");
+        assert_eq!(config.expand_issue_alias(" OPS-7 "), "OPS-7");
+    }
+
+    #[test]
+    fn test_github_repo_issues() {
+        let config = Config {
+            github: GithubConfig {
+                repo_issues: HashMap::from([
+                    ("infra".to_string(), "ops-12".to_string()),
+                    ("acme/infra".to_string(), "support".to_string()),
+                    ("billing".to_string(), "BILL".to_string()),
+                ]),
+                ..GithubConfig::default()
+            },
+            aliases: HashMap::from([("support".to_string(), "OPS-1".to_string())]),
+            ..Config::default()
+        };
+
+        assert_eq!(
+            config.github_default_issue("acme/infra").as_deref(),
+            Some("OPS-1")
+        );
+        assert_eq!(
+            config.github_default_issue("me/infra").as_deref(),
+            Some("OPS-12")
+        );
+        assert_eq!(config.github_default_issue("acme/billing"), None);
+        assert_eq!(config.github.repo_project("acme/billing"), Some("BILL"));
+        assert_eq!(config.github.repo_project("me/infra"), None);
+        assert_eq!(config.github_default_issue("acme/api"), None);
+    }
+}
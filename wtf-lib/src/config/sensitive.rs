@@ -1,141 +1,9 @@
 use base64::{engine::general_purpose::URL_SAFE, Engine as _};
-use config::{Config as ConfigLoader, File};
-use log::debug;
 use regex::Regex;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::collections::HashMap;
 use std::error::Error;
-use std::path::PathBuf;
+use std::fmt;
 use std::str::FromStr;
-use std::{env, fmt, fs};
-
-/// Google Calendar event color names, indexed by (colorId - 1).
-/// colorId "1" → "Lavender", "2" → "Sage", ..., "11" → "Tomato"
-pub const GOOGLE_CALENDAR_EVENT_COLORS: [&str; 11] = [
-    "Lavender",
-    "Sage",
-    "Grape",
-    "Flamingo",
-    "Banana",
-    "Tangerine",
-    "Peacock",
-    "Graphite",
-    "Blueberry",
-    "Basil",
-    "Tomato",
-];
-
-fn get_config_path() -> PathBuf {
-    if let Ok(custom_path) = env::var("WTF_CONFIG_HOME") {
-        PathBuf::from(custom_path).join("config.toml")
-    } else {
-        expand_tilde("~/.config/wtf/config.toml")
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Config {
-    pub jira: JiraConfig,
-    pub github: GithubConfig,
-    #[serde(default)]
-    pub google: Option<GoogleConfig>,
-    #[serde(default)]
-    pub worklog: WorklogConfig,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct JiraConfig {
-    pub base_url: String,
-    pub username: String,
-    pub api_token: SensitiveString,
-    #[serde(default)]
-    pub auto_follow_sprint_pattern: Option<String>,
-    #[serde(default)]
-    pub project_keys: Vec<String>,
-}
-
-impl Default for JiraConfig {
-    fn default() -> Self {
-        Self {
-            base_url: String::new(),
-            username: String::new(),
-            api_token: SensitiveString::new(String::new()),
-            auto_follow_sprint_pattern: None,
-            project_keys: Vec::new(),
-        }
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GithubConfig {
-    #[serde(default)]
-    pub organisation: Option<String>,
-}
-
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
-pub struct GoogleConfig {
-    pub credentials_path: String,
-    pub token_cache_path: String,
-    /// Maps Google Calendar color name (e.g. "Grape") to a Jira issue key (e.g. "INTERNAL-42").
-    /// Used as highest-priority auto-link source in meetings.
-    #[serde(default)]
-    pub color_labels: HashMap<String, String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct WorklogConfig {
-    #[serde(default = "default_daily_hours_limit")]
-    pub daily_hours_limit: f64,
-}
-
-impl Default for WorklogConfig {
-    fn default() -> Self {
-        Self {
-            daily_hours_limit: 8.0,
-        }
-    }
-}
-
-fn default_daily_hours_limit() -> f64 {
-    8.0
-}
-
-impl Default for Config {
-    fn default() -> Self {
-        Self {
-            jira: JiraConfig::default(),
-            github: GithubConfig { organisation: None },
-            google: None,
-            worklog: WorklogConfig::default(),
-        }
-    }
-}
-
-impl Config {
-    /// Returns true if essential Jira credentials have been configured.
-    pub fn is_configured(&self) -> bool {
-        !self.jira.base_url.is_empty() && !self.jira.username.is_empty()
-    }
-
-    pub fn load() -> Result<Self, Box<dyn Error>> {
-        let config_path = get_config_path();
-        debug!("config path: {:?}", config_path);
-        let builder = ConfigLoader::builder().add_source(File::from(config_path).required(false));
-        let config = builder.build()?.try_deserialize()?;
-        Ok(config)
-    }
-
-    pub fn save(&self) -> Result<(), Box<dyn Error>> {
-        let config_path = get_config_path();
-        let config_dir = config_path.parent().unwrap();
-        fs::create_dir_all(config_dir)?;
-
-        let toml = toml::to_string(self)?;
-        fs::write(config_path, toml)?;
-
-        Ok(())
-    }
-}
 
 pub struct SensitiveString(String);
 
@@ -306,12 +174,3 @@ mod tests {
         assert!(format!("{:?}", s).contains("[HIDDEN]"));
     }
 }
-
-pub fn expand_path(path: &str) -> PathBuf {
-    let expanded = shellexpand::full(path).unwrap_or(std::borrow::Cow::Borrowed(path));
-    PathBuf::from(expanded.as_ref())
-}
-
-fn expand_tilde(path: &str) -> PathBuf {
-    expand_path(path)
-}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+/// Sharing the local worklogs and meeting links with other machines, through a folder
+/// they all see (Dropbox, Syncthing, a network share...)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyncConfig {
+    #[serde(default)]
+    pub folder: Option<String>,
+    /// Name of this machine's file in the folder; defaults to the host name
+    #[serde(default)]
+    pub machine: Option<String>,
+}
+
+impl SyncConfig {
+    /// The configured machine name, or the host name with the characters a file name
+    /// can't take replaced
+    pub fn machine_name(&self) -> Option<String> {
+        if let Some(machine) = self.machine.as_deref().map(str::trim) {
+            return Some(machine.to_string()).filter(|machine| !machine.is_empty());
+        }
+        let hostname = ["HOSTNAME", "COMPUTERNAME"]
+            .iter()
+            .find_map(|var| std::env::var(var).ok())
+            .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+            .or_else(|| {
+                std::process::Command::new("hostname")
+                    .output()
+                    .ok()
+                    .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+            })?;
+        let name: String = hostname
+            .trim()
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        Some(name).filter(|name| !name.is_empty())
+    }
+}
+
+/// Background sync run by `wtf daemon`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DaemonConfig {
+    /// Minutes between two syncs.
+    #[serde(default = "default_sync_interval_minutes")]
+    pub interval_minutes: u64,
+}
+
+impl Default for DaemonConfig {
+    fn default() -> Self {
+        Self {
+            interval_minutes: default_sync_interval_minutes(),
+        }
+    }
+}
+
+fn default_sync_interval_minutes() -> u64 {
+    30
+}
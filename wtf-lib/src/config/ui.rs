@@ -0,0 +1,49 @@
+use super::default_true;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UiConfig {
+    /// Draw the TUI with plain ASCII only (no emoji, braille or box-drawing characters),
+    /// for terminals and screen readers that garble them.
+    #[serde(default)]
+    pub ascii_mode: bool,
+    /// Use orange/sky blue instead of yellow/green for worklog statuses.
+    #[serde(default)]
+    pub color_blind_palette: bool,
+    /// Fetch in the background every this many minutes while the TUI is open, like `u`;
+    /// 0 turns it off.
+    #[serde(default)]
+    pub auto_refresh_minutes: u64,
+}
+
+/// Which destructive TUI actions ask for confirmation, and how strictly
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfirmationsConfig {
+    /// Ask before unlinking a meeting from its issue.
+    #[serde(default = "default_true")]
+    pub unlink: bool,
+    /// Make reverting a push require typing its total hours; a plain Y/N otherwise.
+    #[serde(default = "default_true")]
+    pub revert_type_hours: bool,
+}
+
+impl Default for ConfirmationsConfig {
+    fn default() -> Self {
+        Self {
+            unlink: true,
+            revert_type_hours: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_confirmations_keep_defaults_for_missing_flags() {
+        let confirmations: ConfirmationsConfig = toml::from_str("unlink = false").unwrap();
+        assert!(!confirmations.unlink);
+        assert!(confirmations.revert_type_hours);
+    }
+}
@@ -0,0 +1,274 @@
+use crate::duration::DurationFormat;
+use crate::utils::dates;
+use crate::utils::period::Period;
+use crate::utils::worklog_rules::{Severity, ValidationRule};
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorklogConfig {
+    #[serde(default = "default_daily_hours_limit")]
+    pub daily_hours_limit: f64,
+    /// Length of the `1d` of a logged duration; `daily_hours_limit` when unset.
+    #[serde(default)]
+    pub hours_per_day: Option<f64>,
+    /// How durations are displayed: `decimal` (1.5h), `jira` (1d 2h 30m) or `clock` (1:30).
+    #[serde(default)]
+    pub duration_format: DurationFormat,
+    /// Hours expected on given days of the week instead of `daily_hours_limit` on weekdays
+    /// and nothing on weekends, e.g. `fri = 0` for a 4-day week.
+    #[serde(default)]
+    pub weekday_hours: WeekdayHours,
+    /// Start of my working day, `HH:MM` local time: gap fills are logged from there, and
+    /// worklogs made from GitHub sessions don't start earlier.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workday_start: Option<String>,
+    /// End of my working day, `HH:MM` local time: worklogs made from GitHub sessions end
+    /// by then.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workday_end: Option<String>,
+    /// Days the TUI wizard and gap filling work on when no sprint with dates is selected:
+    /// `week` (default), `last-week`, `month` or `last-month`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub period: Option<String>,
+}
+
+impl Default for WorklogConfig {
+    fn default() -> Self {
+        Self {
+            daily_hours_limit: 8.0,
+            hours_per_day: None,
+            duration_format: DurationFormat::default(),
+            weekday_hours: WeekdayHours::default(),
+            workday_start: None,
+            workday_end: None,
+            period: None,
+        }
+    }
+}
+
+impl WorklogConfig {
+    /// Hours in a day of logged time, e.g. in `1d 2h`
+    pub fn hours_per_day(&self) -> f64 {
+        self.hours_per_day
+            .filter(|hours| *hours > 0.0)
+            .unwrap_or(self.daily_hours_limit)
+    }
+
+    /// Hours expected on `day`, absences aside
+    pub fn target_hours(&self, day: NaiveDate) -> f64 {
+        let weekday = day.weekday();
+        self.weekday_hours.get(weekday).unwrap_or(
+            if matches!(weekday, Weekday::Sat | Weekday::Sun) {
+                0.0
+            } else {
+                self.daily_hours_limit
+            },
+        )
+    }
+
+    /// The period to work on without a sprint, the current week when unset or invalid
+    pub fn period(&self) -> Period {
+        let today = Local::now().date_naive();
+        self.period
+            .as_deref()
+            .and_then(|spec| {
+                Period::parse(spec, today)
+                    .map_err(|e| warn!("worklog.period: {}", e))
+                    .ok()
+            })
+            .unwrap_or_else(|| Period::week_of(today))
+    }
+
+    /// Whether any time is expected on `day`, absences aside
+    pub fn is_workday(&self, day: NaiveDate) -> bool {
+        self.target_hours(day) > 0.0
+    }
+
+    /// Hours expected from `from` to `to` (inclusive), skipping the days `is_absent`
+    pub fn capacity(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        is_absent: impl Fn(NaiveDate) -> bool,
+    ) -> f64 {
+        from.iter_days()
+            .take_while(|day| *day <= to)
+            .filter(|day| !is_absent(*day))
+            .map(|day| self.target_hours(day))
+            .sum()
+    }
+
+    pub fn workday_start(&self) -> Option<NaiveTime> {
+        self.workday_start
+            .as_deref()
+            .and_then(dates::parse_time_of_day)
+    }
+
+    pub fn workday_end(&self) -> Option<NaiveTime> {
+        self.workday_end
+            .as_deref()
+            .and_then(dates::parse_time_of_day)
+    }
+
+    /// When a worklog filling `day` starts: `workday_start`, or noon UTC so that the
+    /// date stays the same in most time zones
+    pub fn day_start(&self, day: NaiveDate) -> DateTime<Utc> {
+        match self.workday_start() {
+            Some(start) => dates::at_local_time(&Local, day, start),
+            None => {
+                Utc.from_utc_datetime(&day.and_time(NaiveTime::from_hms_opt(12, 0, 0).unwrap()))
+            }
+        }
+    }
+
+    /// Move a worklog of `seconds` starting at `started` inside the working window of its
+    /// day, shortened to the window's length if needed: a session pushed late at night is
+    /// logged at the end of the day, one from 2 a.m. at its start.
+    pub fn clamp_to_workday(&self, started: DateTime<Utc>, seconds: i64) -> (DateTime<Utc>, i64) {
+        dates::clamp_in(
+            &Local,
+            self.workday_start(),
+            self.workday_end(),
+            started,
+            seconds,
+        )
+    }
+}
+
+/// Per-weekday overrides of the hours expected, for part-time schedules
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WeekdayHours {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mon: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tue: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wed: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thu: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fri: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sat: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sun: Option<f64>,
+}
+
+impl WeekdayHours {
+    pub fn get(&self, weekday: Weekday) -> Option<f64> {
+        match weekday {
+            Weekday::Mon => self.mon,
+            Weekday::Tue => self.tue,
+            Weekday::Wed => self.wed,
+            Weekday::Thu => self.thu,
+            Weekday::Fri => self.fri,
+            Weekday::Sat => self.sat,
+            Weekday::Sun => self.sun,
+        }
+        .map(|hours| hours.max(0.0))
+    }
+}
+
+fn default_daily_hours_limit() -> f64 {
+    8.0
+}
+
+/// Rules checked on worklogs when they are staged
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationConfig {
+    /// Most hours logged on a day, every worklog of the day counted.
+    #[serde(default)]
+    pub max_hours_per_day: Option<f64>,
+    /// Projects worklogs may go to, e.g. `["PROJ", "OPS"]`; any when empty.
+    #[serde(default)]
+    pub allowed_prefixes: Vec<String>,
+    /// Fewest characters in a worklog comment; 0 for no minimum.
+    #[serde(default)]
+    pub min_comment_length: usize,
+    /// Flag worklogs on issues in one of `closed_statuses`.
+    #[serde(default)]
+    pub no_closed_issues: bool,
+    #[serde(default = "default_closed_statuses")]
+    pub closed_statuses: Vec<String>,
+    /// Rules keeping a worklog from being staged; the others only warn.
+    #[serde(default)]
+    pub blocking: Vec<ValidationRule>,
+}
+
+impl Default for ValidationConfig {
+    fn default() -> Self {
+        Self {
+            max_hours_per_day: None,
+            allowed_prefixes: Vec::new(),
+            min_comment_length: 0,
+            no_closed_issues: false,
+            closed_statuses: default_closed_statuses(),
+            blocking: Vec::new(),
+        }
+    }
+}
+
+impl ValidationConfig {
+    pub fn severity(&self, rule: ValidationRule) -> Severity {
+        if self.blocking.contains(&rule) {
+            Severity::Blocking
+        } else {
+            Severity::Warning
+        }
+    }
+
+    pub fn is_closed_status(&self, status: &str) -> bool {
+        self.closed_statuses
+            .iter()
+            .any(|closed| closed.eq_ignore_ascii_case(status.trim()))
+    }
+}
+
+fn default_closed_statuses() -> Vec<String> {
+    ["Done", "Closed", "Resolved"].map(String::from).to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weekday_hours_override_the_daily_hours() {
+        let worklog: WorklogConfig = toml::from_str(
+            r#"
+daily_hours_limit = 7.5
+
+[weekday_hours]
+wed = 4
+fri = 0
+"#,
+        )
+        .unwrap();
+        // 2026-03-02 is a Monday
+        let day =
+            |offset: u64| NaiveDate::from_ymd_opt(2026, 3, 2).unwrap() + chrono::Days::new(offset);
+        assert_eq!(worklog.target_hours(day(0)), 7.5);
+        assert_eq!(worklog.target_hours(day(2)), 4.0);
+        assert!(!worklog.is_workday(day(4)));
+        assert!(!worklog.is_workday(day(5)));
+        assert_eq!(worklog.capacity(day(0), day(6), |_| false), 26.5);
+        assert_eq!(worklog.capacity(day(0), day(6), |d| d == day(1)), 19.0);
+    }
+
+    #[test]
+    fn test_days_filled_from_the_workday_start_or_noon_utc() {
+        let day = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        assert_eq!(
+            WorklogConfig::default().day_start(day),
+            Utc.with_ymd_and_hms(2026, 3, 2, 12, 0, 0).unwrap()
+        );
+        // Without a working window, worklogs stay where they are
+        let started = Utc.with_ymd_and_hms(2026, 3, 2, 2, 0, 0).unwrap();
+        assert_eq!(
+            WorklogConfig::default().clamp_to_workday(started, 5400),
+            (started, 5400)
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::client::github_client::GitHubClient;
+use crate::client::jira_client::JiraClient;
+use crate::config::Config;
+use crate::error::WtfResult;
+use crate::storage::database::{Database, DATABASE};
+use std::error::Error;
+use std::sync::Arc;
+
+/// The database and configuration services work with.
+///
+/// The CLI uses [`Context::production`]: the active profile's database, and its config
+/// file read whenever it's needed. Programs embedding wtf-lib, and tests, build their
+/// own so that they don't depend on `~/.config/wtf`:
+///
+/// ```no_run
+/// use wtf_lib::config::Config;
+/// use wtf_lib::context::Context;
+/// use wtf_lib::services::jira_service::IssueService;
+/// use wtf_lib::storage::database::Database;
+///
+/// let context = Context::new(Database::open("/tmp/wtf-db").unwrap(), Config::default());
+/// let issues = IssueService::new(&context).get_all_issues();
+/// ```
+#[derive(Clone)]
+pub struct Context {
+    database: Database,
+    /// `None` reads the active profile's config file on each use
+    config: Option<Arc<Config>>,
+}
+
+impl Context {
+    pub fn new(database: Database, config: Config) -> Self {
+        Self {
+            database,
+            config: Some(Arc::new(config)),
+        }
+    }
+
+    /// An in-memory database and the default config
+    pub fn temporary() -> Self {
+        Self::new(Database::temporary(), Config::default())
+    }
+
+    /// The active profile's database and config file
+    pub fn production() -> Self {
+        Self {
+            database: Database::clone(&DATABASE),
+            config: None,
+        }
+    }
+
+    pub fn with_config(self, config: Config) -> Self {
+        Self {
+            config: Some(Arc::new(config)),
+            ..self
+        }
+    }
+
+    pub fn database(&self) -> &Database {
+        &self.database
+    }
+
+    /// The context's config, or the active profile's config file
+    pub fn config(&self) -> Result<Config, Box<dyn Error>> {
+        match &self.config {
+            Some(config) => Ok(Config::clone(config)),
+            None => Config::load(),
+        }
+    }
+
+    pub fn jira_client(&self) -> JiraClient {
+        match &self.config {
+            Some(config) => JiraClient::new(&config.jira, &config.network),
+            None => JiraClient::create(),
+        }
+    }
+
+    pub fn github_client(&self) -> WtfResult<GitHubClient> {
+        match &self.config {
+            Some(config) => GitHubClient::new(&config.github, &config.network),
+            None => GitHubClient::create(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::data::Issue;
+    use crate::services::jira_service::IssueService;
+    use chrono::Utc;
+
+    fn issue(key: &str) -> Issue {
+        Issue {
+            id: key.to_string(),
+            key: key.to_string(),
+            summary: String::new(),
+            status: "To Do".to_string(),
+            created: Utc::now(),
+            sprint_ids: Vec::new(),
+            epic: None,
+            components: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
+        }
+    }
+
+    #[test]
+    fn test_services_of_separate_contexts_share_nothing() {
+        let first = Context::temporary();
+        let second = Context::temporary();
+        IssueService::new(&first).save_issue(&issue("PROJ-1"));
+
+        assert_eq!(IssueService::new(&first).get_all_issues().len(), 1);
+        assert!(IssueService::new(&second).get_all_issues().is_empty());
+        // A clone works on the same database
+        assert_eq!(IssueService::new(&first.clone()).get_all_issues().len(), 1);
+    }
+
+    #[test]
+    fn test_context_config_is_used_instead_of_the_config_file() {
+        let mut config = Config::default();
+        config.jira.base_url = "https://example.atlassian.net".to_string();
+        let context = Context::temporary().with_config(config);
+        assert_eq!(
+            context.config().unwrap().jira.base_url,
+            "https://example.atlassian.net"
+        );
+    }
+}
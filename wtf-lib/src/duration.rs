@@ -1,5 +1,11 @@
+use crate::config::Config;
 use chrono::Duration;
+use serde::{Deserialize, Serialize};
 use std::num::ParseIntError;
+use std::sync::RwLock;
+
+/// Display settings in effect, set from the `[worklog]` section on first use
+static DISPLAY: RwLock<Option<DurationDisplay>> = RwLock::new(None);
 
 #[derive(Debug)]
 pub enum DurationParserError {
@@ -13,21 +19,209 @@ impl From<ParseIntError> for DurationParserError {
     }
 }
 
+/// How durations are displayed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DurationFormat {
+    /// `1.5h`, or `45m` under an hour
+    #[default]
+    Decimal,
+    /// `1d 2h 30m`, with days of `hours_per_day`
+    Jira,
+    /// `1:30`
+    Clock,
+}
+
+/// The format and the length of a working day durations are displayed with
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DurationDisplay {
+    pub format: DurationFormat,
+    pub hours_per_day: f64,
+}
+
+impl From<&Config> for DurationDisplay {
+    fn from(config: &Config) -> Self {
+        Self {
+            format: config.worklog.duration_format,
+            hours_per_day: config.worklog.hours_per_day(),
+        }
+    }
+}
+
+/// Display durations with `display` instead of the `[worklog]` section of the config,
+/// e.g. after the settings changed
+pub fn configure(display: DurationDisplay) {
+    if let Ok(mut current) = DISPLAY.write() {
+        *current = Some(display);
+    }
+}
+
+/// The display settings in effect
+pub fn display() -> DurationDisplay {
+    if let Some(display) = DISPLAY.read().ok().and_then(|display| *display) {
+        return display;
+    }
+    let display = DurationDisplay::from(&Config::load().unwrap_or_default());
+    configure(display);
+    display
+}
+
+/// Parse a duration such as `2h`, `1h30`, `1h 30m`, `1.5h`, `1:30` or `1d 2h 30m`.
+///
+/// Days and weeks are calendar days and weeks here; use [`parse_work_duration`] for
+/// time logged on an issue.
 pub fn parse_duration(time_str: &str) -> Result<Duration, DurationParserError> {
-    if time_str.is_empty() {
+    if let Some(duration) = parse_clock(time_str)? {
+        return Ok(duration);
+    }
+    parse_with_day_length(time_str, Duration::days(1), Duration::weeks(1))
+}
+
+/// Parse a duration the way Jira reads logged time: a day is `hours_per_day` and a week
+/// is five of those days, so `1d 2h 30m` is 10h30 with an 8 hour day. A bare number is
+/// hours, so `1.5` is 1h30 too.
+pub fn parse_work_duration(
+    time_str: &str,
+    hours_per_day: f64,
+) -> Result<Duration, DurationParserError> {
+    if let Some(duration) = parse_clock(time_str)? {
+        return Ok(duration);
+    }
+    let trimmed = time_str.trim();
+    if !trimmed.is_empty()
+        && trimmed
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == '.' || c == ',')
+    {
+        return parse_with_day_length(
+            &format!("{}h", trimmed),
+            Duration::days(1),
+            Duration::weeks(1),
+        );
+    }
+    let day = Duration::seconds((hours_per_day * 3600.0).round() as i64);
+    parse_with_day_length(time_str, day, day * 5)
+}
+
+/// `H:MM`, e.g. `1:30` or `01:30`; `None` when `time_str` isn't written that way
+fn parse_clock(time_str: &str) -> Result<Option<Duration>, DurationParserError> {
+    let Some((hours, minutes)) = time_str.trim().split_once(':') else {
+        return Ok(None);
+    };
+    if minutes.len() != 2 {
+        return Err(DurationParserError::InvalidFormat);
+    }
+    let minutes: i64 = minutes.parse()?;
+    if minutes >= 60 {
+        return Err(DurationParserError::InvalidFormat);
+    }
+    Ok(Some(
+        Duration::hours(hours.parse()?) + Duration::minutes(minutes),
+    ))
+}
+
+/// `seconds` written with the display settings in effect
+pub fn format_seconds(seconds: i64) -> String {
+    let display = display();
+    format_duration(seconds, display.format, display.hours_per_day)
+}
+
+/// `hours` written with the display settings in effect
+pub fn format_hours(hours: f64) -> String {
+    format_seconds((hours * 3600.0).round() as i64)
+}
+
+/// `seconds` written in `format`, with days of `hours_per_day` for [`DurationFormat::Jira`]
+pub fn format_duration(seconds: i64, format: DurationFormat, hours_per_day: f64) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let seconds = seconds.abs();
+    let minutes = (seconds + 30) / 60;
+    let text = match format {
+        DurationFormat::Decimal if seconds < 3_600 => format!("{}m", seconds / 60),
+        DurationFormat::Decimal => format!("{:.1}h", seconds as f64 / 3_600.0),
+        DurationFormat::Clock => format!("{}:{:02}", minutes / 60, minutes % 60),
+        DurationFormat::Jira => {
+            let day_minutes = (hours_per_day * 60.0).round() as i64;
+            let (days, rest) = if day_minutes > 0 {
+                (minutes / day_minutes, minutes % day_minutes)
+            } else {
+                (0, minutes)
+            };
+            let parts: Vec<String> = [(days, "d"), (rest / 60, "h"), (rest % 60, "m")]
+                .into_iter()
+                .filter(|(value, _)| *value > 0)
+                .map(|(value, unit)| format!("{}{}", value, unit))
+                .collect();
+            if parts.is_empty() {
+                "0m".to_string()
+            } else {
+                parts.join(" ")
+            }
+        }
+    };
+    format!("{}{}", sign, text)
+}
+
+fn parse_with_day_length(
+    time_str: &str,
+    day: Duration,
+    week: Duration,
+) -> Result<Duration, DurationParserError> {
+    let input = time_str.trim().to_lowercase();
+    if input.is_empty() {
         return Err(DurationParserError::InvalidFormat);
     }
-    let (num_str, unit) = time_str.split_at(time_str.len() - 1);
-    let num = num_str.parse::<i64>()?;
 
-    match unit {
-        "h" => Ok(Duration::hours(num)),
-        "m" => Ok(Duration::minutes(num)),
-        "s" => Ok(Duration::seconds(num)),
-        "d" => Ok(Duration::days(num)),
-        "w" => Ok(Duration::weeks(num)),
-        _ => Err(DurationParserError::InvalidFormat),
+    let mut total = Duration::zero();
+    let mut previous_unit: Option<String> = None;
+    let mut rest = input.as_str();
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+            .unwrap_or(rest.len());
+        let (number, after) = rest.split_at(number_len);
+        let after = after.trim_start();
+        let unit_len = after
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(after.len());
+        let (unit, after) = after.split_at(unit_len);
+        rest = after.trim_start();
+
+        let unit = match unit {
+            // "1h30": a bare number right after hours is minutes
+            "" if rest.is_empty() && previous_unit.as_deref() == Some("h") => "m",
+            "w" | "wk" | "week" | "weeks" => "w",
+            "d" | "day" | "days" => "d",
+            "h" | "hr" | "hrs" | "hour" | "hours" => "h",
+            "m" | "min" | "mins" | "minute" | "minutes" => "m",
+            "s" | "sec" | "secs" | "second" | "seconds" => "s",
+            _ if number.is_empty() => {
+                // Report the missing number rather than the unit, e.g. "abch"
+                number.parse::<i64>()?;
+                return Err(DurationParserError::InvalidFormat);
+            }
+            _ => return Err(DurationParserError::InvalidFormat),
+        };
+        let unit_seconds = match unit {
+            "w" => week.num_seconds(),
+            "d" => day.num_seconds(),
+            "h" => 3600,
+            "m" => 60,
+            _ => 1,
+        };
+        let seconds = if number.contains(['.', ',']) {
+            let value: f64 = number
+                .replace(',', ".")
+                .parse()
+                .map_err(|_| DurationParserError::InvalidFormat)?;
+            (value * unit_seconds as f64).round() as i64
+        } else {
+            number.parse::<i64>()? * unit_seconds
+        };
+        total += Duration::seconds(seconds);
+        previous_unit = Some(unit.to_string());
     }
+    Ok(total)
 }
 
 #[cfg(test)]
@@ -75,6 +269,89 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_parse_compound_durations() {
+        let expected = Duration::hours(1) + Duration::minutes(30);
+        assert_eq!(parse_duration("1h30").unwrap(), expected);
+        assert_eq!(parse_duration("1h30m").unwrap(), expected);
+        assert_eq!(parse_duration("1h 30m").unwrap(), expected);
+        assert_eq!(parse_duration("1.5h").unwrap(), expected);
+        assert_eq!(parse_duration("1,5 hours").unwrap(), expected);
+    }
+
+    #[test]
+    fn test_work_duration_uses_working_days() {
+        assert_eq!(
+            parse_work_duration("1d 2h 30m", 8.0).unwrap(),
+            Duration::hours(10) + Duration::minutes(30)
+        );
+        assert_eq!(
+            parse_work_duration("1w", 7.5).unwrap(),
+            Duration::minutes(2250)
+        );
+    }
+
+    #[test]
+    fn test_bare_number_is_not_a_duration() {
+        assert!(matches!(
+            parse_duration("90"),
+            Err(DurationParserError::InvalidFormat)
+        ));
+    }
+
+    #[test]
+    fn test_parse_clock_durations() {
+        let expected = Duration::hours(1) + Duration::minutes(30);
+        assert_eq!(parse_duration("1:30").unwrap(), expected);
+        assert_eq!(parse_work_duration("01:30", 8.0).unwrap(), expected);
+        assert!(parse_duration("1:75").is_err());
+        assert!(parse_duration("1:5").is_err());
+    }
+
+    #[test]
+    fn test_bare_numbers_are_hours_of_work() {
+        assert_eq!(
+            parse_work_duration("1,5", 8.0).unwrap(),
+            Duration::minutes(90)
+        );
+        assert_eq!(parse_work_duration("2", 8.0).unwrap(), Duration::hours(2));
+    }
+
+    #[test]
+    fn test_format_durations() {
+        let seconds = 2 * 8 * 3600 + 3 * 3600 + 30 * 60;
+        assert_eq!(
+            format_duration(seconds, DurationFormat::Jira, 8.0),
+            "2d 3h 30m"
+        );
+        assert_eq!(
+            format_duration(seconds, DurationFormat::Jira, 7.5),
+            "2d 4h 30m"
+        );
+        assert_eq!(format_duration(5400, DurationFormat::Decimal, 8.0), "1.5h");
+        assert_eq!(format_duration(2700, DurationFormat::Decimal, 8.0), "45m");
+        assert_eq!(format_duration(5400, DurationFormat::Clock, 8.0), "1:30");
+        assert_eq!(format_duration(-900, DurationFormat::Clock, 8.0), "-0:15");
+        assert_eq!(format_duration(0, DurationFormat::Jira, 8.0), "0m");
+    }
+
+    #[test]
+    fn test_formatted_durations_parse_back() {
+        for format in [
+            DurationFormat::Decimal,
+            DurationFormat::Jira,
+            DurationFormat::Clock,
+        ] {
+            let text = format_duration(37_800, format, 7.5);
+            assert_eq!(
+                parse_work_duration(&text, 7.5).unwrap(),
+                Duration::seconds(37_800),
+                "{}",
+                text
+            );
+        }
+    }
+
     #[test]
     fn test_empty_string() {
         assert!(matches!(
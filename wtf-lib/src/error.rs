@@ -0,0 +1,101 @@
+use crate::models::jira::JiraError;
+use crate::services::google_service::GoogleServiceError;
+use std::error::Error;
+use thiserror::Error;
+
+pub type WtfResult<T> = Result<T, WtfError>;
+
+/// What can go wrong in wtf-lib. The messages are meant for the user as is; [`WtfError::hint`]
+/// adds what to do about it when there's something to do.
+#[derive(Debug, Error)]
+pub enum WtfError {
+    #[error("{kind} '{id}' not found")]
+    NotFound { kind: &'static str, id: String },
+    /// An operation the data doesn't allow, e.g. splitting a session outside its bounds
+    #[error("{0}")]
+    Invalid(String),
+    #[error("Jira: {0}")]
+    Jira(#[from] JiraError),
+    #[error("{0}")]
+    Google(#[from] GoogleServiceError),
+    #[error("GitHub CLI is not installed or configured")]
+    GitHubUnavailable,
+    #[error("GitHub error: {0}")]
+    GitHub(String),
+    /// An API answered with something wtf can't read
+    #[error("Unexpected data from {api}: {message}")]
+    Malformed { api: &'static str, message: String },
+    #[error("Database error: {0}")]
+    Storage(Box<dyn Error + Send + Sync>),
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl WtfError {
+    pub fn not_found(kind: &'static str, id: impl Into<String>) -> Self {
+        WtfError::NotFound {
+            kind,
+            id: id.into(),
+        }
+    }
+
+    /// What the user can do to fix it, when it's up to them
+    pub fn hint(&self) -> Option<&'static str> {
+        match self {
+            WtfError::Jira(e) => match e.status() {
+                Some(401) => Some("check jira.username and jira.token with `wtf config`"),
+                Some(403) => Some("your Jira account lacks the permission for this"),
+                Some(404) => Some("the issue or worklog is gone from Jira, run `wtf fetch`"),
+                Some(429) | Some(500..) => Some("Jira is unavailable, try again later"),
+                _ if matches!(e, JiraError::RequestError(_)) => {
+                    Some("check your connection and jira.url")
+                }
+                _ => None,
+            },
+            WtfError::GitHubUnavailable => {
+                Some("install the GitHub CLI and run `gh auth login`, or set github.token")
+            }
+            WtfError::GitHub(message)
+                if message.contains("auth") || message.contains("HTTP 401") =>
+            {
+                Some("run `gh auth login` again, or check github.token")
+            }
+            WtfError::Malformed { .. } => Some("run with --debug and report it"),
+            _ => None,
+        }
+    }
+
+    /// The message followed by the hint, for the TUI log and the CLI
+    pub fn with_hint(&self) -> String {
+        match self.hint() {
+            Some(hint) => format!("{} ({})", self, hint),
+            None => self.to_string(),
+        }
+    }
+}
+
+impl From<Box<dyn Error + Send + Sync>> for WtfError {
+    fn from(e: Box<dyn Error + Send + Sync>) -> Self {
+        WtfError::Storage(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hints_follow_the_jira_status() {
+        let unauthorized = WtfError::from(JiraError::ApiError("401 Unauthorized".to_string()));
+        assert_eq!(
+            unauthorized.with_hint(),
+            "Jira: API error: 401 Unauthorized (check jira.username and jira.token with `wtf config`)"
+        );
+        let invalid = WtfError::Invalid("Start must be before end".to_string());
+        assert_eq!(invalid.with_hint(), "Start must be before end");
+        assert_eq!(
+            WtfError::not_found("Session", "abc").to_string(),
+            "Session 'abc' not found"
+        );
+    }
+}
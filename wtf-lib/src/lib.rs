@@ -1,8 +1,11 @@
 pub mod client;
 pub mod common;
 pub mod config;
+pub mod context;
 pub mod duration;
+pub mod error;
 pub mod models;
+pub mod secrets;
 pub mod services;
 pub mod storage;
 pub mod utils;
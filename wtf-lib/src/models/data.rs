@@ -1,6 +1,8 @@
-use crate::models::jira::JiraBoard;
+use crate::models::jira::{JiraBoard, JiraIssue};
 use crate::services::jira_service::get_jira_identifiers;
 use crate::storage::database::Identifiable;
+use crate::storage::shared::SplitRecord;
+use crate::utils::period::Period;
 use chrono::{DateTime, NaiveDate, TimeZone, Utc};
 use google_calendar3::api::EventAttendee;
 use log::warn;
@@ -33,19 +35,56 @@ impl Identifiable for Sprint {
     }
 }
 
+impl SplitRecord for Sprint {
+    type Personal = SprintUserState;
+
+    fn personal(&self) -> SprintUserState {
+        SprintUserState {
+            id: self.get_id(),
+            followed: self.followed,
+            workdays: self.workdays,
+        }
+    }
+
+    fn shared(self) -> Self {
+        Self {
+            followed: false,
+            workdays: 0,
+            ..self
+        }
+    }
+
+    fn with_personal(self, personal: Option<SprintUserState>) -> Self {
+        let personal = personal.unwrap_or_default();
+        Self {
+            followed: personal.followed,
+            workdays: personal.workdays,
+            ..self
+        }
+    }
+}
+
+/// What of a sprint is mine alone: whether I follow it, and my workdays in it once my
+/// absences are taken out
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct SprintUserState {
+    pub id: String,
+    pub followed: bool,
+    pub workdays: i64,
+}
+
+impl Identifiable for SprintUserState {
+    fn get_id(&self) -> String {
+        self.id.clone()
+    }
+}
+
 impl Sprint {
     /// Returns true if the meeting falls within this sprint, expanding sprint
     /// boundaries to full UTC days so meetings at the start/end of the day
     /// are not missed due to the sprint's configured hour offsets.
     pub fn contains_meeting(&self, meeting: &Meeting) -> bool {
-        if let (Some(start), Some(end)) = (self.start, self.end) {
-            let day_start =
-                Utc.from_utc_datetime(&start.date_naive().and_hms_opt(0, 0, 0).unwrap());
-            let day_end = Utc.from_utc_datetime(&end.date_naive().and_hms_opt(23, 59, 59).unwrap());
-            meeting.is_between(day_start, day_end)
-        } else {
-            false
-        }
+        Period::of_sprint(self).is_some_and(|period| period.contains_meeting(meeting))
     }
 }
 
@@ -102,6 +141,44 @@ impl Identifiable for Board {
     }
 }
 
+impl SplitRecord for Board {
+    type Personal = BoardUserState;
+
+    fn personal(&self) -> BoardUserState {
+        BoardUserState {
+            id: self.get_id(),
+            followed: self.followed,
+        }
+    }
+
+    fn shared(self) -> Self {
+        Self {
+            followed: false,
+            ..self
+        }
+    }
+
+    fn with_personal(self, personal: Option<BoardUserState>) -> Self {
+        Self {
+            followed: personal.is_some_and(|p| p.followed),
+            ..self
+        }
+    }
+}
+
+/// Whether I follow a board
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct BoardUserState {
+    pub id: String,
+    pub followed: bool,
+}
+
+impl Identifiable for BoardUserState {
+    fn get_id(&self) -> String {
+        self.id.clone()
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Worklog {
     pub id: String,
@@ -120,6 +197,9 @@ impl Identifiable for Worklog {
     }
 }
 
+/// Where time on issues without an epic is counted in the per-epic views
+pub const NO_EPIC: &str = "No epic";
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Issue {
     pub id: String,
@@ -127,6 +207,39 @@ pub struct Issue {
     pub summary: String,
     pub status: String,
     pub created: DateTime<Utc>,
+    /// Followed sprints the issue was fetched from (kept across syncs)
+    #[serde(default)]
+    pub sprint_ids: Vec<usize>,
+    /// Key of the epic the issue belongs to
+    #[serde(default)]
+    pub epic: Option<String>,
+    #[serde(default)]
+    pub components: Vec<String>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Email address of the assignee, when Jira shares it
+    #[serde(default)]
+    pub assignee: Option<String>,
+}
+
+impl Issue {
+    pub fn from_jira(jira_issue: JiraIssue, sprint_ids: Vec<usize>) -> Self {
+        Self {
+            sprint_ids,
+            ..jira_issue.into()
+        }
+    }
+
+    /// Whether `query` (lowercase) is in the key, the summary, the epic, a component or a
+    /// label
+    pub fn matches(&self, query: &str) -> bool {
+        [&self.key, &self.summary]
+            .into_iter()
+            .chain(&self.epic)
+            .chain(&self.components)
+            .chain(&self.labels)
+            .any(|text| text.to_lowercase().contains(query))
+    }
 }
 
 impl Identifiable for Issue {
@@ -164,6 +277,21 @@ pub struct Meeting {
     pub my_response_status: Option<String>,
     #[serde(default)]
     pub color_id: Option<String>,
+    /// Organizer display name (or email when no name is set)
+    #[serde(default)]
+    pub organizer: Option<String>,
+    /// Meet/Zoom/Teams video link
+    #[serde(default)]
+    pub conference_link: Option<String>,
+    /// Id of the Google calendar the event was fetched from
+    #[serde(default)]
+    pub calendar: Option<String>,
+    /// Id of the recurring event this meeting is an instance of, shared by the whole series
+    #[serde(default)]
+    pub recurring_event_id: Option<String>,
+    /// Google event type, e.g. `focusTime`; `None` for regular events and older records
+    #[serde(default)]
+    pub event_type: Option<String>,
 }
 
 impl Identifiable for Meeting {
@@ -173,6 +301,11 @@ impl Identifiable for Meeting {
 }
 
 impl Meeting {
+    /// A Google "Focus time" block rather than a meeting
+    pub fn is_focus_time(&self) -> bool {
+        self.event_type.as_deref() == Some("focusTime")
+    }
+
     pub fn get_jira_candidates(&self) -> Vec<String> {
         let mut candidates: Vec<String> = vec![];
         candidates.extend(
@@ -270,6 +403,11 @@ pub struct Attendee {
     pub display_name: Option<String>,
     pub comment: Option<String>,
     pub email: Option<String>,
+    /// Google response status: accepted, declined, tentative or needsAction
+    #[serde(default)]
+    pub response_status: Option<String>,
+    #[serde(default)]
+    pub organizer: bool,
 }
 
 impl Attendee {
@@ -278,6 +416,8 @@ impl Attendee {
             display_name: attendee.display_name.clone(),
             comment: attendee.comment.clone(),
             email: attendee.email.clone(),
+            response_status: attendee.response_status.clone(),
+            organizer: attendee.organizer.unwrap_or(false),
         }
     }
 }
@@ -430,6 +570,103 @@ impl GitHubSession {
     }
 }
 
+/// Front-end UI state, stored as JSON so the UI can evolve it without database migrations
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UiStateEntry {
+    pub key: String,
+    pub json: String,
+}
+
+impl Identifiable for UiStateEntry {
+    fn get_id(&self) -> String {
+        self.key.clone()
+    }
+}
+
+/// Data sources kept up to date by `wtf fetch` and `wtf daemon`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SyncSource {
+    Jira,
+    Google,
+    GitHub,
+}
+
+impl SyncSource {
+    pub const ALL: [SyncSource; 3] = [SyncSource::Jira, SyncSource::Google, SyncSource::GitHub];
+
+    pub fn key(self) -> &'static str {
+        match self {
+            SyncSource::Jira => "jira",
+            SyncSource::Google => "google",
+            SyncSource::GitHub => "github",
+        }
+    }
+}
+
+/// When a source was last fetched successfully
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SyncRecord {
+    pub source: String,
+    pub synced_at: DateTime<Utc>,
+}
+
+impl Identifiable for SyncRecord {
+    fn get_id(&self) -> String {
+        self.source.clone()
+    }
+}
+
+/// What a local change did, as recorded in the audit log
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditAction {
+    Create,
+    Edit,
+    Delete,
+    Stage,
+    Unstage,
+    Link,
+    Unlink,
+    Push,
+    Revert,
+}
+
+impl AuditAction {
+    pub fn name(self) -> &'static str {
+        match self {
+            AuditAction::Create => "create",
+            AuditAction::Edit => "edit",
+            AuditAction::Delete => "delete",
+            AuditAction::Stage => "stage",
+            AuditAction::Unstage => "unstage",
+            AuditAction::Link => "link",
+            AuditAction::Unlink => "unlink",
+            AuditAction::Push => "push",
+            AuditAction::Revert => "revert",
+        }
+    }
+}
+
+/// A local change, kept in the append-only audit log
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AuditEntry {
+    /// Sorts like `at`, so the log is read back in order
+    pub id: String,
+    pub at: DateTime<Utc>,
+    pub action: AuditAction,
+    /// What asked for the change: `cli: wtf log add`, `tui: Worklogs [s]`, `wizard: Push`
+    pub source: String,
+    /// The record changed, e.g. `worklog 1a2b3c4d`
+    pub target: String,
+    pub details: String,
+}
+
+impl Identifiable for AuditEntry {
+    fn get_id(&self) -> String {
+        self.id.clone()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -460,6 +697,11 @@ mod tests {
             logs: HashMap::new(),
             my_response_status: None,
             color_id: None,
+            organizer: None,
+            conference_link: None,
+            calendar: None,
+            recurring_event_id: None,
+            event_type: None,
         }
     }
 
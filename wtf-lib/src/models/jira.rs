@@ -1,7 +1,9 @@
 use crate::models::data::{Issue, Worklog};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
+use thiserror::Error;
 
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, Hash, PartialEq)]
 pub struct JiraIssue {
@@ -28,6 +30,14 @@ impl JiraIssue {
             created: self.fields.created,
             status: self.fields.status.name,
             summary: self.fields.summary,
+            sprint_ids: Vec::new(),
+            epic: self.fields.epic,
+            components: self.fields.components.into_iter().map(|c| c.name).collect(),
+            labels: self.fields.labels,
+            assignee: self
+                .fields
+                .assignee
+                .and_then(|assignee| assignee.email_address),
         }
     }
 }
@@ -38,6 +48,132 @@ pub struct JiraFields {
     pub status: JiraStatus,
     pub created: DateTime<Utc>,
     pub worklogs: Option<Vec<JiraWorklog>>,
+    /// Plain-text rendering of the description (only present on single issue fetches)
+    #[serde(default, deserialize_with = "deserialize_adf_text")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub assignee: Option<JiraUser>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub components: Vec<JiraComponent>,
+    #[serde(default)]
+    pub parent: Option<JiraParent>,
+    /// Key of the issue's epic, set by [`JiraFields::resolve_epic`]
+    #[serde(skip)]
+    pub epic: Option<String>,
+    /// The other fields asked for, custom ones included
+    #[serde(flatten)]
+    pub other: BTreeMap<String, serde_json::Value>,
+}
+
+impl JiraFields {
+    /// Find the epic in `epic_field` when set (Epic Link holds the epic key), else in the
+    /// parent when it's an epic
+    pub fn resolve_epic(&mut self, epic_field: Option<&str>) {
+        let linked = epic_field
+            .and_then(|field| self.other.get(field))
+            .and_then(|value| value.as_str())
+            .map(str::to_string);
+        let parent = self
+            .parent
+            .as_ref()
+            .filter(|parent| parent.is_epic())
+            .map(|parent| parent.key.clone());
+        self.epic = linked.or(parent);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Hash, Eq, PartialEq)]
+pub struct JiraComponent {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Hash, Eq, PartialEq)]
+pub struct JiraParent {
+    pub key: String,
+    #[serde(default)]
+    pub fields: Option<JiraParentFields>,
+}
+
+impl JiraParent {
+    /// Epics are one level above standard issues, whatever their type is named
+    fn is_epic(&self) -> bool {
+        self.fields
+            .as_ref()
+            .and_then(|fields| fields.issuetype.as_ref())
+            .is_some_and(|issuetype| {
+                issuetype.hierarchy_level == Some(1) || issuetype.name.eq_ignore_ascii_case("epic")
+            })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Hash, Eq, PartialEq)]
+pub struct JiraParentFields {
+    #[serde(default)]
+    pub issuetype: Option<JiraIssueType>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Hash, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraIssueType {
+    pub name: String,
+    #[serde(default)]
+    pub hierarchy_level: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Hash, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraUser {
+    pub display_name: String,
+    /// Hidden by Jira when the user's profile keeps it private
+    #[serde(default)]
+    pub email_address: Option<String>,
+}
+
+/// Accepts either a plain string (API v2) or an Atlassian Document Format tree (API v3)
+fn deserialize_adf_text<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(value
+        .map(|v| adf_to_text(&v))
+        .filter(|text| !text.is_empty()))
+}
+
+/// Flatten an ADF document into text, one line per block node
+pub fn adf_to_text(value: &serde_json::Value) -> String {
+    fn walk(node: &serde_json::Value, out: &mut String) {
+        match node {
+            serde_json::Value::String(text) => out.push_str(text),
+            serde_json::Value::Object(map) => {
+                if let Some(serde_json::Value::String(text)) = map.get("text") {
+                    out.push_str(text);
+                }
+                if map.get("type").and_then(|t| t.as_str()) == Some("hardBreak") {
+                    out.push('\n');
+                }
+                if let Some(serde_json::Value::Array(children)) = map.get("content") {
+                    for child in children {
+                        walk(child, out);
+                    }
+                }
+                let is_block = matches!(
+                    map.get("type").and_then(|t| t.as_str()),
+                    Some("paragraph" | "heading" | "listItem" | "codeBlock" | "blockquote")
+                );
+                if is_block && !out.ends_with('\n') {
+                    out.push('\n');
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut out = String::new();
+    walk(value, &mut out);
+    out.trim_end().to_string()
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Hash, Eq, PartialEq)]
@@ -45,6 +181,19 @@ pub struct JiraStatus {
     pub name: String,
 }
 
+/// A workflow transition available on an issue, leading `to` a status
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct JiraTransition {
+    pub id: String,
+    pub name: String,
+    pub to: JiraStatus,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JiraTransitions {
+    pub transitions: Vec<JiraTransition>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Hash, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct JiraWorklog {
@@ -139,9 +288,19 @@ pub struct JiraText {
     pub text: Option<String>,
 }
 
+/// A page of Jira's updated (or deleted) worklogs feed
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct JiraUpdatedWorklogsResponse {
     pub values: Vec<JiraUpdatedWorklog>,
+    #[serde(default)]
+    pub next_page: Option<String>,
+    #[serde(default = "default_last_page")]
+    pub last_page: bool,
+}
+
+fn default_last_page() -> bool {
+    true
 }
 
 #[derive(Debug, Deserialize)]
@@ -151,6 +310,14 @@ pub struct JiraUpdatedWorklog {
     pub updated_time: usize,
 }
 
+/// What changed on Jira since a fetch: the current user's worklogs created or updated,
+/// and the ids of every worklog deleted
+#[derive(Debug, Default)]
+pub struct WorklogChanges {
+    pub updated: Vec<JiraWorklog>,
+    pub deleted: Vec<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Hash, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct JiraAuthor {
@@ -158,19 +325,24 @@ pub struct JiraAuthor {
     pub email_address: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum JiraError {
+    #[error("API error: {0}")]
     ApiError(String),
+    #[error("Deserialize error: {0}")]
     DeserializeError(String),
-    RequestError(reqwest::Error),
+    #[error("Request error: {0}")]
+    RequestError(#[from] reqwest::Error),
 }
 
-impl fmt::Display for JiraError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl JiraError {
+    /// HTTP status of a failed call, from the leading code of the message (e.g. "404 Not
+    /// Found - ...")
+    pub fn status(&self) -> Option<u16> {
         match self {
-            JiraError::ApiError(msg) => write!(f, "API error: {}", msg),
-            JiraError::DeserializeError(msg) => write!(f, "Deserialize error: {}", msg),
-            JiraError::RequestError(e) => write!(f, "Request error: {}", e),
+            JiraError::ApiError(msg) => msg.split(' ').next().and_then(|s| s.parse().ok()),
+            JiraError::RequestError(e) => e.status().map(|status| status.as_u16()),
+            JiraError::DeserializeError(_) => None,
         }
     }
 }
@@ -226,3 +398,37 @@ impl fmt::Display for JiraBoard {
         write!(f, "[{}] {} - ({})", self.id, self.name, self.r#type)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adf_to_text_flattens_blocks() {
+        let doc = serde_json::json!({
+            "type": "doc",
+            "content": [
+                {"type": "paragraph", "content": [{"type": "text", "text": "First line"}]},
+                {"type": "bulletList", "content": [
+                    {"type": "listItem", "content": [
+                        {"type": "paragraph", "content": [{"type": "text", "text": "item"}]}
+                    ]}
+                ]}
+            ]
+        });
+        assert_eq!(adf_to_text(&doc), "First line\nitem");
+    }
+
+    #[test]
+    fn test_issue_fields_accept_missing_and_plain_description() {
+        let json = r#"{"summary":"s","status":{"name":"Open"},"created":"2026-01-01T00:00:00Z","worklogs":null}"#;
+        let fields: JiraFields = serde_json::from_str(json).unwrap();
+        assert!(fields.description.is_none());
+        assert!(fields.assignee.is_none());
+
+        let json = r#"{"summary":"s","status":{"name":"Open"},"created":"2026-01-01T00:00:00Z","worklogs":null,"description":"plain","assignee":{"displayName":"Ada"}}"#;
+        let fields: JiraFields = serde_json::from_str(json).unwrap();
+        assert_eq!(fields.description.as_deref(), Some("plain"));
+        assert_eq!(fields.assignee.unwrap().display_name, "Ada");
+    }
+}
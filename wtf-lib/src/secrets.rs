@@ -0,0 +1,149 @@
+use crate::config::config_home;
+use keyring::Entry;
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::Mutex;
+use std::thread;
+
+const KEYRING_SERVICE: &str = "wtf";
+
+/// Secrets kept in the OS keyring (macOS Keychain, Windows Credential Manager, Secret
+/// Service on Linux) instead of config.toml, one entry per profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Secret {
+    /// The Jira API token, used when `jira.api_token` is empty
+    JiraToken,
+    /// The content of the Google OAuth credentials file, used instead of
+    /// `google.credentials_path`
+    GoogleCredentials,
+    /// The GitHub token, used when `github.token` is unset
+    GitHubToken,
+    /// The key of the encrypted database
+    DatabaseKey,
+}
+
+/// Keyring reads already done by this process, failures included so that machines
+/// without a keyring only try once
+type Lookup = Result<Option<String>, String>;
+static CACHE: Mutex<Option<HashMap<Secret, Lookup>>> = Mutex::new(None);
+
+impl Secret {
+    /// The secrets managed with `wtf config secret`
+    pub const CONFIGURABLE: [Secret; 3] = [
+        Secret::JiraToken,
+        Secret::GoogleCredentials,
+        Secret::GitHubToken,
+    ];
+
+    /// Name used on the command line
+    pub fn name(self) -> &'static str {
+        match self {
+            Secret::JiraToken => "jira",
+            Secret::GoogleCredentials => "google",
+            Secret::GitHubToken => "github",
+            Secret::DatabaseKey => "database",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::CONFIGURABLE
+            .into_iter()
+            .find(|secret| secret.name() == name)
+    }
+
+    fn account(self) -> String {
+        let kind = match self {
+            Secret::JiraToken => "jira-token",
+            Secret::GoogleCredentials => "google-credentials",
+            Secret::GitHubToken => "github-token",
+            Secret::DatabaseKey => "database-key",
+        };
+        format!("{}:{}", kind, config_home().display())
+    }
+
+    /// The stored value; `None` when the keyring has no entry for it
+    pub fn get(self) -> Result<Option<String>, Box<dyn Error + Send + Sync>> {
+        let lookup = match cached(self) {
+            Some(lookup) => lookup,
+            None => {
+                let lookup = match with_entry(self, |entry| entry.get_password())? {
+                    Ok(value) => Ok(Some(value)),
+                    Err(keyring::Error::NoEntry) => Ok(None),
+                    Err(e) => Err(format!("can't read the OS keyring: {}", e)),
+                };
+                remember(self, lookup.clone());
+                lookup
+            }
+        };
+        Ok(lookup?)
+    }
+
+    pub fn set(self, value: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let stored = value.to_string();
+        with_entry(self, move |entry| entry.set_password(&stored))?.map_err(|e| {
+            format!(
+                "can't save the {} secret in the OS keyring: {}",
+                self.name(),
+                e
+            )
+        })?;
+        remember(self, Ok(Some(value.to_string())));
+        Ok(())
+    }
+
+    /// Remove the entry; returns whether there was one
+    pub fn delete(self) -> Result<bool, Box<dyn Error + Send + Sync>> {
+        let deleted = match with_entry(self, |entry| entry.delete_credential())? {
+            Ok(()) => true,
+            Err(keyring::Error::NoEntry) => false,
+            Err(e) => return Err(format!("can't update the OS keyring: {}", e).into()),
+        };
+        remember(self, Ok(None));
+        Ok(deleted)
+    }
+}
+
+fn cached(secret: Secret) -> Option<Lookup> {
+    CACHE
+        .lock()
+        .ok()?
+        .as_ref()
+        .and_then(|cache| cache.get(&secret).cloned())
+}
+
+fn remember(secret: Secret, lookup: Lookup) {
+    if let Ok(mut cache) = CACHE.lock() {
+        cache
+            .get_or_insert_with(HashMap::new)
+            .insert(secret, lookup);
+    }
+}
+
+/// Run `action` on the secret's keyring entry. The Secret Service client drives its own
+/// executor, which can't run inside wtf's tokio runtime: use a thread of its own.
+fn with_entry<R: Send + 'static>(
+    secret: Secret,
+    action: impl FnOnce(&Entry) -> keyring::Result<R> + Send + 'static,
+) -> Result<keyring::Result<R>, Box<dyn Error + Send + Sync>> {
+    let account = secret.account();
+    thread::spawn(move || Entry::new(KEYRING_SERVICE, &account).and_then(|entry| action(&entry)))
+        .join()
+        .map_err(|_| "the OS keyring access crashed".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_the_config_secrets_are_named_on_the_command_line() {
+        for secret in Secret::CONFIGURABLE {
+            assert_eq!(Secret::from_name(secret.name()), Some(secret));
+        }
+        assert_eq!(Secret::from_name("database"), None);
+        assert_ne!(
+            Secret::JiraToken.account(),
+            Secret::GoogleCredentials.account()
+        );
+    }
+}
@@ -1,5 +1,7 @@
+use crate::context::Context;
+use crate::error::WtfResult;
 use crate::models::achievement::{Achievement, AchievementUnlock};
-use crate::storage::database::{GenericDatabase, DATABASE};
+use crate::storage::database::GenericDatabase;
 use log::error;
 use std::sync::Mutex;
 
@@ -16,7 +18,10 @@ pub struct AchievementService {
 
 impl AchievementService {
     /// Create a service from any database handle. Use this in tests with a temp DB.
-    pub fn new(db: GenericDatabase<AchievementUnlock>) -> Self {
+    pub fn new(context: &Context) -> Self {
+        let db = GenericDatabase::new(context.database(), "achievements").unwrap_or_else(|e| {
+            panic!("Failed to initialize achievements database: {}", e);
+        });
         let unlocks = db.get_all().unwrap_or_else(|e| {
             error!("Failed to load achievements from database: {}", e);
             Vec::new()
@@ -29,10 +34,7 @@ impl AchievementService {
 
     /// Create a service backed by the production sled database.
     pub fn production() -> Self {
-        let db = GenericDatabase::new(&DATABASE, "achievements").unwrap_or_else(|e| {
-            panic!("Failed to initialize achievements database: {}", e);
-        });
-        Self::new(db)
+        Self::new(&Context::production())
     }
 
     /// Check if an achievement is unlocked.
@@ -142,10 +144,8 @@ impl AchievementService {
     }
 
     /// Clear all achievement records. Useful for resetting state.
-    pub fn reset_all(&self) -> Result<(), String> {
-        self.db
-            .clear()
-            .map_err(|e| format!("Failed to clear achievements database: {}", e))?;
+    pub fn reset_all(&self) -> WtfResult<()> {
+        self.db.clear()?;
         self.cache.lock().unwrap().clear();
         Ok(())
     }
@@ -156,9 +156,7 @@ mod tests {
     use super::*;
 
     fn temp_service() -> AchievementService {
-        let tmp_db = crate::storage::database::Database::temporary();
-        let db = GenericDatabase::new(&tmp_db, "achievements").expect("temp achievements db");
-        AchievementService::new(db)
+        AchievementService::new(&Context::temporary())
     }
 
     #[test]
@@ -264,17 +262,15 @@ mod tests {
 
     #[test]
     fn test_persistence_across_instances() {
-        let tmp_db = crate::storage::database::Database::temporary();
+        let context = Context::temporary();
 
         {
-            let db = GenericDatabase::new(&tmp_db, "achievements").unwrap();
-            let svc = AchievementService::new(db);
+            let svc = AchievementService::new(&context);
             svc.unlock(Achievement::AboutClicker);
         }
 
         // New instance from same DB should load persisted data
-        let db2 = GenericDatabase::new(&tmp_db, "achievements").unwrap();
-        let svc2 = AchievementService::new(db2);
+        let svc2 = AchievementService::new(&context);
         assert!(svc2.is_unlocked(Achievement::AboutClicker));
         assert_eq!(svc2.unlock_count(), 1);
     }
@@ -0,0 +1,109 @@
+use crate::context::Context;
+use crate::models::data::{AuditAction, AuditEntry};
+use crate::storage::database::GenericDatabase;
+use chrono::{DateTime, Utc};
+use log::error;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+
+/// What the changes recorded from now on are made by (a CLI command, a TUI key, a
+/// wizard step)
+static SOURCE: RwLock<String> = RwLock::new(String::new());
+
+/// Tells apart the entries recorded within the same nanosecond
+static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+pub fn set_source(source: impl Into<String>) {
+    *SOURCE.write().unwrap_or_else(|e| e.into_inner()) = source.into();
+}
+
+pub fn source() -> String {
+    let source = SOURCE.read().unwrap_or_else(|e| e.into_inner());
+    if source.is_empty() {
+        "unknown".to_string()
+    } else {
+        source.clone()
+    }
+}
+
+/// Append-only log of the changes made to the local data, shown by `wtf audit`
+pub struct AuditService {
+    db: GenericDatabase<AuditEntry>,
+}
+
+impl AuditService {
+    pub fn new(context: &Context) -> Self {
+        let db = GenericDatabase::new(context.database(), "audit_log")
+            .expect("could not initialize audit log database");
+        Self { db }
+    }
+
+    /// Create a service backed by the production sled database.
+    pub fn production() -> Self {
+        Self::new(&Context::production())
+    }
+
+    /// Record `action` on `target` now, made by the current [`source`]
+    pub fn record(
+        &self,
+        action: AuditAction,
+        target: impl Into<String>,
+        details: impl Into<String>,
+    ) {
+        self.record_at(Utc::now(), action, target.into(), details.into());
+    }
+
+    fn record_at(&self, at: DateTime<Utc>, action: AuditAction, target: String, details: String) {
+        let sequence = SEQUENCE.fetch_add(1, Ordering::Relaxed) % 1_000_000;
+        let entry = AuditEntry {
+            id: format!("{}-{:06}", at.format("%Y%m%dT%H%M%S%.9f"), sequence),
+            at,
+            action,
+            source: source(),
+            target,
+            details,
+        };
+        if let Err(e) = self.db.insert(&entry) {
+            error!(
+                "Failed to record '{}' of {} in the audit log: {}",
+                action.name(),
+                entry.target,
+                e
+            );
+        }
+    }
+
+    /// Every entry, oldest first
+    pub fn entries(&self) -> Vec<AuditEntry> {
+        let mut entries = self.db.get_all().unwrap_or_else(|e| {
+            error!("Failed to load the audit log: {}", e);
+            Vec::new()
+        });
+        entries.sort_by(|a, b| a.at.cmp(&b.at).then_with(|| a.id.cmp(&b.id)));
+        entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_entries_come_back_oldest_first() {
+        let service = AuditService::new(&Context::temporary());
+        let at = |hour| Utc.with_ymd_and_hms(2026, 3, 2, hour, 0, 0).unwrap();
+        service.record_at(at(11), AuditAction::Push, "worklog b".into(), "".into());
+        service.record_at(at(9), AuditAction::Create, "worklog a".into(), "".into());
+        service.record_at(at(9), AuditAction::Stage, "worklog a".into(), "".into());
+
+        let entries = service.entries();
+
+        let actions: Vec<_> = entries.iter().map(|e| e.action).collect();
+        assert_eq!(
+            actions,
+            vec![AuditAction::Create, AuditAction::Stage, AuditAction::Push]
+        );
+        assert!(entries.iter().all(|e| !e.source.is_empty()));
+    }
+}
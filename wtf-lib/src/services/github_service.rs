@@ -1,37 +1,100 @@
 use crate::client::github_client::{GitHubClient, GitHubEvent as APIGitHubEvent};
-use crate::config::Config;
+use crate::client::github_graphql;
+use crate::config::{Config, GitHubApi, GithubConfig};
+use crate::context::Context;
+use crate::error::{WtfError, WtfResult};
 use crate::models::data::{GitHubEvent, GitHubSession, Sprint};
-use crate::storage::database::{GenericDatabase, DATABASE};
+use crate::services::github_session_edit_service::GitHubSessionEditService;
+use crate::storage::database::GenericDatabase;
 use chrono::{DateTime, Duration, NaiveDate, Utc};
 use log::{debug, info, warn};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+/// Days before a repo's cursor read again, as a commit pushed late keeps the date it was
+/// made
+const CURSOR_OVERLAP_DAYS: i64 = 3;
+
+// --- RepoCursor (private) ---
+
+/// The period whose commits to a repo are stored, so that later syncs only ask for the
+/// newer ones
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub(crate) struct RepoCursor {
+    pub(crate) repo: String,
+    pub(crate) from: DateTime<Utc>,
+    pub(crate) until: DateTime<Utc>,
+}
+
+impl crate::storage::database::Identifiable for RepoCursor {
+    fn get_id(&self) -> String {
+        self.repo.clone()
+    }
+}
+
+impl RepoCursor {
+    /// Where reading the commits of `[start, end]` starts, `None` when they're all stored
+    fn since(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if start < self.from || start > self.until {
+            return Some(start);
+        }
+        let since = start.max(self.until - Duration::days(CURSOR_OVERLAP_DAYS));
+        (since < end).then_some(since)
+    }
+
+    /// The cursor once the commits of `[start, end]` are stored; a period apart from the
+    /// stored one replaces it
+    fn covering(
+        previous: Option<RepoCursor>,
+        repo: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> RepoCursor {
+        match previous {
+            Some(cursor) if start <= cursor.until && end >= cursor.from => RepoCursor {
+                repo: repo.to_string(),
+                from: cursor.from.min(start),
+                until: cursor.until.max(end),
+            },
+            _ => RepoCursor {
+                repo: repo.to_string(),
+                from: start,
+                until: end,
+            },
+        }
+    }
+}
+
+// --- GitHubService ---
 
 pub struct GitHubService {
+    context: Context,
     events_db: GenericDatabase<GitHubEvent>,
     sessions_db: GenericDatabase<GitHubSession>,
+    cursors_db: GenericDatabase<RepoCursor>,
+    edits: GitHubSessionEditService,
 }
 
 impl GitHubService {
-    pub fn new(
-        events_db: GenericDatabase<GitHubEvent>,
-        sessions_db: GenericDatabase<GitHubSession>,
-    ) -> Self {
+    pub fn new(context: &Context) -> Self {
+        let database = context.database();
         Self {
-            events_db,
-            sessions_db,
+            context: context.clone(),
+            events_db: GenericDatabase::new(database, "github_events")
+                .expect("could not initialize github events database"),
+            sessions_db: GenericDatabase::new(database, "github_sessions")
+                .expect("could not initialize github sessions database"),
+            cursors_db: GenericDatabase::new(database, "github_cursors")
+                .expect("could not initialize github cursors database"),
+            edits: GitHubSessionEditService::new(context),
         }
     }
 
     /// Create a service backed by the production sled database.
     pub fn production() -> Self {
-        let events_db = GenericDatabase::new(&DATABASE, "github_events")
-            .expect("could not initialize github events database");
-        let sessions_db = GenericDatabase::new(&DATABASE, "github_sessions")
-            .expect("could not initialize github sessions database");
-        Self::new(events_db, sessions_db)
+        Self::new(&Context::production())
     }
 
-    /// Check if GitHub CLI is available and configured
+    /// Check if a GitHub token is set or the GitHub CLI is available
     pub fn is_configured() -> bool {
         GitHubClient::is_available()
     }
@@ -51,18 +114,13 @@ impl GitHubService {
     }
 
     /// Get all GitHub sessions
-    pub fn get_all_sessions(&self) -> Result<Vec<GitHubSession>, String> {
-        self.sessions_db
-            .get_all()
-            .map_err(|e| format!("Failed to get sessions: {}", e))
+    pub fn get_all_sessions(&self) -> WtfResult<Vec<GitHubSession>> {
+        Ok(self.sessions_db.get_all()?)
     }
 
     /// Get GitHub sessions for a specific date
-    pub fn get_sessions_by_date(&self, date: NaiveDate) -> Result<Vec<GitHubSession>, String> {
-        let all = self
-            .sessions_db
-            .get_all()
-            .map_err(|e| format!("Failed to get sessions: {}", e))?;
+    pub fn get_sessions_by_date(&self, date: NaiveDate) -> WtfResult<Vec<GitHubSession>> {
+        let all = self.sessions_db.get_all()?;
 
         Ok(all.into_iter().filter(|s| s.date == date).collect())
     }
@@ -72,11 +130,8 @@ impl GitHubService {
         &self,
         from: NaiveDate,
         to: NaiveDate,
-    ) -> Result<Vec<GitHubSession>, String> {
-        let all = self
-            .sessions_db
-            .get_all()
-            .map_err(|e| format!("Failed to get sessions: {}", e))?;
+    ) -> WtfResult<Vec<GitHubSession>> {
+        let all = self.sessions_db.get_all()?;
 
         Ok(all
             .into_iter()
@@ -85,29 +140,44 @@ impl GitHubService {
     }
 
     /// Get all GitHub events
-    pub fn get_all_events(&self) -> Result<Vec<GitHubEvent>, String> {
-        self.events_db
-            .get_all()
-            .map_err(|e| format!("Failed to get events: {}", e))
+    pub fn get_all_events(&self) -> WtfResult<Vec<GitHubEvent>> {
+        Ok(self.events_db.get_all()?)
     }
 
     /// Get GitHub events for a specific date
-    pub fn get_events_by_date(&self, date: NaiveDate) -> Result<Vec<GitHubEvent>, String> {
-        let all = self
-            .events_db
-            .get_all()
-            .map_err(|e| format!("Failed to get events: {}", e))?;
+    pub fn get_events_by_date(&self, date: NaiveDate) -> WtfResult<Vec<GitHubEvent>> {
+        let all = self.events_db.get_all()?;
 
         Ok(all.into_iter().filter(|e| e.date == date).collect())
     }
 
+    /// Seconds a session from `start` to `end` counts for, at least the configured minimum
+    pub(crate) fn session_duration(
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        config: &GithubConfig,
+    ) -> i64 {
+        end.signed_duration_since(start)
+            .num_seconds()
+            .max(config.min_session_seconds())
+    }
+
+    fn github_config(&self) -> GithubConfig {
+        self.context
+            .config()
+            .map(|config| config.github)
+            .unwrap_or_default()
+    }
+
     /// Fetch GitHub events for all followed sprints (backward compatibility - don't save to DB)
-    pub fn fetch_events_for_sprints(sprints: &[Sprint]) -> Result<Vec<APIGitHubEvent>, String> {
+    pub async fn fetch_events_for_sprints(sprints: &[Sprint]) -> WtfResult<Vec<APIGitHubEvent>> {
         if !Self::is_configured() {
-            return Err("GitHub CLI is not installed or configured".to_string());
+            return Err(WtfError::GitHubUnavailable);
         }
 
-        let username = GitHubClient::get_username()?;
+        let client = GitHubClient::create()?;
+        let username = client.get_username().await?;
+        let github = Config::load().map(|c| c.github).unwrap_or_default();
         let mut all_api_events = Vec::new();
 
         for sprint in sprints {
@@ -118,7 +188,9 @@ impl GitHubService {
 
             let sprint_end = sprint.end.unwrap_or_else(Utc::now);
 
-            match GitHubClient::fetch_events(&username, sprint_start, sprint_end) {
+            match Self::fetch_period(&client, &username, sprint_start, sprint_end, &github, None)
+                .await
+            {
                 Ok(events) => {
                     all_api_events.extend(events);
                 }
@@ -132,54 +204,69 @@ impl GitHubService {
         let mut seen = std::collections::HashSet::new();
         all_api_events.retain(|e| seen.insert(e.id.clone()));
 
-        // Apply organisation filter if configured
-        if let Ok(config) = Config::load() {
-            if let Some(ref org) = config.github.organisation {
-                let prefix = format!("{}/", org);
-                all_api_events.retain(|e| e.repo.name.starts_with(&prefix));
-                debug!(
-                    "After org filter '{}': {} events",
-                    org,
-                    all_api_events.len()
-                );
-            }
-        }
+        // Apply organisation and repo filters if configured
+        all_api_events.retain(|e| github.tracks_repo(&e.repo.name));
+        debug!("After repo filters: {} events", all_api_events.len());
 
         Ok(all_api_events)
     }
 
     /// Fetch GitHub events for all followed sprints and save to database
-    pub fn sync_events_for_sprints(&self, sprints: &[Sprint]) -> Result<(usize, usize), String> {
+    pub async fn sync_events_for_sprints(&self, sprints: &[Sprint]) -> WtfResult<(usize, usize)> {
+        let periods = sprints
+            .iter()
+            .filter_map(|sprint| match sprint.start {
+                Some(start) if start <= Utc::now() => Some((
+                    format!("sprint {}", sprint.name),
+                    start,
+                    sprint.end.unwrap_or_else(Utc::now),
+                )),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        self.sync_events_for_periods(&periods).await
+    }
+
+    /// Fetch GitHub events between two dates, regardless of sprints, and save to database
+    pub async fn sync_events_between(
+        &self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> WtfResult<(usize, usize)> {
+        let label = format!("{} to {}", start.date_naive(), end.date_naive());
+        self.sync_events_for_periods(&[(label, start, end.min(Utc::now()))])
+            .await
+    }
+
+    async fn sync_events_for_periods(
+        &self,
+        periods: &[(String, DateTime<Utc>, DateTime<Utc>)],
+    ) -> WtfResult<(usize, usize)> {
         if !Self::is_configured() {
-            return Err("GitHub CLI is not installed or configured. Please install gh CLI and run 'gh auth login'".to_string());
+            return Err(WtfError::GitHubUnavailable);
         }
 
-        let username = GitHubClient::get_username()?;
+        let client = self.context.github_client()?;
+        let username = client.get_username().await?;
+        let github = self.github_config();
         info!("Fetching GitHub events for user: {}", username);
-        info!("Note: GitHub API only stores the last 90 days of events");
+        if github.api == GitHubApi::Rest {
+            info!("Note: GitHub API only stores the last 90 days of events");
+        }
 
         let mut all_api_events = Vec::new();
 
-        for sprint in sprints {
-            let sprint_start = match sprint.start {
-                Some(start) if start <= Utc::now() => start,
-                _ => continue,
-            };
-
-            let sprint_end = sprint.end.unwrap_or_else(Utc::now);
+        for (label, start, end) in periods {
+            debug!("Fetching events for {} ({} to {})", label, start, end);
 
-            debug!(
-                "Fetching events for sprint {} ({} to {})",
-                sprint.name, sprint_start, sprint_end
-            );
-
-            match GitHubClient::fetch_events(&username, sprint_start, sprint_end) {
+            let cursors = Some(&self.cursors_db);
+            match Self::fetch_period(&client, &username, *start, *end, &github, cursors).await {
                 Ok(events) => {
-                    info!("Found {} events for sprint {}", events.len(), sprint.name);
+                    info!("Found {} events for {}", events.len(), label);
                     all_api_events.extend(events);
                 }
                 Err(e) => {
-                    warn!("Failed to fetch events for sprint {}: {}", sprint.name, e);
+                    warn!("Failed to fetch events for {}: {}", label, e);
                 }
             }
         }
@@ -190,57 +277,183 @@ impl GitHubService {
 
         info!("Total GitHub events fetched: {}", all_api_events.len());
 
-        // Apply organisation filter if configured
-        if let Ok(config) = Config::load() {
-            if let Some(ref org) = config.github.organisation {
-                let prefix = format!("{}/", org);
-                all_api_events.retain(|e| e.repo.name.starts_with(&prefix));
-                info!(
-                    "After org filter '{}': {} events remain",
-                    org,
-                    all_api_events.len()
-                );
-            }
-        }
+        // Apply organisation and repo filters if configured
+        all_api_events.retain(|e| github.tracks_repo(&e.repo.name));
+        info!("After repo filters: {} events remain", all_api_events.len());
 
         // Convert and save to database
-        let mut events_saved = 0;
+        let mut db_events = Vec::new();
         for api_event in &all_api_events {
-            let jira_issues = GitHubClient::extract_jira_issues(api_event);
-            let description = GitHubClient::extract_description(api_event);
-
-            let db_event = GitHubEvent {
-                id: api_event.id.clone(),
-                event_type: api_event.event_type.clone(),
-                repo: api_event.repo.name.clone(),
-                timestamp: api_event.created_at,
-                description,
-                jira_issues: jira_issues.join(","),
-                date: api_event.created_at.date_naive(),
-            };
-            self.save_event(&db_event);
-            events_saved += 1;
+            self.save_event(&Self::stored_event(api_event, &github));
+            db_events.push(Self::session_event(api_event, &github));
         }
+        let events_saved = db_events.len();
+
+        // A day's sessions are rebuilt from all its events, the ones stored by earlier
+        // syncs included, since an incremental fetch only brings the new ones
+        let dates: HashSet<NaiveDate> = db_events.iter().map(|e| e.date).collect();
+        let mut day_events: HashMap<String, GitHubEvent> = self
+            .get_all_events()?
+            .into_iter()
+            .filter(|e| dates.contains(&e.date) && github.tracks_repo(&e.repo))
+            .map(|e| (e.id.clone(), e))
+            .collect();
+        day_events.extend(db_events.into_iter().map(|e| (e.id.clone(), e)));
 
         // Calculate and save sessions
-        let sessions = self.calculate_and_save_sessions(&all_api_events);
+        let day_events: Vec<GitHubEvent> = day_events.into_values().collect();
+        let sessions = self.calculate_and_save_sessions(&day_events);
 
         Ok((events_saved, sessions))
     }
 
-    /// Calculate work sessions from API events and save to database
-    fn calculate_and_save_sessions(&self, api_events: &[APIGitHubEvent]) -> usize {
+    /// Forget which commits are stored, so that the next sync reads them all again
+    pub fn reset_cursors(&self) -> WtfResult<()> {
+        for cursor in self.cursors_db.get_all()? {
+            self.cursors_db.remove(&cursor.repo)?;
+        }
+        Ok(())
+    }
+
+    /// Activity between `start` and `end` from the configured API; GraphQL falls back to
+    /// the events API when it fails
+    async fn fetch_period(
+        client: &GitHubClient,
+        username: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        github: &GithubConfig,
+        cursors: Option<&GenericDatabase<RepoCursor>>,
+    ) -> WtfResult<Vec<APIGitHubEvent>> {
+        if github.api == GitHubApi::Graphql {
+            match Self::fetch_graphql(client, username, start, end, github, cursors).await {
+                Ok(events) => return Ok(events),
+                Err(e) => warn!("GitHub GraphQL fetch failed, using the events API: {}", e),
+            }
+        }
+        client.fetch_events(username, start, end).await
+    }
+
+    /// My contributions, commits and comments between `start` and `end`. With `cursors`,
+    /// the commits of a repo stored by earlier syncs aren't asked for again.
+    async fn fetch_graphql(
+        client: &GitHubClient,
+        username: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        github: &GithubConfig,
+        cursors: Option<&GenericDatabase<RepoCursor>>,
+    ) -> WtfResult<Vec<APIGitHubEvent>> {
+        let contributions =
+            github_graphql::fetch_contributions(client, username, start, end).await?;
+        let mut events = contributions.events;
+
+        // Commits only count as contributions once on the default branch: the repos of my
+        // pull requests and reviews, and the included ones, are read too
+        let mut repos = contributions.commit_repos;
+        let others = events
+            .iter()
+            .map(|e| e.repo.name.clone())
+            .chain(github.include_repos.iter().cloned())
+            .filter(|repo| repo.contains('/'))
+            .collect::<Vec<_>>();
+        for repo in others {
+            if !repos.contains(&repo) {
+                repos.push(repo);
+            }
+        }
+
+        for repo in repos.iter().filter(|repo| github.tracks_repo(repo)) {
+            let cursor = cursors.and_then(|db| db.get(repo).ok().flatten());
+            let since = match &cursor {
+                Some(cursor) => match cursor.since(start, end) {
+                    Some(since) => since,
+                    None => continue,
+                },
+                None => start,
+            };
+            match github_graphql::fetch_commits(client, repo, &contributions.author_id, since, end)
+                .await
+            {
+                Ok(commits) => {
+                    debug!(
+                        "Found {} commits in {} since {}",
+                        commits.len(),
+                        repo,
+                        since
+                    );
+                    events.extend(commits);
+                    if let Some(db) = cursors {
+                        let covered = RepoCursor::covering(cursor, repo, start, end);
+                        if let Err(e) = db.insert(&covered) {
+                            warn!("Failed to save the cursor of {}: {}", repo, e);
+                        }
+                    }
+                }
+                Err(e) => warn!("Failed to fetch the commits of {}: {}", repo, e),
+            }
+        }
+
+        match github_graphql::fetch_issue_comments(client, username, start, end).await {
+            Ok(comments) => events.extend(comments),
+            Err(e) => warn!("Failed to fetch issue comments: {}", e),
+        }
+        Ok(events)
+    }
+
+    /// The record stored for an API event
+    fn stored_event(api_event: &APIGitHubEvent, github: &GithubConfig) -> GitHubEvent {
+        GitHubEvent {
+            id: api_event.id.clone(),
+            event_type: api_event.event_type.clone(),
+            repo: api_event.repo.name.clone(),
+            timestamp: api_event.created_at,
+            description: GitHubClient::extract_description(api_event),
+            jira_issues: GitHubClient::event_issues(api_event, github).join(","),
+            date: api_event.created_at.date_naive(),
+        }
+    }
+
+    /// The record of an API event, described the way sessions word it
+    fn session_event(api_event: &APIGitHubEvent, github: &GithubConfig) -> GitHubEvent {
+        GitHubEvent {
+            description: Self::get_event_description_from_api(api_event),
+            ..Self::stored_event(api_event, github)
+        }
+    }
+
+    /// Recompute every session from the stored events with the current settings. The
+    /// sessions split, trimmed or merged by hand are kept, unless `discard_edits`.
+    pub fn rebuild_sessions(&self, discard_edits: bool) -> WtfResult<usize> {
+        if discard_edits {
+            self.edits.discard_edits()?;
+        }
+        let edited = self.edits.edited_sessions();
+        for session in self.sessions_db.get_all()? {
+            if !edited.contains_key(&session.id) {
+                self.sessions_db.remove(&session.id)?;
+            }
+        }
+        let events = self.events_db.get_all()?;
+        Ok(self.calculate_and_save_sessions(&events))
+    }
+
+    /// Calculate work sessions from events and save to database
+    fn calculate_and_save_sessions(&self, events: &[GitHubEvent]) -> usize {
         // Delete stale sessions for affected dates before recalculating.
         // Session IDs encode (repo + start + end), so any boundary shift from new events
         // produces a different ID — leaving the old session orphaned in the DB.
-        let affected_dates: std::collections::HashSet<chrono::NaiveDate> = api_events
-            .iter()
-            .map(|e| e.created_at.date_naive())
-            .collect();
+        let affected_dates: std::collections::HashSet<NaiveDate> =
+            events.iter().map(|e| e.date).collect();
+
+        // Sessions edited by hand stay, and so do the events they claim
+        let edited = self.edits.edited_sessions();
+        let claimed: HashSet<&String> =
+            edited.values().flat_map(|edited| &edited.claimed).collect();
 
         if let Ok(existing) = self.sessions_db.get_all() {
             for session in existing {
-                if affected_dates.contains(&session.date) {
+                if affected_dates.contains(&session.date) && !edited.contains_key(&session.id) {
                     if let Err(e) = self.sessions_db.remove(&session.id) {
                         warn!("Failed to remove stale session '{}': {}", session.id, e);
                     }
@@ -248,32 +461,45 @@ impl GitHubService {
             }
         }
 
-        let mut sessions_by_day: HashMap<String, Vec<TempSession>> = HashMap::new();
+        let events: Vec<GitHubEvent> = events
+            .iter()
+            .filter(|event| !claimed.contains(&event.id))
+            .cloned()
+            .collect();
+        let sessions = Self::build_sessions(&events, &self.github_config());
+        for session in &sessions {
+            self.save_session(session);
+        }
+        sessions.len()
+    }
 
-        for event in api_events {
-            let date_key = event.created_at.format("%Y-%m-%d").to_string();
+    /// Stitch events into sessions, day by day: events closer than the configured gap
+    /// share a session, which starts the configured padding before its first event
+    fn build_sessions(events: &[GitHubEvent], config: &GithubConfig) -> Vec<GitHubSession> {
+        let mut sessions_by_day: HashMap<NaiveDate, Vec<TempSession>> = HashMap::new();
 
+        for event in events {
             let session = TempSession::new(
                 event.id.clone(),
                 event.event_type.clone(),
-                event.created_at,
-                event.repo.name.clone(),
-                GitHubClient::extract_jira_issues(event),
-                Self::get_event_description_from_api(event),
+                event.timestamp,
+                event.repo.clone(),
+                event.get_jira_issues(),
+                event.description.clone(),
             );
 
-            sessions_by_day.entry(date_key).or_default().push(session);
+            sessions_by_day.entry(event.date).or_default().push(session);
         }
 
-        // Merge nearby events into sessions (within 2 hours)
-        let mut sessions_saved = 0;
-        for (_date, sessions) in sessions_by_day.iter_mut() {
-            sessions.sort_by(|a, b| a.start_time.cmp(&b.start_time));
+        let mut sessions = Vec::new();
+        for (_date, day_sessions) in sessions_by_day.iter_mut() {
+            day_sessions.sort_by_key(|a| a.start_time);
 
             let mut merged: Vec<TempSession> = Vec::new();
-            for session in sessions.drain(..) {
+            for session in day_sessions.drain(..) {
                 if let Some(last) = merged.last_mut() {
-                    if session.start_time.signed_duration_since(last.end_time) < Duration::hours(2)
+                    if session.start_time.signed_duration_since(last.end_time)
+                        < config.session_gap()
                     {
                         last.end_time = session.end_time;
                         last.jira_issues.extend(session.jira_issues);
@@ -291,32 +517,29 @@ impl GitHubService {
             }
 
             for temp_session in merged {
-                let duration = temp_session
-                    .end_time
-                    .signed_duration_since(temp_session.start_time)
-                    .num_seconds()
-                    .max(15 * 60);
-
-                let db_session = GitHubSession::new(
-                    temp_session.start_time,
+                let start_time = temp_session.start_time - config.session_padding();
+                let duration = Self::session_duration(start_time, temp_session.end_time, config);
+
+                sessions.push(GitHubSession::new(
+                    start_time,
                     temp_session.end_time,
                     duration,
                     temp_session.repo,
                     temp_session.description,
                     temp_session.jira_issues,
                     temp_session.event_ids,
-                );
-
-                self.save_session(&db_session);
-                sessions_saved += 1;
+                ));
             }
         }
 
-        sessions_saved
+        sessions
     }
 
     /// Group events by day and calculate work sessions (no DB access)
-    pub fn calculate_work_sessions(events: &[APIGitHubEvent]) -> HashMap<String, Vec<WorkSession>> {
+    pub fn calculate_work_sessions(
+        events: &[APIGitHubEvent],
+        config: &GithubConfig,
+    ) -> HashMap<String, Vec<WorkSession>> {
         let mut sessions_by_day: HashMap<String, Vec<WorkSession>> = HashMap::new();
 
         for event in events {
@@ -327,7 +550,7 @@ impl GitHubService {
                 end_time: event.created_at,
                 event_type: event.event_type.clone(),
                 repo: event.repo.name.clone(),
-                jira_issues: GitHubClient::extract_jira_issues(event),
+                jira_issues: GitHubClient::event_issues(event, config),
                 description: Self::get_event_description(event),
             };
 
@@ -335,12 +558,13 @@ impl GitHubService {
         }
 
         for (_date, sessions) in sessions_by_day.iter_mut() {
-            sessions.sort_by(|a, b| a.start_time.cmp(&b.start_time));
+            sessions.sort_by_key(|a| a.start_time);
 
             let mut merged: Vec<WorkSession> = Vec::new();
             for session in sessions.drain(..) {
                 if let Some(last) = merged.last_mut() {
-                    if session.start_time.signed_duration_since(last.end_time) < Duration::hours(2)
+                    if session.start_time.signed_duration_since(last.end_time)
+                        < config.session_gap()
                     {
                         last.end_time = session.end_time;
                         last.jira_issues.extend(session.jira_issues);
@@ -355,6 +579,9 @@ impl GitHubService {
                 }
                 merged.push(session);
             }
+            for session in &mut merged {
+                session.start_time -= config.session_padding();
+            }
             *sessions = merged;
         }
 
@@ -386,14 +613,16 @@ impl GitHubService {
                 }
                 "Pull request activity".to_string()
             }
-            "PullRequestReviewEvent" | "PullRequestReviewCommentEvent" => "PR review".to_string(),
+            "PullRequestReviewEvent"
+            | "PullRequestReviewCommentEvent"
+            | "IssueCommentEvent"
+            | "CommitCommentEvent" => GitHubClient::review_description(event),
             "IssuesEvent" => {
                 if let Some(action) = event.payload.get("action").and_then(|a| a.as_str()) {
                     return format!("Issue {}", action);
                 }
                 "Issue activity".to_string()
             }
-            "IssueCommentEvent" => "Issue comment".to_string(),
             "CreateEvent" | "DeleteEvent" => {
                 if let Some(ref_type) = event.payload.get("ref_type").and_then(|r| r.as_str()) {
                     return format!("Branch/tag {}", ref_type);
@@ -455,14 +684,13 @@ pub struct WorkSession {
 }
 
 impl WorkSession {
-    /// Calculate duration in seconds (minimum 15 minutes)
-    pub fn duration_seconds(&self) -> i64 {
+    /// Calculate duration in seconds, at least the configured minimum session length
+    pub fn duration_seconds(&self, config: &GithubConfig) -> i64 {
         let duration = self
             .end_time
             .signed_duration_since(self.start_time)
             .num_seconds();
-        // Minimum 15 minutes per session
-        duration.max(15 * 60)
+        duration.max(config.min_session_seconds())
     }
 
     /// Get the most relevant Jira issue (first one found)
@@ -475,7 +703,6 @@ impl WorkSession {
 mod tests {
     use super::*;
     use crate::client::github_client::{GitHubEvent as APIGitHubEvent, GitHubRepo};
-    use crate::storage::database::{Database, GenericDatabase};
     use chrono::{TimeZone, Timelike};
     use serde_json::json;
 
@@ -493,10 +720,16 @@ mod tests {
     }
 
     fn make_service() -> GitHubService {
-        let db = Database::temporary();
-        let events_db = GenericDatabase::new(&db, "events").unwrap();
-        let sessions_db = GenericDatabase::new(&db, "sessions").unwrap();
-        GitHubService::new(events_db, sessions_db)
+        GitHubService::new(&Context::temporary())
+    }
+
+    fn save_sessions(svc: &GitHubService, events: &[APIGitHubEvent]) -> usize {
+        let github = GithubConfig::default();
+        let events: Vec<GitHubEvent> = events
+            .iter()
+            .map(|event| GitHubService::session_event(event, &github))
+            .collect();
+        svc.calculate_and_save_sessions(&events)
     }
 
     #[test]
@@ -507,12 +740,77 @@ mod tests {
             make_event("e2", "org/repo", 10), // 1h gap → merged
             make_event("e3", "org/repo", 13), // 3h gap → new session
         ];
-        svc.calculate_and_save_sessions(&events);
+        save_sessions(&svc, &events);
 
         let sessions = svc.get_all_sessions().unwrap();
         assert_eq!(sessions.len(), 2);
     }
 
+    #[test]
+    fn test_reviews_and_comments_make_sessions() {
+        let svc = make_service();
+        let activity = |id: &str, event_type: &str, hour: u32, payload| APIGitHubEvent {
+            event_type: event_type.to_string(),
+            payload,
+            ..make_event(id, "org/repo", hour)
+        };
+        let pull_request = json!({"title": "PROJ-7 Cache tokens", "head": {"ref": "proj-7"}});
+        let events = vec![
+            activity(
+                "r1",
+                "PullRequestReviewEvent",
+                9,
+                json!({"review": {"state": "changes_requested"}, "pull_request": pull_request}),
+            ),
+            activity(
+                "r2",
+                "PullRequestReviewCommentEvent",
+                10,
+                json!({"comment": {"body": "nit"}, "pull_request": pull_request}),
+            ),
+            activity(
+                "c1",
+                "IssueCommentEvent",
+                14,
+                json!({"issue": {"title": "PROJ-8 Flaky build", "pull_request": {}}}),
+            ),
+        ];
+        save_sessions(&svc, &events);
+
+        let mut sessions = svc.get_all_sessions().unwrap();
+        sessions.sort_by_key(|s| s.start_time);
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].get_jira_issues(), vec!["PROJ-7"]);
+        assert_eq!(
+            sessions[0].description,
+            "Reviewed PR: PROJ-7 Cache tokens (changes requested); Commented on PR: PROJ-7 Cache tokens"
+        );
+        assert_eq!(sessions[1].get_jira_issues(), vec!["PROJ-8"]);
+        assert_eq!(
+            sessions[1].description,
+            "Commented on PR: PROJ-8 Flaky build"
+        );
+    }
+
+    #[test]
+    fn test_repo_cursor_skips_stored_commits() {
+        let day = |d| Utc.with_ymd_and_hms(2026, 3, d, 0, 0, 0).unwrap();
+        let cursor = RepoCursor::covering(None, "org/api", day(1), day(15));
+
+        // Only the days after the cursor, and the last ones before it, are read again
+        assert_eq!(cursor.since(day(2), day(20)), Some(day(12)));
+        assert_eq!(cursor.since(day(1), day(5)), None);
+        // An earlier or later period is read whole
+        assert_eq!(cursor.since(day(16), day(20)), Some(day(16)));
+        let earlier = Utc.with_ymd_and_hms(2026, 2, 20, 0, 0, 0).unwrap();
+        assert_eq!(cursor.since(earlier, day(20)), Some(earlier));
+
+        let extended = RepoCursor::covering(Some(cursor.clone()), "org/api", day(10), day(20));
+        assert_eq!((extended.from, extended.until), (day(1), day(20)));
+        let apart = RepoCursor::covering(Some(cursor), "org/api", day(25), day(28));
+        assert_eq!((apart.from, apart.until), (day(25), day(28)));
+    }
+
     #[test]
     fn test_resync_removes_stale_sessions() {
         let svc = make_service();
@@ -522,7 +820,7 @@ mod tests {
             make_event("e1", "org/repo", 9),
             make_event("e2", "org/repo", 12),
         ];
-        svc.calculate_and_save_sessions(&first_sync);
+        save_sessions(&svc, &first_sync);
         assert_eq!(svc.get_all_sessions().unwrap().len(), 2);
 
         // Second sync: two bridging events fill the gap (each step < 2h apart)
@@ -532,7 +830,7 @@ mod tests {
             make_event("e4", "org/repo", 11), // 1h after e3 → merged
             make_event("e2", "org/repo", 12), // 1h after e4 → merged
         ];
-        svc.calculate_and_save_sessions(&second_sync);
+        save_sessions(&svc, &second_sync);
 
         let sessions = svc.get_all_sessions().unwrap();
         // Stale sessions from first sync must be gone; only the merged session remains
@@ -546,4 +844,58 @@ mod tests {
         assert_eq!(s.start_time.hour(), 9);
         assert_eq!(s.end_time.hour(), 12);
     }
+
+    #[test]
+    fn test_rebuild_sessions_with_configured_settings() {
+        let config = Config {
+            github: GithubConfig {
+                session_gap_minutes: 30,
+                min_session_minutes: 5,
+                session_padding_minutes: 10,
+                ..GithubConfig::default()
+            },
+            ..Config::default()
+        };
+        let svc = GitHubService::new(&Context::temporary().with_config(config));
+        for (id, minute) in [("e1", 0), ("e2", 20), ("e3", 60)] {
+            let timestamp = Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap()
+                + chrono::Duration::minutes(minute);
+            svc.save_event(&GitHubEvent {
+                id: id.to_string(),
+                event_type: "PushEvent".to_string(),
+                repo: "org/repo".to_string(),
+                timestamp,
+                description: format!("Pushed {}", id),
+                jira_issues: "PROJ-1".to_string(),
+                date: timestamp.date_naive(),
+            });
+        }
+        // Built with the default settings, before the config changed
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap();
+        svc.save_session(&GitHubSession::new(
+            start,
+            start + chrono::Duration::hours(1),
+            3600,
+            "org/repo".to_string(),
+            "stale".to_string(),
+            vec!["PROJ-1".to_string()],
+            vec!["e1".to_string(), "e2".to_string(), "e3".to_string()],
+        ));
+
+        assert_eq!(svc.rebuild_sessions(false).unwrap(), 2);
+
+        let mut sessions = svc.get_all_sessions().unwrap();
+        sessions.sort_by_key(|s| s.start_time);
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(
+            (
+                sessions[0].start_time.hour(),
+                sessions[0].start_time.minute()
+            ),
+            (8, 50)
+        );
+        assert_eq!(sessions[0].duration_seconds, 1800);
+        assert_eq!(sessions[0].get_event_ids(), vec!["e1", "e2"]);
+        assert_eq!(sessions[1].duration_seconds, 600);
+    }
 }
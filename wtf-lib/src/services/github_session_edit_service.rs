@@ -0,0 +1,490 @@
+use crate::config::GithubConfig;
+use crate::context::Context;
+use crate::error::{WtfError, WtfResult};
+use crate::models::data::{GitHubEvent, GitHubSession};
+use crate::services::github_service::GitHubService;
+use crate::storage::database::GenericDatabase;
+use crate::utils::dates;
+use chrono::{DateTime, Local, NaiveTime, TimeZone, Utc};
+use std::collections::{HashMap, HashSet};
+
+// --- EditedSession (private) ---
+
+/// A session split, trimmed or merged by hand, which fetches leave alone. `claimed` are
+/// the events it was made from, the ones trimmed away included, so that they don't turn
+/// into a session of their own again.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub(crate) struct EditedSession {
+    pub(crate) id: String,
+    pub(crate) claimed: Vec<String>,
+}
+
+impl crate::storage::database::Identifiable for EditedSession {
+    fn get_id(&self) -> String {
+        self.id.clone()
+    }
+}
+
+// --- GitHubSessionEditService ---
+
+/// Splitting, trimming and merging GitHub sessions by hand, and keeping track of the
+/// sessions edited so that fetches and rebuilds leave them alone
+pub struct GitHubSessionEditService {
+    context: Context,
+    events_db: GenericDatabase<GitHubEvent>,
+    sessions_db: GenericDatabase<GitHubSession>,
+    edited_db: GenericDatabase<EditedSession>,
+}
+
+impl GitHubSessionEditService {
+    pub fn new(context: &Context) -> Self {
+        let database = context.database();
+        Self {
+            context: context.clone(),
+            events_db: GenericDatabase::new(database, "github_events")
+                .expect("could not initialize github events database"),
+            sessions_db: GenericDatabase::new(database, "github_sessions")
+                .expect("could not initialize github sessions database"),
+            edited_db: GenericDatabase::new(database, "github_edited_sessions")
+                .expect("could not initialize github edited sessions database"),
+        }
+    }
+
+    /// Create a service backed by the production sled database.
+    pub fn production() -> Self {
+        Self::new(&Context::production())
+    }
+
+    /// Split a session at `at`, local time on the session's day: events before it stay
+    /// in the first part, the rest move to the second. Each part is narrowed to the
+    /// events it keeps.
+    pub fn split_session(
+        &self,
+        session_id: &str,
+        at: NaiveTime,
+    ) -> WtfResult<(GitHubSession, GitHubSession)> {
+        self.split_session_in(&Local, session_id, at)
+    }
+
+    fn split_session_in<Tz: TimeZone>(
+        &self,
+        tz: &Tz,
+        session_id: &str,
+        at: NaiveTime,
+    ) -> WtfResult<(GitHubSession, GitHubSession)> {
+        let session = self.get_session(session_id)?;
+        let at = dates::at_local_time(tz, session.date, at);
+        if at <= session.start_time || at >= session.end_time {
+            return Err(WtfError::Invalid(
+                "Split time must fall inside the session".to_string(),
+            ));
+        }
+
+        let (before, after): (Vec<_>, Vec<_>) = self
+            .session_events(&session)
+            .into_iter()
+            .partition(|e| e.timestamp < at);
+        let config = self.github_config();
+        let first = Self::rebuild_session(&session, session.start_time, at, &before, &config);
+        let second = Self::rebuild_session(&session, at, session.end_time, &after, &config);
+
+        self.replace_sessions(&[&session], &[&first, &second])?;
+        Ok((first, second))
+    }
+
+    /// Narrow a session to `[start, end]`, local times on the session's day, dropping
+    /// the events outside it
+    pub fn trim_session(
+        &self,
+        session_id: &str,
+        start: NaiveTime,
+        end: NaiveTime,
+    ) -> WtfResult<GitHubSession> {
+        self.trim_session_in(&Local, session_id, start, end)
+    }
+
+    fn trim_session_in<Tz: TimeZone>(
+        &self,
+        tz: &Tz,
+        session_id: &str,
+        start: NaiveTime,
+        end: NaiveTime,
+    ) -> WtfResult<GitHubSession> {
+        let session = self.get_session(session_id)?;
+        let start = dates::at_local_time(tz, session.date, start);
+        let end = dates::at_local_time(tz, session.date, end);
+        if start >= end {
+            return Err(WtfError::Invalid("Start must be before end".to_string()));
+        }
+        if start < session.start_time || end > session.end_time {
+            return Err(WtfError::Invalid(
+                "Trimmed range must stay within the session".to_string(),
+            ));
+        }
+
+        let kept: Vec<_> = self
+            .session_events(&session)
+            .into_iter()
+            .filter(|e| e.timestamp >= start && e.timestamp <= end)
+            .collect();
+        let config = self.github_config();
+        let narrowed = Self::rebuild_session(&session, start, end, &kept, &config);
+        // Keep the bounds the user asked for rather than snapping to the kept events
+        let trimmed = GitHubSession::new(
+            start,
+            end,
+            GitHubService::session_duration(start, end, &config),
+            narrowed.repo.clone(),
+            narrowed.description.clone(),
+            narrowed.get_jira_issues(),
+            narrowed.get_event_ids(),
+        );
+
+        self.replace_sessions(&[&session], &[&trimmed])?;
+        Ok(trimmed)
+    }
+
+    /// Merge two sessions of the same repo and day into one spanning both
+    pub fn merge_sessions(&self, first_id: &str, second_id: &str) -> WtfResult<GitHubSession> {
+        let first = self.get_session(first_id)?;
+        let second = self.get_session(second_id)?;
+        if first.date != second.date {
+            return Err(WtfError::Invalid(
+                "Only sessions from the same day can be merged".to_string(),
+            ));
+        }
+        if first.repo != second.repo {
+            return Err(WtfError::Invalid(
+                "Only sessions of the same repo can be merged".to_string(),
+            ));
+        }
+        let (earlier, later) = if first.start_time <= second.start_time {
+            (&first, &second)
+        } else {
+            (&second, &first)
+        };
+
+        let start = earlier.start_time;
+        let end = earlier.end_time.max(later.end_time);
+        let mut issues = earlier.get_jira_issues();
+        issues.extend(later.get_jira_issues());
+        issues.sort();
+        issues.dedup();
+        let mut event_ids = earlier.get_event_ids();
+        event_ids.extend(later.get_event_ids());
+        let description = [earlier.description.as_str(), later.description.as_str()]
+            .into_iter()
+            .filter(|d| !d.is_empty())
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        let merged = GitHubSession::new(
+            start,
+            end,
+            GitHubService::session_duration(start, end, &self.github_config()),
+            earlier.repo.clone(),
+            description,
+            issues,
+            event_ids,
+        );
+
+        self.replace_sessions(&[earlier, later], &[&merged])?;
+        Ok(merged)
+    }
+
+    /// The sessions edited by hand that still exist, by id
+    pub(crate) fn edited_sessions(&self) -> HashMap<String, EditedSession> {
+        let sessions: HashSet<String> = self
+            .sessions_db
+            .get_all()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|session| session.id)
+            .collect();
+        self.edited_db
+            .get_all()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|edited| sessions.contains(&edited.id))
+            .map(|edited| (edited.id.clone(), edited))
+            .collect()
+    }
+
+    /// Forget every edit, so that the next rebuild recomputes the sessions from scratch
+    pub(crate) fn discard_edits(&self) -> WtfResult<()> {
+        for edited in self.edited_db.get_all()? {
+            self.edited_db.remove(&edited.id)?;
+        }
+        Ok(())
+    }
+
+    fn get_session(&self, session_id: &str) -> WtfResult<GitHubSession> {
+        self.sessions_db
+            .get(session_id)?
+            .ok_or_else(|| WtfError::not_found("Session", session_id))
+    }
+
+    /// Stored events of a session, oldest first (missing records are skipped)
+    fn session_events(&self, session: &GitHubSession) -> Vec<GitHubEvent> {
+        let mut events: Vec<GitHubEvent> = session
+            .get_event_ids()
+            .iter()
+            .filter_map(|id| self.events_db.get(id).ok().flatten())
+            .collect();
+        events.sort_by_key(|e| e.timestamp);
+        events
+    }
+
+    /// Build a session from a subset of `template`'s events. Without event records
+    /// the given bounds and the template's issues and description are kept.
+    fn rebuild_session(
+        template: &GitHubSession,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        events: &[GitHubEvent],
+        config: &GithubConfig,
+    ) -> GitHubSession {
+        let (Some(first), Some(last)) = (events.first(), events.last()) else {
+            return GitHubSession::new(
+                start,
+                end,
+                GitHubService::session_duration(start, end, config),
+                template.repo.clone(),
+                template.description.clone(),
+                template.get_jira_issues(),
+                Vec::new(),
+            );
+        };
+
+        let mut issues: Vec<String> = events.iter().flat_map(|e| e.get_jira_issues()).collect();
+        issues.sort();
+        issues.dedup();
+        let description = events
+            .iter()
+            .map(|e| e.description.as_str())
+            .filter(|d| !d.is_empty())
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        GitHubSession::new(
+            first.timestamp,
+            last.timestamp,
+            GitHubService::session_duration(first.timestamp, last.timestamp, config),
+            template.repo.clone(),
+            description,
+            issues,
+            events.iter().map(|e| e.id.clone()).collect(),
+        )
+    }
+
+    fn github_config(&self) -> GithubConfig {
+        self.context
+            .config()
+            .map(|config| config.github)
+            .unwrap_or_default()
+    }
+
+    /// Replace `old` by the `new` sessions the user made of them, marked as edited
+    fn replace_sessions(&self, old: &[&GitHubSession], new: &[&GitHubSession]) -> WtfResult<()> {
+        let mut claimed = Vec::new();
+        for session in old {
+            match self.edited_db.get(&session.id)? {
+                Some(edited) => claimed.extend(edited.claimed),
+                None => claimed.extend(session.get_event_ids()),
+            }
+            self.edited_db.remove(&session.id)?;
+            self.sessions_db.remove(&session.id)?;
+        }
+        claimed.sort();
+        claimed.dedup();
+        for session in new {
+            self.sessions_db.insert(session)?;
+            self.edited_db.insert(&EditedSession {
+                id: session.id.clone(),
+                claimed: claimed.clone(),
+            })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, FixedOffset, Timelike};
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn store_event(svc: &GitHubService, id: &str, hour: u32, issue: &str) {
+        let timestamp = Utc.with_ymd_and_hms(2024, 1, 15, hour, 0, 0).unwrap();
+        svc.save_event(&GitHubEvent {
+            id: id.to_string(),
+            event_type: "PushEvent".to_string(),
+            repo: "org/repo".to_string(),
+            timestamp,
+            description: format!("Pushed {}", id),
+            jira_issues: issue.to_string(),
+            date: timestamp.date_naive(),
+        });
+    }
+
+    /// One session glued from a morning (9h, 10h) and an evening (18h) burst
+    fn make_glued_session(svc: &GitHubService) -> GitHubSession {
+        store_event(svc, "e1", 9, "PROJ-1");
+        store_event(svc, "e2", 10, "PROJ-1");
+        store_event(svc, "e3", 18, "PROJ-2");
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 15, 18, 0, 0).unwrap();
+        let session = GitHubSession::new(
+            start,
+            end,
+            9 * 3600,
+            "org/repo".to_string(),
+            "glued".to_string(),
+            vec!["PROJ-1".to_string(), "PROJ-2".to_string()],
+            vec!["e1".to_string(), "e2".to_string(), "e3".to_string()],
+        );
+        svc.save_session(&session);
+        session
+    }
+
+    fn make_services() -> (GitHubService, GitHubSessionEditService) {
+        let context = Context::temporary();
+        (
+            GitHubService::new(&context),
+            GitHubSessionEditService::new(&context),
+        )
+    }
+
+    #[test]
+    fn test_split_session_at_local_time() {
+        let (github, edits) = make_services();
+        let session = make_glued_session(&github);
+
+        let (morning, evening) = edits
+            .split_session_in(&Utc, &session.id, time(14, 0))
+            .unwrap();
+
+        assert_eq!(morning.duration_seconds, 3600);
+        assert_eq!(morning.get_jira_issues(), vec!["PROJ-1"]);
+        assert_eq!(morning.get_event_ids(), vec!["e1", "e2"]);
+        assert_eq!(evening.start_time.hour(), 18);
+        assert_eq!(
+            evening.duration_seconds,
+            GithubConfig::default().min_session_seconds()
+        );
+        assert_eq!(evening.get_jira_issues(), vec!["PROJ-2"]);
+        assert_eq!(github.get_all_sessions().unwrap().len(), 2);
+
+        assert!(edits
+            .split_session_in(&Utc, &morning.id, time(20, 0))
+            .is_err());
+    }
+
+    #[test]
+    fn test_split_time_read_in_the_local_time_zone() {
+        let (github, edits) = make_services();
+        let session = make_glued_session(&github);
+        let paris = FixedOffset::east_opt(3600).unwrap();
+
+        // 10:30 in Paris is 9:30 UTC, between e1 and e2
+        let (first, second) = edits
+            .split_session_in(&paris, &session.id, time(10, 30))
+            .unwrap();
+
+        assert_eq!(first.get_event_ids(), vec!["e1"]);
+        assert_eq!(second.get_event_ids(), vec!["e2", "e3"]);
+    }
+
+    #[test]
+    fn test_trim_session_drops_outside_events() {
+        let (github, edits) = make_services();
+        let session = make_glued_session(&github);
+
+        let trimmed = edits
+            .trim_session_in(&Utc, &session.id, time(9, 0), time(11, 30))
+            .unwrap();
+
+        assert_eq!(
+            trimmed.end_time,
+            Utc.with_ymd_and_hms(2024, 1, 15, 11, 30, 0).unwrap()
+        );
+        assert_eq!(trimmed.duration_seconds, 9000);
+        assert_eq!(trimmed.get_jira_issues(), vec!["PROJ-1"]);
+        assert!(edits
+            .trim_session_in(&Utc, &trimmed.id, time(11, 30), time(9, 0))
+            .is_err());
+        assert_eq!(github.get_all_sessions().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_merge_sessions_restores_split() {
+        let (github, edits) = make_services();
+        let session = make_glued_session(&github);
+        let (morning, evening) = edits
+            .split_session_in(&Utc, &session.id, time(14, 0))
+            .unwrap();
+
+        let merged = edits.merge_sessions(&evening.id, &morning.id).unwrap();
+
+        assert_eq!(merged.start_time, session.start_time);
+        assert_eq!(merged.end_time, session.end_time);
+        assert_eq!(merged.get_jira_issues(), vec!["PROJ-1", "PROJ-2"]);
+        assert_eq!(merged.get_event_ids(), vec!["e1", "e2", "e3"]);
+        assert_eq!(github.get_all_sessions().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_sessions_of_other_repos_are_not_merged() {
+        let (github, edits) = make_services();
+        let session = make_glued_session(&github);
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 20, 0, 0).unwrap();
+        let other = GitHubSession::new(
+            start,
+            start + Duration::hours(1),
+            3600,
+            "org/other".to_string(),
+            String::new(),
+            Vec::new(),
+            Vec::new(),
+        );
+        github.save_session(&other);
+
+        assert!(edits.merge_sessions(&session.id, &other.id).is_err());
+        assert_eq!(github.get_all_sessions().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_edited_sessions_survive_a_new_sync() {
+        let (github, edits) = make_services();
+        let session = make_glued_session(&github);
+        let (morning, _) = edits
+            .split_session_in(&Utc, &session.id, time(14, 0))
+            .unwrap();
+        let trimmed = edits
+            .trim_session_in(&Utc, &morning.id, time(9, 0), time(9, 30))
+            .unwrap();
+        // A later push the same day, after the edits
+        store_event(&github, "e4", 21, "PROJ-3");
+
+        github.rebuild_sessions(false).unwrap();
+
+        let mut sessions = github.get_all_sessions().unwrap();
+        sessions.sort_by_key(|s| s.start_time);
+        let ids: Vec<&str> = sessions.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(sessions.len(), 3, "{:?}", ids);
+        assert_eq!(sessions[0].id, trimmed.id);
+        assert_eq!(sessions[1].start_time.hour(), 18);
+        // The new event makes its own session; e2, trimmed away, doesn't come back
+        assert_eq!(sessions[2].get_event_ids(), vec!["e4"]);
+
+        github.rebuild_sessions(true).unwrap();
+        assert!(!github
+            .get_all_sessions()
+            .unwrap()
+            .iter()
+            .any(|s| s.id == trimmed.id));
+    }
+}
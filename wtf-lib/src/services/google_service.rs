@@ -1,18 +1,21 @@
-use crate::config::{expand_path, Config};
-use google_calendar3::hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
-use google_calendar3::hyper_util::client::legacy::connect::HttpConnector;
+use crate::client::hyper_client::{self, HyperConnector};
+use crate::config::{expand_path, Config, GoogleConfig};
+use crate::secrets::Secret;
 use google_calendar3::hyper_util::client::legacy::Client;
-use google_calendar3::yup_oauth2::authenticator_delegate::InstalledFlowDelegate;
+use google_calendar3::yup_oauth2::authenticator::Authenticator;
+use google_calendar3::yup_oauth2::authenticator_delegate::{
+    DeviceAuthResponse, DeviceFlowDelegate, InstalledFlowDelegate,
+};
 use google_calendar3::yup_oauth2::{
-    ApplicationSecret, InstalledFlowAuthenticator, InstalledFlowReturnMethod,
+    read_service_account_key, ApplicationSecret, CustomHyperClientBuilder, DeviceFlowAuthenticator,
+    InstalledFlowAuthenticator, InstalledFlowReturnMethod, ServiceAccountAuthenticator,
 };
 use google_calendar3::{hyper_util, CalendarHub};
-use log::{debug, error, info};
-use std::error::Error;
-use std::fmt;
+use log::{debug, error, info, log_enabled, Level};
 use std::fs;
 use std::future::Future;
 use std::pin::Pin;
+use thiserror::Error;
 
 // Custom delegate that logs OAuth messages instead of printing to stdout
 #[derive(Clone)]
@@ -41,52 +44,63 @@ impl InstalledFlowDelegate for TuiInstalledFlowDelegate {
     }
 }
 
-#[derive(Debug)]
+// Device flow delegate: the code to enter goes to the logs when someone reads them (the
+// TUI has no stdout), to stderr otherwise
+#[derive(Clone)]
+struct LoggingDeviceFlowDelegate;
+
+impl DeviceFlowDelegate for LoggingDeviceFlowDelegate {
+    fn present_user_code<'a>(
+        &'a self,
+        device_auth_resp: &'a DeviceAuthResponse,
+    ) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            let message = format!(
+                "🔑 Open {} on any device and enter the code {} to authorize Google Calendar access (valid until {})",
+                device_auth_resp.verification_uri,
+                device_auth_resp.user_code,
+                device_auth_resp.expires_at
+            );
+            if log_enabled!(Level::Info) {
+                info!("{}", message);
+            } else {
+                eprintln!("{}", message);
+            }
+        })
+    }
+}
+
+#[derive(Debug, Error)]
 pub enum GoogleServiceError {
+    #[error("Google credentials file not found at: {0}\nPlease download OAuth 2.0 credentials from Google Cloud Console")]
     CredentialsNotFound(String),
+    #[error("Invalid Google credentials file: {0}\nPlease check the JSON format")]
     CredentialsInvalid(String),
+    #[error("Google service account key not found at: {0}\nPlease create a key for the service account in Google Cloud Console")]
+    ServiceAccountKeyNotFound(String),
+    #[error("Google authentication failed: {0}")]
     AuthenticationFailed(String),
+    #[error("SSL initialization failed: {0}")]
     SslInitFailed(String),
+    #[error("Google configuration error: {0}\nPlease add [google] section to config.toml")]
     ConfigError(String),
 }
 
-impl fmt::Display for GoogleServiceError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            GoogleServiceError::CredentialsNotFound(path) => {
-                write!(f, "Google credentials file not found at: {}\nPlease download OAuth 2.0 credentials from Google Cloud Console", path)
-            }
-            GoogleServiceError::CredentialsInvalid(msg) => {
-                write!(
-                    f,
-                    "Invalid Google credentials file: {}\nPlease check the JSON format",
-                    msg
-                )
-            }
-            GoogleServiceError::AuthenticationFailed(msg) => {
-                write!(f, "Google authentication failed: {}", msg)
-            }
-            GoogleServiceError::SslInitFailed(msg) => {
-                write!(f, "SSL initialization failed: {}", msg)
-            }
-            GoogleServiceError::ConfigError(msg) => {
-                write!(
-                    f,
-                    "Google configuration error: {}\nPlease add [google] section to config.toml",
-                    msg
-                )
-            }
-        }
-    }
-}
+pub struct GoogleService;
 
-impl Error for GoogleServiceError {}
+type HubAuthenticator = Authenticator<HyperConnector>;
+type AuthClient = CustomHyperClientBuilder<HyperConnector>;
 
-pub struct GoogleService;
+/// Read the OAuth client of a Google credentials file
+pub fn parse_application_secret(json: &[u8]) -> Result<ApplicationSecret, GoogleServiceError> {
+    serde_json::from_slice(json).map_err(|e| {
+        error!("Failed to parse credentials JSON: {}", e);
+        GoogleServiceError::CredentialsInvalid(e.to_string())
+    })
+}
 
 impl GoogleService {
-    pub async fn get_hub() -> Result<CalendarHub<HttpsConnector<HttpConnector>>, GoogleServiceError>
-    {
+    pub async fn get_hub() -> Result<CalendarHub<HyperConnector>, GoogleServiceError> {
         // Load configuration
         let config = Config::load().map_err(|e| {
             GoogleServiceError::ConfigError(format!("Failed to load config: {}", e))
@@ -96,60 +110,126 @@ impl GoogleService {
             GoogleServiceError::ConfigError("No [google] section found in config".to_string())
         })?;
 
+        // The sign-in and the calendar calls go through the [network] proxy and CAs
+        let connector = hyper_client::connector(&config.network).map_err(|e| {
+            error!("Failed to set up the HTTPS connector: {}", e);
+            GoogleServiceError::SslInitFailed(e)
+        })?;
+        let auth_client = CustomHyperClientBuilder::from(
+            Client::builder(hyper_util::rt::TokioExecutor::new())
+                .build::<_, String>(connector.clone()),
+        );
+
+        let auth = match &google_config.service_account_key_path {
+            Some(key_path) => {
+                Self::service_account_authenticator(
+                    key_path,
+                    google_config.impersonate.as_deref(),
+                    auth_client,
+                )
+                .await?
+            }
+            None => Self::installed_flow_authenticator(&google_config, auth_client).await?,
+        };
+
+        let client = Client::builder(hyper_util::rt::TokioExecutor::new()).build(connector);
+
+        let hub = CalendarHub::new(client, auth);
+        debug!("Google Calendar Hub initialized successfully");
+        Ok(hub)
+    }
+
+    /// The sign-in of an OAuth client, in a browser or with a device code, its tokens
+    /// cached in `token_cache_path`
+    async fn installed_flow_authenticator(
+        google_config: &GoogleConfig,
+        client: AuthClient,
+    ) -> Result<HubAuthenticator, GoogleServiceError> {
         debug!(
             "Loading Google credentials from: {}",
             google_config.credentials_path
         );
 
-        // Check if credentials file exists
-        let creds_path = expand_path(&google_config.credentials_path);
-        if !creds_path.exists() {
-            return Err(GoogleServiceError::CredentialsNotFound(
-                google_config.credentials_path.clone(),
-            ));
-        }
-
-        // Read and parse credentials
-        let creds_file = fs::File::open(creds_path).map_err(|e| {
-            error!("Failed to open credentials file: {}", e);
-            GoogleServiceError::CredentialsNotFound(google_config.credentials_path.clone())
-        })?;
-
-        let secret: ApplicationSecret = serde_json::from_reader(creds_file).map_err(|e| {
-            error!("Failed to parse credentials JSON: {}", e);
-            GoogleServiceError::CredentialsInvalid(e.to_string())
-        })?;
+        let secret: ApplicationSecret = match Secret::GoogleCredentials.get() {
+            Ok(Some(json)) => {
+                debug!("Using the Google credentials from the OS keyring");
+                parse_application_secret(json.as_bytes())?
+            }
+            _ => {
+                // Check if credentials file exists
+                let creds_path = expand_path(&google_config.credentials_path);
+                if !creds_path.exists() {
+                    return Err(GoogleServiceError::CredentialsNotFound(
+                        google_config.credentials_path.clone(),
+                    ));
+                }
+
+                // Read and parse credentials
+                let creds_file = fs::read(creds_path).map_err(|e| {
+                    error!("Failed to open credentials file: {}", e);
+                    GoogleServiceError::CredentialsNotFound(google_config.credentials_path.clone())
+                })?;
+                parse_application_secret(&creds_file)?
+            }
+        };
 
         debug!("Using token cache at: {}", google_config.token_cache_path);
 
         // Build authenticator with custom delegate
         let expanded_token_path = expand_path(&google_config.token_cache_path);
-        let auth =
-            InstalledFlowAuthenticator::builder(secret, InstalledFlowReturnMethod::HTTPRedirect)
+        if google_config.auth_flow.uses_device_code() {
+            return DeviceFlowAuthenticator::with_client(secret, client)
                 .persist_tokens_to_disk(expanded_token_path)
-                .flow_delegate(Box::new(TuiInstalledFlowDelegate))
+                .flow_delegate(Box::new(LoggingDeviceFlowDelegate))
                 .build()
                 .await
                 .map_err(|e| {
-                    error!("OAuth authentication failed: {}", e);
+                    error!("OAuth device authentication failed: {}", e);
                     GoogleServiceError::AuthenticationFailed(e.to_string())
-                })?;
+                });
+        }
+        InstalledFlowAuthenticator::with_client(
+            secret,
+            InstalledFlowReturnMethod::HTTPRedirect,
+            client,
+        )
+        .persist_tokens_to_disk(expanded_token_path)
+        .flow_delegate(Box::new(TuiInstalledFlowDelegate))
+        .build()
+        .await
+        .map_err(|e| {
+            error!("OAuth authentication failed: {}", e);
+            GoogleServiceError::AuthenticationFailed(e.to_string())
+        })
+    }
 
-        // Build HTTPS connector
-        let https_connector = HttpsConnectorBuilder::new()
-            .with_native_roots()
+    /// A service account acting as `subject` when set, without any browser sign-in
+    async fn service_account_authenticator(
+        key_path: &str,
+        subject: Option<&str>,
+        client: AuthClient,
+    ) -> Result<HubAuthenticator, GoogleServiceError> {
+        debug!("Using the Google service account key at: {}", key_path);
+        let expanded_key_path = expand_path(key_path);
+        if !expanded_key_path.exists() {
+            return Err(GoogleServiceError::ServiceAccountKeyNotFound(
+                key_path.to_string(),
+            ));
+        }
+        let key = read_service_account_key(expanded_key_path)
+            .await
             .map_err(|e| {
-                error!("Failed to initialize SSL with native roots: {}", e);
-                GoogleServiceError::SslInitFailed(e.to_string())
-            })?
-            .https_or_http()
-            .enable_http2()
-            .build();
-
-        let client = Client::builder(hyper_util::rt::TokioExecutor::new()).build(https_connector);
-
-        let hub = CalendarHub::new(client, auth);
-        debug!("Google Calendar Hub initialized successfully");
-        Ok(hub)
+                error!("Failed to parse service account key: {}", e);
+                GoogleServiceError::CredentialsInvalid(e.to_string())
+            })?;
+        let builder = ServiceAccountAuthenticator::with_client(key, client);
+        let builder = match subject {
+            Some(subject) => builder.subject(subject),
+            None => builder,
+        };
+        builder.build().await.map_err(|e| {
+            error!("Service account authentication failed: {}", e);
+            GoogleServiceError::AuthenticationFailed(e.to_string())
+        })
     }
 }
@@ -1,49 +1,111 @@
-use crate::client::jira_client::JiraClient;
+use crate::context::Context;
+use crate::error::{WtfError, WtfResult};
 use crate::models::data::{Board, Issue, Sprint, Worklog};
-use crate::models::jira::JiraError;
+use crate::models::jira::{JiraError, JiraTransition};
 use crate::services::worklogs_service::WorklogsService;
-use crate::storage::database::{GenericDatabase, DATABASE};
+use crate::storage::backend::StorageResult;
+use crate::storage::database::GenericDatabase;
+use crate::storage::shared::SplitDatabase;
 use chrono::{DateTime, Duration, Utc};
 use lazy_static::lazy_static;
-use log::{debug, error};
+use log::{debug, error, info, warn};
 use regex::Regex;
-use std::error::Error;
+use std::collections::HashMap;
 
 lazy_static! {
     static ref JIRA_CARD_IDENTIFIER: Regex = Regex::new(r"([a-zA-Z]+-[0-9]+)").unwrap();
 }
 
+/// The transition leading to the status named `status` (case-insensitive)
+fn transition_towards<'a>(
+    transitions: &'a [JiraTransition],
+    status: &str,
+) -> Option<&'a JiraTransition> {
+    transitions
+        .iter()
+        .find(|transition| transition.to.name.eq_ignore_ascii_case(status))
+}
+
 // --- IssueService ---
 
 pub struct IssueService {
+    context: Context,
     issues_db: GenericDatabase<Issue>,
 }
 
 impl IssueService {
-    pub fn new(issues_db: GenericDatabase<Issue>) -> Self {
-        Self { issues_db }
+    pub fn new(context: &Context) -> Self {
+        let issues_db = GenericDatabase::new(context.database(), "issues")
+            .expect("could not initialize issues database");
+        Self {
+            context: context.clone(),
+            issues_db,
+        }
     }
 
     pub fn production() -> Self {
-        let issues_db = GenericDatabase::new(&DATABASE, "issues")
-            .expect("could not initialize issues database");
-        Self::new(issues_db)
+        Self::new(&Context::production())
     }
 
+    /// Save an issue. Sprint membership is only known from per-sprint fetches,
+    /// so the sprints already recorded for it are kept.
     pub fn save_issue(&self, issue: &Issue) {
-        self.issues_db.insert(issue).unwrap();
+        let mut issue = issue.clone();
+        self.merge_known_sprints(&mut issue);
+        or_log(self.issues_db.insert(&issue), "save an issue");
     }
 
+    /// Save a batch of issues; duplicates (an issue fetched from a sprint and
+    /// from its project) are merged, keeping every sprint they were seen in.
     pub fn save_all_issues(&self, issues: Vec<Issue>) {
-        self.issues_db.save_all(issues).unwrap();
+        let mut by_key: HashMap<String, Issue> = HashMap::new();
+        for issue in issues {
+            match by_key.get_mut(&issue.key) {
+                Some(existing) => {
+                    let mut sprint_ids = std::mem::take(&mut existing.sprint_ids);
+                    sprint_ids.extend(&issue.sprint_ids);
+                    *existing = Issue {
+                        sprint_ids,
+                        ..issue
+                    };
+                }
+                None => {
+                    by_key.insert(issue.key.clone(), issue);
+                }
+            }
+        }
+
+        let issues = by_key
+            .into_values()
+            .map(|mut issue| {
+                self.merge_known_sprints(&mut issue);
+                issue
+            })
+            .collect();
+        or_log(self.issues_db.save_all(issues), "save issues");
+    }
+
+    fn merge_known_sprints(&self, issue: &mut Issue) {
+        if let Some(stored) = self.get_by_key(&issue.key) {
+            issue.sprint_ids.extend(stored.sprint_ids);
+        }
+        issue.sprint_ids.sort_unstable();
+        issue.sprint_ids.dedup();
+    }
+
+    /// Fetch one issue from Jira and update the cached copy
+    pub async fn refresh_issue(&self, key: &str) -> WtfResult<Issue> {
+        let issue = self.context.jira_client().get_issue(key).await?.into();
+        self.save_issue(&issue);
+        Ok(self.get_by_key(key).unwrap_or(issue))
     }
 
     pub fn get_all_issues(&self) -> Vec<Issue> {
-        self.issues_db.get_all().unwrap()
+        or_log(self.issues_db.get_all(), "load issues")
     }
 
     pub fn get_by_key(&self, key: &str) -> Option<Issue> {
-        self.issues_db.get(key).unwrap()
+        or_log(self.issues_db.get(key), "load an issue")
     }
 
     pub async fn add_time(
@@ -52,38 +114,79 @@ impl IssueService {
         duration: Duration,
         start: DateTime<Utc>,
         comment: Option<String>,
-    ) -> Result<Option<Worklog>, Box<dyn Error>> {
-        let jira_client = JiraClient::create();
-        match jira_client
+    ) -> WtfResult<Option<Worklog>> {
+        let worklogs_service = WorklogsService::new(&self.context);
+        let transition_to = self
+            .context
+            .config()
+            .ok()
+            .and_then(|config| config.jira.transition_on_first_worklog)
+            .filter(|_| {
+                !worklogs_service
+                    .get_all_worklogs()
+                    .iter()
+                    .any(|worklog| worklog.issue_id == issue_key)
+            });
+
+        let jira_worklog = self
+            .context
+            .jira_client()
             .add_time_to_issue(issue_key, duration, start, comment)
             .await
+            .inspect_err(|e| error!("an error occurred while adding time to issue: {:?}", e))?;
+        if let Some(status) = transition_to {
+            self.transition_to_status(issue_key, &status).await;
+        }
+        Ok(jira_worklog.map(|jira_worklog| {
+            let worklog = jira_worklog.into_worklog();
+            worklogs_service.save_worklog(worklog.clone());
+            worklog
+        }))
+    }
+
+    /// Move the issue to `status` when the workflow allows it from where it is. Failing
+    /// only warns: the worklog that triggered it is pushed either way.
+    async fn transition_to_status(&self, issue_key: &str, status: &str) {
+        let issue = self.get_by_key(issue_key);
+        if issue
+            .as_ref()
+            .is_some_and(|issue| issue.status.eq_ignore_ascii_case(status))
         {
-            Ok(jira_worklog) => {
-                if let Some(jira_worklog) = jira_worklog {
-                    let worklog = jira_worklog.into_worklog();
-                    WorklogsService::production().save_worklog(worklog.clone());
-                    return Ok(Some(worklog));
+            return;
+        }
+        let client = self.context.jira_client();
+        let transitions = match client.get_transitions(issue_key).await {
+            Ok(transitions) => transitions,
+            Err(e) => {
+                warn!("Could not list the transitions of {}: {}", issue_key, e);
+                return;
+            }
+        };
+        let Some(transition) = transition_towards(&transitions, status) else {
+            debug!("No transition of {} leads to '{}'", issue_key, status);
+            return;
+        };
+        match client.transition_issue(issue_key, &transition.id).await {
+            Ok(()) => {
+                info!("{} moved to '{}'", issue_key, transition.to.name);
+                if let Some(mut issue) = issue {
+                    issue.status = transition.to.name.clone();
+                    self.save_issue(&issue);
                 }
-                return Ok(None);
             }
-            Err(e) => error!("an error occurred while adding time to issue: {:?}", e),
+            Err(e) => warn!("Could not move {} to '{}': {}", issue_key, status, e),
         }
-        Err(format!(
-            "an error occurred while adding time to issue: {:?}",
-            issue_key
-        )
-        .into())
     }
 
     pub async fn delete_worklog(&self, issue_key: &str, worklog_id: &str) {
-        let jira_client = JiraClient::create();
+        let jira_client = self.context.jira_client();
         debug!(
             "deleting worklog '{}' for issue '{}' (KEY)",
             worklog_id, issue_key
         );
         match jira_client.delete_worklog(issue_key, worklog_id).await {
             Ok(()) => {
-                WorklogsService::production().remove_worklog(worklog_id);
+                WorklogsService::new(&self.context).remove_worklog(worklog_id);
                 debug!(
                     "worklog '{}' deleted from Jira and local database",
                     worklog_id
@@ -92,9 +195,7 @@ impl IssueService {
             Err(e) => {
                 let should_remove_local = match &e {
                     JiraError::ApiError(msg) => {
-                        // Parse the leading status code (e.g. "404 Not Found - ...")
-                        let status_code = msg.split(' ').next().and_then(|s| s.parse::<u16>().ok());
-                        match status_code {
+                        match e.status() {
                             // Worklog no longer exists or request is malformed — remove locally
                             Some(404) | Some(400) => true,
                             // Auth/permission errors — keep locally, user can fix credentials
@@ -127,7 +228,7 @@ impl IssueService {
                         "worklog '{}' not found or invalid in Jira ({}), removing from local database",
                         worklog_id, e
                     );
-                    WorklogsService::production().remove_worklog(worklog_id);
+                    WorklogsService::new(&self.context).remove_worklog(worklog_id);
                 }
             }
         }
@@ -137,150 +238,139 @@ impl IssueService {
 // --- BoardService ---
 
 pub struct BoardService {
-    boards_db: GenericDatabase<Board>,
+    boards_db: SplitDatabase<Board>,
 }
 
 impl BoardService {
-    pub fn new(boards_db: GenericDatabase<Board>) -> Self {
+    pub fn new(context: &Context) -> Self {
+        let boards_db = SplitDatabase::new(context.database(), "boards", "boards_user_state")
+            .expect("could not initialize board database");
         Self { boards_db }
     }
 
     pub fn production() -> Self {
-        let boards_db =
-            GenericDatabase::new(&DATABASE, "boards").expect("could not initialize board database");
-        Self::new(boards_db)
+        Self::new(&Context::production())
     }
 
     pub fn save_board(&self, board: &Board) {
-        self.boards_db.insert(board).unwrap();
+        or_log(self.boards_db.insert(board), "save a board");
     }
 
     pub fn get_all_boards(&self) -> Vec<Board> {
-        self.boards_db.get_all().unwrap()
+        or_log(self.boards_db.get_all(), "load boards")
     }
 
     pub fn get_by_id(&self, id: &str) -> Option<Board> {
-        self.boards_db.get(id).unwrap()
+        or_log(self.boards_db.get(id), "load a board")
     }
 }
 
 // --- SprintService ---
 
 pub struct SprintService {
-    sprints_db: GenericDatabase<Sprint>,
+    sprints_db: SplitDatabase<Sprint>,
 }
 
 impl SprintService {
-    pub fn new(sprints_db: GenericDatabase<Sprint>) -> Self {
+    pub fn new(context: &Context) -> Self {
+        let sprints_db = SplitDatabase::new(context.database(), "sprints", "sprints_user_state")
+            .expect("could not initialize sprint database");
         Self { sprints_db }
     }
 
     pub fn production() -> Self {
-        let sprints_db = GenericDatabase::new(&DATABASE, "sprints")
-            .expect("could not initialize sprint database");
-        Self::new(sprints_db)
+        Self::new(&Context::production())
     }
 
-    pub fn get_sprint(
-        &self,
-        sprint_id: &str,
-    ) -> Result<Option<Sprint>, Box<dyn Error + Send + Sync>> {
+    pub fn get_sprint(&self, sprint_id: &str) -> StorageResult<Option<Sprint>> {
         self.sprints_db.get(sprint_id)
     }
 
     pub fn get_sprint_by_id(&self, id: &str) -> Option<Sprint> {
-        self.sprints_db.get(id).unwrap()
+        or_log(self.sprints_db.get(id), "load a sprint")
     }
 
     pub fn save_sprint(&self, sprint: &Sprint) {
-        self.sprints_db.insert(sprint).unwrap();
+        or_log(self.sprints_db.insert(sprint), "save a sprint");
     }
 
     pub fn save_all_sprints(&self, sprints: Vec<Sprint>) {
-        self.sprints_db.save_all(sprints).unwrap();
+        or_log(self.sprints_db.save_all(sprints), "save sprints");
     }
 }
 
 // --- JiraService (facade over all three DBs + Jira API) ---
 
 pub struct JiraService {
+    context: Context,
     issues_db: GenericDatabase<Issue>,
-    boards_db: GenericDatabase<Board>,
-    sprints_db: GenericDatabase<Sprint>,
+    boards_db: SplitDatabase<Board>,
+    sprints_db: SplitDatabase<Sprint>,
 }
 
 impl JiraService {
-    pub fn new(
-        issues_db: GenericDatabase<Issue>,
-        boards_db: GenericDatabase<Board>,
-        sprints_db: GenericDatabase<Sprint>,
-    ) -> Self {
+    pub fn new(context: &Context) -> Self {
+        let database = context.database();
         Self {
-            issues_db,
-            boards_db,
-            sprints_db,
+            context: context.clone(),
+            issues_db: GenericDatabase::new(database, "issues")
+                .expect("could not initialize issues database"),
+            boards_db: SplitDatabase::new(database, "boards", "boards_user_state")
+                .expect("could not initialize board database"),
+            sprints_db: SplitDatabase::new(database, "sprints", "sprints_user_state")
+                .expect("could not initialize sprint database"),
         }
     }
 
     pub fn production() -> Self {
-        let issues_db = GenericDatabase::new(&DATABASE, "issues")
-            .expect("could not initialize issues database");
-        let boards_db =
-            GenericDatabase::new(&DATABASE, "boards").expect("could not initialize board database");
-        let sprints_db = GenericDatabase::new(&DATABASE, "sprints")
-            .expect("could not initialize sprint database");
-        Self::new(issues_db, boards_db, sprints_db)
+        Self::new(&Context::production())
     }
 
     pub fn get_available_sprints(&self) -> Vec<Sprint> {
-        self.sprints_db.get_all().unwrap()
+        or_log(self.sprints_db.get_all(), "load sprints")
     }
 
     pub fn get_followed_sprint(&self) -> Vec<Sprint> {
-        self.sprints_db
-            .get_all()
-            .unwrap()
+        or_log(self.sprints_db.get_all(), "load sprints")
             .iter()
             .filter(|s| s.followed)
             .cloned()
             .collect()
     }
 
-    pub fn follow_sprint(&self, sprint_id: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    pub fn follow_sprint(&self, sprint_id: &str) -> WtfResult<()> {
         match self.sprints_db.get(sprint_id)? {
             Some(mut sprint) => {
                 if !sprint.followed {
                     sprint.followed = true;
-                    self.sprints_db.insert(&sprint).unwrap();
-                    Ok(())
+                    Ok(self.sprints_db.insert(&sprint)?)
                 } else {
-                    Err("Sprint already followed")?
+                    Err(WtfError::Invalid("Sprint already followed".to_string()))
                 }
             }
-            None => Err("Sprint not found")?,
+            None => Err(WtfError::not_found("Sprint", sprint_id)),
         }
     }
 
-    pub fn unfollow_sprint(&self, sprint_id: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    pub fn unfollow_sprint(&self, sprint_id: &str) -> WtfResult<()> {
         match self.sprints_db.get(sprint_id)? {
             Some(mut sprint) => {
                 if sprint.followed {
                     sprint.followed = false;
-                    self.sprints_db.insert(&sprint).unwrap();
-                    Ok(())
+                    Ok(self.sprints_db.insert(&sprint)?)
                 } else {
-                    Err("Sprint not followed")?
+                    Err(WtfError::Invalid("Sprint not followed".to_string()))
                 }
             }
-            None => Err("Sprint not found")?,
+            None => Err(WtfError::not_found("Sprint", sprint_id)),
         }
     }
 
-    pub fn get_available_boards(&self) -> Result<Vec<Board>, Box<dyn Error + Send + Sync>> {
-        self.boards_db.get_all()
+    pub fn get_available_boards(&self) -> WtfResult<Vec<Board>> {
+        Ok(self.boards_db.get_all()?)
     }
 
-    pub fn get_followed_boards(&self) -> Result<Vec<Board>, Box<dyn Error + Send + Sync>> {
+    pub fn get_followed_boards(&self) -> WtfResult<Vec<Board>> {
         Ok(self
             .boards_db
             .get_all()?
@@ -290,23 +380,23 @@ impl JiraService {
             .collect())
     }
 
-    pub fn follow_board(&self, board_id: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    pub fn follow_board(&self, board_id: &str) -> WtfResult<()> {
         match self.boards_db.get(board_id)? {
             Some(mut db_board) => {
                 db_board.followed = true;
-                self.boards_db.insert(&db_board)
+                Ok(self.boards_db.insert(&db_board)?)
             }
-            None => Err(format!("Board '{}' not found", board_id))?,
+            None => Err(WtfError::not_found("Board", board_id)),
         }
     }
 
-    pub fn unfollow_board(&self, board_id: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    pub fn unfollow_board(&self, board_id: &str) -> WtfResult<()> {
         match self.boards_db.get(board_id)? {
             Some(mut db_board) => {
                 db_board.followed = false;
-                self.boards_db.insert(&db_board)
+                Ok(self.boards_db.insert(&db_board)?)
             }
-            None => Err(format!("Board '{}' not found", board_id))?,
+            None => Err(WtfError::not_found("Board", board_id)),
         }
     }
 
@@ -323,9 +413,9 @@ impl JiraService {
             Some(issue) => Some(issue),
             None => {
                 debug!("no issue found in database, checking remotely...");
-                if let Ok(issue) = JiraClient::create().get_issue(key).await {
+                if let Ok(issue) = self.context.jira_client().get_issue(key).await {
                     let issue_to_store = issue.into();
-                    self.issues_db.insert(&issue_to_store).unwrap();
+                    or_log(self.issues_db.insert(&issue_to_store), "save an issue");
                     return Some(issue_to_store);
                 }
                 None
@@ -334,6 +424,15 @@ impl JiraService {
     }
 }
 
+/// The stored value, or the default after logging the failure: a cache that can't be read
+/// or written shouldn't bring the whole app down
+fn or_log<T: Default>(result: StorageResult<T>, action: &str) -> T {
+    result.unwrap_or_else(|e| {
+        error!("Failed to {}: {}", action, e);
+        T::default()
+    })
+}
+
 pub fn has_jira_identifier(s: &str) -> bool {
     JIRA_CARD_IDENTIFIER.is_match(s)
 }
@@ -356,6 +455,39 @@ pub fn get_jira_identifiers(s: &str) -> Vec<String> {
 mod tests {
     use super::*;
 
+    fn issue(key: &str, summary: &str, sprint_ids: Vec<usize>) -> Issue {
+        Issue {
+            id: key.to_string(),
+            key: key.to_string(),
+            summary: summary.to_string(),
+            status: "To Do".to_string(),
+            created: Utc::now(),
+            sprint_ids,
+            epic: None,
+            components: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
+        }
+    }
+
+    #[test]
+    fn test_saving_issues_keeps_sprint_membership() {
+        let svc = IssueService::new(&Context::temporary());
+
+        svc.save_all_issues(vec![
+            issue("PROJ-1", "old title", vec![7]),
+            issue("PROJ-1", "old title", vec![]), // same issue from the project fetch
+        ]);
+        assert_eq!(svc.get_by_key("PROJ-1").unwrap().sprint_ids, vec![7]);
+
+        svc.save_all_issues(vec![issue("PROJ-1", "new title", vec![8])]);
+        svc.save_issue(&issue("PROJ-1", "renamed", vec![]));
+
+        let stored = svc.get_by_key("PROJ-1").unwrap();
+        assert_eq!(stored.summary, "renamed");
+        assert_eq!(stored.sprint_ids, vec![7, 8]);
+    }
+
     #[test]
     fn test_jira_identifier_detection() {
         assert!(has_jira_identifier("etech-123"));
@@ -365,12 +497,9 @@ mod tests {
         assert!(has_jira_identifier("the card is etech-123 use it wisely"));
         assert!(has_jira_identifier("plw-1"));
 
-        assert_eq!(has_jira_identifier("etech123"), false);
-        assert_eq!(
-            has_jira_identifier("etech is under - rated with 123"),
-            false
-        );
-        assert_eq!(has_jira_identifier("nothing the see here"), false);
+        assert!(!has_jira_identifier("etech123"));
+        assert!(!has_jira_identifier("etech is under - rated with 123"));
+        assert!(!has_jira_identifier("nothing the see here"));
     }
 
     #[test]
@@ -394,4 +523,25 @@ mod tests {
         assert!(candidates.contains(&"ETECH-123".to_string()));
         assert!(candidates.contains(&"PLW-14".to_string()));
     }
+
+    #[test]
+    fn test_transition_towards_matches_the_target_status() {
+        let transition = |id: &str, name: &str, to: &str| JiraTransition {
+            id: id.to_string(),
+            name: name.to_string(),
+            to: crate::models::jira::JiraStatus {
+                name: to.to_string(),
+            },
+        };
+        let transitions = vec![
+            transition("11", "Start", "In Progress"),
+            transition("31", "Done", "Done"),
+        ];
+
+        assert_eq!(
+            transition_towards(&transitions, "in progress").map(|t| t.id.as_str()),
+            Some("11")
+        );
+        assert_eq!(transition_towards(&transitions, "Review"), None);
+    }
 }
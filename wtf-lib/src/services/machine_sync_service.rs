@@ -0,0 +1,454 @@
+use crate::context::Context;
+use crate::models::data::{LocalWorklog, LocalWorklogHistory};
+use crate::services::audit_service::AuditService;
+use crate::services::machine_sync_snapshot::{
+    hash, read_snapshots, write_snapshot, Snapshot, SyncedRecord, SNAPSHOT_VERSION,
+};
+use crate::services::meetings_service::MeetingsService;
+use crate::services::worklogs_service::LocalWorklogService;
+use crate::storage::database::{GenericDatabase, Identifiable};
+use chrono::{DateTime, Utc};
+use log::{debug, error};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::Path;
+
+const WORKLOG: &str = "worklog/";
+const HISTORY: &str = "history/";
+const LINK: &str = "link/";
+
+/// A record as of the last sync, telling the local changes made since
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct SyncBase {
+    key: String,
+    hash: String,
+    changed_at: DateTime<Utc>,
+}
+
+impl Identifiable for SyncBase {
+    fn get_id(&self) -> String {
+        self.key.clone()
+    }
+}
+
+/// What [`MachineSyncService::sync`] did
+#[derive(Debug, Default)]
+pub struct SyncReport {
+    /// The other machines whose snapshots were read
+    pub machines: Vec<String>,
+    /// Local changes since the last sync, now shared
+    pub sent: usize,
+    /// Changes from the other machines applied here
+    pub received: usize,
+    /// Records changed on both sides, and whose version was kept
+    pub conflicts: Vec<String>,
+    /// Links of meetings not fetched on this machine yet, applied by a later sync
+    pub pending_links: usize,
+}
+
+/// Keeps the local worklogs and meeting links of several machines in step through a
+/// shared folder. Each machine writes everything it knows to its own file and merges the
+/// others' files: the most recent change of a record wins, except that a pushed worklog
+/// always wins over an unpushed version of it, since it's the one in Jira.
+pub struct MachineSyncService {
+    context: Context,
+    base_db: GenericDatabase<SyncBase>,
+}
+
+impl MachineSyncService {
+    pub fn new(context: &Context) -> Self {
+        Self {
+            context: context.clone(),
+            base_db: GenericDatabase::new(context.database(), "machine_sync")
+                .expect("could not initialize machine sync database"),
+        }
+    }
+
+    /// Create a service on the active profile's database, whichever backend it uses.
+    pub fn production() -> Self {
+        Self::new(&Context::production())
+    }
+
+    /// Merge the snapshots of the other machines found in `folder`, then write this
+    /// machine's
+    pub fn sync(&self, folder: &Path, machine: &str) -> Result<SyncReport, String> {
+        self.sync_at(folder, machine, Utc::now())
+    }
+
+    fn sync_at(
+        &self,
+        folder: &Path,
+        machine: &str,
+        now: DateTime<Utc>,
+    ) -> Result<SyncReport, String> {
+        fs::create_dir_all(folder)
+            .map_err(|e| format!("Can't create {}: {}", folder.display(), e))?;
+        let worklogs = LocalWorklogService::new(&self.context);
+        let meetings = MeetingsService::new(&self.context);
+        let mut report = SyncReport::default();
+
+        let base: HashMap<String, SyncBase> = self
+            .base_db
+            .get_all()
+            .map_err(|e| format!("Failed to load the sync state: {}", e))?
+            .into_iter()
+            .map(|base| (base.key.clone(), base))
+            .collect();
+        let current = self.current_records(&worklogs, &meetings);
+        let last_changes = self.last_changes();
+
+        // This machine's records, deleted ones included; a link whose meeting is gone
+        // (pruned, say) is unknown rather than unlinked
+        let keys: BTreeSet<&String> = current
+            .keys()
+            .chain(base.keys().filter(|key| !key.starts_with(LINK)))
+            .collect();
+        let mut local_changes = BTreeSet::new();
+        let mut merged: BTreeMap<String, SyncedRecord> = BTreeMap::new();
+        for key in keys {
+            let value = current.get(key).cloned().flatten();
+            let hash = hash(&value);
+            let changed_at = match base.get(key) {
+                Some(base) if base.hash == hash => base.changed_at,
+                base => {
+                    local_changes.insert(key.clone());
+                    last_changes
+                        .get(&audit_target(key))
+                        .copied()
+                        .filter(|at| base.is_none_or(|base| *at > base.changed_at))
+                        .unwrap_or(now)
+                }
+            };
+            merged.insert(key.clone(), SyncedRecord { value, changed_at });
+        }
+        report.sent = local_changes.len();
+
+        for snapshot in read_snapshots(folder, machine)? {
+            report.machines.push(snapshot.machine.clone());
+            for (key, record) in snapshot.records {
+                let Some(kept) = merged.get(&key) else {
+                    merged.insert(key, record);
+                    continue;
+                };
+                if hash(&kept.value) == hash(&record.value) || !prefer(&record, kept) {
+                    if local_changes.contains(&key) && hash(&kept.value) != hash(&record.value) {
+                        report
+                            .conflicts
+                            .push(format!("{}: kept this machine's version", key));
+                    }
+                    continue;
+                }
+                if local_changes.contains(&key) {
+                    report
+                        .conflicts
+                        .push(format!("{}: kept {}'s version", key, snapshot.machine));
+                }
+                merged.insert(key, record);
+            }
+        }
+
+        let mut bases = Vec::new();
+        for (key, record) in &merged {
+            let local = current.get(key).cloned().flatten();
+            if hash(&local) != hash(&record.value) || !current.contains_key(key) {
+                match apply(key, &record.value, &worklogs, &meetings) {
+                    Applied::Yes => report.received += 1,
+                    Applied::Unchanged => {}
+                    Applied::Pending => {
+                        report.pending_links += 1;
+                        continue;
+                    }
+                }
+            }
+            bases.push(SyncBase {
+                key: key.clone(),
+                hash: hash(&record.value),
+                changed_at: record.changed_at,
+            });
+        }
+        self.base_db
+            .save_all(bases)
+            .map_err(|e| format!("Failed to save the sync state: {}", e))?;
+
+        write_snapshot(
+            folder,
+            &Snapshot {
+                version: SNAPSHOT_VERSION,
+                machine: machine.to_string(),
+                written_at: now,
+                records: merged,
+            },
+        )?;
+        Ok(report)
+    }
+
+    /// The records to share, as they are now; meetings without a link are listed with
+    /// `None`, to tell them from meetings this machine doesn't have
+    fn current_records(
+        &self,
+        worklogs: &LocalWorklogService,
+        meetings: &MeetingsService,
+    ) -> BTreeMap<String, Option<Value>> {
+        let mut records = BTreeMap::new();
+        for worklog in worklogs.get_all_local_worklogs() {
+            records.insert(format!("{}{}", WORKLOG, worklog.id), to_value(&worklog));
+        }
+        for history in worklogs.get_history() {
+            records.insert(format!("{}{}", HISTORY, history.id), to_value(&history));
+        }
+        for meeting in meetings.get_all_meetings() {
+            records.insert(
+                format!("{}{}", LINK, meeting.id),
+                meeting.jira_link.map(Value::String),
+            );
+        }
+        records
+    }
+
+    /// When each worklog and meeting was last changed here, from the audit log
+    fn last_changes(&self) -> HashMap<String, DateTime<Utc>> {
+        let mut changes = HashMap::new();
+        for entry in AuditService::new(&self.context).entries() {
+            changes.insert(entry.target, entry.at);
+        }
+        changes
+    }
+}
+
+enum Applied {
+    Yes,
+    Unchanged,
+    /// The meeting isn't on this machine
+    Pending,
+}
+
+fn apply(
+    key: &str,
+    value: &Option<Value>,
+    worklogs: &LocalWorklogService,
+    meetings: &MeetingsService,
+) -> Applied {
+    if let Some(id) = key.strip_prefix(WORKLOG) {
+        match value {
+            Some(value) => match serde_json::from_value::<LocalWorklog>(value.clone()) {
+                Ok(worklog) => worklogs.save_local_worklog(worklog),
+                Err(e) => {
+                    error!("Invalid worklog '{}' in a sync snapshot: {}", id, e);
+                    return Applied::Unchanged;
+                }
+            },
+            None => match worklogs.get_local_worklog_by_id(id) {
+                Some(worklog) => worklogs.remove_local_worklog(&worklog),
+                None => return Applied::Unchanged,
+            },
+        }
+    } else if let Some(id) = key.strip_prefix(HISTORY) {
+        match value {
+            Some(value) => match serde_json::from_value::<LocalWorklogHistory>(value.clone()) {
+                Ok(history) => worklogs.save_history(&history),
+                Err(e) => {
+                    error!("Invalid worklog history '{}' in a sync snapshot: {}", id, e);
+                    return Applied::Unchanged;
+                }
+            },
+            None => {
+                if worklogs.get_history_by_id(id).is_none() {
+                    return Applied::Unchanged;
+                }
+                if let Err(e) = worklogs.delete_history_from_db(id) {
+                    error!("{}", e);
+                }
+            }
+        }
+    } else if let Some(id) = key.strip_prefix(LINK) {
+        let Some(mut meeting) = meetings.get_meeting_by_id(id.to_string()) else {
+            return Applied::Pending;
+        };
+        meeting.jira_link = value.as_ref().and_then(Value::as_str).map(str::to_string);
+        meetings.save(&meeting);
+    } else {
+        debug!("Unknown record '{}' in a sync snapshot", key);
+        return Applied::Unchanged;
+    }
+    Applied::Yes
+}
+
+/// Whether `candidate` should replace `kept`: a pushed worklog is the one in Jira, so it
+/// wins over any other version still around; otherwise the most recent change wins
+fn prefer(candidate: &SyncedRecord, kept: &SyncedRecord) -> bool {
+    match (is_pushed(candidate), is_pushed(kept)) {
+        (true, false) if kept.value.is_some() => true,
+        (false, true) if candidate.value.is_some() => false,
+        _ => candidate.changed_at > kept.changed_at,
+    }
+}
+
+fn is_pushed(record: &SyncedRecord) -> bool {
+    record
+        .value
+        .as_ref()
+        .and_then(|value| value.get("status"))
+        .and_then(Value::as_str)
+        == Some("Pushed")
+}
+
+/// The audit log target of a record
+fn audit_target(key: &str) -> String {
+    if let Some(id) = key.strip_prefix(WORKLOG) {
+        format!("worklog {}", id)
+    } else if let Some(id) = key.strip_prefix(LINK) {
+        format!("meeting {}", id)
+    } else {
+        key.to_string()
+    }
+}
+
+fn to_value<T: Serialize>(item: &T) -> Option<Value> {
+    serde_json::to_value(item).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::data::{LocalWorklogState, Meeting};
+    use chrono::{Duration, TimeZone};
+    use std::path::PathBuf;
+
+    fn temp_folder(test: &str) -> PathBuf {
+        let folder = std::env::temp_dir().join(format!("wtf-sync-{}-{}", std::process::id(), test));
+        let _ = fs::remove_dir_all(&folder);
+        folder
+    }
+
+    fn worklog(id: &str, seconds: i64, status: LocalWorklogState) -> LocalWorklog {
+        LocalWorklog {
+            id: id.to_string(),
+            comment: String::new(),
+            time_spent_seconds: seconds,
+            issue_id: "PROJ-1".to_string(),
+            status,
+            started: Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap(),
+            meeting_id: None,
+            worklog_id: None,
+        }
+    }
+
+    fn meeting(id: &str) -> Meeting {
+        let start = Utc.with_ymd_and_hms(2026, 3, 2, 10, 0, 0).unwrap();
+        Meeting {
+            id: id.to_string(),
+            title: Some("Daily".to_string()),
+            description: None,
+            start,
+            end: start + Duration::minutes(15),
+            attendees: None,
+            jira_link: None,
+            recurrence: None,
+            logs: HashMap::new(),
+            my_response_status: None,
+            color_id: None,
+            organizer: None,
+            conference_link: None,
+            calendar: None,
+            recurring_event_id: None,
+            event_type: None,
+        }
+    }
+
+    #[test]
+    fn test_two_machines_converge() {
+        let folder = temp_folder("converge");
+        let (desktop, laptop) = (Context::temporary(), Context::temporary());
+        let t0 = Utc.with_ymd_and_hms(2026, 3, 2, 18, 0, 0).unwrap();
+        let sync = |context: &Context, machine: &str, at| {
+            MachineSyncService::new(context)
+                .sync_at(&folder, machine, at)
+                .unwrap()
+        };
+
+        LocalWorklogService::new(&desktop).save_local_worklog(worklog(
+            "a",
+            3600,
+            LocalWorklogState::Created,
+        ));
+        MeetingsService::new(&desktop).save(&meeting("daily"));
+        MeetingsService::new(&laptop).save(&meeting("daily"));
+        let mut linked = meeting("daily");
+        linked.jira_link = Some("PROJ-7".to_string());
+        MeetingsService::new(&laptop).save(&linked);
+
+        let report = sync(&desktop, "desktop", t0);
+        assert_eq!(report.sent, 2);
+        let report = sync(&laptop, "laptop", t0 + Duration::minutes(1));
+        assert_eq!((report.machines.len(), report.received), (1, 1));
+        let report = sync(&desktop, "desktop", t0 + Duration::minutes(2));
+        assert_eq!(report.received, 1);
+
+        let laptop_worklogs = LocalWorklogService::new(&laptop);
+        assert_eq!(laptop_worklogs.get_all_local_worklogs().len(), 1);
+        let desktop_meeting = MeetingsService::new(&desktop)
+            .get_meeting_by_id("daily".to_string())
+            .unwrap();
+        assert_eq!(desktop_meeting.jira_link.as_deref(), Some("PROJ-7"));
+
+        // Deleted on the laptop, deleted on the desktop
+        laptop_worklogs.remove_local_worklog(&worklog("a", 3600, LocalWorklogState::Created));
+        sync(&laptop, "laptop", t0 + Duration::minutes(3));
+        let report = sync(&desktop, "desktop", t0 + Duration::minutes(4));
+        assert_eq!(report.received, 1);
+        assert!(LocalWorklogService::new(&desktop)
+            .get_all_local_worklogs()
+            .is_empty());
+        let _ = fs::remove_dir_all(&folder);
+    }
+
+    #[test]
+    fn test_pushed_worklog_wins_a_conflict() {
+        let folder = temp_folder("conflict");
+        let (desktop, laptop) = (Context::temporary(), Context::temporary());
+        let t0 = Utc.with_ymd_and_hms(2026, 3, 2, 18, 0, 0).unwrap();
+        for context in [&desktop, &laptop] {
+            LocalWorklogService::new(context).save_local_worklog(worklog(
+                "a",
+                3600,
+                LocalWorklogState::Created,
+            ));
+        }
+        MachineSyncService::new(&desktop)
+            .sync_at(&folder, "desktop", t0)
+            .unwrap();
+        MachineSyncService::new(&laptop)
+            .sync_at(&folder, "laptop", t0)
+            .unwrap();
+
+        // Pushed from the desktop, edited later on the laptop
+        LocalWorklogService::new(&desktop).save_local_worklog(worklog(
+            "a",
+            3600,
+            LocalWorklogState::Pushed,
+        ));
+        MachineSyncService::new(&desktop)
+            .sync_at(&folder, "desktop", t0 + Duration::hours(1))
+            .unwrap();
+        LocalWorklogService::new(&laptop).save_local_worklog(worklog(
+            "a",
+            7200,
+            LocalWorklogState::Created,
+        ));
+        let report = MachineSyncService::new(&laptop)
+            .sync_at(&folder, "laptop", t0 + Duration::hours(2))
+            .unwrap();
+
+        assert_eq!(report.conflicts, vec!["worklog/a: kept desktop's version"]);
+        let kept = LocalWorklogService::new(&laptop)
+            .get_local_worklog_by_id("a")
+            .unwrap();
+        assert_eq!(
+            (kept.status, kept.time_spent_seconds),
+            (LocalWorklogState::Pushed, 3600)
+        );
+        let _ = fs::remove_dir_all(&folder);
+    }
+}
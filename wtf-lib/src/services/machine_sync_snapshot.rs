@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Format version of the snapshot files written by this build
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// A record as a machine knows it; `value` is `None` once deleted, or unlinked for a
+/// meeting link
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SyncedRecord {
+    pub value: Option<Value>,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// What a machine writes to the sync folder, as `<machine>.json`: the local worklogs,
+/// their push history and the meeting links, keyed `worklog/<id>`, `history/<id>` and
+/// `link/<meeting id>`
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Snapshot {
+    pub version: u32,
+    pub machine: String,
+    pub written_at: DateTime<Utc>,
+    pub records: BTreeMap<String, SyncedRecord>,
+}
+
+/// Fingerprint of a record's value, to tell whether it changed since the last sync
+pub(crate) fn hash(value: &Option<Value>) -> String {
+    let json = match value {
+        Some(value) => value.to_string(),
+        None => "null".to_string(),
+    };
+    format!("{:x}", md5::compute(json))
+}
+
+/// The snapshots of every machine but `machine`, refusing files from a newer format
+pub(crate) fn read_snapshots(folder: &Path, machine: &str) -> Result<Vec<Snapshot>, String> {
+    let entries =
+        fs::read_dir(folder).map_err(|e| format!("Can't read {}: {}", folder.display(), e))?;
+    let mut snapshots = Vec::new();
+    for path in entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+    {
+        let is_other_machine = path.extension().is_some_and(|ext| ext == "json")
+            && path.file_stem().is_some_and(|stem| stem != machine);
+        if !is_other_machine {
+            continue;
+        }
+        let snapshot: Snapshot = fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|json| serde_json::from_str(&json).map_err(|e| e.to_string()))
+            .map_err(|e| format!("{} is not a wtf sync snapshot: {}", path.display(), e))?;
+        if snapshot.version > SNAPSHOT_VERSION {
+            return Err(format!(
+                "{} was written by a newer version of wtf (format v{}), upgrade wtf here too",
+                path.display(),
+                snapshot.version
+            ));
+        }
+        snapshots.push(snapshot);
+    }
+    snapshots.sort_by(|a, b| a.machine.cmp(&b.machine));
+    Ok(snapshots)
+}
+
+/// Written next to its final name then renamed, so the folder's sync never ships half
+/// a file
+pub(crate) fn write_snapshot(folder: &Path, snapshot: &Snapshot) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(snapshot).map_err(|e| e.to_string())?;
+    let path = folder.join(format!("{}.json", snapshot.machine));
+    let partial = folder.join(format!(".{}.json.partial", snapshot.machine));
+    fs::write(&partial, json)
+        .and_then(|_| fs::rename(&partial, &path))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
@@ -1,6 +1,9 @@
-use crate::models::data::{Absence, Meeting, Sprint, SprintState};
+use crate::context::Context;
+use crate::models::data::{Absence, AuditAction, Meeting, Sprint, SprintState};
+use crate::services::audit_service::AuditService;
 use crate::services::jira_service::{JiraService, SprintService};
-use crate::storage::database::{GenericDatabase, DATABASE};
+use crate::storage::database::GenericDatabase;
+use crate::utils::period::Period;
 use chrono::{DateTime, NaiveDate, Utc};
 use log::{error, warn};
 
@@ -8,7 +11,7 @@ use log::{error, warn};
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
 pub(crate) struct UntrackedMeeting {
-    meeting_id: String,
+    pub(crate) meeting_id: String,
 }
 
 impl crate::storage::database::Identifiable for UntrackedMeeting {
@@ -17,36 +20,69 @@ impl crate::storage::database::Identifiable for UntrackedMeeting {
     }
 }
 
+// --- SeriesLink (private) ---
+
+/// The issue every meeting of a recurring series goes to, the ones fetched later included
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub(crate) struct SeriesLink {
+    pub(crate) recurring_event_id: String,
+    pub(crate) issue: String,
+}
+
+impl crate::storage::database::Identifiable for SeriesLink {
+    fn get_id(&self) -> String {
+        self.recurring_event_id.clone()
+    }
+}
+
+// --- DismissedAbsence (private) ---
+
+/// An out-of-office event I chose not to record as an absence, not offered again
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub(crate) struct DismissedAbsence {
+    pub(crate) event_id: String,
+}
+
+impl crate::storage::database::Identifiable for DismissedAbsence {
+    fn get_id(&self) -> String {
+        self.event_id.clone()
+    }
+}
+
 // --- MeetingsService ---
 
 pub struct MeetingsService {
+    context: Context,
     meetings_db: GenericDatabase<Meeting>,
     untracked_db: GenericDatabase<UntrackedMeeting>,
+    series_db: GenericDatabase<SeriesLink>,
     absences_db: GenericDatabase<Absence>,
+    dismissed_absences_db: GenericDatabase<DismissedAbsence>,
+    audit: AuditService,
 }
 
 impl MeetingsService {
-    pub(crate) fn new(
-        meetings_db: GenericDatabase<Meeting>,
-        untracked_db: GenericDatabase<UntrackedMeeting>,
-        absences_db: GenericDatabase<Absence>,
-    ) -> Self {
+    pub fn new(context: &Context) -> Self {
+        let database = context.database();
         Self {
-            meetings_db,
-            untracked_db,
-            absences_db,
+            context: context.clone(),
+            meetings_db: GenericDatabase::new(database, "meetings")
+                .expect("could not initialize meeting database"),
+            untracked_db: GenericDatabase::new(database, "untracked_meetings")
+                .expect("could not initialize untracked_meetings database"),
+            series_db: GenericDatabase::new(database, "meeting_series_links")
+                .expect("could not initialize meeting_series_links database"),
+            absences_db: GenericDatabase::new(database, "absences")
+                .expect("could not initialize absence database"),
+            dismissed_absences_db: GenericDatabase::new(database, "dismissed_absences")
+                .expect("could not initialize dismissed_absences database"),
+            audit: AuditService::new(context),
         }
     }
 
     /// Create a service backed by the production sled database.
     pub fn production() -> Self {
-        let meetings_db = GenericDatabase::new(&DATABASE, "meetings")
-            .expect("could not initialize meeting database");
-        let untracked_db = GenericDatabase::new(&DATABASE, "untracked_meetings")
-            .expect("could not initialize untracked_meetings database");
-        let absences_db = GenericDatabase::new(&DATABASE, "absences")
-            .expect("could not initialize absence database");
-        Self::new(meetings_db, untracked_db, absences_db)
+        Self::new(&Context::production())
     }
 
     pub fn clear_all_meetings(&self) {
@@ -69,7 +105,7 @@ impl MeetingsService {
             return all_meetings;
         }
 
-        if let Some(sprint) = JiraService::production()
+        if let Some(sprint) = JiraService::new(&self.context)
             .get_followed_sprint()
             .iter()
             .find(|s| matches!(s.state, SprintState::Active))
@@ -87,7 +123,7 @@ impl MeetingsService {
     }
 
     pub fn get_meetings_for_sprint_id(&self, spring_id: &str) -> Vec<Meeting> {
-        match SprintService::production().get_sprint(spring_id) {
+        match SprintService::new(&self.context).get_sprint(spring_id) {
             Ok(Some(sprint)) => self.get_meetings_for_sprint(&sprint),
             _ => {
                 eprintln!("Sprint '{}' not found!", spring_id);
@@ -105,6 +141,15 @@ impl MeetingsService {
             .collect()
     }
 
+    pub fn get_meetings_for_period(&self, period: &Period) -> Vec<Meeting> {
+        self.meetings_db
+            .get_all()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|m| period.contains_meeting(m))
+            .collect()
+    }
+
     pub fn get_meetings_between_dates(
         &self,
         start: DateTime<Utc>,
@@ -150,10 +195,55 @@ impl MeetingsService {
         }
     }
 
+    /// Whether the calendar event was already recorded as an absence, or dismissed
+    pub fn is_absence_known(&self, event_id: &str) -> bool {
+        self.absences_db.get(event_id).ok().flatten().is_some()
+            || self
+                .dismissed_absences_db
+                .get(event_id)
+                .ok()
+                .flatten()
+                .is_some()
+    }
+
+    /// Never offer the out-of-office event as an absence again
+    pub fn dismiss_absence(&self, event_id: &str) {
+        let record = DismissedAbsence {
+            event_id: event_id.to_string(),
+        };
+        if let Err(e) = self.dismissed_absences_db.insert(&record) {
+            error!("Failed to dismiss absence '{}': {}", event_id, e);
+        }
+    }
+
     pub fn save(&self, meeting: &Meeting) {
+        let previous_link = self
+            .meetings_db
+            .get(&meeting.id)
+            .ok()
+            .flatten()
+            .and_then(|previous| previous.jira_link);
         if let Err(e) = self.meetings_db.insert(meeting) {
             error!("Failed to save meeting '{}': {}", meeting.id, e);
+            return;
+        }
+        if previous_link == meeting.jira_link {
+            return;
         }
+        let title = meeting.title.as_deref().unwrap_or("Untitled");
+        let (action, details) = match (&previous_link, &meeting.jira_link) {
+            (_, None) => (
+                AuditAction::Unlink,
+                format!("'{}' from {}", title, previous_link.unwrap_or_default()),
+            ),
+            (None, Some(issue)) => (AuditAction::Link, format!("'{}' to {}", title, issue)),
+            (Some(from), Some(to)) => (
+                AuditAction::Link,
+                format!("'{}' to {} (was {})", title, to, from),
+            ),
+        };
+        self.audit
+            .record(action, format!("meeting {}", meeting.id), details);
     }
 
     pub fn get_meeting_by_id(&self, id: String) -> Option<Meeting> {
@@ -181,6 +271,68 @@ impl MeetingsService {
             .collect()
     }
 
+    /// Link every stored meeting of a recurring series to `issue`, and the ones fetched
+    /// later too; the ones not attended (see `[meetings]`) are left alone. Returns the previous link of each changed meeting, for undo.
+    pub fn link_series(
+        &self,
+        recurring_event_id: &str,
+        issue: &str,
+    ) -> Vec<(String, Option<String>)> {
+        let record = SeriesLink {
+            recurring_event_id: recurring_event_id.to_string(),
+            issue: issue.to_string(),
+        };
+        if let Err(e) = self.series_db.insert(&record) {
+            error!(
+                "Failed to save the link of series '{}': {}",
+                recurring_event_id, e
+            );
+        }
+        let meetings_config = self.context.config().unwrap_or_default().meetings;
+        let mut changed = Vec::new();
+        for mut meeting in self.get_all_meetings() {
+            if meeting.recurring_event_id.as_deref() != Some(recurring_event_id)
+                || meeting.jira_link.as_deref() == Some(issue)
+                || !meetings_config.attends(&meeting)
+            {
+                continue;
+            }
+            let previous = meeting.jira_link.replace(issue.to_string());
+            self.save(&meeting);
+            changed.push((meeting.id, previous));
+        }
+        changed
+    }
+
+    /// The issue the whole series is linked to, if any
+    pub fn series_link(&self, recurring_event_id: &str) -> Option<String> {
+        match self.series_db.get(recurring_event_id) {
+            Ok(record) => record.map(|r| r.issue),
+            Err(e) => {
+                error!(
+                    "Failed to load the link of series '{}': {}",
+                    recurring_event_id, e
+                );
+                None
+            }
+        }
+    }
+
+    /// Stop linking the meetings of the series fetched from now on; the stored ones keep
+    /// their link. Returns whether the series was linked.
+    pub fn forget_series_link(&self, recurring_event_id: &str) -> bool {
+        let linked = self.series_link(recurring_event_id).is_some();
+        if linked {
+            if let Err(e) = self.series_db.remove(recurring_event_id) {
+                error!(
+                    "Failed to forget the link of series '{}': {}",
+                    recurring_event_id, e
+                );
+            }
+        }
+        linked
+    }
+
     /// Toggle a meeting's manually-untracked state.
     /// Returns `true` if it is now untracked, `false` if it was removed.
     pub fn toggle_untracked(&self, meeting_id: &str) -> bool {
@@ -210,16 +362,11 @@ impl AbsenceService {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::storage::database::{Database, GenericDatabase};
     use chrono::{Duration, TimeZone, Utc};
     use std::collections::HashMap;
 
     fn make_service() -> MeetingsService {
-        let db = Database::temporary();
-        let meetings_db = GenericDatabase::new(&db, "meetings").unwrap();
-        let untracked_db = GenericDatabase::new(&db, "untracked_meetings").unwrap();
-        let absences_db = GenericDatabase::new(&db, "absences").unwrap();
-        MeetingsService::new(meetings_db, untracked_db, absences_db)
+        MeetingsService::new(&Context::temporary())
     }
 
     fn make_meeting(id: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Meeting {
@@ -235,6 +382,11 @@ mod tests {
             logs: HashMap::new(),
             my_response_status: None,
             color_id: None,
+            organizer: None,
+            conference_link: None,
+            calendar: None,
+            recurring_event_id: None,
+            event_type: None,
         }
     }
 
@@ -290,6 +442,50 @@ mod tests {
         assert_eq!(results[0].id, "m1");
     }
 
+    #[test]
+    fn test_link_series_links_every_instance() {
+        let svc = make_service();
+        let monday = Utc.with_ymd_and_hms(2024, 1, 8, 9, 0, 0).unwrap();
+        let instance = |id: &str, week: i64, series: Option<&str>| Meeting {
+            recurring_event_id: series.map(str::to_string),
+            ..make_meeting(
+                id,
+                monday + Duration::weeks(week),
+                monday + Duration::weeks(week) + Duration::hours(1),
+            )
+        };
+        svc.save(&instance("planning_1", 0, Some("planning")));
+        svc.save(&Meeting {
+            jira_link: Some("OLD-1".to_string()),
+            ..instance("planning_2", 1, Some("planning"))
+        });
+        svc.save(&Meeting {
+            my_response_status: Some("declined".to_string()),
+            ..instance("planning_3", 2, Some("planning"))
+        });
+        svc.save(&instance("one-off", 0, None));
+
+        let mut changed = svc.link_series("planning", "PROJ-5");
+        changed.sort();
+        assert_eq!(
+            changed,
+            vec![
+                ("planning_1".to_string(), None),
+                ("planning_2".to_string(), Some("OLD-1".to_string())),
+            ]
+        );
+        let link = |id: &str| svc.get_meeting_by_id(id.to_string()).unwrap().jira_link;
+        assert_eq!(link("planning_2").as_deref(), Some("PROJ-5"));
+        assert_eq!(link("planning_3"), None);
+        assert_eq!(link("one-off"), None);
+        assert_eq!(svc.series_link("planning").as_deref(), Some("PROJ-5"));
+
+        assert!(svc.forget_series_link("planning"));
+        assert!(!svc.forget_series_link("planning"));
+        assert_eq!(svc.series_link("planning"), None);
+        assert_eq!(link("planning_1").as_deref(), Some("PROJ-5"));
+    }
+
     #[test]
     fn test_toggle_untracked() {
         let svc = make_service();
@@ -321,6 +517,23 @@ mod tests {
         assert!(!svc.is_absent(NaiveDate::from_ymd_opt(2024, 1, 18).unwrap()));
     }
 
+    #[test]
+    fn test_known_absences() {
+        let svc = make_service();
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        svc.save_absence(&Absence {
+            id: "ooo-1".to_string(),
+            start,
+            end: start + Duration::days(2),
+        });
+        svc.dismiss_absence("ooo-2");
+
+        assert!(svc.is_absence_known("ooo-1"));
+        assert!(svc.is_absence_known("ooo-2"));
+        assert!(!svc.is_absence_known("ooo-3"));
+        assert!(!svc.is_absent(start.date_naive() + Duration::days(10)));
+    }
+
     #[test]
     fn test_clear_all_meetings() {
         let svc = make_service();
@@ -1,9 +1,15 @@
 pub mod achievement_service;
+pub mod audit_service;
 pub mod github_service;
+pub mod github_session_edit_service;
 pub mod google_service;
 pub mod jira_service;
+pub mod machine_sync_service;
+pub mod machine_sync_snapshot;
 pub mod meetings_service;
+pub mod sync_status_service;
 pub mod tiered_achievement_service;
+pub mod ui_state_service;
 pub mod worklogs_service;
 
 pub use achievement_service::AchievementService;
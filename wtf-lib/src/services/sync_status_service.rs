@@ -0,0 +1,233 @@
+use crate::context::Context;
+use crate::models::data::{SyncRecord, SyncSource};
+use crate::models::jira::{JiraError, JiraUser};
+use crate::storage::database::GenericDatabase;
+use chrono::{DateTime, Utc};
+use log::{error, warn};
+
+/// Key of the last successful credential check, next to the sources' syncs
+const CREDENTIALS_KEY: &str = "jira_credentials";
+
+/// Outcome of the `myself` probe run before fetching
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CredentialStatus {
+    Valid,
+    /// 401: the API token is wrong, revoked or expired
+    Invalid,
+    /// 403: the token works but the account may not use the API
+    Forbidden,
+    /// Jira could not be reached or answered something else: says nothing about the token
+    Unreachable(String),
+}
+
+impl CredentialStatus {
+    pub fn from_result(result: &Result<JiraUser, JiraError>) -> Self {
+        match result {
+            Ok(_) => CredentialStatus::Valid,
+            Err(e) => match e.status() {
+                Some(401) => CredentialStatus::Invalid,
+                Some(403) => CredentialStatus::Forbidden,
+                _ => CredentialStatus::Unreachable(e.to_string()),
+            },
+        }
+    }
+
+    /// Whether the token itself was refused, as opposed to a network failure
+    pub fn is_rejected(&self) -> bool {
+        matches!(
+            self,
+            CredentialStatus::Invalid | CredentialStatus::Forbidden
+        )
+    }
+
+    /// What to tell the user when the token was refused
+    pub fn message(&self) -> Option<&'static str> {
+        match self {
+            CredentialStatus::Invalid => {
+                Some("Jira token invalid or expired (401), update jira.api_token")
+            }
+            CredentialStatus::Forbidden => {
+                Some("Jira token refused (403), check the account's API access")
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Remembers when each data source was last fetched, so the TUI and `wtf status` can tell
+/// how fresh the local data is
+pub struct SyncStatusService {
+    db: GenericDatabase<SyncRecord>,
+    /// Per-range watermarks of the incremental fetches, keyed by range instead of source
+    watermarks: GenericDatabase<SyncRecord>,
+}
+
+impl SyncStatusService {
+    pub fn new(context: &Context) -> Self {
+        let db = GenericDatabase::new(context.database(), "sync_status")
+            .expect("could not initialize sync status database");
+        let watermarks = GenericDatabase::new(context.database(), "sync_watermarks")
+            .expect("could not initialize sync watermarks database");
+        Self { db, watermarks }
+    }
+
+    /// Create a service backed by the production sled database.
+    pub fn production() -> Self {
+        Self::new(&Context::production())
+    }
+
+    /// Record a successful fetch of `source` now.
+    pub fn record(&self, source: SyncSource) {
+        self.record_at(source, Utc::now());
+    }
+
+    pub fn record_at(&self, source: SyncSource, synced_at: DateTime<Utc>) {
+        let record = SyncRecord {
+            source: source.key().to_string(),
+            synced_at,
+        };
+        if let Err(e) = self.db.insert(&record) {
+            error!("Failed to record sync of '{}': {}", record.source, e);
+        }
+    }
+
+    pub fn last_sync(&self, source: SyncSource) -> Option<DateTime<Utc>> {
+        match self.db.get(source.key()) {
+            Ok(record) => record.map(|r| r.synced_at),
+            Err(e) => {
+                error!("Failed to load sync status of '{}': {}", source.key(), e);
+                None
+            }
+        }
+    }
+
+    /// The least recent sync among the sources fetched at least once: how stale the local
+    /// data is as a whole
+    pub fn oldest_sync(&self) -> Option<DateTime<Utc>> {
+        SyncSource::ALL
+            .iter()
+            .filter_map(|source| self.last_sync(*source))
+            .min()
+    }
+
+    /// Ask Jira who the token belongs to, and remember when that last worked
+    pub async fn check_jira_credentials(&self, context: &Context) -> CredentialStatus {
+        let status = CredentialStatus::from_result(&context.jira_client().get_myself().await);
+        match &status {
+            CredentialStatus::Valid => self.record_credentials_verified(Utc::now()),
+            CredentialStatus::Unreachable(reason) => {
+                warn!("Could not verify the Jira credentials: {}", reason)
+            }
+            rejected => warn!("Jira credentials rejected: {:?}", rejected),
+        }
+        status
+    }
+
+    pub fn record_credentials_verified(&self, verified_at: DateTime<Utc>) {
+        let record = SyncRecord {
+            source: CREDENTIALS_KEY.to_string(),
+            synced_at: verified_at,
+        };
+        if let Err(e) = self.db.insert(&record) {
+            error!("Failed to record the credential check: {}", e);
+        }
+    }
+
+    /// When the Jira token was last accepted, `None` if it never was
+    pub fn credentials_verified(&self) -> Option<DateTime<Utc>> {
+        match self.db.get(CREDENTIALS_KEY) {
+            Ok(record) => record.map(|r| r.synced_at),
+            Err(e) => {
+                error!("Failed to load the credential check: {}", e);
+                None
+            }
+        }
+    }
+
+    /// When the data behind `key` (a sprint's worklogs, a board's issues...) was last
+    /// fetched, so that the next fetch only asks Jira for what changed since
+    pub fn watermark(&self, key: &str) -> Option<DateTime<Utc>> {
+        match self.watermarks.get(key) {
+            Ok(record) => record.map(|r| r.synced_at),
+            Err(e) => {
+                error!("Failed to load the watermark of '{}': {}", key, e);
+                None
+            }
+        }
+    }
+
+    pub fn record_watermark(&self, key: &str, fetched_at: DateTime<Utc>) {
+        let record = SyncRecord {
+            source: key.to_string(),
+            synced_at: fetched_at,
+        };
+        if let Err(e) = self.watermarks.insert(&record) {
+            error!("Failed to record the watermark of '{}': {}", key, e);
+        }
+    }
+
+    /// Forget every watermark: the next fetches download everything again
+    pub fn clear_watermarks(&self) {
+        if let Err(e) = self.watermarks.clear() {
+            error!("Failed to clear the sync watermarks: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_oldest_sync_ignores_sources_never_fetched() {
+        let svc = SyncStatusService::new(&Context::temporary());
+        assert_eq!(svc.oldest_sync(), None);
+
+        let morning = Utc.with_ymd_and_hms(2026, 1, 5, 8, 0, 0).unwrap();
+        let noon = Utc.with_ymd_and_hms(2026, 1, 5, 12, 0, 0).unwrap();
+        svc.record_at(SyncSource::Jira, morning);
+        svc.record_at(SyncSource::Google, noon);
+
+        assert_eq!(svc.last_sync(SyncSource::Google), Some(noon));
+        assert_eq!(svc.last_sync(SyncSource::GitHub), None);
+        assert_eq!(svc.oldest_sync(), Some(morning));
+
+        svc.record_at(SyncSource::Jira, noon);
+        assert_eq!(svc.oldest_sync(), Some(noon));
+    }
+
+    #[test]
+    fn test_watermarks_are_kept_apart_from_the_sources() {
+        let svc = SyncStatusService::new(&Context::temporary());
+        let fetched_at = Utc.with_ymd_and_hms(2026, 1, 5, 8, 0, 0).unwrap();
+        svc.record_watermark("worklogs:2026-01-05:2026-01-16", fetched_at);
+
+        assert_eq!(
+            svc.watermark("worklogs:2026-01-05:2026-01-16"),
+            Some(fetched_at)
+        );
+        assert_eq!(svc.watermark("worklogs:2026-01-19:2026-01-30"), None);
+        assert_eq!(svc.oldest_sync(), None);
+
+        svc.clear_watermarks();
+        assert_eq!(svc.watermark("worklogs:2026-01-05:2026-01-16"), None);
+    }
+
+    #[test]
+    fn test_credential_status_tells_rejection_from_network_failure() {
+        let status =
+            |msg: &str| CredentialStatus::from_result(&Err(JiraError::ApiError(msg.into())));
+        assert_eq!(status("401 Unauthorized"), CredentialStatus::Invalid);
+        assert_eq!(status("403 Forbidden"), CredentialStatus::Forbidden);
+        assert!(status("401 Unauthorized").is_rejected());
+        assert!(!status("error sending request for url").is_rejected());
+        assert!(!status("503 Service Unavailable").is_rejected());
+
+        let svc = SyncStatusService::new(&Context::temporary());
+        let verified_at = Utc.with_ymd_and_hms(2026, 1, 5, 8, 0, 0).unwrap();
+        svc.record_credentials_verified(verified_at);
+        assert_eq!(svc.credentials_verified(), Some(verified_at));
+        assert_eq!(svc.oldest_sync(), None);
+    }
+}
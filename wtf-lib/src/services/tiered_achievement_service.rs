@@ -1,5 +1,7 @@
+use crate::context::Context;
+use crate::error::WtfResult;
 use crate::models::tiered_achievement::TieredProgress;
-use crate::storage::database::{GenericDatabase, DATABASE};
+use crate::storage::database::GenericDatabase;
 use log::error;
 use std::collections::HashMap;
 use std::sync::Mutex;
@@ -10,7 +12,11 @@ pub struct TieredAchievementService {
 }
 
 impl TieredAchievementService {
-    pub fn new(db: GenericDatabase<TieredProgress>) -> Self {
+    pub fn new(context: &Context) -> Self {
+        let db: GenericDatabase<TieredProgress> =
+            GenericDatabase::new(context.database(), "tiered_achievements").unwrap_or_else(|e| {
+                panic!("Failed to initialize tiered achievements database: {}", e);
+            });
         let all = db.get_all().unwrap_or_else(|e| {
             error!("Failed to load tiered achievements: {}", e);
             Vec::new()
@@ -23,10 +29,7 @@ impl TieredAchievementService {
     }
 
     pub fn production() -> Self {
-        let db = GenericDatabase::new(&DATABASE, "tiered_achievements").unwrap_or_else(|e| {
-            panic!("Failed to initialize tiered achievements database: {}", e);
-        });
-        Self::new(db)
+        Self::new(&Context::production())
     }
 
     /// Increment a counter. Returns (old_count, new_count).
@@ -69,10 +72,8 @@ impl TieredAchievementService {
         self.cache.lock().unwrap().clone()
     }
 
-    pub fn reset_all(&self) -> Result<(), String> {
-        self.db
-            .clear()
-            .map_err(|e| format!("Failed to clear tiered achievements: {}", e))?;
+    pub fn reset_all(&self) -> WtfResult<()> {
+        self.db.clear()?;
         self.cache.lock().unwrap().clear();
         Ok(())
     }
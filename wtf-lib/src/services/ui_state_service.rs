@@ -0,0 +1,60 @@
+use crate::context::Context;
+use crate::models::data::UiStateEntry;
+use crate::storage::database::GenericDatabase;
+use log::error;
+
+/// Persists UI state between runs as opaque JSON documents keyed by front-end name
+pub struct UiStateService {
+    db: GenericDatabase<UiStateEntry>,
+}
+
+impl UiStateService {
+    pub fn new(context: &Context) -> Self {
+        let db = GenericDatabase::new(context.database(), "ui_state")
+            .expect("could not initialize ui state database");
+        Self { db }
+    }
+
+    /// Create a service backed by the production sled database.
+    pub fn production() -> Self {
+        Self::new(&Context::production())
+    }
+
+    /// Load the stored state for `key`, if any.
+    pub fn load(&self, key: &str) -> Option<String> {
+        match self.db.get(key) {
+            Ok(entry) => entry.map(|e| e.json),
+            Err(e) => {
+                error!("Failed to load UI state '{}': {}", key, e);
+                None
+            }
+        }
+    }
+
+    /// Replace the stored state for `key`.
+    pub fn save(&self, key: &str, json: String) {
+        let entry = UiStateEntry {
+            key: key.to_string(),
+            json,
+        };
+        if let Err(e) = self.db.insert(&entry) {
+            error!("Failed to save UI state '{}': {}", key, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let svc = UiStateService::new(&Context::temporary());
+
+        assert_eq!(svc.load("tui"), None);
+        svc.save("tui", r#"{"tab":"GitHub"}"#.to_string());
+        svc.save("tui", r#"{"tab":"Meetings"}"#.to_string());
+
+        assert_eq!(svc.load("tui").as_deref(), Some(r#"{"tab":"Meetings"}"#));
+    }
+}
@@ -1,32 +1,55 @@
-use crate::models::data::{LocalWorklog, LocalWorklogHistory, LocalWorklogState, Worklog};
+use crate::config::{HooksConfig, ValidationConfig};
+use crate::context::Context;
+use crate::duration::format_seconds;
+use crate::error::WtfResult;
+use crate::models::data::{
+    AuditAction, LocalWorklog, LocalWorklogHistory, LocalWorklogState, Worklog,
+};
+use crate::services::audit_service::AuditService;
 use crate::services::jira_service::IssueService;
-use crate::storage::database::{GenericDatabase, DATABASE};
+use crate::storage::database::GenericDatabase;
+use crate::utils::hooks::{self, HookEvent};
+use crate::utils::worklog_rules::{self, RuleContext, Violation};
 use chrono::{DateTime, NaiveDate, Utc};
 use log::{debug, error};
+use std::collections::{BTreeMap, HashSet};
+use std::sync::OnceLock;
 
 pub struct LocalWorklogService {
+    context: Context,
     worklogs_db: GenericDatabase<LocalWorklog>,
     history_db: GenericDatabase<LocalWorklogHistory>,
+    audit: AuditService,
+    /// Read from the config the first time a worklog is created
+    hooks: OnceLock<HooksConfig>,
 }
 
 impl LocalWorklogService {
-    pub fn new(
-        worklogs_db: GenericDatabase<LocalWorklog>,
-        history_db: GenericDatabase<LocalWorklogHistory>,
-    ) -> Self {
+    pub fn new(context: &Context) -> Self {
+        let database = context.database();
         Self {
-            worklogs_db,
-            history_db,
+            context: context.clone(),
+            worklogs_db: GenericDatabase::new(database, "local_worklogs")
+                .expect("could not initialize local_worklogs database"),
+            history_db: GenericDatabase::new(database, "local_worklogs_history")
+                .expect("could not initialize local_worklogs_history database"),
+            audit: AuditService::new(context),
+            hooks: OnceLock::new(),
         }
     }
 
     /// Create a service backed by the production sled database.
     pub fn production() -> Self {
-        let worklogs_db = GenericDatabase::new(&DATABASE, "local_worklogs")
-            .expect("could not initialize local_worklogs database");
-        let history_db = GenericDatabase::new(&DATABASE, "local_worklogs_history")
-            .expect("could not initialize local_worklogs_history database");
-        Self::new(worklogs_db, history_db)
+        Self::new(&Context::production())
+    }
+
+    fn hooks(&self) -> &HooksConfig {
+        self.hooks.get_or_init(|| {
+            self.context
+                .config()
+                .map(|config| config.hooks)
+                .unwrap_or_default()
+        })
     }
 
     pub fn get_worklog(&self, worklog_id: &String) -> Option<LocalWorklog> {
@@ -58,10 +81,16 @@ impl LocalWorklogService {
                     "removing worklog '{}' for issue '{}'",
                     worklog_id, wl.issue_id
                 );
-                IssueService::production()
+                IssueService::new(&self.context)
                     .delete_worklog(&wl.issue_id, worklog_id)
                     .await;
-                self.remove_local_worklog(&wl);
+                if self.remove(&wl) {
+                    self.audit.record(
+                        AuditAction::Revert,
+                        format!("worklog {}", wl.id),
+                        format!("{} (Jira worklog {})", summary(&wl), worklog_id),
+                    );
+                }
             } else {
                 debug!("local worklog not associated with jira worklog...");
             }
@@ -134,20 +163,40 @@ impl LocalWorklogService {
     }
 
     pub fn save_local_worklog(&self, local_worklog: LocalWorklog) {
+        let previous = self.get_local_worklog_by_id(&local_worklog.id);
         if let Err(e) = self.worklogs_db.insert(&local_worklog) {
             error!("Failed to save local worklog '{}': {}", local_worklog.id, e);
+            return;
+        }
+        if let Some((action, details)) = audit_change(previous.as_ref(), &local_worklog) {
+            self.audit
+                .record(action, format!("worklog {}", local_worklog.id), details);
         }
     }
 
     pub fn remove_local_worklog(&self, local_worklog: &LocalWorklog) {
-        if let Err(e) = self.worklogs_db.remove(local_worklog.id.as_str()) {
-            error!(
-                "Failed to remove local worklog '{}': {}",
-                local_worklog.id, e
+        if self.remove(local_worklog) {
+            self.audit.record(
+                AuditAction::Delete,
+                format!("worklog {}", local_worklog.id),
+                summary(local_worklog),
             );
         }
     }
 
+    fn remove(&self, local_worklog: &LocalWorklog) -> bool {
+        match self.worklogs_db.remove(local_worklog.id.as_str()) {
+            Ok(_) => true,
+            Err(e) => {
+                error!(
+                    "Failed to remove local worklog '{}': {}",
+                    local_worklog.id, e
+                );
+                false
+            }
+        }
+    }
+
     pub fn create_history_for_pushed_worklogs(&self) {
         let pushed_worklogs =
             self.get_all_local_worklogs_by_status(vec![LocalWorklogState::Pushed]);
@@ -261,10 +310,18 @@ impl LocalWorklogService {
             meeting_id,
             worklog_id: None,
         };
-        if let Err(e) = self.worklogs_db.insert(&worklog) {
-            error!("Failed to create worklog '{}': {}", worklog.id, e);
+        match self.worklogs_db.insert(&worklog) {
+            Ok(_) => {
+                debug!("new worklog created: '{}'", worklog.id);
+                self.audit.record(
+                    AuditAction::Create,
+                    format!("worklog {}", worklog.id),
+                    summary(&worklog),
+                );
+                hooks::notify(self.hooks(), HookEvent::WorklogCreated, &worklog);
+            }
+            Err(e) => error!("Failed to create worklog '{}': {}", worklog.id, e),
         }
-        debug!("new worklog created: '{}'", worklog.id);
         worklog
     }
 
@@ -286,9 +343,16 @@ impl LocalWorklogService {
         history_id
     }
 
+    /// Store a history entry as is, e.g. one received from another machine
+    pub fn save_history(&self, history: &LocalWorklogHistory) {
+        if let Err(e) = self.history_db.insert(history) {
+            error!("Failed to save worklog history '{}': {}", history.id, e);
+        }
+    }
+
     pub fn get_history(&self) -> Vec<LocalWorklogHistory> {
         let mut history = self.history_db.get_all().unwrap_or_default();
-        history.sort_by(|a, b| b.date.cmp(&a.date));
+        history.sort_by_key(|h| std::cmp::Reverse(h.date));
         history
     }
 
@@ -297,10 +361,8 @@ impl LocalWorklogService {
     }
 
     /// Delete a history entry from the database WITHOUT reverting in Jira
-    pub fn delete_history_from_db(&self, history_id: &str) -> Result<(), String> {
-        self.history_db
-            .remove(history_id)
-            .map_err(|e| format!("Failed to delete history: {}", e))?;
+    pub fn delete_history_from_db(&self, history_id: &str) -> WtfResult<()> {
+        self.history_db.remove(history_id)?;
         debug!("Deleted history entry from DB: {}", history_id);
         Ok(())
     }
@@ -309,9 +371,21 @@ impl LocalWorklogService {
     ///
     /// Combines local worklogs (pending or pushed through wtf) with any Jira-fetched
     /// worklogs that were not created via wtf (i.e. logged directly in Jira).
-    /// Pushed local worklogs already have a Jira counterpart, so those are excluded
-    /// from `jira_worklogs` to avoid double-counting.
     pub fn calculate_daily_total(&self, date: NaiveDate, jira_worklogs: &[Worklog]) -> f64 {
+        // A fold rather than `sum`, which returns -0.0 for an empty day
+        self.daily_hours_by_issue(date, jira_worklogs)
+            .values()
+            .fold(0.0, |total, hours| total + hours)
+    }
+
+    /// Hours logged on each issue on a specific date, counted like
+    /// [`Self::calculate_daily_total`]. Pushed local worklogs already have a Jira
+    /// counterpart, so those are excluded from `jira_worklogs` to avoid double-counting.
+    pub fn daily_hours_by_issue(
+        &self,
+        date: NaiveDate,
+        jira_worklogs: &[Worklog],
+    ) -> BTreeMap<String, f64> {
         let local_worklogs = self.worklogs_db.get_all().unwrap_or_default();
 
         // Jira IDs that correspond to a pushed local worklog (already counted locally).
@@ -320,44 +394,88 @@ impl LocalWorklogService {
             .filter_map(|wl| wl.worklog_id.as_deref())
             .collect();
 
-        let local_hours: f64 = local_worklogs
+        let mut hours: BTreeMap<String, f64> = BTreeMap::new();
+        for wl in local_worklogs
             .iter()
             .filter(|wl| wl.started.date_naive() == date)
-            .map(|wl| wl.time_spent_seconds as f64 / 3600.0)
-            .sum();
+        {
+            *hours.entry(wl.issue_id.clone()).or_default() += wl.time_spent_seconds as f64 / 3600.0;
+        }
 
         // Add hours from worklogs logged directly in Jira (not via wtf).
-        let jira_only_hours: f64 = jira_worklogs
+        for wl in jira_worklogs.iter().filter(|wl| {
+            wl.started.date_naive() == date && !pushed_jira_ids.contains(wl.id.as_str())
+        }) {
+            *hours.entry(wl.issue_id.clone()).or_default() += wl.time_spent_seconds as f64 / 3600.0;
+        }
+        hours
+    }
+
+    /// Check `worklogs` against the `[validation]` rules. Day totals count every local
+    /// worklog plus the Jira-fetched `jira_worklogs`; issue statuses are the fetched ones.
+    pub fn validate(
+        &self,
+        worklogs: &[LocalWorklog],
+        rules: &ValidationConfig,
+        jira_worklogs: &[Worklog],
+    ) -> Vec<Violation> {
+        let issues = IssueService::new(&self.context);
+        worklog_rules::check(
+            rules,
+            worklogs,
+            &RuleContext {
+                day_total: &|date| self.calculate_daily_total(date, jira_worklogs),
+                issue_status: &|key| issues.get_by_key(key).map(|issue| issue.status),
+            },
+        )
+    }
+
+    /// Stage `worklogs`, except those breaking a blocking `[validation]` rule. Returns the
+    /// worklogs staged and every violation found, warnings included.
+    pub fn stage_worklogs(
+        &self,
+        worklogs: Vec<LocalWorklog>,
+        rules: &ValidationConfig,
+        jira_worklogs: &[Worklog],
+    ) -> (Vec<LocalWorklog>, Vec<Violation>) {
+        let violations = self.validate(&worklogs, rules, jira_worklogs);
+        let blocked: HashSet<&str> = violations
             .iter()
-            .filter(|wl| {
-                wl.started.date_naive() == date && !pushed_jira_ids.contains(wl.id.as_str())
+            .filter(|v| v.is_blocking())
+            .map(|v| v.worklog_id.as_str())
+            .collect();
+        let staged = worklogs
+            .into_iter()
+            .filter(|w| !blocked.contains(w.id.as_str()))
+            .map(|mut worklog| {
+                worklog.status = LocalWorklogState::Staged;
+                self.save_local_worklog(worklog.clone());
+                worklog
             })
-            .map(|wl| wl.time_spent_seconds as f64 / 3600.0)
-            .sum();
-
-        local_hours + jira_only_hours
+            .collect();
+        (staged, violations)
     }
 
-    /// Find days in a date range that have gaps (less than daily_limit hours logged).
+    /// Find days in a date range that have gaps (less than their target hours logged).
+    /// `daily_target` gives the hours expected on a day; days without any (weekends, days off of
+    /// a part-time schedule) are skipped.
     /// `is_absent` is a predicate returning true for days that should be skipped (e.g. holidays, leave).
     /// `jira_worklogs` are Jira-fetched worklogs used to account for time logged directly in Jira.
     pub fn find_gap_days(
         &self,
         start_date: NaiveDate,
         end_date: NaiveDate,
-        daily_limit: f64,
+        daily_target: &dyn Fn(NaiveDate) -> f64,
         min_threshold: f64,
         is_absent: &dyn Fn(NaiveDate) -> bool,
         jira_worklogs: &[Worklog],
     ) -> Vec<(NaiveDate, f64)> {
-        use chrono::Datelike;
-
         let mut gaps = Vec::new();
         let mut current_date = start_date;
 
         while current_date <= end_date {
-            let weekday = current_date.weekday().num_days_from_monday();
-            if weekday >= 5 {
+            let target = daily_target(current_date);
+            if target <= 0.0 {
                 current_date = current_date.succ_opt().unwrap_or(current_date);
                 continue;
             }
@@ -369,12 +487,13 @@ impl LocalWorklogService {
 
             let existing_hours = self.calculate_daily_total(current_date, jira_worklogs);
 
-            if existing_hours >= min_threshold {
+            // A short day is substantially logged well under the usual threshold
+            if existing_hours >= min_threshold.min(target) {
                 current_date = current_date.succ_opt().unwrap_or(current_date);
                 continue;
             }
 
-            let hours_to_add = daily_limit - existing_hours;
+            let hours_to_add = target - existing_hours;
             if hours_to_add > 0.0 {
                 gaps.push((current_date, hours_to_add));
             }
@@ -386,20 +505,86 @@ impl LocalWorklogService {
     }
 }
 
+/// The issue, time and day of a worklog, as written in the audit log
+fn summary(worklog: &LocalWorklog) -> String {
+    format!(
+        "{} {} on {}",
+        worklog.issue_id,
+        format_seconds(worklog.time_spent_seconds),
+        worklog.started.date_naive()
+    )
+}
+
+/// What saving `saved` over `previous` does, for the audit log; `None` when nothing changes
+fn audit_change(
+    previous: Option<&LocalWorklog>,
+    saved: &LocalWorklog,
+) -> Option<(AuditAction, String)> {
+    let Some(previous) = previous else {
+        return Some((AuditAction::Create, summary(saved)));
+    };
+    let mut changes = Vec::new();
+    if previous.issue_id != saved.issue_id {
+        changes.push(format!("issue {} -> {}", previous.issue_id, saved.issue_id));
+    }
+    if previous.time_spent_seconds != saved.time_spent_seconds {
+        changes.push(format!(
+            "time {} -> {}",
+            format_seconds(previous.time_spent_seconds),
+            format_seconds(saved.time_spent_seconds)
+        ));
+    }
+    if previous.started != saved.started {
+        changes.push(format!(
+            "start {} -> {}",
+            previous.started.format("%Y-%m-%d %H:%M"),
+            saved.started.format("%Y-%m-%d %H:%M")
+        ));
+    }
+    if previous.comment != saved.comment {
+        changes.push("comment".to_string());
+    }
+    if previous.worklog_id != saved.worklog_id {
+        changes.push(format!(
+            "Jira worklog {}",
+            saved.worklog_id.as_deref().unwrap_or("removed")
+        ));
+    }
+
+    let action = match (&previous.status, &saved.status) {
+        (LocalWorklogState::Created, LocalWorklogState::Staged) => AuditAction::Stage,
+        (LocalWorklogState::Staged, LocalWorklogState::Created) => AuditAction::Unstage,
+        (from, LocalWorklogState::Pushed) if *from != LocalWorklogState::Pushed => {
+            AuditAction::Push
+        }
+        (from, to) if from != to => {
+            changes.push(format!("status {:?} -> {:?}", from, to));
+            AuditAction::Edit
+        }
+        _ if changes.is_empty() => return None,
+        _ => AuditAction::Edit,
+    };
+    let details = match action {
+        AuditAction::Edit => format!("{}: {}", previous.issue_id, changes.join(", ")),
+        _ => summary(saved),
+    };
+    Some((action, details))
+}
+
 pub struct WorklogsService {
     db: GenericDatabase<Worklog>,
 }
 
 impl WorklogsService {
-    pub fn new(db: GenericDatabase<Worklog>) -> Self {
+    pub fn new(context: &Context) -> Self {
+        let db = GenericDatabase::new(context.database(), "worklogs")
+            .expect("could not initialize worklogs database");
         Self { db }
     }
 
     /// Create a service backed by the production sled database.
     pub fn production() -> Self {
-        let db = GenericDatabase::new(&DATABASE, "worklogs")
-            .expect("could not initialize worklogs database");
-        Self::new(db)
+        Self::new(&Context::production())
     }
 
     pub fn get_all_worklogs(&self) -> Vec<Worklog> {
@@ -417,15 +602,31 @@ impl WorklogsService {
     }
 
     pub fn save_worklog(&self, worklog: Worklog) {
-        self.db.insert(&worklog).unwrap();
+        if let Err(e) = self.db.insert(&worklog) {
+            error!("Failed to save worklog '{}': {}", worklog.id, e);
+        }
     }
 
     pub fn remove_worklog(&self, worklog_id: &str) {
-        self.db.remove(worklog_id).unwrap();
+        if let Err(e) = self.db.remove(worklog_id) {
+            error!("Failed to remove worklog '{}': {}", worklog_id, e);
+        }
     }
 
     pub fn save_all_worklogs(&self, worklogs: Vec<Worklog>) {
-        self.db.save_all(worklogs).unwrap();
+        if let Err(e) = self.db.save_all(worklogs) {
+            error!("Failed to save worklogs: {}", e);
+        }
+    }
+
+    /// Catch up with Jira's changes: save the updated worklogs and drop the deleted ones
+    /// we hold (the deleted feed covers every author)
+    pub fn apply_changes(&self, updated: Vec<Worklog>, deleted: &[String]) {
+        let known: HashSet<String> = self.get_all_worklogs().into_iter().map(|w| w.id).collect();
+        for id in deleted.iter().filter(|id| known.contains(*id)) {
+            self.remove_worklog(id);
+        }
+        self.save_all_worklogs(updated);
     }
 
     pub fn replace_worklogs_for_date_range(
@@ -447,29 +648,28 @@ impl WorklogsService {
         let mut all_combined = worklogs_to_keep;
         all_combined.extend(new_worklogs);
 
-        self.db.clear().unwrap();
-        self.db.save_all(all_combined).unwrap();
+        if let Err(e) = self.db.clear() {
+            error!("Failed to replace worklogs: {}", e);
+            return;
+        }
+        self.save_all_worklogs(all_combined);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::WorklogConfig;
     use crate::models::data::{LocalWorklog, LocalWorklogState, Worklog};
-    use crate::storage::database::{Database, GenericDatabase};
+    use crate::utils::worklog_rules::ValidationRule;
     use chrono::{NaiveDate, TimeZone, Utc};
 
     fn make_local_service() -> LocalWorklogService {
-        let db = Database::temporary();
-        let worklogs_db = GenericDatabase::new(&db, "local_worklogs").unwrap();
-        let history_db = GenericDatabase::new(&db, "local_worklogs_history").unwrap();
-        LocalWorklogService::new(worklogs_db, history_db)
+        LocalWorklogService::new(&Context::temporary())
     }
 
     fn make_worklogs_service() -> WorklogsService {
-        let db = Database::temporary();
-        let worklogs_db = GenericDatabase::new(&db, "worklogs").unwrap();
-        WorklogsService::new(worklogs_db)
+        WorklogsService::new(&Context::temporary())
     }
 
     fn local_worklog(id: &str, started: DateTime<Utc>, seconds: i64) -> LocalWorklog {
@@ -532,6 +732,26 @@ mod tests {
         assert!((total - 1.5).abs() < 0.001);
     }
 
+    #[test]
+    fn test_daily_hours_by_issue_skips_pushed_jira_copies() {
+        let svc = make_local_service();
+        let jan10 = Utc.with_ymd_and_hms(2024, 1, 10, 9, 0, 0).unwrap();
+        let mut pushed = local_worklog("wl-a", jan10, 3600);
+        pushed.worklog_id = Some("jira-1".to_string());
+        svc.save_local_worklog(pushed);
+        let mut direct = worklog("jira-2", jan10, 1800);
+        direct.issue_id = "OPS-7".to_string();
+
+        let hours = svc.daily_hours_by_issue(
+            NaiveDate::from_ymd_opt(2024, 1, 10).unwrap(),
+            &[worklog("jira-1", jan10, 3600), direct],
+        );
+        assert_eq!(
+            hours,
+            BTreeMap::from([("OPS-7".to_string(), 0.5), ("PROJ-1".to_string(), 1.0)])
+        );
+    }
+
     #[test]
     fn test_find_gap_days_skips_weekends() {
         let svc = make_local_service();
@@ -539,7 +759,7 @@ mod tests {
         let gaps = svc.find_gap_days(
             NaiveDate::from_ymd_opt(2024, 1, 13).unwrap(),
             NaiveDate::from_ymd_opt(2024, 1, 14).unwrap(),
-            8.0,
+            &|date| WorklogConfig::default().target_hours(date),
             0.0,
             &|_| false,
             &[],
@@ -554,7 +774,7 @@ mod tests {
         let gaps = svc.find_gap_days(
             NaiveDate::from_ymd_opt(2024, 1, 10).unwrap(),
             NaiveDate::from_ymd_opt(2024, 1, 10).unwrap(),
-            8.0,
+            &|date| WorklogConfig::default().target_hours(date),
             0.5,
             &|_| false,
             &[],
@@ -571,7 +791,7 @@ mod tests {
         let gaps = svc.find_gap_days(
             absent_day,
             absent_day,
-            8.0,
+            &|date| WorklogConfig::default().target_hours(date),
             0.0,
             &|date| date == absent_day,
             &[],
@@ -579,6 +799,92 @@ mod tests {
         assert!(gaps.is_empty());
     }
 
+    #[test]
+    fn test_find_gap_days_follows_the_weekday_targets() {
+        let svc = make_local_service();
+        let worklog: WorklogConfig = toml::from_str("[weekday_hours]\nwed = 4\nfri = 0").unwrap();
+        // 2024-01-10 is Wednesday, 2024-01-12 is Friday
+        let gaps = svc.find_gap_days(
+            NaiveDate::from_ymd_opt(2024, 1, 10).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 12).unwrap(),
+            &|date| worklog.target_hours(date),
+            0.5,
+            &|_| false,
+            &[],
+        );
+        assert_eq!(
+            gaps,
+            vec![
+                (NaiveDate::from_ymd_opt(2024, 1, 10).unwrap(), 4.0),
+                (NaiveDate::from_ymd_opt(2024, 1, 11).unwrap(), 8.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_changes_are_recorded_in_the_audit_log() {
+        let context = Context::temporary();
+        let svc = LocalWorklogService::new(&context);
+        let t = Utc.with_ymd_and_hms(2024, 1, 10, 9, 0, 0).unwrap();
+        let mut wl = local_worklog("wl-1", t, 3600);
+        svc.save_local_worklog(wl.clone());
+        // Saving it unchanged isn't a change
+        svc.save_local_worklog(wl.clone());
+        wl.status = LocalWorklogState::Staged;
+        svc.save_local_worklog(wl.clone());
+        wl.time_spent_seconds = 5400;
+        svc.save_local_worklog(wl.clone());
+        wl.status = LocalWorklogState::Pushed;
+        wl.worklog_id = Some("10001".to_string());
+        svc.save_local_worklog(wl.clone());
+        svc.remove_local_worklog(&wl);
+
+        let entries = AuditService::new(&context).entries();
+
+        let actions: Vec<_> = entries.iter().map(|e| e.action).collect();
+        assert_eq!(
+            actions,
+            vec![
+                AuditAction::Create,
+                AuditAction::Stage,
+                AuditAction::Edit,
+                AuditAction::Push,
+                AuditAction::Delete,
+            ]
+        );
+        assert!(entries.iter().all(|e| e.target == "worklog wl-1"));
+        assert!(
+            entries[2].details.starts_with("PROJ-1: time "),
+            "{}",
+            entries[2].details
+        );
+    }
+
+    #[test]
+    fn test_stage_worklogs_holds_back_blocked_ones() {
+        let svc = make_local_service();
+        let t = Utc.with_ymd_and_hms(2024, 1, 10, 9, 0, 0).unwrap();
+        let mut described = local_worklog("wl-1", t, 3600);
+        described.comment = "Code review".to_string();
+        let bare = local_worklog("wl-2", t, 3600);
+        svc.save_local_worklog(described.clone());
+        svc.save_local_worklog(bare.clone());
+        let rules = ValidationConfig {
+            min_comment_length: 5,
+            blocking: vec![ValidationRule::MinCommentLength],
+            ..ValidationConfig::default()
+        };
+
+        let (staged, violations) = svc.stage_worklogs(vec![described, bare], &rules, &[]);
+
+        assert_eq!(staged.len(), 1);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].worklog_id, "wl-2");
+        let status = |id: &str| svc.get_local_worklog_by_id(id).unwrap().status;
+        assert_eq!(status("wl-1"), LocalWorklogState::Staged);
+        assert_eq!(status("wl-2"), LocalWorklogState::Created);
+    }
+
     #[test]
     fn test_worklogs_service_save_and_get() {
         let svc = make_worklogs_service();
@@ -604,6 +910,28 @@ mod tests {
         assert_eq!(results[0].id, "w1");
     }
 
+    #[test]
+    fn test_worklogs_service_apply_changes() {
+        let svc = make_worklogs_service();
+        let t = Utc.with_ymd_and_hms(2024, 1, 10, 9, 0, 0).unwrap();
+        svc.save_worklog(worklog("w1", t, 3600));
+        svc.save_worklog(worklog("w2", t, 3600));
+
+        svc.apply_changes(
+            vec![worklog("w1", t, 7200), worklog("w3", t, 1800)],
+            &["w2".to_string(), "someone-elses".to_string()],
+        );
+
+        let mut all = svc.get_all_worklogs();
+        all.sort_by(|a, b| a.id.cmp(&b.id));
+        assert_eq!(all.len(), 2);
+        assert_eq!(
+            (all[0].id.as_str(), all[0].time_spent_seconds),
+            ("w1", 7200)
+        );
+        assert_eq!(all[1].id, "w3");
+    }
+
     #[test]
     fn test_worklogs_service_remove() {
         let svc = make_worklogs_service();
@@ -0,0 +1,120 @@
+use sled::{Db, Tree};
+use std::error::Error;
+use std::path::Path;
+
+pub type StorageResult<T> = Result<T, Box<dyn Error + Send + Sync>>;
+
+/// One record of a collection: its id and its bincode-encoded value
+pub struct Record {
+    pub key: String,
+    pub value: Vec<u8>,
+    /// The value as JSON, filled in for backends that can query it
+    /// (see [`Storage::stores_documents`])
+    pub document: Option<String>,
+}
+
+/// Where a [`Database`](super::database::Database) keeps its collections.
+///
+/// Every write is durable once the call returns.
+pub trait Storage: Send + Sync {
+    fn get(&self, collection: &str, key: &str) -> StorageResult<Option<Vec<u8>>>;
+
+    /// Every record of `collection`, ordered by key
+    fn scan(&self, collection: &str) -> StorageResult<Vec<(String, Vec<u8>)>>;
+
+    /// Insert or replace `records`: all of them or, on error, none
+    fn put(&self, collection: &str, records: Vec<Record>) -> StorageResult<()>;
+
+    fn remove(&self, collection: &str, keys: &[String]) -> StorageResult<()>;
+
+    fn clear(&self, collection: &str) -> StorageResult<()>;
+
+    /// Names of the collections holding records
+    fn collections(&self) -> StorageResult<Vec<String>>;
+
+    /// Whether records should carry their JSON `document`
+    fn stores_documents(&self) -> bool {
+        false
+    }
+}
+
+/// The original embedded key-value backend: one sled tree per collection. Only one
+/// process can have it open at a time.
+pub struct SledStorage {
+    db: Db,
+}
+
+impl SledStorage {
+    pub fn open(path: impl AsRef<Path>) -> StorageResult<Self> {
+        Ok(Self {
+            db: sled::open(path)?,
+        })
+    }
+
+    /// An in-memory database, deleted when dropped
+    pub fn temporary() -> Self {
+        let db = sled::Config::new()
+            .temporary(true)
+            .open()
+            .expect("temp sled db");
+        Self { db }
+    }
+
+    fn tree(&self, collection: &str) -> StorageResult<Tree> {
+        Ok(self.db.open_tree(collection)?)
+    }
+}
+
+impl Storage for SledStorage {
+    fn get(&self, collection: &str, key: &str) -> StorageResult<Option<Vec<u8>>> {
+        Ok(self.tree(collection)?.get(key)?.map(|value| value.to_vec()))
+    }
+
+    fn scan(&self, collection: &str) -> StorageResult<Vec<(String, Vec<u8>)>> {
+        self.tree(collection)?
+            .iter()
+            .map(|entry| {
+                let (key, value) = entry?;
+                Ok((String::from_utf8_lossy(&key).into_owned(), value.to_vec()))
+            })
+            .collect()
+    }
+
+    fn put(&self, collection: &str, records: Vec<Record>) -> StorageResult<()> {
+        let tree = self.tree(collection)?;
+        let mut batch = sled::Batch::default();
+        for record in records {
+            batch.insert(record.key.as_bytes(), record.value);
+        }
+        tree.apply_batch(batch)?;
+        tree.flush()?;
+        Ok(())
+    }
+
+    fn remove(&self, collection: &str, keys: &[String]) -> StorageResult<()> {
+        let tree = self.tree(collection)?;
+        for key in keys {
+            tree.remove(key)?;
+        }
+        tree.flush()?;
+        Ok(())
+    }
+
+    fn clear(&self, collection: &str) -> StorageResult<()> {
+        let tree = self.tree(collection)?;
+        tree.clear()?;
+        tree.flush()?;
+        Ok(())
+    }
+
+    fn collections(&self) -> StorageResult<Vec<String>> {
+        let default = self.db.name();
+        Ok(self
+            .db
+            .tree_names()
+            .into_iter()
+            .filter(|name| *name != default)
+            .map(|name| String::from_utf8_lossy(&name).into_owned())
+            .collect())
+    }
+}
@@ -0,0 +1,281 @@
+use crate::config::config_home;
+use chrono::{Local, NaiveDateTime};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const TIMESTAMP_FORMAT: &str = "%Y%m%d_%H%M%S";
+const CONFIG_FILE: &str = "config.toml";
+const DB_DIR: &str = ".wtf_db";
+
+/// Label of the snapshots taken before pushing worklogs to Jira
+pub const PRE_PUSH_LABEL: &str = "pre-push";
+/// Label of the snapshots taken before a restore, so it can be undone
+pub const PRE_RESTORE_LABEL: &str = "pre-restore";
+/// Label of the snapshots taken before the database is migrated to a new schema
+pub const PRE_MIGRATION_LABEL: &str = "pre-migration";
+
+/// A copy of the config file and the database directory, stored in
+/// `backups/<timestamp>[_<label>]`. Unlabelled backups are manual ones.
+#[derive(Debug, Clone)]
+pub struct Backup {
+    pub name: String,
+    pub created_at: NaiveDateTime,
+    /// Set on automatic snapshots, e.g. [`PRE_PUSH_LABEL`]
+    pub label: Option<String>,
+    pub path: PathBuf,
+}
+
+impl Backup {
+    fn from_dir(path: PathBuf) -> Option<Self> {
+        let name = path.file_name()?.to_str()?.to_string();
+        let (timestamp, label) = match name.get(15..) {
+            Some("") => (name.as_str(), None),
+            Some(rest) => (&name[..15], Some(rest.strip_prefix('_')?.to_string())),
+            None => return None,
+        };
+        let created_at = NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT).ok()?;
+        Some(Self {
+            name,
+            created_at,
+            label,
+            path,
+        })
+    }
+
+    pub fn is_automatic(&self) -> bool {
+        self.label.is_some()
+    }
+
+    pub fn has_database(&self) -> bool {
+        self.path.join(DB_DIR).is_dir()
+    }
+
+    /// Total size of the backed up files, in bytes
+    pub fn size(&self) -> u64 {
+        dir_size(&self.path)
+    }
+}
+
+/// Creates, lists, restores and prunes backups of the wtf home directory
+pub struct BackupStore {
+    home: PathBuf,
+}
+
+impl BackupStore {
+    pub fn new(home: PathBuf) -> Self {
+        Self { home }
+    }
+
+    /// Store backing up the real config and database.
+    pub fn production() -> Self {
+        Self::new(config_home())
+    }
+
+    pub fn backup_dir(&self) -> PathBuf {
+        self.home.join("backups")
+    }
+
+    /// Copy the config and the database into a new backup. The database must not be written
+    /// to meanwhile; every wtf write is flushed, so copying between writes is safe.
+    pub fn create(&self, label: Option<&str>) -> io::Result<Backup> {
+        self.create_at(Local::now().naive_local(), label)
+    }
+
+    fn create_at(&self, at: NaiveDateTime, label: Option<&str>) -> io::Result<Backup> {
+        let mut name = at.format(TIMESTAMP_FORMAT).to_string();
+        if let Some(label) = label {
+            name = format!("{}_{}", name, label);
+        }
+        let path = self.backup_dir().join(&name);
+        // Two backups in the same second: keep the latest
+        if path.exists() {
+            fs::remove_dir_all(&path)?;
+        }
+        fs::create_dir_all(&path)?;
+
+        let config = self.home.join(CONFIG_FILE);
+        if config.exists() {
+            fs::copy(&config, path.join(CONFIG_FILE))?;
+        }
+        let db = self.home.join(DB_DIR);
+        if db.exists() {
+            copy_dir_all(&db, &path.join(DB_DIR))?;
+        }
+
+        Ok(Backup {
+            name,
+            created_at: at,
+            label: label.map(str::to_string),
+            path,
+        })
+    }
+
+    /// Every backup, newest first
+    pub fn list(&self) -> io::Result<Vec<Backup>> {
+        let dir = self.backup_dir();
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut backups: Vec<_> = fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| Backup::from_dir(entry.path()))
+            .collect();
+        backups.sort_by(|a, b| b.name.cmp(&a.name));
+        Ok(backups)
+    }
+
+    /// The backup named `name`, or the only one starting with it (so a bare timestamp
+    /// matches a labelled snapshot)
+    pub fn find(&self, name: &str) -> io::Result<Option<Backup>> {
+        let backups = self.list()?;
+        if let Some(backup) = backups.iter().find(|b| b.name == name) {
+            return Ok(Some(backup.clone()));
+        }
+        let mut matching = backups.into_iter().filter(|b| b.name.starts_with(name));
+        match (matching.next(), matching.next()) {
+            (Some(backup), None) => Ok(Some(backup)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Put the backed up config and database back in place. The database must not be open,
+    /// in this process or another one.
+    pub fn restore(&self, backup: &Backup) -> io::Result<()> {
+        let config = backup.path.join(CONFIG_FILE);
+        if config.exists() {
+            fs::copy(&config, self.home.join(CONFIG_FILE))?;
+        }
+        let db = backup.path.join(DB_DIR);
+        if db.exists() {
+            let target = self.home.join(DB_DIR);
+            if target.exists() {
+                fs::remove_dir_all(&target)?;
+            }
+            copy_dir_all(&db, &target)?;
+        }
+        Ok(())
+    }
+
+    /// Delete the automatic snapshots beyond the `keep` newest ones and return them.
+    /// Manual backups are left alone.
+    pub fn prune(&self, keep: usize) -> io::Result<Vec<Backup>> {
+        let removed: Vec<_> = self
+            .list()?
+            .into_iter()
+            .filter(Backup::is_automatic)
+            .skip(keep)
+            .collect();
+        for backup in &removed {
+            fs::remove_dir_all(&backup.path)?;
+        }
+        Ok(removed)
+    }
+}
+
+fn copy_dir_all(src: &Path, dst: &Path) -> io::Result<()> {
+    fs::create_dir_all(dst)?;
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            copy_dir_all(&entry.path(), &dst.join(entry.file_name()))?;
+        } else {
+            fs::copy(entry.path(), dst.join(entry.file_name()))?;
+        }
+    }
+    Ok(())
+}
+
+fn dir_size(path: &Path) -> u64 {
+    fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| match entry.file_type() {
+                    Ok(ty) if ty.is_dir() => dir_size(&entry.path()),
+                    _ => entry.metadata().map(|m| m.len()).unwrap_or(0),
+                })
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn temp_home(test: &str) -> PathBuf {
+        let home = std::env::temp_dir().join(format!("wtf-backup-{}-{}", std::process::id(), test));
+        let _ = fs::remove_dir_all(&home);
+        fs::create_dir_all(home.join(DB_DIR)).unwrap();
+        fs::write(home.join(CONFIG_FILE), "v1").unwrap();
+        fs::write(home.join(DB_DIR).join("db"), "data v1").unwrap();
+        home
+    }
+
+    fn at(minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 3, 2)
+            .unwrap()
+            .and_hms_opt(9, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_restore_puts_config_and_database_back() {
+        let home = temp_home("restore");
+        let store = BackupStore::new(home.clone());
+        let backup = store.create_at(at(0), None).unwrap();
+        fs::write(home.join(CONFIG_FILE), "v2").unwrap();
+        fs::write(home.join(DB_DIR).join("db"), "data v2").unwrap();
+        fs::write(home.join(DB_DIR).join("snap.1"), "new").unwrap();
+
+        store.restore(&backup).unwrap();
+
+        assert_eq!(fs::read_to_string(home.join(CONFIG_FILE)).unwrap(), "v1");
+        assert_eq!(
+            fs::read_to_string(home.join(DB_DIR).join("db")).unwrap(),
+            "data v1"
+        );
+        assert!(!home.join(DB_DIR).join("snap.1").exists());
+        fs::remove_dir_all(home).unwrap();
+    }
+
+    #[test]
+    fn test_list_and_find_parse_labels() {
+        let home = temp_home("list");
+        let store = BackupStore::new(home.clone());
+        store.create_at(at(0), None).unwrap();
+        store.create_at(at(5), Some(PRE_PUSH_LABEL)).unwrap();
+        fs::create_dir_all(store.backup_dir().join("not-a-backup")).unwrap();
+
+        let backups = store.list().unwrap();
+        assert_eq!(backups.len(), 2);
+        assert_eq!(backups[0].name, "20260302_090500_pre-push");
+        assert_eq!(backups[0].label.as_deref(), Some(PRE_PUSH_LABEL));
+        assert!(backups[1].label.is_none() && backups[1].has_database());
+
+        let found = store.find("20260302_0905").unwrap().unwrap();
+        assert_eq!(found.created_at, at(5));
+        assert!(store.find("2026").unwrap().is_none());
+        fs::remove_dir_all(home).unwrap();
+    }
+
+    #[test]
+    fn test_prune_keeps_manual_backups() {
+        let home = temp_home("prune");
+        let store = BackupStore::new(home.clone());
+        store.create_at(at(0), None).unwrap();
+        for minute in 1..=4 {
+            store.create_at(at(minute), Some(PRE_PUSH_LABEL)).unwrap();
+        }
+
+        let removed = store.prune(2).unwrap();
+
+        let removed_at: Vec<_> = removed.iter().map(|b| b.created_at).collect();
+        assert_eq!(removed_at, vec![at(2), at(1)]);
+        assert_eq!(store.list().unwrap().len(), 3);
+        fs::remove_dir_all(home).unwrap();
+    }
+}
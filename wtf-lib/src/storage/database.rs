@@ -1,10 +1,18 @@
+use crate::config::{config_home, StorageBackend, StorageConfig};
+use crate::storage::backend::{Record, SledStorage, Storage, StorageResult};
+use crate::storage::backup::{BackupStore, PRE_MIGRATION_LABEL};
+use crate::storage::encryption::{DatabaseKey, EncryptedStorage, KEY_ENV};
+use crate::storage::migration;
+use crate::storage::postgres::PostgresStorage;
+use crate::storage::sqlite::SqliteStorage;
+use log::info;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
-use sled::{Db, Tree};
 use std::error::Error;
 use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::{env, fmt};
+use thiserror::Error;
 
 pub trait Identifiable {
     fn get_id(&self) -> String;
@@ -13,59 +21,70 @@ pub trait CollectionItem: Serialize + for<'de> Deserialize<'de> + Clone + Identi
 
 impl<T: Serialize + for<'de> Deserialize<'de> + Clone + Identifiable> CollectionItem for T {}
 
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum DatabaseError {
+    #[error("Record not found in the database")]
     NotFound,
+    #[error("Record already exists in the database")]
     AlreadyExists,
+    #[error("Database error: {0}")]
     DatabaseFailure(String),
 }
 
-impl fmt::Display for DatabaseError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            DatabaseError::NotFound => write!(f, "Record not found in the database"),
-            DatabaseError::AlreadyExists => write!(f, "Record already exists in the database"),
-            DatabaseError::DatabaseFailure(msg) => write!(f, "Database error: {}", msg),
-        }
-    }
-}
-
-impl Error for DatabaseError {}
-
 #[derive(Clone)]
 pub struct Database {
-    db: Db,
+    storage: Arc<dyn Storage>,
 }
 
 impl Database {
-    fn new(path: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
-        let db = sled::open(path)?;
-        Ok(Self { db })
+    /// Open (or create) the sled database in the `path` directory, upgraded to the current
+    /// schema. Only one process can have it open at a time.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Self::upgraded(Arc::new(SledStorage::open(path)?))
+    }
+
+    /// Open (or create) the SQLite database file at `path`, upgraded to the current schema
+    pub fn open_sqlite(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Self::upgraded(Arc::new(SqliteStorage::open(path)?))
     }
 
-    #[cfg(test)]
+    /// Connect to the PostgreSQL database at `url`, as `user` for the per-user collections,
+    /// upgraded to the current schema
+    pub fn connect_postgres(url: &str, user: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Self::upgraded(Arc::new(PostgresStorage::connect(url, user)?))
+    }
+
+    fn upgraded(storage: Arc<dyn Storage>) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let database = Self::with_storage(storage);
+        migration::upgrade(&database, || Ok(()))?;
+        Ok(database)
+    }
+
+    /// An in-memory database, deleted when dropped
     pub fn temporary() -> Self {
-        let db = sled::Config::new()
-            .temporary(true)
-            .open()
-            .expect("temp sled db");
-        Self { db }
+        Self::with_storage(Arc::new(SledStorage::temporary()))
+    }
+
+    pub fn with_storage(storage: Arc<dyn Storage>) -> Self {
+        Self { storage }
     }
 
-    fn get_tree(&self, collection: &str) -> Result<Tree, Box<dyn Error + Send + Sync>> {
-        Ok(self.db.open_tree(collection)?)
+    pub(crate) fn storage(&self) -> &dyn Storage {
+        self.storage.as_ref()
     }
 }
 
 #[derive(Clone)]
-/// Generic sled-backed store using **bincode** for serialization.
+/// A typed collection of a [`Database`], using **bincode** for serialization.
 ///
 /// ⚠️  **Schema evolution warning**: bincode serializes structs field-by-field with no
 /// field names or defaults. Adding, removing, or reordering fields in a stored struct
 /// **will corrupt existing records** (they will be silently dropped on next load).
-/// Always write a startup migration in the relevant service when changing a stored struct.
+/// Always bump the schema version and add a migration in [`migration`] when changing a
+/// stored struct.
 pub struct GenericDatabase<T: CollectionItem> {
-    tree: Tree,
+    storage: Arc<dyn Storage>,
+    collection: String,
     _marker: PhantomData<T>,
 }
 
@@ -74,28 +93,36 @@ impl<T: CollectionItem> GenericDatabase<T> {
         database: &Database,
         collection_name: &str,
     ) -> Result<Self, Box<dyn Error + Send + Sync>> {
-        let tree = database.get_tree(collection_name)?;
         Ok(Self {
-            tree,
+            storage: database.storage.clone(),
+            collection: collection_name.to_string(),
             _marker: PhantomData,
         })
     }
 
+    fn record(&self, item: &T) -> Result<Record, Box<dyn Error + Send + Sync>> {
+        let document = if self.storage.stores_documents() {
+            serde_json::to_string(item).ok()
+        } else {
+            None
+        };
+        Ok(Record {
+            key: item.get_id(),
+            value: bincode::serialize(item)?,
+            document,
+        })
+    }
+
     pub fn clear(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
-        self.tree.clear()?;
-        self.tree.flush()?;
-        Ok(())
+        self.storage.clear(&self.collection)
     }
 
     pub fn insert(&self, item: &T) -> Result<(), Box<dyn Error + Send + Sync>> {
-        let serialized_item = bincode::serialize(item)?;
-        self.tree.insert(item.get_id(), serialized_item)?;
-        self.tree.flush()?;
-        Ok(())
+        self.storage.put(&self.collection, vec![self.record(item)?])
     }
 
     pub fn get(&self, key: &str) -> Result<Option<T>, Box<dyn Error + Send + Sync>> {
-        if let Some(value) = self.tree.get(key)? {
+        if let Some(value) = self.storage.get(&self.collection, key)? {
             let item: T = bincode::deserialize(&value)?;
             Ok(Some(item))
         } else {
@@ -104,22 +131,19 @@ impl<T: CollectionItem> GenericDatabase<T> {
     }
 
     pub fn remove(&self, key: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
-        self.tree.remove(key)?;
-        self.tree.flush()?;
-        Ok(())
+        self.storage.remove(&self.collection, &[key.to_string()])
     }
 
     pub fn get_all(&self) -> Result<Vec<T>, Box<dyn Error + Send + Sync>> {
         let mut items = Vec::new();
         let mut corrupted_keys = Vec::new();
 
-        for item in self.tree.iter() {
-            let (key, value) = item?;
+        for (key, value) in self.storage.scan(&self.collection)? {
             match bincode::deserialize(&value) {
                 Ok(deserialized_item) => items.push(deserialized_item),
                 Err(_) => {
                     // Track corrupted record keys for cleanup
-                    corrupted_keys.push(key.to_vec());
+                    corrupted_keys.push(key);
                     continue;
                 }
             }
@@ -131,42 +155,120 @@ impl<T: CollectionItem> GenericDatabase<T> {
                 "Warning: Found {} corrupted record(s), removing them...",
                 corrupted_keys.len()
             );
-            for key in corrupted_keys {
-                self.tree.remove(key)?;
-            }
-            self.tree.flush()?;
+            self.storage.remove(&self.collection, &corrupted_keys)?;
         }
 
         Ok(items)
     }
 
     pub fn save_all(&self, items: Vec<T>) -> Result<(), Box<dyn Error + Send + Sync>> {
-        let mut batch = sled::Batch::default();
-        for item in items {
-            let serialized_item = bincode::serialize(&item)?;
-            let key = sled::IVec::from(item.get_id().as_bytes());
-            batch.insert(key, serialized_item);
+        let records = items
+            .iter()
+            .map(|item| self.record(item))
+            .collect::<Result<_, _>>()?;
+        self.storage.put(&self.collection, records)
+    }
+
+    /// Rewrite records stored with a previous layout `L` into the current layout.
+    /// Records that already decode as `T` are left untouched. Returns the number migrated.
+    pub(crate) fn migrate_from<L>(
+        &self,
+        convert: impl Fn(L) -> T,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>>
+    where
+        L: for<'de> Deserialize<'de>,
+    {
+        let mut migrated = Vec::new();
+        for (_, value) in self.storage.scan(&self.collection)? {
+            if bincode::deserialize::<T>(&value).is_ok() {
+                continue;
+            }
+            if let Ok(legacy) = bincode::deserialize::<L>(&value) {
+                migrated.push(self.record(&convert(legacy))?);
+            }
         }
+        let count = migrated.len();
+        if count > 0 {
+            self.storage.put(&self.collection, migrated)?;
+        }
+        Ok(count)
+    }
+}
 
-        self.tree.apply_batch(batch)?;
-        self.tree.flush()?;
-        Ok(())
+fn database_path() -> PathBuf {
+    config_home().join(".wtf_db")
+}
+
+/// The SQLite file lives in the sled directory, so backups and profiles cover both
+fn sqlite_path() -> PathBuf {
+    database_path().join("wtf.sqlite3")
+}
+
+/// The configured backend of the active profile, without encryption
+pub fn open_storage(config: &StorageConfig) -> StorageResult<Arc<dyn Storage>> {
+    match config.backend {
+        StorageBackend::Sled => Ok(Arc::new(SledStorage::open(database_path())?)),
+        StorageBackend::Sqlite => {
+            std::fs::create_dir_all(database_path())?;
+            Ok(Arc::new(SqliteStorage::open(sqlite_path())?))
+        }
+        StorageBackend::Postgres => {
+            let url = config
+                .url
+                .as_ref()
+                .ok_or("the postgres backend needs `url` in the [storage] section")?;
+            let user = config
+                .user
+                .as_deref()
+                .filter(|user| !user.is_empty())
+                .ok_or("the postgres backend needs `user` in the [storage] section")?;
+            Ok(Arc::new(PostgresStorage::connect(url.reveal(), user)?))
+        }
     }
 }
 
-pub static DATABASE: Lazy<Arc<Database>> = Lazy::new(|| {
-    let home = env::var("HOME").expect("HOME env var not set");
+/// `storage` behind the encryption layer when the config asks for it
+fn unlock(storage: Arc<dyn Storage>, config: &StorageConfig) -> StorageResult<Database> {
+    if !config.encrypt {
+        return Ok(Database::with_storage(storage));
+    }
+    if config.backend == StorageBackend::Postgres {
+        return Err("encryption isn't available with the shared postgres backend".into());
+    }
+    let key = DatabaseKey::load()?.ok_or("the database is encrypted but its key is missing")?;
+    Ok(Database::with_storage(Arc::new(EncryptedStorage::new(
+        storage, &key,
+    ))))
+}
 
-    // Check for WTF_CONFIG_HOME environment variable
-    let path = if let Ok(custom_path) = env::var("WTF_CONFIG_HOME") {
-        format!("{}/.wtf_db", custom_path)
-    } else {
-        format!("{}/.config/wtf/.wtf_db", home)
-    };
+/// Whether the database can be opened right now. A sled database is locked while another
+/// wtf process (usually the TUI) runs; callers that must never fail, like shell
+/// completion, check this before touching [`DATABASE`].
+pub fn is_database_available() -> bool {
+    match StorageConfig::load().backend {
+        StorageBackend::Sled => sled::open(database_path()).is_ok(),
+        // SQLite and PostgreSQL let several processes share the database
+        StorageBackend::Sqlite | StorageBackend::Postgres => true,
+    }
+}
+
+pub static DATABASE: Lazy<Arc<Database>> = Lazy::new(|| {
+    let config = StorageConfig::load();
 
-    match Database::new(&path) {
-        Ok(db) => Arc::new(db),
+    let storage = match open_storage(&config) {
+        Ok(storage) => storage,
+        Err(e) if config.backend == StorageBackend::Postgres => {
+            eprintln!("Failed to connect to the PostgreSQL database: {}", e);
+            eprintln!("\nCheck `url` and `user` in the [storage] section of the config");
+            panic!("Could not connect to database: {}", e);
+        }
         Err(e) => {
+            let path = match config.backend {
+                StorageBackend::Sqlite => sqlite_path(),
+                _ => database_path(),
+            }
+            .display()
+            .to_string();
             eprintln!("Failed to open database at {}: {}", path, e);
             eprintln!("\nPossible causes:");
             eprintln!("  1. Another instance of wtf is already running");
@@ -177,5 +279,30 @@ pub static DATABASE: Lazy<Arc<Database>> = Lazy::new(|| {
             eprintln!("  - Check file permissions: ls -la {}", path);
             panic!("Could not create database: {}", e);
         }
+    };
+
+    let database = match unlock(storage, &config) {
+        Ok(db) => db,
+        Err(e) => {
+            eprintln!("Failed to unlock the encrypted database: {}", e);
+            eprintln!("\nIts key is kept in the OS keyring; on machines without one, set");
+            eprintln!("{} to the key (base64).", KEY_ENV);
+            panic!("Could not unlock database: {}", e);
+        }
+    };
+
+    // The PostgreSQL server is backed up on its own side
+    let backup = || match config.backend {
+        StorageBackend::Postgres => Ok(()),
+        _ => BackupStore::production()
+            .create(Some(PRE_MIGRATION_LABEL))
+            .map(|backup| info!("Database backed up to {} before migrating", backup.name))
+            .map_err(Into::into),
+    };
+    if let Err(e) = migration::upgrade(&database, backup) {
+        eprintln!("Failed to upgrade the database: {}", e);
+        eprintln!("\nA backup taken before the upgrade is listed by `wtf backup list`.");
+        panic!("Could not upgrade database: {}", e);
     }
+    Arc::new(database)
 });
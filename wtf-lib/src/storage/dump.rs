@@ -0,0 +1,457 @@
+use crate::models::achievement::AchievementUnlock;
+use crate::models::data::{
+    Absence, Board, GitHubEvent, GitHubSession, Issue, LocalWorklog, LocalWorklogHistory,
+    LocalWorklogState, Meeting, Sprint, Worklog,
+};
+use crate::models::tiered_achievement::TieredProgress;
+use crate::services::meetings_service::{DismissedAbsence, SeriesLink, UntrackedMeeting};
+use crate::storage::database::{CollectionItem, Database, DatabaseError, GenericDatabase};
+use crate::storage::shared::{SplitDatabase, SplitRecord};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::error::Error;
+
+/// Format version written by this build. Bump it when a stored model changes in a way
+/// older dumps can't be read back with `#[serde(default)]`.
+pub const DUMP_VERSION: u32 = 1;
+
+/// Portable snapshot of the local database, for moving to another machine or attaching
+/// to a bug report. Unlike the sled files it is plain JSON with a format version.
+/// Machine-local state (UI state, sync timestamps) is not included.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DatabaseDump {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    #[serde(default)]
+    pub boards: Vec<Board>,
+    #[serde(default)]
+    pub sprints: Vec<Sprint>,
+    #[serde(default)]
+    pub issues: Vec<Issue>,
+    /// Worklogs fetched from Jira
+    #[serde(default)]
+    pub worklogs: Vec<Worklog>,
+    #[serde(default)]
+    pub local_worklogs: Vec<LocalWorklog>,
+    #[serde(default)]
+    pub local_worklogs_history: Vec<LocalWorklogHistory>,
+    #[serde(default)]
+    pub meetings: Vec<Meeting>,
+    /// IDs of meetings manually marked as untracked
+    #[serde(default)]
+    pub untracked_meetings: Vec<String>,
+    /// Issue each linked recurring series goes to, by recurring event ID
+    #[serde(default)]
+    pub meeting_series_links: BTreeMap<String, String>,
+    #[serde(default)]
+    pub absences: Vec<Absence>,
+    /// IDs of out-of-office events I chose not to record as absences
+    #[serde(default)]
+    pub dismissed_absences: Vec<String>,
+    #[serde(default)]
+    pub achievements: Vec<AchievementUnlock>,
+    #[serde(default)]
+    pub tiered_achievements: Vec<TieredProgress>,
+    #[serde(default)]
+    pub github_events: Vec<GitHubEvent>,
+    #[serde(default)]
+    pub github_sessions: Vec<GitHubSession>,
+}
+
+type DumpResult<T> = Result<T, Box<dyn Error + Send + Sync>>;
+
+fn load<T: CollectionItem>(database: &Database, collection: &str) -> DumpResult<Vec<T>> {
+    GenericDatabase::<T>::new(database, collection)?.get_all()
+}
+
+fn restore<T: CollectionItem>(
+    database: &Database,
+    collection: &str,
+    items: Vec<T>,
+    replace: bool,
+) -> DumpResult<()> {
+    let db = GenericDatabase::<T>::new(database, collection)?;
+    if replace {
+        db.clear()?;
+    }
+    db.save_all(items)
+}
+
+/// The collection keeping the user's part of the records of a split `collection`
+fn split<T: SplitRecord>(database: &Database, collection: &str) -> DumpResult<SplitDatabase<T>> {
+    SplitDatabase::new(database, collection, &format!("{}_user_state", collection))
+}
+
+fn load_split<T: SplitRecord>(database: &Database, collection: &str) -> DumpResult<Vec<T>> {
+    split(database, collection)?.get_all()
+}
+
+fn restore_split<T: SplitRecord>(
+    database: &Database,
+    collection: &str,
+    items: Vec<T>,
+    replace: bool,
+) -> DumpResult<()> {
+    let db = split::<T>(database, collection)?;
+    if replace {
+        db.clear()?;
+    }
+    db.save_all(items)
+}
+
+fn remove<T: CollectionItem>(database: &Database, collection: &str, items: &[T]) -> DumpResult<()> {
+    let db = GenericDatabase::<T>::new(database, collection)?;
+    for item in items {
+        db.remove(&item.get_id())?;
+    }
+    Ok(())
+}
+
+impl DatabaseDump {
+    /// Read every exported collection from `database`.
+    pub fn export(database: &Database) -> DumpResult<Self> {
+        let untracked: Vec<UntrackedMeeting> = load(database, "untracked_meetings")?;
+        let series_links: Vec<SeriesLink> = load(database, "meeting_series_links")?;
+        let dismissed: Vec<DismissedAbsence> = load(database, "dismissed_absences")?;
+        Ok(Self {
+            version: DUMP_VERSION,
+            exported_at: Utc::now(),
+            boards: load_split(database, "boards")?,
+            sprints: load_split(database, "sprints")?,
+            issues: load(database, "issues")?,
+            worklogs: load(database, "worklogs")?,
+            local_worklogs: load(database, "local_worklogs")?,
+            local_worklogs_history: load(database, "local_worklogs_history")?,
+            meetings: load(database, "meetings")?,
+            untracked_meetings: untracked.into_iter().map(|u| u.meeting_id).collect(),
+            meeting_series_links: series_links
+                .into_iter()
+                .map(|link| (link.recurring_event_id, link.issue))
+                .collect(),
+            absences: load(database, "absences")?,
+            dismissed_absences: dismissed.into_iter().map(|d| d.event_id).collect(),
+            achievements: load(database, "achievements")?,
+            tiered_achievements: load(database, "tiered_achievements")?,
+            github_events: load(database, "github_events")?,
+            github_sessions: load(database, "github_sessions")?,
+        })
+    }
+
+    /// The records dated before `cutoff`, that `wtf db prune` archives and deletes: Jira
+    /// worklogs, pushed local worklogs and their push history, meetings, GitHub events and
+    /// sessions. Worklogs not pushed yet and recurring meetings are kept.
+    pub fn older_than(database: &Database, cutoff: NaiveDate) -> DumpResult<Self> {
+        let worklogs: Vec<Worklog> = load(database, "worklogs")?;
+        let local_worklogs: Vec<LocalWorklog> = load(database, "local_worklogs")?;
+        let history: Vec<LocalWorklogHistory> = load(database, "local_worklogs_history")?;
+        let meetings: Vec<Meeting> = load(database, "meetings")?;
+        let untracked: Vec<UntrackedMeeting> = load(database, "untracked_meetings")?;
+        let github_events: Vec<GitHubEvent> = load(database, "github_events")?;
+        let github_sessions: Vec<GitHubSession> = load(database, "github_sessions")?;
+
+        let (local_worklogs, kept_worklogs): (Vec<_>, Vec<_>) =
+            local_worklogs.into_iter().partition(|w| {
+                w.status == LocalWorklogState::Pushed && w.started.date_naive() < cutoff
+            });
+        let kept_worklogs: HashSet<String> = kept_worklogs.into_iter().map(|w| w.id).collect();
+        let meetings: Vec<Meeting> = meetings
+            .into_iter()
+            .filter(|m| m.recurrence.is_none() && m.end.date_naive() < cutoff)
+            .collect();
+        let meeting_ids: HashSet<&str> = meetings.iter().map(|m| m.id.as_str()).collect();
+
+        Ok(Self {
+            version: DUMP_VERSION,
+            exported_at: Utc::now(),
+            boards: Vec::new(),
+            sprints: Vec::new(),
+            issues: Vec::new(),
+            worklogs: worklogs
+                .into_iter()
+                .filter(|w| w.started.date_naive() < cutoff)
+                .collect(),
+            // A push stays revertable while one of its worklogs is kept
+            local_worklogs_history: history
+                .into_iter()
+                .filter(|h| {
+                    h.date.date_naive() < cutoff
+                        && !h
+                            .local_worklogs_id
+                            .iter()
+                            .any(|id| kept_worklogs.contains(id))
+                })
+                .collect(),
+            local_worklogs,
+            untracked_meetings: untracked
+                .into_iter()
+                .map(|u| u.meeting_id)
+                .filter(|id| meeting_ids.contains(id.as_str()))
+                .collect(),
+            meetings,
+            meeting_series_links: BTreeMap::new(),
+            absences: Vec::new(),
+            dismissed_absences: Vec::new(),
+            achievements: Vec::new(),
+            tiered_achievements: Vec::new(),
+            github_events: github_events
+                .into_iter()
+                .filter(|e| e.date < cutoff)
+                .collect(),
+            github_sessions: github_sessions
+                .into_iter()
+                .filter(|s| s.date < cutoff)
+                .collect(),
+        })
+    }
+
+    /// Delete the dump's records from `database`, the counterpart of [`Self::import`]
+    pub fn remove_from(&self, database: &Database) -> DumpResult<()> {
+        let untracked: Vec<UntrackedMeeting> = self
+            .untracked_meetings
+            .iter()
+            .map(|meeting_id| UntrackedMeeting {
+                meeting_id: meeting_id.clone(),
+            })
+            .collect();
+        remove(database, "boards", &self.boards)?;
+        remove(database, "sprints", &self.sprints)?;
+        remove(database, "issues", &self.issues)?;
+        remove(database, "worklogs", &self.worklogs)?;
+        remove(database, "local_worklogs", &self.local_worklogs)?;
+        remove(
+            database,
+            "local_worklogs_history",
+            &self.local_worklogs_history,
+        )?;
+        remove(database, "meetings", &self.meetings)?;
+        remove(database, "untracked_meetings", &untracked)?;
+        remove(database, "absences", &self.absences)?;
+        remove(database, "achievements", &self.achievements)?;
+        remove(database, "tiered_achievements", &self.tiered_achievements)?;
+        remove(database, "github_events", &self.github_events)?;
+        remove(database, "github_sessions", &self.github_sessions)?;
+        Ok(())
+    }
+
+    /// Write the dump into `database`. Records are upserted by ID; with `replace`, each
+    /// collection is emptied first so the database ends up identical to the dump.
+    pub fn import(self, database: &Database, replace: bool) -> DumpResult<()> {
+        if self.version > DUMP_VERSION {
+            return Err(Box::new(DatabaseError::DatabaseFailure(format!(
+                "dump format v{} is newer than this version of wtf supports (v{})",
+                self.version, DUMP_VERSION
+            ))));
+        }
+        let untracked = self
+            .untracked_meetings
+            .into_iter()
+            .map(|meeting_id| UntrackedMeeting { meeting_id })
+            .collect();
+        let series_links = self
+            .meeting_series_links
+            .into_iter()
+            .map(|(recurring_event_id, issue)| SeriesLink {
+                recurring_event_id,
+                issue,
+            })
+            .collect();
+        let dismissed = self
+            .dismissed_absences
+            .into_iter()
+            .map(|event_id| DismissedAbsence { event_id })
+            .collect();
+
+        restore_split(database, "boards", self.boards, replace)?;
+        restore_split(database, "sprints", self.sprints, replace)?;
+        restore(database, "issues", self.issues, replace)?;
+        restore(database, "worklogs", self.worklogs, replace)?;
+        restore(database, "local_worklogs", self.local_worklogs, replace)?;
+        restore(
+            database,
+            "local_worklogs_history",
+            self.local_worklogs_history,
+            replace,
+        )?;
+        restore(database, "meetings", self.meetings, replace)?;
+        restore(database, "untracked_meetings", untracked, replace)?;
+        restore(database, "meeting_series_links", series_links, replace)?;
+        restore(database, "absences", self.absences, replace)?;
+        restore(database, "dismissed_absences", dismissed, replace)?;
+        restore(database, "achievements", self.achievements, replace)?;
+        restore(
+            database,
+            "tiered_achievements",
+            self.tiered_achievements,
+            replace,
+        )?;
+        restore(database, "github_events", self.github_events, replace)?;
+        restore(database, "github_sessions", self.github_sessions, replace)?;
+        Ok(())
+    }
+
+    /// Number of records per collection, in dump order
+    pub fn counts(&self) -> Vec<(&'static str, usize)> {
+        vec![
+            ("boards", self.boards.len()),
+            ("sprints", self.sprints.len()),
+            ("issues", self.issues.len()),
+            ("worklogs", self.worklogs.len()),
+            ("local worklogs", self.local_worklogs.len()),
+            ("worklog history", self.local_worklogs_history.len()),
+            ("meetings", self.meetings.len()),
+            ("untracked meetings", self.untracked_meetings.len()),
+            ("meeting series links", self.meeting_series_links.len()),
+            ("absences", self.absences.len()),
+            ("dismissed absences", self.dismissed_absences.len()),
+            ("achievements", self.achievements.len()),
+            ("tiered achievements", self.tiered_achievements.len()),
+            ("GitHub events", self.github_events.len()),
+            ("GitHub sessions", self.github_sessions.len()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::data::BoardType;
+    use chrono::{NaiveDate, TimeZone};
+    use std::collections::HashMap;
+
+    fn board(id: usize) -> Board {
+        Board {
+            id,
+            name: format!("Board {}", id),
+            board_type: BoardType::Scrum,
+            followed: true,
+            project_name: None,
+        }
+    }
+
+    fn meeting() -> Meeting {
+        let start = Utc.with_ymd_and_hms(2026, 1, 5, 9, 0, 0).unwrap();
+        Meeting {
+            id: "daily".to_string(),
+            title: Some("Daily".to_string()),
+            description: None,
+            start,
+            end: start + chrono::Duration::minutes(15),
+            attendees: None,
+            jira_link: Some("PROJ-1".to_string()),
+            recurrence: None,
+            logs: HashMap::from([(NaiveDate::from_ymd_opt(2026, 1, 5).unwrap(), "42".into())]),
+            my_response_status: None,
+            color_id: None,
+            organizer: None,
+            conference_link: None,
+            calendar: None,
+            recurring_event_id: None,
+            event_type: None,
+        }
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let source = Database::temporary();
+        GenericDatabase::new(&source, "boards")
+            .unwrap()
+            .save_all(vec![board(1), board(2)])
+            .unwrap();
+        GenericDatabase::new(&source, "meetings")
+            .unwrap()
+            .insert(&meeting())
+            .unwrap();
+        GenericDatabase::new(&source, "untracked_meetings")
+            .unwrap()
+            .insert(&UntrackedMeeting {
+                meeting_id: "lunch".to_string(),
+            })
+            .unwrap();
+
+        let json = serde_json::to_string(&DatabaseDump::export(&source).unwrap()).unwrap();
+        let target = Database::temporary();
+        GenericDatabase::new(&target, "boards")
+            .unwrap()
+            .insert(&board(3))
+            .unwrap();
+        let dump: DatabaseDump = serde_json::from_str(&json).unwrap();
+        dump.import(&target, true).unwrap();
+
+        let restored = DatabaseDump::export(&target).unwrap();
+        let mut board_ids: Vec<_> = restored.boards.iter().map(|b| b.id).collect();
+        board_ids.sort();
+        assert_eq!(board_ids, vec![1, 2]);
+        assert_eq!(restored.meetings[0].logs.len(), 1);
+        assert_eq!(restored.untracked_meetings, vec!["lunch"]);
+    }
+
+    #[test]
+    fn test_import_merges_and_rejects_newer_versions() {
+        let target = Database::temporary();
+        GenericDatabase::new(&target, "boards")
+            .unwrap()
+            .insert(&board(3))
+            .unwrap();
+        let mut dump = DatabaseDump::export(&Database::temporary()).unwrap();
+        dump.boards.push(board(1));
+        dump.clone().import(&target, false).unwrap();
+        assert_eq!(DatabaseDump::export(&target).unwrap().boards.len(), 2);
+
+        dump.version = DUMP_VERSION + 1;
+        assert!(dump.import(&target, true).is_err());
+    }
+
+    #[test]
+    fn test_prune_takes_old_records_out() {
+        let database = Database::temporary();
+        let old = Utc.with_ymd_and_hms(2025, 6, 2, 9, 0, 0).unwrap();
+        let local_worklog = |id: &str, started, status| LocalWorklog {
+            id: id.to_string(),
+            comment: String::new(),
+            time_spent_seconds: 3600,
+            issue_id: "PROJ-1".to_string(),
+            status,
+            started,
+            meeting_id: None,
+            worklog_id: None,
+        };
+        GenericDatabase::new(&database, "local_worklogs")
+            .unwrap()
+            .save_all(vec![
+                local_worklog("pushed", old, LocalWorklogState::Pushed),
+                local_worklog("staged", old, LocalWorklogState::Staged),
+                local_worklog("recent", meeting().start, LocalWorklogState::Pushed),
+            ])
+            .unwrap();
+        let mut old_meeting = meeting();
+        old_meeting.id = "old".to_string();
+        old_meeting.start = old;
+        old_meeting.end = old + chrono::Duration::minutes(30);
+        let mut recurring = old_meeting.clone();
+        recurring.id = "weekly".to_string();
+        recurring.recurrence = Some(vec!["RRULE:FREQ=WEEKLY".to_string()]);
+        GenericDatabase::new(&database, "meetings")
+            .unwrap()
+            .save_all(vec![meeting(), old_meeting, recurring])
+            .unwrap();
+
+        let cutoff = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let pruned = DatabaseDump::older_than(&database, cutoff).unwrap();
+        pruned.remove_from(&database).unwrap();
+
+        let ids = |dump: &DatabaseDump| {
+            let mut ids: Vec<String> = dump
+                .local_worklogs
+                .iter()
+                .map(|w| w.id.clone())
+                .chain(dump.meetings.iter().map(|m| m.id.clone()))
+                .collect();
+            ids.sort();
+            ids
+        };
+        assert_eq!(ids(&pruned), vec!["old", "pushed"]);
+        let remaining = DatabaseDump::export(&database).unwrap();
+        assert_eq!(ids(&remaining), vec!["daily", "recent", "staged", "weekly"]);
+    }
+}
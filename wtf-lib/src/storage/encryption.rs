@@ -0,0 +1,260 @@
+use crate::secrets::Secret;
+use crate::storage::backend::{Record, Storage, StorageResult};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use std::env;
+use std::sync::Arc;
+
+/// Prefix of encrypted values, followed by the nonce and the ciphertext
+const MAGIC: &[u8] = b"wtf\0enc1";
+const NONCE_LEN: usize = 24;
+
+/// Environment variable holding the key (base64) on machines without an OS keyring
+pub const KEY_ENV: &str = "WTF_DATABASE_KEY";
+
+/// The 256-bit key the database records are encrypted with, one per profile
+#[derive(Clone)]
+pub struct DatabaseKey(Key);
+
+impl DatabaseKey {
+    pub fn generate() -> Self {
+        Self(XChaCha20Poly1305::generate_key(&mut OsRng))
+    }
+
+    pub fn from_base64(encoded: &str) -> StorageResult<Self> {
+        let bytes = STANDARD.decode(encoded.trim())?;
+        if bytes.len() != 32 {
+            return Err("a database key is 32 bytes long".into());
+        }
+        Ok(Self(*Key::from_slice(&bytes)))
+    }
+
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(self.0)
+    }
+
+    /// The key from `$WTF_DATABASE_KEY`, or else from the OS keyring; `None` when
+    /// neither has one
+    pub fn load() -> StorageResult<Option<Self>> {
+        if let Ok(encoded) = env::var(KEY_ENV) {
+            return Self::from_base64(&encoded).map(Some);
+        }
+        Secret::DatabaseKey
+            .get()?
+            .map(|encoded| Self::from_base64(&encoded))
+            .transpose()
+    }
+
+    /// The existing key, or a new one saved in the OS keyring
+    pub fn load_or_create() -> StorageResult<Self> {
+        if let Some(key) = Self::load()? {
+            return Ok(key);
+        }
+        let key = Self::generate();
+        Secret::DatabaseKey.set(&key.to_base64())?;
+        Ok(key)
+    }
+}
+
+/// Encrypts the records of another backend with XChaCha20-Poly1305. Keys stay in clear,
+/// JSON documents aren't stored. Records not encrypted yet are read as they are, so a
+/// database can be used while [`encrypt_all`] runs.
+pub struct EncryptedStorage {
+    inner: Arc<dyn Storage>,
+    cipher: XChaCha20Poly1305,
+}
+
+impl EncryptedStorage {
+    pub fn new(inner: Arc<dyn Storage>, key: &DatabaseKey) -> Self {
+        Self {
+            inner,
+            cipher: XChaCha20Poly1305::new(&key.0),
+        }
+    }
+
+    fn seal(&self, plaintext: &[u8]) -> StorageResult<Vec<u8>> {
+        seal(&self.cipher, plaintext)
+    }
+
+    fn open(&self, value: Vec<u8>) -> StorageResult<Vec<u8>> {
+        open(&self.cipher, value)
+    }
+}
+
+fn seal(cipher: &XChaCha20Poly1305, plaintext: &[u8]) -> StorageResult<Vec<u8>> {
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext)
+        .map_err(|_| "failed to encrypt a record")?;
+    Ok([MAGIC, nonce.as_slice(), &ciphertext].concat())
+}
+
+fn open(cipher: &XChaCha20Poly1305, value: Vec<u8>) -> StorageResult<Vec<u8>> {
+    let Some(sealed) = value.strip_prefix(MAGIC) else {
+        return Ok(value);
+    };
+    if sealed.len() < NONCE_LEN {
+        return Err("truncated encrypted record".into());
+    }
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+    Ok(cipher
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "can't decrypt the database: wrong key?")?)
+}
+
+fn is_sealed(value: &[u8]) -> bool {
+    value.starts_with(MAGIC)
+}
+
+impl Storage for EncryptedStorage {
+    fn get(&self, collection: &str, key: &str) -> StorageResult<Option<Vec<u8>>> {
+        self.inner
+            .get(collection, key)?
+            .map(|value| self.open(value))
+            .transpose()
+    }
+
+    fn scan(&self, collection: &str) -> StorageResult<Vec<(String, Vec<u8>)>> {
+        self.inner
+            .scan(collection)?
+            .into_iter()
+            .map(|(key, value)| Ok((key, self.open(value)?)))
+            .collect()
+    }
+
+    fn put(&self, collection: &str, records: Vec<Record>) -> StorageResult<()> {
+        let records = records
+            .into_iter()
+            .map(|record| {
+                Ok(Record {
+                    value: self.seal(&record.value)?,
+                    document: None,
+                    ..record
+                })
+            })
+            .collect::<StorageResult<_>>()?;
+        self.inner.put(collection, records)
+    }
+
+    fn remove(&self, collection: &str, keys: &[String]) -> StorageResult<()> {
+        self.inner.remove(collection, keys)
+    }
+
+    fn clear(&self, collection: &str) -> StorageResult<()> {
+        self.inner.clear(collection)
+    }
+
+    fn collections(&self) -> StorageResult<Vec<String>> {
+        self.inner.collections()
+    }
+}
+
+/// Encrypt every record of `storage` not encrypted yet. Returns how many were.
+pub fn encrypt_all(storage: &dyn Storage, key: &DatabaseKey) -> StorageResult<usize> {
+    let cipher = XChaCha20Poly1305::new(&key.0);
+    rewrite(storage, |value| {
+        (!is_sealed(value))
+            .then(|| seal(&cipher, value))
+            .transpose()
+    })
+}
+
+/// Decrypt every encrypted record of `storage`. Returns how many were.
+pub fn decrypt_all(storage: &dyn Storage, key: &DatabaseKey) -> StorageResult<usize> {
+    let cipher = XChaCha20Poly1305::new(&key.0);
+    rewrite(storage, |value| {
+        is_sealed(value)
+            .then(|| open(&cipher, value.to_vec()))
+            .transpose()
+    })
+}
+
+/// Replace the values `convert` returns something for, one transaction per collection
+fn rewrite(
+    storage: &dyn Storage,
+    convert: impl Fn(&[u8]) -> StorageResult<Option<Vec<u8>>>,
+) -> StorageResult<usize> {
+    let mut rewritten = 0;
+    for collection in storage.collections()? {
+        let mut records = Vec::new();
+        for (key, value) in storage.scan(&collection)? {
+            if let Some(value) = convert(&value)? {
+                records.push(Record {
+                    key,
+                    value,
+                    document: None,
+                });
+            }
+        }
+        rewritten += records.len();
+        if !records.is_empty() {
+            storage.put(&collection, records)?;
+        }
+    }
+    Ok(rewritten)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::data::{Board, BoardType};
+    use crate::storage::backend::SledStorage;
+    use crate::storage::database::{Database, GenericDatabase};
+
+    fn board(id: usize, name: &str) -> Board {
+        Board {
+            id,
+            name: name.to_string(),
+            board_type: BoardType::Scrum,
+            followed: true,
+            project_name: None,
+        }
+    }
+
+    #[test]
+    fn test_encrypt_all_then_read_through_the_encrypted_storage() {
+        let raw: Arc<dyn Storage> = Arc::new(SledStorage::temporary());
+        let plain: GenericDatabase<Board> =
+            GenericDatabase::new(&Database::with_storage(raw.clone()), "boards").unwrap();
+        plain
+            .save_all(vec![board(1, "Secret project"), board(2, "Other")])
+            .unwrap();
+        let key = DatabaseKey::generate();
+
+        assert_eq!(encrypt_all(raw.as_ref(), &key).unwrap(), 2);
+        assert_eq!(encrypt_all(raw.as_ref(), &key).unwrap(), 0);
+        let stored = raw.get("boards", "1").unwrap().unwrap();
+        assert!(is_sealed(&stored));
+        assert!(!String::from_utf8_lossy(&stored).contains("Secret"));
+
+        let encrypted = Database::with_storage(Arc::new(EncryptedStorage::new(raw.clone(), &key)));
+        let boards: GenericDatabase<Board> = GenericDatabase::new(&encrypted, "boards").unwrap();
+        boards.insert(&board(3, "New")).unwrap();
+        assert_eq!(boards.get("1").unwrap().unwrap().name, "Secret project");
+        assert_eq!(boards.get_all().unwrap().len(), 3);
+
+        assert_eq!(decrypt_all(raw.as_ref(), &key).unwrap(), 3);
+        assert_eq!(plain.get_all().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_a_wrong_key_fails_instead_of_dropping_records() {
+        let raw: Arc<dyn Storage> = Arc::new(SledStorage::temporary());
+        let key = DatabaseKey::generate();
+        let encrypted = Database::with_storage(Arc::new(EncryptedStorage::new(raw.clone(), &key)));
+        GenericDatabase::new(&encrypted, "boards")
+            .unwrap()
+            .insert(&board(1, "Board"))
+            .unwrap();
+
+        let other = DatabaseKey::generate();
+        let wrong = Database::with_storage(Arc::new(EncryptedStorage::new(raw.clone(), &other)));
+        let boards: GenericDatabase<Board> = GenericDatabase::new(&wrong, "boards").unwrap();
+        assert!(boards.get_all().is_err());
+        assert!(raw.get("boards", "1").unwrap().is_some());
+
+        let restored = DatabaseKey::from_base64(&key.to_base64()).unwrap();
+        assert_eq!(decrypt_all(raw.as_ref(), &restored).unwrap(), 1);
+    }
+}
@@ -0,0 +1,721 @@
+use crate::models::data::{Attendee, Board, Issue, Meeting, Sprint};
+use crate::storage::backend::{Record, StorageResult};
+use crate::storage::database::{Database, DatabaseError, GenericDatabase, Identifiable};
+use crate::storage::shared::{SplitDatabase, SplitRecord};
+use chrono::{DateTime, NaiveDate, Utc};
+use log::info;
+use std::collections::HashMap;
+
+/// Schema version of the databases written by this build. Changing the layout of a
+/// stored struct means bumping it and appending the [`Migration`] that upgrades the
+/// records to [`MIGRATIONS`].
+pub const SCHEMA_VERSION: u32 = 8;
+
+/// Collection holding the schema version marker
+const SCHEMA_COLLECTION: &str = "schema";
+const VERSION_KEY: &str = "version";
+
+/// One step of the schema history: brings a database from `version - 1` to `version`
+pub struct Migration {
+    pub version: u32,
+    pub description: &'static str,
+    /// Rewrites the records, returning how many changed
+    run: fn(&Database) -> StorageResult<usize>,
+}
+
+/// Every migration, in version order
+pub const MIGRATIONS: [Migration; 8] = [
+    Migration {
+        version: 1,
+        description: "issues remember the sprints they were seen in",
+        run: |database| {
+            GenericDatabase::<LegacyIssueV2>::new(database, "issues")?
+                .migrate_from::<LegacyIssueV1>(LegacyIssueV2::from)
+        },
+    },
+    Migration {
+        version: 2,
+        description: "meetings keep attendee responses, organizer and conference link",
+        run: |database| {
+            GenericDatabase::<LegacyMeetingV4>::new(database, "meetings")?
+                .migrate_from::<LegacyMeetingV1>(LegacyMeetingV4::from)
+        },
+    },
+    Migration {
+        version: 3,
+        description: "issues know their epic, components and labels",
+        run: |database| {
+            GenericDatabase::<LegacyIssueV3>::new(database, "issues")?
+                .migrate_from::<LegacyIssueV2>(LegacyIssueV3::from)
+        },
+    },
+    Migration {
+        version: 4,
+        description: "issues know their assignee",
+        run: |database| {
+            GenericDatabase::<Issue>::new(database, "issues")?
+                .migrate_from::<LegacyIssueV3>(Issue::from)
+        },
+    },
+    Migration {
+        version: 5,
+        description: "meetings remember the calendar they come from",
+        run: |database| {
+            GenericDatabase::<LegacyMeetingV5>::new(database, "meetings")?
+                .migrate_from::<LegacyMeetingV4>(LegacyMeetingV5::from)
+        },
+    },
+    Migration {
+        version: 6,
+        description: "meetings know the recurring event they belong to",
+        run: |database| {
+            GenericDatabase::<LegacyMeetingV6>::new(database, "meetings")?
+                .migrate_from::<LegacyMeetingV5>(LegacyMeetingV6::from)
+        },
+    },
+    Migration {
+        version: 7,
+        description: "meetings know their Google event type",
+        run: |database| {
+            GenericDatabase::<Meeting>::new(database, "meetings")?
+                .migrate_from::<LegacyMeetingV6>(Meeting::from)
+        },
+    },
+    Migration {
+        version: 8,
+        description: "followed boards and sprints, and sprint workdays, are kept per user",
+        run: |database| {
+            Ok(split_user_state::<Board>(database, "boards")?
+                + split_user_state::<Sprint>(database, "sprints")?)
+        },
+    },
+];
+
+/// What [`upgrade`] did to a database
+#[derive(Debug, PartialEq, Eq)]
+pub struct Upgrade {
+    pub from: u32,
+    pub to: u32,
+    /// Description and number of records changed of each migration run
+    pub migrations: Vec<(&'static str, usize)>,
+}
+
+/// The schema version recorded in `database`; `None` for databases written before
+/// versioning
+pub fn schema_version(database: &Database) -> StorageResult<Option<u32>> {
+    match database.storage().get(SCHEMA_COLLECTION, VERSION_KEY)? {
+        Some(value) => Ok(Some(bincode::deserialize(&value)?)),
+        None => Ok(None),
+    }
+}
+
+fn set_schema_version(database: &Database, version: u32) -> StorageResult<()> {
+    database.storage().put(
+        SCHEMA_COLLECTION,
+        vec![Record {
+            key: VERSION_KEY.to_string(),
+            value: bincode::serialize(&version)?,
+            document: None,
+        }],
+    )
+}
+
+/// Bring `database` to [`SCHEMA_VERSION`] by running, in order, the migrations it hasn't
+/// had yet; `backup` is called once before the first of them, and an error from it stops
+/// the upgrade. A database without a version marker predates versioning and goes through
+/// every migration (they leave alone the records already in the current layout); an
+/// empty one is only marked. `None` when there was nothing to do.
+pub fn upgrade(
+    database: &Database,
+    backup: impl FnOnce() -> StorageResult<()>,
+) -> StorageResult<Option<Upgrade>> {
+    let version = match schema_version(database)? {
+        Some(version) => version,
+        None if is_empty(database)? => {
+            set_schema_version(database, SCHEMA_VERSION)?;
+            return Ok(None);
+        }
+        None => 0,
+    };
+    if version > SCHEMA_VERSION {
+        return Err(Box::new(DatabaseError::DatabaseFailure(format!(
+            "the database has schema v{}, newer than this version of wtf supports (v{}); \
+             upgrade wtf",
+            version, SCHEMA_VERSION
+        ))));
+    }
+    if version == SCHEMA_VERSION {
+        return Ok(None);
+    }
+
+    backup()?;
+    let mut migrations = Vec::new();
+    for migration in MIGRATIONS.iter().filter(|m| m.version > version) {
+        let count = (migration.run)(database)?;
+        // Recorded step by step, so an interrupted upgrade resumes where it stopped
+        set_schema_version(database, migration.version)?;
+        info!(
+            "Database migrated to schema v{} ({}): {} records",
+            migration.version, migration.description, count
+        );
+        migrations.push((migration.description, count));
+    }
+    Ok(Some(Upgrade {
+        from: version,
+        to: SCHEMA_VERSION,
+        migrations,
+    }))
+}
+
+/// Move the user's part of the records of `collection` to its own collection
+fn split_user_state<T: SplitRecord>(database: &Database, collection: &str) -> StorageResult<usize> {
+    let records = GenericDatabase::<T>::new(database, collection)?.get_all()?;
+    let count = records.len();
+    SplitDatabase::new(database, collection, &format!("{}_user_state", collection))?
+        .save_all(records)?;
+    Ok(count)
+}
+
+fn is_empty(database: &Database) -> StorageResult<bool> {
+    let storage = database.storage();
+    for collection in storage.collections()? {
+        if collection != SCHEMA_COLLECTION && !storage.scan(&collection)?.is_empty() {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+// --- Legacy issue layout (before sprint membership) ---
+
+#[derive(serde::Deserialize)]
+struct LegacyIssueV1 {
+    id: String,
+    key: String,
+    summary: String,
+    status: String,
+    created: DateTime<Utc>,
+}
+
+impl From<LegacyIssueV1> for LegacyIssueV2 {
+    fn from(legacy: LegacyIssueV1) -> Self {
+        LegacyIssueV2 {
+            id: legacy.id,
+            key: legacy.key,
+            summary: legacy.summary,
+            status: legacy.status,
+            created: legacy.created,
+            sprint_ids: Vec::new(),
+        }
+    }
+}
+
+// --- Legacy issue layout (before epics, components and labels) ---
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+struct LegacyIssueV2 {
+    id: String,
+    key: String,
+    summary: String,
+    status: String,
+    created: DateTime<Utc>,
+    sprint_ids: Vec<usize>,
+}
+
+impl Identifiable for LegacyIssueV2 {
+    fn get_id(&self) -> String {
+        self.key.clone()
+    }
+}
+
+impl From<LegacyIssueV2> for LegacyIssueV3 {
+    fn from(legacy: LegacyIssueV2) -> Self {
+        LegacyIssueV3 {
+            id: legacy.id,
+            key: legacy.key,
+            summary: legacy.summary,
+            status: legacy.status,
+            created: legacy.created,
+            sprint_ids: legacy.sprint_ids,
+            epic: None,
+            components: Vec::new(),
+            labels: Vec::new(),
+        }
+    }
+}
+
+// --- Legacy issue layout (before the assignee) ---
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+struct LegacyIssueV3 {
+    id: String,
+    key: String,
+    summary: String,
+    status: String,
+    created: DateTime<Utc>,
+    sprint_ids: Vec<usize>,
+    epic: Option<String>,
+    components: Vec<String>,
+    labels: Vec<String>,
+}
+
+impl Identifiable for LegacyIssueV3 {
+    fn get_id(&self) -> String {
+        self.key.clone()
+    }
+}
+
+impl From<LegacyIssueV3> for Issue {
+    fn from(legacy: LegacyIssueV3) -> Self {
+        Issue {
+            id: legacy.id,
+            key: legacy.key,
+            summary: legacy.summary,
+            status: legacy.status,
+            created: legacy.created,
+            sprint_ids: legacy.sprint_ids,
+            epic: legacy.epic,
+            components: legacy.components,
+            labels: legacy.labels,
+            assignee: None,
+        }
+    }
+}
+
+// --- Legacy meeting layout (before organizer/conference link/attendee responses) ---
+
+#[derive(serde::Deserialize)]
+struct LegacyAttendeeV1 {
+    display_name: Option<String>,
+    comment: Option<String>,
+    email: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct LegacyMeetingV1 {
+    id: String,
+    title: Option<String>,
+    description: Option<String>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    attendees: Option<Vec<LegacyAttendeeV1>>,
+    jira_link: Option<String>,
+    recurrence: Option<Vec<String>>,
+    logs: HashMap<NaiveDate, String>,
+    my_response_status: Option<String>,
+    color_id: Option<String>,
+}
+
+impl From<LegacyMeetingV1> for LegacyMeetingV4 {
+    fn from(legacy: LegacyMeetingV1) -> Self {
+        LegacyMeetingV4 {
+            id: legacy.id,
+            title: legacy.title,
+            description: legacy.description,
+            start: legacy.start,
+            end: legacy.end,
+            attendees: legacy.attendees.map(|attendees| {
+                attendees
+                    .into_iter()
+                    .map(|a| Attendee {
+                        display_name: a.display_name,
+                        comment: a.comment,
+                        email: a.email,
+                        response_status: None,
+                        organizer: false,
+                    })
+                    .collect()
+            }),
+            jira_link: legacy.jira_link,
+            recurrence: legacy.recurrence,
+            logs: legacy.logs,
+            my_response_status: legacy.my_response_status,
+            color_id: legacy.color_id,
+            organizer: None,
+            conference_link: None,
+        }
+    }
+}
+
+// --- Legacy meeting layout (before the calendar) ---
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+struct LegacyMeetingV4 {
+    id: String,
+    title: Option<String>,
+    description: Option<String>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    attendees: Option<Vec<Attendee>>,
+    jira_link: Option<String>,
+    recurrence: Option<Vec<String>>,
+    logs: HashMap<NaiveDate, String>,
+    my_response_status: Option<String>,
+    color_id: Option<String>,
+    organizer: Option<String>,
+    conference_link: Option<String>,
+}
+
+impl Identifiable for LegacyMeetingV4 {
+    fn get_id(&self) -> String {
+        self.id.clone()
+    }
+}
+
+impl From<LegacyMeetingV4> for LegacyMeetingV5 {
+    fn from(legacy: LegacyMeetingV4) -> Self {
+        LegacyMeetingV5 {
+            id: legacy.id,
+            title: legacy.title,
+            description: legacy.description,
+            start: legacy.start,
+            end: legacy.end,
+            attendees: legacy.attendees,
+            jira_link: legacy.jira_link,
+            recurrence: legacy.recurrence,
+            logs: legacy.logs,
+            my_response_status: legacy.my_response_status,
+            color_id: legacy.color_id,
+            organizer: legacy.organizer,
+            conference_link: legacy.conference_link,
+            calendar: None,
+        }
+    }
+}
+
+// --- Legacy meeting layout (before the recurring event id) ---
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+struct LegacyMeetingV5 {
+    id: String,
+    title: Option<String>,
+    description: Option<String>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    attendees: Option<Vec<Attendee>>,
+    jira_link: Option<String>,
+    recurrence: Option<Vec<String>>,
+    logs: HashMap<NaiveDate, String>,
+    my_response_status: Option<String>,
+    color_id: Option<String>,
+    organizer: Option<String>,
+    conference_link: Option<String>,
+    calendar: Option<String>,
+}
+
+impl Identifiable for LegacyMeetingV5 {
+    fn get_id(&self) -> String {
+        self.id.clone()
+    }
+}
+
+impl From<LegacyMeetingV5> for LegacyMeetingV6 {
+    fn from(legacy: LegacyMeetingV5) -> Self {
+        LegacyMeetingV6 {
+            id: legacy.id,
+            title: legacy.title,
+            description: legacy.description,
+            start: legacy.start,
+            end: legacy.end,
+            attendees: legacy.attendees,
+            jira_link: legacy.jira_link,
+            recurrence: legacy.recurrence,
+            logs: legacy.logs,
+            my_response_status: legacy.my_response_status,
+            color_id: legacy.color_id,
+            organizer: legacy.organizer,
+            conference_link: legacy.conference_link,
+            calendar: legacy.calendar,
+            recurring_event_id: None,
+        }
+    }
+}
+
+// --- Legacy meeting layout (before the event type) ---
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+struct LegacyMeetingV6 {
+    id: String,
+    title: Option<String>,
+    description: Option<String>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    attendees: Option<Vec<Attendee>>,
+    jira_link: Option<String>,
+    recurrence: Option<Vec<String>>,
+    logs: HashMap<NaiveDate, String>,
+    my_response_status: Option<String>,
+    color_id: Option<String>,
+    organizer: Option<String>,
+    conference_link: Option<String>,
+    calendar: Option<String>,
+    recurring_event_id: Option<String>,
+}
+
+impl Identifiable for LegacyMeetingV6 {
+    fn get_id(&self) -> String {
+        self.id.clone()
+    }
+}
+
+impl From<LegacyMeetingV6> for Meeting {
+    fn from(legacy: LegacyMeetingV6) -> Self {
+        Meeting {
+            id: legacy.id,
+            title: legacy.title,
+            description: legacy.description,
+            start: legacy.start,
+            end: legacy.end,
+            attendees: legacy.attendees,
+            jira_link: legacy.jira_link,
+            recurrence: legacy.recurrence,
+            logs: legacy.logs,
+            my_response_status: legacy.my_response_status,
+            color_id: legacy.color_id,
+            organizer: legacy.organizer,
+            conference_link: legacy.conference_link,
+            calendar: legacy.calendar,
+            recurring_event_id: legacy.recurring_event_id,
+            event_type: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn no_backup() -> StorageResult<()> {
+        panic!("no backup expected")
+    }
+
+    #[test]
+    fn test_new_database_is_marked_without_migrating() {
+        let db = Database::temporary();
+        assert_eq!(upgrade(&db, no_backup).unwrap(), None);
+        assert_eq!(schema_version(&db).unwrap(), Some(SCHEMA_VERSION));
+        // Already current
+        assert_eq!(upgrade(&db, no_backup).unwrap(), None);
+    }
+
+    #[test]
+    fn test_newer_schema_is_refused() {
+        let db = Database::temporary();
+        set_schema_version(&db, SCHEMA_VERSION + 1).unwrap();
+        let error = upgrade(&db, no_backup).unwrap_err();
+        assert!(error.to_string().contains("upgrade wtf"), "{}", error);
+    }
+
+    #[test]
+    fn test_migrations_are_in_version_order() {
+        let versions: Vec<u32> = MIGRATIONS.iter().map(|m| m.version).collect();
+        assert_eq!(versions, (1..=SCHEMA_VERSION).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_unversioned_database_is_migrated_after_a_backup() {
+        #[derive(serde::Serialize, serde::Deserialize, Clone)]
+        struct LegacyAttendee {
+            display_name: Option<String>,
+            comment: Option<String>,
+            email: Option<String>,
+        }
+        #[derive(serde::Serialize, serde::Deserialize, Clone)]
+        struct Legacy {
+            id: String,
+            title: Option<String>,
+            description: Option<String>,
+            start: DateTime<Utc>,
+            end: DateTime<Utc>,
+            attendees: Option<Vec<LegacyAttendee>>,
+            jira_link: Option<String>,
+            recurrence: Option<Vec<String>>,
+            logs: HashMap<NaiveDate, String>,
+            my_response_status: Option<String>,
+            color_id: Option<String>,
+        }
+        impl crate::storage::database::Identifiable for Legacy {
+            fn get_id(&self) -> String {
+                self.id.clone()
+            }
+        }
+
+        let db = Database::temporary();
+        let start = Utc.with_ymd_and_hms(2024, 1, 10, 9, 0, 0).unwrap();
+        let legacy_db: GenericDatabase<Legacy> = GenericDatabase::new(&db, "meetings").unwrap();
+        legacy_db
+            .insert(&Legacy {
+                id: "old".to_string(),
+                title: Some("Daily".to_string()),
+                description: None,
+                start,
+                end: start + Duration::minutes(15),
+                attendees: Some(vec![LegacyAttendee {
+                    display_name: Some("Ada".to_string()),
+                    comment: None,
+                    email: None,
+                }]),
+                jira_link: Some("PROJ-1".to_string()),
+                recurrence: None,
+                logs: HashMap::new(),
+                my_response_status: Some("accepted".to_string()),
+                color_id: None,
+            })
+            .unwrap();
+
+        let mut backups = 0;
+        let upgrade = upgrade(&db, || {
+            backups += 1;
+            Ok(())
+        })
+        .unwrap()
+        .unwrap();
+        assert_eq!(backups, 1);
+        assert_eq!((upgrade.from, upgrade.to), (0, SCHEMA_VERSION));
+        assert_eq!(upgrade.migrations.len(), MIGRATIONS.len());
+        assert_eq!(schema_version(&db).unwrap(), Some(SCHEMA_VERSION));
+
+        let meeting = GenericDatabase::<Meeting>::new(&db, "meetings")
+            .unwrap()
+            .get("old")
+            .unwrap()
+            .unwrap();
+        assert_eq!(meeting.jira_link.as_deref(), Some("PROJ-1"));
+        assert_eq!(
+            meeting.attendees.unwrap()[0].display_name.as_deref(),
+            Some("Ada")
+        );
+        assert!(meeting.conference_link.is_none());
+    }
+
+    fn legacy_meeting_v4(id: &str) -> LegacyMeetingV4 {
+        let start = Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+        LegacyMeetingV4 {
+            id: id.to_string(),
+            title: Some("Daily".to_string()),
+            description: None,
+            start,
+            end: start + Duration::minutes(15),
+            attendees: None,
+            jira_link: Some("PROJ-1".to_string()),
+            recurrence: None,
+            logs: HashMap::new(),
+            my_response_status: None,
+            color_id: None,
+            organizer: Some("Ada".to_string()),
+            conference_link: Some("https://meet.example.com/abc".to_string()),
+        }
+    }
+
+    fn migrated_meeting(db: &Database, id: &str) -> Meeting {
+        GenericDatabase::<Meeting>::new(db, "meetings")
+            .unwrap()
+            .get(id)
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_v4_meetings_keep_their_link_when_gaining_a_calendar() {
+        let db = Database::temporary();
+        GenericDatabase::<LegacyMeetingV4>::new(&db, "meetings")
+            .unwrap()
+            .insert(&legacy_meeting_v4("v4"))
+            .unwrap();
+        set_schema_version(&db, 4).unwrap();
+
+        let upgrade = upgrade(&db, || Ok(())).unwrap().unwrap();
+
+        assert_eq!(upgrade.from, 4);
+        assert_eq!(upgrade.migrations.len(), MIGRATIONS.len() - 4);
+        assert_eq!(upgrade.migrations[0].1, 1);
+        let meeting = migrated_meeting(&db, "v4");
+        assert_eq!(meeting.jira_link.as_deref(), Some("PROJ-1"));
+        assert_eq!(meeting.organizer.as_deref(), Some("Ada"));
+        assert!(meeting.calendar.is_none());
+    }
+
+    #[test]
+    fn test_v5_meetings_keep_their_calendar_when_gaining_a_series() {
+        let db = Database::temporary();
+        let legacy = LegacyMeetingV5 {
+            calendar: Some("team@group.calendar.google.com".to_string()),
+            ..LegacyMeetingV5::from(legacy_meeting_v4("v5"))
+        };
+        GenericDatabase::<LegacyMeetingV5>::new(&db, "meetings")
+            .unwrap()
+            .insert(&legacy)
+            .unwrap();
+        set_schema_version(&db, 5).unwrap();
+
+        let upgrade = upgrade(&db, || Ok(())).unwrap().unwrap();
+
+        assert_eq!(upgrade.migrations[0].1, 1);
+        let meeting = migrated_meeting(&db, "v5");
+        assert_eq!(meeting.jira_link.as_deref(), Some("PROJ-1"));
+        assert_eq!(
+            meeting.calendar.as_deref(),
+            Some("team@group.calendar.google.com")
+        );
+        assert!(meeting.recurring_event_id.is_none());
+    }
+
+    #[test]
+    fn test_v6_meetings_keep_their_series_when_gaining_an_event_type() {
+        let db = Database::temporary();
+        let legacy = LegacyMeetingV6 {
+            recurring_event_id: Some("daily-series".to_string()),
+            ..LegacyMeetingV6::from(LegacyMeetingV5::from(legacy_meeting_v4("v6")))
+        };
+        GenericDatabase::<LegacyMeetingV6>::new(&db, "meetings")
+            .unwrap()
+            .insert(&legacy)
+            .unwrap();
+        set_schema_version(&db, 6).unwrap();
+
+        let upgrade = upgrade(&db, || Ok(())).unwrap().unwrap();
+
+        assert_eq!(upgrade.migrations[0], (MIGRATIONS[6].description, 1));
+        let meeting = migrated_meeting(&db, "v6");
+        assert_eq!(meeting.jira_link.as_deref(), Some("PROJ-1"));
+        assert_eq!(meeting.recurring_event_id.as_deref(), Some("daily-series"));
+        assert!(meeting.event_type.is_none());
+    }
+
+    #[test]
+    fn test_v7_follow_state_and_workdays_move_to_the_user_state() {
+        let db = Database::temporary();
+        let sprint = Sprint {
+            id: 3,
+            name: "Sprint 3".to_string(),
+            state: crate::models::data::SprintState::Closed,
+            start: None,
+            end: None,
+            followed: true,
+            workdays: 9,
+        };
+        GenericDatabase::<Sprint>::new(&db, "sprints")
+            .unwrap()
+            .insert(&sprint)
+            .unwrap();
+        set_schema_version(&db, 7).unwrap();
+
+        let upgrade = upgrade(&db, || Ok(())).unwrap().unwrap();
+
+        assert_eq!(upgrade.migrations, vec![(MIGRATIONS[7].description, 1)]);
+        let shared = GenericDatabase::<Sprint>::new(&db, "sprints")
+            .unwrap()
+            .get("3")
+            .unwrap()
+            .unwrap();
+        assert!(!shared.followed);
+        let mine = SplitDatabase::<Sprint>::new(&db, "sprints", "sprints_user_state")
+            .unwrap()
+            .get("3")
+            .unwrap()
+            .unwrap();
+        assert!(mine.followed);
+        assert_eq!(mine.workdays, 9);
+    }
+}
@@ -1 +1,9 @@
+pub mod backend;
+pub mod backup;
 pub mod database;
+pub mod dump;
+pub mod encryption;
+pub mod migration;
+pub mod postgres;
+pub mod shared;
+pub mod sqlite;
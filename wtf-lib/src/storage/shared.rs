@@ -0,0 +1,214 @@
+use crate::storage::database::{CollectionItem, Database, GenericDatabase, Identifiable};
+use std::collections::HashMap;
+use std::error::Error;
+
+/// A record of a collection a team can share (see
+/// [`SHARED_COLLECTIONS`](super::postgres::SHARED_COLLECTIONS)) that also carries what
+/// belongs to a single user, like the sprints followed. That part is kept apart, in a
+/// collection of the user's own.
+pub trait SplitRecord: CollectionItem {
+    type Personal: CollectionItem;
+
+    /// The user's part of the record
+    fn personal(&self) -> Self::Personal;
+
+    /// The record without the user's part, as shared with the team
+    fn shared(self) -> Self;
+
+    /// The shared record completed with the user's part, or its defaults when the user
+    /// hasn't got one yet
+    fn with_personal(self, personal: Option<Self::Personal>) -> Self;
+}
+
+/// A [`GenericDatabase`] of records split between a shared collection and the user's
+/// own, with the same methods
+pub struct SplitDatabase<T: SplitRecord> {
+    shared: GenericDatabase<T>,
+    personal: GenericDatabase<T::Personal>,
+}
+
+impl<T: SplitRecord> SplitDatabase<T> {
+    pub(crate) fn new(
+        database: &Database,
+        collection_name: &str,
+        personal_collection_name: &str,
+    ) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Ok(Self {
+            shared: GenericDatabase::new(database, collection_name)?,
+            personal: GenericDatabase::new(database, personal_collection_name)?,
+        })
+    }
+
+    pub fn clear(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.shared.clear()?;
+        self.personal.clear()
+    }
+
+    pub fn insert(&self, item: &T) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.personal.insert(&item.personal())?;
+        self.shared.insert(&item.clone().shared())
+    }
+
+    pub fn get(&self, key: &str) -> Result<Option<T>, Box<dyn Error + Send + Sync>> {
+        match self.shared.get(key)? {
+            Some(item) => Ok(Some(item.with_personal(self.personal.get(key)?))),
+            None => Ok(None),
+        }
+    }
+
+    pub fn remove(&self, key: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.shared.remove(key)?;
+        self.personal.remove(key)
+    }
+
+    pub fn get_all(&self) -> Result<Vec<T>, Box<dyn Error + Send + Sync>> {
+        let mut personal: HashMap<String, T::Personal> = self
+            .personal
+            .get_all()?
+            .into_iter()
+            .map(|p| (p.get_id(), p))
+            .collect();
+        Ok(self
+            .shared
+            .get_all()?
+            .into_iter()
+            .map(|item| {
+                let mine = personal.remove(&item.get_id());
+                item.with_personal(mine)
+            })
+            .collect())
+    }
+
+    pub fn save_all(&self, items: Vec<T>) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.personal
+            .save_all(items.iter().map(|item| item.personal()).collect())?;
+        self.shared
+            .save_all(items.into_iter().map(|item| item.shared()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::data::{Sprint, SprintState};
+    use crate::storage::backend::{Record, Storage, StorageResult};
+    use crate::storage::postgres::owner_of;
+    use std::collections::BTreeMap;
+    use std::sync::{Arc, Mutex};
+
+    type Rows = Arc<Mutex<BTreeMap<(String, String, String), Vec<u8>>>>;
+
+    /// One PostgreSQL table seen by `user`, with the owners the real backend uses
+    struct TeamStorage {
+        rows: Rows,
+        user: &'static str,
+    }
+
+    impl TeamStorage {
+        fn row(&self, collection: &str, key: &str) -> (String, String, String) {
+            (
+                owner_of(collection, self.user).to_string(),
+                collection.to_string(),
+                key.to_string(),
+            )
+        }
+    }
+
+    impl Storage for TeamStorage {
+        fn get(&self, collection: &str, key: &str) -> StorageResult<Option<Vec<u8>>> {
+            Ok(self
+                .rows
+                .lock()
+                .unwrap()
+                .get(&self.row(collection, key))
+                .cloned())
+        }
+
+        fn scan(&self, collection: &str) -> StorageResult<Vec<(String, Vec<u8>)>> {
+            let owner = owner_of(collection, self.user);
+            Ok(self
+                .rows
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|((o, c, _), _)| o == owner && c == collection)
+                .map(|((_, _, key), value)| (key.clone(), value.clone()))
+                .collect())
+        }
+
+        fn put(&self, collection: &str, records: Vec<Record>) -> StorageResult<()> {
+            let mut rows = self.rows.lock().unwrap();
+            for record in records {
+                rows.insert(self.row(collection, &record.key), record.value);
+            }
+            Ok(())
+        }
+
+        fn remove(&self, collection: &str, keys: &[String]) -> StorageResult<()> {
+            let mut rows = self.rows.lock().unwrap();
+            for key in keys {
+                rows.remove(&self.row(collection, key));
+            }
+            Ok(())
+        }
+
+        fn clear(&self, collection: &str) -> StorageResult<()> {
+            let owner = owner_of(collection, self.user);
+            self.rows
+                .lock()
+                .unwrap()
+                .retain(|(o, c, _), _| !(o == owner && c == collection));
+            Ok(())
+        }
+
+        fn collections(&self) -> StorageResult<Vec<String>> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn sprints_of(rows: &Rows, user: &'static str) -> SplitDatabase<Sprint> {
+        let storage = Arc::new(TeamStorage {
+            rows: rows.clone(),
+            user,
+        });
+        SplitDatabase::new(
+            &Database::with_storage(storage),
+            "sprints",
+            "sprints_user_state",
+        )
+        .unwrap()
+    }
+
+    fn sprint(followed: bool, workdays: i64) -> Sprint {
+        Sprint {
+            id: 7,
+            name: "Sprint 7".to_string(),
+            state: SprintState::Active,
+            start: None,
+            end: None,
+            followed,
+            workdays,
+        }
+    }
+
+    #[test]
+    fn test_teammates_share_the_sprint_but_not_its_follow_state_nor_workdays() {
+        let rows = Rows::default();
+        let ada = sprints_of(&rows, "ada@example.com");
+        let bob = sprints_of(&rows, "bob@example.com");
+
+        ada.insert(&sprint(true, 8)).unwrap();
+        let seen_by_bob = bob.get("7").unwrap().expect("the sprint is shared");
+        assert_eq!(seen_by_bob.name, "Sprint 7");
+        assert!(!seen_by_bob.followed);
+        assert_eq!(seen_by_bob.workdays, 0);
+
+        // A teammate fetching the sprint with other absences leaves Ada's state alone
+        bob.save_all(vec![sprint(false, 10)]).unwrap();
+        let seen_by_ada = ada.get_all().unwrap();
+        assert_eq!(seen_by_ada.len(), 1);
+        assert!(seen_by_ada[0].followed);
+        assert_eq!(seen_by_ada[0].workdays, 8);
+        assert_eq!(bob.get("7").unwrap().unwrap().workdays, 10);
+    }
+}
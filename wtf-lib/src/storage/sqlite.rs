@@ -0,0 +1,208 @@
+use crate::storage::backend::{Record, Storage, StorageResult};
+use rusqlite::{params, Connection, OptionalExtension};
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+/// Every collection lives in the `records` table. Next to the bincode `value` each row
+/// keeps its JSON `document`, so the file can be queried with any SQLite client, e.g.
+/// `SELECT json_extract(document, '$.issue_id'), sum(json_extract(document,
+/// '$.time_spent_seconds')) FROM records WHERE collection = 'local_worklogs' GROUP BY 1`.
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS records (
+    collection TEXT NOT NULL,
+    key TEXT NOT NULL,
+    value BLOB NOT NULL,
+    document TEXT,
+    PRIMARY KEY (collection, key)
+) WITHOUT ROWID;
+CREATE INDEX IF NOT EXISTS worklogs_by_start ON records
+    (collection, json_extract(document, '$.started'))
+    WHERE collection IN ('local_worklogs', 'worklogs');
+CREATE INDEX IF NOT EXISTS worklogs_by_issue ON records
+    (collection, json_extract(document, '$.issue_id'))
+    WHERE collection IN ('local_worklogs', 'worklogs');
+";
+
+/// Backend keeping the database in a single SQLite file. Unlike sled, several processes
+/// (the TUI, the daemon, a one-off command) can use it at the same time.
+pub struct SqliteStorage {
+    connection: Mutex<Connection>,
+}
+
+impl SqliteStorage {
+    pub fn open(path: impl AsRef<Path>) -> StorageResult<Self> {
+        let connection = Connection::open(path)?;
+        // Wait for the other processes' writes instead of failing right away
+        connection.busy_timeout(Duration::from_secs(5))?;
+        connection.pragma_update(None, "journal_mode", "WAL")?;
+        // Overwrite deleted content, so that encrypting the database leaves no plaintext
+        connection.pragma_update(None, "secure_delete", true)?;
+        Self::with_connection(connection)
+    }
+
+    /// An in-memory database, deleted when dropped
+    pub fn temporary() -> Self {
+        let connection = Connection::open_in_memory().expect("in-memory sqlite db");
+        Self::with_connection(connection).expect("sqlite schema")
+    }
+
+    fn with_connection(connection: Connection) -> StorageResult<Self> {
+        connection.execute_batch(SCHEMA)?;
+        Ok(Self {
+            connection: Mutex::new(connection),
+        })
+    }
+
+    fn connection(&self) -> StorageResult<MutexGuard<'_, Connection>> {
+        Ok(self
+            .connection
+            .lock()
+            .map_err(|_| "the SQLite connection is poisoned")?)
+    }
+}
+
+impl Storage for SqliteStorage {
+    fn get(&self, collection: &str, key: &str) -> StorageResult<Option<Vec<u8>>> {
+        let connection = self.connection()?;
+        let mut statement = connection
+            .prepare_cached("SELECT value FROM records WHERE collection = ?1 AND key = ?2")?;
+        Ok(statement
+            .query_row(params![collection, key], |row| row.get(0))
+            .optional()?)
+    }
+
+    fn scan(&self, collection: &str) -> StorageResult<Vec<(String, Vec<u8>)>> {
+        let connection = self.connection()?;
+        let mut statement = connection
+            .prepare_cached("SELECT key, value FROM records WHERE collection = ?1 ORDER BY key")?;
+        let rows =
+            statement.query_map(params![collection], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+
+    fn put(&self, collection: &str, records: Vec<Record>) -> StorageResult<()> {
+        let mut connection = self.connection()?;
+        let transaction = connection.transaction()?;
+        {
+            let mut statement = transaction.prepare_cached(
+                "INSERT INTO records (collection, key, value, document) VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT (collection, key)
+                 DO UPDATE SET value = excluded.value, document = excluded.document",
+            )?;
+            for record in records {
+                statement.execute(params![
+                    collection,
+                    record.key,
+                    record.value,
+                    record.document
+                ])?;
+            }
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    fn remove(&self, collection: &str, keys: &[String]) -> StorageResult<()> {
+        let mut connection = self.connection()?;
+        let transaction = connection.transaction()?;
+        {
+            let mut statement = transaction
+                .prepare_cached("DELETE FROM records WHERE collection = ?1 AND key = ?2")?;
+            for key in keys {
+                statement.execute(params![collection, key])?;
+            }
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    fn clear(&self, collection: &str) -> StorageResult<()> {
+        self.connection()?.execute(
+            "DELETE FROM records WHERE collection = ?1",
+            params![collection],
+        )?;
+        Ok(())
+    }
+
+    fn collections(&self) -> StorageResult<Vec<String>> {
+        let connection = self.connection()?;
+        let mut statement = connection.prepare_cached("SELECT DISTINCT collection FROM records")?;
+        let rows = statement.query_map([], |row| row.get(0))?;
+        Ok(rows.collect::<Result<_, _>>()?)
+    }
+
+    fn stores_documents(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::data::{LocalWorklog, LocalWorklogState};
+    use crate::storage::database::{Database, GenericDatabase};
+    use chrono::{TimeZone, Utc};
+    use std::sync::Arc;
+
+    fn worklog(id: &str, issue: &str, day: u32, seconds: i64) -> LocalWorklog {
+        LocalWorklog {
+            id: id.to_string(),
+            comment: String::new(),
+            time_spent_seconds: seconds,
+            issue_id: issue.to_string(),
+            status: LocalWorklogState::Created,
+            started: Utc.with_ymd_and_hms(2026, 3, day, 9, 0, 0).unwrap(),
+            meeting_id: None,
+            worklog_id: None,
+        }
+    }
+
+    #[test]
+    fn test_generic_database_round_trip() {
+        let database = Database::with_storage(Arc::new(SqliteStorage::temporary()));
+        let db: GenericDatabase<LocalWorklog> =
+            GenericDatabase::new(&database, "local_worklogs").unwrap();
+
+        db.save_all(vec![
+            worklog("b", "PROJ-1", 2, 1800),
+            worklog("a", "PROJ-2", 3, 900),
+        ])
+        .unwrap();
+        db.insert(&worklog("b", "PROJ-1", 2, 3600)).unwrap();
+        db.remove("a").unwrap();
+
+        let all = db.get_all().unwrap();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].time_spent_seconds, 3600);
+        assert!(db.get("a").unwrap().is_none());
+        db.clear().unwrap();
+        assert!(db.get_all().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_documents_can_be_queried_with_sql() {
+        let storage = Arc::new(SqliteStorage::temporary());
+        let database = Database::with_storage(storage.clone());
+        let db: GenericDatabase<LocalWorklog> =
+            GenericDatabase::new(&database, "local_worklogs").unwrap();
+        db.save_all(vec![
+            worklog("1", "PROJ-1", 2, 1800),
+            worklog("2", "PROJ-1", 3, 1800),
+            worklog("3", "PROJ-2", 3, 900),
+        ])
+        .unwrap();
+
+        let connection = storage.connection().unwrap();
+        let total: i64 = connection
+            .query_row(
+                "SELECT sum(json_extract(document, '$.time_spent_seconds')) FROM records
+                 WHERE collection = 'local_worklogs'
+                 AND json_extract(document, '$.issue_id') = 'PROJ-1'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(total, 3600);
+    }
+}
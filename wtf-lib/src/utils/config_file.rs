@@ -0,0 +1,494 @@
+use crate::client::http_client;
+use crate::config::{is_valid_profile_name, Config};
+use chrono::NaiveTime;
+use config::builder::DefaultState;
+use config::{ConfigBuilder, ConfigError};
+use std::collections::HashMap;
+use std::env;
+use toml::{Table, Value};
+
+/// Prefix of the environment variables overriding config keys: `WTF_JIRA__BASE_URL` sets
+/// `jira.base_url`, a double underscore separating the section from the key
+const ENV_PREFIX: &str = "WTF_";
+const ENV_SEPARATOR: &str = "__";
+
+/// Keys holding lists, given comma-separated in the environment
+const ENV_LIST_KEYS: [&str; 1] = ["jira.project_keys"];
+
+/// Problems found in a config file. Errors make wtf fail to load it or misbehave;
+/// warnings are settings it ignores.
+#[derive(Debug, Default)]
+pub struct Validation {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+impl Validation {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Check a config file's TOML against the [`Config`] schema. Messages point at the
+/// offending line when it can be found.
+pub fn validate(text: &str) -> Validation {
+    let mut validation = Validation::default();
+    let config: Config = match toml::from_str(text) {
+        Ok(config) => config,
+        Err(e) => {
+            validation.errors.push(e.to_string().trim_end().to_string());
+            return validation;
+        }
+    };
+
+    let raw: Table = toml::from_str(text).unwrap_or_default();
+    let known = Table::try_from(&config).unwrap_or_default();
+    for key in unknown_keys(&raw, &known, "") {
+        validation.warnings.push(at_line(
+            text,
+            &key,
+            format!("unknown key `{}` is ignored", key),
+        ));
+    }
+
+    let mut error = |key: &str, message: &str| {
+        validation
+            .errors
+            .push(at_line(text, key, format!("`{}` {}", key, message)))
+    };
+    let base_url = &config.jira.base_url;
+    if !base_url.is_empty() && !base_url.starts_with("http://") && !base_url.starts_with("https://")
+    {
+        error("jira.base_url", "must start with http:// or https://");
+    }
+    let hours = config.worklog.daily_hours_limit;
+    if hours <= 0.0 || hours > 24.0 {
+        error(
+            "worklog.daily_hours_limit",
+            "must be more than 0 and at most 24",
+        );
+    }
+    let weekday_hours = &config.worklog.weekday_hours;
+    for (day, hours) in [
+        ("mon", weekday_hours.mon),
+        ("tue", weekday_hours.tue),
+        ("wed", weekday_hours.wed),
+        ("thu", weekday_hours.thu),
+        ("fri", weekday_hours.fri),
+        ("sat", weekday_hours.sat),
+        ("sun", weekday_hours.sun),
+    ] {
+        if hours.is_some_and(|hours| !(0.0..=24.0).contains(&hours)) {
+            error(
+                &format!("worklog.weekday_hours.{}", day),
+                "must be between 0 and 24",
+            );
+        }
+    }
+    for (key, time) in [
+        ("worklog.workday_start", &config.worklog.workday_start),
+        ("worklog.workday_end", &config.worklog.workday_end),
+    ] {
+        if time
+            .as_deref()
+            .is_some_and(|time| NaiveTime::parse_from_str(time.trim(), "%H:%M").is_err())
+        {
+            error(key, "must be a time like 09:00");
+        }
+    }
+    if let (Some(start), Some(end)) = (config.worklog.workday_start(), config.worklog.workday_end())
+    {
+        if end <= start {
+            error("worklog.workday_end", "must be after worklog.workday_start");
+        }
+    }
+    if config
+        .validation
+        .max_hours_per_day
+        .is_some_and(|hours| hours <= 0.0 || hours > 24.0)
+    {
+        error(
+            "validation.max_hours_per_day",
+            "must be more than 0 and at most 24",
+        );
+    }
+    if config.storage.retention_months == Some(0) {
+        error("storage.retention_months", "must be at least 1");
+    }
+    if config
+        .sync
+        .machine
+        .as_deref()
+        .is_some_and(|machine| !is_valid_profile_name(machine.trim()))
+    {
+        error("sync.machine", "use letters, digits, - and _");
+    }
+    if config.daemon.interval_minutes == 0 {
+        error("daemon.interval_minutes", "must be at least 1");
+    }
+    if let Some(google) = &config.google {
+        if google.credentials_path.is_empty() {
+            error("google.credentials_path", "must not be empty");
+        }
+    }
+    if let Some(proxy) = &config.network.proxy {
+        if reqwest::Proxy::all(proxy.reveal()).is_err() {
+            error(
+                "network.proxy",
+                "must be a URL such as http://proxy.corp:3128",
+            );
+        }
+    }
+    if let Some(path) = &config.network.ca_bundle {
+        if let Err(e) = http_client::ca_certificates(path) {
+            validation
+                .errors
+                .push(at_line(text, "network.ca_bundle", e));
+        }
+    }
+    if config.network.insecure_skip_verify {
+        validation.warnings.push(at_line(
+            text,
+            "network.insecure_skip_verify",
+            "`network.insecure_skip_verify` accepts any TLS certificate, lab environments only"
+                .to_string(),
+        ));
+    }
+    validation
+}
+
+/// Set `key` (dotted, e.g. `worklog.daily_hours_limit`) in the config file `text` and
+/// return the resulting config. The value is read as a TOML literal when that fits the
+/// key's type, as a plain string otherwise, and as a comma-separated list for arrays.
+pub fn set_key(text: &str, key: &str, value: &str) -> Result<Config, String> {
+    let table: Table = toml::from_str(text).map_err(|e| e.to_string())?;
+    let path: Vec<&str> = key.split('.').collect();
+    if path.iter().any(|part| part.is_empty()) {
+        return Err(format!("Invalid key '{}'", key));
+    }
+
+    // Report why the most natural reading of the value failed, not the list fallback
+    let mut first_error = None;
+    for candidate in candidates(value) {
+        let mut table = table.clone();
+        insert(&mut table, &path, candidate)?;
+        match Value::Table(table).try_into::<Config>() {
+            Ok(config) => {
+                let serialized = Value::try_from(&config).map_err(|e| e.to_string())?;
+                return if lookup(&serialized, &path).is_some() {
+                    Ok(config)
+                } else {
+                    Err(format!("Unknown config key '{}'", key))
+                };
+            }
+            Err(e) => {
+                let message = e.to_string();
+                first_error.get_or_insert(message.lines().next().unwrap_or_default().to_string());
+            }
+        }
+    }
+    Err(format!(
+        "Invalid value '{}' for {}: {}",
+        value,
+        key,
+        first_error.unwrap_or_default()
+    ))
+}
+
+/// The config keys set by `WTF_SECTION__KEY` environment variables, with their value.
+/// `WTF_PROFILE`, `WTF_CONFIG_HOME` and the like aren't config keys and are left out.
+pub fn env_overrides() -> HashMap<String, String> {
+    overrides_in(env::vars())
+}
+
+fn overrides_in(vars: impl IntoIterator<Item = (String, String)>) -> HashMap<String, String> {
+    vars.into_iter()
+        .filter_map(|(name, value)| {
+            let key = name.strip_prefix(ENV_PREFIX)?;
+            key.contains(ENV_SEPARATOR)
+                .then(|| (key.to_lowercase().replace(ENV_SEPARATOR, "."), value))
+        })
+        .collect()
+}
+
+/// Layer the `overrides` over the sources of `builder`
+pub fn with_env_overrides(
+    builder: ConfigBuilder<DefaultState>,
+    overrides: &HashMap<String, String>,
+) -> Result<ConfigBuilder<DefaultState>, ConfigError> {
+    overrides.iter().try_fold(builder, |builder, (key, value)| {
+        if ENV_LIST_KEYS.contains(&key.as_str()) {
+            let items: Vec<&str> = value
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .collect();
+            builder.set_override(key, items)
+        } else {
+            builder.set_override(key, value.as_str())
+        }
+    })
+}
+
+/// Put back in `saved` the `file` values of the overridden `keys`, or leave them out when
+/// the file has none, so that saving never writes what came from the environment
+pub fn keep_file_values<'a>(
+    saved: &mut Table,
+    file: &Table,
+    keys: impl Iterator<Item = &'a String>,
+) {
+    let file = Value::Table(file.clone());
+    for key in keys {
+        let path: Vec<&str> = key.split('.').collect();
+        match lookup(&file, &path) {
+            Some(value) => {
+                let _ = insert(saved, &path, value.clone());
+            }
+            None => remove(saved, &path),
+        }
+    }
+}
+
+fn remove(table: &mut Table, path: &[&str]) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    let parent = parents.iter().try_fold(table, |current, part| {
+        current.get_mut(*part)?.as_table_mut()
+    });
+    if let Some(parent) = parent {
+        parent.remove(*last);
+    }
+}
+
+fn lookup<'a>(value: &'a Value, path: &[&str]) -> Option<&'a Value> {
+    path.iter()
+        .try_fold(value, |current, part| current.as_table()?.get(*part))
+}
+
+fn candidates(value: &str) -> Vec<Value> {
+    let mut candidates = Vec::new();
+    if let Ok(mut literal) = toml::from_str::<Table>(&format!("v = {}", value)) {
+        if let Some(literal) = literal.remove("v") {
+            candidates.push(literal);
+        }
+    }
+    candidates.push(Value::String(value.to_string()));
+    candidates.push(Value::Array(
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| Value::String(item.to_string()))
+            .collect(),
+    ));
+    candidates
+}
+
+fn insert(table: &mut Table, path: &[&str], value: Value) -> Result<(), String> {
+    let (last, parents) = path.split_last().ok_or("Empty key")?;
+    let mut current = table;
+    for part in parents {
+        current = current
+            .entry(part.to_string())
+            .or_insert_with(|| Value::Table(Table::new()))
+            .as_table_mut()
+            .ok_or_else(|| format!("'{}' is not a section", part))?;
+    }
+    current.insert(last.to_string(), value);
+    Ok(())
+}
+
+fn unknown_keys(raw: &Table, known: &Table, prefix: &str) -> Vec<String> {
+    let mut unknown = Vec::new();
+    for (key, value) in raw {
+        let full_key = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match (known.get(key), value) {
+            (None, _) => unknown.push(full_key),
+            // Free-form maps, e.g. google.color_labels, accept any key
+            (Some(Value::Table(known_table)), Value::Table(table)) if !known_table.is_empty() => {
+                unknown.extend(unknown_keys(table, known_table, &full_key))
+            }
+            _ => {}
+        }
+    }
+    unknown
+}
+
+/// Prefix `message` with the line `key` is set on, when found
+fn at_line(text: &str, key: &str, message: String) -> String {
+    let (section, name) = key.rsplit_once('.').unwrap_or(("", key));
+    let mut current_section = String::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            current_section = header.trim().to_string();
+        } else if current_section == section
+            && line
+                .split_once('=')
+                .is_some_and(|(k, _)| k.trim().trim_matches('"') == name)
+        {
+            return format!("line {}: {}", index + 1, message);
+        }
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID: &str = r#"
+[jira]
+base_url = "https://example.atlassian.net"
+username = "me@example.com"
+api_token = "token"
+
+[github]
+
+[worklog]
+daily_hours_limit = 8.0
+"#;
+
+    #[test]
+    fn test_validate_reports_location_and_unknown_keys() {
+        assert!(validate(VALID).is_valid());
+
+        let typo = VALID.replace("daily_hours_limit = 8.0", "daily_hours_limt = 8.0");
+        let validation = validate(&typo);
+        assert!(validation.is_valid());
+        assert_eq!(
+            validation.warnings,
+            vec!["line 10: unknown key `worklog.daily_hours_limt` is ignored"]
+        );
+
+        let out_of_range = VALID.replace("8.0", "30.0");
+        assert_eq!(
+            validate(&out_of_range).errors,
+            vec!["line 10: `worklog.daily_hours_limit` must be more than 0 and at most 24"]
+        );
+
+        let wrong_type = VALID.replace("8.0", "\"eight\"");
+        let errors = validate(&wrong_type).errors;
+        assert!(errors[0].contains("line 10"), "{}", errors[0]);
+
+        let weekday_hours = format!("{}\n[worklog.weekday_hours]\nwed = 4\nfri = -1\n", VALID);
+        let errors = validate(&weekday_hours).errors;
+        assert_eq!(errors.len(), 1, "{:?}", errors);
+        assert!(
+            errors[0].contains("`worklog.weekday_hours.fri` must be between 0 and 24"),
+            "{}",
+            errors[0]
+        );
+    }
+
+    #[test]
+    fn test_validate_checks_the_network_settings() {
+        let network = format!(
+            "{}\n[network]\nproxy = \"not a url\"\nca_bundle = \"/nonexistent/ca.pem\"\ninsecure_skip_verify = true\n",
+            VALID
+        );
+        let validation = validate(&network);
+        assert_eq!(validation.errors.len(), 2);
+        assert!(validation.errors[0].starts_with("line 13: `network.proxy`"));
+        assert!(validation.errors[1].starts_with("line 14: Failed to read network.ca_bundle"));
+        assert_eq!(
+            validation.warnings,
+            vec!["line 15: `network.insecure_skip_verify` accepts any TLS certificate, lab environments only"]
+        );
+    }
+
+    #[test]
+    fn test_set_key_infers_the_value_type() {
+        let config = set_key(VALID, "worklog.daily_hours_limit", "7.4").unwrap();
+        assert_eq!(config.worklog.daily_hours_limit, 7.4);
+
+        let config = set_key(VALID, "jira.username", "1234").unwrap();
+        assert_eq!(config.jira.username, "1234");
+
+        let config = set_key(VALID, "jira.project_keys", "PROJ, OPS").unwrap();
+        assert_eq!(config.jira.project_keys, vec!["PROJ", "OPS"]);
+
+        let config = set_key(VALID, "daemon.interval_minutes", "15").unwrap();
+        assert_eq!(config.daemon.interval_minutes, 15);
+    }
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_environment_overrides_the_file() {
+        let vars = vars(&[
+            ("WTF_JIRA__BASE_URL", "https://ci.atlassian.net"),
+            ("WTF_WORKLOG__DAILY_HOURS_LIMIT", "7.5"),
+            ("WTF_JIRA__PROJECT_KEYS", "PROJ, OPS"),
+            ("WTF_JIRA__API_TOKEN", "0123"),
+            ("WTF_UI__ASCII_MODE", "true"),
+            ("WTF_PROFILE", "acme"),
+            ("WTF_CONFIG_HOME", "/tmp/wtf"),
+        ]);
+        let file = config::File::from_str(VALID, config::FileFormat::Toml);
+        let config: Config = with_env_overrides(
+            config::Config::builder().add_source(file),
+            &overrides_in(vars.clone()),
+        )
+        .and_then(|builder| builder.build())
+        .and_then(|config| config.try_deserialize())
+        .unwrap();
+        assert_eq!(config.jira.base_url, "https://ci.atlassian.net");
+        assert_eq!(config.jira.username, "me@example.com");
+        assert_eq!(config.worklog.daily_hours_limit, 7.5);
+        assert_eq!(config.jira.project_keys, vec!["PROJ", "OPS"]);
+        assert!(config.ui.ascii_mode);
+        assert_eq!(config.jira.api_token.reveal(), "0123");
+
+        let overrides = overrides_in(vars);
+        assert_eq!(overrides.len(), 5);
+        assert_eq!(overrides["jira.base_url"], "https://ci.atlassian.net");
+    }
+
+    #[test]
+    fn test_saving_keeps_the_file_values_of_overridden_keys() {
+        let file: Table = toml::from_str(VALID).unwrap();
+        let mut saved = file.clone();
+        insert(
+            &mut saved,
+            &["jira", "base_url"],
+            "https://ci.atlassian.net".into(),
+        )
+        .unwrap();
+        insert(&mut saved, &["ui", "ascii_mode"], true.into()).unwrap();
+        insert(&mut saved, &["jira", "issue_jql"], "project = OPS".into()).unwrap();
+
+        let overrides = overrides_in(vars(&[
+            ("WTF_JIRA__BASE_URL", "https://ci.atlassian.net"),
+            ("WTF_UI__ASCII_MODE", "true"),
+        ]));
+        keep_file_values(&mut saved, &file, overrides.keys());
+        assert_eq!(
+            saved["jira"]["base_url"].as_str(),
+            Some("https://example.atlassian.net")
+        );
+        assert!(saved["ui"].get("ascii_mode").is_none());
+        assert_eq!(saved["jira"]["issue_jql"].as_str(), Some("project = OPS"));
+    }
+
+    #[test]
+    fn test_set_key_rejects_unknown_keys_and_bad_values() {
+        assert_eq!(
+            set_key(VALID, "worklog.daily_hours", "7").unwrap_err(),
+            "Unknown config key 'worklog.daily_hours'"
+        );
+        assert!(set_key(VALID, "worklog.daily_hours_limit", "lots")
+            .unwrap_err()
+            .starts_with("Invalid value 'lots' for worklog.daily_hours_limit"));
+    }
+}
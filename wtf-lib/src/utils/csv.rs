@@ -0,0 +1,99 @@
+/// Quote a CSV field when it contains a separator, a quote or a line break (RFC 4180)
+pub fn escape_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Join fields into one CSV line, without the trailing line break
+pub fn to_line<S: AsRef<str>>(fields: &[S]) -> String {
+    fields
+        .iter()
+        .map(|field| escape_field(field.as_ref()))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Split CSV text into records (RFC 4180: quoted fields may hold separators, doubled
+/// quotes and line breaks). Each record comes with the line it starts on; blank lines are
+/// skipped.
+pub fn parse(text: &str) -> Vec<(usize, Vec<String>)> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => record.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes => {}
+            '\n' if !in_quotes => {
+                record.push(std::mem::take(&mut field));
+                let fields = std::mem::take(&mut record);
+                if fields.iter().any(|f| !f.is_empty()) {
+                    records.push((record_line, fields));
+                }
+                line += 1;
+                record_line = line;
+            }
+            _ => {
+                if c == '\n' {
+                    line += 1;
+                }
+                field.push(c);
+            }
+        }
+    }
+    record.push(field);
+    if record.iter().any(|f| !f.is_empty()) {
+        records.push((record_line, record));
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_fields_are_left_as_is() {
+        assert_eq!(
+            to_line(&["2026-01-05", "PROJ-1", "1.50"]),
+            "2026-01-05,PROJ-1,1.50"
+        );
+    }
+
+    #[test]
+    fn test_special_characters_are_quoted() {
+        assert_eq!(escape_field("Daily, team"), "\"Daily, team\"");
+        assert_eq!(escape_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(escape_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn test_parse_handles_quotes_and_line_numbers() {
+        let text = "date,comment\r\n2026-01-05,\"Daily, \"\"team\"\"\"\n\n2026-01-06,\"two\nlines\"\n2026-01-07,x";
+        let records = parse(text);
+
+        assert_eq!(records.len(), 4);
+        assert_eq!(
+            records[1],
+            (2, vec!["2026-01-05".into(), "Daily, \"team\"".into()])
+        );
+        assert_eq!(
+            records[2],
+            (4, vec!["2026-01-06".into(), "two\nlines".into()])
+        );
+        assert_eq!(records[3].0, 6);
+    }
+}
@@ -0,0 +1,139 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+
+/// Read a day the way people type it on the command line, relative to `today`:
+/// `today`, `yesterday`, `2026-01-05`, `monday`/`mon` (the latest one, today included),
+/// `last friday` (strictly before today) and `3 days ago`.
+pub fn parse_day(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let input = input.trim().to_lowercase();
+    match input.as_str() {
+        "today" => return Some(today),
+        "yesterday" => return Some(today - Duration::days(1)),
+        _ => {}
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
+        return Some(date);
+    }
+    if let Some(days) = input
+        .strip_suffix("days ago")
+        .or_else(|| input.strip_suffix("day ago"))
+    {
+        return days
+            .trim()
+            .parse::<i64>()
+            .ok()
+            .map(|d| today - Duration::days(d));
+    }
+    if let Some(day) = input.strip_prefix("last ") {
+        let weekday = day.trim().parse::<Weekday>().ok()?;
+        return Some(latest_weekday(weekday, today - Duration::days(1)));
+    }
+    input
+        .parse::<Weekday>()
+        .ok()
+        .map(|weekday| latest_weekday(weekday, today))
+}
+
+/// The most recent `weekday` on or before `day`
+fn latest_weekday(weekday: Weekday, day: NaiveDate) -> NaiveDate {
+    let back = (day.weekday().num_days_from_monday() + 7 - weekday.num_days_from_monday()) % 7;
+    day - Duration::days(back as i64)
+}
+
+/// A time of day typed as `HH:MM`
+pub fn parse_time_of_day(text: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(text.trim(), "%H:%M").ok()
+}
+
+/// `time` on `day` in `tz`, the earlier one on DST changes
+pub fn at_local_time<Tz: TimeZone>(tz: &Tz, day: NaiveDate, time: NaiveTime) -> DateTime<Utc> {
+    let naive = day.and_time(time);
+    tz.from_local_datetime(&naive)
+        .earliest()
+        // Skipped by a DST change: an hour later exists
+        .or_else(|| {
+            tz.from_local_datetime(&(naive + Duration::hours(1)))
+                .earliest()
+        })
+        .map(|at| at.with_timezone(&Utc))
+        .unwrap_or_else(|| Utc.from_utc_datetime(&naive))
+}
+
+/// Move `seconds` starting at `started` inside the window from `start` to `end` of its
+/// day in `tz`, shortened to the window's length if needed; midnight stands for a bound
+/// left unset, and nothing moves without either
+pub fn clamp_in<Tz: TimeZone>(
+    tz: &Tz,
+    start: Option<NaiveTime>,
+    end: Option<NaiveTime>,
+    started: DateTime<Utc>,
+    seconds: i64,
+) -> (DateTime<Utc>, i64) {
+    if start.is_none() && end.is_none() {
+        return (started, seconds);
+    }
+    let day = started.with_timezone(tz).date_naive();
+    let window_start = at_local_time(tz, day, start.unwrap_or(NaiveTime::MIN));
+    let window_end = match end {
+        Some(end) => at_local_time(tz, day, end),
+        None => at_local_time(tz, day + Duration::days(1), NaiveTime::MIN),
+    };
+    if window_end <= window_start {
+        return (started, seconds);
+    }
+    let seconds = seconds.min((window_end - window_start).num_seconds());
+    let latest = window_end - Duration::seconds(seconds);
+    (started.clamp(window_start, latest), seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, day).unwrap()
+    }
+
+    #[test]
+    fn test_relative_days() {
+        // 2026-01-07 is a Wednesday
+        let today = date(7);
+        assert_eq!(parse_day("today", today), Some(today));
+        assert_eq!(parse_day("Yesterday", today), Some(date(6)));
+        assert_eq!(parse_day("3 days ago", today), Some(date(4)));
+        assert_eq!(parse_day("2026-01-02", today), Some(date(2)));
+    }
+
+    #[test]
+    fn test_weekdays() {
+        let today = date(7);
+        assert_eq!(parse_day("monday", today), Some(date(5)));
+        assert_eq!(parse_day("wed", today), Some(today));
+        assert_eq!(
+            parse_day("last wednesday", today),
+            Some(NaiveDate::from_ymd_opt(2025, 12, 31).unwrap())
+        );
+        assert_eq!(parse_day("last friday", today), Some(date(2)));
+    }
+
+    #[test]
+    fn test_unknown_input() {
+        assert_eq!(parse_day("someday", date(7)), None);
+        assert_eq!(parse_day("last week", date(7)), None);
+    }
+
+    #[test]
+    fn test_clamped_to_the_working_window() {
+        let window = (parse_time_of_day("09:00"), parse_time_of_day("17:30"));
+        let at = |hour, minute| Utc.with_ymd_and_hms(2026, 3, 2, hour, minute, 0).unwrap();
+        let clamp = |started, seconds| clamp_in(&Utc, window.0, window.1, started, seconds);
+
+        assert_eq!(clamp(at(10, 0), 3600), (at(10, 0), 3600));
+        // An overnight session is logged from the start of the day
+        assert_eq!(clamp(at(2, 0), 5400), (at(9, 0), 5400));
+        // A late one ends with the working day
+        assert_eq!(clamp(at(17, 0), 3600), (at(16, 30), 3600));
+        assert_eq!(clamp(at(8, 0), 12 * 3600), (at(9, 0), 8 * 3600 + 1800));
+        // No window, no change
+        assert_eq!(clamp_in(&Utc, None, None, at(2, 0), 5400), (at(2, 0), 5400));
+    }
+}
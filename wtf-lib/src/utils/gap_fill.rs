@@ -0,0 +1,190 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Comment given to gap-fill worklogs, also used to recognise them afterwards
+pub const GAP_FILL_COMMENT: &str = "Tech activities";
+
+/// Days already logged above this many hours are left alone by gap filling
+pub const GAP_FILL_MIN_THRESHOLD: f64 = 6.0;
+
+/// How the missing hours of a day are split between the gap-fill issues
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GapFillStrategy {
+    /// The same share for every issue
+    #[default]
+    Even,
+    /// In proportion to the hours already logged on each issue
+    Proportional,
+    /// In proportion to the hours already logged on the epic of each issue, so work
+    /// keeps following the epics that took the most time
+    Epic,
+}
+
+impl FromStr for GapFillStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "even" => Ok(Self::Even),
+            "proportional" => Ok(Self::Proportional),
+            "epic" => Ok(Self::Epic),
+            _ => Err(format!("unknown gap fill strategy '{}'", s)),
+        }
+    }
+}
+
+/// One worklog the gap fill would create
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GapFillEntry {
+    pub date: NaiveDate,
+    pub issue: String,
+    pub hours: f64,
+}
+
+/// Split every gap (day, missing hours) between `issues`. `logged_hours` (issue → hours
+/// already logged) weights the proportional strategy, summed per epic with `epics`
+/// (issue → epic) for the epic strategy; an issue without an epic counts its own hours.
+/// Without any logged time both fall back to an even split.
+pub fn plan_gap_fill(
+    gaps: &[(NaiveDate, f64)],
+    issues: &[String],
+    strategy: GapFillStrategy,
+    logged_hours: &HashMap<String, f64>,
+    epics: &HashMap<String, String>,
+) -> Vec<GapFillEntry> {
+    if issues.is_empty() {
+        return Vec::new();
+    }
+    let logged = |issue: &String| logged_hours.get(issue).copied().unwrap_or(0.0);
+    let weights: Vec<f64> = match strategy {
+        GapFillStrategy::Even => vec![1.0; issues.len()],
+        GapFillStrategy::Proportional => issues.iter().map(logged).collect(),
+        GapFillStrategy::Epic => {
+            let mut epic_hours: HashMap<&String, f64> = HashMap::new();
+            for (issue, hours) in logged_hours {
+                if let Some(epic) = epics.get(issue) {
+                    *epic_hours.entry(epic).or_default() += hours;
+                }
+            }
+            issues
+                .iter()
+                .map(|issue| match epics.get(issue) {
+                    Some(epic) => epic_hours.get(epic).copied().unwrap_or(0.0),
+                    None => logged(issue),
+                })
+                .collect()
+        }
+    };
+    let weights = if weights.iter().sum::<f64>() > 0.0 {
+        weights
+    } else {
+        vec![1.0; issues.len()]
+    };
+    let total_weight: f64 = weights.iter().sum();
+
+    gaps.iter()
+        .flat_map(|(date, hours)| {
+            issues
+                .iter()
+                .zip(&weights)
+                .filter(|(_, weight)| **weight > 0.0)
+                .map(move |(issue, weight)| GapFillEntry {
+                    date: *date,
+                    issue: issue.clone(),
+                    hours: hours * weight / total_weight,
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, day).unwrap()
+    }
+
+    fn issues() -> Vec<String> {
+        vec!["PROJ-1".to_string(), "PROJ-2".to_string()]
+    }
+
+    #[test]
+    fn test_even_split() {
+        let plan = plan_gap_fill(
+            &[(date(5), 4.0)],
+            &issues(),
+            GapFillStrategy::Even,
+            &HashMap::new(),
+            &HashMap::new(),
+        );
+
+        assert_eq!(plan.len(), 2);
+        assert!(plan.iter().all(|entry| entry.hours == 2.0));
+    }
+
+    #[test]
+    fn test_proportional_split_follows_logged_hours() {
+        let logged = HashMap::from([("PROJ-1".to_string(), 6.0), ("PROJ-2".to_string(), 2.0)]);
+        let plan = plan_gap_fill(
+            &[(date(5), 4.0), (date(6), 8.0)],
+            &issues(),
+            GapFillStrategy::Proportional,
+            &logged,
+            &HashMap::new(),
+        );
+
+        let hours: Vec<_> = plan
+            .iter()
+            .map(|e| (e.date, e.issue.as_str(), e.hours))
+            .collect();
+        assert_eq!(
+            hours,
+            vec![
+                (date(5), "PROJ-1", 3.0),
+                (date(5), "PROJ-2", 1.0),
+                (date(6), "PROJ-1", 6.0),
+                (date(6), "PROJ-2", 2.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_proportional_without_history_is_even() {
+        let plan = plan_gap_fill(
+            &[(date(5), 3.0)],
+            &issues(),
+            GapFillStrategy::Proportional,
+            &HashMap::new(),
+            &HashMap::new(),
+        );
+
+        assert!(plan.iter().all(|entry| entry.hours == 1.5));
+    }
+
+    #[test]
+    fn test_epic_split_follows_hours_logged_on_the_epic() {
+        let logged = HashMap::from([
+            ("PROJ-1".to_string(), 1.0),
+            ("PROJ-2".to_string(), 1.0),
+            ("PROJ-3".to_string(), 5.0),
+        ]);
+        let epics = HashMap::from([
+            ("PROJ-1".to_string(), "PROJ-100".to_string()),
+            ("PROJ-3".to_string(), "PROJ-100".to_string()),
+        ]);
+        let plan = plan_gap_fill(
+            &[(date(5), 7.0)],
+            &issues(),
+            GapFillStrategy::Epic,
+            &logged,
+            &epics,
+        );
+
+        let hours: Vec<_> = plan.iter().map(|e| (e.issue.as_str(), e.hours)).collect();
+        assert_eq!(hours, vec![("PROJ-1", 6.0), ("PROJ-2", 1.0)]);
+        assert_eq!("epic".parse(), Ok(GapFillStrategy::Epic));
+    }
+}
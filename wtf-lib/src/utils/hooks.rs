@@ -0,0 +1,228 @@
+use crate::config::HooksConfig;
+use chrono::{DateTime, Utc};
+use lazy_static::lazy_static;
+use log::{debug, warn};
+use serde::Serialize;
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::Mutex;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Environment variable telling a hook command which event it runs for
+pub const EVENT_VAR: &str = "WTF_HOOK_EVENT";
+
+lazy_static! {
+    /// Hooks running in the background, waited for before the process exits
+    static ref PENDING: Mutex<Vec<JoinHandle<()>>> = Mutex::new(Vec::new());
+}
+
+/// Lifecycle events a command of the `[hooks]` section can be run on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookEvent {
+    PrePush,
+    PostPush,
+    WorklogCreated,
+    FetchComplete,
+}
+
+impl HookEvent {
+    pub fn name(self) -> &'static str {
+        match self {
+            HookEvent::PrePush => "pre_push",
+            HookEvent::PostPush => "post_push",
+            HookEvent::WorklogCreated => "worklog_created",
+            HookEvent::FetchComplete => "fetch_complete",
+        }
+    }
+
+    fn command(self, config: &HooksConfig) -> Option<&str> {
+        let command = match self {
+            HookEvent::PrePush => &config.pre_push,
+            HookEvent::PostPush => &config.post_push,
+            HookEvent::WorklogCreated => &config.worklog_created,
+            HookEvent::FetchComplete => &config.fetch_complete,
+        };
+        command
+            .as_deref()
+            .map(str::trim)
+            .filter(|command| !command.is_empty())
+    }
+}
+
+/// The JSON document a hook reads on its stdin
+#[derive(Serialize)]
+struct Payload<'a, T: Serialize> {
+    event: &'static str,
+    timestamp: DateTime<Utc>,
+    data: &'a T,
+}
+
+/// Run the command configured for `event` with `data` as JSON on its stdin, and wait for
+/// it. An error when it exits non-zero or outlives `timeout_secs`: that is how a
+/// `pre_push` hook cancels the push.
+pub fn run<T: Serialize>(config: &HooksConfig, event: HookEvent, data: &T) -> Result<(), String> {
+    let Some(command) = event.command(config) else {
+        return Ok(());
+    };
+    let payload = payload(event, data)?;
+    execute(command, event, &payload, timeout(config))
+}
+
+/// Like [`run`], in the background, for the events a hook can't veto: a failing hook is
+/// only logged
+pub fn notify<T: Serialize>(config: &HooksConfig, event: HookEvent, data: &T) {
+    let Some(command) = event.command(config) else {
+        return;
+    };
+    let payload = match payload(event, data) {
+        Ok(payload) => payload,
+        Err(e) => {
+            warn!("Can't run the {} hook: {}", event.name(), e);
+            return;
+        }
+    };
+    let command = command.to_string();
+    let timeout = timeout(config);
+    let handle = thread::spawn(move || {
+        if let Err(e) = execute(&command, event, &payload, timeout) {
+            warn!("The {} hook failed: {}", event.name(), e);
+        }
+    });
+    let mut pending = PENDING.lock().unwrap_or_else(|e| e.into_inner());
+    pending.retain(|handle| !handle.is_finished());
+    pending.push(handle);
+}
+
+/// Wait for the hooks still running in the background, before the process exits
+pub fn wait_pending() {
+    let pending = std::mem::take(&mut *PENDING.lock().unwrap_or_else(|e| e.into_inner()));
+    for handle in pending {
+        let _ = handle.join();
+    }
+}
+
+fn payload<T: Serialize>(event: HookEvent, data: &T) -> Result<String, String> {
+    serde_json::to_string(&Payload {
+        event: event.name(),
+        timestamp: Utc::now(),
+        data,
+    })
+    .map_err(|e| e.to_string())
+}
+
+fn timeout(config: &HooksConfig) -> Duration {
+    Duration::from_secs(config.timeout_secs)
+}
+
+fn shell(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut shell = Command::new("cmd");
+        shell.args(["/C", command]);
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.args(["-c", command]);
+        shell
+    }
+}
+
+fn execute(
+    command: &str,
+    event: HookEvent,
+    payload: &str,
+    timeout: Duration,
+) -> Result<(), String> {
+    debug!("Running the {} hook: {}", event.name(), command);
+    let mut child = shell(command)
+        .env(EVENT_VAR, event.name())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("can't start '{}': {}", command, e))?;
+
+    // Fed and drained on the side, so a hook ignoring its stdin or chatty on stderr can't
+    // block on a full pipe
+    if let Some(mut stdin) = child.stdin.take() {
+        let payload = payload.to_string();
+        thread::spawn(move || stdin.write_all(payload.as_bytes()));
+    }
+    let stderr = child.stderr.take();
+    let stderr = thread::spawn(move || {
+        let mut text = String::new();
+        if let Some(mut stderr) = stderr {
+            let _ = stderr.read_to_string(&mut text);
+        }
+        text
+    });
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if Instant::now() < deadline => thread::sleep(Duration::from_millis(20)),
+            Ok(None) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!("'{}' timed out after {:?}", command, timeout));
+            }
+            Err(e) => return Err(e.to_string()),
+        }
+    };
+    if status.success() {
+        return Ok(());
+    }
+    let stderr = stderr.join().unwrap_or_default();
+    match stderr.trim() {
+        "" => Err(format!("'{}' failed ({})", command, status)),
+        stderr => Err(format!("'{}' failed ({}): {}", command, status, stderr)),
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn hooks(pre_push: &str) -> HooksConfig {
+        HooksConfig {
+            pre_push: Some(pre_push.to_string()),
+            ..HooksConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_hook_reads_its_payload_on_stdin() {
+        let config = hooks(
+            r#"test "$WTF_HOOK_EVENT" = pre_push && grep -q '"event":"pre_push".*"data":{"count":2}'"#,
+        );
+        assert_eq!(
+            run(&config, HookEvent::PrePush, &json!({ "count": 2 })),
+            Ok(())
+        );
+        assert!(run(&config, HookEvent::PrePush, &json!({ "count": 3 })).is_err());
+    }
+
+    #[test]
+    fn test_failing_hook_reports_its_stderr() {
+        let config = hooks("echo 'closed period' >&2; exit 3");
+        let error = run(&config, HookEvent::PrePush, &json!({})).unwrap_err();
+        assert!(error.contains("closed period"), "{}", error);
+
+        // Only the configured events run a command
+        assert_eq!(run(&config, HookEvent::PostPush, &json!({})), Ok(()));
+    }
+
+    #[test]
+    fn test_hook_outliving_its_timeout_is_killed() {
+        let error = execute(
+            "sleep 5",
+            HookEvent::FetchComplete,
+            "{}",
+            Duration::from_millis(100),
+        )
+        .unwrap_err();
+        assert!(error.contains("timed out"), "{}", error);
+    }
+}
@@ -0,0 +1,370 @@
+use crate::config::{expand_path, Config, GOOGLE_CALENDAR_EVENT_COLORS};
+use crate::models::data::{GitHubSession, Meeting};
+use std::fs;
+
+/// Operations a script may run for one meeting or session, so a runaway loop can't hang
+/// a sync or the TUI
+#[cfg(feature = "scripting")]
+const MAX_OPERATIONS: u64 = 100_000;
+
+/// What a script links a meeting or a GitHub session to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptLink {
+    pub issue: String,
+    /// Time to log instead of the meeting's or the session's own duration
+    pub seconds: Option<i64>,
+}
+
+/// The Rhai script of `[linking] script`: its `link_meeting(meeting)` and
+/// `link_session(session)` functions, both optional, return `()` to leave the decision to
+/// the usual rules, an issue key (or alias), or `#{ issue: "PROJ-1", minutes: 30 }`.
+pub struct LinkScript {
+    #[cfg_attr(not(feature = "scripting"), allow(dead_code))]
+    config: Config,
+    #[cfg(feature = "scripting")]
+    engine: rhai::Engine,
+    #[cfg(feature = "scripting")]
+    ast: rhai::AST,
+}
+
+impl LinkScript {
+    /// The configured script, compiled; `None` when there's none
+    pub fn load(config: &Config) -> Result<Option<Self>, String> {
+        let Some(path) = config
+            .linking
+            .script
+            .as_deref()
+            .map(str::trim)
+            .filter(|path| !path.is_empty())
+        else {
+            return Ok(None);
+        };
+        let source = fs::read_to_string(expand_path(path))
+            .map_err(|e| format!("Failed to read linking.script '{}': {}", path, e))?;
+        Self::compile(&source, config)
+            .map(Some)
+            .map_err(|e| format!("Invalid linking.script '{}': {}", path, e))
+    }
+
+    #[cfg(feature = "scripting")]
+    pub fn compile(source: &str, config: &Config) -> Result<Self, String> {
+        let mut engine = rhai::Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+        let ast = engine.compile(source).map_err(|e| e.to_string())?;
+        Ok(Self {
+            config: config.clone(),
+            engine,
+            ast,
+        })
+    }
+
+    #[cfg(not(feature = "scripting"))]
+    pub fn compile(_source: &str, _config: &Config) -> Result<Self, String> {
+        Err(
+            "this build of wtf has no scripting support (build it with --features scripting)"
+                .to_string(),
+        )
+    }
+
+    pub fn link_meeting(&self, meeting: &Meeting) -> Option<ScriptLink> {
+        let color = meeting
+            .color_id
+            .as_deref()
+            .and_then(|id| id.parse::<usize>().ok())
+            .and_then(|id| GOOGLE_CALENDAR_EVENT_COLORS.get(id.wrapping_sub(1)))
+            .copied()
+            .unwrap_or_default();
+        let attendees: Vec<String> = meeting
+            .attendees
+            .iter()
+            .flatten()
+            .filter_map(|attendee| attendee.email.clone())
+            .collect();
+        self.call(
+            "link_meeting",
+            vec![
+                ("id", meeting.id.clone().into()),
+                ("title", meeting.title.clone().unwrap_or_default().into()),
+                (
+                    "description",
+                    meeting.description.clone().unwrap_or_default().into(),
+                ),
+                ("start", meeting.start.to_rfc3339().into()),
+                ("end", meeting.end.to_rfc3339().into()),
+                (
+                    "minutes",
+                    (meeting.end - meeting.start).num_minutes().into(),
+                ),
+                (
+                    "organizer",
+                    meeting.organizer.clone().unwrap_or_default().into(),
+                ),
+                ("attendees", Value::List(attendees)),
+                ("color", color.into()),
+                (
+                    "response",
+                    meeting
+                        .my_response_status
+                        .clone()
+                        .unwrap_or_default()
+                        .into(),
+                ),
+            ],
+        )
+    }
+
+    pub fn link_session(&self, session: &GitHubSession) -> Option<ScriptLink> {
+        self.call(
+            "link_session",
+            vec![
+                ("id", session.id.clone().into()),
+                ("repo", session.repo.clone().into()),
+                ("description", session.description.clone().into()),
+                ("date", session.date.to_string().into()),
+                ("start", session.start_time.to_rfc3339().into()),
+                ("end", session.end_time.to_rfc3339().into()),
+                ("minutes", (session.duration_seconds / 60).into()),
+                ("issues", Value::List(session.get_jira_issues())),
+            ],
+        )
+    }
+
+    #[cfg(feature = "scripting")]
+    fn call(&self, function: &str, fields: Vec<(&str, Value)>) -> Option<ScriptLink> {
+        use log::warn;
+
+        let defined = self
+            .ast
+            .iter_functions()
+            .any(|f| f.name == function && f.params.len() == 1);
+        if !defined {
+            return None;
+        }
+        let subject: rhai::Map = fields
+            .into_iter()
+            .map(|(name, value)| (name.into(), value.into()))
+            .collect();
+        let result: rhai::Dynamic = self
+            .engine
+            .call_fn(&mut rhai::Scope::new(), &self.ast, function, (subject,))
+            .inspect_err(|e| warn!("linking.script {}() failed: {}", function, e))
+            .ok()?;
+        self.to_link(result)
+            .inspect_err(|e| warn!("linking.script {}() returned {}", function, e))
+            .ok()?
+    }
+
+    #[cfg(not(feature = "scripting"))]
+    fn call(&self, _function: &str, _fields: Vec<(&str, Value)>) -> Option<ScriptLink> {
+        None
+    }
+
+    #[cfg(feature = "scripting")]
+    fn to_link(&self, result: rhai::Dynamic) -> Result<Option<ScriptLink>, String> {
+        if result.is_unit() {
+            return Ok(None);
+        }
+        let type_name = result.type_name();
+        let (issue, minutes) = if result.is_string() {
+            (result.into_string()?, None)
+        } else if let Some(mut map) = result.try_cast::<rhai::Map>() {
+            let issue = map
+                .remove("issue")
+                .and_then(|issue| issue.into_string().ok())
+                .ok_or("a map without an `issue` key")?;
+            let minutes = match map.remove("minutes") {
+                None => None,
+                Some(minutes) if minutes.is_unit() => None,
+                Some(minutes) => Some(
+                    minutes
+                        .as_int()
+                        .map(|minutes| minutes as f64)
+                        .or_else(|_| minutes.as_float())
+                        .map_err(|_| format!("`minutes` as a {}", minutes.type_name()))?,
+                ),
+            };
+            (issue, minutes)
+        } else {
+            return Err(format!("a {}", type_name));
+        };
+
+        let issue = self.config.expand_issue_alias(&issue).to_uppercase();
+        if issue.is_empty() {
+            return Ok(None);
+        }
+        let seconds = minutes.map(|minutes| (minutes * 60.0).round() as i64);
+        if seconds.is_some_and(|seconds| seconds <= 0) {
+            return Err(format!("no time to log on {}", issue));
+        }
+        Ok(Some(ScriptLink { issue, seconds }))
+    }
+}
+
+/// A field of the meeting or session handed to the script
+#[cfg_attr(not(feature = "scripting"), allow(dead_code))]
+enum Value {
+    Text(String),
+    Number(i64),
+    List(Vec<String>),
+}
+
+impl From<String> for Value {
+    fn from(text: String) -> Self {
+        Value::Text(text)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(text: &str) -> Self {
+        Value::Text(text.to_string())
+    }
+}
+
+impl From<i64> for Value {
+    fn from(number: i64) -> Self {
+        Value::Number(number)
+    }
+}
+
+#[cfg(feature = "scripting")]
+impl From<Value> for rhai::Dynamic {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Text(text) => text.into(),
+            Value::Number(number) => number.into(),
+            Value::List(items) => items
+                .into_iter()
+                .map(rhai::Dynamic::from)
+                .collect::<rhai::Array>()
+                .into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_script_configured() {
+        assert!(LinkScript::load(&Config::default()).unwrap().is_none());
+    }
+
+    #[cfg(not(feature = "scripting"))]
+    #[test]
+    fn test_scripts_need_the_scripting_feature() {
+        let error = LinkScript::compile("", &Config::default()).err().unwrap();
+        assert!(error.contains("--features scripting"), "{}", error);
+    }
+
+    #[cfg(feature = "scripting")]
+    mod scripting {
+        use super::*;
+        use chrono::{TimeZone, Utc};
+        use std::collections::HashMap;
+
+        const SCRIPT: &str = r#"
+            fn link_meeting(meeting) {
+                if meeting.title.contains("Standup") {
+                    return #{ issue: "standup", minutes: 15 };
+                }
+                if meeting.color == "Tomato" {
+                    return "ops-7";
+                }
+            }
+            fn link_session(session) {
+                if session.repo == "acme/infra" && session.issues.is_empty() {
+                    return #{ issue: "OPS-7", minutes: session.minutes / 2 };
+                }
+            }
+        "#;
+
+        fn script() -> LinkScript {
+            let mut config = Config::default();
+            config
+                .aliases
+                .insert("standup".to_string(), "PROJ-101".to_string());
+            LinkScript::compile(SCRIPT, &config).unwrap()
+        }
+
+        fn meeting(title: &str, color_id: Option<&str>) -> Meeting {
+            Meeting {
+                id: "meet-1".to_string(),
+                title: Some(title.to_string()),
+                description: None,
+                start: Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap(),
+                end: Utc.with_ymd_and_hms(2026, 3, 2, 9, 30, 0).unwrap(),
+                attendees: None,
+                jira_link: None,
+                recurrence: None,
+                logs: HashMap::new(),
+                my_response_status: None,
+                color_id: color_id.map(str::to_string),
+                organizer: None,
+                conference_link: None,
+                calendar: None,
+                recurring_event_id: None,
+                event_type: None,
+            }
+        }
+
+        #[test]
+        fn test_meetings_linked_by_the_script() {
+            let script = script();
+            assert_eq!(
+                script.link_meeting(&meeting("Daily Standup", None)),
+                Some(ScriptLink {
+                    issue: "PROJ-101".to_string(),
+                    seconds: Some(900),
+                })
+            );
+            assert_eq!(
+                script.link_meeting(&meeting("Incident review", Some("11"))),
+                Some(ScriptLink {
+                    issue: "OPS-7".to_string(),
+                    seconds: None,
+                })
+            );
+            assert_eq!(script.link_meeting(&meeting("1:1", None)), None);
+        }
+
+        #[test]
+        fn test_sessions_linked_by_the_script() {
+            let start = Utc.with_ymd_and_hms(2026, 3, 2, 14, 0, 0).unwrap();
+            let session = |repo: &str, issues: Vec<String>| {
+                GitHubSession::new(
+                    start,
+                    start + chrono::Duration::hours(2),
+                    7200,
+                    repo.to_string(),
+                    "Push to main".to_string(),
+                    issues,
+                    Vec::new(),
+                )
+            };
+            assert_eq!(
+                script().link_session(&session("acme/infra", Vec::new())),
+                Some(ScriptLink {
+                    issue: "OPS-7".to_string(),
+                    seconds: Some(3600),
+                })
+            );
+            assert_eq!(
+                script().link_session(&session("acme/infra", vec!["PROJ-3".to_string()])),
+                None
+            );
+        }
+
+        #[test]
+        fn test_broken_scripts_link_nothing() {
+            let config = Config::default();
+            assert!(LinkScript::compile("fn link_meeting(m) {", &config).is_err());
+
+            let wrong_type = LinkScript::compile("fn link_meeting(m) { 42 }", &config).unwrap();
+            assert_eq!(wrong_type.link_meeting(&meeting("Standup", None)), None);
+
+            let endless = LinkScript::compile("fn link_meeting(m) { loop {} }", &config).unwrap();
+            assert_eq!(endless.link_meeting(&meeting("Standup", None)), None);
+        }
+    }
+}
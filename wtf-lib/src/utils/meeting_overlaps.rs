@@ -0,0 +1,241 @@
+use crate::config::OverlapPolicy;
+use crate::models::data::Meeting;
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::HashMap;
+
+/// The time a meeting takes on one day
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeetingSlot {
+    pub id: String,
+    pub title: String,
+    pub day: NaiveDate,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl MeetingSlot {
+    /// The occurrence of the meeting on `day`, `None` when a recurring one doesn't take
+    /// place that day
+    pub fn of_meeting(meeting: &Meeting, day: NaiveDate) -> Option<Self> {
+        let start = if meeting.recurrence.is_some() {
+            meeting.get_start_for_day(day)?
+        } else {
+            meeting.start
+        };
+        Some(Self {
+            id: meeting.id.clone(),
+            title: meeting.title.clone().unwrap_or("no title".to_string()),
+            day,
+            start,
+            end: start + (meeting.end - meeting.start),
+        })
+    }
+
+    pub fn seconds(&self) -> i64 {
+        (self.end - self.start).num_seconds().max(0)
+    }
+
+    fn key(&self) -> (NaiveDate, String) {
+        (self.day, self.id.clone())
+    }
+}
+
+/// How the overlapping time of a group of meetings is counted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resolution {
+    /// Every meeting logs its whole length
+    Keep,
+    /// The overlapping time is shared evenly between the meetings running then
+    Split,
+    /// This meeting gets the overlapping time; where it isn't running, the longest
+    /// meeting running then does
+    Winner(String),
+}
+
+/// The longest meeting of a group, the earliest one on a tie
+pub fn longest(group: &[MeetingSlot]) -> &MeetingSlot {
+    longest_of(group).expect("overlap groups have at least two meetings")
+}
+
+fn longest_of<'a>(slots: impl IntoIterator<Item = &'a MeetingSlot>) -> Option<&'a MeetingSlot> {
+    slots
+        .into_iter()
+        .min_by_key(|slot| (std::cmp::Reverse(slot.seconds()), slot.start))
+}
+
+/// The resolution `policy` picks on its own; `Ask` falls back to the longest meeting
+/// when nobody is there to answer
+pub fn resolve(policy: OverlapPolicy, group: &[MeetingSlot]) -> Resolution {
+    match policy {
+        OverlapPolicy::Keep => Resolution::Keep,
+        OverlapPolicy::Split => Resolution::Split,
+        OverlapPolicy::Longest | OverlapPolicy::Ask => {
+            Resolution::Winner(longest(group).id.clone())
+        }
+    }
+}
+
+/// The groups of meetings overlapping each other, directly or through another one,
+/// ordered by start
+pub fn overlap_groups(slots: &[MeetingSlot]) -> Vec<Vec<MeetingSlot>> {
+    let mut sorted: Vec<&MeetingSlot> = slots.iter().collect();
+    sorted.sort_by_key(|slot| (slot.start, slot.end));
+
+    let mut groups = Vec::new();
+    let mut current: Vec<MeetingSlot> = Vec::new();
+    let mut current_end = None;
+    for slot in sorted {
+        if current_end.is_some_and(|end| slot.start < end) {
+            current_end = current_end.max(Some(slot.end));
+        } else {
+            if current.len() > 1 {
+                groups.push(std::mem::take(&mut current));
+            }
+            current.clear();
+            current_end = Some(slot.end);
+        }
+        current.push(slot.clone());
+    }
+    if current.len() > 1 {
+        groups.push(current);
+    }
+    groups
+}
+
+/// Seconds each meeting of an overlap group logs once `resolution` is applied
+pub fn allocate(
+    group: &[MeetingSlot],
+    resolution: &Resolution,
+) -> HashMap<(NaiveDate, String), i64> {
+    let mut seconds: HashMap<(NaiveDate, String), i64> =
+        group.iter().map(|slot| (slot.key(), 0)).collect();
+    let mut bounds: Vec<DateTime<Utc>> = group
+        .iter()
+        .flat_map(|slot| [slot.start, slot.end])
+        .collect();
+    bounds.sort();
+    bounds.dedup();
+
+    for window in bounds.windows(2) {
+        let (from, to) = (window[0], window[1]);
+        let running: Vec<&MeetingSlot> = group
+            .iter()
+            .filter(|slot| slot.start < to && from < slot.end)
+            .collect();
+        if running.is_empty() {
+            continue;
+        }
+        let length = (to - from).num_seconds();
+        match resolution {
+            Resolution::Keep => {
+                for slot in &running {
+                    *seconds.entry(slot.key()).or_default() += length;
+                }
+            }
+            Resolution::Split => {
+                let share = length / running.len() as i64;
+                for slot in &running {
+                    *seconds.entry(slot.key()).or_default() += share;
+                }
+                // The seconds left by the division go to the first meeting
+                *seconds.entry(running[0].key()).or_default() += length % running.len() as i64;
+            }
+            Resolution::Winner(id) => {
+                let winner = running
+                    .iter()
+                    .copied()
+                    .find(|slot| &slot.id == id)
+                    .or_else(|| longest_of(running.iter().copied()));
+                if let Some(winner) = winner {
+                    *seconds.entry(winner.key()).or_default() += length;
+                }
+            }
+        }
+    }
+    seconds
+}
+
+/// The meetings that log less than their length once their overlaps are settled, with
+/// the seconds left to them; `decide` settles each overlap group
+pub fn settle_overlaps(
+    slots: &[MeetingSlot],
+    mut decide: impl FnMut(&[MeetingSlot]) -> Resolution,
+) -> HashMap<(NaiveDate, String), i64> {
+    let mut trimmed = HashMap::new();
+    for group in overlap_groups(slots) {
+        let resolution = decide(&group);
+        let seconds = allocate(&group, &resolution);
+        for slot in &group {
+            let left = seconds.get(&slot.key()).copied().unwrap_or(0);
+            if left < slot.seconds() {
+                trimmed.insert(slot.key(), left);
+            }
+        }
+    }
+    trimmed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn slot(id: &str, from: (u32, u32), to: (u32, u32)) -> MeetingSlot {
+        let at =
+            |(hour, minute): (u32, u32)| Utc.with_ymd_and_hms(2026, 3, 2, hour, minute, 0).unwrap();
+        MeetingSlot {
+            id: id.to_string(),
+            title: id.to_string(),
+            day: NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+            start: at(from),
+            end: at(to),
+        }
+    }
+
+    fn left(trimmed: &HashMap<(NaiveDate, String), i64>, id: &str) -> Option<i64> {
+        trimmed
+            .get(&(NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(), id.to_string()))
+            .copied()
+    }
+
+    #[test]
+    fn test_overlap_groups_chain_meetings() {
+        let slots = vec![
+            slot("a", (9, 0), (10, 0)),
+            slot("b", (9, 30), (10, 30)),
+            slot("c", (10, 15), (11, 0)),
+            slot("d", (11, 0), (12, 0)),
+        ];
+
+        let groups = overlap_groups(&slots);
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(
+            groups[0].iter().map(|s| s.id.as_str()).collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+    }
+
+    #[test]
+    fn test_settle_overlaps() {
+        let slots = vec![
+            slot("long", (9, 0), (11, 0)),
+            slot("short", (10, 0), (10, 30)),
+        ];
+
+        let kept = settle_overlaps(&slots, |_| Resolution::Keep);
+        assert!(kept.is_empty());
+
+        let longest = settle_overlaps(&slots, |group| resolve(OverlapPolicy::Longest, group));
+        assert_eq!(left(&longest, "short"), Some(0));
+        assert_eq!(left(&longest, "long"), None);
+
+        let split = settle_overlaps(&slots, |_| Resolution::Split);
+        assert_eq!(left(&split, "long"), Some(6_300));
+        assert_eq!(left(&split, "short"), Some(900));
+
+        let chosen = settle_overlaps(&slots, |_| Resolution::Winner("short".to_string()));
+        assert_eq!(left(&chosen, "long"), Some(5_400));
+        assert_eq!(left(&chosen, "short"), None);
+    }
+}
@@ -1,6 +1,11 @@
-use crate::config::Config;
+use crate::config::{Config, MeetingRule, GOOGLE_CALENDAR_EVENT_COLORS};
 use crate::models::data::Meeting;
-use std::collections::HashSet;
+use crate::services::jira_service::get_jira_identifier;
+use crate::utils::link_script::LinkScript;
+use chrono::NaiveDate;
+use log::warn;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
 
 const UNTRACK_KEYWORD: &str = "#untrack";
 const NOTRACK_COLOR_VALUE: &str = "notrack";
@@ -10,7 +15,6 @@ pub fn is_notrack_color(meeting: &Meeting, config: &Config) -> bool {
     if let Some(color_id) = &meeting.color_id {
         if let Ok(idx) = color_id.parse::<usize>() {
             if (1..=11).contains(&idx) {
-                use crate::config::GOOGLE_CALENDAR_EVENT_COLORS;
                 let color_name = GOOGLE_CALENDAR_EVENT_COLORS[idx - 1];
                 return config
                     .google
@@ -49,7 +53,6 @@ pub fn is_untracked(meeting: &Meeting, config: &Config, manual_ids: &HashSet<Str
     if let Some(color_id) = &meeting.color_id {
         if let Ok(idx) = color_id.parse::<usize>() {
             if (1..=11).contains(&idx) {
-                use crate::config::GOOGLE_CALENDAR_EVENT_COLORS;
                 let color_name = GOOGLE_CALENDAR_EVENT_COLORS[idx - 1];
                 if config
                     .google
@@ -67,13 +70,175 @@ pub fn is_untracked(meeting: &Meeting, config: &Config, manual_ids: &HashSet<Str
     false
 }
 
+/// Whether `meeting` fulfils every condition `rule` sets; a rule without conditions
+/// matches nothing
+pub fn matches_rule(rule: &MeetingRule, meeting: &Meeting) -> bool {
+    if rule.title.is_none() && rule.attendee.is_none() && rule.color.is_none() {
+        return false;
+    }
+    let title =
+        rule.title
+            .as_ref()
+            .is_none_or(|pattern| match Regex::new(&format!("(?i){}", pattern)) {
+                Ok(regex) => meeting.title.as_deref().is_some_and(|t| regex.is_match(t)),
+                Err(e) => {
+                    warn!("Invalid meeting_rules title '{}': {}", pattern, e);
+                    false
+                }
+            });
+    let attendee = rule.attendee.as_ref().is_none_or(|wanted| {
+        meeting.attendees.iter().flatten().any(|a| {
+            [&a.email, &a.display_name]
+                .into_iter()
+                .flatten()
+                .any(|value| value.eq_ignore_ascii_case(wanted))
+        })
+    });
+    let color = rule.color.as_ref().is_none_or(|wanted| {
+        meeting
+            .color_id
+            .as_deref()
+            .and_then(|id| id.parse::<usize>().ok())
+            .and_then(|index| GOOGLE_CALENDAR_EVENT_COLORS.get(index.checked_sub(1)?))
+            .is_some_and(|name| name.eq_ignore_ascii_case(wanted))
+    });
+    title && attendee && color
+}
+
+/// The issue key the auto-link step would link a meeting to: `[meetings] focus_time_issue`
+/// for a focus-time block, the one `script` picks, then the one of the first matching
+/// `[meeting_rules]` rule, or the first key found in its title or description. The
+/// script's and the found keys are kept when `is_known` says the issue exists. Meetings
+/// already linked, untracked or not attended (see `[meetings]`) are never auto-linked.
+pub fn autolink_key(
+    meeting: &Meeting,
+    config: &Config,
+    untracked_ids: &HashSet<String>,
+    script: Option<&LinkScript>,
+    is_known: impl Fn(&str) -> bool,
+) -> Option<String> {
+    if meeting.jira_link.is_some()
+        || !config.meetings.attends(meeting)
+        || is_untracked(meeting, config, untracked_ids)
+    {
+        return None;
+    }
+    if let Some(issue) = config
+        .meetings
+        .focus_time_issue
+        .as_ref()
+        .filter(|_| meeting.is_focus_time())
+    {
+        return Some(issue.clone());
+    }
+    if let Some(link) = script.and_then(|script| script.link_meeting(meeting)) {
+        if is_known(&link.issue) {
+            return Some(link.issue);
+        }
+    }
+    if let Some(issue) = config.meeting_rules.issue_for(meeting) {
+        return Some(issue);
+    }
+    let text = format!(
+        "{} {}",
+        meeting.title.as_deref().unwrap_or_default(),
+        meeting.description.as_deref().unwrap_or_default()
+    );
+    get_jira_identifier(&text).filter(|key| is_known(key))
+}
+
+/// Attendee response counts for a meeting
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ResponseBreakdown {
+    pub accepted: usize,
+    pub declined: usize,
+    pub tentative: usize,
+    pub pending: usize,
+}
+
+pub fn response_breakdown(meeting: &Meeting) -> ResponseBreakdown {
+    let mut breakdown = ResponseBreakdown::default();
+    for attendee in meeting.attendees.iter().flatten() {
+        match attendee.response_status.as_deref() {
+            Some("accepted") => breakdown.accepted += 1,
+            Some("declined") => breakdown.declined += 1,
+            Some("tentative") => breakdown.tentative += 1,
+            _ => breakdown.pending += 1,
+        }
+    }
+    breakdown
+}
+
+/// Human-readable summary of RRULE lines, e.g. "Every 2 weeks on Mon, Thu until 2026-03-31"
+pub fn describe_recurrence(rules: &[String]) -> Option<String> {
+    let rule = rules
+        .iter()
+        .find_map(|r| r.strip_prefix("RRULE:"))?
+        .split(';')
+        .filter_map(|part| part.split_once('='))
+        .collect::<HashMap<&str, &str>>();
+
+    let interval: u32 = rule
+        .get("INTERVAL")
+        .and_then(|i| i.parse().ok())
+        .unwrap_or(1);
+    let unit = match *rule.get("FREQ")? {
+        "DAILY" => "day",
+        "WEEKLY" => "week",
+        "MONTHLY" => "month",
+        "YEARLY" => "year",
+        other => return Some(other.to_lowercase()),
+    };
+    let mut summary = match (interval, unit) {
+        (1, "day") => "Daily".to_string(),
+        (1, unit) => format!("{}ly", capitalize(unit)),
+        (n, unit) => format!("Every {} {}s", n, unit),
+    };
+
+    if let Some(days) = rule.get("BYDAY") {
+        let names: Vec<&str> = days.split(',').map(weekday_name).collect();
+        summary.push_str(&format!(" on {}", names.join(", ")));
+    }
+    if let Some(until) = rule.get("UNTIL") {
+        let date = until.get(..8).unwrap_or(until);
+        if let Ok(date) = NaiveDate::parse_from_str(date, "%Y%m%d") {
+            summary.push_str(&format!(" until {}", date));
+        }
+    } else if let Some(count) = rule.get("COUNT") {
+        summary.push_str(&format!(", {} times", count));
+    }
+    Some(summary)
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn weekday_name(day: &str) -> &str {
+    // BYDAY may carry an ordinal prefix such as "1MO" or "-1FR"
+    match day.trim_start_matches(|c: char| c == '-' || c.is_ascii_digit()) {
+        "MO" => "Mon",
+        "TU" => "Tue",
+        "WE" => "Wed",
+        "TH" => "Thu",
+        "FR" => "Fri",
+        "SA" => "Sat",
+        "SU" => "Sun",
+        _ => day,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{GoogleConfig, GOOGLE_CALENDAR_EVENT_COLORS};
+    use crate::config::{GoogleAuthFlow, GoogleConfig};
+    use crate::models::data::Attendee;
     use crate::models::data::Meeting;
     use chrono::Utc;
-    use std::collections::HashMap;
 
     fn base_meeting() -> Meeting {
         Meeting {
@@ -88,6 +253,11 @@ mod tests {
             logs: HashMap::new(),
             my_response_status: None,
             color_id: None,
+            organizer: None,
+            conference_link: None,
+            calendar: None,
+            recurring_event_id: None,
+            event_type: None,
         }
     }
 
@@ -135,6 +305,11 @@ mod tests {
             credentials_path: String::new(),
             token_cache_path: String::new(),
             color_labels,
+            calendar_ids: Vec::new(),
+            time_off_keywords: Vec::new(),
+            service_account_key_path: None,
+            impersonate: None,
+            auth_flow: GoogleAuthFlow::default(),
         });
 
         assert!(is_untracked(&meeting, &config, &HashSet::new()));
@@ -153,8 +328,204 @@ mod tests {
             credentials_path: String::new(),
             token_cache_path: String::new(),
             color_labels,
+            calendar_ids: Vec::new(),
+            time_off_keywords: Vec::new(),
+            service_account_key_path: None,
+            impersonate: None,
+            auth_flow: GoogleAuthFlow::default(),
         });
 
         assert!(!is_untracked(&meeting, &config, &HashSet::new()));
     }
+
+    #[test]
+    fn test_autolink_key_from_title_or_description() {
+        let config = Config::default();
+        let known = |key: &str| key == "PROJ-12";
+        let mut meeting = base_meeting();
+        meeting.title = Some("Refinement".to_string());
+        meeting.description = Some("Agenda: proj-12 and OPS-1".to_string());
+        assert_eq!(
+            autolink_key(&meeting, &config, &HashSet::new(), None, known),
+            Some("PROJ-12".to_string())
+        );
+
+        meeting.title = Some("OPS-1 follow-up".to_string());
+        meeting.description = None;
+        assert_eq!(
+            autolink_key(&meeting, &config, &HashSet::new(), None, known),
+            None
+        );
+    }
+
+    #[test]
+    fn test_meeting_rules_come_before_the_keys_in_the_text() {
+        let mut config = Config::default();
+        config.meeting_rules.rules = vec![
+            MeetingRule {
+                issue: Some("OPS-7".to_string()),
+                attendee: Some("ops@example.com".to_string()),
+                color: Some("grape".to_string()),
+                ..MeetingRule::default()
+            },
+            MeetingRule {
+                issue: Some("PROJ-100".to_string()),
+                title: Some("stand-?up".to_string()),
+                ..MeetingRule::default()
+            },
+        ];
+        let known = |key: &str| key == "PROJ-12";
+        let mut meeting = base_meeting();
+        meeting.title = Some("Daily Standup PROJ-12".to_string());
+        assert_eq!(
+            autolink_key(&meeting, &config, &HashSet::new(), None, known),
+            Some("PROJ-100".to_string())
+        );
+
+        // Every condition of a rule has to hold
+        meeting.attendees = Some(vec![Attendee {
+            display_name: None,
+            comment: None,
+            email: Some("OPS@example.com".to_string()),
+            response_status: None,
+            organizer: false,
+        }]);
+        assert_eq!(
+            config.meeting_rules.issue_for(&meeting).as_deref(),
+            Some("PROJ-100")
+        );
+        meeting.color_id = Some("3".to_string()); // Grape
+        assert_eq!(
+            config.meeting_rules.issue_for(&meeting).as_deref(),
+            Some("OPS-7")
+        );
+
+        assert!(!matches_rule(&MeetingRule::default(), &meeting));
+    }
+
+    #[test]
+    fn test_meeting_rules_scale_worklogs() {
+        let mut config = Config::default();
+        config.meeting_rules.rules = vec![
+            MeetingRule {
+                title: Some("guild".to_string()),
+                multiplier: Some(0.5),
+                min_minutes: Some(20),
+                ..MeetingRule::default()
+            },
+            MeetingRule {
+                issue: Some("PROJ-100".to_string()),
+                title: Some("stand-?up".to_string()),
+                min_minutes: Some(15),
+                ..MeetingRule::default()
+            },
+        ];
+        let rules = &config.meeting_rules;
+        let mut meeting = base_meeting();
+        meeting.title = Some("Rust guild".to_string());
+        assert_eq!(rules.worklog_seconds(&meeting, 3600), 1800);
+        assert_eq!(rules.worklog_seconds(&meeting, 1800), 1200);
+        // A rule only scaling time links nothing
+        assert_eq!(rules.issue_for(&meeting), None);
+
+        meeting.title = Some("Standup".to_string());
+        assert_eq!(rules.worklog_seconds(&meeting, 600), 900);
+        assert_eq!(rules.worklog_seconds(&meeting, 1200), 1200);
+
+        meeting.title = Some("Planning".to_string());
+        assert_eq!(rules.worklog_seconds(&meeting, 3600), 3600);
+    }
+
+    #[test]
+    fn test_focus_time_goes_to_its_issue() {
+        let mut config = Config::default();
+        let mut meeting = base_meeting();
+        meeting.title = Some("Focus time PROJ-12".to_string());
+        meeting.event_type = Some("focusTime".to_string());
+        let known = |key: &str| key == "PROJ-12";
+        assert_eq!(
+            autolink_key(&meeting, &config, &HashSet::new(), None, known),
+            Some("PROJ-12".to_string())
+        );
+
+        config.meetings.focus_time_issue = Some("DEV-1".to_string());
+        assert_eq!(
+            autolink_key(&meeting, &config, &HashSet::new(), None, known),
+            Some("DEV-1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_autolink_key_skips_linked_and_declined() {
+        let config = Config::default();
+        let mut meeting = base_meeting();
+        meeting.title = Some("PROJ-12 demo".to_string());
+        meeting.my_response_status = Some("declined".to_string());
+        assert_eq!(
+            autolink_key(&meeting, &config, &HashSet::new(), None, |_| true),
+            None
+        );
+
+        meeting.my_response_status = None;
+        meeting.jira_link = Some("PROJ-1".to_string());
+        assert_eq!(
+            autolink_key(&meeting, &config, &HashSet::new(), None, |_| true),
+            None
+        );
+    }
+
+    #[test]
+    fn test_describe_recurrence() {
+        let weekly = vec!["RRULE:FREQ=WEEKLY;BYDAY=MO,TH".to_string()];
+        assert_eq!(
+            describe_recurrence(&weekly).as_deref(),
+            Some("Weekly on Mon, Thu")
+        );
+
+        let biweekly =
+            vec!["RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=FR;UNTIL=20260331T000000Z".to_string()];
+        assert_eq!(
+            describe_recurrence(&biweekly).as_deref(),
+            Some("Every 2 weeks on Fri until 2026-03-31")
+        );
+
+        let daily = vec![
+            "EXDATE:20260101".to_string(),
+            "RRULE:FREQ=DAILY;COUNT=5".to_string(),
+        ];
+        assert_eq!(
+            describe_recurrence(&daily).as_deref(),
+            Some("Daily, 5 times")
+        );
+        assert_eq!(describe_recurrence(&[]), None);
+    }
+
+    #[test]
+    fn test_response_breakdown() {
+        let mut meeting = base_meeting();
+        let attendee = |status: Option<&str>| Attendee {
+            display_name: None,
+            comment: None,
+            email: None,
+            response_status: status.map(str::to_string),
+            organizer: false,
+        };
+        meeting.attendees = Some(vec![
+            attendee(Some("accepted")),
+            attendee(Some("accepted")),
+            attendee(Some("declined")),
+            attendee(Some("needsAction")),
+            attendee(None),
+        ]);
+
+        assert_eq!(
+            response_breakdown(&meeting),
+            ResponseBreakdown {
+                accepted: 2,
+                declined: 1,
+                tentative: 0,
+                pending: 2,
+            }
+        );
+    }
 }
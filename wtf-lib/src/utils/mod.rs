@@ -1,3 +1,18 @@
 pub mod branding;
+pub mod config_file;
+pub mod csv;
+pub mod dates;
+pub mod gap_fill;
+pub mod hooks;
+pub mod link_script;
+pub mod meeting_overlaps;
 pub mod meetings;
+pub mod period;
+pub mod sprint_report;
+pub mod team_coverage;
+pub mod template;
+pub mod timesheet;
+pub mod timesheet_html;
 pub mod version;
+pub mod worklog_import;
+pub mod worklog_rules;
@@ -0,0 +1,132 @@
+use crate::models::data::{Meeting, Sprint};
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, NaiveTime, Utc};
+use serde::Serialize;
+use std::fmt;
+
+/// The days the wizard, gap filling and reports work on: a sprint's, or a calendar week
+/// or month for teams without sprints
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Period {
+    pub name: String,
+    pub start: NaiveDate,
+    /// Last day, included
+    pub end: NaiveDate,
+    /// The sprint the period comes from, if any
+    pub sprint_id: Option<usize>,
+}
+
+impl Period {
+    /// Monday to Sunday of the week holding `date`
+    pub fn week_of(date: NaiveDate) -> Self {
+        let start = date - Duration::days(date.weekday().num_days_from_monday() as i64);
+        Self {
+            name: format!("Week {}", start.format("%G-W%V")),
+            start,
+            end: start + Duration::days(6),
+            sprint_id: None,
+        }
+    }
+
+    /// The calendar month holding `date`
+    pub fn month_of(date: NaiveDate) -> Self {
+        let start = date.with_day(1).unwrap_or(date);
+        let end = start
+            .checked_add_months(Months::new(1))
+            .map_or(start, |next| next - Duration::days(1));
+        Self {
+            name: format!("Month {}", start.format("%B %Y")),
+            start,
+            end,
+            sprint_id: None,
+        }
+    }
+
+    /// The sprint's days, `None` when it has no dates
+    pub fn of_sprint(sprint: &Sprint) -> Option<Self> {
+        Some(Self {
+            name: sprint.name.clone(),
+            start: sprint.start?.date_naive(),
+            end: sprint.end?.date_naive(),
+            sprint_id: Some(sprint.id),
+        })
+    }
+
+    /// Read `week`, `last-week`, `month` or `last-month`, relative to `today`
+    pub fn parse(spec: &str, today: NaiveDate) -> Result<Self, String> {
+        match spec.trim().to_lowercase().as_str() {
+            "week" => Ok(Self::week_of(today)),
+            "last-week" => Ok(Self::week_of(today - Duration::days(7))),
+            "month" => Ok(Self::month_of(today)),
+            "last-month" => Ok(Self::month_of(
+                today.with_day(1).unwrap_or(today) - Duration::days(1),
+            )),
+            _ => Err(format!(
+                "unknown period '{}' (week, last-week, month or last-month)",
+                spec
+            )),
+        }
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        date >= self.start && date <= self.end
+    }
+
+    /// Midnight (UTC) of the first day
+    pub fn start_time(&self) -> DateTime<Utc> {
+        self.start.and_time(NaiveTime::MIN).and_utc()
+    }
+
+    /// The last second (UTC) of the last day
+    pub fn end_time(&self) -> DateTime<Utc> {
+        self.end
+            .and_hms_opt(23, 59, 59)
+            .unwrap_or_default()
+            .and_utc()
+    }
+
+    /// Whether the meeting, or one of its occurrences, falls on one of the days
+    pub fn contains_meeting(&self, meeting: &Meeting) -> bool {
+        meeting.is_between(self.start_time(), self.end_time())
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({} → {})", self.name, self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_week_and_month_bounds() {
+        // 2026-03-11 is a Wednesday
+        let week = Period::week_of(date(3, 11));
+        assert_eq!((week.start, week.end), (date(3, 9), date(3, 15)));
+        assert_eq!(week.name, "Week 2026-W11");
+
+        let month = Period::month_of(date(2, 11));
+        assert_eq!((month.start, month.end), (date(2, 1), date(2, 28)));
+        assert!(month.contains(date(2, 28)) && !month.contains(date(3, 1)));
+    }
+
+    #[test]
+    fn test_parse_relative_periods() {
+        let today = date(3, 2);
+        assert_eq!(
+            Period::parse("last-week", today).unwrap().start,
+            date(2, 23)
+        );
+        assert_eq!(
+            Period::parse("Last-Month", today).unwrap(),
+            Period::month_of(date(2, 1))
+        );
+        assert!(Period::parse("sprint", today).is_err());
+    }
+}
@@ -0,0 +1,278 @@
+use crate::models::jira::JiraWorklog;
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// Hours logged by everyone on a sprint's issues, per person, issue and day
+#[derive(Debug, Clone, Serialize)]
+pub struct SprintReport {
+    pub sprint: String,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    /// Sorted by hours, most first
+    pub people: Vec<PersonHours>,
+    /// Sorted by hours, most first
+    pub issues: Vec<IssueHours>,
+    /// Only the days with time logged
+    pub days: Vec<DayHours>,
+    pub total_hours: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PersonHours {
+    pub name: String,
+    pub hours: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueHours {
+    pub key: String,
+    /// `None` when the issue isn't in the local database
+    pub summary: Option<String>,
+    pub hours: f64,
+    pub by_person: BTreeMap<String, f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DayHours {
+    pub date: NaiveDate,
+    pub hours: f64,
+    pub by_person: BTreeMap<String, f64>,
+}
+
+impl SprintReport {
+    /// Group the worklogs started between `from` and `to` (inclusive) by author, issue
+    /// and day. `summaries` maps issue keys to their titles.
+    pub fn build(
+        sprint: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+        worklogs: &[JiraWorklog],
+        summaries: &HashMap<String, String>,
+    ) -> Self {
+        let mut people: HashMap<String, f64> = HashMap::new();
+        let mut issues: HashMap<String, BTreeMap<String, f64>> = HashMap::new();
+        let mut days: BTreeMap<NaiveDate, BTreeMap<String, f64>> = BTreeMap::new();
+        for worklog in worklogs {
+            let date = worklog.started.date_naive();
+            if date < from || date > to {
+                continue;
+            }
+            let name = &worklog.author.display_name;
+            let hours = worklog.time_spent_seconds as f64 / 3600.0;
+            *people.entry(name.clone()).or_default() += hours;
+            *issues
+                .entry(worklog.issue_id.clone())
+                .or_default()
+                .entry(name.clone())
+                .or_default() += hours;
+            *days
+                .entry(date)
+                .or_default()
+                .entry(name.clone())
+                .or_default() += hours;
+        }
+
+        let sum = |by_person: &BTreeMap<String, f64>| by_person.values().fold(0.0, |a, h| a + h);
+        let mut people: Vec<_> = people
+            .into_iter()
+            .map(|(name, hours)| PersonHours { name, hours })
+            .collect();
+        people.sort_by(|a, b| b.hours.total_cmp(&a.hours).then(a.name.cmp(&b.name)));
+        let mut issues: Vec<_> = issues
+            .into_iter()
+            .map(|(key, by_person)| IssueHours {
+                summary: summaries.get(&key).cloned(),
+                hours: sum(&by_person),
+                key,
+                by_person,
+            })
+            .collect();
+        issues.sort_by(|a, b| b.hours.total_cmp(&a.hours).then(a.key.cmp(&b.key)));
+        let days = days
+            .into_iter()
+            .map(|(date, by_person)| DayHours {
+                date,
+                hours: sum(&by_person),
+                by_person,
+            })
+            .collect();
+
+        Self {
+            sprint: sprint.to_string(),
+            from,
+            to,
+            total_hours: people.iter().fold(0.0, |total, p| total + p.hours),
+            people,
+            issues,
+            days,
+        }
+    }
+
+    /// Header and cells of the person totals, ending with a "Total" row
+    pub fn people_grid(&self) -> (Vec<String>, Vec<Vec<String>>) {
+        let header = vec!["Person".to_string(), "Hours".to_string()];
+        let mut rows: Vec<Vec<String>> = self
+            .people
+            .iter()
+            .map(|person| vec![person.name.clone(), hours(person.hours)])
+            .collect();
+        rows.push(vec!["Total".to_string(), hours(self.total_hours)]);
+        (header, rows)
+    }
+
+    /// Header and cells of the issue × person grid, ending with a "Total" column
+    pub fn issue_grid(&self) -> (Vec<String>, Vec<Vec<String>>) {
+        let mut header = vec!["Issue".to_string(), "Summary".to_string()];
+        header.extend(self.people.iter().map(|p| p.name.clone()));
+        header.push("Total".to_string());
+        let rows = self
+            .issues
+            .iter()
+            .map(|issue| {
+                let mut row = vec![issue.key.clone(), issue.summary.clone().unwrap_or_default()];
+                row.extend(self.person_cells(&issue.by_person));
+                row.push(hours(issue.hours));
+                row
+            })
+            .collect();
+        (header, rows)
+    }
+
+    /// Header and cells of the day × person grid, ending with a "Total" column
+    pub fn day_grid(&self) -> (Vec<String>, Vec<Vec<String>>) {
+        let mut header = vec!["Day".to_string()];
+        header.extend(self.people.iter().map(|p| p.name.clone()));
+        header.push("Total".to_string());
+        let rows = self
+            .days
+            .iter()
+            .map(|day| {
+                let mut row = vec![day.date.format("%a %Y-%m-%d").to_string()];
+                row.extend(self.person_cells(&day.by_person));
+                row.push(hours(day.hours));
+                row
+            })
+            .collect();
+        (header, rows)
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!(
+            "## Sprint {} ({} → {})\n\n{:.2}h logged on {} issue(s) by {} person(s)\n",
+            self.sprint,
+            self.from,
+            self.to,
+            self.total_hours,
+            self.issues.len(),
+            self.people.len()
+        );
+        for (title, (header, rows)) in [
+            ("By person", self.people_grid()),
+            ("By issue", self.issue_grid()),
+            ("By day", self.day_grid()),
+        ] {
+            out.push_str(&format!("\n### {}\n\n", title));
+            out.push_str(&markdown_table(&header, &rows));
+        }
+        out
+    }
+
+    fn person_cells<'a>(
+        &'a self,
+        by_person: &'a BTreeMap<String, f64>,
+    ) -> impl Iterator<Item = String> + 'a {
+        self.people.iter().map(|person| {
+            by_person
+                .get(&person.name)
+                .map(|h| hours(*h))
+                .unwrap_or_default()
+        })
+    }
+}
+
+pub(crate) fn hours(hours: f64) -> String {
+    format!("{:.2}", hours)
+}
+
+pub(crate) fn markdown_table(header: &[String], rows: &[Vec<String>]) -> String {
+    // Pipes would split a cell in two
+    let line = |cells: &[String]| {
+        let cells: Vec<_> = cells.iter().map(|c| c.replace('|', "\\|")).collect();
+        format!("| {} |\n", cells.join(" | "))
+    };
+    let mut out = line(header);
+    out.push_str(&line(&vec!["---".to_string(); header.len()]));
+    for row in rows {
+        out.push_str(&line(row));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::jira::JiraAuthor;
+    use chrono::{TimeZone, Utc};
+
+    fn worklog(author: &str, issue: &str, day: u32, seconds: u64) -> JiraWorklog {
+        let started = Utc.with_ymd_and_hms(2026, 1, day, 9, 0, 0).unwrap();
+        JiraWorklog {
+            id: format!("{}-{}-{}", author, issue, day),
+            author: JiraAuthor {
+                display_name: author.to_string(),
+                email_address: String::new(),
+            },
+            created: started,
+            time_spent: String::new(),
+            time_spent_seconds: seconds,
+            comment: None,
+            issue_id: issue.to_string(),
+            started,
+        }
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, day).unwrap()
+    }
+
+    fn report() -> SprintReport {
+        let worklogs = vec![
+            worklog("Ada", "PROJ-1", 5, 3600),
+            worklog("Ada", "PROJ-2", 5, 1800),
+            worklog("Bob", "PROJ-2", 6, 7200),
+            worklog("Bob", "PROJ-2", 20, 3600),
+        ];
+        let summaries = HashMap::from([("PROJ-2".to_string(), "Fix | login".to_string())]);
+        SprintReport::build("12", date(5), date(16), &worklogs, &summaries)
+    }
+
+    #[test]
+    fn test_build_groups_by_person_issue_and_day() {
+        let report = report();
+
+        assert_eq!(report.total_hours, 3.5);
+        let people: Vec<_> = report
+            .people
+            .iter()
+            .map(|p| (p.name.as_str(), p.hours))
+            .collect();
+        assert_eq!(people, vec![("Bob", 2.0), ("Ada", 1.5)]);
+        assert_eq!(report.issues[0].key, "PROJ-2");
+        assert_eq!(report.issues[0].hours, 2.5);
+        assert_eq!(report.issues[0].by_person["Ada"], 0.5);
+        assert_eq!(report.issues[1].summary, None);
+        let days: Vec<_> = report.days.iter().map(|d| d.date).collect();
+        assert_eq!(days, vec![date(5), date(6)]);
+    }
+
+    #[test]
+    fn test_markdown_has_one_table_per_breakdown() {
+        let md = report().to_markdown();
+
+        assert!(md.starts_with("## Sprint 12 (2026-01-05 → 2026-01-16)\n"));
+        assert!(md.contains("| Person | Hours |\n| --- | --- |\n| Bob | 2.00 |"));
+        assert!(md.contains("| PROJ-2 | Fix \\| login | 2.00 | 0.50 | 2.50 |"));
+        assert!(md.contains("| Mon 2026-01-05 |  | 1.50 | 1.50 |"));
+    }
+}
@@ -0,0 +1,202 @@
+use crate::models::jira::JiraWorklog;
+use crate::utils::sprint_report::{hours, markdown_table};
+use chrono::NaiveDate;
+use serde::Serialize;
+
+/// How well the configured teammates logged a sprint, read from their Jira worklogs
+#[derive(Debug, Clone, Serialize)]
+pub struct TeamCoverage {
+    pub sprint: String,
+    pub from: NaiveDate,
+    /// Last day checked: the sprint's end, or today while it runs
+    pub to: NaiveDate,
+    /// In the order of `[team] members`
+    pub members: Vec<MemberCoverage>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MemberCoverage {
+    /// The account as configured
+    pub account: String,
+    /// Jira display name, the account when nothing was logged
+    pub name: String,
+    pub hours: f64,
+    pub expected_hours: f64,
+    /// Logged hours over expected hours, in percent
+    pub coverage: f64,
+    /// Workdays logged under their expected hours
+    pub missing_days: Vec<NaiveDate>,
+}
+
+impl TeamCoverage {
+    /// Sum the worklogs of each of `members` (matched on the author's email or display
+    /// name) between `from` and `to`, against `target_hours` per day. Absences aren't
+    /// known for teammates, so every workday counts.
+    pub fn build(
+        sprint: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+        worklogs: &[JiraWorklog],
+        members: &[String],
+        target_hours: impl Fn(NaiveDate) -> f64,
+    ) -> Self {
+        let days: Vec<NaiveDate> = from.iter_days().take_while(|day| *day <= to).collect();
+        let expected_hours: f64 = days.iter().map(|day| target_hours(*day)).sum();
+
+        let members = members
+            .iter()
+            .map(|account| {
+                let theirs: Vec<&JiraWorklog> = worklogs
+                    .iter()
+                    .filter(|w| is_author(w, account))
+                    .filter(|w| (from..=to).contains(&w.started.date_naive()))
+                    .collect();
+                let logged_on = |day: NaiveDate| {
+                    theirs
+                        .iter()
+                        .filter(|w| w.started.date_naive() == day)
+                        .map(|w| w.time_spent_seconds as f64 / 3600.0)
+                        .sum::<f64>()
+                };
+                let hours = theirs
+                    .iter()
+                    .map(|w| w.time_spent_seconds as f64 / 3600.0)
+                    .sum();
+                MemberCoverage {
+                    account: account.clone(),
+                    name: theirs
+                        .first()
+                        .map_or(account.clone(), |w| w.author.display_name.clone()),
+                    hours,
+                    expected_hours,
+                    coverage: if expected_hours > 0.0 {
+                        hours / expected_hours * 100.0
+                    } else {
+                        0.0
+                    },
+                    missing_days: days
+                        .iter()
+                        .copied()
+                        .filter(|day| {
+                            target_hours(*day) > 0.0 && logged_on(*day) < target_hours(*day)
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Self {
+            sprint: sprint.to_string(),
+            from,
+            to,
+            members,
+        }
+    }
+
+    /// Header and cells of the per-person table
+    pub fn grid(&self) -> (Vec<String>, Vec<Vec<String>>) {
+        let header = ["Person", "Logged", "Expected", "Coverage", "Missing days"]
+            .map(String::from)
+            .to_vec();
+        let rows = self
+            .members
+            .iter()
+            .map(|member| {
+                vec![
+                    member.name.clone(),
+                    hours(member.hours),
+                    hours(member.expected_hours),
+                    format!("{:.0}%", member.coverage),
+                    member
+                        .missing_days
+                        .iter()
+                        .map(|day| day.format("%a %d").to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ]
+            })
+            .collect();
+        (header, rows)
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let (header, rows) = self.grid();
+        format!(
+            "## Sprint {} team coverage ({} → {})\n\n{}",
+            self.sprint,
+            self.from,
+            self.to,
+            markdown_table(&header, &rows)
+        )
+    }
+}
+
+fn is_author(worklog: &JiraWorklog, account: &str) -> bool {
+    let account = account.trim();
+    worklog.author.email_address.eq_ignore_ascii_case(account)
+        || worklog.author.display_name.eq_ignore_ascii_case(account)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::jira::JiraAuthor;
+    use chrono::{Datelike, TimeZone, Utc, Weekday};
+
+    fn worklog(email: &str, name: &str, day: u32, hours: u64) -> JiraWorklog {
+        let started = Utc.with_ymd_and_hms(2026, 3, day, 9, 0, 0).unwrap();
+        JiraWorklog {
+            id: format!("{}-{}", email, day),
+            author: JiraAuthor {
+                display_name: name.to_string(),
+                email_address: email.to_string(),
+            },
+            created: started,
+            time_spent: String::new(),
+            time_spent_seconds: hours * 3600,
+            comment: None,
+            issue_id: "PROJ-1".to_string(),
+            started,
+        }
+    }
+
+    #[test]
+    fn test_coverage_per_member() {
+        // Monday 2 to Sunday 8 March 2026: five workdays of 8 hours
+        let (from, to) = (
+            NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 3, 8).unwrap(),
+        );
+        let mut worklogs: Vec<_> = (2..=6)
+            .map(|day| worklog("ada@x.io", "Ada", day, 8))
+            .collect();
+        worklogs.push(worklog("bob@x.io", "Bob", 3, 4));
+        worklogs.push(worklog("eve@x.io", "Eve", 3, 8));
+        let members = [
+            "ADA@x.io".to_string(),
+            "Bob".to_string(),
+            "max@x.io".to_string(),
+        ];
+        let target = |day: NaiveDate| match day.weekday() {
+            Weekday::Sat | Weekday::Sun => 0.0,
+            _ => 8.0,
+        };
+
+        let coverage = TeamCoverage::build("12", from, to, &worklogs, &members, target);
+
+        let summary: Vec<_> = coverage
+            .members
+            .iter()
+            .map(|m| (m.name.as_str(), m.hours, m.coverage, m.missing_days.len()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Ada", 40.0, 100.0, 0),
+                ("Bob", 4.0, 10.0, 5),
+                ("max@x.io", 0.0, 0.0, 5)
+            ]
+        );
+        assert_eq!(coverage.members[0].expected_hours, 40.0);
+    }
+}
@@ -0,0 +1,342 @@
+use serde_json::Value;
+
+/// A small Handlebars-like template, to shape the JSON output of commands:
+///
+/// - `{{path}}` prints a field; dotted paths reach nested fields and array items
+///   (`{{days.0.date}}`), `{{this}}` the current value and `{{@index}}` its position
+/// - `{{#each path}}…{{/each}}` repeats a block for every item of an array
+/// - `{{#if path}}…{{else}}…{{/if}}` when the field isn't null, false, 0 or empty
+/// - `{{upper path}}`, `{{lower path}}`, `{{round path}}` (2 decimals) and `{{hours path}}`
+///   (seconds as decimal hours) transform a field
+///
+/// Inside a block, names not found on the current item are looked up in the outer ones.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Text(String),
+    Field {
+        helper: Option<Helper>,
+        path: String,
+    },
+    Each {
+        path: String,
+        body: Vec<Node>,
+    },
+    If {
+        path: String,
+        then: Vec<Node>,
+        otherwise: Vec<Node>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Helper {
+    Upper,
+    Lower,
+    Round,
+    Hours,
+}
+
+impl Helper {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "upper" => Some(Helper::Upper),
+            "lower" => Some(Helper::Lower),
+            "round" => Some(Helper::Round),
+            "hours" => Some(Helper::Hours),
+            _ => None,
+        }
+    }
+
+    fn apply(self, value: &Value) -> String {
+        let number = value.as_f64();
+        match (self, number) {
+            (Helper::Upper, _) => display(value).to_uppercase(),
+            (Helper::Lower, _) => display(value).to_lowercase(),
+            (Helper::Round, Some(n)) => format!("{:.2}", n),
+            (Helper::Hours, Some(n)) => format!("{:.2}", n / 3600.0),
+            (_, None) => display(value),
+        }
+    }
+}
+
+/// Where a parsed block stops: `{{/each}}`, `{{/if}}`, `{{else}}` or the end of the text
+#[derive(Debug, PartialEq)]
+enum End {
+    Eof,
+    Close(String),
+    Else,
+}
+
+/// One level of the lookup chain while rendering
+#[derive(Clone, Copy)]
+struct Scope<'a> {
+    value: &'a Value,
+    index: Option<usize>,
+}
+
+impl Template {
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut rest = source;
+        let (nodes, end) = parse_nodes(&mut rest)?;
+        match end {
+            End::Eof => Ok(Self { nodes }),
+            End::Close(name) => Err(format!("`{{{{/{}}}}}` without a matching opening", name)),
+            End::Else => Err("`{{else}}` outside of an `{{#if}}`".to_string()),
+        }
+    }
+
+    /// Render the template once for `value`
+    pub fn render(&self, value: &Value) -> String {
+        let mut out = String::new();
+        render_nodes(&self.nodes, &[Scope { value, index: None }], &mut out);
+        out
+    }
+
+    /// Render a command's output: once per item for an array (one line each), once
+    /// otherwise
+    pub fn render_output(&self, value: &Value) -> String {
+        match value {
+            Value::Array(items) => items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    let mut out = String::new();
+                    let scopes = [
+                        Scope { value, index: None },
+                        Scope {
+                            value: item,
+                            index: Some(index),
+                        },
+                    ];
+                    render_nodes(&self.nodes, &scopes, &mut out);
+                    out
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            _ => self.render(value),
+        }
+    }
+}
+
+fn parse_nodes(rest: &mut &str) -> Result<(Vec<Node>, End), String> {
+    let mut nodes = Vec::new();
+    loop {
+        let Some(open) = rest.find("{{") else {
+            if !rest.is_empty() {
+                nodes.push(Node::Text(rest.to_string()));
+            }
+            *rest = "";
+            return Ok((nodes, End::Eof));
+        };
+        if open > 0 {
+            nodes.push(Node::Text(rest[..open].to_string()));
+        }
+        let close = rest[open..]
+            .find("}}")
+            .ok_or_else(|| format!("unclosed `{{{{` in '{}'", &rest[open..]))?;
+        let tag = rest[open + 2..open + close].trim().to_string();
+        *rest = &rest[open + close + 2..];
+
+        if let Some(block) = tag.strip_prefix('#') {
+            let (name, path) = block
+                .split_once(char::is_whitespace)
+                .map(|(name, path)| (name, path.trim()))
+                .ok_or_else(|| format!("`{{{{{}}}}}` needs a field", tag))?;
+            let (body, end) = parse_nodes(rest)?;
+            match (name, end) {
+                ("each", End::Close(closed)) if closed == "each" => nodes.push(Node::Each {
+                    path: path.to_string(),
+                    body,
+                }),
+                ("if", End::Close(closed)) if closed == "if" => nodes.push(Node::If {
+                    path: path.to_string(),
+                    then: body,
+                    otherwise: Vec::new(),
+                }),
+                ("if", End::Else) => {
+                    let (otherwise, end) = parse_nodes(rest)?;
+                    if end != End::Close("if".to_string()) {
+                        return Err("`{{#if}}` isn't closed by `{{/if}}`".to_string());
+                    }
+                    nodes.push(Node::If {
+                        path: path.to_string(),
+                        then: body,
+                        otherwise,
+                    });
+                }
+                ("each" | "if", _) => {
+                    return Err(format!(
+                        "`{{{{#{}}}}}` isn't closed by `{{{{/{}}}}}`",
+                        name, name
+                    ))
+                }
+                _ => return Err(format!("unknown block `{{{{#{}}}}}`", name)),
+            }
+        } else if let Some(name) = tag.strip_prefix('/') {
+            return Ok((nodes, End::Close(name.trim().to_string())));
+        } else if tag == "else" {
+            return Ok((nodes, End::Else));
+        } else if tag.is_empty() {
+            return Err("empty `{{}}`".to_string());
+        } else {
+            let node = match tag.split_once(char::is_whitespace) {
+                Some((helper, path)) => Node::Field {
+                    helper: Some(
+                        Helper::parse(helper)
+                            .ok_or_else(|| format!("unknown helper `{}`", helper))?,
+                    ),
+                    path: path.trim().to_string(),
+                },
+                None => Node::Field {
+                    helper: None,
+                    path: tag,
+                },
+            };
+            nodes.push(node);
+        }
+    }
+}
+
+fn render_nodes(nodes: &[Node], scopes: &[Scope], out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Field { helper, path } => {
+                let value = lookup(scopes, path);
+                match (helper, value) {
+                    (Some(helper), Some(value)) => out.push_str(&helper.apply(&value)),
+                    (None, Some(value)) => out.push_str(&display(&value)),
+                    (_, None) => {}
+                }
+            }
+            Node::Each { path, body } => {
+                if let Some(Value::Array(items)) = lookup(scopes, path) {
+                    for (index, item) in items.iter().enumerate() {
+                        let mut inner = scopes.to_vec();
+                        inner.push(Scope {
+                            value: item,
+                            index: Some(index),
+                        });
+                        render_nodes(body, &inner, out);
+                    }
+                }
+            }
+            Node::If {
+                path,
+                then,
+                otherwise,
+            } => {
+                let branch = if lookup(scopes, path).is_some_and(|v| truthy(&v)) {
+                    then
+                } else {
+                    otherwise
+                };
+                render_nodes(branch, scopes, out);
+            }
+        }
+    }
+}
+
+/// Resolve `path` against the innermost scope that has its first segment
+fn lookup(scopes: &[Scope], path: &str) -> Option<Value> {
+    let current = scopes.last()?;
+    match path {
+        "this" | "." => return Some(current.value.clone()),
+        "@index" => return current.index.map(Value::from),
+        _ => {}
+    }
+    let path = path.strip_prefix("this.").unwrap_or(path);
+    let mut segments = path.split('.');
+    let first = segments.next()?;
+    let start = scopes
+        .iter()
+        .rev()
+        .find_map(|scope| child(scope.value, first))?;
+    segments
+        .try_fold(start, |value, segment| child(value, segment))
+        .cloned()
+}
+
+fn child<'a>(value: &'a Value, segment: &str) -> Option<&'a Value> {
+    match value {
+        Value::Object(map) => map.get(segment),
+        Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+        _ => None,
+    }
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64() != Some(0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(_) => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_render_output_prints_a_line_per_item() {
+        let template = Template::parse("{{@index}} {{upper key}}\t{{hours seconds}}h").unwrap();
+        let output = template.render_output(&json!([
+            {"key": "proj-1", "seconds": 5400},
+            {"key": "proj-2", "seconds": 900},
+        ]));
+
+        assert_eq!(output, "0 PROJ-1\t1.50h\n1 PROJ-2\t0.25h");
+    }
+
+    #[test]
+    fn test_each_and_if_blocks_see_outer_fields() {
+        let template = Template::parse(
+            "{{#each days}}{{date}};{{round total_hours}};{{#if issues}}{{issues.0}}{{else}}-{{/if}};{{from}}\n{{/each}}",
+        )
+        .unwrap();
+        let output = template.render(&json!({
+            "from": "2026-01-05",
+            "days": [
+                {"date": "2026-01-05", "total_hours": 7.5, "issues": ["PROJ-1"]},
+                {"date": "2026-01-06", "total_hours": 0, "issues": []},
+            ],
+        }));
+
+        assert_eq!(
+            output,
+            "2026-01-05;7.50;PROJ-1;2026-01-05\n2026-01-06;0.00;-;2026-01-05\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_reports_unbalanced_blocks_and_unknown_helpers() {
+        assert!(Template::parse("{{#each days}}{{date}}").is_err());
+        assert!(Template::parse("{{#if a}}x{{/each}}").is_err());
+        assert!(Template::parse("{{/if}}").is_err());
+        assert!(Template::parse("{{shout key}}").is_err());
+        assert!(Template::parse("{{key").is_err());
+        assert_eq!(
+            Template::parse("{{missing.field}}!")
+                .unwrap()
+                .render(&json!({})),
+            "!"
+        );
+    }
+}
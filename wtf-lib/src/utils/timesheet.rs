@@ -0,0 +1,284 @@
+use crate::models::data::Worklog;
+use crate::utils::csv;
+use chrono::{Datelike, NaiveDate};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Hours logged per day and per issue over a period, compared to the capacity
+#[derive(Debug, Clone, Serialize)]
+pub struct Timesheet {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    /// Issue keys that have time logged, sorted
+    pub issues: Vec<String>,
+    pub days: Vec<TimesheetDay>,
+    pub issue_totals: BTreeMap<String, f64>,
+    pub total_hours: f64,
+    pub capacity_hours: f64,
+    /// Logged minus capacity: negative when hours are missing
+    pub delta_hours: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TimesheetDay {
+    pub date: NaiveDate,
+    pub hours: BTreeMap<String, f64>,
+    pub total_hours: f64,
+}
+
+impl Timesheet {
+    /// Group the worklogs started between `from` and `to` (inclusive). Every weekday of
+    /// the period gets a row; weekend days only when something was logged on them.
+    pub fn build(
+        from: NaiveDate,
+        to: NaiveDate,
+        worklogs: &[Worklog],
+        capacity_hours: f64,
+    ) -> Self {
+        let mut by_day: BTreeMap<NaiveDate, BTreeMap<String, f64>> = BTreeMap::new();
+        for day in from.iter_days().take_while(|d| *d <= to) {
+            if day.weekday().num_days_from_monday() < 5 {
+                by_day.entry(day).or_default();
+            }
+        }
+        for worklog in worklogs {
+            let date = worklog.started.date_naive();
+            if date < from || date > to {
+                continue;
+            }
+            *by_day
+                .entry(date)
+                .or_default()
+                .entry(worklog.issue_id.clone())
+                .or_default() += worklog.time_spent_seconds as f64 / 3600.0;
+        }
+
+        let mut issue_totals: BTreeMap<String, f64> = BTreeMap::new();
+        let days: Vec<_> = by_day
+            .into_iter()
+            .map(|(date, hours)| {
+                for (issue, h) in &hours {
+                    *issue_totals.entry(issue.clone()).or_default() += h;
+                }
+                TimesheetDay {
+                    date,
+                    total_hours: hours.values().sum(),
+                    hours,
+                }
+            })
+            .collect();
+        let total_hours = issue_totals.values().sum();
+
+        Self {
+            from,
+            to,
+            issues: issue_totals.keys().cloned().collect(),
+            days,
+            issue_totals,
+            total_hours,
+            capacity_hours,
+            delta_hours: total_hours - capacity_hours,
+        }
+    }
+
+    /// Header and cells of the day × issue grid, ending with a "Total" row and column
+    pub fn grid(&self) -> (Vec<String>, Vec<Vec<String>>) {
+        let mut header = vec!["Day".to_string()];
+        header.extend(self.issues.iter().cloned());
+        header.push("Total".to_string());
+
+        let cell = |hours: Option<&f64>| hours.map(|h| format!("{:.2}", h)).unwrap_or_default();
+        let mut rows: Vec<Vec<String>> = self
+            .days
+            .iter()
+            .map(|day| {
+                let mut row = vec![day.date.format("%a %Y-%m-%d").to_string()];
+                row.extend(self.issues.iter().map(|issue| cell(day.hours.get(issue))));
+                row.push(format!("{:.2}", day.total_hours));
+                row
+            })
+            .collect();
+        let mut totals = vec!["Total".to_string()];
+        totals.extend(
+            self.issues
+                .iter()
+                .map(|issue| cell(self.issue_totals.get(issue))),
+        );
+        totals.push(format!("{:.2}", self.total_hours));
+        rows.push(totals);
+
+        (header, rows)
+    }
+
+    /// The same timesheet with the issue columns merged by `group` (issue key → column),
+    /// e.g. to report per epic
+    pub fn group_issues(&self, group: impl Fn(&str) -> String) -> Self {
+        let regroup = |hours: &BTreeMap<String, f64>| {
+            let mut grouped: BTreeMap<String, f64> = BTreeMap::new();
+            for (issue, h) in hours {
+                *grouped.entry(group(issue)).or_default() += h;
+            }
+            grouped
+        };
+        let issue_totals = regroup(&self.issue_totals);
+        Self {
+            issues: issue_totals.keys().cloned().collect(),
+            days: self
+                .days
+                .iter()
+                .map(|day| TimesheetDay {
+                    hours: regroup(&day.hours),
+                    ..day.clone()
+                })
+                .collect(),
+            issue_totals,
+            ..self.clone()
+        }
+    }
+
+    /// Hours per Jira project (the part of the issue key before the dash)
+    pub fn project_totals(&self) -> BTreeMap<String, f64> {
+        let mut totals: BTreeMap<String, f64> = BTreeMap::new();
+        for (issue, hours) in &self.issue_totals {
+            *totals.entry(project_key(issue).to_string()).or_default() += hours;
+        }
+        totals
+    }
+
+    /// The summary line printed under the grid
+    pub fn summary(&self) -> String {
+        format!(
+            "Logged {:.2}h of {:.2}h capacity (delta {:+.2}h)",
+            self.total_hours, self.capacity_hours, self.delta_hours
+        )
+    }
+
+    pub fn to_csv(&self) -> String {
+        let (header, rows) = self.grid();
+        let mut out = csv::to_line(&header);
+        out.push('\n');
+        for row in rows {
+            out.push_str(&csv::to_line(&row));
+            out.push('\n');
+        }
+        out
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let (header, rows) = self.grid();
+        let line = |cells: &[String]| format!("| {} |\n", cells.join(" | "));
+        let mut out = line(&header);
+        out.push_str(&line(&vec!["---".to_string(); header.len()]));
+        for row in rows {
+            out.push_str(&line(&row));
+        }
+        out.push('\n');
+        out.push_str(&self.summary());
+        out.push('\n');
+        out
+    }
+}
+
+/// "PROJ-12" → "PROJ"; keys without a dash are their own project
+pub fn project_key(issue: &str) -> &str {
+    issue.rsplit_once('-').map_or(issue, |(project, _)| project)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn worklog(issue: &str, day: u32, seconds: u64) -> Worklog {
+        let started = Utc.with_ymd_and_hms(2026, 1, day, 9, 0, 0).unwrap();
+        Worklog {
+            id: format!("{}-{}", issue, day),
+            author: "me".to_string(),
+            created: started,
+            time_spent: String::new(),
+            time_spent_seconds: seconds,
+            comment: None,
+            issue_id: issue.to_string(),
+            started,
+        }
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, day).unwrap()
+    }
+
+    #[test]
+    fn test_build_groups_by_day_and_issue() {
+        // 2026-01-05 is a Monday
+        let worklogs = vec![
+            worklog("PROJ-1", 5, 3600),
+            worklog("PROJ-1", 5, 1800),
+            worklog("PROJ-2", 6, 7200),
+            worklog("PROJ-2", 12, 3600),
+        ];
+        let sheet = Timesheet::build(date(5), date(11), &worklogs, 40.0);
+
+        assert_eq!(sheet.issues, vec!["PROJ-1", "PROJ-2"]);
+        assert_eq!(sheet.days.len(), 5);
+        assert_eq!(sheet.days[0].hours["PROJ-1"], 1.5);
+        assert_eq!(sheet.days[1].total_hours, 2.0);
+        assert_eq!(sheet.total_hours, 3.5);
+        assert_eq!(sheet.delta_hours, -36.5);
+    }
+
+    #[test]
+    fn test_weekend_rows_only_when_logged() {
+        let worklogs = vec![worklog("PROJ-1", 10, 3600)];
+        let sheet = Timesheet::build(date(5), date(11), &worklogs, 40.0);
+
+        let days: Vec<_> = sheet.days.iter().map(|d| d.date).collect();
+        assert_eq!(days.len(), 6);
+        assert_eq!(days.last(), Some(&date(10)));
+    }
+
+    #[test]
+    fn test_project_totals_group_issue_keys() {
+        let worklogs = vec![
+            worklog("PROJ-1", 5, 3600),
+            worklog("PROJ-2", 5, 3600),
+            worklog("OPS-7", 6, 1800),
+        ];
+        let totals = Timesheet::build(date(5), date(9), &worklogs, 40.0).project_totals();
+
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals["PROJ"], 2.0);
+        assert_eq!(totals["OPS"], 0.5);
+    }
+
+    #[test]
+    fn test_group_issues_merges_columns() {
+        let worklogs = vec![
+            worklog("PROJ-1", 5, 3600),
+            worklog("PROJ-2", 5, 1800),
+            worklog("OPS-7", 6, 1800),
+        ];
+        let sheet = Timesheet::build(date(5), date(9), &worklogs, 40.0).group_issues(|issue| {
+            if issue.starts_with("PROJ") {
+                "PROJ-100".to_string()
+            } else {
+                "No epic".to_string()
+            }
+        });
+
+        assert_eq!(sheet.issues, vec!["No epic", "PROJ-100"]);
+        assert_eq!(sheet.days[0].hours["PROJ-100"], 1.5);
+        assert_eq!(sheet.issue_totals["No epic"], 0.5);
+        assert_eq!(sheet.total_hours, 2.0);
+    }
+
+    #[test]
+    fn test_markdown_has_totals_row_and_summary() {
+        let worklogs = vec![worklog("PROJ-1", 5, 3600)];
+        let md = Timesheet::build(date(5), date(5), &worklogs, 8.0).to_markdown();
+
+        assert!(md.starts_with("| Day | PROJ-1 | Total |\n| --- | --- | --- |\n"));
+        assert!(md.contains("| Mon 2026-01-05 | 1.00 | 1.00 |"));
+        assert!(md.contains("| Total | 1.00 | 1.00 |"));
+        assert!(md.contains("Logged 1.00h of 8.00h capacity (delta -7.00h)"));
+    }
+}
@@ -0,0 +1,180 @@
+use crate::utils::timesheet::{project_key, Timesheet};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+const STYLE: &str = "
+body { font-family: -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; color: #222; margin: 2.5em; }
+h1 { margin-bottom: 0.1em; }
+.meta { color: #666; margin-top: 0; }
+table { border-collapse: collapse; width: 100%; margin: 0.8em 0 1.8em; font-size: 0.92em; }
+th, td { border-bottom: 1px solid #ddd; padding: 0.35em 0.6em; text-align: left; }
+th { background: #f3f4f6; }
+td.num, th.num { text-align: right; font-variant-numeric: tabular-nums; }
+tr.total td { font-weight: bold; border-top: 2px solid #999; }
+.summary td { border: none; padding: 0.15em 1.2em 0.15em 0; }
+.negative { color: #b45309; }
+.signatures { display: flex; gap: 4em; margin-top: 4em; }
+.signature { flex: 1; border-top: 1px solid #222; padding-top: 0.4em; color: #444; }
+@media print { body { margin: 1.2cm; } h2 { page-break-after: avoid; } }
+";
+
+/// What goes around the numbers in the printable timesheet
+pub struct HtmlTimesheet<'a> {
+    pub title: &'a str,
+    /// Name of the person submitting the timesheet
+    pub name: &'a str,
+    /// Issue key → summary, used in the project breakdown
+    pub issue_summaries: &'a HashMap<String, String>,
+}
+
+impl HtmlTimesheet<'_> {
+    /// A standalone HTML document (inline CSS, no external assets) ready to print or
+    /// convert to PDF
+    pub fn render(&self, timesheet: &Timesheet) -> String {
+        let mut html = String::new();
+        let _ = write!(
+            html,
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>{title}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n\
+             <h1>{title}</h1>\n<p class=\"meta\">{name} · {from} to {to}</p>\n",
+            title = escape(self.title),
+            name = escape(self.name),
+            from = timesheet.from,
+            to = timesheet.to,
+        );
+
+        let delta_class = if timesheet.delta_hours < 0.0 {
+            " class=\"negative\""
+        } else {
+            ""
+        };
+        let _ = write!(
+            html,
+            "<table class=\"summary\">\n\
+             <tr><td>Logged</td><td class=\"num\">{:.2} h</td></tr>\n\
+             <tr><td>Capacity</td><td class=\"num\">{:.2} h</td></tr>\n\
+             <tr><td>Delta</td><td class=\"num\"><span{}>{:+.2} h</span></td></tr>\n\
+             </table>\n",
+            timesheet.total_hours, timesheet.capacity_hours, delta_class, timesheet.delta_hours
+        );
+
+        html.push_str(&self.project_breakdown(timesheet));
+        html.push_str(&daily_grid(timesheet));
+
+        html.push_str(
+            "<div class=\"signatures\">\n\
+             <div class=\"signature\">Signature &amp; date</div>\n\
+             <div class=\"signature\">Approved by (client) &amp; date</div>\n\
+             </div>\n</body>\n</html>\n",
+        );
+        html
+    }
+
+    fn project_breakdown(&self, timesheet: &Timesheet) -> String {
+        let mut html = String::from("<h2>Projects</h2>\n");
+        for (project, project_hours) in timesheet.project_totals() {
+            let _ = write!(
+                html,
+                "<h3>{}</h3>\n<table>\n<tr><th>Issue</th><th>Summary</th>\
+                 <th class=\"num\">Hours</th></tr>\n",
+                escape(&project)
+            );
+            for (issue, hours) in timesheet
+                .issue_totals
+                .iter()
+                .filter(|(issue, _)| project_key(issue) == project)
+            {
+                let summary = self
+                    .issue_summaries
+                    .get(issue)
+                    .map(String::as_str)
+                    .unwrap_or("");
+                let _ = writeln!(
+                    html,
+                    "<tr><td>{}</td><td>{}</td><td class=\"num\">{:.2}</td></tr>",
+                    escape(issue),
+                    escape(summary),
+                    hours
+                );
+            }
+            let _ = write!(
+                html,
+                "<tr class=\"total\"><td colspan=\"2\">Total {}</td>\
+                 <td class=\"num\">{:.2}</td></tr>\n</table>\n",
+                escape(&project),
+                project_hours
+            );
+        }
+        html
+    }
+}
+
+fn daily_grid(timesheet: &Timesheet) -> String {
+    let (header, rows) = timesheet.grid();
+    let mut html = String::from("<h2>Daily detail</h2>\n<table>\n<tr>");
+    for (i, cell) in header.iter().enumerate() {
+        let class = if i == 0 { "" } else { " class=\"num\"" };
+        let _ = write!(html, "<th{}>{}</th>", class, escape(cell));
+    }
+    html.push_str("</tr>\n");
+    let last = rows.len().saturating_sub(1);
+    for (r, row) in rows.iter().enumerate() {
+        html.push_str(if r == last {
+            "<tr class=\"total\">"
+        } else {
+            "<tr>"
+        });
+        for (i, cell) in row.iter().enumerate() {
+            let class = if i == 0 { "" } else { " class=\"num\"" };
+            let _ = write!(html, "<td{}>{}</td>", class, escape(cell));
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</table>\n");
+    html
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::data::Worklog;
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    #[test]
+    fn test_render_includes_breakdown_and_signature() {
+        let started = Utc.with_ymd_and_hms(2026, 1, 5, 9, 0, 0).unwrap();
+        let worklog = Worklog {
+            id: "1".to_string(),
+            author: "me".to_string(),
+            created: started,
+            time_spent: "2h".to_string(),
+            time_spent_seconds: 7200,
+            comment: None,
+            issue_id: "PROJ-1".to_string(),
+            started,
+        };
+        let day = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+        let timesheet = Timesheet::build(day, day, &[worklog], 8.0);
+        let summaries = HashMap::from([("PROJ-1".to_string(), "Fix <login>".to_string())]);
+
+        let html = HtmlTimesheet {
+            title: "Timesheet January 2026",
+            name: "Ada & Co",
+            issue_summaries: &summaries,
+        }
+        .render(&timesheet);
+
+        assert!(html.contains("<p class=\"meta\">Ada &amp; Co · 2026-01-05 to 2026-01-05</p>"));
+        assert!(html.contains("<h3>PROJ</h3>"));
+        assert!(html.contains("<td>Fix &lt;login&gt;</td>"));
+        assert!(html.contains("<span class=\"negative\">-6.00 h</span>"));
+        assert!(html.contains("class=\"signature\""));
+    }
+}
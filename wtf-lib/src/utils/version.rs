@@ -1,3 +1,5 @@
+use crate::client::{http_client, retry};
+use crate::config::Config;
 /// Fetches all available release tags from GitHub.
 /// If `include_prerelease` is false, only stable releases are returned.
 pub async fn list_versions(include_prerelease: bool) -> Vec<String> {
@@ -6,10 +8,9 @@ pub async fn list_versions(include_prerelease: bool) -> Vec<String> {
         None => return vec![],
     };
 
-    let response = client
-        .get("https://api.github.com/repos/thermoweb/work-time-filler/releases")
-        .send()
-        .await;
+    let response =
+        retry::send(client.get("https://api.github.com/repos/thermoweb/work-time-filler/releases"))
+            .await;
 
     let releases: serde_json::Value = match response.ok().map(|r| async move { r.json().await }) {
         Some(fut) => match fut.await.ok() {
@@ -48,14 +49,13 @@ pub async fn check_latest_version() -> Option<String> {
 pub async fn check_latest_prerelease_version() -> Option<String> {
     let client = build_client()?;
 
-    let releases: serde_json::Value = client
-        .get("https://api.github.com/repos/thermoweb/work-time-filler/releases")
-        .send()
-        .await
-        .ok()?
-        .json()
-        .await
-        .ok()?;
+    let releases: serde_json::Value =
+        retry::send(client.get("https://api.github.com/repos/thermoweb/work-time-filler/releases"))
+            .await
+            .ok()?
+            .json()
+            .await
+            .ok()?;
 
     // Releases are sorted newest-first; take the first one
     let tag = releases.get(0)?.get("tag_name")?.as_str()?;
@@ -70,7 +70,11 @@ pub async fn check_latest_prerelease_version() -> Option<String> {
 }
 
 fn build_client() -> Option<reqwest::Client> {
-    reqwest::Client::builder()
+    let network = Config::load()
+        .map(|config| config.network)
+        .unwrap_or_default();
+    http_client::builder(&network)
+        .ok()?
         .user_agent(concat!("wtf/", env!("CARGO_PKG_VERSION")))
         .timeout(std::time::Duration::from_secs(5))
         .build()
@@ -78,9 +82,7 @@ fn build_client() -> Option<reqwest::Client> {
 }
 
 async fn check_version_from_url(url: &str) -> Option<String> {
-    let resp: serde_json::Value = build_client()?
-        .get(url)
-        .send()
+    let resp: serde_json::Value = retry::send(build_client()?.get(url))
         .await
         .ok()?
         .json()
@@ -0,0 +1,293 @@
+use crate::duration::parse_work_duration;
+use crate::utils::csv;
+use crate::utils::dates::parse_day;
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Start of the first worklog of a day when the row gives no start time
+const DEFAULT_START: NaiveTime = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+
+/// One row of an import file, before validation
+#[derive(Debug, Default, Deserialize)]
+pub struct ImportRow {
+    #[serde(alias = "day")]
+    pub date: String,
+    #[serde(alias = "key", alias = "issue_key")]
+    pub issue: String,
+    /// Decimal hours, e.g. 1.5
+    #[serde(default)]
+    pub hours: Option<f64>,
+    /// Jira-style duration, e.g. "1h30" or "1d"; used when `hours` is missing
+    #[serde(default, alias = "time_spent")]
+    pub duration: Option<String>,
+    /// HH:MM, local time
+    #[serde(default)]
+    pub start: Option<String>,
+    #[serde(default, alias = "description")]
+    pub comment: Option<String>,
+}
+
+/// A validated row, ready to become a local worklog
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportEntry {
+    /// Row number in the file (CSV line, or position in the JSON array), starting at 1
+    pub row: usize,
+    pub issue: String,
+    /// Local date and time the work started
+    pub started: NaiveDateTime,
+    pub seconds: i64,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowError {
+    pub row: usize,
+    pub message: String,
+}
+
+#[derive(Debug, Default)]
+pub struct ImportPlan {
+    pub entries: Vec<ImportEntry>,
+    pub errors: Vec<RowError>,
+}
+
+/// Read rows from a CSV file with a header line. Columns are matched by name
+/// (`date`, `issue`, `hours` or `duration`, and optionally `start` and `comment`), in any
+/// order; other columns are ignored, so a `wtf export worklogs` file can be imported.
+pub fn rows_from_csv(text: &str) -> Result<Vec<(usize, ImportRow)>, String> {
+    let mut records = csv::parse(text).into_iter();
+    let Some((_, header)) = records.next() else {
+        return Ok(Vec::new());
+    };
+    let columns: HashMap<String, usize> = header
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (name.trim().to_lowercase(), i))
+        .collect();
+    let column = |names: &[&str]| names.iter().find_map(|name| columns.get(*name).copied());
+    let (Some(date), Some(issue)) = (
+        column(&["date", "day"]),
+        column(&["issue", "key", "issue_key"]),
+    ) else {
+        return Err("The header needs `date` and `issue` columns".to_string());
+    };
+    let hours = column(&["hours"]);
+    let duration = column(&["duration", "time_spent"]);
+    if hours.is_none() && duration.is_none() {
+        return Err("The header needs an `hours` or a `duration` column".to_string());
+    }
+    let start = column(&["start"]);
+    let comment = column(&["comment", "description"]);
+
+    Ok(records
+        .map(|(line, fields)| {
+            let get = |index: Option<usize>| {
+                index
+                    .and_then(|i| fields.get(i))
+                    .map(|f| f.trim().to_string())
+                    .filter(|f| !f.is_empty())
+            };
+            let row = ImportRow {
+                date: get(Some(date)).unwrap_or_default(),
+                issue: get(Some(issue)).unwrap_or_default(),
+                hours: get(hours).and_then(|h| h.replace(',', ".").parse().ok()),
+                // An unreadable `hours` cell is reported through the duration parser
+                duration: get(duration).or_else(|| get(hours)),
+                start: get(start),
+                comment: get(comment),
+            };
+            (line, row)
+        })
+        .collect())
+}
+
+/// Read rows from a JSON array of objects with the same fields as the CSV columns
+pub fn rows_from_json(text: &str) -> Result<Vec<(usize, ImportRow)>, String> {
+    let rows: Vec<ImportRow> = serde_json::from_str(text).map_err(|e| e.to_string())?;
+    Ok(rows
+        .into_iter()
+        .enumerate()
+        .map(|(i, row)| (i + 1, row))
+        .collect())
+}
+
+/// Validate rows and work out when each worklog starts. `resolve_issue` maps the key
+/// written in the file to a known issue key. Rows without a start time are laid end to
+/// end from 09:00 on their day.
+pub fn plan_import(
+    rows: Vec<(usize, ImportRow)>,
+    hours_per_day: f64,
+    today: NaiveDate,
+    resolve_issue: impl Fn(&str) -> Option<String>,
+) -> ImportPlan {
+    let mut plan = ImportPlan::default();
+    let mut next_start: HashMap<NaiveDate, NaiveDateTime> = HashMap::new();
+    // Local worklog IDs derive from the issue and start, so these must be unique
+    let mut seen: HashMap<(String, NaiveDateTime), usize> = HashMap::new();
+
+    for (row, input) in rows {
+        match validate_row(&input, hours_per_day, today, &resolve_issue) {
+            Ok((issue, day, start, seconds)) => {
+                let started = match start {
+                    Some(time) => day.and_time(time),
+                    None => {
+                        let cursor = next_start
+                            .entry(day)
+                            .or_insert_with(|| day.and_time(DEFAULT_START));
+                        let started = *cursor;
+                        *cursor += Duration::seconds(seconds);
+                        started
+                    }
+                };
+                if let Some(first) = seen.insert((issue.clone(), started), row) {
+                    plan.errors.push(RowError {
+                        row,
+                        message: format!("same issue and start time as row {}", first),
+                    });
+                    continue;
+                }
+                plan.entries.push(ImportEntry {
+                    row,
+                    issue,
+                    started,
+                    seconds,
+                    comment: input.comment,
+                });
+            }
+            Err(message) => plan.errors.push(RowError { row, message }),
+        }
+    }
+    plan
+}
+
+fn validate_row(
+    row: &ImportRow,
+    hours_per_day: f64,
+    today: NaiveDate,
+    resolve_issue: &impl Fn(&str) -> Option<String>,
+) -> Result<(String, NaiveDate, Option<NaiveTime>, i64), String> {
+    if row.issue.trim().is_empty() {
+        return Err("missing issue".to_string());
+    }
+    let issue = resolve_issue(row.issue.trim())
+        .ok_or_else(|| format!("unknown issue '{}' (run `wtf fetch issue`?)", row.issue))?;
+    let day = parse_day(&row.date, today).ok_or_else(|| {
+        if row.date.trim().is_empty() {
+            "missing date".to_string()
+        } else {
+            format!("invalid date '{}'", row.date)
+        }
+    })?;
+    let seconds = match (&row.hours, &row.duration) {
+        (Some(hours), _) => (hours * 3600.0).round() as i64,
+        (None, Some(duration)) => parse_work_duration(duration, hours_per_day)
+            .map_err(|_| format!("invalid duration '{}'", duration))?
+            .num_seconds(),
+        (None, None) => return Err("missing hours or duration".to_string()),
+    };
+    if seconds <= 0 {
+        return Err("time spent must be positive".to_string());
+    }
+    let start = match &row.start {
+        Some(start) => Some(
+            NaiveTime::parse_from_str(start.trim(), "%H:%M")
+                .map_err(|_| format!("invalid start time '{}', expected HH:MM", start))?,
+        ),
+        None => None,
+    };
+    Ok((issue, day, start, seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, 9).unwrap()
+    }
+
+    fn resolve(key: &str) -> Option<String> {
+        ["PROJ-1", "PROJ-2"]
+            .iter()
+            .find(|known| known.eq_ignore_ascii_case(key))
+            .map(|known| known.to_string())
+    }
+
+    #[test]
+    fn test_csv_rows_are_matched_by_header() {
+        let text = "Issue,Date,Hours,Comment,Source\n\
+                    proj-1,2026-01-05,1.5,\"Review, part 1\",local\n\
+                    PROJ-2,yesterday,\"0,5\",,jira\n";
+        let plan = plan_import(rows_from_csv(text).unwrap(), 8.0, today(), resolve);
+
+        assert!(plan.errors.is_empty(), "{:?}", plan.errors);
+        assert_eq!(plan.entries[0].issue, "PROJ-1");
+        assert_eq!(plan.entries[0].seconds, 5400);
+        assert_eq!(plan.entries[0].comment.as_deref(), Some("Review, part 1"));
+        assert_eq!(plan.entries[1].row, 3);
+        assert_eq!(plan.entries[1].seconds, 1800);
+        assert_eq!(
+            plan.entries[1].started.date(),
+            NaiveDate::from_ymd_opt(2026, 1, 8).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rows_without_start_are_laid_end_to_end() {
+        let json = r#"[
+            {"date": "2026-01-05", "issue": "PROJ-1", "duration": "1h30"},
+            {"date": "2026-01-05", "issue": "PROJ-1", "hours": 2},
+            {"date": "2026-01-05", "issue": "PROJ-2", "hours": 1, "start": "14:00"},
+            {"date": "2026-01-05", "issue": "PROJ-2", "hours": 1, "start": "14:00"}
+        ]"#;
+        let plan = plan_import(rows_from_json(json).unwrap(), 8.0, today(), resolve);
+
+        let starts: Vec<_> = plan
+            .entries
+            .iter()
+            .map(|e| e.started.format("%H:%M").to_string())
+            .collect();
+        assert_eq!(starts, vec!["09:00", "10:30", "14:00"]);
+        assert_eq!(
+            plan.errors,
+            vec![RowError {
+                row: 4,
+                message: "same issue and start time as row 3".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_invalid_rows_are_reported_with_their_row() {
+        let text = "date,issue,duration\n\
+                    2026-01-05,PROJ-9,1h\n\
+                    someday,PROJ-1,1h\n\
+                    2026-01-05,PROJ-1,lots\n\
+                    2026-01-05,PROJ-1,1d\n";
+        let plan = plan_import(rows_from_csv(text).unwrap(), 7.5, today(), resolve);
+
+        let errors: Vec<_> = plan
+            .errors
+            .iter()
+            .map(|e| format!("{}: {}", e.row, e.message))
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                "2: unknown issue 'PROJ-9' (run `wtf fetch issue`?)",
+                "3: invalid date 'someday'",
+                "4: invalid duration 'lots'",
+            ]
+        );
+        assert_eq!(plan.entries.len(), 1);
+        assert_eq!(plan.entries[0].seconds, 27000);
+    }
+
+    #[test]
+    fn test_csv_header_must_name_the_required_columns() {
+        assert!(rows_from_csv("when,what\n2026-01-05,PROJ-1\n").is_err());
+        assert!(rows_from_csv("date,issue,comment\n").is_err());
+        assert!(rows_from_csv("").unwrap().is_empty());
+    }
+}
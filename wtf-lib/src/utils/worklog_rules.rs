@@ -0,0 +1,206 @@
+use crate::config::ValidationConfig;
+use crate::models::data::LocalWorklog;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The checks of the `[validation]` section
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationRule {
+    MaxHoursPerDay,
+    AllowedPrefixes,
+    MinCommentLength,
+    NoClosedIssues,
+}
+
+impl ValidationRule {
+    pub fn name(self) -> &'static str {
+        match self {
+            ValidationRule::MaxHoursPerDay => "max_hours_per_day",
+            ValidationRule::AllowedPrefixes => "allowed_prefixes",
+            ValidationRule::MinCommentLength => "min_comment_length",
+            ValidationRule::NoClosedIssues => "no_closed_issues",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Shown, the worklog is staged anyway
+    Warning,
+    /// The worklog is not staged
+    Blocking,
+}
+
+/// A rule a worklog breaks
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Violation {
+    pub worklog_id: String,
+    pub issue: String,
+    pub date: NaiveDate,
+    pub rule: ValidationRule,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Violation {
+    pub fn is_blocking(&self) -> bool {
+        self.severity == Severity::Blocking
+    }
+}
+
+/// What the rules need to know beyond the worklogs themselves
+pub struct RuleContext<'a> {
+    /// Hours logged on a day, the checked worklogs included
+    pub day_total: &'a dyn Fn(NaiveDate) -> f64,
+    /// Jira status of an issue, when it has been fetched
+    pub issue_status: &'a dyn Fn(&str) -> Option<String>,
+}
+
+/// Check `worklogs` against the configured rules, in the worklogs' order
+pub fn check(
+    config: &ValidationConfig,
+    worklogs: &[LocalWorklog],
+    context: &RuleContext,
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let mut day_totals: HashMap<NaiveDate, f64> = HashMap::new();
+    for worklog in worklogs {
+        let date = worklog.started.date_naive();
+        let mut violation = |rule: ValidationRule, message: String| {
+            violations.push(Violation {
+                worklog_id: worklog.id.clone(),
+                issue: worklog.issue_id.clone(),
+                date,
+                rule,
+                severity: config.severity(rule),
+                message,
+            })
+        };
+
+        if let Some(max_hours) = config.max_hours_per_day {
+            let total = *day_totals
+                .entry(date)
+                .or_insert_with(|| (context.day_total)(date));
+            if total > max_hours + f64::EPSILON {
+                violation(
+                    ValidationRule::MaxHoursPerDay,
+                    format!("{:.1}h logged on {}, more than {}h", total, date, max_hours),
+                );
+            }
+        }
+
+        let project = worklog.issue_id.split('-').next().unwrap_or_default();
+        if !config.allowed_prefixes.is_empty()
+            && !config
+                .allowed_prefixes
+                .iter()
+                .any(|prefix| prefix.eq_ignore_ascii_case(project))
+        {
+            violation(
+                ValidationRule::AllowedPrefixes,
+                format!(
+                    "{} is not in an allowed project ({})",
+                    worklog.issue_id,
+                    config.allowed_prefixes.join(", ")
+                ),
+            );
+        }
+
+        let comment_length = worklog.comment.trim().chars().count();
+        if comment_length < config.min_comment_length {
+            violation(
+                ValidationRule::MinCommentLength,
+                format!(
+                    "comment of {} characters, {} needed",
+                    comment_length, config.min_comment_length
+                ),
+            );
+        }
+
+        if config.no_closed_issues {
+            if let Some(status) = (context.issue_status)(&worklog.issue_id)
+                .filter(|status| config.is_closed_status(status))
+            {
+                violation(
+                    ValidationRule::NoClosedIssues,
+                    format!("{} is {}", worklog.issue_id, status),
+                );
+            }
+        }
+    }
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::data::LocalWorklogState;
+    use chrono::{Datelike, TimeZone, Utc};
+
+    fn worklog(id: &str, issue: &str, day: u32, comment: &str) -> LocalWorklog {
+        LocalWorklog {
+            id: id.to_string(),
+            comment: comment.to_string(),
+            time_spent_seconds: 3600,
+            issue_id: issue.to_string(),
+            status: LocalWorklogState::Created,
+            started: Utc.with_ymd_and_hms(2026, 3, day, 9, 0, 0).unwrap(),
+            meeting_id: None,
+            worklog_id: None,
+        }
+    }
+
+    #[test]
+    fn test_each_rule_and_its_severity() {
+        let config = ValidationConfig {
+            max_hours_per_day: Some(10.0),
+            allowed_prefixes: vec!["PROJ".to_string(), "OPS".to_string()],
+            min_comment_length: 5,
+            no_closed_issues: true,
+            blocking: vec![ValidationRule::NoClosedIssues],
+            ..ValidationConfig::default()
+        };
+        let worklogs = vec![
+            worklog("1", "PROJ-1", 2, "Code review"),
+            worklog("2", "HR-4", 3, "Training"),
+            worklog("3", "OPS-7", 3, "fix"),
+            worklog("4", "PROJ-2", 4, "Release notes"),
+        ];
+        let day_total = |date: NaiveDate| if date.day0() == 1 { 11.5 } else { 4.0 };
+        let issue_status = |key: &str| (key == "PROJ-2").then(|| "Done".to_string());
+        let context = RuleContext {
+            day_total: &day_total,
+            issue_status: &issue_status,
+        };
+
+        let violations = check(&config, &worklogs, &context);
+
+        let found: Vec<_> = violations
+            .iter()
+            .map(|v| (v.worklog_id.as_str(), v.rule, v.severity))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("1", ValidationRule::MaxHoursPerDay, Severity::Warning),
+                ("2", ValidationRule::AllowedPrefixes, Severity::Warning),
+                ("3", ValidationRule::MinCommentLength, Severity::Warning),
+                ("4", ValidationRule::NoClosedIssues, Severity::Blocking),
+            ]
+        );
+        assert_eq!(violations[3].message, "PROJ-2 is Done");
+    }
+
+    #[test]
+    fn test_no_rules_configured() {
+        let context = RuleContext {
+            day_total: &|_| 24.0,
+            issue_status: &|_| Some("Closed".to_string()),
+        };
+        let worklogs = vec![worklog("1", "ANY-1", 2, "")];
+        assert!(check(&ValidationConfig::default(), &worklogs, &context).is_empty());
+    }
+}